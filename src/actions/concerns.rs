@@ -0,0 +1,394 @@
+//! What is about to go wrong, looked ahead from the current state: the
+//! "Concerns" section under `status`. Each concern carries one thing to do
+//! about it, and only the most pressing few are shown.
+
+use crate::actions::nearest_water;
+use crate::descriptions::distance_phrase;
+use crate::entity::{FireState, PartCondition};
+use crate::persistence::GameState;
+use crate::world::WorldMap;
+
+/// How many concerns `status` lists at most.
+const MAX_CONCERNS: usize = 3;
+
+/// Below this, fullness and hydration start costing energy every tick.
+const NEED_THRESHOLD: f32 = 20.0;
+
+/// How far ahead, in hours, a concern is worth raising.
+const LOOKAHEAD_HOURS: f32 = 4.0;
+
+struct Concern {
+    /// Higher is more pressing.
+    urgency: f32,
+    warning: String,
+    action: String,
+}
+
+fn minutes_of_day(state: &GameState) -> i32 {
+    state.time.hour as i32 * 60 + state.time.minute as i32
+}
+
+/// Minutes from now until `hour` o'clock comes round again.
+fn minutes_until(state: &GameState, hour: i32) -> i32 {
+    (hour * 60 - minutes_of_day(state)).rem_euclid(24 * 60)
+}
+
+fn hours_phrase(hours: f32) -> String {
+    let rounded = hours.round().max(1.0) as u32;
+    if rounded == 1 {
+        "about an hour".to_string()
+    } else {
+        format!("about {} hours", rounded)
+    }
+}
+
+/// The hearth running out of fuel before morning.
+fn fire_concern(state: &GameState) -> Option<Concern> {
+    let fire = &state.cabin_state()?.fireplace;
+    if fire.state == FireState::Cold {
+        return None;
+    }
+    let hour = state.time.hour;
+    if (6..17).contains(&hour) {
+        return None;
+    }
+    let burn = fire.fuel_per_tick() * state.weather.wind.fire_draw();
+    if burn <= 0.0 {
+        return None;
+    }
+    let fire_hours = fire.fuel / burn * state.time.minutes_per_tick as f32 / 60.0;
+    let dawn_hours = minutes_until(state, 6) as f32 / 60.0;
+    if fire_hours >= dawn_hours {
+        return None;
+    }
+    Some(Concern {
+        urgency: 55.0 + (dawn_hours - fire_hours).min(10.0) * 2.0,
+        warning: format!(
+            "The hearth has {} of fuel left, and dawn is {} off.",
+            hours_phrase(fire_hours),
+            hours_phrase(dawn_hours)
+        ),
+        action: "Feed it before you sleep: use firewood on fire.".to_string(),
+    })
+}
+
+/// Fullness or hydration running down toward the point where it hurts.
+fn need_concern(label: &str, value: f32, action: String) -> Option<Concern> {
+    // Both decay by half a point every ten minutes.
+    let runway = (value - NEED_THRESHOLD) / 3.0;
+    if runway > LOOKAHEAD_HOURS {
+        return None;
+    }
+    let warning = if runway <= 0.0 {
+        format!(
+            "Your {} is at {:.0} and already wearing you down.",
+            label, value
+        )
+    } else {
+        format!(
+            "Your {} is at {:.0}; at this rate it starts to hurt in {}.",
+            label,
+            value,
+            hours_phrase(runway)
+        )
+    };
+    Some(Concern {
+        urgency: 90.0 - runway.max(0.0) * 10.0,
+        warning,
+        action,
+    })
+}
+
+fn thirst_concern(state: &GameState, map: &WorldMap) -> Option<Concern> {
+    let pos = state.player.position;
+    let action = match nearest_water(&pos, map) {
+        Some(water) if state.player.room.is_none() => {
+            format!("Drink at the water, {}.", distance_phrase(&pos, &water))
+        }
+        _ => "Get to the lake or the oasis and drink.".to_string(),
+    };
+    need_concern("hydration", state.player.hydration, action)
+}
+
+fn hunger_concern(state: &GameState) -> Option<Concern> {
+    let action =
+        "Eat something: forage a bush, fish the shallows, or cook what you carry.".to_string();
+    // Hunger bites a little slower than thirst, so it ranks just behind.
+    need_concern("fullness", state.player.fullness, action).map(|mut c| {
+        c.urgency -= 5.0;
+        c
+    })
+}
+
+/// Warmth an hour from now if the player stays where they are.
+fn projected_warmth(state: &GameState, map: &WorldMap) -> f32 {
+    let target = (state.current_temperature(map) + 20.0).clamp(0.0, 100.0);
+    let ticks = (60 / state.time.minutes_per_tick.max(1)) as i32;
+    let current = state.player.warmth;
+    current + (target - current) * (1.0 - 0.9_f32.powi(ticks))
+}
+
+fn warmth_concern(state: &GameState, map: &WorldMap) -> Option<Concern> {
+    let projected = projected_warmth(state, map);
+    let warmth = state.player.warmth;
+    if projected < 25.0 && projected <= warmth {
+        Some(Concern {
+            urgency: 70.0 + (25.0 - projected),
+            warning: format!(
+                "You're cooling: warmth {:.0} now, near {:.0} within the hour.",
+                warmth, projected
+            ),
+            action: "Get indoors or beside a fire, or put on something warmer.".to_string(),
+        })
+    } else if projected > 80.0 && projected >= warmth {
+        Some(Concern {
+            urgency: 50.0 + (projected - 80.0),
+            warning: format!(
+                "You're overheating: warmth {:.0} now, near {:.0} within the hour.",
+                warmth, projected
+            ),
+            action: "Find shade or water and rest out of the sun.".to_string(),
+        })
+    } else {
+        None
+    }
+}
+
+/// A carried tool down to its last few uses.
+fn tool_concern(state: &GameState) -> Option<Concern> {
+    let player = &state.player;
+    let (item, left) = player
+        .inventory
+        .slots
+        .iter()
+        .filter_map(|slot| {
+            let (left, max) = player.tool_condition(&slot.item)?;
+            (left <= 3 || left * 5 <= max).then_some((slot.item, left))
+        })
+        .min_by_key(|(_, left)| *left)?;
+    Some(Concern {
+        urgency: 40.0 - left as f32,
+        warning: format!(
+            "Your {} is close to breaking ({} left).",
+            state.display_name(&item),
+            left
+        ),
+        action: "Save it for what matters, or make a spare before it goes.".to_string(),
+    })
+}
+
+/// Injuries that get worse if left alone.
+fn condition_concern(state: &GameState) -> Option<Concern> {
+    let body = &state.player.body;
+    let bleeding = body.bleeding_parts();
+    if bleeding > 0 {
+        let action = if state.player.inventory.has(&crate::entity::Item::Bandage, 1) {
+            "Bandage it: use bandage.".to_string()
+        } else {
+            "Make a bandage and use it before you lose more blood.".to_string()
+        };
+        return Some(Concern {
+            urgency: 85.0 + bleeding as f32 * 3.0,
+            warning: if bleeding == 1 {
+                "You're bleeding, and it's costing you health every tick.".to_string()
+            } else {
+                format!(
+                    "You're bleeding in {} places, costing health every tick.",
+                    bleeding
+                )
+            },
+            action,
+        });
+    }
+    let nipped: Vec<&str> = body
+        .parts
+        .iter()
+        .filter(|p| p.has(PartCondition::Frostnipped))
+        .map(|p| p.kind.name())
+        .collect();
+    if nipped.is_empty() {
+        return None;
+    }
+    Some(Concern {
+        urgency: 60.0,
+        warning: format!("Frostnip is setting into your {}.", nipped.join(" and ")),
+        action: "Warm up properly; it only eases once you're comfortably warm.".to_string(),
+    })
+}
+
+/// Dark falling before the walk back to the cabin is done.
+fn nightfall_concern(state: &GameState) -> Option<Concern> {
+    if state.player.room.is_some() || state.player.inside.is_some() {
+        return None;
+    }
+    let hour = state.time.hour;
+    if !(12..19).contains(&hour) {
+        return None;
+    }
+    let cabin = state.objects.find("cabin")?.position;
+    let pos = state.player.position;
+    let steps = (pos.row - cabin.row).abs() + (pos.col - cabin.col).abs();
+    if steps <= 3 {
+        return None;
+    }
+    let walk = steps * state.time.minutes_per_tick as i32;
+    let dark = minutes_until(state, 19);
+    if walk + 30 < dark {
+        return None;
+    }
+    Some(Concern {
+        urgency: 65.0 + ((walk + 30 - dark) as f32 / 30.0).min(15.0),
+        warning: format!(
+            "Dark falls in {}, and the cabin is {} away.",
+            hours_phrase(dark as f32 / 60.0),
+            hours_phrase(walk as f32 / 60.0)
+        ),
+        action: format!("Start back now; it's {}.", distance_phrase(&pos, &cabin)),
+    })
+}
+
+/// Everything looming, most pressing first.
+fn ranked_concerns(state: &GameState, map: &WorldMap) -> Vec<Concern> {
+    let mut concerns: Vec<Concern> = [
+        fire_concern(state),
+        thirst_concern(state, map),
+        hunger_concern(state),
+        warmth_concern(state, map),
+        tool_concern(state),
+        condition_concern(state),
+        nightfall_concern(state),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    concerns.sort_by(|a, b| b.urgency.total_cmp(&a.urgency));
+    concerns
+}
+
+/// The single most pressing concern as one line, for the web view's strip.
+pub fn top_concern(state: &GameState, map: &WorldMap) -> Option<String> {
+    ranked_concerns(state, map)
+        .into_iter()
+        .next()
+        .map(|c| format!("{} → {}", c.warning, c.action))
+}
+
+/// The most pressing few concerns as a status section, or `None` if
+/// nothing is looming.
+pub fn concerns_section(state: &GameState, map: &WorldMap) -> Option<String> {
+    let mut concerns = ranked_concerns(state, map);
+    if concerns.is_empty() {
+        return None;
+    }
+    concerns.truncate(MAX_CONCERNS);
+
+    let mut text = String::from("**Concerns:**");
+    for (i, c) in concerns.iter().enumerate() {
+        text.push_str(&format!("\n{}. {} → {}", i + 1, c.warning, c.action));
+    }
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{BodyPartKind, Item, Room};
+    use crate::world::{Biome, Position, Weather};
+
+    /// Mid-morning in the cabin, fed, watered and comfortable.
+    fn settled(map: &WorldMap) -> GameState {
+        let mut state = GameState::new(map);
+        let (w, clear) = (&mut state.weather, Weather::Clear);
+        (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+        state.player.room = Some(Room::CabinMain);
+        state.player.position = state.objects.find("cabin").unwrap().position;
+        state.time.hour = 10;
+        state.time.minute = 0;
+        let player = &mut state.player;
+        (player.fullness, player.hydration, player.warmth) = (90.0, 90.0, 50.0);
+        state
+    }
+
+    fn warnings(state: &GameState, map: &WorldMap) -> Vec<String> {
+        ranked_concerns(state, map)
+            .into_iter()
+            .map(|c| c.warning)
+            .collect()
+    }
+
+    #[test]
+    fn each_trouble_on_the_way_raises_its_own_concern() {
+        let map = WorldMap::new();
+        assert!(warnings(&settled(&map), &map).is_empty());
+        let only = |state: &GameState, lead: &str| {
+            let raised = warnings(state, &map);
+            assert_eq!(raised.len(), 1, "{:?}", raised);
+            assert!(raised[0].starts_with(lead), "{:?}", raised);
+        };
+
+        // A low fire at bedtime
+        let mut state = settled(&map);
+        state.time.hour = 22;
+        let fire = &mut state.cabin_state_mut().unwrap().fireplace;
+        (fire.state, fire.bulk_lit, fire.fuel) = (FireState::Burning, true, 5.0);
+        only(&state, "The hearth has");
+
+        let mut state = settled(&map);
+        state.player.hydration = 25.0;
+        only(&state, "Your hydration is at 25; at this rate");
+        let mut state = settled(&map);
+        state.player.fullness = 15.0;
+        only(&state, "Your fullness is at 15 and already wearing");
+
+        // Caught out in a blizzard in the winter woods
+        let mut state = settled(&map);
+        state.player.room = None;
+        state.player.position = map
+            .positions()
+            .find(|p| map.biome_at(p) == Some(Biome::WinterForest))
+            .unwrap();
+        let (w, storm) = (&mut state.weather, Weather::Blizzard);
+        (w.north, w.south, w.east, w.west) = (storm, storm, storm, storm);
+        state.player.warmth = 30.0;
+        only(&state, "You're cooling");
+
+        let mut state = settled(&map);
+        state.player.inventory.add(Item::Axe, 1);
+        state.player.tool_durability.insert(Item::Axe, 2);
+        only(&state, "Your axe is close to breaking (2 left).");
+
+        let mut state = settled(&map);
+        state.player.injure_part(BodyPartKind::LegLeft, 10.0, true);
+        only(&state, "You're bleeding");
+
+        // Late afternoon, a long walk from home
+        let mut state = settled(&map);
+        state.player.room = None;
+        let cabin = state.player.position;
+        state.player.position = Position::new(cabin.row + 8, cabin.col + 8);
+        state.time.hour = 17;
+        only(&state, "Dark falls in");
+    }
+
+    #[test]
+    fn status_lists_only_the_three_most_pressing() {
+        let map = WorldMap::new();
+        let mut state = settled(&map);
+        state.player.hydration = 10.0;
+        state.player.fullness = 10.0;
+        state.player.injure_part(BodyPartKind::ArmLeft, 10.0, true);
+        state.player.inventory.add(Item::Axe, 1);
+        state.player.tool_durability.insert(Item::Axe, 2);
+        assert_eq!(ranked_concerns(&state, &map).len(), 4);
+
+        let section = concerns_section(&state, &map).unwrap();
+        let lines: Vec<&str> = section.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "**Concerns:**");
+        assert!(lines[1].starts_with("1. Your hydration is at 10"));
+        assert!(lines[2].starts_with("2. You're bleeding"));
+        assert!(lines[3].starts_with("3. Your fullness is at 10"));
+        assert!(!section.contains("axe"));
+        assert!(concerns_section(&settled(&map), &map).is_none());
+    }
+}
@@ -1,11 +1,25 @@
-use crate::entity::{Blueprint, BookEntry, Body, BodyPartKind, FireState, Item, Room, Species};
-use crate::persistence::GameState;
-use crate::world::{Biome, Position, TimeOfDay, Weather, WorldMap};
+use crate::entity::{
+    capitalize, recipes_using, Behavior, Blueprint, BookEntry, Body, BodyPartKind, CabinSpot,
+    FireState, FruitStage, Item, ItemCategory, Language, PartCondition, Player, Room, Skill,
+    Species, ToolWear, TreeType, Workspace,
+};
+use super::loot::roll_forage;
+use super::targets::{ambiguity_message, resolve_fixture, Fixture, Resolved, Verb};
+use crate::descriptions::{distance_phrase, AmbientLife};
+use crate::persistence::{
+    Doneness, FishingSpot, GameState, OnTheFire, Practice, CAVE_BEAR_ID, DEATH_NOTE_ID,
+    FIREFLY_JAR_MINUTES, LIT_KINDLING_TICKS, MAX_PAUSED_PROJECTS, RAFT_ID, SIGNAL_DAYS_NEEDED,
+    WORKBENCH_ID,
+};
+use crate::world::{
+    BearState, Biome, CaveBear, Direction, ObjectKind, Position, Raft, Ruin, Seat, SeatKind,
+    TileType, TimeOfDay, Weather, WindStrength, WorldMap, WorldObject, WorldTime,
+};
 use rand::Rng;
 
 pub enum InteractionResult {
     Success(String),
-    Failure(String),
+    Failure(Failure),
     ItemObtained(Item, String),
     ItemLost(Item, String),
     ActionSuccess {
@@ -15,6 +29,78 @@ pub enum InteractionResult {
     },
 }
 
+/// Why an action didn't come off. The player reads the same message either
+/// way; the kind tells the server whether the attempt cost anything.
+pub enum Failure {
+    /// Nothing here answers to what was asked for.
+    InvalidTarget(String),
+    /// Something has to be in hand first.
+    MissingItem { item: Item, message: String },
+    /// The right idea in the wrong place. `hint` says where to go, if known.
+    WrongLocation {
+        message: String,
+        hint: Option<String>,
+    },
+    /// Too worn out to try.
+    Exhausted(String),
+    /// It was tried, and luck went against it. The attempt still took its
+    /// time and effort.
+    Chance {
+        message: String,
+        time_cost: u32,
+        energy_cost: f32,
+    },
+}
+
+impl Failure {
+    /// What the player reads.
+    pub fn into_message(self) -> String {
+        match self {
+            Failure::InvalidTarget(message)
+            | Failure::Exhausted(message)
+            | Failure::MissingItem { message, .. }
+            | Failure::Chance { message, .. } => message,
+            Failure::WrongLocation {
+                message,
+                hint: Some(hint),
+            } => format!("{} {}", message, hint),
+            Failure::WrongLocation { message, .. } => message,
+        }
+    }
+}
+
+impl InteractionResult {
+    pub fn invalid(message: impl Into<String>) -> Self {
+        InteractionResult::Failure(Failure::InvalidTarget(message.into()))
+    }
+
+    pub fn missing(item: Item, message: impl Into<String>) -> Self {
+        InteractionResult::Failure(Failure::MissingItem {
+            item,
+            message: message.into(),
+        })
+    }
+
+    pub fn wrong_place(message: impl Into<String>) -> Self {
+        InteractionResult::Failure(Failure::WrongLocation {
+            message: message.into(),
+            hint: None,
+        })
+    }
+
+    pub fn exhausted(message: impl Into<String>) -> Self {
+        InteractionResult::Failure(Failure::Exhausted(message.into()))
+    }
+
+    pub fn chance(message: impl Into<String>, time_cost: u32, energy_cost: f32) -> Self {
+        InteractionResult::Failure(Failure::Chance {
+            message: message.into(),
+            time_cost,
+            energy_cost,
+        })
+    }
+}
+
 // ... (Duck constants omit for brevity, will include) ...
 const DUCK_GAZE: &[&str] = &[
     "The rubber duck fixes you with a glassy stare.",
@@ -140,6 +226,41 @@ const CAT_REPLIES: &[&str] = &[
     "The cat pretends not to listen, but one ear stays angled toward your voice.",
 ];
 
+const FIRE_REPLIES: &[&str] = &[
+    "The fire pops in reply, sending a spark up into the dark.",
+    "A log settles in the flames with a soft crunch, as if making room for what you said.",
+    "The flames lean toward you for a moment, then straighten.",
+    "The fire hisses over a wet knot, which is almost an answer.",
+];
+
+const CAVE_REPLIES: &[&str] = &[
+    "Your voice echoes off the cave walls and comes back to you smaller, a word at a time.",
+    "The stone gives your words back twice, the second time from somewhere deeper in.",
+    "Water drips somewhere in the dark, keeping time with what you said.",
+];
+
+const OPEN_REPLIES: &[&str] = &[
+    "The open air takes your words and doesn't give them back.",
+    "A breeze carries what you said off somewhere, and the quiet closes behind it.",
+    "Nothing answers, but the saying of it loosens something all the same.",
+];
+
+const SNOW_REPLIES: &[&str] = &[
+    "The snow swallows your words before they reach the trees.",
+    "Your breath hangs where the words were, then drifts apart.",
+    "The hush of the snow folds over your voice like a blanket.",
+];
+
+const MISSING_DUCK: &[&str] = &[
+    "Somewhere, a small yellow shape is missing this.",
+    "It's the sort of thing you'd usually say to the duck.",
+];
+
+/// Mood a conversation the duck or a companion was there for lifts.
+pub const TALK_MOOD: f32 = 1.5;
+/// Mood talking to nobody in particular lifts: less, but not nothing.
+pub const SOLILOQUY_MOOD: f32 = 0.5;
+
 fn random_duck_phrase(rng: &mut impl rand::Rng) -> String {
     use rand::seq::SliceRandom;
     let part_a = DUCK_GAZE
@@ -152,109 +273,227 @@ fn random_duck_phrase(rng: &mut impl rand::Rng) -> String {
 // ... Open/Close/Take/Drop handlers (omitted here to save space if unchanged, but will include needed ones) ...
 // Actually, I need to include them to overwrite the file properly.
 
-pub fn try_open(target: &str, state: &mut GameState) -> InteractionResult {
-    let normalized = target.to_lowercase();
-    if normalized.contains("card") || normalized.contains("case") {
-        if !state.player.inventory.has(&Item::CardCase, 1) {
-            return InteractionResult::Failure(
-                "You need to be holding the card case to open it.".to_string(),
-            );
-        }
-        if state.card_case_open {
-            return InteractionResult::Failure("The card case is already open.".to_string());
-        }
-        state.card_case_open = true;
-        return InteractionResult::Success(
-            "You flip open the card case. A neat stack of cards waits inside."
-                .to_string(),
-        );
+/// Open or close one of the things `open` and `close` understand.
+fn set_fixture_open(fixture: Fixture, state: &mut GameState, open: bool) -> InteractionResult {
+    let already = if open { "open" } else { "closed" };
+    if !fixture.in_scope(state) {
+        let msg = match fixture {
+            Fixture::CardCase => format!(
+                "You need to be holding the card case to {} it.",
+                if open { "open" } else { "close" }
+            ),
+            Fixture::ShedDoor => "You're too far from the wood shed door.".to_string(),
+            Fixture::CabinDoor => "You're too far from the cabin door.".to_string(),
+            _ => "You're too far from the cabin to reach its windows.".to_string(),
+        };
+        return InteractionResult::invalid(msg);
     }
 
-    let cabin_pos = match state.objects.find("cabin") {
-        Some(obj) => obj.position,
-        None => return InteractionResult::Failure("You don't see a cabin to open.".to_string()),
-    };
-    if normalized.contains("door") || normalized.contains("cabin") {
-        let near_cabin = {
-            let room = state.player.room.clone();
-            state.player.position.distance_to(&cabin_pos) <= 1.5
-                || matches!(room, Some(Room::CabinMain))
-        };
-        let Some(cabin) = state.cabin_state_mut() else {
-            return InteractionResult::Failure("The cabin seems missing its details.".to_string());
-        };
-        if cabin.door_open {
-            return InteractionResult::Failure("The door is already open.".to_string());
+    match fixture {
+        Fixture::CardCase => {
+            if !state.player.inventory.has(&Item::CardCase, 1) {
+                return InteractionResult::missing(
+                    Item::CardCase,
+                    format!(
+                        "You need to be holding the card case to {} it.",
+                        if open { "open" } else { "close" }
+                    ),
+                );
+            }
+            if state.card_case_open == open {
+                return InteractionResult::invalid(format!(
+                    "The card case is already {}.",
+                    already
+                ));
+            }
+            state.card_case_open = open;
+            let msg = if open {
+                "You flip open the card case. A neat stack of cards waits inside."
+            } else {
+                "You snap the card case shut, keeping the deck contained."
+            };
+            InteractionResult::Success(msg.to_string())
+        }
+        Fixture::ShedDoor => {
+            let Some(shed) = state.wood_shed_state_mut() else {
+                return InteractionResult::invalid(
+                    "The wood shed seems missing its details.".to_string(),
+                );
+            };
+            if shed.door_open == open {
+                return InteractionResult::invalid(format!(
+                    "The shed door is already {}.",
+                    already
+                ));
+            }
+            shed.door_open = open;
+            let msg = if open {
+                "You lift the wooden latch and the shed door swings outward on leather hinges."
+            } else {
+                "You pull the shed door to and drop the latch. The smell of cut wood settles inside."
+            };
+            InteractionResult::Success(msg.to_string())
         }
-        if !near_cabin {
-            return InteractionResult::Failure("You're too far from the cabin door.".to_string());
+        Fixture::CabinDoor => {
+            let Some(cabin) = state.cabin_state_mut() else {
+                return InteractionResult::invalid(
+                    "The cabin seems missing its details.".to_string(),
+                );
+            };
+            if cabin.door_open == open {
+                return InteractionResult::invalid(format!("The door is already {}.", already));
+            }
+            cabin.door_open = open;
+            let msg = if open {
+                "You grasp the worn iron handle and pull. The door swings open with a soft creak."
+            } else {
+                "You push the door closed. It latches with a satisfying click."
+            };
+            InteractionResult::Success(msg.to_string())
         }
-        cabin.door_open = true;
-        InteractionResult::Success(
-            "You grasp the worn iron handle and pull. The door swings open with a soft creak."
-                .to_string(),
-        )
-    } else {
-        InteractionResult::Failure(format!("You don't see a '{}' to open.", target))
+        Fixture::Shutters => {
+            let Some(cabin) = state.cabin_state_mut() else {
+                return InteractionResult::invalid(
+                    "The cabin seems missing its details.".to_string(),
+                );
+            };
+            // Shutters are "open" when folded back from the glass.
+            if cabin.shutters_closed != open {
+                return InteractionResult::invalid(format!(
+                    "The shutters are already {}.",
+                    already
+                ));
+            }
+            cabin.shutters_closed = !open;
+            let msg = if open {
+                "You fold the wooden shutters back against the walls. Light spills across the floorboards."
+            } else {
+                "You swing the wooden shutters closed and hook them fast. The room dims to a snug, private hush."
+            };
+            InteractionResult::Success(msg.to_string())
+        }
+        Fixture::Windows => {
+            let Some(cabin) = state.cabin_state_mut() else {
+                return InteractionResult::invalid(
+                    "The cabin seems missing its details.".to_string(),
+                );
+            };
+            if cabin.windows_open == open {
+                return InteractionResult::invalid(format!(
+                    "The windows are already {}.",
+                    already
+                ));
+            }
+            cabin.windows_open = open;
+            let msg = if open {
+                "You unlatch the windows and push them open. Outside air drifts through the cabin."
+            } else {
+                "You pull the windows shut and latch them. The room goes still."
+            };
+            InteractionResult::Success(msg.to_string())
+        }
+        _ => InteractionResult::invalid(format!(
+            "The {} isn't something you can {}.",
+            fixture.label(),
+            if open { "open" } else { "close" }
+        )),
     }
 }
 
-pub fn try_close(target: &str, state: &mut GameState) -> InteractionResult {
-    let normalized = target.to_lowercase();
-    if normalized.contains("card") || normalized.contains("case") {
-        if !state.player.inventory.has(&Item::CardCase, 1) {
-            return InteractionResult::Failure(
-                "You need to be holding the card case to close it.".to_string(),
-            );
+pub fn try_open(target: &str, state: &mut GameState) -> InteractionResult {
+    match resolve_fixture(target, state, Verb::Open) {
+        Resolved::Found { fixture, .. } => set_fixture_open(fixture, state, true),
+        Resolved::Ambiguous(options) => {
+            InteractionResult::invalid(ambiguity_message(&options, Verb::Open))
         }
-        if !state.card_case_open {
-            return InteractionResult::Failure("The card case is already closed.".to_string());
+        Resolved::Unknown => {
+            InteractionResult::invalid(format!("You don't see a '{}' to open.", target))
         }
-        state.card_case_open = false;
-        return InteractionResult::Success(
-            "You snap the card case shut, keeping the deck contained."
-                .to_string(),
-        );
     }
+}
 
-    let cabin_pos = match state.objects.find("cabin") {
-        Some(obj) => obj.position,
-        None => return InteractionResult::Failure("You don't see a cabin to close.".to_string()),
-    };
-    if normalized.contains("door") || normalized.contains("cabin") {
-        let near_cabin = {
-            let room = state.player.room.clone();
-            state.player.position.distance_to(&cabin_pos) <= 1.5
-                || matches!(room, Some(Room::CabinMain))
-        };
-        let Some(cabin) = state.cabin_state_mut() else {
-            return InteractionResult::Failure("The cabin seems missing its details.".to_string());
-        };
-        if !cabin.door_open {
-            return InteractionResult::Failure("The door is already closed.".to_string());
+pub fn try_close(target: &str, state: &mut GameState) -> InteractionResult {
+    match resolve_fixture(target, state, Verb::Close) {
+        Resolved::Found { fixture, .. } => set_fixture_open(fixture, state, false),
+        Resolved::Ambiguous(options) => {
+            InteractionResult::invalid(ambiguity_message(&options, Verb::Close))
         }
-        if !near_cabin {
-            return InteractionResult::Failure("You're too far from the cabin door.".to_string());
+        Resolved::Unknown => {
+            InteractionResult::invalid(format!("You don't see a '{}' to close.", target))
         }
-        cabin.door_open = false;
-        InteractionResult::Success(
-            "You push the door closed. It latches with a satisfying click.".to_string(),
-        )
-    } else {
-        InteractionResult::Failure(format!("You don't see a '{}' to close.", target))
     }
 }
 
 pub fn try_take(item_name: &str, state: &mut GameState, map: &mut WorldMap) -> InteractionResult {
-    let item = match Item::from_str(item_name) {
+    let item = match state.resolve_item(item_name) {
         Some(i) => i,
         None => {
-            return InteractionResult::Failure(format!("You don't know what '{}' is.", item_name))
+            return InteractionResult::invalid(format!("You don't know what '{}' is.", item_name))
         }
     };
+    take_one(item, item_name, state, map)
+}
+
+fn take_one(
+    item: Item,
+    item_name: &str,
+    state: &mut GameState,
+    map: &mut WorldMap,
+) -> InteractionResult {
+    if state.player.mounts.has(&item) {
+        if !state.player.inventory.add(item, 1) {
+            return InteractionResult::invalid("Your inventory is too heavy.".to_string());
+        }
+        let perch = state.player.mounts.take(&item).unwrap_or("its perch");
+        return InteractionResult::ItemObtained(
+            item.clone(),
+            format!(
+                "You lift the {} down from {}.",
+                state.display_name(&item),
+                perch
+            ),
+        );
+    }
+
+    if item == Item::Raft && state.player.room.is_none() && state.raft_within_reach() {
+        if state.aboard_raft() {
+            return InteractionResult::invalid(
+                "You can't very well pick up the raft while you're standing on it.".to_string(),
+            );
+        }
+        let laden = state
+            .raft()
+            .and_then(|po| po.object.storage.as_ref())
+            .map(|s| !s.is_empty())
+            .unwrap_or(false);
+        if laden {
+            return InteractionResult::invalid(
+                "There's still cargo lashed to the raft. Take it off before you haul the raft out."
+                    .to_string(),
+            );
+        }
+        if !state.player.inventory.add(Item::Raft, 1) {
+            return InteractionResult::invalid(
+                "The raft is too heavy to carry on top of everything else you've got.".to_string(),
+            );
+        }
+        state.objects.remove(RAFT_ID);
+        return InteractionResult::ItemObtained(
+            Item::Raft,
+            "You haul the raft out of the water, streaming, and hoist it onto your back."
+                .to_string(),
+        );
+    }
 
     let player_room = state.player.room.clone();
 
+    // Whatever is within arm's reach of where the player stands comes first.
+    if let Some(id) = state.drop_surface() {
+        if let Some(result) = take_from_surface(state, id, &item) {
+            return result;
+        }
+    }
+
     match player_room {
         Some(Room::CabinMain) => {
             let mut from_cabin_floor = false;
@@ -268,13 +507,13 @@ pub fn try_take(item_name: &str, state: &mut GameState, map: &mut WorldMap) -> I
                     state.on_player_pickup(&item);
                     return InteractionResult::ItemObtained(
                         item.clone(),
-                        format!("You pick up the {}.", item.name()),
+                        format!("You pick up the {}.", state.display_name(&item)),
                     );
                 } else {
                     if let Some(cabin) = state.cabin_state_mut() {
                         cabin.add_item(item.clone());
                     }
-                    return InteractionResult::Failure("Your inventory is too heavy.".to_string());
+                    return InteractionResult::invalid("Your inventory is too heavy.".to_string());
                 }
             }
 
@@ -283,11 +522,26 @@ pub fn try_take(item_name: &str, state: &mut GameState, map: &mut WorldMap) -> I
                     state.on_player_pickup(&item);
                     return InteractionResult::ItemObtained(
                         item.clone(),
-                        format!("You lift the {} from the table.", item.name()),
+                        format!("You lift the {} from the table.", state.display_name(&item)),
                     );
                 } else {
                     state.add_table_item(item.clone());
-                    return InteractionResult::Failure("Too heavy.".to_string());
+                    return InteractionResult::invalid("Too heavy.".to_string());
+                }
+            }
+
+            if state.take_mantel_item(&item) {
+                if state.player.inventory.add(item, 1) {
+                    state.on_player_pickup(&item);
+                    return InteractionResult::ItemObtained(
+                        item,
+                        format!("You take the {} from the mantelpiece.", state.display_name(&item)),
+                    );
+                } else {
+                    if let Some(mantel) = state.mantel_surface_mut() {
+                        mantel.items.add(item, 1);
+                    }
+                    return InteractionResult::invalid("Your inventory is too heavy.".to_string());
                 }
             }
 
@@ -310,7 +564,13 @@ pub fn try_take(item_name: &str, state: &mut GameState, map: &mut WorldMap) -> I
                     if let Some(cabin) = state.cabin_state_mut() {
                         cabin.add_item(Item::Matchbox);
                     }
-                    return InteractionResult::Failure("Your inventory is too heavy.".to_string());
+                    return InteractionResult::invalid("Your inventory is too heavy.".to_string());
+                }
+            }
+
+            for id in GameState::room_surfaces(&Room::CabinMain) {
+                if let Some(result) = take_from_surface(state, id, &item) {
+                    return result;
                 }
             }
         }
@@ -336,78 +596,101 @@ pub fn try_take(item_name: &str, state: &mut GameState, map: &mut WorldMap) -> I
                         if let Some(wood_shed) = state.wood_shed_state_mut() {
                             wood_shed.axe_on_floor = true;
                         }
-                        return InteractionResult::Failure("Too heavy.".to_string());
+                        return InteractionResult::invalid("Too heavy.".to_string());
                     }
                 }
             }
 
             if item == Item::Log {
-                let mut took_log = false;
-                {
-                    if let Some(wood_shed) = state.wood_shed_state_mut() {
-                        if wood_shed.logs > 0 {
-                            wood_shed.logs -= 1;
-                            took_log = true;
-                        }
-                    }
-                }
-                if took_log {
+                let taken = state.wood_shed_state_mut().and_then(|w| w.take_wood(&Item::Log));
+                if let Some(green) = taken {
                     if state.player.inventory.add(Item::Log, 1) {
                         state.on_player_pickup(&Item::Log);
                         let remaining = state.wood_shed_state().map(|w| w.logs).unwrap_or(0);
-                        return InteractionResult::ItemObtained(
-                            Item::Log,
-                            format!("You heft a heavy log. {} remain.", remaining),
-                        );
+                        let mut message = format!("You heft a heavy log. {} remain.", remaining);
+                        if let Some(ticks) = green {
+                            state.carried_green_wood.add(Item::Log, 1, ticks);
+                            message.push_str(" It's still green and heavy with sap.");
+                        }
+                        return InteractionResult::ItemObtained(Item::Log, message);
                     } else {
                         if let Some(wood_shed) = state.wood_shed_state_mut() {
                             wood_shed.logs += 1;
+                            if let Some(ticks) = green {
+                                wood_shed.green.add(Item::Log, 1, ticks);
+                            }
                         }
-                        return InteractionResult::Failure("Carrying too much.".to_string());
+                        return InteractionResult::invalid("Carrying too much.".to_string());
                     }
                 }
             }
 
             if item == Item::Firewood {
-                let mut took_firewood = false;
-                {
-                    if let Some(wood_shed) = state.wood_shed_state_mut() {
-                        if wood_shed.firewood > 0 {
-                            wood_shed.firewood -= 1;
-                            took_firewood = true;
-                        }
-                    }
-                }
-                if took_firewood {
+                let taken = state
+                    .wood_shed_state_mut()
+                    .and_then(|w| w.take_wood(&Item::Firewood));
+                if let Some(green) = taken {
                     if state.player.inventory.add(Item::Firewood, 1) {
                         state.on_player_pickup(&Item::Firewood);
-                        return InteractionResult::ItemObtained(
-                            Item::Firewood,
-                            "You gather a piece of split firewood.".to_string(),
-                        );
+                        let mut message = "You gather a piece of split firewood.".to_string();
+                        if let Some(ticks) = green {
+                            state.carried_green_wood.add(Item::Firewood, 1, ticks);
+                            message.push_str(" It's still green; it needs more time to dry.");
+                        }
+                        return InteractionResult::ItemObtained(Item::Firewood, message);
                     } else {
                         if let Some(wood_shed) = state.wood_shed_state_mut() {
                             wood_shed.firewood += 1;
+                            if let Some(ticks) = green {
+                                wood_shed.green.add(Item::Firewood, 1, ticks);
+                            }
                         }
-                        return InteractionResult::Failure("Carrying too much.".to_string());
+                        return InteractionResult::invalid("Carrying too much.".to_string());
                     }
                 }
             }
+
+            if let Some(result) = take_from_surface(state, WORKBENCH_ID, &item) {
+                return result;
+            }
         }
         None => {
+            // Outdoors - crafted seats standing here pack back up
+            if let Some(kind) = SeatKind::from_item(&item) {
+                let seat_id = state
+                    .seats_here()
+                    .into_iter()
+                    .find(|po| po.object.as_seat().map(|s| s.kind) == Some(kind))
+                    .map(|po| po.id.clone());
+                if let Some(id) = seat_id {
+                    if !state.player.inventory.add(item.clone(), 1) {
+                        return InteractionResult::invalid(
+                            "Your inventory is too heavy.".to_string(),
+                        );
+                    }
+                    if state.player.seated.as_deref() == Some(id.as_str()) {
+                        state.player.seated = None;
+                    }
+                    state.objects.remove(&id);
+                    return InteractionResult::ItemObtained(
+                        item.clone(),
+                        format!("You pick up the {}.", state.display_name(&item)),
+                    );
+                }
+            }
             // Outdoors - check tile items
-            if let Some((r, c)) = state.player.position.as_usize() {
+            if let Some((r, c)) = map.index_of(&state.player.position) {
                 if let Some(tile) = map.get_tile_mut(r, c) {
                     if tile.items.take(&item) {
                         if state.player.inventory.add(item.clone(), 1) {
                             state.on_player_pickup(&item);
                             return InteractionResult::ItemObtained(
                                 item.clone(),
-                                format!("You pick up the {}.", item.name()),
+                                format!("You pick up the {}.", state.display_name(&item)),
                             );
                         } else {
                             tile.items.add(item.clone(), 1); // Put it back
-                            return InteractionResult::Failure(
+                            return InteractionResult::invalid(
                                 "Your inventory is too heavy.".to_string(),
                             );
                         }
@@ -417,34 +700,118 @@ pub fn try_take(item_name: &str, state: &mut GameState, map: &mut WorldMap) -> I
         }
         _ => {}
     }
-    InteractionResult::Failure(format!(
+    InteractionResult::invalid(format!(
         "You don't see any {} here that you can take.",
         item_name
     ))
 }
 
+/// Lift `item` off the named surface, if it's there. `None` leaves the
+/// search to go on elsewhere.
+fn take_from_surface(
+    state: &mut GameState,
+    id: &str,
+    item: &Item,
+) -> Option<InteractionResult> {
+    if !state.surface_mut(id)?.take_item(item) {
+        return None;
+    }
+    if !state.player.inventory.add(item.clone(), 1) {
+        if let Some(surface) = state.surface_mut(id) {
+            surface.items.add(item.clone(), 1);
+        }
+        return Some(InteractionResult::invalid(
+            "Your inventory is too heavy.".to_string(),
+        ));
+    }
+    state.on_player_pickup(item);
+    let (_, from) = GameState::surface_phrases(id);
+    Some(InteractionResult::ItemObtained(
+        item.clone(),
+        format!("You take the {} {}.", state.display_name(item), from),
+    ))
+}
+
 pub fn try_drop(item_name: &str, state: &mut GameState, map: &mut WorldMap) -> InteractionResult {
-    let item = match Item::from_str(item_name) {
+    let item = match state.resolve_item(item_name) {
         Some(i) => i,
         None => {
-            return InteractionResult::Failure(format!("You don't know what '{}' is.", item_name))
+            return InteractionResult::invalid(format!("You don't know what '{}' is.", item_name))
         }
     };
+    drop_one(item, state, map)
+}
+
+fn drop_one(item: Item, state: &mut GameState, map: &mut WorldMap) -> InteractionResult {
     if !state.player.inventory.has(&item, 1) {
-        return InteractionResult::Failure(format!("You don't have any {}.", item.name()));
+        return InteractionResult::missing(
+            item,
+            format!("You don't have any {}.", state.display_name(&item)),
+        );
+    }
+    // Only the axe and wood have their own places in the shed; the rest
+    // needs room on the workbench.
+    if matches!(state.player.room, Some(Room::WoodShed))
+        && !matches!(item, Item::Axe | Item::Log | Item::Firewood)
+        && state
+            .surface(WORKBENCH_ID)
+            .map(|s| {
+                s.capacity
+                    .map(|c| s.items.total() as usize >= c)
+                    .unwrap_or(false)
+            })
+            .unwrap_or(true)
+    {
+        return InteractionResult::invalid(
+            "The workbench is already crowded; there's nowhere in the shed to set that down."
+                .to_string(),
+        );
     }
     state.player.inventory.remove(&item, 1);
+    if item == Item::Raft {
+        if let Some(perched) = state.player.mounts.raft_prow.take() {
+            state.player.inventory.add(perched, 1);
+        }
+    }
     let dropped_book_id = state.on_player_drop(&item);
+    let mut card_pickup_note = None;
+    let mut placed_note = None;
     match &state.player.room {
         Some(Room::CabinMain) => {
-            if let Some(cabin) = state.cabin_state_mut() {
-                cabin.add_item(item.clone());
+            let surface = state.drop_surface();
+            let placed = surface
+                .and_then(|id| state.surface_mut(id))
+                .map(|s| s.add_item(item.clone()))
+                .unwrap_or(false);
+            let label = state.display_name(&item);
+            if let (true, Some(id)) = (placed, surface) {
+                let (on, _) = GameState::surface_phrases(id);
+                placed_note = Some(format!("You set the {} down {}.", label, on));
+            } else {
+                if let Some(cabin) = state.cabin_state_mut() {
+                    cabin.add_item(item.clone());
+                    // Things left on the floor wear on the room's order.
+                    cabin.tidiness = (cabin.tidiness - 0.5).max(0.0);
+                }
+                if let Some(id) = surface {
+                    let (on, _) = GameState::surface_phrases(id);
+                    placed_note = Some(format!(
+                        "There's no room left {}, so the {} goes on the floor.",
+                        on, label
+                    ));
+                }
             }
             if let Some(id) = dropped_book_id {
                 state.add_cabin_book(id);
             }
         }
         Some(Room::WoodShed) => {
+            // Green wood is what wants stacking under cover, so it goes first.
+            let green = if matches!(item, Item::Log | Item::Firewood) {
+                state.carried_green_wood.take(&item)
+            } else {
+                None
+            };
             if let Some(wood_shed) = state.wood_shed_state_mut() {
                 match &item {
                     Item::Axe => wood_shed.axe_on_floor = true,
@@ -452,56 +819,78 @@ pub fn try_drop(item_name: &str, state: &mut GameState, map: &mut WorldMap) -> I
                     Item::Firewood => wood_shed.firewood += 1,
                     _ => {}
                 }
+                if let Some(ticks) = green {
+                    wood_shed.green.add(item.clone(), 1, ticks);
+                }
+            }
+            if !matches!(item, Item::Axe | Item::Log | Item::Firewood) {
+                if let Some(bench) = state.surface_mut(WORKBENCH_ID) {
+                    bench.add_item(item.clone());
+                }
+                if let Some(id) = dropped_book_id {
+                    state.add_cabin_book(id);
+                }
+                placed_note = Some(format!(
+                    "You set the {} down on the workbench.",
+                    state.display_name(&item)
+                ));
             }
         }
         None => {
-            if let Some((r, c)) = state.player.position.as_usize() {
+            // A flame dropped on dry ground may not stay small
+            if item == Item::LitKindling {
+                let pos = state.player.position;
+                let message = if state.try_start_wildfire(map, pos, &mut rand::thread_rng()) {
+                    "You drop the burning kindling. The dry litter catches at once, and flame runs out through the undergrowth."
+                } else {
+                    "You drop the burning kindling and it gutters out on the ground."
+                };
+                return InteractionResult::ItemLost(item, message.to_string());
+            }
+            // Crafted seats stand up as furniture rather than lying on the ground.
+            if let Some(kind) = SeatKind::from_item(&item) {
+                let pos = state.player.position;
+                state.last_seat_id += 1;
+                let id = format!("seat-{}", state.last_seat_id);
+                state.objects.add(
+                    id,
+                    pos,
+                    WorldObject::new(ObjectKind::Seat(Seat { kind, room: None })),
+                );
+                return InteractionResult::ItemLost(
+                    item.clone(),
+                    format!(
+                        "You set the {} down on level ground. It would make a fine seat.",
+                        state.display_name(&item)
+                    ),
+                );
+            }
+            if let Some((r, c)) = map.index_of(&state.player.position) {
                 if let Some(tile) = map.get_tile_mut(r, c) {
                     if item == Item::CardCase {
                         // Drop the card case itself on this tile
                         tile.items.add(Item::CardCase, 1);
 
                         // Scatter any cards currently inside the case around this tile
-                        let cards_to_scatter = state.card_case_cards_inside.min(52);
-                        state.card_case_cards_inside = 0;
-                        state.card_case_open = false;
-
-                        if cards_to_scatter > 0 {
-                            let pos = state.player.position;
-                            let mut rng = rand::thread_rng();
-                            let mut positions = Vec::new();
-                            for dr in -1..=1 {
-                                for dc in -1..=1 {
-                                    let p = Position::new(pos.row + dr, pos.col + dc);
-                                    if let Some((rr, cc)) = p.as_usize() {
-                                        positions.push((rr, cc));
-                                    }
-                                }
-                            }
-                            if !positions.is_empty() {
-                                for _ in 0..cards_to_scatter {
-                                    let &(rr, cc) =
-                                        positions.get(rng.gen_range(0..positions.len())).unwrap();
-                                    if let Some(t) = map.get_tile_mut(rr, cc) {
-                                        t.items.add(Item::PlayingCard, 1);
-                                    }
-                                }
-                            }
+                        let pos = state.player.position;
+                        let at_feet = scatter_card_case(state, map, pos);
+                        if at_feet > 0 {
+                            card_pickup_note =
+                                state.land_at_feet(map, &[(Item::PlayingCard, at_feet)], false);
                         }
-
                     } else {
                         tile.items.add(item.clone(), 1);
                     }
                 } else {
                     // Failed to place, return item
                     state.player.inventory.add(item.clone(), 1);
-                    return InteractionResult::Failure(
+                    return InteractionResult::invalid(
                         "You fumble and fail to set that down here.".to_string(),
                     );
                 }
             } else {
                 state.player.inventory.add(item.clone(), 1);
-                return InteractionResult::Failure(
+                return InteractionResult::invalid(
                     "You fumble and fail to set that down here.".to_string(),
                 );
             }
@@ -509,1900 +898,6622 @@ pub fn try_drop(item_name: &str, state: &mut GameState, map: &mut WorldMap) -> I
         _ => {}
     }
     if item == Item::CardCase {
-        let first_scatter = !state.card_scatter_achievement;
-        if first_scatter {
-            state.card_scatter_achievement = true;
-        }
         let mut message =
             "You hurl the card case; cards explode into a chaotic ring around you."
                 .to_string();
-        if first_scatter {
+        note_card_scatter(state, &mut message);
+        if let Some(note) = card_pickup_note {
             message.push(' ');
-            message.push_str(
-                "(Achievement unlocked: 52 Pickup. Sometimes you have to let things fly.)",
-            );
+            message.push_str(&note);
         }
         InteractionResult::ItemLost(item.clone(), message)
+    } else if let Some(note) = placed_note {
+        InteractionResult::ItemLost(item.clone(), note)
     } else {
-        InteractionResult::ItemLost(item.clone(), format!("You set down the {}.", item.name()))
+        let label = state.display_name(&item);
+        InteractionResult::ItemLost(item.clone(), format!("You set down the {}.", label))
     }
 }
 
-pub fn examine(target: &str, state: &GameState) -> String {
-    let normalized = target.to_lowercase();
-    let player = &state.player;
-    let player_pos = player.position;
+/// What an "all" phrase reaches for: everything, one kind of item, or a
+/// whole category.
+enum Selection {
+    Everything,
+    One(Item),
+    Category(ItemCategory),
+}
 
-    // Check for active project
-    if normalized.contains("blueprint") || normalized.contains("project") {
-        if let Some(bp) = &player.active_project {
-            return bp.status_description();
-        } else {
-            let mut parts = Vec::new();
-            let known = state.known_blueprint_names();
-            if !known.is_empty() {
-                parts.push(format!("Known blueprints: {}", known.join(", ")));
-            }
-            let locked = state.locked_blueprint_hints();
-            if !locked.is_empty() {
-                parts.push(format!("Locked: {}", locked.join(" | ")));
-            }
-            let tail = if parts.is_empty() {
-                String::new()
-            } else {
-                format!(" {}", parts.join(" "))
-            };
-            return format!(
-                "You don't have any active blueprint. Use 'create [item]' to start one.{}",
-                tail
-            );
+impl Selection {
+    /// Read "all", "all firewood", "all logs" or "all fuel"; the leading
+    /// "all" is optional.
+    fn parse(state: &GameState, phrase: &str) -> Result<Selection, InteractionResult> {
+        let phrase = phrase.trim();
+        let rest = match phrase.split_once(char::is_whitespace) {
+            Some((first, rest)) if first.eq_ignore_ascii_case("all") => rest.trim(),
+            _ if phrase.eq_ignore_ascii_case("all") => "",
+            _ => phrase,
+        };
+        if rest.is_empty() || matches!(rest, "everything" | "items" | "things") {
+            return Ok(Selection::Everything);
+        }
+        if let Some(category) = ItemCategory::parse(rest) {
+            return Ok(Selection::Category(category));
         }
+        resolve_plural(state, rest)
+            .map(Selection::One)
+            .ok_or_else(|| InteractionResult::invalid(format!("You don't know what '{}' is.", rest)))
     }
 
-    if normalized.contains("book") || normalized.contains("note") || normalized.contains("책") {
-        if let Some(book) = state.accessible_book(&normalized) {
-            let page_info = if book.pages.is_empty() {
-                "no pages yet".to_string()
-            } else {
-                format!("{} page(s)", book.pages.len())
-            };
-            return format!("Book [{}]: {} ({})", book.id, book.title, page_info);
+    /// Items with a mind of their own (a scattering card case, the raft) only
+    /// move when asked for by name.
+    fn includes(&self, item: &Item) -> bool {
+        match self {
+            Selection::One(wanted) => wanted == item,
+            _ if matches!(item, Item::CardCase | Item::Raft) => false,
+            Selection::Everything => true,
+            Selection::Category(category) => item.category() == *category,
         }
     }
 
-    for (item, _) in state.player.inventory.list() {
-        if item.name().to_lowercase().contains(&normalized) {
-            return item.description().to_string();
+    fn label(&self, state: &GameState) -> String {
+        match self {
+            Selection::Everything => "anything".to_string(),
+            Selection::One(item) => format!("any {}", state.display_name(item)),
+            Selection::Category(category) => format!("any {}", category.name()),
         }
     }
+}
+
+/// Whether an item phrase asks for a whole armful: "all", "all logs".
+pub fn is_all_phrase(phrase: &str) -> bool {
+    let phrase = phrase.trim().to_lowercase();
+    phrase == "all" || phrase == "everything" || phrase.starts_with("all ")
+}
+
+/// Everything the player could pick up from where they stand, with counts.
+fn takeable_here(state: &GameState, map: &WorldMap) -> Vec<(Item, u32)> {
+    let mut found: Vec<(Item, u32)> = Vec::new();
+    let mut note = |item: Item, qty: u32| {
+        match found.iter_mut().find(|(i, _)| *i == item) {
+            Some((_, n)) => *n += qty,
+            None if qty > 0 => found.push((item, qty)),
+            None => {}
+        }
+    };
     match &state.player.room {
         Some(Room::CabinMain) => {
-            if normalized.contains("fire") || normalized.contains("hearth") {
-                if let Some(cabin) = state.cabin_state() {
-                    return cabin.fireplace.state.description().to_string();
-                }
-            }
-            if normalized.contains("table") {
-                let items = state.table_item_names();
-                return if items.is_empty() {
-                    "A sturdy wooden table, surface clear.".to_string()
-                } else {
-                    format!("A sturdy wooden table, holding: {}.", items.join(", "))
-                };
+            let loose = match state.drop_surface() {
+                Some(id) => state.surface(id).map(|s| s.items.items.clone()),
+                None => state.cabin_state().map(|c| c.items.items.clone()),
+            };
+            for (item, qty) in loose.unwrap_or_default() {
+                note(item, qty);
             }
-            // ... (other examine logic)
         }
-        _ => {}
-    }
-
-    // Examine nearby wildlife (living animals)
-    {
-        let mut same_tile_indices: Vec<usize> = Vec::new();
-        let mut best_idx: Option<usize> = None;
-        let mut best_dist = f32::MAX;
-        for (idx, w) in state.wildlife.iter().enumerate() {
-            let species_name = w.species.name().to_lowercase();
-            if !species_name.contains(&normalized)
-                && !normalized.contains(&species_name)
-                && !normalized.contains("animal")
-            {
-                continue;
-            }
-            if w.position == player_pos {
-                same_tile_indices.push(idx);
+        Some(Room::WoodShed) => {
+            if let Some(shed) = state.wood_shed_state() {
+                note(Item::Log, shed.logs);
+                note(Item::Firewood, shed.firewood);
+                if shed.axe_on_floor {
+                    note(Item::Axe, 1);
+                }
             }
-            let dist = player_pos.distance_to(&w.position);
-            if dist <= 6.0 && dist < best_dist {
-                best_dist = dist;
-                best_idx = Some(idx);
+            let bench = state.surface(WORKBENCH_ID).map(|s| s.items.items.clone());
+            for (item, qty) in bench.unwrap_or_default() {
+                note(item, qty);
             }
         }
-
-        if !same_tile_indices.is_empty() {
-            let mut lines = Vec::new();
-            if same_tile_indices.len() == 1 {
-                let w = &state.wildlife[same_tile_indices[0]];
-                let body = &w.body;
-                let overall = (body.overall_health_ratio() * 100.0).round();
-                lines.push(format!(
-                    "You study {} from very close.",
-                    w.display_name()
-                ));
-                lines.push(format!("Overall condition: {:.0}%.", overall));
-
-                for part in &body.parts {
-                    let name = match part.kind {
-                        BodyPartKind::Head => "head",
-                        BodyPartKind::Torso => "torso",
-                        BodyPartKind::ArmLeft => "left arm",
-                        BodyPartKind::ArmRight => "right arm",
-                        BodyPartKind::LegLeft => "left leg",
-                        BodyPartKind::LegRight => "right leg",
-                        BodyPartKind::FrontLeftLeg => "front left leg",
-                        BodyPartKind::FrontRightLeg => "front right leg",
-                        BodyPartKind::BackLeftLeg => "back left leg",
-                        BodyPartKind::BackRightLeg => "back right leg",
-                        BodyPartKind::Tail => "tail",
-                    };
-                    let ratio = (part.ratio() * 100.0).round();
-                    let tag = if part.vital {
-                        " (vital)"
-                    } else if part.movement {
-                        " (movement)"
-                    } else if part.manipulation {
-                        " (grip)"
-                    } else {
-                        ""
-                    };
-                    lines.push(format!("- {}: {:.0}%{}", name, ratio, tag));
-                }
-
-                return lines.join("\n");
-            } else {
-                lines.push(
-                    "You study the animals sharing this patch of ground.".to_string(),
-                );
-                for idx in same_tile_indices {
-                    let w = &state.wildlife[idx];
-                    let body = &w.body;
-                    let overall = (body.overall_health_ratio() * 100.0).round();
-                    lines.push(String::new());
-                    lines.push(format!("{}:", w.display_name()));
-                    lines.push(format!("  Overall condition: {:.0}%.", overall));
-
-                    for part in &body.parts {
-                        let name = match part.kind {
-                            BodyPartKind::Head => "head",
-                            BodyPartKind::Torso => "torso",
-                            BodyPartKind::ArmLeft => "left arm",
-                            BodyPartKind::ArmRight => "right arm",
-                            BodyPartKind::LegLeft => "left leg",
-                            BodyPartKind::LegRight => "right leg",
-                            BodyPartKind::FrontLeftLeg => "front left leg",
-                            BodyPartKind::FrontRightLeg => "front right leg",
-                            BodyPartKind::BackLeftLeg => "back left leg",
-                            BodyPartKind::BackRightLeg => "back right leg",
-                            BodyPartKind::Tail => "tail",
-                        };
-                        let ratio = (part.ratio() * 100.0).round();
-                        let tag = if part.vital {
-                            " (vital)"
-                        } else if part.movement {
-                            " (movement)"
-                        } else if part.manipulation {
-                            " (grip)"
-                        } else {
-                            ""
-                        };
-                        lines.push(format!("- {}: {:.0}%{}", name, ratio, tag));
-                    }
+        Some(Room::CabinTerrace) => {}
+        None => {
+            if let Some(tile) = map.tile_at(&state.player.position) {
+                for (item, qty) in &tile.items.items {
+                    note(*item, *qty);
                 }
-
-                return lines.join("\n");
             }
         }
+    }
+    found
+}
 
-        if let Some(idx) = best_idx {
-            let w = &state.wildlife[idx];
-            let mut lines = Vec::new();
-            let body = &w.body;
-            let overall = (body.overall_health_ratio() * 100.0).round();
-            lines.push(format!(
-                "You study {} from nearby.",
-                w.display_name()
-            ));
-            lines.push(format!("Overall condition: {:.0}%.", overall));
+fn tally(moved: &mut Vec<(Item, u32)>, item: Item) {
+    match moved.iter_mut().find(|(i, _)| *i == item) {
+        Some((_, n)) => *n += 1,
+        None => moved.push((item, 1)),
+    }
+}
 
-            for part in &body.parts {
-                let name = match part.kind {
-                    BodyPartKind::Head => "head",
-                    BodyPartKind::Torso => "torso",
-                    BodyPartKind::ArmLeft => "left arm",
-                    BodyPartKind::ArmRight => "right arm",
-                    BodyPartKind::LegLeft => "left leg",
-                    BodyPartKind::LegRight => "right leg",
-                    BodyPartKind::FrontLeftLeg => "front left leg",
-                    BodyPartKind::FrontRightLeg => "front right leg",
-                    BodyPartKind::BackLeftLeg => "back left leg",
-                    BodyPartKind::BackRightLeg => "back right leg",
-                    BodyPartKind::Tail => "tail",
-                };
-                let ratio = (part.ratio() * 100.0).round();
-                let tag = if part.vital {
-                    " (vital)"
-                } else if part.movement {
-                    " (movement)"
-                } else if part.manipulation {
-                    " (grip)"
-                } else {
-                    ""
-                };
-                lines.push(format!("- {}: {:.0}%{}", name, ratio, tag));
+/// Sum up a bulk move as one action: a single message, with the time and
+/// effort of the whole armful. A stop partway is reported after what did move.
+fn bulk_result(
+    state: &GameState,
+    moved: &[(Item, u32)],
+    verb: &str,
+    destination: &str,
+    stopped: Option<Failure>,
+) -> InteractionResult {
+    if moved.is_empty() {
+        return match stopped {
+            Some(failure) => InteractionResult::Failure(failure),
+            None => InteractionResult::invalid("There was nothing to move.".to_string()),
+        };
+    }
+    let count: u32 = moved.iter().map(|(_, n)| n).sum();
+    let weight: f32 = moved.iter().map(|(i, n)| i.weight() * *n as f32).sum();
+    let list = moved
+        .iter()
+        .map(|(item, n)| {
+            let name = state.display_name(item);
+            if *n > 1 {
+                format!("{} x{}", name, n)
+            } else {
+                name
             }
-
-            return lines.join("\n");
-        }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut message = format!("You {} {}{}.", verb, list, destination);
+    if let Some(failure) = stopped {
+        message.push_str(&format!(" Then you have to stop: {}", failure.into_message()));
+    }
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: count.div_ceil(5),
+        energy_cost: (weight * 0.05).min(10.0),
     }
+}
 
-    // Examine corpse at feet (with stored body, if any)
-    {
-        for po in state.objects.objects_at(&player_pos) {
-            if let crate::world::ObjectKind::Corpse(ref corpse) = po.object.kind {
-                let mut lines = Vec::new();
-                lines.push(format!(
-                    "You examine the {} carcass in detail.",
-                    corpse.species.name()
-                ));
-                lines.push(format!("Freshness: {} (higher means older).", corpse.freshness));
+/// `take all`, `take all firewood`, `take all fuel`: pick up everything that
+/// matches from the tile, the surface within reach, or the shed.
+pub fn try_take_all(phrase: &str, state: &mut GameState, map: &mut WorldMap) -> InteractionResult {
+    let selection = match Selection::parse(state, phrase) {
+        Ok(s) => s,
+        Err(result) => return result,
+    };
+    let wanted: Vec<(Item, u32)> = takeable_here(state, map)
+        .into_iter()
+        .filter(|(item, _)| selection.includes(item))
+        .collect();
+    if wanted.is_empty() {
+        return InteractionResult::invalid(format!(
+            "You don't see {} here that you can take.",
+            selection.label(state)
+        ));
+    }
 
-                if let Some(ref body) = corpse.body {
-                    let overall = (body.overall_health_ratio() * 100.0).round();
-                    lines.push(format!(
-                        "Overall condition at death: {:.0}% of original.",
-                        overall
-                    ));
-                    for part in &body.parts {
-                        let name = match part.kind {
-                            BodyPartKind::Head => "head",
-                            BodyPartKind::Torso => "torso",
-                            BodyPartKind::ArmLeft => "left arm",
-                            BodyPartKind::ArmRight => "right arm",
-                            BodyPartKind::LegLeft => "left leg",
-                            BodyPartKind::LegRight => "right leg",
-                            BodyPartKind::FrontLeftLeg => "front left leg",
-                            BodyPartKind::FrontRightLeg => "front right leg",
-                            BodyPartKind::BackLeftLeg => "back left leg",
-                            BodyPartKind::BackRightLeg => "back right leg",
-                            BodyPartKind::Tail => "tail",
-                        };
-                        let ratio = (part.ratio() * 100.0).round();
-                        let tag = if part.vital {
-                            " (vital)"
-                        } else if part.movement {
-                            " (movement)"
-                        } else if part.manipulation {
-                            " (grip)"
-                        } else {
-                            ""
-                        };
-                        lines.push(format!("- {}: {:.0}%{}", name, ratio, tag));
-                    }
-                } else {
-                    lines.push("You see no obvious sign of what killed it; only stillness.".to_string());
+    let mut moved = Vec::new();
+    let mut stopped = None;
+    'items: for (item, qty) in wanted {
+        let name = item.name();
+        for _ in 0..qty {
+            match take_one(item, name, state, map) {
+                InteractionResult::ItemObtained(got, _) => tally(&mut moved, got),
+                InteractionResult::Failure(failure) => {
+                    stopped = Some(failure);
+                    break 'items;
                 }
-
-                return lines.join("\n");
+                _ => break,
             }
         }
     }
+    bulk_result(state, &moved, "gather up", "", stopped)
+}
 
-    // Self examine
-    if normalized.contains("self") || normalized == "me" {
-        return state.player.status_summary();
+/// `drop all`, `drop all firewood`: set down everything that matches, as one
+/// action.
+pub fn try_drop_all(phrase: &str, state: &mut GameState, map: &mut WorldMap) -> InteractionResult {
+    let destination = match state.player.room {
+        Some(Room::CabinMain) => " in the cabin",
+        Some(Room::WoodShed) => " in the shed",
+        _ => "",
+    };
+    unload(phrase, "set down", destination, state, map)
+}
+
+/// `store all <category>`: unload the pack into the cabin or the shed.
+pub fn try_store(phrase: &str, state: &mut GameState, map: &mut WorldMap) -> InteractionResult {
+    let destination = match state.player.room {
+        Some(Room::CabinMain) => " into the cabin",
+        Some(Room::WoodShed) => " into the shed",
+        _ => {
+            return InteractionResult::Failure(Failure::WrongLocation {
+                message: "There's nowhere to store things out here.".to_string(),
+                hint: Some("Step into the cabin or the wood shed first.".to_string()),
+            })
+        }
+    };
+    unload(phrase, "unload", destination, state, map)
+}
+
+/// Split "log from raft" or "logs into the raft" into the item part and the
+/// id of the object with storage it names, if one is at hand.
+fn storage_phrase(phrase: &str, joins: &[&str], state: &GameState) -> Option<(String, String)> {
+    let lower = phrase.trim().to_lowercase();
+    joins.iter().find_map(|join| {
+        let (items, target) = lower.split_once(join)?;
+        let id = state.storage_at_hand(target)?;
+        Some((items.trim().to_string(), id))
+    })
+}
+
+/// How many of a phrase's items to move: a leading count, every one for
+/// "all", otherwise just the one.
+fn phrase_limit(phrase: &str) -> (u32, &str) {
+    if is_all_phrase(phrase) {
+        return (u32::MAX, phrase);
     }
-    format!("You don't see anything special about '{}'.", target)
+    let (count, rest) = split_quantity(phrase);
+    (count.unwrap_or(1), rest)
 }
 
-pub fn talk_to_rubber_duck(
-    message: Option<&str>,
-    state: &GameState,
-    duck_name: &str,
-) -> InteractionResult {
-    let holding_duck = state.player.inventory.has(&Item::RubberDuck, 1);
-    let duck_on_table = state
-        .table_surface()
-        .map(|s| s.items.contains(&Item::RubberDuck))
-        .unwrap_or(false);
-    let duck_in_cabin = state
-        .cabin_state()
-        .map(|c| c.items.contains(&Item::RubberDuck) || c.table_items.contains(&Item::RubberDuck))
+/// Take whatever is grilling off the cabin fire, done or not. `None` when
+/// the phrase isn't about the fire or what's on it.
+pub fn try_take_off_fire(phrase: &str, state: &mut GameState) -> Option<InteractionResult> {
+    let food = state.on_the_fire.as_ref()?;
+    if !matches!(state.player.room, Some(Room::CabinMain)) {
+        return None;
+    }
+    let lower = phrase.to_lowercase();
+    let names_fire = ["fire", "hearth", "grate"]
+        .iter()
+        .any(|w| lower.contains(w));
+    let names_food = state
+        .resolve_item(phrase.trim())
+        .map(|i| i == food.raw || i == food.dish)
         .unwrap_or(false);
-    let in_cabin = matches!(state.player.room, Some(Room::CabinMain));
+    if !names_fire && !names_food {
+        return None;
+    }
 
-    if !(holding_duck || (in_cabin && (duck_in_cabin || duck_on_table))) {
-        return InteractionResult::Failure("You need to be near the rubber duck.".to_string());
+    let food = state.on_the_fire.take()?;
+    let doneness = food.doneness();
+    let mut outcome = CookingOutcome::roll(state.player.skills.get(Skill::Cooking));
+    outcome.perfect = doneness == Doneness::Perfect;
+    let portions = food.portions + u32::from(outcome.extra_portion);
+    state.player.step_to(CabinSpot::Hearth);
+    state.record_dish(food.dish, 10);
+    if food.raw == Item::RawMeat {
+        state.note_kill_meat_cooked();
     }
-    let mut rng = rand::thread_rng();
-    let opener = match message {
-        Some(msg) if !msg.trim().is_empty() => format!("You: \"{}\"\n", msg.trim()),
-        _ => "You address the rubber duck softly.\n".to_string(),
+
+    let label = food.label();
+    let mut message = match doneness {
+        Doneness::Undercooked => format!(
+            "You pull the {} off the fire too soon; it's still underdone in the middle.",
+            label
+        ),
+        Doneness::Perfect => format!(
+            "You lift the {} off the fire at just the right moment.",
+            label
+        ),
+        Doneness::Charred => format!(
+            "You pull the {} off the fire, blackened at the edges. Still edible, if bitter.",
+            label
+        ),
     };
-    let middle = "The rubber duck seems lost in thought...";
-    let contemplation = random_duck_phrase(&mut rng);
-    let closer = format!("{}: ...", duck_name);
-    InteractionResult::Success(format!(
-        "{}{}\n{}\n{}",
-        opener, middle, contemplation, closer
-    ))
+    outcome.apply(state, food.dish, &mut message);
+    if state.player.inventory.add(food.dish, portions) {
+        state.cooked_portions.add(food.dish, doneness, portions);
+    } else {
+        if let Some(cabin) = state.cabin_state_mut() {
+            cabin.items.add(food.dish, portions);
+        }
+        message.push_str(" Your pack is too full, so you set it down by the hearth.");
+    }
+    Some(InteractionResult::ActionSuccess {
+        message,
+        time_cost: 0,
+        energy_cost: 0.5,
+    })
 }
 
-pub fn talk_to_animal_companion(
-    message: Option<&str>,
-    state: &GameState,
-) -> Option<InteractionResult> {
-    let player_pos = state.player.position;
-
-    let mut nearest_index: Option<usize> = None;
-    let mut nearest_distance = f32::MAX;
+/// `take log from raft`, `take all from raft`: unload a placed object's own
+/// storage. `None` when the phrase doesn't name one within reach.
+pub fn try_take_from(phrase: &str, state: &mut GameState) -> Option<InteractionResult> {
+    let (items, id) = storage_phrase(phrase, &[" from ", " off ", " out of "], state)?;
+    let (limit, items) = phrase_limit(&items);
+    let selection = match Selection::parse(state, items) {
+        Ok(s) => s,
+        Err(result) => return Some(result),
+    };
+    let po = state.objects.find(&id)?;
+    let name = po.object.display_name();
+    let stored: Vec<(Item, u32)> = po
+        .object
+        .storage
+        .as_ref()
+        .map(|s| {
+            s.items
+                .items
+                .iter()
+                .filter(|(item, qty)| *qty > 0 && selection.includes(item))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+    if stored.is_empty() {
+        return Some(InteractionResult::invalid(format!(
+            "There isn't {} in the {}.",
+            selection.label(state),
+            name
+        )));
+    }
 
-    for (idx, w) in state.wildlife.iter().enumerate() {
-        if !w.tamed {
-            continue;
-        }
-        if !matches!(w.species, Species::Dog | Species::Cat) {
-            continue;
-        }
-        let dist = player_pos.distance_to(&w.position);
-        if dist <= 4.0 && dist < nearest_distance {
-            nearest_distance = dist;
-            nearest_index = Some(idx);
+    let mut moved = Vec::new();
+    let mut stopped = None;
+    let mut left = limit;
+    'items: for (item, qty) in stored {
+        for _ in 0..qty {
+            if left == 0 {
+                break 'items;
+            }
+            if !state.player.inventory.add(item, 1) {
+                stopped = Some(Failure::InvalidTarget(
+                    "Your inventory is too heavy.".to_string(),
+                ));
+                break 'items;
+            }
+            if let Some(storage) = state
+                .objects
+                .find_mut(&id)
+                .and_then(|po| po.object.storage_mut())
+            {
+                storage.take(&item);
+            }
+            state.on_player_pickup(&item);
+            tally(&mut moved, item);
+            left -= 1;
         }
     }
+    Some(bulk_result(
+        state,
+        &moved,
+        "take",
+        &format!(" from the {}", name),
+        stopped,
+    ))
+}
 
-    let idx = nearest_index?;
-    let companion = &state.wildlife[idx];
-    let species_name = companion.species.name();
-    let display_name = companion.display_name();
-
-    let mut rng = rand::thread_rng();
-    let opener = match message {
-        Some(msg) if !msg.trim().is_empty() => {
-            format!("You, to your {}: \"{}\"\n", display_name, msg.trim())
-        }
-        _ => format!("You speak quietly to your {}.\n", display_name),
-    };
+/// `drop log in raft`, `drop all logs onto the raft`: stow things in a placed
+/// object's own storage. `None` when the phrase doesn't name one within reach.
+pub fn try_put_in(phrase: &str, state: &mut GameState) -> Option<InteractionResult> {
+    let (items, id) = storage_phrase(
+        phrase,
+        &[" into ", " in ", " onto ", " on ", " aboard "],
+        state,
+    )?;
+    Some(put_in_storage(&items, &id, state))
+}
 
-    let reply_pool = match companion.species {
-        Species::Dog => DOG_REPLIES,
-        Species::Cat => CAT_REPLIES,
-        _ => DOG_REPLIES,
-    };
-    let reply = if reply_pool.is_empty() {
-        "It stays close, in its own thoughtful way."
-    } else {
-        let idx = rng.gen_range(0..reply_pool.len());
-        reply_pool[idx]
+fn put_in_storage(phrase: &str, id: &str, state: &mut GameState) -> InteractionResult {
+    let (limit, items) = phrase_limit(phrase);
+    let selection = match Selection::parse(state, items) {
+        Ok(s) => s,
+        Err(result) => return result,
     };
-
-    let closer = match companion.species {
-        Species::Dog => "The dog falls into step beside you, as if the conversation is enough.",
-        Species::Cat => "The cat settles where it can keep you in sight, gaze soft and steady.",
-        _ => "It remains near, a quiet presence.",
+    let Some(po) = state.objects.find(id) else {
+        return InteractionResult::invalid("There's nothing here to put that in.".to_string());
     };
+    let name = po.object.display_name();
+    let carried: Vec<(Item, u32)> = state
+        .player
+        .inventory
+        .slots
+        .iter()
+        .filter(|slot| slot.quantity > 0 && selection.includes(&slot.item))
+        // A raft can't very well carry itself
+        .filter(|slot| slot.item != Item::Raft)
+        .map(|slot| (slot.item, slot.quantity))
+        .collect();
+    if carried.is_empty() {
+        return InteractionResult::invalid(format!(
+            "You aren't carrying {} to put in the {}.",
+            selection.label(state),
+            name
+        ));
+    }
 
-    Some(InteractionResult::Success(format!(
-        "{}{}\n{}",
-        opener, reply, closer
-    )))
+    let mut moved = Vec::new();
+    let mut stopped = None;
+    let mut left = limit;
+    'items: for (item, qty) in carried {
+        for _ in 0..qty {
+            if left == 0 {
+                break 'items;
+            }
+            let Some(storage) = state
+                .objects
+                .find_mut(id)
+                .and_then(|po| po.object.storage_mut())
+            else {
+                break 'items;
+            };
+            if storage.put(item, 1) == 0 {
+                stopped = Some(Failure::InvalidTarget(format!(
+                    "The {} is full. Take something out first.",
+                    name
+                )));
+                break 'items;
+            }
+            state.player.inventory.remove(&item, 1);
+            state.on_player_drop(&item);
+            tally(&mut moved, item);
+            left -= 1;
+        }
+    }
+    bulk_result(state, &moved, "stow", &format!(" in the {}", name), stopped)
 }
 
-// --- NEW UNIVERSAL USE HANDLER ---
+/// What a placed object's own storage holds, for `examine`.
+fn storage_line(state: &GameState, id: &str) -> Option<String> {
+    let po = state.objects.find(id)?;
+    let name = po.object.display_name();
+    let Some(storage) = po.object.storage.as_ref().filter(|s| !s.is_empty()) else {
+        let capacity = po
+            .object
+            .kind
+            .default_storage()
+            .map(|s| s.capacity)
+            .unwrap_or(0);
+        return Some(format!(
+            "The {} is empty; it could hold {} things.",
+            name, capacity
+        ));
+    };
+    let list = storage
+        .items
+        .items
+        .iter()
+        .filter(|(_, qty)| *qty > 0)
+        .map(|(item, qty)| format!("{} x{}", state.display_name(item), qty))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(format!(
+        "In the {}: {} (room for {} more).",
+        name,
+        list,
+        storage.room()
+    ))
+}
 
-pub fn try_use(
-    item_name: &str,
-    target_name: Option<&str>,
+fn unload(
+    phrase: &str,
+    verb: &str,
+    destination: &str,
     state: &mut GameState,
     map: &mut WorldMap,
 ) -> InteractionResult {
-    let item_query = item_name.trim();
-    let item_query_lower = item_query.to_lowercase();
-    let using_hands = matches!(
-        item_query_lower.as_str(),
-        "hand" | "hands" | "fist" | "fists" | "bare hands" | "bare-hands" | "barehand"
-    );
-
-    let target_normalized = target_name.as_ref().map(|s| s.to_lowercase());
-    let target_str = target_normalized.as_deref();
+    let selection = match Selection::parse(state, phrase) {
+        Ok(s) => s,
+        Err(result) => return result,
+    };
+    let carried: Vec<(Item, u32)> = state
+        .player
+        .inventory
+        .slots
+        .iter()
+        .filter(|slot| slot.quantity > 0 && selection.includes(&slot.item))
+        .map(|slot| (slot.item, slot.quantity))
+        .collect();
+    if carried.is_empty() {
+        return InteractionResult::invalid(format!(
+            "You aren't carrying {} to put down.",
+            selection.label(state)
+        ));
+    }
 
-    if using_hands {
-        if let Some(target) = target_str {
-            if target.contains("bush") || target.contains("shrub") || target.contains("ground") {
-                return handle_foraging(state, None, map);
+    let mut moved = Vec::new();
+    let mut stopped = None;
+    'items: for (item, qty) in carried {
+        for _ in 0..qty {
+            match drop_one(item, state, map) {
+                InteractionResult::ItemLost(lost, _) => tally(&mut moved, lost),
+                InteractionResult::Failure(failure) => {
+                    stopped = Some(failure);
+                    break 'items;
+                }
+                _ => break,
             }
         }
-        return InteractionResult::Failure(
-            "Use what with your hands? Try 'use hands on bush' to forage, or specify a tool and target."
-                .to_string(),
-        );
     }
+    bulk_result(state, &moved, verb, destination, stopped)
+}
 
-    let item = match Item::from_str(item_query) {
-        Some(i) => i,
-        None => {
-            return InteractionResult::Failure(format!("You don't know what '{}' is.", item_name))
+/// Look the current tile over slowly, turning up any hidden detail.
+pub fn try_search(state: &mut GameState, map: &mut WorldMap) -> InteractionResult {
+    if state.player.room.is_some() || state.player.inside.is_some() {
+        return InteractionResult::invalid(
+            "You poke around, but everything in here is where you'd expect it.".to_string(),
+        );
+    }
+    let pos = state.player.position;
+    if state.sands.has_buried(&pos) {
+        let found = state.unearth_here(map);
+        state.player.skills.improve(Skill::Observation, 1);
+        return InteractionResult::ActionSuccess {
+            message: format!(
+                "You sift the drifted sand with your fingers and turn up what the storm buried: {}. It lies on the ground now.",
+                found.join(", ")
+            ),
+            time_cost: 3,
+            energy_cost: 3.0,
+        };
+    }
+    let message = match state.hidden_detail_here(map) {
+        Some(detail) if state.noticed_details.contains(&pos) => {
+            format!("You've already found what's here: the {}.", detail.name())
         }
-    };
-
-    let mut has_item = state.player.inventory.has(&item, 1);
-    if !has_item
-        && matches!(
-            item,
-            Item::Book
-                | Item::TutorialBook
-                | Item::OldBook
-                | Item::DeathNote
-                | Item::BookOfFishing
-                | Item::BlankBook
-        )
-        && matches!(state.player.room, Some(Room::CabinMain))
-    {
-        if let Some(cabin) = state.cabin_state() {
-            let id_match = state
-                .book_id_for_item(&item)
-                .map(|bid| cabin.book_ids.iter().any(|b| b == bid))
-                .unwrap_or(false);
-            has_item = cabin.items.contains(&item) || cabin.table_items.contains(&item) || id_match;
+        Some(detail) => {
+            state.noticed_details.insert(pos);
+            state.player.skills.improve(Skill::Observation, 1);
+            format!(
+                "You take your time, parting the undergrowth and turning things over. {}",
+                detail.notice()
+            )
         }
+        None => "You search carefully, but find nothing out of the ordinary.".to_string(),
+    };
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 2,
+        energy_cost: 2.0,
     }
+}
 
-    if !has_item {
-        return InteractionResult::Failure(format!("You don't have a {}.", item.name()));
-    }
+/// Empty the card case's cards in a ring around `center`. Cards that fall on
+/// `center` itself aren't placed; their count is returned so the caller can
+/// decide where they end up.
+fn scatter_card_case(state: &mut GameState, map: &mut WorldMap, center: Position) -> u32 {
+    let cards_to_scatter = state.card_case_cards_inside.min(52);
+    state.card_case_cards_inside = 0;
+    state.card_case_open = false;
 
-    if item == Item::CardCase {
-        return handle_card_case_use(state, map, target_str);
+    if cards_to_scatter == 0 {
+        return 0;
     }
-
-    if item == Item::PlayingCard {
-        if let Some(target) = target_str {
-            if target.contains("case") || target.contains("card") {
-                if !state.player.inventory.has(&Item::CardCase, 1) {
-                    return InteractionResult::Failure(
-                        "You need to be holding the card case to tuck this card away."
-                            .to_string(),
-                    );
-                }
-                if state.card_case_cards_inside >= 52 {
-                    return InteractionResult::Failure(
-                        "The card case is already full; you can't squeeze in another card."
-                            .to_string(),
-                    );
-                }
-                if !state.player.inventory.remove(&Item::PlayingCard, 1) {
-                    return InteractionResult::Failure(
-                        "You fumble and nearly drop the card; best try again more carefully."
-                            .to_string(),
-                    );
-                }
-                state.card_case_cards_inside =
-                    (state.card_case_cards_inside.saturating_add(1)).min(52);
-                return InteractionResult::Success(
-                    "You slide the card neatly back into the case."
-                        .to_string(),
-                );
+    let mut rng = rand::thread_rng();
+    let mut positions = Vec::new();
+    for dr in -1..=1 {
+        for dc in -1..=1 {
+            let p = Position::new(center.row + dr, center.col + dc);
+            if let Some((rr, cc)) = map.index_of(&p) {
+                positions.push((rr, cc));
             }
         }
-        return InteractionResult::Failure(
-            "You flick the card between your fingers. Maybe use it with the card case?"
-                .to_string(),
-        );
     }
+    if positions.is_empty() {
+        return 0;
+    }
+    let center_tile = map.index_of(&center);
+    let mut at_center = 0;
+    for _ in 0..cards_to_scatter {
+        let &(rr, cc) = positions.get(rng.gen_range(0..positions.len())).unwrap();
+        if Some((rr, cc)) == center_tile {
+            at_center += 1;
+        } else if let Some(t) = map.get_tile_mut(rr, cc) {
+            t.items.add(Item::PlayingCard, 1);
+        }
+    }
+    at_center
+}
 
-    if matches!(
-        item,
-        Item::Book | Item::TutorialBook | Item::OldBook | Item::DeathNote | Item::BookOfFishing
-    ) {
-        return handle_book_use(state, map, &item, target_str);
+fn note_card_scatter(state: &mut GameState, message: &mut String) {
+    if state.card_scatter_achievement {
+        return;
     }
-    if item == Item::BlankBook {
-        return InteractionResult::Failure(
-            "It's a blank book. Title it first with 'write 제목:<title> on 빈 책'.".to_string(),
+    state.card_scatter_achievement = true;
+    message.push(' ');
+    message.push_str("(Achievement unlocked: 52 Pickup. Sometimes you have to let things fly.)");
+}
+
+fn is_companion_food(item: &Item) -> bool {
+    matches!(
+        item,
+        Item::RawMeat
+            | Item::CookedMeat
+            | Item::Fish
+            | Item::SmallFish
+            | Item::Pupfish
+            | Item::BigFish
+            | Item::CookedFish
+    )
+}
+
+/// Find the closest animal within `range` tiles whose species or name matches `target`.
+fn wildlife_matching(state: &GameState, target: &str, range: f32) -> Option<usize> {
+    let t = target.to_lowercase();
+    let pos = state.player.position;
+    state
+        .wildlife
+        .iter()
+        .enumerate()
+        .filter(|(_, w)| {
+            let species = w.species.name().to_lowercase();
+            let named = w
+                .name
+                .as_ref()
+                .map(|n| t.contains(&n.to_lowercase()))
+                .unwrap_or(false);
+            species.contains(&t) || t.contains(&species) || t.contains("animal") || named
+        })
+        .map(|(idx, w)| (idx, pos.distance_to(&w.position)))
+        .filter(|(_, dist)| *dist <= range)
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(idx, _)| idx)
+}
+
+/// How far off, in tiles, an animal can be and still be crept up on.
+const APPROACH_RANGE: f32 = 4.0;
+
+/// Odds that one careful step toward an animal goes unnoticed. The closer
+/// and the warier it is, the harder; sleeping animals are easiest.
+fn approach_chance(skill: f32, wariness: f32, dist: f32, behavior: Behavior) -> f64 {
+    let settled = match behavior {
+        Behavior::Sleeping => 0.15,
+        Behavior::Resting | Behavior::Grazing | Behavior::Foraging => 0.05,
+        Behavior::Fleeing | Behavior::Alert => -0.2,
+        _ => 0.0,
+    };
+    (0.6 + skill / 200.0 - wariness / 100.0 - dist * 0.05 + settled).clamp(0.05, 0.95) as f64
+}
+
+/// Creep up on an animal, a step at a time. Each step may give you away;
+/// made it all the way, you see what distance hides, and can sketch it.
+pub fn try_approach(target: &str, state: &mut GameState, map: &WorldMap) -> InteractionResult {
+    if state.player.room.is_some() || state.player.inside.is_some() {
+        return InteractionResult::wrong_place(
+            "There's nothing to creep up on in here. Step outside first.".to_string(),
         );
     }
+    let Some(idx) = wildlife_matching(state, target, APPROACH_RANGE)
+        .filter(|&i| state.wildlife[i].on_land(map) && !state.wildlife[i].tamed)
+    else {
+        return InteractionResult::invalid(format!(
+            "You can't see any {} near enough to creep up on.",
+            target
+        ));
+    };
 
-    // 1. Blueprint Interaction (Building)
-    let target_is_blueprint = target_str
-        .map(|t| t.contains("blueprint") || t.contains("project"))
-        .unwrap_or(false);
-    if target_is_blueprint {
-        return handle_blueprint_interaction(state, &item);
+    let pos = state.player.position;
+    let w = &state.wildlife[idx];
+    let name = w.display_name();
+    let species = w.species;
+    let animal_pos = w.position;
+    let dist = pos.distance_to(&animal_pos);
+    let skill = state.player.effective_skill(Skill::Observation) as f32
+        + state.player.effective_skill(Skill::Survival) as f32;
+    let chance = approach_chance(skill, w.wariness, dist, w.behavior);
+    let mut rng = rand::thread_rng();
+
+    if !rng.gen_bool(chance) {
+        let structures = state.objects.structure_positions();
+        let w = &mut state.wildlife[idx];
+        w.alarm(15.0);
+        w.flee_from(pos, map, &structures, &mut rng);
+        state.player.skills.improve(Skill::Observation, 1);
+        return InteractionResult::chance(
+            format!(
+                "Something gives you away: a twig, a shadow, your breath. The {} startles and bolts.",
+                name
+            ),
+            1,
+            2.0,
+        );
     }
-    // Also check if target is the name of the blueprint item or if no target is given but material matches
-    if let Some(bp) = &state.player.active_project {
-        if target_str
-            .map(|t| bp.target_item.name().to_lowercase().contains(t))
-            .unwrap_or(false)
-            || (target_str.is_none() && bp.required.contains_key(&item))
-        {
-            return handle_blueprint_interaction(state, &item);
+
+    if dist > 1.5 {
+        let (dr, dc) = (animal_pos.row - pos.row, animal_pos.col - pos.col);
+        let next = if dr.abs() >= dc.abs() {
+            Position::new(pos.row + dr.signum(), pos.col)
+        } else {
+            Position::new(pos.row, pos.col + dc.signum())
+        };
+        let open = map
+            .index_of(&next)
+            .is_some_and(|(r, c)| map.is_walkable(r, c))
+            && !state.objects.structure_positions().contains(&next);
+        if !open {
+            return InteractionResult::invalid(format!(
+                "There's no quiet way closer to the {} from here.",
+                name
+            ));
         }
+        state.player.position = next;
+        state.player.mark_visited();
+        return InteractionResult::ActionSuccess {
+            message: format!(
+                "You ease forward, setting each foot down slowly. The {} hasn't noticed you; it's {}.",
+                name,
+                distance_phrase(&next, &animal_pos)
+            ),
+            time_cost: 1,
+            energy_cost: 2.0,
+        };
     }
 
-    // 2. Resource Gathering (Chopping, etc)
-    if let Some(target) = target_str {
-        if target.contains("bamboo") {
-            if item == Item::Axe || item == Item::StoneAxe {
-                return try_chop_tree(state, map, &item);
-            }
-        }
-        if target.contains("tree") || target.contains("wood") || target.contains("log") {
-            if item == Item::Axe || item == Item::StoneAxe {
-                // Check if it's chopping block or standing tree
-                if target.contains("block") || target.contains("chop") {
-                    return try_chop_firewood(state, &item);
-                } else {
-                    return try_chop_tree(state, map, &item);
-                }
-            }
-        }
-        if target.contains("bush") || target.contains("shrub") || target.contains("ground") {
-            return handle_foraging(state, Some(&item), map);
+    // Close enough to touch: what you can see from here you couldn't before
+    state.wildlife[idx].alarm(-5.0);
+    state.player.skills.improve(Skill::Observation, 2);
+    let mut message = format!(
+        "You ease in until the {} is almost within reach. {}",
+        name,
+        species.close_detail()
+    );
+    if !state.sketched_species.contains(&species) {
+        state.sketched_species.push(species);
+        state.journal.push(format!(
+            "Sketch: a {}, drawn from an arm's length away. {}",
+            species.name(),
+            species.close_detail()
+        ));
+        message.push_str(" You fix it in your memory, and later sketch it into your journal.");
+    }
+    state.record_sighting(species, 0.0);
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 1,
+        energy_cost: 1.0,
+    }
+}
+
+pub fn try_throw(
+    item_name: &str,
+    target: Option<&str>,
+    direction: Option<Direction>,
+    accept_loss: bool,
+    state: &mut GameState,
+    map: &mut WorldMap,
+) -> InteractionResult {
+    let item = match state.resolve_item(item_name) {
+        Some(i) => i,
+        None => {
+            return InteractionResult::invalid(format!("You don't know what '{}' is.", item_name))
         }
+    };
+    if !state.player.inventory.has(&item, 1) {
+        return InteractionResult::missing(
+            item,
+            format!("You don't have any {}.", state.display_name(&item)),
+        );
+    }
+    if state.player.room.is_some() {
+        return InteractionResult::wrong_place(
+            "There's no room to throw anything in here. Step outside first.".to_string(),
+        );
+    }
+    if item == Item::Raft {
+        return InteractionResult::invalid(
+            "The raft is far too unwieldy to throw. Drop it instead.".to_string(),
+        );
     }
+    let label = state.display_name(&item);
+    // Whatever it hits, the clatter carries a long way in the cave
+    state.make_noise(2);
 
-    // 3. Processing (Crafting Materials)
-    if item == Item::Knife || item == Item::StoneKnife {
-        if let Some(target) = target_str {
-            if target.contains("log") {
-                if state.player.inventory.has(&Item::Log, 1) {
-                    state.player.inventory.remove(&Item::Log, 1);
-                    state.player.inventory.add(Item::Kindling, 4);
-                    state.player.skills.improve("woodcutting", 2);
-                    state.damage_tool(&item, 1, "whittling wood");
-                    return InteractionResult::ActionSuccess {
-                        message: "You whittle the log down into a pile of fine kindling."
-                            .to_string(),
-                        time_cost: 2, // 20 mins
-                        energy_cost: 10.0,
-                    };
-                }
-            }
-            if target.contains("branch") || target.contains("stick") {
-                if state.player.inventory.has(&Item::Stick, 1) {
-                    state.player.inventory.remove(&Item::Stick, 1);
-                    state.player.inventory.add(Item::Kindling, 1);
-                    state.player.skills.improve("woodcutting", 1);
-                    state.damage_tool(&item, 1, "carving a stick");
-                    return InteractionResult::ActionSuccess {
-                        message: "You shave the stick into tinder.".to_string(),
-                        time_cost: 1,
-                        energy_cost: 2.0,
-                    };
-                }
-            }
-            if target.contains("bamboo") {
-                if state.player.inventory.has(&Item::Bamboo, 1) {
-                    state.player.inventory.remove(&Item::Bamboo, 1);
-                    state.player.inventory.add(Item::Paper, 3);
-                    state.player.skills.improve("tailoring", 2);
-                    state.damage_tool(&item, 1, "splitting bamboo");
-                    return InteractionResult::ActionSuccess {
-                        message: "You split the bamboo and press it into thin sheets of paper."
-                            .to_string(),
-                        time_cost: 2,
-                        energy_cost: 6.0,
-                    };
-                } else {
-                    return InteractionResult::Failure(
-                        "You need bamboo in your inventory to cut into paper.".to_string(),
+    // Stones at wildlife: a real, if small, hunting option.
+    if item == Item::Stone {
+        if let Some(idx) = target.and_then(|t| wildlife_matching(state, t, 3.0)) {
+            state.player.inventory.remove(&item, 1);
+            let w = &state.wildlife[idx];
+            let name = w.species.name();
+            let landing = w.position;
+            let dist = state.player.position.distance_to(&landing);
+            let skill = state.player.effective_skill(Skill::Observation) as f32
+                + state.player.effective_skill(Skill::Survival) as f32;
+            let hit_chance = (0.25 + skill / 200.0 - dist * 0.08).clamp(0.1, 0.9) as f64;
+            let mut rng = rand::thread_rng();
+            drop_on_ground(map, landing, Item::Stone);
+
+            let message = if rng.gen_bool(hit_chance) {
+                state.player.skills.improve(Skill::Survival, 1);
+                state
+                    .strike_wildlife(idx, 8.0)
+                    .map(|hit| format!("Your stone flies true. {}", hit))
+                    .unwrap_or_else(|| format!("The stone glances off the {}.", name))
+            } else {
+                state.startle_wildlife(10.0, 3.0);
+                if let Some(w) = state.wildlife.get_mut(idx) {
+                    w.behavior = Behavior::Fleeing;
+                    w.alarm(25.0);
+                    let (dr, dc) = (
+                        (w.position.row - state.player.position.row).signum(),
+                        (w.position.col - state.player.position.col).signum(),
                     );
+                    let away = Position::new(w.position.row + dr, w.position.col + dc);
+                    if let Some((r, c)) = map.index_of(&away) {
+                        if map.is_walkable(r, c) {
+                            w.position = away;
+                        }
+                    }
                 }
+                state.player.skills.improve(Skill::Observation, 1);
+                format!(
+                    "The stone thuds into the ground beside the {}. It bolts away from you.",
+                    name
+                )
+            };
+            return InteractionResult::ActionSuccess {
+                message,
+                time_cost: 1,
+                energy_cost: 2.0,
+            };
+        }
+    }
+
+    // Food tossed to a companion feeds it from a distance.
+    if is_companion_food(&item) {
+        if let Some(idx) = target.and_then(|t| wildlife_matching(state, t, 3.0)) {
+            if matches!(state.wildlife[idx].species, Species::Dog | Species::Cat) {
+                state.player.inventory.remove(&item, 1);
+                let w = &mut state.wildlife[idx];
+                w.tamed = true;
+                let companion = w.display_name();
+                state.player.modify_mood(4.0);
+                state.player.modify_connection(1.0);
+                return InteractionResult::ActionSuccess {
+                    message: format!(
+                        "You toss the {}. {} snaps it out of the air, then trots over to stay close.",
+                        label, companion
+                    ),
+                    time_cost: 1,
+                    energy_cost: 1.0,
+                };
             }
         }
     }
 
-    // 3a. Combat: attack nearby wildlife with melee weapons
-    if matches!(
-        item,
-        Item::Axe | Item::StoneAxe | Item::Knife | Item::StoneKnife | Item::SharpStone
-    ) {
-        if let Some(target) = target_str {
-            let t = target.to_lowercase();
-            let looks_like_creature = t.contains("deer")
-                || t.contains("rabbit")
-                || t.contains("fox")
-                || t.contains("wolf")
-                || t.contains("hare")
-                || t.contains("boar")
-                || t.contains("pig")
-                || t.contains("animal")
-                || t.contains("creature");
-            if looks_like_creature {
-                if state.player.energy < 5.0 {
-                    return InteractionResult::Failure(
-                        "You are too exhausted to swing a weapon with any force right now."
-                            .to_string(),
-                    );
-                }
+    let target_dir = target.and_then(Direction::from_str);
+    if let (Some(t), None, None) = (target, target_dir, direction) {
+        return InteractionResult::invalid(format!(
+            "Nothing matching '{}' is within throwing range.",
+            t
+        ));
+    }
+    let Some(dir) = direction.or(target_dir) else {
+        return InteractionResult::invalid(format!(
+            "Throw the {} which way? Give a direction or something nearby to aim at.",
+            label
+        ));
+    };
+    if matches!(dir, Direction::Up | Direction::Down) {
+        return InteractionResult::invalid(
+            "Whatever goes up will only come down on your head.".to_string(),
+        );
+    }
 
-                let base_damage = match item {
-                    Item::Axe => 32.0,
-                    Item::StoneAxe => 26.0,
-                    Item::Knife => 22.0,
-                    Item::StoneKnife => 18.0,
-                    Item::SharpStone => 14.0,
-                    _ => 18.0,
-                };
+    // Light things carry two tiles; anything heavy lands close.
+    let mut rng = rand::thread_rng();
+    let reach = if item.weight() <= 1.0 && rng.gen_bool(0.5) { 2 } else { 1 };
+    let mut landing = state.player.position;
+    let mut into_water = false;
+    for _ in 0..reach {
+        let next = landing.move_in_direction(dir);
+        let Some((r, c)) = map.index_of(&next) else {
+            break;
+        };
+        let Some(tile) = map.get_tile(r, c) else {
+            break;
+        };
+        landing = next;
+        if matches!(tile.tile_type, TileType::Lake) {
+            into_water = true;
+            break;
+        }
+    }
 
-                if let Some(msg) =
-                    state.attack_nearby_wildlife(map, &item, base_damage, Some(target))
-                {
-                    state.damage_tool(&item, 1, "striking a creature");
-                    return InteractionResult::ActionSuccess {
-                        message: msg,
-                        time_cost: 1,
-                        energy_cost: 6.0,
-                    };
-                } else {
-                    return InteractionResult::Failure(
-                        "You don't see any such creature close enough to strike.".to_string(),
-                    );
-                }
-            }
+    if into_water && !accept_loss {
+        return InteractionResult::invalid(format!(
+            "That throw would land the {} in the lake, and you'd never see it again. Throw again with accept_loss if you really mean it.",
+            label
+        ));
+    }
+
+    state.player.inventory.remove(&item, 1);
+    let _ = state.on_player_drop(&item);
+
+    if item == Item::RubberDuck {
+        state.player.modify_mood(-6.0);
+        let label = if state.custom_names.contains_key(&item) {
+            label
+        } else {
+            format!("the {}", label)
+        };
+        let mut message = if into_water {
+            format!(
+                "You hurl {} out over the lake. It lands with a small, forgiving splash and bobs there, painted smile unwavering, slowly drifting out of reach. You feel terrible.",
+                label
+            )
+        } else {
+            drop_on_ground(map, landing, item);
+            format!(
+                "You throw {}. It tumbles end over end and lands face-down in the dirt {} of you. When you pick it up it will still be smiling, which somehow makes it worse.",
+                label,
+                dir.name()
+            )
+        };
+        if !state.duck_throw_achievement {
+            state.duck_throw_achievement = true;
+            message.push_str(" (Achievement unlocked: Why Would You Do That. It only ever listened.)");
         }
+        return InteractionResult::ItemLost(item, message);
     }
 
-    // Raft: short lake excursion for observations
-    if item == Item::Raft {
-        let pos = state.player.position;
-        let mut near_water = false;
-        'outer: for dr in -1..=1 {
-            for dc in -1..=1 {
-                let check = Position::new(pos.row + dr, pos.col + dc);
-                if let Some((r, c)) = check.as_usize() {
-                    if let Some(tile) = map.get_tile(r, c) {
-                        if matches!(tile.biome, Biome::Lake | Biome::Oasis) {
-                            near_water = true;
-                            break 'outer;
-                        }
-                    }
-                }
+    if into_water {
+        match item {
+            Item::PlayingCard => state.cards_lost += 1,
+            Item::CardCase => {
+                state.cards_lost += state.card_case_cards_inside as u32;
+                state.card_case_cards_inside = 0;
+                state.card_case_open = false;
             }
+            _ => {}
         }
-        if !near_water {
-            return InteractionResult::Failure(
-                "Find a shoreline first; you need water to launch the raft.".to_string(),
-            );
-        }
+        return InteractionResult::ItemLost(
+            item,
+            format!(
+                "The {} arcs out over the water and sinks with a plop. Gone.",
+                label
+            ),
+        );
+    }
 
-        let weather_here = state.weather.get_for_position(pos.row, pos.col);
-        let severe = matches!(
-            weather_here,
-            Weather::Blizzard | Weather::HeavySnow | Weather::HeavyRain | Weather::Sandstorm
+    let message = if item == Item::CardCase {
+        drop_on_ground(map, landing, item);
+        let at_landing = scatter_card_case(state, map, landing);
+        if let Some((r, c)) = map.index_of(&landing) {
+            if let Some(tile) = map.get_tile_mut(r, c) {
+                tile.items.add(Item::PlayingCard, at_landing);
+            }
+        }
+        let mut message = format!(
+            "The card case sails {} and bursts open where it lands, cards fluttering everywhere.",
+            dir.name()
         );
-        let mut time_cost = 3;
-        let mut energy_cost = 8.0;
-        if severe {
-            time_cost += 1;
-            energy_cost += 2.0;
+        note_card_scatter(state, &mut message);
+        message
+    } else {
+        drop_on_ground(map, landing, item);
+        format!(
+            "You toss the {} {}. It lands {} away.",
+            label,
+            dir.name(),
+            if landing.distance_to(&state.player.position) > 1.5 {
+                "a couple of paces"
+            } else {
+                "a pace"
+            }
+        )
+    };
+    InteractionResult::ItemLost(item, message)
+}
+
+fn drop_on_ground(map: &mut WorldMap, pos: Position, item: Item) {
+    if let Some((r, c)) = map.index_of(&pos) {
+        if let Some(tile) = map.get_tile_mut(r, c) {
+            tile.items.add(item, 1);
         }
+    }
+}
 
-        let mut rng = rand::thread_rng();
-        let mut findings = Vec::new();
-        if rng.gen_bool(0.5) {
-            findings.push("a glint of fish beneath the surface");
-            state.player.skills.improve("observation", 2);
+pub fn examine(target: &str, state: &GameState, map: &WorldMap) -> String {
+    let normalized = target.to_lowercase();
+    let player = &state.player;
+    let player_pos = player.position;
+
+    // Check for active project
+    if normalized.contains("blueprint") || normalized.contains("project") {
+        if let Some(bp) = &player.active_project {
+            return bp.status_description();
         } else {
-            state.player.skills.improve("observation", 1);
+            let mut parts = Vec::new();
+            let known = state.known_blueprint_names();
+            if !known.is_empty() {
+                parts.push(format!("Known blueprints: {}", known.join(", ")));
+            }
+            let locked = state.locked_blueprint_hints();
+            if !locked.is_empty() {
+                parts.push(format!("Locked: {}", locked.join(" | ")));
+            }
+            let tail = if parts.is_empty() {
+                String::new()
+            } else {
+                format!(" {}", parts.join(" "))
+            };
+            return format!(
+                "You don't have any active blueprint. Use 'create [item]' to start one.{}",
+                tail
+            );
         }
-        if rng.gen_bool(0.35) {
-            if state.player.inventory.add(Item::Driftwood, 1) {
-                findings.push("a floating piece of driftwood you haul aboard");
+    }
+
+    if normalized.contains("bear") && state.in_cave() {
+        if let Some(po) = state.objects.find(CAVE_BEAR_ID) {
+            if let Some(bear) = po.object.as_cave_bear() {
+                let extra = match bear.state {
+                    BearState::Hibernating => " You can't see it, only hear it. Quiet feet and no fire would keep it that way.",
+                    BearState::Stirring => " It isn't fully awake. A little more noise would change that.",
+                    BearState::Hostile => " You could back out now, offer it something to eat, or stand and fight.",
+                    BearState::Pacified | BearState::DrivenOff => "",
+                };
+                return format!("{}{}", bear.sense(), extra);
             }
         }
+    }
 
-        state.player.modify_mood(3.0);
-        let finding_text = if findings.is_empty() {
-            "You mostly drift and listen to the water slap the hull.".to_string()
+    if state.in_ruin()
+        && ["inscription", "carving", "wall", "plinth", "sand"]
+            .iter()
+            .any(|w| normalized.contains(w))
+    {
+        let read = state.ruin_inscriptions();
+        let cleared = state.ruin().map(|r| r.chambers_cleared).unwrap_or(0);
+        let mut text = if read.is_empty() {
+            "Worn carving runs over the doorway, half hidden by drifted sand. Whatever it says runs on into the buried chambers.".to_string()
         } else {
-            findings.join("; ")
+            format!("You trace the carvings you've uncovered:\n{}", read.join("\n\n"))
         };
+        if cleared < Ruin::CHAMBERS {
+            text.push_str(&format!(
+                "\n\nSand still chokes the way into the {}. A shovel would clear it.",
+                Ruin::chamber_name(cleared + 1)
+            ));
+        }
+        return text;
+    }
 
-        return InteractionResult::ActionSuccess {
-            message: format!(
-                "You slide the raft into the water and paddle out, letting the cabin shrink behind you. {}",
-                finding_text
-            ),
-            time_cost,
-            energy_cost,
-        };
+    if normalized.contains("book") || normalized.contains("note") || normalized.contains("책") {
+        if let Some(book) = state.accessible_book(&normalized) {
+            let page_info = if book.pages.is_empty() {
+                "no pages yet".to_string()
+            } else {
+                format!("{} page(s)", book.pages.len())
+            };
+            return format!("Book [{}]: {} ({})", book.id, book.title, page_info);
+        }
     }
 
-    // 3b. Butchering corpses into resources
-    if matches!(
-        item,
-        Item::Knife | Item::StoneKnife | Item::Axe | Item::StoneAxe | Item::SharpStone
-    ) {
-        if let Some(target) = target_str {
-            let t = target.to_lowercase();
-            if t.contains("corpse")
-                || t.contains("carcass")
-                || t.contains("body")
-                || t.contains("pig")
-                || t.contains("boar")
-            {
-                if state.player.energy < 5.0 {
-                    return InteractionResult::Failure(
-                        "You are too tired to properly butcher anything right now.".to_string(),
-                    );
-                }
+    match resolve_fixture(&normalized, state, Verb::Examine) {
+        Resolved::Found {
+            fixture,
+            whole: true,
+        } if fixture.in_scope(state) => {
+            if let Some(text) = examine_fixture(fixture, state, map) {
+                return text;
+            }
+        }
+        Resolved::Ambiguous(options) if options.iter().all(|f| f.in_scope(state)) => {
+            return ambiguity_message(&options, Verb::Examine);
+        }
+        _ => {}
+    }
 
-                if let Some(msg) = state.butcher_corpse_at_player(&item) {
-                    state.damage_tool(&item, 1, "butchering a carcass");
-                    return InteractionResult::ActionSuccess {
-                        message: msg,
-                        time_cost: 2,
-                        energy_cost: 0.0,
-                    };
-                }
+    if let Some(text) = state
+        .storage_at_hand(&normalized)
+        .and_then(|id| storage_line(state, &id))
+    {
+        return text;
+    }
 
-                return InteractionResult::Failure(
-                    "You don't see a suitable carcass here to butcher.".to_string(),
-                );
+    // Things carried or lying here by name come before any tree
+    let named = state.resolve_item(&normalized);
+    let on_ground = |item: &Item| {
+        state.player.room.is_none()
+            && map
+                .tile_at(&player_pos)
+                .map(|t| t.items.contains(item))
+                .unwrap_or(false)
+    };
+    let named_here = named.filter(|i| state.player.inventory.has(i, 1) || on_ground(i));
+    if named_here.is_none() {
+        if let Some(text) = examine_tree(&normalized, state) {
+            return text;
+        }
+    }
+    for (item, _) in state.player.inventory.list() {
+        let custom_match = state
+            .custom_name(&item)
+            .map(|n| n.to_lowercase().contains(&normalized))
+            .unwrap_or(false);
+        if custom_match || named == Some(item) || item.name().to_lowercase().contains(&normalized) {
+            let mut text = match state.custom_name(&item) {
+                Some(custom) => format!("{} ({}) {}", custom, item.name(), item.description()),
+                None => item.description().to_string(),
+            };
+            // Only blueprints the player already knows give anything away
+            let uses: Vec<String> = recipes_using(item)
+                .iter()
+                .filter(|(target, _)| state.knows_blueprint(*target))
+                .map(|(target, qty)| format!("{} (needs {})", target.name(), qty))
+                .collect();
+            if !uses.is_empty() {
+                text.push_str(&format!("\nUsed in: {}.", uses.join(", ")));
             }
+            if item.is_perishable() {
+                text.push_str("\nIt won't keep for long.");
+            }
+            if item == Item::BlankBook {
+                text.push('\n');
+                text.push_str(write_syntax(state.player.language));
+            }
+            if let Some(note) = state.item_provenance(&item) {
+                text.push('\n');
+                text.push_str(&note);
+            }
+            return text;
         }
     }
+    if let Some(item) = named_here {
+        return format!("{} It lies at your feet.", item.description());
+    }
+    // Examine nearby wildlife (living animals)
+    {
+        let mut same_tile_indices: Vec<usize> = Vec::new();
+        let mut best_idx: Option<usize> = None;
+        let mut best_dist = f32::MAX;
+        for (idx, w) in state.wildlife.iter().enumerate() {
+            let species_name = w.species.name().to_lowercase();
+            if !species_name.contains(&normalized)
+                && !normalized.contains(&species_name)
+                && !normalized.contains("animal")
+            {
+                continue;
+            }
+            if w.position == player_pos {
+                same_tile_indices.push(idx);
+            }
+            let dist = player_pos.distance_to(&w.position);
+            if dist <= 6.0 && dist < best_dist {
+                best_dist = dist;
+                best_idx = Some(idx);
+            }
+        }
 
-    // 3c. Feeding wildlife (dogs, cats, and others)
-    if matches!(
-        item,
-        Item::RawMeat
-            | Item::CookedMeat
-            | Item::Fish
-            | Item::SmallFish
-            | Item::BigFish
-            | Item::CookedFish
-    ) {
-        if let Some(target) = target_str {
-            let t = target.to_lowercase();
-            let pos = state.player.position;
-            let mut same_tile_index: Option<usize> = None;
-            let mut best_index: Option<usize> = None;
-            let mut best_distance = f32::MAX;
+        if !same_tile_indices.is_empty() {
+            let mut lines = Vec::new();
+            if same_tile_indices.len() == 1 {
+                let w = &state.wildlife[same_tile_indices[0]];
+                let body = &w.body;
+                let overall = (body.overall_health_ratio() * 100.0).round();
+                lines.push(format!(
+                    "You study {} from very close.",
+                    w.display_name()
+                ));
+                lines.push(format!("Overall condition: {:.0}%.", overall));
 
-            for (idx, w) in state.wildlife.iter().enumerate() {
-                let species_name = w.species.name().to_lowercase();
+                for part in &body.parts {
+                    let name = match part.kind {
+                        BodyPartKind::Head => "head",
+                        BodyPartKind::Torso => "torso",
+                        BodyPartKind::ArmLeft => "left arm",
+                        BodyPartKind::ArmRight => "right arm",
+                        BodyPartKind::LegLeft => "left leg",
+                        BodyPartKind::LegRight => "right leg",
+                        BodyPartKind::FrontLeftLeg => "front left leg",
+                        BodyPartKind::FrontRightLeg => "front right leg",
+                        BodyPartKind::BackLeftLeg => "back left leg",
+                        BodyPartKind::BackRightLeg => "back right leg",
+                        BodyPartKind::Tail => "tail",
+                    };
+                    let ratio = (part.ratio() * 100.0).round();
+                    let tag = if part.vital {
+                        " (vital)"
+                    } else if part.movement {
+                        " (movement)"
+                    } else if part.manipulation {
+                        " (grip)"
+                    } else {
+                        ""
+                    };
+                    lines.push(format!("- {}: {:.0}%{}", name, ratio, tag));
+                }
 
-                let mut matches_target = species_name.contains(&t)
-                    || t.contains(&species_name)
-                    || t.contains("animal");
+                return lines.join("\n");
+            } else {
+                lines.push(
+                    "You study the animals sharing this patch of ground.".to_string(),
+                );
+                for idx in same_tile_indices {
+                    let w = &state.wildlife[idx];
+                    let body = &w.body;
+                    let overall = (body.overall_health_ratio() * 100.0).round();
+                    lines.push(String::new());
+                    lines.push(format!("{}:", w.display_name()));
+                    lines.push(format!("  Overall condition: {:.0}%.", overall));
 
-                if !matches_target {
-                    if let Some(ref name) = w.name {
-                        let n = name.to_lowercase();
-                        if n.contains(&t) || t.contains(&n) {
-                            matches_target = true;
-                        }
+                    for part in &body.parts {
+                        let name = match part.kind {
+                            BodyPartKind::Head => "head",
+                            BodyPartKind::Torso => "torso",
+                            BodyPartKind::ArmLeft => "left arm",
+                            BodyPartKind::ArmRight => "right arm",
+                            BodyPartKind::LegLeft => "left leg",
+                            BodyPartKind::LegRight => "right leg",
+                            BodyPartKind::FrontLeftLeg => "front left leg",
+                            BodyPartKind::FrontRightLeg => "front right leg",
+                            BodyPartKind::BackLeftLeg => "back left leg",
+                            BodyPartKind::BackRightLeg => "back right leg",
+                            BodyPartKind::Tail => "tail",
+                        };
+                        let ratio = (part.ratio() * 100.0).round();
+                        let tag = if part.vital {
+                            " (vital)"
+                        } else if part.movement {
+                            " (movement)"
+                        } else if part.manipulation {
+                            " (grip)"
+                        } else {
+                            ""
+                        };
+                        lines.push(format!("- {}: {:.0}%{}", name, ratio, tag));
                     }
                 }
 
-                if !matches_target {
-                    continue;
-                }
+                return lines.join("\n");
+            }
+        }
 
-                let dist = pos.distance_to(&w.position);
-                if dist > 2.0 {
-                    continue;
-                }
+        if let Some(idx) = best_idx {
+            let w = &state.wildlife[idx];
+            let mut lines = Vec::new();
+            let body = &w.body;
+            let overall = (body.overall_health_ratio() * 100.0).round();
+            lines.push(format!(
+                "You study {} from nearby.",
+                w.display_name()
+            ));
+            lines.push(format!("Overall condition: {:.0}%.", overall));
 
-                if w.position == pos && same_tile_index.is_none() {
-                    same_tile_index = Some(idx);
-                }
+            for part in &body.parts {
+                let name = match part.kind {
+                    BodyPartKind::Head => "head",
+                    BodyPartKind::Torso => "torso",
+                    BodyPartKind::ArmLeft => "left arm",
+                    BodyPartKind::ArmRight => "right arm",
+                    BodyPartKind::LegLeft => "left leg",
+                    BodyPartKind::LegRight => "right leg",
+                    BodyPartKind::FrontLeftLeg => "front left leg",
+                    BodyPartKind::FrontRightLeg => "front right leg",
+                    BodyPartKind::BackLeftLeg => "back left leg",
+                    BodyPartKind::BackRightLeg => "back right leg",
+                    BodyPartKind::Tail => "tail",
+                };
+                let ratio = (part.ratio() * 100.0).round();
+                let tag = if part.vital {
+                    " (vital)"
+                } else if part.movement {
+                    " (movement)"
+                } else if part.manipulation {
+                    " (grip)"
+                } else {
+                    ""
+                };
+                lines.push(format!("- {}: {:.0}%{}", name, ratio, tag));
+            }
 
-                if dist < best_distance {
-                    best_distance = dist;
-                    best_index = Some(idx);
+            return lines.join("\n");
+        }
+    }
+
+    // Examine corpse at feet (with stored body, if any)
+    {
+        for po in state.objects.objects_at(&player_pos) {
+            if let crate::world::ObjectKind::Corpse(ref corpse) = po.object.kind {
+                let mut lines = Vec::new();
+                lines.push(format!(
+                    "You examine the {} carcass in detail.",
+                    corpse.species.name()
+                ));
+                lines.push(format!("Freshness: {} (higher means older).", corpse.freshness));
+
+                if let Some(ref body) = corpse.body {
+                    let overall = (body.overall_health_ratio() * 100.0).round();
+                    lines.push(format!(
+                        "Overall condition at death: {:.0}% of original.",
+                        overall
+                    ));
+                    for part in &body.parts {
+                        let name = match part.kind {
+                            BodyPartKind::Head => "head",
+                            BodyPartKind::Torso => "torso",
+                            BodyPartKind::ArmLeft => "left arm",
+                            BodyPartKind::ArmRight => "right arm",
+                            BodyPartKind::LegLeft => "left leg",
+                            BodyPartKind::LegRight => "right leg",
+                            BodyPartKind::FrontLeftLeg => "front left leg",
+                            BodyPartKind::FrontRightLeg => "front right leg",
+                            BodyPartKind::BackLeftLeg => "back left leg",
+                            BodyPartKind::BackRightLeg => "back right leg",
+                            BodyPartKind::Tail => "tail",
+                        };
+                        let ratio = (part.ratio() * 100.0).round();
+                        let tag = if part.vital {
+                            " (vital)"
+                        } else if part.movement {
+                            " (movement)"
+                        } else if part.manipulation {
+                            " (grip)"
+                        } else {
+                            ""
+                        };
+                        lines.push(format!("- {}: {:.0}%{}", name, ratio, tag));
+                    }
+                } else {
+                    lines.push("You see no obvious sign of what killed it; only stillness.".to_string());
                 }
+
+                return lines.join("\n");
             }
+        }
+    }
+
+    // Self examine
+    if normalized.contains("self") || normalized == "me" {
+        let mut text = state.player.status_summary();
+        if let Some(body) = state.player.body_summary() {
+            text.push(' ');
+            text.push_str(&body);
+        }
+        match state.in_hand() {
+            Some(held) => text.push_str(&format!(" You have {} in hand.", held)),
+            None => text.push_str(" Your hands are empty."),
+        }
+        return text;
+    }
+    format!("You don't see anything special about '{}'.", target)
+}
+
+/// A look at one of the cabin's or shed's fixed things. `None` when there's
+/// nothing to say, like a card case that isn't to hand.
+fn examine_fixture(fixture: Fixture, state: &GameState, map: &WorldMap) -> Option<String> {
+    let surface_line = |id: &str, bare: &str, holding: &str| {
+        let items: Vec<String> = state
+            .surface(id)
+            .map(|s| s.items.labels(|i| state.display_name(i)))
+            .unwrap_or_default();
+        if items.is_empty() {
+            bare.to_string()
+        } else {
+            format!("{} {}.", holding, items.join(", "))
+        }
+    };
+    let text = match fixture {
+        Fixture::CardCase => {
+            let case_here = state.player.inventory.has(&Item::CardCase, 1)
+                || map
+                    .tile_at(&state.player.position)
+                    .map(|t| t.items.items.iter().any(|(i, q)| *i == Item::CardCase && *q > 0))
+                    .unwrap_or(false);
+            if !case_here {
+                return None;
+            }
+            let census = state.card_census(map);
+            let mut line = format!(
+                "{} {} of 52 cards inside, {} known to be loose.",
+                if state.card_case_open {
+                    "The card case sits open."
+                } else {
+                    "The card case is snapped shut."
+                },
+                census.in_case,
+                census.loose()
+            );
+            if census.lost > 0 {
+                line.push_str(&format!(" {} lost to the lake.", census.lost));
+            }
+            line
+        }
+        Fixture::Hearth => state.cabin_state()?.fireplace.description(),
+        Fixture::Mantel => {
+            let items = state.mantel_item_names();
+            if items.is_empty() {
+                "A narrow wooden mantelpiece above the hearth, bare for now.".to_string()
+            } else {
+                format!(
+                    "A narrow wooden mantelpiece above the hearth, holding: {}.",
+                    items.join(", ")
+                )
+            }
+        }
+        Fixture::Table => {
+            let items = state.table_item_names();
+            if items.is_empty() {
+                "A sturdy wooden table, surface clear.".to_string()
+            } else {
+                format!("A sturdy wooden table, holding: {}.", items.join(", "))
+            }
+        }
+        Fixture::CabinDoor => {
+            if state.cabin_state()?.door_open {
+                "The heavy plank door stands open on its iron hinges.".to_string()
+            } else {
+                "The heavy plank door is shut, its worn iron handle polished by use.".to_string()
+            }
+        }
+        Fixture::Windows => {
+            let cabin = state.cabin_state()?;
+            let glass = if cabin.windows_open {
+                "The windows are unlatched and pushed open"
+            } else {
+                "The windows are latched shut"
+            };
+            let shutters = if cabin.shutters_closed {
+                "the shutters closed over them"
+            } else {
+                "the shutters folded back"
+            };
+            format!("{}, {}.", glass, shutters)
+        }
+        Fixture::Shutters => {
+            if state.cabin_state()?.shutters_closed {
+                "The wooden shutters are swung closed and hooked fast.".to_string()
+            } else {
+                "The wooden shutters are folded back against the walls.".to_string()
+            }
+        }
+        Fixture::ShedDoor => {
+            if state.wood_shed_state()?.door_open {
+                "The shed door hangs open on its leather hinges.".to_string()
+            } else {
+                "The shed door is pulled to, its wooden latch dropped.".to_string()
+            }
+        }
+        Fixture::ChoppingBlock => {
+            let block = &state.wood_shed_state()?.chopping_block;
+            if block.has_log {
+                "A scarred oak round with a log stood up on it, ready for the axe.".to_string()
+            } else {
+                "A scarred oak round, its top bitten by a thousand axe strokes. Nothing on it now."
+                    .to_string()
+            }
+        }
+        Fixture::Workbench => surface_line(
+            WORKBENCH_ID,
+            "A rough workbench under the shed's one window, bare for now.",
+            "A rough workbench under the shed's one window, holding:",
+        ),
+        Fixture::Tree => return None,
+    };
+    Some(text)
+}
+
+/// A close look at the tree on the player's tile, or a neighbouring one
+/// named by direction ("apple tree to the north").
+fn examine_tree(normalized: &str, state: &GameState) -> Option<String> {
+    if !names_tree(normalized) {
+        return None;
+    }
+    let kind_word = |kind: TreeType| match kind {
+        TreeType::Pine => "pine",
+        TreeType::Birch => "birch",
+        TreeType::Apple => "apple",
+        TreeType::Bamboo => "bamboo",
+        TreeType::DatePalm => "palm",
+    };
+    let origin = state.player.position;
+    let compass = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ];
+    let words: Vec<&str> = normalized.split_whitespace().collect();
+    let named_dir = compass.into_iter().find(|d| words.contains(&d.name()));
+    let named_kind = ["pine", "birch", "apple", "bamboo", "palm"]
+        .into_iter()
+        .find(|w| words.contains(w));
+
+    // Your own tile first, then the four around it, unless a direction was given
+    let candidates: Vec<(Option<Direction>, Position)> = match named_dir {
+        Some(dir) => vec![(Some(dir), origin.move_in_direction(dir))],
+        None => std::iter::once((None, origin))
+            .chain(compass.into_iter().map(|d| (Some(d), origin.move_in_direction(d))))
+            .collect(),
+    };
+    let (dir, tree) = candidates.iter().find_map(|(dir, pos)| {
+        state
+            .objects
+            .objects_at(pos)
+            .into_iter()
+            .filter_map(|po| po.object.as_tree())
+            .find(|t| named_kind.map(|k| kind_word(t.kind) == k).unwrap_or(true))
+            .map(|t| (*dir, t))
+    })?;
+
+    let kind = match tree.kind {
+        TreeType::Apple => "apple tree",
+        TreeType::DatePalm => "date palm",
+        other => kind_word(other),
+    };
+    let place = dir
+        .map(|d| format!(" to the {}", d.name()))
+        .unwrap_or_default();
+
+    if tree.felled {
+        let text = match tree.kind {
+            TreeType::Bamboo => "Only cut stalks remain here, their hollow ends already greening over.".to_string(),
+            _ => format!(
+                "A {} stump{}, its cut face pale and ringed. Whatever grew here has already been taken for wood.",
+                kind, place
+            ),
+        };
+        return Some(text);
+    }
+
+    let mut lines = Vec::new();
+    if !place.is_empty() {
+        lines.push(format!("The {}{}:", kind, place));
+    }
+    lines.push(tree.description().to_string());
+    lines.extend(tree.stage_line().map(str::to_string));
+    let fruiting = matches!(tree.fruit_stage, FruitStage::Setting | FruitStage::Ripe);
+    if let Some(fruit) = tree.fruit_item().filter(|_| fruiting) {
+        let fruit_name = fruit.name();
+        let foliage = match tree.kind {
+            TreeType::DatePalm => "fronds",
+            _ => "leaves",
+        };
+        let observation = state.player.effective_skill(Skill::Observation);
+        let count = tree.fruit_count;
+        let fruit_line = if count == 0 {
+            format!("There are no {}s on its branches right now.", fruit_name)
+        } else if observation >= 20 {
+            format!(
+                "You count {} {}{} among the {}.",
+                count,
+                fruit_name,
+                if count == 1 { "" } else { "s" },
+                foliage
+            )
+        } else if count <= 2 {
+            let article = if fruit_name.starts_with(['a', 'e', 'i', 'o', 'u']) {
+                "An"
+            } else {
+                "A"
+            };
+            format!(
+                "{} {} or two hangs among the {}.",
+                article, fruit_name, foliage
+            )
+        } else {
+            format!("Plenty of {}s hang among the {}.", fruit_name, foliage)
+        };
+        lines.push(fruit_line);
+    }
+    if tree.tapped {
+        lines.push(
+            match tree.kind {
+                TreeType::Pine => "A tap cut low in the bark weeps amber resin.",
+                _ => "A tap cut low in the bark weeps clear sap.",
+            }
+            .to_string(),
+        );
+    }
+    if let Some(nest) = tree.nest() {
+        lines.push(nest.line().to_string());
+    }
+    if tree.hits_done > 0 {
+        lines.push(format!(
+            "Axe cuts score the trunk. {}",
+            tree.progress_text()
+        ));
+    }
+    Some(lines.join(" "))
+}
+
+/// Cut a tap into the standing pine or birch here and catch what runs.
+fn tap_tree(knife: Item, state: &mut GameState) -> InteractionResult {
+    let pos = state.player.position;
+    let Some(tree) = state.objects.find_tree_mut_at(&pos) else {
+        return InteractionResult::invalid("There's no tree here to tap.".to_string());
+    };
+    if !tree.can_be_tapped() {
+        return InteractionResult::invalid(
+            "Nothing worth catching would run from this one; only pines and birches give sap."
+                .to_string(),
+        );
+    }
+    if tree.tapped {
+        return InteractionResult::invalid(
+            "This tree is already tapped; the cut has given what it will for now.".to_string(),
+        );
+    }
+    tree.tapped = true;
+    state.player.inventory.add(Item::Sap, 1);
+    state.damage_tool(&knife, ToolWear::Light, "tapping a tree");
+    InteractionResult::ActionSuccess {
+        message: "You cut a shallow notch low in the bark and wait while it weeps, then scrape off a sticky lump of sap.".to_string(),
+        time_cost: 1,
+        energy_cost: 2.0,
+    }
+}
+
+/// Whether a phrase is about a tree and nothing else: "pine", "apple tree
+/// to the north", "stump". Whole words only, so "pinecone" or "birch bark"
+/// is left to whatever else it might name.
+fn names_tree(phrase: &str) -> bool {
+    const TREE_WORDS: &[&str] = &[
+        "tree", "trees", "trunk", "stump", "grove", "pine", "birch", "bamboo", "palm", "나무",
+    ];
+    const ALSO: &[&str] = &[
+        "apple", "date", "the", "a", "an", "to", "of", "on", "that", "this", "north", "south",
+        "east", "west",
+    ];
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    words.iter().any(|w| TREE_WORDS.contains(w))
+        && words
+            .iter()
+            .all(|w| TREE_WORDS.contains(w) || ALSO.contains(w))
+}
+
+/// Whether the duck is close enough to be talked to.
+pub fn duck_in_earshot(state: &GameState) -> bool {
+    let holding_duck = state.player.inventory.has(&Item::RubberDuck, 1);
+    let duck_on_table = state
+        .table_surface()
+        .map(|s| s.items.contains(&Item::RubberDuck))
+        .unwrap_or(false);
+    let duck_in_cabin = state
+        .cabin_state()
+        .map(|c| c.items.contains(&Item::RubberDuck) || c.table_items.contains(&Item::RubberDuck))
+        .unwrap_or(false);
+    let in_cabin = matches!(state.player.room, Some(Room::CabinMain));
+    // A duck riding along on your shoulder is always within earshot.
+    let duck_perched_on_you = state.player.mounts.has(&Item::RubberDuck);
+    let duck_on_mantel = state.duck_on_mantel();
+
+    holding_duck
+        || duck_perched_on_you
+        || (in_cabin && (duck_in_cabin || duck_on_table || duck_on_mantel))
+}
+
+pub fn talk_to_rubber_duck(
+    message: Option<&str>,
+    state: &GameState,
+    duck_name: &str,
+) -> InteractionResult {
+    if !duck_in_earshot(state) {
+        return InteractionResult::wrong_place("You need to be near the rubber duck.".to_string());
+    }
+    let mut rng = rand::thread_rng();
+    let opener = match message {
+        Some(msg) if !msg.trim().is_empty() => format!("You: \"{}\"\n", msg.trim()),
+        _ => "You address the rubber duck softly.\n".to_string(),
+    };
+    let middle = "The rubber duck seems lost in thought...";
+    let contemplation = random_duck_phrase(&mut rng);
+    let closer = format!("{}: ...", duck_name);
+    let reckoning = match state.recent_kill() {
+        Some(k) => format!(
+            "\nThe duck's painted eyes seem to rest on your hands a moment longer than usual, as if it knows about the {}.",
+            k.species.name()
+        ),
+        None => String::new(),
+    };
+    let regard = state.player.render(if state.player.connection >= 75.0 {
+        "The rubber duck regards {name} warmly, the way an old friend does."
+    } else {
+        "The rubber duck regards {name} with patience."
+    });
+    InteractionResult::Success(format!(
+        "{}{}\n{}{}\n{}\n{}",
+        opener, middle, contemplation, reckoning, regard, closer
+    ))
+}
+
+/// Who, if anyone, hears words spoken with no duck or companion about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoliloquyScene {
+    Fire,
+    Cave,
+    Open,
+}
+
+impl SoliloquyScene {
+    pub fn of(state: &GameState, map: &WorldMap) -> Self {
+        let hearth_lit = matches!(state.player.room, Some(Room::CabinMain))
+            && state
+                .cabin_state()
+                .map(|c| !matches!(c.fireplace.state, FireState::Cold))
+                .unwrap_or(false);
+        let campfire_here = state.player.room.is_none()
+            && map
+                .tile_at(&state.player.position)
+                .map(|t| {
+                    t.items
+                        .items
+                        .iter()
+                        .any(|(i, q)| *i == Item::Campfire && *q > 0)
+                })
+                .unwrap_or(false);
+        if hearth_lit || campfire_here {
+            SoliloquyScene::Fire
+        } else if state.in_cave() {
+            SoliloquyScene::Cave
+        } else {
+            SoliloquyScene::Open
+        }
+    }
+
+    /// How the words go in the journal.
+    fn journal_lead(&self) -> &'static str {
+        match self {
+            SoliloquyScene::Fire => "Said to the fire",
+            SoliloquyScene::Cave => "Said aloud in the cave",
+            SoliloquyScene::Open => "Said aloud to no one",
+        }
+    }
+}
+
+/// Talking with neither the duck nor a companion in earshot: to yourself,
+/// or to the fire. What's said goes in the journal, and the surroundings
+/// answer in their own way.
+pub fn talk_to_yourself(
+    message: Option<&str>,
+    state: &mut GameState,
+    map: &WorldMap,
+) -> InteractionResult {
+    use rand::seq::SliceRandom;
+    let mut rng = rand::thread_rng();
+    let scene = SoliloquyScene::of(state, map);
+    let said = message.map(str::trim).filter(|m| !m.is_empty());
+    let opener = match (said, scene) {
+        (Some(msg), SoliloquyScene::Fire) => format!("You, to the fire: \"{}\"", msg),
+        (Some(msg), _) => format!("You, to yourself: \"{}\"", msg),
+        (None, SoliloquyScene::Fire) => "You murmur at the flames for a while.".to_string(),
+        (None, _) => "You talk quietly to yourself for a while.".to_string(),
+    };
+    let pos = state.player.position;
+    let snowbound = state.player.room.is_none()
+        && (matches!(
+            state.weather.get_for_position(pos.row, pos.col),
+            Weather::LightSnow | Weather::HeavySnow | Weather::Blizzard
+        ) || map
+            .tile_at(&pos)
+            .map(|t| t.biome == Biome::WinterForest)
+            .unwrap_or(false));
+    let pool = match scene {
+        SoliloquyScene::Fire => FIRE_REPLIES,
+        SoliloquyScene::Cave => CAVE_REPLIES,
+        SoliloquyScene::Open if snowbound => SNOW_REPLIES,
+        SoliloquyScene::Open => OPEN_REPLIES,
+    };
+    let mut text = format!(
+        "{}\n{}",
+        opener,
+        pool.choose(&mut rng).unwrap_or(&"Nothing answers.")
+    );
+    if rng.gen_bool(0.35) {
+        if let Some(line) = MISSING_DUCK.choose(&mut rng) {
+            text.push(' ');
+            text.push_str(line);
+        }
+    }
+    if let Some(msg) = said {
+        state
+            .journal
+            .push(format!("{}: \"{}\"", scene.journal_lead(), msg));
+    }
+    state.player.modify_mood(SOLILOQUY_MOOD);
+    InteractionResult::Success(text)
+}
+
+pub fn talk_to_animal_companion(
+    message: Option<&str>,
+    state: &GameState,
+) -> Option<InteractionResult> {
+    let player_pos = state.player.position;
+
+    let mut nearest_index: Option<usize> = None;
+    let mut nearest_distance = f32::MAX;
+
+    for (idx, w) in state.wildlife.iter().enumerate() {
+        if !w.tamed {
+            continue;
+        }
+        if !matches!(w.species, Species::Dog | Species::Cat) {
+            continue;
+        }
+        let dist = player_pos.distance_to(&w.position);
+        if dist <= 4.0 && dist < nearest_distance {
+            nearest_distance = dist;
+            nearest_index = Some(idx);
+        }
+    }
+
+    let idx = nearest_index?;
+    let companion = &state.wildlife[idx];
+    let species_name = companion.species.name();
+    let display_name = companion.display_name();
+
+    let mut rng = rand::thread_rng();
+    let opener = match message {
+        Some(msg) if !msg.trim().is_empty() => {
+            format!("You, to your {}: \"{}\"\n", display_name, msg.trim())
+        }
+        _ => format!("You speak quietly to your {}.\n", display_name),
+    };
+
+    let reply_pool = match companion.species {
+        Species::Dog => DOG_REPLIES,
+        Species::Cat => CAT_REPLIES,
+        _ => DOG_REPLIES,
+    };
+    let reply = if reply_pool.is_empty() {
+        "It stays close, in its own thoughtful way."
+    } else {
+        let idx = rng.gen_range(0..reply_pool.len());
+        reply_pool[idx]
+    };
+
+    let closer = match companion.species {
+        Species::Dog => "The dog falls into step beside you, as if the conversation is enough.",
+        Species::Cat => "The cat settles where it can keep you in sight, gaze soft and steady.",
+        _ => "It remains near, a quiet presence.",
+    };
+
+    Some(InteractionResult::Success(format!(
+        "{}{}\n{}",
+        opener, reply, closer
+    )))
+}
+
+// --- NEW UNIVERSAL USE HANDLER ---
+
+pub fn try_use(
+    item_name: &str,
+    target_name: Option<&str>,
+    state: &mut GameState,
+    map: &mut WorldMap,
+) -> InteractionResult {
+    let item_query = item_name.trim();
+    let item_query_lower = item_query.to_lowercase();
+    let using_hands = matches!(
+        item_query_lower.as_str(),
+        "hand" | "hands" | "fist" | "fists" | "bare hands" | "bare-hands" | "barehand"
+    );
+
+    let target_normalized = target_name.as_ref().map(|s| s.to_lowercase());
+    let target_str = target_normalized.as_deref();
+
+    if using_hands {
+        if let Some(target) = target_str {
+            if target.contains("bush") || target.contains("shrub") || target.contains("ground") {
+                return handle_foraging(state, None, map);
+            }
+            if ["water", "lake", "shallows", "shore", "oasis", "pool"]
+                .iter()
+                .any(|w| target.contains(w))
+            {
+                return try_fish(state, map, Some("hands"));
+            }
+        }
+        return InteractionResult::invalid(
+            "Use what with your hands? Try 'use hands on bush' to forage, 'use hands on water' to grab at fish, or specify a tool and target."
+                .to_string(),
+        );
+    }
+
+    // "use materials on blueprint": fit whatever comes next, lightest first.
+    if matches!(
+        item_query_lower.as_str(),
+        "materials" | "material" | "blueprint" | "project"
+    ) {
+        let Some(bp) = &state.player.active_project else {
+            return InteractionResult::invalid(
+                "You don't have an active blueprint. Use 'create [item]' first.".to_string(),
+            );
+        };
+        let inventory = &state.player.inventory;
+        return match bp.choose_material(None, |i| inventory.count(i)) {
+            Some(material) => handle_blueprint_interaction(state, map, &material),
+            None => InteractionResult::invalid(format!(
+                "You aren't carrying anything the {} still needs. Progress: {}.",
+                bp.target_item.name(),
+                bp.progress_summary()
+            )),
+        };
+    }
+
+    let mut item = match state.resolve_item(item_query) {
+        Some(i) => i,
+        None => {
+            return InteractionResult::invalid(format!("You don't know what '{}' is.", item_name))
+        }
+    };
+
+    // Out of a blueprint material but carrying a stand-in the plans accept
+    if !state.player.inventory.has(&item, 1) {
+        if let Some(bp) = &state.player.active_project {
+            let for_project = target_str
+                .map(|t| {
+                    t.contains("blueprint")
+                        || t.contains("project")
+                        || bp.target_item.name().to_lowercase().contains(t)
+                })
+                .unwrap_or(true);
+            let inventory = &state.player.inventory;
+            if let Some(stand_in) = for_project
+                .then(|| bp.choose_material(Some(item), |i| inventory.count(i)))
+                .flatten()
+            {
+                item = stand_in;
+            }
+        }
+    }
+
+    let mut has_item = state.player.inventory.has(&item, 1);
+    if !has_item
+        && matches!(
+            item,
+            Item::Book
+                | Item::TutorialBook
+                | Item::OldBook
+                | Item::DeathNote
+                | Item::BookOfFishing
+                | Item::BlankBook
+        )
+        && matches!(state.player.room, Some(Room::CabinMain))
+    {
+        if let Some(cabin) = state.cabin_state() {
+            let id_match = state
+                .book_id_for_item(&item)
+                .map(|bid| cabin.book_ids.iter().any(|b| b == bid))
+                .unwrap_or(false);
+            has_item = cabin.items.contains(&item) || cabin.table_items.contains(&item) || id_match;
+        }
+    }
+    // A log for the chopping block can come straight off the shed's pile.
+    if !has_item
+        && item == Item::Log
+        && matches!(state.player.room, Some(Room::WoodShed))
+        && target_name.map(|t| t.to_lowercase().contains("block")).unwrap_or(false)
+    {
+        has_item = state.wood_shed_state().map(|w| w.logs > 0).unwrap_or(false);
+    }
+
+    if !has_item {
+        return InteractionResult::missing(
+            item,
+            format!("You don't have a {}.", state.display_name(&item)),
+        );
+    }
+    // Tools and lights come out of the pack to be used.
+    if item.is_handheld() && state.player.inventory.has(&item, 1) {
+        state.player.hands.take_up(item);
+    }
+
+    // First aid on your own body
+    if item == Item::Bandage {
+        return try_bandage(target_str, state);
+    }
+    if item == Item::Stick {
+        if let Some(part) = target_str.and_then(BodyPartKind::from_str) {
+            return try_splint(part, state);
+        }
+    }
+
+    if let Some(target) = target_str {
+        if item != Item::Raft {
+            if let Some(id) = state.storage_at_hand(target) {
+                return put_in_storage(item.name(), &id, state);
+            }
+        }
+        if let Some(placed) = place_on_surface(target, state, item) {
+            return placed;
+        }
+    }
+
+    if item == Item::CardCase {
+        return handle_card_case_use(state, map, target_str);
+    }
+
+    if item == Item::PlayingCard {
+        if let Some(target) = target_str {
+            if target.contains("case") || target.contains("card") {
+                if !state.player.inventory.has(&Item::CardCase, 1) {
+                    return InteractionResult::missing(
+                        Item::CardCase,
+                        "You need to be holding the card case to tuck this card away."
+                            .to_string(),
+                    );
+                }
+                if state.card_case_cards_inside >= 52 {
+                    return InteractionResult::invalid(
+                        "The card case is already full; you can't squeeze in another card."
+                            .to_string(),
+                    );
+                }
+                if !state.player.inventory.remove(&Item::PlayingCard, 1) {
+                    return InteractionResult::invalid(
+                        "You fumble and nearly drop the card; best try again more carefully."
+                            .to_string(),
+                    );
+                }
+                state.card_case_cards_inside =
+                    (state.card_case_cards_inside.saturating_add(1)).min(52);
+                return InteractionResult::Success(
+                    "You slide the card neatly back into the case."
+                        .to_string(),
+                );
+            }
+        }
+        return InteractionResult::invalid(
+            "You flick the card between your fingers. Maybe use it with the card case?"
+                .to_string(),
+        );
+    }
+
+    if matches!(
+        item,
+        Item::Book | Item::TutorialBook | Item::OldBook | Item::DeathNote | Item::BookOfFishing
+    ) {
+        return handle_book_use(state, map, &item, target_str);
+    }
+    if item == Item::BlankBook {
+        let lang = state.player.language;
+        return InteractionResult::invalid(format!(
+            "{} {}",
+            lang.pick("It's a blank book.", "아직 빈 책입니다."),
+            write_syntax(lang)
+        ));
+    }
+
+    // 1. Blueprint Interaction (Building)
+    let target_is_blueprint = target_str
+        .map(|t| t.contains("blueprint") || t.contains("project"))
+        .unwrap_or(false);
+    if target_is_blueprint {
+        return handle_blueprint_interaction(state, map, &item);
+    }
+    // Also check if target is the name of the blueprint item or if no target is given but material matches
+    if let Some(bp) = &state.player.active_project {
+        if target_str
+            .map(|t| bp.target_item.name().to_lowercase().contains(t))
+            .unwrap_or(false)
+            || (target_str.is_none() && bp.accepts(&item))
+        {
+            return handle_blueprint_interaction(state, map, &item);
+        }
+    }
+
+    // 2. Resource Gathering (Chopping, etc)
+    if let Some(target) = target_str {
+        if target.contains("block") {
+            if item == Item::Log {
+                return try_place_on_block(state);
+            }
+            if item == Item::Axe || item == Item::StoneAxe {
+                return try_split_on_block(state, &item);
+            }
+        }
+        if target.contains("bamboo") {
+            if item == Item::Axe || item == Item::StoneAxe {
+                return try_chop_tree(state, map, &item);
+            }
+        }
+        if target.contains("tree") || target.contains("wood") || target.contains("log") {
+            if item == Item::Axe || item == Item::StoneAxe {
+                return try_chop_tree(state, map, &item);
+            }
+        }
+        if target.contains("bush") || target.contains("shrub") || target.contains("ground") {
+            if matches!(
+                item,
+                Item::MuddyWater | Item::CleanWater | Item::WaterKettle | Item::Ash
+            ) {
+                return try_tend_brush(state, map, item);
+            }
+            return handle_foraging(state, Some(&item), map);
+        }
+    }
+
+    // 3. Processing (Crafting Materials)
+    if item == Item::Knife || item == Item::StoneKnife {
+        if let Some(target) = target_str {
+            // A stalk in the pack is split, not tapped
+            let stalk = target.contains("bamboo") && state.player.inventory.has(&Item::Bamboo, 1);
+            if names_tree(target) && !stalk {
+                return tap_tree(item, state);
+            }
+            if target.contains("log") {
+                if state.player.inventory.has(&Item::Log, 1) {
+                    state.player.inventory.remove(&Item::Log, 1);
+                    state.player.inventory.add(Item::Kindling, 4);
+                    state.player.skills.improve(Skill::Woodcutting, 2);
+                    state.damage_tool(&item, ToolWear::Light, "whittling wood");
+                    return InteractionResult::ActionSuccess {
+                        message: "You whittle the log down into a pile of fine kindling."
+                            .to_string(),
+                        time_cost: 2, // 20 mins
+                        energy_cost: 10.0,
+                    };
+                }
+            }
+            if target.contains("branch") || target.contains("stick") {
+                if state.player.inventory.has(&Item::Stick, 1) {
+                    state.player.inventory.remove(&Item::Stick, 1);
+                    state.player.inventory.add(Item::Kindling, 1);
+                    state.player.skills.improve(Skill::Woodcutting, 1);
+                    state.damage_tool(&item, ToolWear::Light, "carving a stick");
+                    return InteractionResult::ActionSuccess {
+                        message: "You shave the stick down into a curl of kindling.".to_string(),
+                        time_cost: 1,
+                        energy_cost: 2.0,
+                    };
+                }
+            }
+            if target.contains("reed") {
+                if state.player.inventory.count(&Item::Reeds) >= 2 {
+                    state.player.inventory.remove(&Item::Reeds, 2);
+                    state.player.inventory.add(Item::Cordage, 1);
+                    state.player.skills.improve(Skill::Tailoring, 2);
+                    state.damage_tool(&item, ToolWear::Light, "splitting reeds");
+                    return InteractionResult::ActionSuccess {
+                        message: "You split the reeds into strips and twist them into a length of cordage."
+                            .to_string(),
+                        time_cost: 1,
+                        energy_cost: 3.0,
+                    };
+                } else {
+                    return InteractionResult::missing(
+                        Item::Reeds,
+                        "You need a couple of reeds to twist into cordage.".to_string(),
+                    );
+                }
+            }
+            if target.contains("bamboo") {
+                if state.player.inventory.has(&Item::Bamboo, 1) {
+                    state.player.inventory.remove(&Item::Bamboo, 1);
+                    state.player.inventory.add(Item::Paper, 3);
+                    state.player.skills.improve(Skill::Tailoring, 2);
+                    state.damage_tool(&item, ToolWear::Light, "splitting bamboo");
+                    return InteractionResult::ActionSuccess {
+                        message: "You split the bamboo and press it into thin sheets of paper."
+                            .to_string(),
+                        time_cost: 2,
+                        energy_cost: 6.0,
+                    };
+                } else {
+                    return InteractionResult::missing(
+                        Item::Bamboo,
+                        "You need bamboo in your inventory to cut into paper.".to_string(),
+                    );
+                }
+            }
+        }
+    }
+
+    // 3a. The cave bear: feed it or fight it, from inside the cave
+    if let Some(target) = target_str {
+        if target.contains("bear") && state.in_cave() && state.cave_bear().is_some() {
+            if matches!(
+                item,
+                Item::Fish | Item::SmallFish | Item::Pupfish | Item::BigFish | Item::CookedFish
+            ) {
+                return try_offer_bear(state, item);
+            }
+            if matches!(
+                item,
+                Item::Axe | Item::StoneAxe | Item::Knife | Item::StoneKnife | Item::SharpStone
+            ) {
+                if state.player.energy < 5.0 {
+                    return InteractionResult::exhausted(
+                        "You are too exhausted to swing a weapon with any force right now."
+                            .to_string(),
+                    );
+                }
+                let base_damage = match item {
+                    Item::Axe => 32.0,
+                    Item::StoneAxe => 26.0,
+                    Item::Knife => 22.0,
+                    Item::StoneKnife => 18.0,
+                    _ => 14.0,
+                };
+                return try_fight_bear(state, item, base_damage);
+            }
+        }
+    }
+
+    // 3b. Combat: attack nearby wildlife with melee weapons
+    if matches!(
+        item,
+        Item::Axe | Item::StoneAxe | Item::Knife | Item::StoneKnife | Item::SharpStone
+    ) {
+        if let Some(target) = target_str {
+            let t = target.to_lowercase();
+            let looks_like_creature = t.contains("deer")
+                || t.contains("rabbit")
+                || t.contains("fox")
+                || t.contains("wolf")
+                || t.contains("hare")
+                || t.contains("boar")
+                || t.contains("pig")
+                || t.contains("animal")
+                || t.contains("creature");
+            if looks_like_creature {
+                if state.player.energy < 5.0 {
+                    return InteractionResult::exhausted(
+                        "You are too exhausted to swing a weapon with any force right now."
+                            .to_string(),
+                    );
+                }
+
+                let base_damage = match item {
+                    Item::Axe => 32.0,
+                    Item::StoneAxe => 26.0,
+                    Item::Knife => 22.0,
+                    Item::StoneKnife => 18.0,
+                    Item::SharpStone => 14.0,
+                    _ => 18.0,
+                };
+
+                if let Some(msg) =
+                    state.attack_nearby_wildlife(map, &item, base_damage, Some(target))
+                {
+                    state.damage_tool(&item, ToolWear::Light, "striking a creature");
+                    return InteractionResult::ActionSuccess {
+                        message: msg,
+                        time_cost: 1,
+                        energy_cost: 6.0,
+                    };
+                } else {
+                    return InteractionResult::invalid(
+                        "You don't see any such creature close enough to strike.".to_string(),
+                    );
+                }
+            }
+        }
+    }
+
+    // Shovel: dig out the ruin's sand-choked chambers
+    if item == Item::Shovel {
+        if state.in_ruin() {
+            return try_dig_ruin(state, &item);
+        }
+        if state.player.room.is_none()
+            && state.player.inside.is_none()
+            && state.sands.has_buried(&state.player.position)
+        {
+            let found = state.unearth_here(map);
+            state.damage_tool(&item, ToolWear::Light, "digging in the sand");
+            return InteractionResult::ActionSuccess {
+                message: format!(
+                    "A few shovelfuls and the drift gives up what it was hiding: {}.",
+                    found.join(", ")
+                ),
+                time_cost: 1,
+                energy_cost: 4.0,
+            };
+        }
+        return InteractionResult::invalid(
+            "There's nothing here worth the digging. The shovel is for the sand in the desert ruin, or wherever a storm has buried something."
+                .to_string(),
+        );
+    }
+
+    // Firefly jar: unstopper it and let them go
+    if item == Item::FireflyJar {
+        return release_fireflies(state);
+    }
+
+    // Raft: push it into the shallows, where it stays moored until boarded
+    if item == Item::Raft {
+        let pos = state.player.position;
+        if state.player.room.is_some() || state.player.inside.is_some() {
+            return InteractionResult::wrong_place(
+                "Get outside and down to the shore first.".to_string(),
+            );
+        }
+        if state.raft().is_some() {
+            return InteractionResult::invalid(
+                "One raft in the water is plenty to keep track of.".to_string(),
+            );
+        }
+        let water = [
+            state.player.facing,
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ]
+        .into_iter()
+        .map(|d| (d, pos.move_in_direction(d)))
+        .find(|(_, p)| map.is_open_water(p));
+        let Some((dir, mooring)) = water else {
+            return InteractionResult::wrong_place(
+                "Find a shoreline first; you need water to launch the raft.".to_string(),
+            );
+        };
+
+        state.player.inventory.remove(&Item::Raft, 1);
+        state.objects.add(
+            RAFT_ID,
+            mooring,
+            WorldObject::new(ObjectKind::Raft(Raft::default())),
+        );
+        let mut message = format!(
+            "You drag the raft down to the water's edge and push it out into the shallows to the {}, where it bobs against its mooring line. Board it to paddle out.",
+            dir.name()
+        );
+        if state.player.mounts.raft_prow == Some(Item::RubberDuck) {
+            message.push_str(" The duck on the prow surveys the lake with quiet approval.");
+        }
+        return InteractionResult::ActionSuccess {
+            message,
+            time_cost: 1,
+            energy_cost: 4.0,
+        };
+    }
+
+    // 3b. Butchering corpses into resources
+    if matches!(
+        item,
+        Item::Knife | Item::StoneKnife | Item::Axe | Item::StoneAxe | Item::SharpStone
+    ) {
+        if let Some(target) = target_str {
+            let t = target.to_lowercase();
+            if t.contains("corpse")
+                || t.contains("carcass")
+                || t.contains("body")
+                || t.contains("pig")
+                || t.contains("boar")
+            {
+                if state.player.energy < 5.0 {
+                    return InteractionResult::exhausted(
+                        "You are too tired to properly butcher anything right now.".to_string(),
+                    );
+                }
+
+                if let Some(msg) = state.butcher_corpse_at_player(&item) {
+                    state.damage_tool(&item, ToolWear::Light, "butchering a carcass");
+                    return InteractionResult::ActionSuccess {
+                        message: msg,
+                        time_cost: 2,
+                        energy_cost: 0.0,
+                    };
+                }
+
+                return InteractionResult::invalid(
+                    "You don't see a suitable carcass here to butcher.".to_string(),
+                );
+            }
+        }
+    }
+
+    // 3c. Feeding wildlife (dogs, cats, and others)
+    if matches!(
+        item,
+        Item::RawMeat
+            | Item::CookedMeat
+            | Item::Fish
+            | Item::SmallFish
+            | Item::Pupfish
+            | Item::BigFish
+            | Item::CookedFish
+    ) {
+        // Food held out to the fire is for cooking, not for an animal
+        if let Some(target) =
+            target_str.filter(|t| !t.contains("fire") && !t.contains("hearth"))
+        {
+            let t = target.to_lowercase();
+            let pos = state.player.position;
+            let mut same_tile_index: Option<usize> = None;
+            let mut best_index: Option<usize> = None;
+            let mut best_distance = f32::MAX;
+
+            for (idx, w) in state.wildlife.iter().enumerate() {
+                let species_name = w.species.name().to_lowercase();
+
+                let mut matches_target = species_name.contains(&t)
+                    || t.contains(&species_name)
+                    || t.contains("animal");
+
+                if !matches_target {
+                    if let Some(ref name) = w.name {
+                        let n = name.to_lowercase();
+                        if n.contains(&t) || t.contains(&n) {
+                            matches_target = true;
+                        }
+                    }
+                }
+
+                if !matches_target {
+                    continue;
+                }
+
+                let dist = pos.distance_to(&w.position);
+                if dist > 2.0 {
+                    continue;
+                }
+
+                if w.position == pos && same_tile_index.is_none() {
+                    same_tile_index = Some(idx);
+                }
+
+                if dist < best_distance {
+                    best_distance = dist;
+                    best_index = Some(idx);
+                }
+            }
+
+            let idx = if let Some(i) = same_tile_index {
+                Some(i)
+            } else {
+                best_index
+            };
+
+            let idx = match idx {
+                Some(i) => i,
+                None => {
+                    return InteractionResult::invalid(
+                        "You don't see any such animal close enough to feed.".to_string(),
+                    )
+                }
+            };
+
+            if !state.player.inventory.remove(&item, 1) {
+                return InteractionResult::invalid(
+                    "You don't have any food to offer right now.".to_string(),
+                );
+            }
+
+            if let Some(w) = state.wildlife.get_mut(idx) {
+                let is_companion = matches!(w.species, Species::Dog | Species::Cat);
+                if is_companion {
+                    w.tamed = true;
+                    state.player.modify_mood(4.0);
+                    state.player.modify_connection(1.0);
+                } else {
+                    state.player.modify_mood(1.0);
+                }
+
+                let label = w.display_name();
+                let message = if is_companion {
+                    format!(
+                        "You offer the {}. {} eats gratefully and falls into step beside you.",
+                        item.name(),
+                        label
+                    )
+                } else {
+                    format!(
+                        "You offer the {}. {} accepts the food for a moment, then drifts back to its own concerns.",
+                        item.name(),
+                        label
+                    )
+                };
+
+                return InteractionResult::ActionSuccess {
+                    message,
+                    time_cost: 1,
+                    energy_cost: 1.0,
+                };
+            }
+
+            return InteractionResult::invalid(
+                "Something about feeding that animal goes strangely wrong.".to_string(),
+            );
+        }
+    }
+
+    // 3c. Cooking simple foods on fire
+    if matches!(
+        item,
+        Item::Fish
+            | Item::SmallFish
+            | Item::Pupfish
+            | Item::BigFish
+            | Item::WildBerry
+            | Item::RawMeat
+            | Item::PineNuts
+            | Item::Acorn
+            | Item::BambooShoot
+    ) {
+        let in_cabin = matches!(state.player.room, Some(Room::CabinMain));
+        let fire_lit = state
+            .cabin_state()
+            .map(|c| !matches!(c.fireplace.state, FireState::Cold))
+            .unwrap_or(false);
+        if !in_cabin || !fire_lit {
+            return InteractionResult::wrong_place(
+                "You need to be by a lit fireplace to cook that right now.".to_string(),
+            );
+        }
+        state.player.step_to(CabinSpot::Hearth);
+
+        let severe = {
+            let pos = state.player.position;
+            let weather_here = state.weather.get_for_position(pos.row, pos.col);
+            matches!(
+                weather_here,
+                Weather::Blizzard | Weather::HeavySnow | Weather::HeavyRain | Weather::Sandstorm
+            )
+        };
+        let mut time_cost: u32 = 2;
+        let mut energy_cost = 4.0;
+        if severe {
+            time_cost += 1;
+            energy_cost += 2.0;
+        }
+
+        if matches!(
+            item,
+            Item::Fish | Item::SmallFish | Item::Pupfish | Item::BigFish | Item::RawMeat
+        ) {
+            if let Some(food) = &state.on_the_fire {
+                return InteractionResult::invalid(format!(
+                    "There's already {} grilling over the fire. Take it off first.",
+                    food.label()
+                ));
+            }
+            if !state.player.inventory.remove(&item, 1) {
+                return InteractionResult::invalid(
+                    "You don't have anything suitable to cook.".to_string(),
+                );
+            }
+            let (dish, portions, ready_at, text) = match item {
+                Item::RawMeat => (
+                    Item::CookedMeat,
+                    1,
+                    3.0,
+                    "You lay the meat on the grate over the fire. It starts to sizzle at once.",
+                ),
+                Item::BigFish => (
+                    Item::CookedFish,
+                    2,
+                    3.0,
+                    "You portion the large fish into hearty fillets and lay them over the fire.",
+                ),
+                _ => (
+                    Item::CookedFish,
+                    1,
+                    2.0,
+                    "You lay the fish on the grate over the fire, skin side down.",
+                ),
+            };
+            // A practiced cook has a feel for when it's right
+            let window = 1.0 + state.player.skills.get(Skill::Cooking) as f32 / 40.0;
+            state.on_the_fire = Some(OnTheFire {
+                raw: item,
+                dish,
+                portions,
+                heat: 0.0,
+                ready_at,
+                window,
+                cued: false,
+                scorch_cued: false,
+            });
+            return InteractionResult::ActionSuccess {
+                message: format!(
+                    "{} Keep an eye on it, and take it off the fire when it's ready.",
+                    text
+                ),
+                time_cost: time_cost - 1,
+                energy_cost,
+            };
+        } else {
+            let (needed, dish, too_few, text) = match item {
+                Item::PineNuts | Item::Acorn => (
+                    2,
+                    Item::RoastedNuts,
+                    "Gather at least a couple to make roasting worth it.",
+                    "You shake the nuts in the embers until their shells crack and they smell toasty.",
+                ),
+                Item::BambooShoot => (
+                    1,
+                    Item::RoastedShoot,
+                    "You need a bamboo shoot to roast.",
+                    "You roast the shoot in its sheath until the bitterness cooks out and it turns soft and sweet.",
+                ),
+                _ => (
+                    2,
+                    Item::CookedBerries,
+                    "Gather at least a couple of berries to roast.",
+                    "You roast the berries, caramelizing their juices.",
+                ),
+            };
+            if state.player.inventory.count(&item) < needed {
+                return InteractionResult::invalid(too_few.to_string());
+            }
+            let outcome = CookingOutcome::roll(state.player.skills.get(Skill::Cooking));
+            time_cost = time_cost.saturating_sub(outcome.time_saved).max(1);
+            state.player.inventory.remove(&item, needed);
+            state
+                .player
+                .inventory
+                .add(dish, 1 + u32::from(outcome.extra_portion));
+            state.record_dish(dish, 8);
+
+            let mut message = text.to_string();
+            outcome.apply(state, dish, &mut message);
+            return InteractionResult::ActionSuccess {
+                message,
+                time_cost,
+                energy_cost,
+            };
+        }
+    }
+
+    // Stone on water -> skip it
+    if item == Item::Stone {
+        if let Some(target) = target_str {
+            if target.contains("lake") || target.contains("water") || target.contains("skip") {
+                return try_lakeside_ritual("skip_stone", state, map);
+            }
+        }
+    }
+
+    // Stone on Stone -> Sharp Stone
+    if item == Item::Stone {
+        if let Some(target) = target_str {
+            if target.contains("stone") || target.contains("rock") {
+                if state.player.inventory.count(&Item::Stone) >= 2 {
+                    state.player.inventory.remove(&Item::Stone, 1);
+                    state.player.inventory.add(Item::SharpStone, 1);
+                    state.player.skills.improve(Skill::Stonemasonry, 5);
+                    return InteractionResult::ActionSuccess {
+                        message: "You smash the stones together, flaking off a razor-sharp edge."
+                            .to_string(),
+                        time_cost: 1,
+                        energy_cost: 5.0,
+                    };
+                } else {
+                    return InteractionResult::missing(
+                        Item::Stone,
+                        "You need another stone to knap against.".to_string(),
+                    );
+                }
+            }
+        }
+    }
+
+    // Flint on stone -> Sharp Stone, a keener edge for less work
+    if item == Item::Flint {
+        if let Some(target) = target_str {
+            if target.contains("stone") || target.contains("rock") {
+                if !state.player.inventory.has(&Item::Stone, 1) {
+                    return InteractionResult::missing(
+                        Item::Stone,
+                        "You need a stone to strike the flint against.".to_string(),
+                    );
+                }
+                state.player.inventory.remove(&Item::Flint, 1);
+                state.player.inventory.add(Item::SharpStone, 2);
+                state.player.skills.improve(Skill::Stonemasonry, 5);
+                return InteractionResult::ActionSuccess {
+                    message: "You strike the flint against the stone and it sheds two long, glassy flakes, each keen as a razor."
+                        .to_string(),
+                    time_cost: 1,
+                    energy_cost: 3.0,
+                };
+            }
+        }
+    }
+
+    // Paper on paper -> Blank Book
+    if item == Item::Paper {
+        if state.player.inventory.count(&Item::Paper) >= 5 {
+            state.player.inventory.remove(&Item::Paper, 5);
+            state.player.inventory.add(Item::BlankBook, 1);
+            state.player.skills.improve(Skill::Tailoring, 1);
+            return InteractionResult::ActionSuccess {
+                message: format!(
+                    "You fold and bind the sheets into a blank book. {}",
+                    write_syntax(state.player.language)
+                ),
+                time_cost: 2,
+                energy_cost: 3.0,
+            };
+        } else {
+            return InteractionResult::missing(
+                Item::Paper,
+                "You need at least 5 sheets of paper to bind a blank book.".to_string(),
+            );
+        }
+    }
+
+    // Kettle, tea, blanket and the mantelpiece curios
+    if let Some(result) = super::crafting::try_household_use(item, target_str, state, map) {
+        return result;
+    }
+
+    // Signal fire up on the lookout
+    let is_signal_target = target_str
+        .map(|t| t.contains("signal") || t.contains("beacon") || t.contains("pyre"))
+        .unwrap_or(false);
+    if item == Item::Matchbox && is_signal_target {
+        return try_light_signal_fire(state, map);
+    }
+
+    // A match to a piece of kindling makes a flame to carry
+    if item == Item::Matchbox && target_str.map(|t| t.contains("kindling")).unwrap_or(false) {
+        return light_kindling(state);
+    }
+
+    // 4. Fire Interaction
+    let is_fire_target = target_str
+        .map(|t| t.contains("fire") || t.contains("hearth"))
+        .unwrap_or(false);
+    let in_cabin = matches!(state.player.room, Some(Room::CabinMain));
+
+    if is_fire_target || (in_cabin && target_str.is_none()) {
+        if item.is_flammable() {
+            return handle_add_fuel(state, item);
+        }
+        if item == Item::Matchbox {
+            return handle_light_fire(state);
+        }
+    }
+
+    // 5. Consumption (Food/Drink)
+    if item.is_food() || item.is_drink() {
+        return handle_consumption(state, item);
+    }
+
+    let suggestions = super::suggest::suggest_uses(item, state, map);
+    if suggestions.is_empty() {
+        return InteractionResult::invalid(format!(
+            "You can't use the {} that way. Try patterns like: use axe on tree (gather), use knife on stick (process), or use log on blueprint (build). 'help use' lists them all.",
+            item.name()
+        ));
+    }
+    InteractionResult::invalid(format!(
+        "You can't use the {} that way. Try: {}.",
+        item.name(),
+        suggestions.join("; ")
+    ))
+}
+
+#[derive(Clone, Copy)]
+enum CabinSurface {
+    Table,
+    Mantel,
+}
+
+impl CabinSurface {
+    fn from_fixture(fixture: Fixture) -> Option<Self> {
+        match fixture {
+            Fixture::Mantel => Some(CabinSurface::Mantel),
+            Fixture::Table => Some(CabinSurface::Table),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            CabinSurface::Table => "table",
+            CabinSurface::Mantel => "mantelpiece",
+        }
+    }
+}
+
+/// Set `item` down on the surface `target` names, when it names nothing but
+/// the table or the mantelpiece. `None` leaves the phrase to other handlers.
+fn place_on_surface(target: &str, state: &mut GameState, item: Item) -> Option<InteractionResult> {
+    match resolve_fixture(target, state, Verb::Place) {
+        Resolved::Found {
+            fixture,
+            whole: true,
+        } => CabinSurface::from_fixture(fixture)
+            .map(|surface| handle_surface_placement(state, item, surface)),
+        Resolved::Ambiguous(options) => Some(InteractionResult::invalid(ambiguity_message(
+            &options,
+            Verb::Place,
+        ))),
+        _ => None,
+    }
+}
+
+/// Set an inventory item down on one of the cabin's surfaces.
+fn handle_surface_placement(
+    state: &mut GameState,
+    item: Item,
+    surface_kind: CabinSurface,
+) -> InteractionResult {
+    let surface_name = surface_kind.name();
+    if !matches!(state.player.room, Some(Room::CabinMain)) {
+        return InteractionResult::wrong_place(format!(
+            "The only {} worth the name is inside the cabin.",
+            surface_name
+        ));
+    }
+    if !state.player.inventory.has(&item, 1) {
+        return InteractionResult::missing(
+            item,
+            format!(
+                "You need to be holding the {} to set it on the {}.",
+                state.display_name(&item),
+                surface_name
+            ),
+        );
+    }
+    // The mantelpiece is a narrow ledge; only small things fit up there.
+    if matches!(surface_kind, CabinSurface::Mantel) && item.weight() > 2.0 {
+        return InteractionResult::invalid(format!(
+            "The {} is too bulky for the narrow mantelpiece.",
+            state.display_name(&item)
+        ));
+    }
+
+    let label = state.display_name(&item);
+    let surface = match surface_kind {
+        CabinSurface::Table => state.table_surface(),
+        CabinSurface::Mantel => state.mantel_surface(),
+    };
+    let Some(surface) = surface else {
+        return InteractionResult::invalid(format!("You can't find the {} here.", surface_name));
+    };
+    if item == Item::RubberDuck && !surface.supports_mounts {
+        return InteractionResult::invalid(format!(
+            "The {} has nowhere steady for the duck to perch.",
+            surface_name
+        ));
+    }
+    if surface
+        .capacity
+        .map(|cap| surface.items.total() as usize >= cap)
+        .unwrap_or(false)
+    {
+        return InteractionResult::invalid(format!(
+            "There's no room left on the {}. Take something off first.",
+            surface_name
+        ));
+    }
+
+    state.player.inventory.remove(&item, 1);
+    state.player.step_to(match surface_kind {
+        CabinSurface::Table => CabinSpot::Table,
+        CabinSurface::Mantel => CabinSpot::Hearth,
+    });
+    let dropped_book_id = state.on_player_drop(&item);
+    let placed = match surface_kind {
+        CabinSurface::Table => state.table_surface_mut(),
+        CabinSurface::Mantel => state.mantel_surface_mut(),
+    }
+    .map(|s| s.add_item(item))
+    .unwrap_or(false);
+    if !placed {
+        state.player.inventory.add(item, 1);
+        if let Some(id) = dropped_book_id {
+            state.add_player_book(&id);
+        }
+        return InteractionResult::invalid(format!(
+            "The {} slips from the {} back into your hands.",
+            label, surface_name
+        ));
+    }
+    if let Some(id) = dropped_book_id {
+        state.add_cabin_book(id);
+    }
+
+    let message = match surface_kind {
+        CabinSurface::Table if item == Item::RubberDuck => {
+            format!("You set the {} back in its place on the table, where it can keep an eye on things.", label)
+        }
+        CabinSurface::Table => format!("You place the {} carefully on the table.", label),
+        CabinSurface::Mantel => format!(
+            "You reach up and set the {} on the mantelpiece among the other curios.",
+            label
+        ),
+    };
+    InteractionResult::ItemLost(item, message)
+}
+
+fn handle_card_case_use(
+    state: &mut GameState,
+    map: &mut WorldMap,
+    target: Option<&str>,
+) -> InteractionResult {
+    let pos = state.player.position;
+    let Some((r, c)) = map.index_of(&pos) else {
+        return InteractionResult::invalid(
+            "You feel strangely ungrounded; the card case slips in your hands.".to_string(),
+        );
+    };
+
+    let Some(tile) = map.get_tile_mut(r, c) else {
+        return InteractionResult::invalid(
+            "You can't quite find space here to lay out cards.".to_string(),
+        );
+    };
+
+    let cards_on_ground = tile
+        .items
+        .items
+        .iter()
+        .find_map(|(item, qty)| {
+            if *item == Item::PlayingCard && *qty > 0 {
+                Some(*qty)
+            } else {
+                None
+            }
+        })
+        .unwrap_or(0);
+
+    let target_hint = target.unwrap_or("").to_lowercase();
+
+    // If the case is closed, using it mostly acts like flipping it open.
+    if !state.card_case_open && state.card_case_cards_inside > 0 && cards_on_ground == 0 {
+        state.card_case_open = true;
+        return InteractionResult::Success(
+            "You flick open the card case. A full deck rests inside, edges whispering together."
+                .to_string(),
+        );
+    }
+
+    // Open case with cards inside and clear ground -> lay them out
+    if state.card_case_open && state.card_case_cards_inside > 0 && cards_on_ground == 0 {
+        let inside = state.card_case_cards_inside.min(52);
+        state.card_case_cards_inside = state.card_case_cards_inside.saturating_sub(inside);
+        tile.items.add(Item::PlayingCard, inside as u32);
+        return InteractionResult::Success(format!(
+            "You slide {} cards out of the case and spread them across the ground.",
+            inside
+        ));
+    }
+
+    // Open case, cards on ground -> scoop back in up to full deck
+    if state.card_case_open && cards_on_ground > 0 {
+        let capacity_left = 52u8.saturating_sub(state.card_case_cards_inside);
+        if capacity_left == 0 {
+            return InteractionResult::invalid(
+                "The card case is already holding a full deck.".to_string(),
+            );
+        }
+
+        let mut moved: u8 = 0;
+        while moved < capacity_left && tile.items.take(&Item::PlayingCard) {
+            moved = moved.saturating_add(1);
+        }
+
+        if moved == 0 {
+            return InteractionResult::invalid(
+                "You don't see any cards here to scoop into the case.".to_string(),
+            );
+        }
+
+        state.card_case_cards_inside =
+            (state.card_case_cards_inside.saturating_add(moved)).min(52);
+
+        return InteractionResult::Success(format!(
+            "You gather {} cards from the ground back into the case.",
+            moved
+        ));
+    }
+
+    // Closed or empty case with no cards nearby
+    if !state.card_case_open && state.card_case_cards_inside == 0 && cards_on_ground == 0 {
+        return InteractionResult::invalid(
+            "The card case feels light and empty. There are no cards here to work with."
+                .to_string(),
+        );
+    }
+
+    // Fallback: if there are cards on the ground but the case is closed, hint to open it first
+    if !state.card_case_open && cards_on_ground > 0 {
+        let verb = if target_hint.contains("open") {
+            "Use 'open card case' first, then use the case again to scoop cards from the ground."
+        } else {
+            "Open the card case first, then use it again to scoop cards from the ground."
+        };
+        return InteractionResult::invalid(verb.to_string());
+    }
+
+    InteractionResult::invalid(
+        "You turn the card case over in your hands, but nothing interesting happens."
+            .to_string(),
+    )
+}
+
+/// The book a target names, with page-turning words left out: "book-1 next"
+/// and "My Journal (book-1)" both give the book; a bare "nextpage" gives none.
+fn parse_book_id_from_target(target: Option<&str>) -> Option<String> {
+    let target = target?.trim();
+    if target.is_empty() {
+        return None;
+    }
+    if let Some(start) = target.find('(') {
+        if let Some(end_rel) = target[start + 1..].find(')') {
+            return Some(target[start + 1..start + 1 + end_rel].trim().to_string());
+        }
+    }
+    let query: Vec<&str> = target
+        .split_whitespace()
+        .filter(|w| {
+            !matches!(
+                w.to_lowercase().as_str(),
+                "next" | "nextpage" | "prev" | "previous" | "prevpage" | "page" | "다음" | "이전"
+            )
+        })
+        .collect();
+    (!query.is_empty()).then(|| query.join(" "))
+}
+
+fn handle_book_use(
+    state: &mut GameState,
+    map: &mut WorldMap,
+    item: &Item,
+    target: Option<&str>,
+) -> InteractionResult {
+    let id_from_item = state.book_id_for_item(item).map(|s| s.to_string());
+    let mut accessible_ids = state.accessible_book_ids();
+    let book_id = if let Some(id) = id_from_item {
+        id
+    } else if let Some(query) = parse_book_id_from_target(target) {
+        state.find_book_id(&query).unwrap_or(query)
+    } else if accessible_ids.len() == 1 {
+        accessible_ids.pop().unwrap()
+    } else {
+        accessible_ids.sort();
+        let listing = if accessible_ids.is_empty() {
+            format!(
+                "{} {}",
+                state
+                    .player
+                    .language
+                    .pick("No books to hand yet.", "아직 가진 책이 없습니다."),
+                write_syntax(state.player.language)
+            )
+        } else {
+            format!(
+                "Specify which book to read. Available: {}",
+                accessible_ids.join(", ")
+            )
+        };
+        return InteractionResult::invalid(listing);
+    };
+
+    if !state.player_or_cabin_has_book(&book_id) {
+        return InteractionResult::invalid(
+            "You need to hold that book (or be next to it in the cabin).".to_string(),
+        );
+    }
+
+    let Some(book) = state.books.get(&book_id) else {
+        return InteractionResult::invalid("That book doesn't seem to exist.".to_string());
+    };
+    let title = book.title.clone();
+    let total_pages = book.pages.len();
+    let pages_copy = book.pages.clone();
+    let book_label = book.id.clone();
+
+    let start_page = state.book_page(&book_id);
+    let mut page = start_page;
+    if let Some(t) = target {
+        let lower = t.to_lowercase();
+        if lower.contains("next") || lower.contains("다음") {
+            page = page.saturating_add(1);
+        } else if lower.contains("prev") || lower.contains("이전") {
+            page = page.saturating_sub(1);
+        }
+    }
+
+    let max_page = book.pages.len();
+    if page > max_page {
+        page = max_page;
+    }
+    state.set_book_page(&book_id, page);
+    state.refresh_blueprint_knowledge(true);
+    state.grant_tutorial_reward_if_needed(map);
+
+    let message = if page == 0 {
+        format!(
+            "{} [{}] — cover page. Total pages: {}. Use 'use {} on nextpage' to turn pages.",
+            title,
+            book_label,
+            total_pages,
+            item.name()
+        )
+    } else {
+        let content = pages_copy
+            .get(page - 1)
+            .map(|s| s.as_str())
+            .unwrap_or("This page is blank.");
+        let mut text = format!("{} [{}] — Page {}: {}", title, book_label, page, content);
+        if let Some(line) = state.read_duck_letter(&book_id) {
+            text.push_str("\n\n");
+            text.push_str(&line);
+        }
+        text
+    };
+
+    // Turning pages is light work, and none at all curled up by the fire.
+    let energy_cost = if page == start_page || state.by_fire(map) {
+        0.0
+    } else {
+        0.5
+    };
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 0,
+        energy_cost,
+    }
+}
+
+/// How cooking skill shapes a dish: a tick saved at 20 and again at 40, a
+/// chance of an extra portion, and now and then a dish that comes out just right.
+pub struct CookingOutcome {
+    pub time_saved: u32,
+    pub extra_portion: bool,
+    pub perfect: bool,
+}
+
+impl CookingOutcome {
+    pub fn roll(skill: u8) -> Self {
+        let mut rng = rand::thread_rng();
+        let time_saved = match skill {
+            40.. => 2,
+            20.. => 1,
+            _ => 0,
+        };
+        let extra_chance = (skill.saturating_sub(10) as f64 / 200.0).min(0.25);
+        let perfect_chance = if skill >= 20 {
+            (skill as f64 / 100.0).min(0.6)
+        } else {
+            0.0
+        };
+        Self {
+            time_saved,
+            extra_portion: rng.gen_bool(extra_chance),
+            perfect: rng.gen_bool(perfect_chance),
+        }
+    }
+
+    pub fn apply(&self, state: &mut GameState, dish: Item, message: &mut String) {
+        if self.extra_portion {
+            message.push_str(" With a practiced hand you stretch it into an extra portion.");
+        }
+        if self.perfect {
+            state.player.modify_mood(3.0);
+            message.push(' ');
+            message.push_str(match dish {
+                Item::CookedFish => "It comes out perfectly flaky, golden at the edges.",
+                Item::CookedMeat => "It's seared outside and juicy right through.",
+                Item::CookedBerries => "The berries turn glossy and jammy, just shy of burnt.",
+                Item::RoastedNuts => "They're toasted right through, golden and crisp.",
+                Item::RoastedShoot => "It's tender to the core and faintly sweet.",
+                Item::HerbalTea => "The steep is exactly right, fragrant without a trace of bitterness.",
+                _ => "It turns out exactly as you hoped.",
+            });
+        }
+    }
+}
+
+/// Checks a blueprint's tool and site requirements against where the player
+/// stands now. On success returns the tools that will take the wear.
+fn check_build_site(
+    bp: &Blueprint,
+    state: &GameState,
+    map: &WorldMap,
+) -> Result<Vec<Item>, String> {
+    let target = bp.target_item.name();
+    let mut tools = Vec::new();
+    for need in bp.tools_needed() {
+        match need
+            .accepts()
+            .iter()
+            .find(|t| state.player.inventory.has(t, 1))
+        {
+            Some(tool) => tools.push(*tool),
+            None => {
+                return Err(format!(
+                    "You need {} on hand to make the {}.",
+                    need.name(),
+                    target
+                ))
+            }
+        }
+    }
+    if let Some(ws) = bp.workspace() {
+        let pos = state.player.position;
+        if state.player.room.is_some() || state.player.inside.is_some() {
+            return Err(format!(
+                "There's no room for that in here. The {} {}.",
+                target,
+                ws.describe()
+            ));
+        }
+        let ok = match ws {
+            Workspace::BesideWater => is_near_water(&pos, map),
+            Workspace::OpenGround => map
+                .tile_at(&pos)
+                .map(|t| t.biome != Biome::Path)
+                .unwrap_or(false),
+        };
+        if !ok {
+            let hint = match ws {
+                Workspace::BesideWater => "Carry the work down to the shore first.",
+                Workspace::OpenGround => "Step off the path onto open ground first.",
+            };
+            return Err(format!("The {} {}. {}", target, ws.describe(), hint));
+        }
+    }
+    Ok(tools)
+}
+
+fn handle_blueprint_interaction(
+    state: &mut GameState,
+    map: &WorldMap,
+    item: &Item,
+) -> InteractionResult {
+    // The last material is only fitted where the build can be finished.
+    if let Some(bp) = &state.player.active_project {
+        let mut preview = bp.clone();
+        if preview.add_material(*item) && preview.is_complete() {
+            if let Err(msg) = check_build_site(&preview, state, map) {
+                return InteractionResult::invalid(format!(
+                    "{} You keep the {} in hand for now.",
+                    msg,
+                    item.name()
+                ));
+            }
+        }
+    }
+
+    if let Some(bp) = &mut state.player.active_project {
+        if bp.add_material(item.clone()) {
+            state.player.inventory.remove(item, 1);
+            if !bp.is_complete() {
+                let progress = bp.progress_summary();
+                return InteractionResult::ActionSuccess {
+                    message: format!(
+                        "You add the {} to the {}. Progress: {}. Total build time: {} mins.",
+                        item.name(),
+                        bp.target_item.name(),
+                        progress,
+                        bp.time_cost
+                    ),
+                    time_cost: 1, // 10 mins per action
+                    energy_cost: 2.0,
+                };
+            }
+        } else {
+            return InteractionResult::invalid(format!(
+                "The {} doesn't need any (more) {}.",
+                bp.target_item.name(),
+                item.name()
+            ));
+        }
+    } else {
+        return InteractionResult::invalid(
+            "You don't have an active blueprint. Use 'create [item]' first.".to_string(),
+        );
+    }
+
+    if let Some(bp) = state.player.active_project.take() {
+        let tools = check_build_site(&bp, state, map).unwrap_or_default();
+        state.player.inventory.add(bp.target_item.clone(), 1);
+        let context = format!("making the {}", bp.target_item.name());
+        for tool in &tools {
+            state.damage_tool(tool, ToolWear::Light, &context);
+        }
+
+        // Skill gain based on item type
+        match bp.target_item {
+            Item::StoneKnife | Item::StoneAxe => {
+                state.player.skills.improve(Skill::Stonemasonry, 10)
+            }
+            Item::Campfire => state.player.skills.improve(Skill::Survival, 5),
+            Item::Cordage => state.player.skills.improve(Skill::Tailoring, 5),
+            Item::HideWrap => {
+                state.player.skills.improve(Skill::Tailoring, 8);
+                state.note_kill_hide_used();
+            }
+            _ => {}
+        }
+
+        let slug: String = bp
+            .target_item
+            .name()
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        state.milestone(format!("built-{}-day-{}", slug, state.time.day));
+
+        let time_cost = bp.time_cost.div_ceil(WorldTime::COST_MINUTES).max(1);
+        // Sore hands make the same work more tiring.
+        let hands = state.player.body.manipulation_factor();
+        let energy_cost = (time_cost as f32 * 2.0).max(5.0) * (2.0 - hands);
+
+        return InteractionResult::ActionSuccess {
+            message: format!(
+                "You finish crafting the {}. It is ready to use.",
+                bp.target_item.name()
+            ),
+            time_cost,
+            energy_cost,
+        };
+    }
+
+    InteractionResult::invalid("Something went wrong with the blueprint.".to_string())
+}
+
+fn handle_foraging(
+    state: &mut GameState,
+    tool: Option<&Item>,
+    map: &WorldMap,
+) -> InteractionResult {
+    let mut rng = rand::thread_rng();
+    let skill = state.player.effective_skill(Skill::Foraging);
+    let pos = state.player.position;
+
+    // Check energy
+    if state.player.energy < 5.0 {
+        return InteractionResult::exhausted("You are too exhausted to forage.".to_string());
+    }
+
+    let tool_bonus = matches!(
+        tool,
+        Some(Item::Knife | Item::StoneKnife | Item::Axe | Item::StoneAxe)
+    );
+    let success_chance =
+        (0.6 + (skill as f64 * 0.005) + if tool_bonus { 0.1 } else { 0.0 }).min(0.95);
+
+    // Local biome can tilt what we find
+    let biome = map
+        .index_of(&pos)
+        .and_then(|(r, c)| map.get_tile(r, c).map(|t| t.biome))
+        .unwrap_or(Biome::MixedForest);
+
+    // Forage node depletion
+    state.foraging_node_for(pos, map);
+    let depleted = state
+        .forage_nodes
+        .get(&pos)
+        .map(|n| n.charges == 0)
+        .unwrap_or(false);
+    if depleted {
+        return InteractionResult::invalid(
+            "The brush here is picked clean. Give it some time to recover.".to_string(),
+        );
+    }
+
+    // Drops
+    let drops = if rng.gen_bool(success_chance) {
+        let weather = state.weather.get_for_position(pos.row, pos.col);
+        let mut haul = roll_forage(biome, skill, tool_bonus, weather, &mut rng);
+
+        // Clay from the lakeshore, whatever grows there
+        if is_near_water(&pos, map) && rng.gen_bool(0.3) {
+            haul.items.push((Item::Clay, 1));
+        }
+        for (item, qty) in &haul.items {
+            state.player.inventory.add(*item, *qty);
+        }
+
+        state.player.skills.improve(Skill::Foraging, 1);
+        if let Some(node) = state.forage_nodes.get_mut(&pos) {
+            node.harvest();
+        }
+
+        if let Some(t) = tool {
+            state.damage_tool(t, ToolWear::Light, "foraging");
+        }
+
+        let mut message = if haul.has_food() {
+            "You rummage through the bushes and come away with something to eat and a handful of useful materials."
+                .to_string()
+        } else {
+            "You rummage through the brush and find useful materials.".to_string()
+        };
+        for note in haul.notes {
+            message.push(' ');
+            message.push_str(note);
+        }
+        InteractionResult::ActionSuccess {
+            message,
+            time_cost: 1, // 10 mins
+            energy_cost: 5.0,
+        }
+    } else {
+        // A fruitless search still drags the blade through the brush.
+        if let Some(t) = tool {
+            state.damage_tool(t, ToolWear::Light, "foraging");
+        }
+        InteractionResult::chance("You search for a while but find nothing useful.", 1, 3.0)
+    };
+    drops
+}
+
+/// Water a foraged patch through a dry spell, or work ash in around its
+/// roots, so it grows back faster for a day.
+fn try_tend_brush(state: &mut GameState, map: &WorldMap, item: Item) -> InteractionResult {
+    let pos = state.player.position;
+    if state.player.room.is_some() || state.player.inside.is_some() {
+        return InteractionResult::wrong_place(
+            "There's nothing growing in here to tend.".to_string(),
+        );
+    }
+    let watering = item != Item::Ash;
+    if watering {
+        let dry = map.biome_at(&pos) == Some(Biome::Desert)
+            || state.weather.get_for_position(pos.row, pos.col) == Weather::HeatWave;
+        if !dry {
+            return InteractionResult::invalid(
+                "The soil is damp enough already. Water would only run off; save it for a dry spell."
+                    .to_string(),
+            );
+        }
+    }
+    let Some(node) = state.forage_nodes.get_mut(&pos) else {
+        return InteractionResult::invalid(
+            "Nobody has foraged here. The brush is thriving without your help.".to_string(),
+        );
+    };
+    if node.tended > 0.0 {
+        return InteractionResult::invalid(
+            "You've already tended this patch. It has all the help it can use for now.".to_string(),
+        );
+    }
+    if !node.tend() {
+        return InteractionResult::invalid(
+            "This patch has grown back fully. It doesn't need your help.".to_string(),
+        );
+    }
+    state.player.inventory.remove(&item, 1);
+    if item == Item::WaterKettle {
+        state.player.inventory.add(Item::Kettle, 1);
+    }
+    state.player.modify_mood(2.0);
+    let message = if watering {
+        "You pour the water slowly around the roots and watch the dry earth drink it in. The patch should come back faster now."
+    } else {
+        "You work the ash into the soil around the roots with your fingers. The patch should come back faster now."
+    };
+    InteractionResult::ActionSuccess {
+        message: message.to_string(),
+        time_cost: 1,
+        energy_cost: 2.0,
+    }
+}
+
+/// Set a log up on the shed's chopping block, from the player's arms or the
+/// log pile.
+fn try_place_on_block(state: &mut GameState) -> InteractionResult {
+    if !matches!(state.player.room, Some(Room::WoodShed)) {
+        return InteractionResult::wrong_place(
+            "The chopping block is in the wood shed.".to_string(),
+        );
+    }
+    if state
+        .wood_shed_state()
+        .map(|w| w.chopping_block.has_log)
+        .unwrap_or(false)
+    {
+        return InteractionResult::invalid(
+            "There's already a log standing on the chopping block.".to_string(),
+        );
+    }
+
+    // Seasoned logs go up first; green only when that's all there is.
+    state.sync_carried_wood();
+    let (green, from_pile) = if state.player.inventory.has(&Item::Log, 1) {
+        let carried = state.player.inventory.count(&Item::Log);
+        let green = if state.carried_green_wood.count(&Item::Log) >= carried {
+            state.carried_green_wood.take(&Item::Log)
+        } else {
+            None
+        };
+        state.player.inventory.remove(&Item::Log, 1);
+        (green, false)
+    } else {
+        match state.wood_shed_state_mut().and_then(|w| w.take_wood(&Item::Log)) {
+            Some(green) => (green, true),
+            None => {
+                return InteractionResult::missing(
+                    Item::Log,
+                    "You have no log, and the pile is empty.".to_string(),
+                )
+            }
+        }
+    };
+    if let Some(wood_shed) = state.wood_shed_state_mut() {
+        wood_shed.chopping_block.has_log = true;
+        wood_shed.chopping_block.green = green;
+    }
+
+    let mut message = if from_pile {
+        "You pull a log from the pile and stand it on end on the chopping block.".to_string()
+    } else {
+        "You stand the log on end on the chopping block.".to_string()
+    };
+    if green.is_some() {
+        message.push_str(" It's green; the sap makes it heavy.");
+    }
+    message.push_str(" It's ready for the axe.");
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 1,
+        energy_cost: 2.0,
+    }
+}
+
+/// Swing at the log on the chopping block. A clean stroke splits it into
+/// firewood for the shed's stack, more pieces the better the woodcutter; a
+/// glancing one can send the blade into a leg or a hand.
+fn try_split_on_block(state: &mut GameState, tool: &Item) -> InteractionResult {
+    if !matches!(state.player.room, Some(Room::WoodShed)) {
+        return InteractionResult::wrong_place("Go to the wood shed to chop firewood.".to_string());
+    }
+    let Some(block) = state.wood_shed_state().map(|w| w.chopping_block.clone()) else {
+        return InteractionResult::invalid("The wood shed isn't available right now.".to_string());
+    };
+    if !block.has_log {
+        return InteractionResult::invalid(
+            "The chopping block is bare. Set a log on it first: use log on chopping block."
+                .to_string(),
+        );
+    }
+
+    // Every stroke rings out across the clearing
+    state.startle_wildlife(12.0, 5.0);
+
+    // A log standing still is an easier mark than a tree trunk.
+    let success_chance = (chop_success_chance(&state.player) + 0.1).min(0.98);
+    let mut rng = rand::thread_rng();
+    if !rng.gen_bool(success_chance as f64) {
+        let mut message =
+            "The axe catches the log's edge and glances off, spinning it half off the block."
+                .to_string();
+        if rng.gen_bool(0.2) {
+            let (part, wound) = if rng.gen_bool(0.5) {
+                (BodyPartKind::LegLeft, 8.0)
+            } else if rng.gen_bool(0.5) {
+                (BodyPartKind::ArmLeft, 6.0)
+            } else {
+                (BodyPartKind::ArmRight, 6.0)
+            };
+            state.player.injure_part(part, wound, true);
+            message.push_str(&format!(
+                " The blade follows through into your {}. It's bleeding.",
+                part.name()
+            ));
+        } else {
+            message.push_str(" You set it straight again.");
+        }
+        state.damage_tool(tool, ToolWear::Light, "splitting firewood");
+        return InteractionResult::chance(message, 1, 6.0);
+    }
+
+    let skill = state.player.skills.get(Skill::Woodcutting) as u32;
+    let pieces = 2 + (skill / 25).min(2);
+    let mut stacked = 0;
+    if let Some(wood_shed) = state.wood_shed_state_mut() {
+        wood_shed.chopping_block.has_log = false;
+        wood_shed.chopping_block.green = None;
+        wood_shed.firewood += pieces;
+        if let Some(ticks) = block.green {
+            wood_shed.green.add(Item::Firewood, pieces, ticks);
+        }
+        stacked = wood_shed.firewood;
+    }
+    state.player.skills.improve(Skill::Woodcutting, 2);
+
+    let stroke = match pieces {
+        2 => "The axe bites and the log splits in two",
+        3 => "A clean stroke, and the log falls apart in three pieces",
+        _ => "You read the grain and split the log into four neat pieces",
+    };
+    let mut message = format!(
+        "{}. You add them to the stack of firewood ({} now).",
+        stroke, stacked
+    );
+    if block.green.is_some() {
+        message.push_str(" They're green still and want time to dry.");
+    }
+    let result = InteractionResult::ActionSuccess {
+        message,
+        time_cost: 2,
+        energy_cost: 10.0,
+    };
+    state.damage_tool(tool, ToolWear::Heavy, "splitting firewood");
+    result
+}
+
+fn try_bandage(target: Option<&str>, state: &mut GameState) -> InteractionResult {
+    let part = match target {
+        Some(t) => match BodyPartKind::from_str(t) {
+            Some(p) => Some(p),
+            None => {
+                return InteractionResult::invalid(
+                    "Bandage which part? Try 'left arm', 'right leg', 'head' or 'torso'."
+                        .to_string(),
+                )
+            }
+        },
+        None => state.player.body.most_injured(),
+    };
+    let Some(kind) = part else {
+        return InteractionResult::invalid(
+            "You look yourself over. Nothing needs bandaging right now.".to_string(),
+        );
+    };
+    let Some(body_part) = state.player.body.part_mut(kind) else {
+        return InteractionResult::invalid("You can't bandage that.".to_string());
+    };
+    if body_part.has(PartCondition::Bandaged) {
+        return InteractionResult::invalid(format!(
+            "Your {} is already bandaged.",
+            kind.name()
+        ));
+    }
+    if body_part.ratio() >= 0.95 && !body_part.has(PartCondition::Bleeding) {
+        return InteractionResult::invalid(format!(
+            "Your {} doesn't need a bandage.",
+            kind.name()
+        ));
+    }
+    let was_bleeding = body_part.has(PartCondition::Bleeding);
+    body_part.clear_condition(PartCondition::Bleeding);
+    body_part.add_condition(PartCondition::Bandaged);
+    state.player.inventory.remove(&Item::Bandage, 1);
+    state.player.skills.improve(Skill::Survival, 2);
+    let message = if was_bleeding {
+        format!(
+            "You wrap your {} firmly. The bleeding slows, then stops under the herb-packed fiber.",
+            kind.name()
+        )
+    } else {
+        format!(
+            "You wrap your {} snugly. It already feels better supported.",
+            kind.name()
+        )
+    };
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 1,
+        energy_cost: 1.0,
+    }
+}
+
+fn try_splint(kind: BodyPartKind, state: &mut GameState) -> InteractionResult {
+    let Some(part) = state.player.body.part(kind) else {
+        return InteractionResult::invalid("You can't splint that.".to_string());
+    };
+    if !(part.movement || part.manipulation) {
+        return InteractionResult::invalid("Only an arm or a leg can take a splint.".to_string());
+    }
+    if part.has(PartCondition::Splinted) {
+        return InteractionResult::invalid(format!("Your {} is already splinted.", kind.name()));
+    }
+    if part.ratio() >= 0.5 {
+        return InteractionResult::invalid(format!(
+            "Your {} isn't hurt badly enough to need a splint.",
+            kind.name()
+        ));
+    }
+    if !state.player.inventory.has(&Item::Cordage, 1) {
+        return InteractionResult::missing(
+            Item::Cordage,
+            "You'll need cordage to lash the stick in place.".to_string(),
+        );
+    }
+    state.player.inventory.remove(&Item::Stick, 1);
+    state.player.inventory.remove(&Item::Cordage, 1);
+    if let Some(part) = state.player.body.part_mut(kind) {
+        part.add_condition(PartCondition::Splinted);
+    }
+    state.player.skills.improve(Skill::Survival, 3);
+    InteractionResult::ActionSuccess {
+        message: format!(
+            "You brace your {} against a straight stick and lash it tight. It aches, but it holds.",
+            kind.name()
+        ),
+        time_cost: 2,
+        energy_cost: 3.0,
+    }
+}
+
+/// Chance a swing at a standing tree bites true, from woodcutting skill and arm health.
+pub fn chop_success_chance(player: &Player) -> f32 {
+    let skill = player.skills.get(Skill::Woodcutting) as f32;
+    let arms = player.body.manipulation_factor();
+    ((0.75 + skill / 100.0) * (0.4 + arms * 0.6)).clamp(0.1, 0.98)
+}
+
+// Re-implement tree chopping with ActionSuccess
+/// Leave a fish for the woken bear. Fed, it never rises against the
+/// player again.
+fn try_offer_bear(state: &mut GameState, item: Item) -> InteractionResult {
+    let label = state.display_name(&item);
+    let Some(bear) = state.cave_bear_mut() else {
+        return InteractionResult::invalid("There's no bear here to feed.".to_string());
+    };
+    match bear.state {
+        BearState::Hibernating => {
+            return InteractionResult::invalid(
+                "It's fast asleep. Leaving food under its nose seems like a good way to wake it; better to keep the fish for when it stirs."
+                    .to_string(),
+            )
+        }
+        BearState::Pacified => {
+            return InteractionResult::invalid(
+                "The bear is already fed and content. It doesn't need more from you."
+                    .to_string(),
+            )
+        }
+        _ => {}
+    }
+    bear.state = BearState::Pacified;
+    bear.agitation = 0;
+    bear.fighting = false;
+    let _ = state.player.inventory.remove(&item, 1);
+    state.player.modify_mood(6.0);
+    state.journal.push(
+        "The bear in the east cave woke, and I gave it a fish instead of a fight. It ate, watched me for a long moment, and lay back down."
+            .to_string(),
+    );
+    InteractionResult::ActionSuccess {
+        message: format!(
+            "Slowly, keeping your eyes low, you toss the {} onto the stone between you. The bear's head swings to it. It sniffs, eats in three wet bites, and lets out a long, rumbling sigh before settling back into its bed. (Achievement unlocked: Peace Offering. Sometimes a fish says more than words.)",
+            label
+        ),
+        time_cost: 1,
+        energy_cost: 1.0,
+    }
+}
+
+/// Stand and fight the bear. Each blow comes back with interest; beat it
+/// down far enough and it abandons the cave.
+fn try_fight_bear(state: &mut GameState, weapon: Item, base_damage: f32) -> InteractionResult {
+    let mut rng = rand::thread_rng();
+    let label = state.display_name(&weapon);
+    let Some(bear) = state.cave_bear_mut() else {
+        return InteractionResult::invalid("There's nothing here to fight.".to_string());
+    };
+    if bear.state == BearState::Pacified {
+        return InteractionResult::invalid(
+            "The bear has made its peace with you. You can't bring yourself to break it."
+                .to_string(),
+        );
+    }
+    let woke = bear.state != BearState::Hostile;
+    bear.state = BearState::Hostile;
+    bear.agitation = bear.agitation.max(CaveBear::WAKE_AT);
+    bear.fighting = true;
+    let damage = base_damage * rng.gen_range(0.8..1.2);
+    bear.health = (bear.health - damage).max(0.0);
+    let driven_off = bear.health <= 0.0;
+    if driven_off {
+        bear.state = BearState::DrivenOff;
+        bear.fighting = false;
+    }
+
+    let mut message = if woke {
+        format!(
+            "You drive the {} into the sleeping bulk in the dark. It wakes with a roar that shakes grit from the ceiling.",
+            label
+        )
+    } else {
+        format!("You swing the {} and feel it bite into thick fur and muscle.", label)
+    };
+    state.damage_tool(&weapon, ToolWear::Heavy, "fighting the bear");
+
+    if driven_off {
+        message.push_str(" With a last bellow the bear shoulders past you, out of the cave and away through the trees. It doesn't look back. (Achievement unlocked: Stood Your Ground. Not everyone would call that wise.)");
+        state.journal.push(
+            "I fought the bear in the east cave and it fled. My hands won't stop shaking. The cave is mine now, for whatever that's worth."
+                .to_string(),
+        );
+        state.player.skills.improve(Skill::Survival, 3);
+    } else if rng.gen_bool(0.75) {
+        let parts = [
+            BodyPartKind::Head,
+            BodyPartKind::Torso,
+            BodyPartKind::ArmLeft,
+            BodyPartKind::ArmRight,
+            BodyPartKind::LegLeft,
+            BodyPartKind::LegRight,
+        ];
+        let part = parts[rng.gen_range(0..parts.len())];
+        let hurt = rng.gen_range(15.0..35.0);
+        state.player.injure_part(part, hurt, true);
+        message.push_str(&format!(
+            " The bear answers: a paw the size of your chest rakes your {}, and you taste blood.",
+            part.name()
+        ));
+    } else {
+        message.push_str(" Its counter-swipe whistles past your face as you stumble clear.");
+    }
+
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 1,
+        energy_cost: 12.0,
+    }
+}
+
+fn try_chop_tree(state: &mut GameState, map: &mut WorldMap, tool: &Item) -> InteractionResult {
+    let player_pos = state.player.position;
+    let day = state.time.day;
+    let Some(tree) = state.objects.find_tree_mut_at(&player_pos) else {
+        return InteractionResult::invalid(
+            "There isn't a standing tree right here to chop.".to_string(),
+        );
+    };
+    if tree.felled {
+        return InteractionResult::invalid("This tree has already been felled.".to_string());
+    }
+
+    // What fire left standing gives only charcoal
+    let charred = tree.is_charred();
+
+    if matches!(tree.kind, crate::entity::TreeType::Bamboo) {
+        tree.fell(day);
+        let (mut message, yield_) = if charred {
+            (
+                "The burnt stalks crumble under the blade into black flakes and a little charcoal."
+                    .to_string(),
+                (Item::Charcoal, 1),
+            )
+        } else {
+            (
+                "You slice through the bamboo. The stalks fall neatly.".to_string(),
+                (Item::Bamboo, 2),
+            )
+        };
+        if let Some(note) = state.land_at_feet(map, &[yield_], true) {
+            message.push(' ');
+            message.push_str(&note);
+        }
+        state.player.skills.improve(Skill::Woodcutting, 3);
+        let result = InteractionResult::ActionSuccess {
+            message,
+            time_cost: 2,
+            energy_cost: 10.0,
+        };
+        state.damage_tool(tool, ToolWear::Light, "cutting bamboo");
+        return result;
+    }
+
+    // Axe on trunk carries a long way; nothing nearby mistakes it for wind.
+    state.startle_wildlife(20.0, 6.0);
+
+    // Injured arms make for wild swings, and wild swings make for injured arms.
+    let success_chance = chop_success_chance(&state.player);
+    let mut rng = rand::thread_rng();
+    if !rng.gen_bool(success_chance as f64) {
+        let mut message =
+            "Your swing glances off the trunk, barely biting into the bark.".to_string();
+        if rng.gen_bool(0.15) {
+            let arm = if rng.gen_bool(0.5) {
+                BodyPartKind::ArmLeft
+            } else {
+                BodyPartKind::ArmRight
+            };
+            state.player.injure_part(arm, 6.0, true);
+            message.push_str(&format!(
+                " The blade skips and nicks your {}. It's bleeding.",
+                arm.name()
+            ));
+        }
+        state.damage_tool(tool, ToolWear::Light, "chopping a tree");
+        return InteractionResult::chance(message, 2, 8.0);
+    }
+
+    let Some(tree) = state.objects.find_tree_mut_at(&player_pos) else {
+        return InteractionResult::invalid(
+            "There isn't a standing tree right here to chop.".to_string(),
+        );
+    };
+    tree.fell(day);
+    let (mut message, yields): (String, &[(Item, u32)]) = if charred {
+        (
+            "The charred trunk gives way in a shower of black flakes. There's no timber left in it, but you knock loose some charcoal.".to_string(),
+            &[(Item::Charcoal, 2)],
+        )
+    } else {
+        (
+            "You fell a tree! Timber!".to_string(),
+            &[(Item::Log, 2), (Item::Kindling, 1), (Item::Bark, 1)],
+        )
+    };
+    let logs_before = state.player.inventory.count(&Item::Log);
+    if let Some(note) = state.land_at_feet(map, yields, true) {
+        message.push(' ');
+        message.push_str(&note);
+    }
+    let fresh_logs = state.player.inventory.count(&Item::Log) - logs_before;
+    state.carried_green_wood.add(Item::Log, fresh_logs, 0);
+    state.player.skills.improve(Skill::Woodcutting, 5);
+    state.note_practice(Practice::FelledTree);
+
+    let result = InteractionResult::ActionSuccess {
+        message,
+        time_cost: 6, // 1 hour
+        energy_cost: 20.0,
+    };
+    state.damage_tool(tool, ToolWear::Felling, "chopping a tree");
+    result
+}
+
+fn handle_add_fuel(state: &mut GameState, item: Item) -> InteractionResult {
+    let label = state.display_name(&item);
+    state.sync_carried_wood();
+    // Seasoned wood goes on first; green only when nothing drier is at hand.
+    let green =
+        state.carried_seasoned(&item) == 0 && state.carried_green_wood.take(&item).is_some();
+    state.player.inventory.remove(&item, 1);
+    state.player.step_to(CabinSpot::Hearth);
+    if let Some(cabin) = state.cabin_state_mut() {
+        let was_cold = cabin.fireplace.state == FireState::Cold;
+        let placed = if green {
+            cabin.fireplace.add_green_fuel_item(item)
+        } else {
+            cabin.fireplace.add_fuel_item(item)
+        };
+        if let Some(stage) = placed {
+            let note = cabin.fireplace.placement_note(stage);
+            state.player.skills.improve(Skill::FireMaking, 1);
+            let time_cost = if matches!(item, Item::Log | Item::Firewood) {
+                2
+            } else {
+                1
+            };
+            let energy_cost = if matches!(item, Item::Log | Item::Firewood) {
+                3.0
+            } else {
+                1.0
+            };
+            let mut message = if was_cold {
+                format!("You lay {} in the cold hearth.", label)
+            } else {
+                format!("You add {} to the fire.", label)
+            };
+            if green && !was_cold {
+                message.push_str(
+                    " It's still green: it hisses and spits sap, pushing out more smoke than heat.",
+                );
+            } else {
+                message.push(' ');
+                message.push_str(note);
+            }
+            return InteractionResult::ActionSuccess {
+                message,
+                time_cost,
+                energy_cost,
+            };
+        }
+    }
+    state.player.inventory.add(item, 1);
+    InteractionResult::invalid("It won't burn.".to_string())
+}
+
+/// Stoke, bank, or put out the cabin fire.
+pub fn try_tend_fire(mode: &str, state: &mut GameState) -> InteractionResult {
+    let putting_out = ["extinguish", "douse", "put out", "beat"]
+        .iter()
+        .any(|w| mode.to_lowercase().contains(w));
+    if putting_out && state.player.room.is_none() && state.player.inside.is_none() {
+        if let Some(pos) = state.wildfire.nearest(&state.player.position, 1.5) {
+            return fight_wildfire(state, pos);
+        }
+    }
+    if !matches!(state.player.room, Some(Room::CabinMain)) {
+        return InteractionResult::wrong_place(
+            "You need to be at the cabin hearth to tend the fire.".to_string(),
+        );
+    }
+    state.player.step_to(CabinSpot::Hearth);
+    let mode = mode.trim().to_lowercase();
+
+    if mode.contains("stoke") || mode.contains("poke") || mode.contains("rake") {
+        let Some(cabin) = state.cabin_state_mut() else {
+            return InteractionResult::invalid("There's no hearth here.".to_string());
+        };
+        return match cabin.fireplace.stoke() {
+            Ok(message) => {
+                state.player.skills.improve(Skill::FireMaking, 1);
+                InteractionResult::ActionSuccess {
+                    message,
+                    time_cost: 1,
+                    energy_cost: 2.0,
+                }
+            }
+            Err(msg) => InteractionResult::invalid(msg),
+        };
+    }
+
+    if mode.contains("bank") {
+        let Some(cabin) = state.cabin_state_mut() else {
+            return InteractionResult::invalid("There's no hearth here.".to_string());
+        };
+        return match cabin.fireplace.bank() {
+            Ok(message) => InteractionResult::ActionSuccess {
+                message,
+                time_cost: 1,
+                energy_cost: 1.0,
+            },
+            Err(msg) => InteractionResult::invalid(msg),
+        };
+    }
+
+    if mode.contains("extinguish") || mode.contains("douse") || mode.contains("put out") {
+        let lit = state
+            .cabin_state()
+            .map(|c| c.fireplace.state != FireState::Cold)
+            .unwrap_or(false);
+        if !lit {
+            return InteractionResult::invalid("The hearth is already cold.".to_string());
+        }
+
+        // Any water will do; a full kettle goes back to being an empty one.
+        let water = [
+            Item::MuddyWater,
+            Item::CleanWater,
+            Item::WaterKettle,
+            Item::HotWaterKettle,
+        ]
+        .into_iter()
+        .find(|w| state.player.inventory.has(w, 1));
+        let Some(water) = water else {
+            return InteractionResult::missing(
+                Item::WaterKettle,
+                "You'll need some water to douse the fire. A filled kettle would do.".to_string(),
+            );
+        };
+        state.player.inventory.remove(&water, 1);
+        if matches!(water, Item::WaterKettle | Item::HotWaterKettle) {
+            state.player.inventory.add(Item::Kettle, 1);
+        }
+
+        let charcoal = state
+            .cabin_state_mut()
+            .map(|c| c.fireplace.extinguish())
+            .unwrap_or(0);
+        let mut message =
+            "You pour the water over the hearth. The fire dies with an angry hiss and a cloud of steam."
+                .to_string();
+        if charcoal > 0 {
+            let mut stashed = 0;
+            for _ in 0..charcoal {
+                if !state.player.inventory.add(Item::Charcoal, 1) {
+                    if let Some(cabin) = state.cabin_state_mut() {
+                        cabin.add_item(Item::Charcoal);
+                    }
+                    stashed += 1;
+                }
+            }
+            message.push_str(&format!(
+                " You rake {} lump(s) of charcoal out of the wet ash",
+                charcoal
+            ));
+            if stashed > 0 {
+                message.push_str(", leaving some on the hearthstones");
+            }
+            message.push('.');
+        }
+        return InteractionResult::ActionSuccess {
+            message,
+            time_cost: 1,
+            energy_cost: 1.0,
+        };
+    }
+
+    InteractionResult::invalid(
+        "You can stoke, bank, or extinguish the fire.".to_string(),
+    )
+}
+
+/// Light a signal fire set down on the lookout. It only counts toward
+/// rescue under a clear sky, once per day.
+fn try_light_signal_fire(state: &mut GameState, map: &mut WorldMap) -> InteractionResult {
+    if state.player.room.is_some() || state.player.inside.is_some() {
+        return InteractionResult::wrong_place(
+            "A signal fire belongs out in the open, not under a roof.".to_string(),
+        );
+    }
+    let pos = state.player.position;
+    let Some((r, c)) = map.index_of(&pos) else {
+        return InteractionResult::invalid("There's nowhere to light it here.".to_string());
+    };
+    let on_ground = map
+        .get_tile(r, c)
+        .map(|t| t.items.items.iter().any(|(i, q)| *i == Item::SignalFire && *q > 0))
+        .unwrap_or(false);
+    if !on_ground {
+        let hint = if state.player.inventory.has(&Item::SignalFire, 1) {
+            " Set it down first ('drop signal fire')."
+        } else {
+            ""
+        };
+        return InteractionResult::invalid(format!(
+            "There's no signal fire built here.{}",
+            hint
+        ));
+    }
+    let lookout = map.highest_point();
+    if pos != lookout {
+        return InteractionResult::wrong_place(format!(
+            "Down here the smoke would be lost among the trees. Carry the signal fire up to the rocky knoll, {}.",
+            crate::descriptions::distance_phrase(&pos, &lookout)
+        ));
+    }
+    if state
+        .objects
+        .find_tree_at(&pos)
+        .map(|t| !t.felled)
+        .unwrap_or(false)
+    {
+        return InteractionResult::invalid(
+            "The tree standing here would swallow the smoke. Clear it first.".to_string(),
+        );
+    }
+    let sky = state.weather.get_for_position(pos.row, pos.col);
+    if sky != Weather::Clear {
+        return InteractionResult::invalid(format!(
+            "Under {} skies no one would see the smoke. Wait for a clear day.",
+            sky.name().to_lowercase()
+        ));
+    }
+    if state.rescue.signal_days.contains(&state.time.day) {
+        return InteractionResult::invalid(
+            "You've already sent smoke up today. Anyone watching would need another day to be sure."
+                .to_string(),
+        );
+    }
+
+    if let Some(tile) = map.get_tile_mut(r, c) {
+        tile.items.take(&Item::SignalFire);
+    }
+    state.player.skills.improve(Skill::FireMaking, 3);
+    let mut message = "You strike a match into the green boughs. The pyre catches with a roar, and a thick grey column of smoke climbs straight up into the clear sky, higher than anything else for miles.".to_string();
+    if !state.rescue.active {
+        message.push_str(" It's a fine sight, even if you aren't waiting on anyone.");
+        return InteractionResult::ActionSuccess {
+            message,
+            time_cost: 6,
+            energy_cost: 4.0,
+        };
+    }
+    match state.record_signal_fire() {
+        Some(count) if count >= SIGNAL_DAYS_NEEDED => {
+            message.push_str(concat!(
+                "\n\nLate in the afternoon, as the pyre burns down to embers, an answer comes: a thin line of smoke far off beyond the trees, then another, closer.",
+                "\n\nBy dusk you can hear voices calling through the forest, and the crack of boots on dry branches. Lanterns bob between the trunks. They saw your fire, on all three days.",
+                "\n\nYou look back at the knoll, the lake, the cabin roof among the trees. (A last chapter has been written in your journal.)",
+                "\n\nThe choice is yours: use 'rescue' with choice 'leave' and confirm true to go home and end this stay, or choice 'stay' to send them off with thanks and remain."
+            ));
+        }
+        Some(count) => message.push_str(&format!(
+            " Someone, somewhere, might see it. ({}/{} clear days.)",
+            count, SIGNAL_DAYS_NEEDED
+        )),
+        None => {}
+    }
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 6,
+        energy_cost: 4.0,
+    }
+}
+
+/// Put a match to a piece of kindling to carry the flame somewhere. It
+/// doesn't last long, and dropped outdoors it can take the undergrowth.
+fn light_kindling(state: &mut GameState) -> InteractionResult {
+    if !state.player.inventory.has(&Item::Kindling, 1) {
+        return InteractionResult::missing(
+            Item::Kindling,
+            "You need a piece of kindling to put the match to.".to_string(),
+        );
+    }
+    let pos = state.player.position;
+    let outdoors = state.player.room.is_none() && state.player.inside.is_none();
+    if outdoors
+        && state
+            .weather
+            .get_for_position(pos.row, pos.col)
+            .ignition_chance()
+            == 0.0
+    {
+        return InteractionResult::chance(
+            "The wet snuffs the match before the kindling takes.".to_string(),
+            1,
+            0.5,
+        );
+    }
+    state.player.inventory.remove(&Item::Kindling, 1);
+    if !state.player.inventory.add(Item::LitKindling, 1) {
+        state.player.inventory.add(Item::Kindling, 1);
+        return InteractionResult::invalid("You've no free hand to hold a flame.".to_string());
+    }
+    state.player.hands.take_up(Item::LitKindling);
+    if state.wildfire.kindling_ticks == 0 {
+        state.wildfire.kindling_ticks = LIT_KINDLING_TICKS;
+    }
+    InteractionResult::ActionSuccess {
+        message: "You strike a match and the kindling catches, a small flame cupped in your hand. It won't last long: feed it to a fire, and mind where you drop it.".to_string(),
+        time_cost: 1,
+        energy_cost: 0.5,
+    }
+}
+
+/// Beat out or douse the burning ground at `pos`, on or next to the
+/// player's tile.
+fn fight_wildfire(state: &mut GameState, pos: Position) -> InteractionResult {
+    let player_pos = state.player.position;
+    let place = if pos == player_pos {
+        "at your feet".to_string()
+    } else {
+        format!("to the {}", player_pos.bearing_to(&pos))
+    };
+    let water = [
+        Item::MuddyWater,
+        Item::CleanWater,
+        Item::WaterKettle,
+        Item::HotWaterKettle,
+    ]
+    .into_iter()
+    .find(|w| state.player.inventory.has(w, 1));
+    if let Some(water) = water {
+        state.player.inventory.remove(&water, 1);
+        if matches!(water, Item::WaterKettle | Item::HotWaterKettle) {
+            state.player.inventory.add(Item::Kettle, 1);
+        }
+        state.wildfire.douse(&pos);
+        return InteractionResult::ActionSuccess {
+            message: format!(
+                "You throw the water over the flames {}. They die back in a hiss of steam, leaving black, smoking ground.",
+                place
+            ),
+            time_cost: 1,
+            energy_cost: 2.0,
+        };
+    }
+
+    if !rand::thread_rng().gen_bool(0.6) {
+        return InteractionResult::chance(
+            format!(
+                "You beat at the flames {} with a leafy branch, but they leap up again the moment you stop.",
+                place
+            ),
+            2,
+            10.0,
+        );
+    }
+    state.wildfire.douse(&pos);
+    InteractionResult::ActionSuccess {
+        message: format!(
+            "You beat at the flames {} with a leafy branch until they smother into smoke. Your arms ache.",
+            place
+        ),
+        time_cost: 2,
+        energy_cost: 10.0,
+    }
+}
+
+fn handle_light_fire(state: &mut GameState) -> InteractionResult {
+    state.player.step_to(CabinSpot::Hearth);
+    // Gusts coming down the chimney can snuff a match before it catches
+    let blowout = match state.weather.wind.strength {
+        WindStrength::Gale => 0.3,
+        WindStrength::Strong => 0.1,
+        _ => 0.0,
+    };
+    let laid = state
+        .cabin_state()
+        .map(|c| c.fireplace.is_laid())
+        .unwrap_or(false);
+    if laid && blowout > 0.0 && rand::thread_rng().gen_bool(blowout) {
+        return InteractionResult::ActionSuccess {
+            message: "A gust moans down the chimney and snuffs the match before the tinder catches. Try again.".to_string(),
+            time_cost: 1,
+            energy_cost: 0.5,
+        };
+    }
+    if let Some(cabin) = state.cabin_state_mut() {
+        return match cabin.fireplace.ignite() {
+            Ok(()) => {
+                let message = if cabin.fireplace.fuel > 0.0 {
+                    "You strike a match. The tinder catches, and flame creeps up into the kindling under the wood."
+                } else {
+                    "You strike a match. The tinder catches, and flame creeps up into the kindling. It'll need wood on it soon."
+                };
+                state.player.skills.improve(Skill::FireMaking, 2);
+                state.note_practice(Practice::LitFire);
+                InteractionResult::ActionSuccess {
+                    message: message.to_string(),
+                    time_cost: 1,
+                    energy_cost: 1.0,
+                }
+            }
+            Err(msg) => InteractionResult::invalid(msg),
+        };
+    }
+    InteractionResult::invalid("There's no hearth here.".to_string())
+}
+
+fn handle_consumption(state: &mut GameState, item: Item) -> InteractionResult {
+    let label = state.display_name(&item);
+    let held = state.player.inventory.count(&item);
+    state.cooked_portions.trim(&item, held);
+    let doneness = state.cooked_portions.take(&item);
+    state.player.inventory.remove(&item, 1);
+    let mut message = match item.nourishment() {
+        Some(nourishment) => {
+            nourishment.apply(&mut state.player);
+            nourishment.message.to_string()
+        }
+        None => format!("You consume the {}.", label),
+    };
+    if let (Some(doneness), Some(nourishment)) = (doneness, item.nourishment()) {
+        message.push(' ');
+        message.push_str(eat_cooked_portion(state, doneness, nourishment.fullness));
+    }
+
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 1,
+        energy_cost: 0.0,
+    }
+}
+
+/// What a grilled portion does beyond the dish itself: less for one pulled
+/// too soon, with a chance it disagrees with you; more for one pulled just
+/// right; a bitter mouthful for one left too long.
+fn eat_cooked_portion(state: &mut GameState, doneness: Doneness, fullness: f32) -> &'static str {
+    match doneness {
+        Doneness::Undercooked => {
+            state.player.modify_fullness(-fullness * 0.4);
+            if rand::thread_rng().gen_bool(0.25) {
+                state.player.modify_health(-2.0);
+                state.player.modify_mood(-3.0);
+                "Halfway through, your stomach turns. The middle wasn't cooked through."
+            } else {
+                "It's underdone in the middle, and doesn't go as far as it should."
+            }
+        }
+        Doneness::Perfect => {
+            state.player.modify_fullness(fullness * 0.25);
+            state.player.modify_mood(1.0);
+            "Cooked just right, it fills you better than you expected."
+        }
+        Doneness::Charred => {
+            state.player.modify_mood(-3.0);
+            "The char is bitter on your tongue, though it fills you all the same."
+        }
+    }
+}
+
+/// Split a leading count off an item phrase: "3 berries" -> (3, "berries").
+fn split_quantity(phrase: &str) -> (Option<u32>, &str) {
+    let phrase = phrase.trim();
+    match phrase.split_once(char::is_whitespace) {
+        Some((n, rest)) => match n.parse::<u32>() {
+            Ok(n) => (Some(n), rest.trim()),
+            Err(_) => (None, phrase),
+        },
+        None => (None, phrase),
+    }
+}
+
+/// Resolve an item name, also accepting plain plurals ("apples", "berries").
+fn resolve_plural(state: &GameState, query: &str) -> Option<Item> {
+    state.resolve_item(query).or_else(|| {
+        let q = query.trim().to_lowercase();
+        let singular = if let Some(stem) = q.strip_suffix("ies") {
+            format!("{}y", stem)
+        } else if let Some(stem) = q.strip_suffix("es").filter(|s| s.ends_with("sh")) {
+            stem.to_string()
+        } else {
+            q.strip_suffix('s').unwrap_or(&q).to_string()
+        };
+        state.resolve_item(&singular)
+    })
+}
+
+/// Eat or drink `quantity` of an item (a count inside the name, like
+/// "3 berries", also works). `drinking` picks which kind of thing is
+/// acceptable.
+pub fn try_consume(
+    item_name: &str,
+    quantity: Option<u32>,
+    drinking: bool,
+    state: &mut GameState,
+) -> InteractionResult {
+    let (counted, name) = split_quantity(item_name);
+    let wanted = quantity.or(counted).unwrap_or(1).max(1);
+    let verb = if drinking { "drink" } else { "eat" };
+    let Some(item) = resolve_plural(state, name) else {
+        return InteractionResult::invalid(format!("You don't know what '{}' is.", name));
+    };
+    let label = state.display_name(&item);
+    let fits = if drinking { item.is_drink() } else { item.is_food() };
+    if !fits {
+        let hint = if !drinking && item.is_drink() {
+            format!(" Try 'drink {}' instead.", label)
+        } else if drinking && item.is_food() {
+            format!(" Try 'eat {}' instead.", label)
+        } else {
+            " See 'help food' for what you can eat and drink.".to_string()
+        };
+        return InteractionResult::invalid(format!("You can't {} the {}.{}", verb, label, hint));
+    }
+    let held = state.player.inventory.count(&item);
+    if held == 0 {
+        return InteractionResult::missing(item, format!("You don't have any {}.", label));
+    }
+
+    let count = wanted.min(held);
+    let mut message = String::new();
+    for _ in 0..count {
+        if let InteractionResult::ActionSuccess { message: m, .. } = handle_consumption(state, item) {
+            if message.is_empty() {
+                message = m;
+            }
+        }
+    }
+    if count > 1 {
+        message.push_str(&format!(" ({} in all.)", count));
+    }
+    if wanted > held {
+        message.push_str(" That was all you had.");
+    }
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: count.div_ceil(3),
+        energy_cost: 0.0,
+    }
+}
+
+/// What the player can eat and drink, for `help food`.
+pub fn food_help() -> String {
+    "**Food and drink:**\n\
+    - eat [item]: apples, wild berries, dates, fish, meat. Cooked fish, meat, and berries fill you more and lift your mood; raw fish and meat sit poorly.\n\
+    - drink [item]: clean water, herbal tea, or (if you must) muddy water.\n\
+    - drink with no item: sip from clean water you carry, otherwise from the lake or an oasis pool if you're beside one.\n\n\
+    Counts work too: 'eat 3 berries'. Cook over a lit hearth with 'use fish on fire'; fish and meat grill until you 'take fish off the fire', so pull them when they smell ready, not before and not long after."
+        .to_string()
+}
+
+/// Whether `pos` is on or beside the lake or an oasis pool.
+fn is_near_water(pos: &Position, map: &WorldMap) -> bool {
+    for dr in -1..=1 {
+        for dc in -1..=1 {
+            let check = Position::new(pos.row + dr, pos.col + dc);
+            if let Some((r, c)) = map.index_of(&check) {
+                if let Some(tile) = map.get_tile(r, c) {
+                    if matches!(tile.biome, Biome::Lake | Biome::Oasis) {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+/// The short, nearly free things you can do at the water's edge.
+pub fn lakeside_help() -> String {
+    "**By the water:**\n\
+    - skip_stone: Skim a stone across the surface. Needs a stone and a calm-ish day; you get better at it the more you throw.\n\
+    - dip_feet: Cool your feet in the shallows on a warm day.\n\
+    - watch_ripples: Sit with the water a moment and notice how it moves.\n\
+    - wash: Rinse sandstorm grit out of your eyes and hair.\n\n\
+    Use these with the 'lakeside' tool. Each gives less back if you repeat it too often in one day."
+        .to_string()
+}
+
+pub fn try_lakeside_ritual(
+    action: &str,
+    state: &mut GameState,
+    map: &WorldMap,
+) -> InteractionResult {
+    if state.player.room.is_some() || !is_near_water(&state.player.position, map) {
+        let pos = state.player.position;
+        let hint = super::suggest::nearest_water(&pos, map).map(|w| {
+            format!(
+                "The nearest water lies {}.",
+                crate::descriptions::distance_phrase(&pos, &w)
+            )
+        });
+        return InteractionResult::Failure(Failure::WrongLocation {
+            message: "You need to be standing at the water's edge for that.".to_string(),
+            hint,
+        });
+    }
+    match action {
+        "skip_stone" | "skip" => try_skip_stone(state),
+        "dip_feet" | "dip" => try_dip_feet(state, map),
+        "watch_ripples" | "watch" => try_watch_ripples(state),
+        "wash" | "rinse" => try_wash(state),
+        _ => InteractionResult::invalid(lakeside_help()),
+    }
+}
+
+fn try_skip_stone(state: &mut GameState) -> InteractionResult {
+    if !state.player.inventory.has(&Item::Stone, 1) {
+        return InteractionResult::missing(
+            Item::Stone,
+            "You'll need a flat stone to skip. The ground nearby is worth a look.".to_string(),
+        );
+    }
+    let pos = state.player.position;
+    if matches!(
+        state.weather.get_for_position(pos.row, pos.col),
+        Weather::HeavyRain | Weather::Blizzard | Weather::HeavySnow | Weather::Sandstorm
+    ) {
+        return InteractionResult::invalid(
+            "The water is too choppy to skip anything on today.".to_string(),
+        );
+    }
+
+    state.player.inventory.remove(&Item::Stone, 1);
+    let multiplier = state.rituals.perform(state.time.day, "skip_stone");
+    let skill = (state.player.effective_skill(Skill::Observation) as f32
+        + state.player.effective_skill(Skill::Survival) as f32)
+        / 2.0;
+    let mut rng = rand::thread_rng();
+    let knack = state.rituals.stone_knack;
+    let roll = skill / 8.0 + knack + rng.gen_range(-2.0..3.0);
+    let skips = roll.round().clamp(0.0, 12.0) as u32;
+    state.rituals.stone_knack = (knack + 0.4).min(5.0);
+
+    let perfect = skips >= 12 || rng.gen_bool(0.02);
+    state.player.modify_mood((1.0 + skips as f32 * 0.5) * multiplier);
+    state.player.skills.improve(Skill::Observation, 1);
+
+    let mut message = match skips {
+        0 => "The stone bites the water and vanishes with a plunk. Maybe a flatter one next time."
+            .to_string(),
+        1..=2 => format!(
+            "The stone hops {} before sinking. Not bad.",
+            if skips == 1 { "once" } else { "twice" }
+        ),
+        3..=6 => format!(
+            "Skip, skip, skip - {} bounces, each ring of ripples smaller than the last.",
+            skips
+        ),
+        _ => format!(
+            "The stone dances across the lake, {} skips, stitching a line of rings toward the far shore.",
+            skips
+        ),
+    };
+    if perfect {
+        message = "You feel it leave your hand just right. The stone skims flat and low, skipping again and again until the skips blur into a hiss and it simply glides, impossibly far, before slipping under. The lake seems to hold its breath.".to_string();
+        state.player.modify_mood(6.0);
+        if !state.rituals.perfect_skip {
+            state.rituals.perfect_skip = true;
+            message.push_str(" (Achievement unlocked: Glass Lake. Some throws you only get once.)");
+        }
+    } else if multiplier < 0.5 {
+        message.push_str(" It's pleasant, but the magic has worn a little thin for today.");
+    }
+
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 1,
+        energy_cost: 0.5,
+    }
+}
+
+fn try_dip_feet(state: &mut GameState, map: &WorldMap) -> InteractionResult {
+    if state.outdoor_temperature(map) < 24.0 {
+        return InteractionResult::invalid(
+            "You test the water with a toe. Far too cold today; better save it for a warmer day."
+                .to_string(),
+        );
+    }
+    let multiplier = state.rituals.perform(state.time.day, "dip_feet");
+    state.player.modify_mood(3.0 * multiplier);
+    state.player.modify_warmth(-6.0);
+    InteractionResult::ActionSuccess {
+        message: "You sit at the edge and let your feet sink into the cool shallows. Tiny fish come to investigate your toes. The heat drains out of you."
+            .to_string(),
+        time_cost: 2,
+        energy_cost: 0.0,
+    }
+}
+
+fn try_wash(state: &mut GameState) -> InteractionResult {
+    if !state.wash_off_grit() {
+        return InteractionResult::invalid(
+            "You splash your face. Cool, but there was nothing much to wash off.".to_string(),
+        );
+    }
+    state.player.modify_mood(2.0);
+    InteractionResult::ActionSuccess {
+        message: "You kneel and scoop water over your face and hair, blinking out the last of the storm's grit. The world comes back into focus."
+            .to_string(),
+        time_cost: 1,
+        energy_cost: 0.0,
+    }
+}
+
+fn try_watch_ripples(state: &mut GameState) -> InteractionResult {
+    let multiplier = state.rituals.perform(state.time.day, "watch_ripples");
+    let xp = (4.0 * multiplier).round().max(1.0) as u8;
+    state.player.skills.improve(Skill::Observation, xp);
+    state.player.modify_mood(1.0 * multiplier);
+    let message = if multiplier < 0.5 {
+        "You watch the ripples again. They are much as they were before."
+    } else {
+        "You watch the wind write across the water: rings from a rising fish, a patch of calm where the breeze can't reach, the long slow swell from somewhere far off."
+    };
+    InteractionResult::ActionSuccess {
+        message: message.to_string(),
+        time_cost: 1,
+        energy_cost: 0.0,
+    }
+}
+
+pub fn try_fish(
+    state: &mut GameState,
+    map: &WorldMap,
+    gear_hint: Option<&str>,
+) -> InteractionResult {
+    let pos = state.player.position;
+    let temperature = state.outdoor_temperature(map);
+    let Some(spot) = FishingSpot::at(map, &pos, state.aboard_raft(), temperature) else {
+        return InteractionResult::wrong_place(
+            "You need to be right by the lake or oasis shore to fish.".to_string(),
+        );
+    };
+
+    if state.player.energy < 5.0 {
+        return InteractionResult::exhausted("You are too exhausted to fish right now.".to_string());
+    }
 
-            let idx = if let Some(i) = same_tile_index {
-                Some(i)
-            } else {
-                best_index
-            };
+    let has_rod = state.player.inventory.has(&Item::FishingRod, 1);
+    let wants_rod = gear_hint
+        .map(|g| {
+            let lower = g.to_lowercase();
+            lower.contains("rod") || lower.contains("pole")
+        })
+        .unwrap_or(has_rod);
+    if wants_rod && !has_rod {
+        return InteractionResult::missing(
+            Item::FishingRod,
+            "You reach for a rod, but you don't have one with you.".to_string(),
+        );
+    }
+    let using_rod = wants_rod && has_rod;
+    if using_rod {
+        state.player.hands.take_up(Item::FishingRod);
+    }
 
-            let idx = match idx {
-                Some(i) => i,
-                None => {
-                    return InteractionResult::Failure(
-                        "You don't see any such animal close enough to feed.".to_string(),
-                    )
-                }
-            };
+    let weather_here = state.weather.get_for_position(pos.row, pos.col);
+    let tod = state.time.time_of_day();
+    let stormy = matches!(
+        weather_here,
+        Weather::HeavyRain | Weather::HeavySnow | Weather::Blizzard | Weather::Sandstorm
+    );
 
-            if !state.player.inventory.remove(&item, 1) {
-                return InteractionResult::Failure(
-                    "You don't have any food to offer right now.".to_string(),
-                );
-            }
+    let weights: [u32; 5] = match (spot, using_rod) {
+        (FishingSpot::Shallows, true) => [0, 45, 18, 12, 25],
+        (FishingSpot::Shallows, false) => [0, 25, 6, 20, 49],
+        (FishingSpot::DeepWater, true) => [0, 25, 35, 6, 34],
+        (FishingSpot::DeepWater, false) => [0, 12, 4, 4, 80],
+        (FishingSpot::Oasis, true) => [50, 14, 2, 6, 18],
+        (FishingSpot::Oasis, false) => [40, 6, 0, 8, 36],
+        (FishingSpot::IceHole, true) => [0, 38, 14, 4, 44],
+        (FishingSpot::IceHole, false) => [0, 18, 2, 4, 76],
+    };
+    let mut outcomes: Vec<(&str, u32)> = ["pupfish", "small", "big", "trash", "nothing"]
+        .into_iter()
+        .zip(weights)
+        .collect();
 
-            if let Some(w) = state.wildlife.get_mut(idx) {
-                let is_companion = matches!(w.species, Species::Dog | Species::Cat);
-                if is_companion {
-                    w.tamed = true;
-                    state.player.modify_mood(4.0);
-                } else {
-                    state.player.modify_mood(1.0);
-                }
+    if matches!(tod, TimeOfDay::Dawn | TimeOfDay::Dusk | TimeOfDay::Evening) {
+        outcomes[1].1 += 6;
+        outcomes[2].1 += 4;
+        outcomes[4].1 = outcomes[4].1.saturating_sub(8);
+    }
 
-                let label = w.display_name();
-                let message = if is_companion {
-                    format!(
-                        "You offer the {}. {} eats gratefully and falls into step beside you.",
-                        item.name(),
-                        label
-                    )
-                } else {
-                    format!(
-                        "You offer the {}. {} accepts the food for a moment, then drifts back to its own concerns.",
-                        item.name(),
-                        label
-                    )
-                };
+    if stormy {
+        outcomes[1].1 = outcomes[1].1.saturating_sub(5);
+        outcomes[2].1 = outcomes[2].1.saturating_sub(3);
+        outcomes[3].1 += 6;
+        outcomes[4].1 += 6;
+    }
 
-                return InteractionResult::ActionSuccess {
-                    message,
-                    time_cost: 1,
-                    energy_cost: 1.0,
-                };
-            }
+    // On hot days the fish leave the warm margins for the cool depths
+    if temperature >= 24.0 {
+        match spot {
+            FishingSpot::Shallows => outcomes[4].1 += 12,
+            FishingSpot::DeepWater => outcomes[2].1 += 8,
+            _ => {}
+        }
+    }
 
-            return InteractionResult::Failure(
-                "Something about feeding that animal goes strangely wrong.".to_string(),
-            );
+    // Water fished hard lately has less left in it
+    let pressure = state.fishing_pressure.get(&spot).copied().unwrap_or(0.0);
+    let wary = (pressure * 6.0).round() as u32;
+    if wary > 0 {
+        for outcome in outcomes.iter_mut().take(3) {
+            outcome.1 = outcome.1.saturating_sub(wary / 3);
         }
+        outcomes[4].1 += wary;
     }
 
-    // 3c. Cooking simple foods on fire
-    if matches!(
-        item,
-        Item::Fish | Item::SmallFish | Item::BigFish | Item::WildBerry | Item::RawMeat
-    ) {
-        let in_cabin = matches!(state.player.room, Some(Room::CabinMain));
-        let fire_lit = state
-            .cabin_state()
-            .map(|c| !matches!(c.fireplace.state, FireState::Cold))
-            .unwrap_or(false);
-        if !in_cabin || !fire_lit {
-            return InteractionResult::Failure(
-                "You need to be by a lit fireplace to cook that right now.".to_string(),
-            );
+    let skill_bonus = (state.player.effective_skill(Skill::Survival) as u32 / 12)
+        + (state.player.effective_skill(Skill::Observation) as u32 / 20);
+    if skill_bonus > 0 {
+        let main = if spot == FishingSpot::Oasis { 0 } else { 1 };
+        outcomes[main].1 += skill_bonus;
+        outcomes[4].1 = outcomes[4].1.saturating_sub(skill_bonus.min(outcomes[4].1));
+    }
+
+    let total: u32 = outcomes.iter().map(|(_, w)| *w).sum::<u32>().max(1);
+    let roll = rand::thread_rng().gen_range(0..total);
+    let mut cursor = 0;
+    let chosen = outcomes
+        .iter()
+        .find(|(_, weight)| {
+            cursor += *weight;
+            roll < cursor
+        })
+        .map(|(name, _)| *name)
+        .unwrap_or("nothing");
+
+    let mut time_cost = if using_rod { 2 } else { 1 };
+    let mut energy_cost = if using_rod { 5.0 } else { 4.0 };
+    if stormy {
+        time_cost += 1;
+        energy_cost += 2.0;
+    }
+    match spot {
+        // Long casts to reach the fish lying deep
+        FishingSpot::DeepWater => time_cost += 1,
+        // Chipping the hole and kneeling on the ice
+        FishingSpot::IceHole => {
+            energy_cost += 1.0;
+            state.player.modify_warmth(-2.0);
         }
+        _ => {}
+    }
 
-        let severe = {
-            let pos = state.player.position;
-            let weather_here = state.weather.get_for_position(pos.row, pos.col);
-            matches!(
-                weather_here,
-                Weather::Blizzard | Weather::HeavySnow | Weather::HeavyRain | Weather::Sandstorm
+    let from = capitalize(spot.phrase());
+    let message = match chosen {
+        "pupfish" => {
+            if !state.player.inventory.add(Item::Pupfish, 1) {
+                return InteractionResult::invalid(
+                    "Your pack is too heavy to stow even a fish that small.".to_string(),
+                );
+            }
+            state.player.skills.improve(Skill::Survival, 1);
+            state.player.skills.improve(Skill::Observation, 1);
+            format!(
+                "{} you lift a flicker of blue: an oasis pupfish, no longer than your finger.",
+                from
             )
-        };
-        let mut time_cost = 2;
-        let mut energy_cost = 4.0;
-        if severe {
-            time_cost += 1;
-            energy_cost += 2.0;
         }
-
-        if matches!(
-            item,
-            Item::Fish | Item::SmallFish | Item::BigFish | Item::RawMeat
-        ) {
-            if !state.player.inventory.remove(&item, 1) {
-                return InteractionResult::Failure(
-                    "You don't have anything suitable to cook.".to_string(),
+        "small" => {
+            if !state.player.inventory.add(Item::SmallFish, 1) {
+                return InteractionResult::invalid(
+                    "Your pack is too heavy to stow the fish.".to_string(),
                 );
             }
-            let (yield_item, yield_count, extra_time, text) = if item == Item::RawMeat {
-                (Item::CookedMeat, 1, 1, "You grill the meat over the fire until it sizzles and smells savory.")
+            state.player.skills.improve(Skill::Survival, 2);
+            state.player.skills.improve(Skill::Observation, 1);
+            if using_rod {
+                format!(
+                    "{} you feel a quick tug and pull up a small fish, cool and slick in your hand.",
+                    from
+                )
             } else {
-                let yield_count = if item == Item::BigFish { 2 } else { 1 };
-                let extra_time = if item == Item::BigFish { 1 } else { 0 };
-                let text = if yield_count > 1 {
-                    "You portion the large fish into hearty fillets and grill them until they flake easily."
-                } else {
-                    "You grill the fish over the fire until it flakes easily."
-                };
-                (Item::CookedFish, yield_count, extra_time, text)
-            };
-
-            if extra_time > 0 {
-                time_cost += extra_time;
+                format!(
+                    "{} your hands snap shut on a small fish, cool and slick and wriggling.",
+                    from
+                )
             }
-            state.player.inventory.add(yield_item, yield_count);
-
-            let portion_text = text;
-            return InteractionResult::ActionSuccess {
-                message: portion_text.to_string(),
-                time_cost,
-                energy_cost,
-            };
-        } else {
-            if state.player.inventory.count(&Item::WildBerry) < 2 {
-                return InteractionResult::Failure(
-                    "Gather at least a couple of berries to roast.".to_string(),
+        }
+        "big" => {
+            if !state.player.inventory.add(Item::BigFish, 1) {
+                return InteractionResult::invalid(
+                    "The catch is too heavy for your current pack.".to_string(),
                 );
             }
-            state.player.inventory.remove(&Item::WildBerry, 2);
-            state.player.inventory.add(Item::CookedBerries, 1);
-            return InteractionResult::ActionSuccess {
-                message: "You roast the berries, caramelizing their juices.".to_string(),
-                time_cost,
-                energy_cost,
-            };
+            state.player.skills.improve(Skill::Survival, 3);
+            state.player.skills.improve(Skill::Observation, 1);
+            time_cost += 1;
+            energy_cost += 1.0;
+            if using_rod {
+                format!(
+                    "{} a strong pull bends your line. After a short struggle you haul in a hefty fish.",
+                    from
+                )
+            } else {
+                format!(
+                    "{} you get both arms around something heavy and, after a soaking struggle, heave a hefty fish onto the bank.",
+                    from
+                )
+            }
         }
-    }
-
-    // Stone on Stone -> Sharp Stone
-    if item == Item::Stone {
-        if let Some(target) = target_str {
-            if target.contains("stone") || target.contains("rock") {
-                if state.player.inventory.count(&Item::Stone) >= 2 {
-                    state.player.inventory.remove(&Item::Stone, 1);
-                    state.player.inventory.add(Item::SharpStone, 1);
-                    state.player.skills.improve("stonemasonry", 5);
-                    return InteractionResult::ActionSuccess {
-                        message: "You smash the stones together, flaking off a razor-sharp edge."
-                            .to_string(),
-                        time_cost: 1,
-                        energy_cost: 5.0,
-                    };
-                } else {
-                    return InteractionResult::Failure(
-                        "You need another stone to knap against.".to_string(),
-                    );
-                }
+        "trash" => {
+            if !state.player.inventory.add(Item::Driftwood, 1) {
+                return InteractionResult::invalid(
+                    "You snag some driftwood, but you're carrying too much to keep it.".to_string(),
+                );
+            }
+            state.player.skills.improve(Skill::Survival, 1);
+            if using_rod {
+                format!(
+                    "{} your line goes taut on something lifeless. You drag in a piece of driftwood.",
+                    from
+                )
+            } else {
+                format!(
+                    "{} your fingers close on something lifeless. You drag out a piece of driftwood.",
+                    from
+                )
             }
         }
-    }
-
-    // Paper on paper -> Blank Book
-    if item == Item::Paper {
-        if state.player.inventory.count(&Item::Paper) >= 5 {
-            state.player.inventory.remove(&Item::Paper, 5);
-            state.player.inventory.add(Item::BlankBook, 1);
-            state.player.skills.improve("tailoring", 1);
-            return InteractionResult::ActionSuccess {
-                message: "You fold and bind the sheets into a blank book. Try 'write 제목:... on 빈 책' to title it.".to_string(),
-                time_cost: 2,
-                energy_cost: 3.0,
-            };
-        } else {
-            return InteractionResult::Failure(
-                "You need at least 5 sheets of paper to bind a blank book.".to_string(),
-            );
+        _ => {
+            state.player.skills.improve(Skill::Survival, 1);
+            if pressure >= 2.0 {
+                "You wait with quiet patience, but nothing bites. You've taken a lot out of this water lately; the fish have grown wary.".to_string()
+            } else {
+                "You wait with quiet patience, but nothing bites this time.".to_string()
+            }
         }
+    };
+
+    if using_rod {
+        state.damage_tool(&Item::FishingRod, ToolWear::Light, "casting for fish");
+    }
+    if chosen == "nothing" {
+        return InteractionResult::chance(message, time_cost, energy_cost);
+    }
+    if chosen != "trash" {
+        *state.fishing_pressure.entry(spot).or_insert(0.0) += 1.0;
     }
 
-    // 4. Fire Interaction
-    let is_fire_target = target_str
-        .map(|t| t.contains("fire") || t.contains("hearth"))
-        .unwrap_or(false);
-    let in_cabin = matches!(state.player.room, Some(Room::CabinMain));
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost,
+        energy_cost,
+    }
+}
 
-    if is_fire_target || (in_cabin && target_str.is_none()) {
-        if item.is_flammable() {
-            return handle_add_fuel(state, item);
-        }
-        if item == Item::Matchbox {
-            return handle_light_fire(state);
-        }
+// New Create command handler
+/// Catch something small that is out tonight: fireflies into a clay jar,
+/// or a frog just long enough to look at it.
+pub fn try_catch(target: &str, state: &mut GameState, map: &WorldMap) -> InteractionResult {
+    if state.player.room.is_some() || state.player.inside.is_some() {
+        return InteractionResult::wrong_place(
+            "There's nothing to catch in here. Try outside on a warm evening.".to_string(),
+        );
     }
+    let about = AmbientLife::around(&state.player.position, map, &state.time, &state.weather);
+    let Some(life) = about.into_iter().find(|l| l.matches(target)) else {
+        let (named, hint) = if AmbientLife::Fireflies.matches(target) {
+            (
+                AmbientLife::Fireflies.name(),
+                " Fireflies come out on warm, still evenings by the water or the forest edge.",
+            )
+        } else if AmbientLife::Frogs.matches(target) {
+            (
+                AmbientLife::Frogs.name(),
+                " Frogs call from the reeds by the water, except in the heat of the day or the cold.",
+            )
+        } else {
+            (target.trim(), "")
+        };
+        return InteractionResult::invalid(format!(
+            "You don't see any {} about right now.{}",
+            named, hint
+        ));
+    };
 
-    // 5. Consumption (Food/Drink)
-    if matches!(
-        item,
-        Item::Apple
-            | Item::WildBerry
-            | Item::HerbalTea
-            | Item::MuddyWater
-            | Item::CleanWater
-            | Item::Date
-            | Item::CookedFish
-            | Item::CookedBerries
-            | Item::SmallFish
-            | Item::Fish
-            | Item::BigFish
-            | Item::RawMeat
-            | Item::CookedMeat
-    ) {
-        return handle_consumption(state, item);
+    match life {
+        AmbientLife::Fireflies => {
+            if state.player.inventory.has(&Item::FireflyJar, 1) {
+                return InteractionResult::invalid(
+                    "Your jar already glows with a handful of them. Let those go before catching more."
+                        .to_string(),
+                );
+            }
+            if !state.player.inventory.remove(&Item::ClayJar, 1) {
+                state.player.modify_mood(1.0);
+                return InteractionResult::ActionSuccess {
+                    message: "You cup a firefly in your hands and watch it glow between your fingers before it slips away. A jar would hold a few."
+                        .to_string(),
+                    time_cost: 1,
+                    energy_cost: 1.0,
+                };
+            }
+            state.player.inventory.add(Item::FireflyJar, 1);
+            state.firefly_jar_minutes = FIREFLY_JAR_MINUTES;
+            state.player.modify_mood(2.0);
+            state.player.skills.improve(Skill::Observation, 1);
+            InteractionResult::ActionSuccess {
+                message: "You sweep the open jar slowly through the dusk and stopper it. Inside, a dozen small lights wink on and off. They won't last more than a night or two shut away; use the jar to let them go."
+                    .to_string(),
+                time_cost: 2,
+                energy_cost: 2.0,
+            }
+        }
+        AmbientLife::Frogs => {
+            state.player.modify_mood(1.0);
+            InteractionResult::ActionSuccess {
+                message: "You crouch in the reeds and close your hands around a cool, startled frog. It regards you, unimpressed, and you let it go with a plop."
+                    .to_string(),
+                time_cost: 1,
+                energy_cost: 2.0,
+            }
+        }
     }
-
-    InteractionResult::Failure(format!(
-        "You can't use the {} that way. Try patterns like: use axe on tree (gather), use knife on stick (process), or use log on blueprint (build).",
-        item.name()
-    ))
 }
 
-fn handle_card_case_use(
-    state: &mut GameState,
-    map: &mut WorldMap,
-    target: Option<&str>,
-) -> InteractionResult {
-    let pos = state.player.position;
-    let Some((r, c)) = pos.as_usize() else {
-        return InteractionResult::Failure(
-            "You feel strangely ungrounded; the card case slips in your hands.".to_string(),
+/// Move one load of sand out of the ruin. Each chamber takes a few loads
+/// and stays as dug between visits; opening one reveals its inscription.
+fn try_dig_ruin(state: &mut GameState, shovel: &Item) -> InteractionResult {
+    if state.player.energy < 12.0 {
+        return InteractionResult::exhausted(
+            "You're too spent to shift any more sand. Rest on the bench a while.".to_string(),
         );
+    }
+    let Some(ruin) = state.ruin_mut() else {
+        return InteractionResult::invalid("The ruin seems to have vanished.".to_string());
     };
-
-    let Some(tile) = map.get_tile_mut(r, c) else {
-        return InteractionResult::Failure(
-            "You can't quite find space here to lay out cards.".to_string(),
+    if ruin.fully_cleared() {
+        return InteractionResult::invalid(
+            "The chambers are dug clear. Only the sand you've already moved is left, heaped by the door."
+                .to_string(),
         );
+    }
+    let opened = ruin.dig();
+    let next = Ruin::chamber_name(ruin.chambers_cleared + 1);
+    state.damage_tool(shovel, ToolWear::Light, "digging out the ruin");
+    state.player.skills.improve(Skill::Survival, 1);
+
+    let mut message = match opened {
+        None => format!(
+            "You dig into the drift blocking the {}, throwing sand back toward the entrance until your arms burn. It's giving way.",
+            next
+        ),
+        Some(chamber) => format!(
+            "The last of the sand slides away and the {} opens in front of you.",
+            Ruin::chamber_name(chamber)
+        ),
     };
-
-    let cards_on_ground = tile
-        .items
-        .items
-        .iter()
-        .find_map(|(item, qty)| {
-            if *item == Item::PlayingCard && *qty > 0 {
-                Some(*qty)
-            } else {
-                None
+    if let Some(text) = state.reveal_inscription(0) {
+        message = format!(
+            "Your first shovelful bares the lintel, where carving runs across the stone. {} (Copied into your journal.) {}",
+            text, message
+        );
+    }
+    if let Some(chamber) = opened {
+        if let Some(text) = state.reveal_inscription(chamber) {
+            message.push_str(&format!(" Carving covers the walls. {} (Copied into your journal.)", text));
+        }
+        let cleared = state.ruin().map(|r| r.fully_cleared()).unwrap_or(false);
+        if cleared {
+            if let Some(ruin) = state.ruin_mut() {
+                ruin.relic_taken = true;
             }
-        })
-        .unwrap_or(0);
-
-    let target_hint = target.unwrap_or("").to_lowercase();
+            state.player.inventory.add(Item::Lodestone, 1);
+            state.player.modify_mood(6.0);
+            message.push_str(" On the plinth lies a lump of smooth black stone. When you lift it, iron filings bristle along its face. You tuck the lodestone away.");
+            if state.player.inventory.has(&Item::StrangeCompass, 1) {
+                message.push_str(" In your pack, the strange compass needle swings hard to follow it.");
+            }
+        }
+    }
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 3,
+        energy_cost: 12.0,
+    }
+}
 
-    // If the case is closed, using it mostly acts like flipping it open.
-    if !state.card_case_open && state.card_case_cards_inside > 0 && cards_on_ground == 0 {
-        state.card_case_open = true;
-        return InteractionResult::Success(
-            "You flick open the card case. A full deck rests inside, edges whispering together."
-                .to_string(),
+fn release_fireflies(state: &mut GameState) -> InteractionResult {
+    if state.player.room.is_some() || state.player.inside.is_some() {
+        return InteractionResult::wrong_place(
+            "Let them go outside, where they can find their way back to the grass.".to_string(),
         );
     }
+    state.player.inventory.remove(&Item::FireflyJar, 1);
+    state.player.inventory.add(Item::ClayJar, 1);
+    state.firefly_jar_minutes = 0;
+    state.player.modify_mood(5.0);
+    InteractionResult::ActionSuccess {
+        message: "You pull the stopper. For a moment nothing happens; then the fireflies lift out one by one and drift away over the grass, blinking as they go."
+            .to_string(),
+        time_cost: 1,
+        energy_cost: 0.5,
+    }
+}
 
-    // Open case with cards inside and clear ground -> lay them out
-    if state.card_case_open && state.card_case_cards_inside > 0 && cards_on_ground == 0 {
-        let inside = state.card_case_cards_inside.min(52);
-        state.card_case_cards_inside = state.card_case_cards_inside.saturating_sub(inside);
-        tile.items.add(Item::PlayingCard, inside as u32);
-        return InteractionResult::Success(format!(
-            "You slide {} cards out of the case and spread them across the ground.",
-            inside
-        ));
+pub fn try_create(item_name: &str, state: &mut GameState, map: &WorldMap) -> InteractionResult {
+    let target_item = match Item::from_str(item_name) {
+        Some(i) => i,
+        None => return InteractionResult::invalid(format!("Unknown item '{}'.", item_name)),
+    };
+
+    let recipe_available = Blueprint::new(target_item).is_some();
+    if !recipe_available {
+        return InteractionResult::invalid(format!("You don't know how to craft a {}.", item_name));
     }
 
-    // Open case, cards on ground -> scoop back in up to full deck
-    if state.card_case_open && cards_on_ground > 0 {
-        let capacity_left = 52u8.saturating_sub(state.card_case_cards_inside);
-        if capacity_left == 0 {
-            return InteractionResult::Failure(
-                "The card case is already holding a full deck.".to_string(),
-            );
-        }
+    state.refresh_blueprint_knowledge(true);
 
-        let mut moved: u8 = 0;
-        while moved < capacity_left && tile.items.take(&Item::PlayingCard) {
-            moved = moved.saturating_add(1);
+    if !state.knows_blueprint(target_item) {
+        let mut msg = format!(
+            "You haven't learned the {} blueprint yet.",
+            target_item.name()
+        );
+        if let Some(hint) = state.blueprint_hint_text(target_item) {
+            msg.push(' ');
+            msg.push_str(hint);
         }
-
-        if moved == 0 {
-            return InteractionResult::Failure(
-                "You don't see any cards here to scoop into the case.".to_string(),
-            );
+        let known = state.known_blueprint_names();
+        if !known.is_empty() {
+            msg.push_str(&format!(" Known blueprints: {}.", known.join(", ")));
         }
-
-        state.card_case_cards_inside =
-            (state.card_case_cards_inside.saturating_add(moved)).min(52);
-
-        return InteractionResult::Success(format!(
-            "You gather {} cards from the ground back into the case.",
-            moved
-        ));
+        return InteractionResult::invalid(msg);
     }
 
-    // Closed or empty case with no cards nearby
-    if !state.card_case_open && state.card_case_cards_inside == 0 && cards_on_ground == 0 {
-        return InteractionResult::Failure(
-            "The card case feels light and empty. There are no cards here to work with."
-                .to_string(),
-        );
+    // Plans already set aside are picked back up, not started over
+    if let Some(index) = state
+        .paused_projects
+        .iter()
+        .position(|bp| bp.target_item == target_item)
+    {
+        return resume_project(state, index);
     }
 
-    // Fallback: if there are cards on the ground but the case is closed, hint to open it first
-    if !state.card_case_open && cards_on_ground > 0 {
-        let verb = if target_hint.contains("open") {
-            "Use 'open card case' first, then use the case again to scoop cards from the ground."
-        } else {
-            "Open the card case first, then use it again to scoop cards from the ground."
-        };
-        return InteractionResult::Failure(verb.to_string());
+    let mut bp = Blueprint::new(target_item).unwrap();
+    if let Err(msg) = check_build_site(&bp, state, map) {
+        return InteractionResult::invalid(msg);
     }
-
-    InteractionResult::Failure(
-        "You turn the card case over in your hands, but nothing interesting happens."
-            .to_string(),
-    )
+    let aside = match set_aside_active_project(state) {
+        Ok(line) => line,
+        Err(msg) => return InteractionResult::invalid(msg),
+    };
+    state.last_project_id += 1;
+    bp.id = state.last_project_id;
+    if bp.workspace().is_some() {
+        bp.anchor = Some(state.player.position);
+    }
+    let progress = bp.progress_summary();
+    let time_cost = bp.time_cost;
+    let note = bp
+        .requirements_note()
+        .map(|n| format!(" {}", n))
+        .unwrap_or_default();
+    let inventory = &state.player.inventory;
+    let short = bp.still_needed(|i| inventory.count(i));
+    let delta = if short.is_empty() {
+        "You're carrying everything it needs.".to_string()
+    } else {
+        format!("Still needed: {}.", short.join(", "))
+    };
+    state.player.active_project = Some(bp);
+    InteractionResult::Success(format!(
+        "{}You lay out plans for a {}. Requires: {}. {} Total build time: {} mins.{}",
+        aside,
+        target_item.name(),
+        progress,
+        delta,
+        time_cost,
+        note
+    ))
 }
 
-fn parse_book_id_from_target(target: Option<&str>) -> Option<String> {
-    let target = target?.trim();
-    if target.is_empty() {
-        return None;
-    }
-    if let Some(start) = target.find('(') {
-        if let Some(end_rel) = target[start + 1..].find(')') {
-            return Some(target[start + 1..start + 1 + end_rel].trim().to_string());
+/// Set the project being worked on aside with everything already fitted to
+/// it. Untouched plans are just folded away once the paused list is full.
+/// Returns a line to lead the reply with.
+fn set_aside_active_project(state: &mut GameState) -> Result<String, String> {
+    let Some(mut bp) = state.player.active_project.take() else {
+        return Ok(String::new());
+    };
+    let name = bp.target_item.name();
+    if state.paused_projects.len() >= MAX_PAUSED_PROJECTS {
+        if !bp.invested() {
+            return Ok(format!(
+                "You fold away the untouched plans for the {}. ",
+                name
+            ));
         }
+        let paused: Vec<String> = state
+            .paused_projects
+            .iter()
+            .map(|p| format!("#{} {}", p.id, p.target_item.name()))
+            .collect();
+        state.player.active_project = Some(bp);
+        return Err(format!(
+            "You already have {} projects set aside ({}). Finish or abandon one before setting the {} aside too.",
+            MAX_PAUSED_PROJECTS,
+            paused.join(", "),
+            name
+        ));
     }
-    Some(target.to_string())
-}
-
-fn handle_book_use(
-    state: &mut GameState,
-    map: &mut WorldMap,
-    item: &Item,
-    target: Option<&str>,
-) -> InteractionResult {
-    let id_from_item = state.book_id_for_item(item).map(|s| s.to_string());
-    let mut accessible_ids = state.accessible_book_ids();
-    let book_id = if let Some(id) = id_from_item {
-        id
-    } else if let Some(id) = parse_book_id_from_target(target) {
-        id
-    } else if accessible_ids.len() == 1 {
-        accessible_ids.pop().unwrap()
+    if bp.id == 0 {
+        state.last_project_id += 1;
+        bp.id = state.last_project_id;
+    }
+    let line = if bp.invested() {
+        format!(
+            "You set the {} aside as project #{}, its materials kept with it. ",
+            name, bp.id
+        )
     } else {
-        accessible_ids.sort();
-        let listing = if accessible_ids.is_empty() {
-            "No book IDs available. Bind a blank book first with 'write 제목:<title> on 빈 책'."
-                .to_string()
-        } else {
-            format!(
-                "Specify which book to read. Available: {}",
-                accessible_ids.join(", ")
-            )
-        };
-        return InteractionResult::Failure(listing);
+        format!(
+            "You set the plans for the {} aside as project #{}. ",
+            name, bp.id
+        )
     };
+    state.paused_projects.push(bp);
+    Ok(line)
+}
 
-    if !state.player_or_cabin_has_book(&book_id) {
-        return InteractionResult::Failure(
-            "You need to hold that book (or be next to it in the cabin).".to_string(),
-        );
+/// The paused project a player means, by number ("2", "#2") or by what it
+/// builds.
+fn find_paused_project(state: &GameState, which: &str) -> Option<usize> {
+    let which = which.trim().trim_start_matches('#');
+    if let Ok(id) = which.parse::<u32>() {
+        return state.paused_projects.iter().position(|bp| bp.id == id);
     }
+    let item = Item::from_str(which)?;
+    state
+        .paused_projects
+        .iter()
+        .position(|bp| bp.target_item == item)
+}
 
-    let Some(book) = state.books.get(&book_id) else {
-        return InteractionResult::Failure("That book doesn't seem to exist.".to_string());
-    };
-    let title = book.title.clone();
-    let total_pages = book.pages.len();
-    let pages_copy = book.pages.clone();
-    let book_label = book.id.clone();
-
-    let mut page = state.book_page(&book_id);
-    if let Some(t) = target {
-        let lower = t.to_lowercase();
-        if lower.contains("next") {
-            page = page.saturating_add(1);
-        } else if lower.contains("prev") || lower.contains("previous") {
-            page = page.saturating_sub(1);
+/// Pick a paused project back up, setting the current one aside in its
+/// place. Builds bound to their site are only picked up where they were laid out.
+fn resume_project(state: &mut GameState, index: usize) -> InteractionResult {
+    let bp = &state.paused_projects[index];
+    if let Some(anchor) = bp.anchor {
+        let here = state.player.position;
+        let indoors = state.player.inside.is_some() || state.player.room.is_some();
+        if here != anchor || indoors {
+            let distance = here.distance_to(&anchor).round() as i32;
+            let whereabouts = if distance == 0 {
+                "just outside".to_string()
+            } else {
+                format!(
+                    "{} tile{} to the {}",
+                    distance,
+                    if distance == 1 { "" } else { "s" },
+                    here.bearing_to(&anchor)
+                )
+            };
+            return InteractionResult::wrong_place(format!(
+                "The {} lies where you laid it out, {}. Go back there to take it up again.",
+                bp.target_item.name(),
+                whereabouts
+            ));
         }
     }
+    let bp = state.paused_projects.remove(index);
+    let aside = match set_aside_active_project(state) {
+        Ok(line) => line,
+        Err(msg) => {
+            state.paused_projects.insert(index, bp);
+            return InteractionResult::invalid(msg);
+        }
+    };
+    let text = format!(
+        "{}You take up the {} again. {}",
+        aside,
+        bp.target_item.name(),
+        bp.status_description()
+    );
+    state.player.active_project = Some(bp);
+    InteractionResult::Success(text)
+}
 
-    let max_page = book.pages.len();
-    if page > max_page {
-        page = max_page;
+/// Give back what went into a project and tear up its plans.
+fn abandon_project(state: &mut GameState, bp: Blueprint) -> String {
+    let mut returned = Vec::new();
+    for (item, count) in bp.current.iter().chain(bp.stand_ins.iter()) {
+        if *count > 0 {
+            state.player.inventory.add(*item, *count);
+            returned.push(if *count > 1 {
+                format!("{} x{}", item.name(), count)
+            } else {
+                item.name().to_string()
+            });
+        }
     }
-    state.set_book_page(&book_id, page);
-    state.refresh_blueprint_knowledge(true);
-    state.grant_tutorial_reward_if_needed(map);
-
-    let message = if page == 0 {
+    let name = bp.target_item.name();
+    if returned.is_empty() {
+        format!("You tear up the plans for the {}.", name)
+    } else {
+        returned.sort();
         format!(
-            "{} [{}] — cover page. Total pages: {}. Use 'use {} on nextpage' to turn pages.",
-            title,
-            book_label,
-            total_pages,
-            item.name()
+            "You pull the {} apart and take back {}.",
+            name,
+            returned.join(", ")
         )
-    } else {
-        let content = pages_copy
-            .get(page - 1)
-            .map(|s| s.as_str())
-            .unwrap_or("This page is blank.");
-        format!("{} [{}] — Page {}: {}", title, book_label, page, content)
-    };
-
-    InteractionResult::Success(message)
+    }
 }
 
-fn handle_blueprint_interaction(state: &mut GameState, item: &Item) -> InteractionResult {
-    if let Some(bp) = &mut state.player.active_project {
-        if bp.add_material(item.clone()) {
-            state.player.inventory.remove(item, 1);
-            if !bp.is_complete() {
-                let progress = bp.progress_summary();
-                return InteractionResult::ActionSuccess {
-                    message: format!(
-                        "You add the {} to the {}. Progress: {}. Total build time: {} mins.",
-                        item.name(),
-                        bp.target_item.name(),
-                        progress,
-                        bp.time_cost
-                    ),
-                    time_cost: 1, // 10 mins per action
-                    energy_cost: 2.0,
-                };
+/// List, switch between, pause or abandon the player's projects.
+pub fn try_projects(action: &str, which: Option<&str>, state: &mut GameState) -> InteractionResult {
+    match action {
+        "list" => {
+            let mut lines = Vec::new();
+            match &state.player.active_project {
+                Some(bp) => lines.push(format!("Working on: {}", bp.status_description())),
+                None => lines.push("Working on: nothing just now.".to_string()),
+            }
+            if state.paused_projects.is_empty() {
+                lines.push("Nothing set aside.".to_string());
+            } else {
+                lines.push(format!(
+                    "Set aside ({} of {}):",
+                    state.paused_projects.len(),
+                    MAX_PAUSED_PROJECTS
+                ));
+                let here = state.player.position;
+                for bp in &state.paused_projects {
+                    let mut line = format!("- #{} {}", bp.id, bp.status_description());
+                    if let Some(anchor) = bp.anchor.filter(|a| *a != here) {
+                        line.push_str(&format!(
+                            " Laid out {} tiles to the {}.",
+                            here.distance_to(&anchor).round() as i32,
+                            here.bearing_to(&anchor)
+                        ));
+                    }
+                    lines.push(line);
+                }
+            }
+            InteractionResult::Success(lines.join("\n"))
+        }
+        "switch" | "resume" => {
+            let index = match which {
+                Some(which) => match find_paused_project(state, which) {
+                    Some(index) => index,
+                    None => {
+                        return InteractionResult::invalid(format!(
+                            "You have no project '{}' set aside.",
+                            which
+                        ))
+                    }
+                },
+                None if state.paused_projects.is_empty() => {
+                    return InteractionResult::invalid("You have nothing set aside.".to_string())
+                }
+                None if action == "resume" => state.paused_projects.len() - 1,
+                None => {
+                    return InteractionResult::invalid(
+                        "Which project? Give its number or what it builds.".to_string(),
+                    )
+                }
+            };
+            resume_project(state, index)
+        }
+        "pause" => {
+            if state.player.active_project.is_none() {
+                return InteractionResult::invalid("You aren't working on anything.".to_string());
+            }
+            match set_aside_active_project(state) {
+                Ok(line) => InteractionResult::Success(line.trim_end().to_string()),
+                Err(msg) => InteractionResult::invalid(msg),
             }
-        } else {
-            return InteractionResult::Failure(format!(
-                "The {} doesn't need any (more) {}.",
-                bp.target_item.name(),
-                item.name()
-            ));
         }
-    } else {
-        return InteractionResult::Failure(
-            "You don't have an active blueprint. Use 'create [item]' first.".to_string(),
-        );
-    }
-
-    if let Some(bp) = state.player.active_project.take() {
-        state.player.inventory.add(bp.target_item.clone(), 1);
-
-        // Skill gain based on item type
-        match bp.target_item {
-            Item::StoneKnife | Item::StoneAxe => state.player.skills.improve("stonemasonry", 10),
-            Item::Campfire => state.player.skills.improve("survival", 5),
-            Item::Cordage => state.player.skills.improve("tailoring", 5),
-            _ => {}
+        "abandon" => {
+            let bp = match which {
+                Some(which) => match find_paused_project(state, which) {
+                    Some(index) => state.paused_projects.remove(index),
+                    None => {
+                        return InteractionResult::invalid(format!(
+                            "You have no project '{}' set aside.",
+                            which
+                        ))
+                    }
+                },
+                None => match state.player.active_project.take() {
+                    Some(bp) => bp,
+                    None => {
+                        return InteractionResult::invalid(
+                            "You aren't working on anything.".to_string(),
+                        )
+                    }
+                },
+            };
+            InteractionResult::Success(abandon_project(state, bp))
         }
-
-        let time_cost = ((bp.time_cost + 9) / 10).max(1);
-        let energy_cost = (time_cost as f32 * 2.0).max(5.0);
-
-        return InteractionResult::ActionSuccess {
-            message: format!(
-                "You finish crafting the {}. It is ready to use.",
-                bp.target_item.name()
-            ),
-            time_cost,
-            energy_cost,
-        };
+        _ => InteractionResult::invalid(format!(
+            "'{}' isn't something to do with projects. Use list, switch, resume, pause or abandon.",
+            action
+        )),
     }
+}
 
-    InteractionResult::Failure("Something went wrong with the blueprint.".to_string())
+/// How to title and fill a blank book, in the player's language.
+pub fn write_syntax(lang: Language) -> &'static str {
+    lang.pick(
+        "Title it with 'write title:<title> on blank book', then fill it with 'write page1:<text> on <book id or title>'.",
+        "'write 제목:<제목> on 빈 책'으로 제목을 붙이고, 'write 페이지1:<내용> on <책 ID 또는 제목>'으로 내용을 쓰세요.",
+    )
 }
 
-fn handle_foraging(
-    state: &mut GameState,
-    tool: Option<&Item>,
-    map: &WorldMap,
-) -> InteractionResult {
-    let mut rng = rand::thread_rng();
-    let skill = state.player.effective_skill("foraging");
-    let pos = state.player.position;
+/// What a `write` call asks for; `제목:`/`title:` and `페이지N:`/`pageN:` are
+/// the same thing in either language.
+enum WriteSpec<'a> {
+    Title(&'a str),
+    Page(usize, &'a str),
+}
 
-    // Check energy
-    if state.player.energy < 5.0 {
-        return InteractionResult::Failure("You are too exhausted to forage.".to_string());
+fn parse_write_spec(content: &str) -> Option<WriteSpec<'_>> {
+    let (head, body) = content
+        .split_once(':')
+        .or_else(|| content.split_once('：'))?;
+    let head = head.trim().to_lowercase();
+    if head == "제목" || head == "title" {
+        return Some(WriteSpec::Title(body.trim()));
     }
+    let number = head
+        .strip_prefix("페이지")
+        .or_else(|| head.strip_prefix("page"))?
+        .trim();
+    Some(WriteSpec::Page(number.parse().unwrap_or(0), body.trim()))
+}
 
-    let tool_bonus = matches!(
-        tool,
-        Some(Item::Knife | Item::StoneKnife | Item::Axe | Item::StoneAxe)
-    );
-    let success_chance =
-        (0.6 + (skill as f64 * 0.005) + if tool_bonus { 0.1 } else { 0.0 }).min(0.95);
-
-    // Local biome can tilt what we find
-    let biome = pos
-        .as_usize()
-        .and_then(|(r, c)| map.get_tile(r, c).map(|t| t.biome))
-        .unwrap_or(Biome::MixedForest);
+/// "blank book" and "빈 책" (with or without the space) name the same thing.
+fn is_blank_book_target(target: &str) -> bool {
+    let compact: String = target
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    matches!(compact.as_str(), "빈책" | "blankbook" | "emptybook")
+        || Item::from_str(target) == Some(Item::BlankBook)
+}
 
-    // Forage node depletion
-    state.foraging_node_for(pos, map, &mut rng);
-    let depleted = state
-        .forage_nodes
-        .get(&pos)
-        .map(|n| n.charges == 0)
-        .unwrap_or(false);
-    if depleted {
-        return InteractionResult::Failure(
-            "The brush here is picked clean. Give it some time to recover.".to_string(),
-        );
+pub fn write_on_book(text: &str, target: &str, state: &mut GameState) -> InteractionResult {
+    let lang = state.player.language;
+    let content = text.trim();
+    if content.is_empty() {
+        return InteractionResult::invalid(format!(
+            "{} {}",
+            lang.pick("Provide text to write.", "쓸 내용을 입력하세요."),
+            write_syntax(lang)
+        ));
     }
 
-    // Drops
-    let drops = if rng.gen_bool(success_chance) {
-        // Success: always some basic materials, with better food odds in lush biomes
-
-        // Sticks: base 1, sometimes more as skill improves
-        let mut stick_count = 1;
-        if rng.gen_bool((0.3 + skill as f64 * 0.01).min(0.8)) {
-            stick_count += 1;
+    let (page_num, body) = match parse_write_spec(content) {
+        Some(WriteSpec::Title(title)) => return bind_title(state, title, target),
+        Some(WriteSpec::Page(page, body)) => (page, body),
+        None => {
+            return InteractionResult::invalid(format!(
+                "{} {}",
+                lang.pick("Unsupported write format.", "지원하지 않는 형식입니다."),
+                write_syntax(lang)
+            ))
         }
-        state.player.inventory.add(Item::Stick, stick_count);
+    };
+    if page_num == 0 {
+        return InteractionResult::invalid(
+            lang.pick(
+                "Specify a page number like page1 or 페이지2.",
+                "페이지1이나 page2처럼 페이지 번호를 적어 주세요.",
+            )
+            .to_string(),
+        );
+    }
 
-        // Plant fiber: more common with tools and skill
-        let fiber_chance = (0.35 + skill as f64 * 0.005 + if tool_bonus { 0.15 } else { 0.0 })
-            .min(0.85);
-        let fiber_rolls = if tool_bonus { 2 } else { 1 };
-        for _ in 0..fiber_rolls {
-            if rng.gen_bool(fiber_chance) {
-                state.player.inventory.add(Item::PlantFiber, 1);
-            }
-        }
+    let query = parse_book_id_from_target(Some(target)).unwrap_or_default();
+    if query.is_empty() {
+        return InteractionResult::invalid(
+            lang.pick(
+                "Please specify which book to write in, by id or title (e.g., on book-3).",
+                "어느 책에 쓸지 ID나 제목으로 알려 주세요 (예: on book-3).",
+            )
+            .to_string(),
+        );
+    }
+    if is_blank_book_target(&query) {
+        return InteractionResult::invalid(format!(
+            "{} {}",
+            lang.pick(
+                "A blank book needs a title before it has pages.",
+                "빈 책에는 먼저 제목을 붙여야 합니다.",
+            ),
+            write_syntax(lang)
+        ));
+    }
+    let book_id = state.find_book_id(&query).unwrap_or(query);
 
-        // Stone: slightly more likely than before
-        if rng.gen_bool(0.3) {
-            state.player.inventory.add(Item::PlantFiber, 1);
-        }
-        if rng.gen_bool(0.25) {
-            state.player.inventory.add(Item::Stone, 1);
-        }
+    let book_in_cabin = matches!(state.player.room, Some(Room::CabinMain))
+        && state
+            .cabin_state()
+            .map(|c| c.book_ids.iter().any(|b| b == &book_id))
+            .unwrap_or(false);
+    if !state.player_has_book(&book_id) && !book_in_cabin {
+        return InteractionResult::invalid(
+            "You need to hold the book (or be next to it in the cabin) to write in it.".to_string(),
+        );
+    }
 
-        // Food-focused drops
-        let mut food_found = 0u32;
+    let Some(book) = state.book_entry_mut(&book_id) else {
+        return InteractionResult::invalid("That book ID doesn't exist.".to_string());
+    };
+    if !book.writable {
+        return InteractionResult::invalid("This book cannot be written in.".to_string());
+    }
 
-        // Berries: more generous in forest/oasis-style biomes
-        let berry_base = 0.25;
-        let berry_biome_bonus = match biome {
-            Biome::SpringForest | Biome::MixedForest | Biome::BambooGrove | Biome::Oasis => 0.25,
-            Biome::Lake => 0.15,
-            _ => 0.0,
-        };
-        let berry_chance =
-            (berry_base + berry_biome_bonus + (skill as f64 * 0.005)).min(0.9);
-        let mut berry_count = 0u32;
-        for _ in 0..3 {
-            if rng.gen_bool(berry_chance) {
-                berry_count += 1;
-            }
-        }
-        if berry_count > 0 {
-            state
-                .player
-                .inventory
-                .add(Item::WildBerry, berry_count as u32);
-            food_found += berry_count;
-        }
+    if book_id == DEATH_NOTE_ID {
+        return write_death_note(state, page_num - 1, body.trim());
+    }
 
-        // Dates in oasis/desert edge
-        if matches!(biome, Biome::Oasis | Biome::Desert) && rng.gen_bool(0.15) {
-            state.player.inventory.add(Item::Date, 1);
-            food_found += 1;
-        }
+    book.set_page(page_num - 1, body.trim());
+    InteractionResult::ActionSuccess {
+        message: format!(
+            "You write on page {} of {} ({})",
+            page_num, book.title, book.id
+        ),
+        time_cost: 1,
+        energy_cost: 1.0,
+    }
+}
 
-        // Occasional edible herbs for tea
-        if rng.gen_bool(0.12) {
-            state.player.inventory.add(Item::WildHerbs, 1);
-            food_found += 1;
-        }
+fn bind_title(state: &mut GameState, title: &str, target: &str) -> InteractionResult {
+    let lang = state.player.language;
+    if title.is_empty() {
+        return InteractionResult::invalid(
+            lang.pick(
+                "Please provide a title after 'title:' or '제목:'.",
+                "'제목:'이나 'title:' 뒤에 제목을 적어 주세요.",
+            )
+            .to_string(),
+        );
+    }
+    if !is_blank_book_target(target) {
+        return InteractionResult::invalid(format!(
+            "{} {}",
+            lang.pick(
+                "Titles go on a blank book.",
+                "제목은 빈 책에만 붙일 수 있습니다.",
+            ),
+            write_syntax(lang)
+        ));
+    }
+    if !state.player.inventory.has(&Item::BlankBook, 1) {
+        return InteractionResult::missing(
+            Item::BlankBook,
+            lang.pick(
+                "You need a blank book to bind a title.",
+                "제목을 붙이려면 빈 책이 필요합니다.",
+            )
+            .to_string(),
+        );
+    }
+    state.player.inventory.remove(&Item::BlankBook, 1);
+    state.player.inventory.add(Item::Book, 1);
+    let id = state.generate_book_id();
+    let entry = BookEntry::new(id.clone(), title.to_string(), true);
+    state.register_book(entry);
+    state.add_player_book(&id);
+    InteractionResult::ActionSuccess {
+        message: format!(
+            "You title the book and bind it. Book ID: {}. Write in it by id or by its title, '{}'.",
+            id, title
+        ),
+        time_cost: 1,
+        energy_cost: 1.0,
+    }
+}
 
-        state.player.skills.improve("foraging", 1);
-        if let Some(node) = state.forage_nodes.get_mut(&pos) {
-            node.charges = node.charges.saturating_sub(1);
-            if node.charges == 0 {
-                node.cooldown = 12;
-            }
+/// The Death Note takes a few names before it's spent. An animal's kind
+/// answers to it; the writer, the duck and the companions don't; anything
+/// else is gone from the page by morning.
+fn write_death_note(state: &mut GameState, page: usize, name: &str) -> InteractionResult {
+    let wanted = name.to_lowercase();
+    let own_name = state.player.name.as_deref().map(str::to_lowercase);
+    let duck_name = state.custom_name(&Item::RubberDuck).map(str::to_lowercase);
+    let refused = matches!(wanted.as_str(), "me" | "myself" | "self" | "rubber duck" | "duck")
+        || own_name.as_deref() == Some(wanted.as_str())
+        || duck_name.as_deref() == Some(wanted.as_str())
+        || state.wildlife.iter().any(|w| {
+            w.tamed && w.name.as_deref().map(str::to_lowercase).as_deref() == Some(wanted.as_str())
+        });
+    if refused {
+        let mut message = format!(
+            "You write '{}'. The ink beads up like rain on waxed cloth and won't sink in. \
+            Letter by letter it slides off the page, and the paper is left clean. \
+            Somewhere on the table, the duck goes on smiling.",
+            name
+        );
+        if !state.death_note.refusal_achievement {
+            state.death_note.refusal_achievement = true;
+            message.push_str(" (Achievement unlocked: Not That Name. Some books have standards.)");
         }
+        return InteractionResult::ActionSuccess {
+            message,
+            time_cost: 1,
+            energy_cost: 1.0,
+        };
+    }
 
-        if let Some(t) = tool {
-            state.damage_tool(t, 1, "foraging");
-        }
+    if state.death_note.ink == 0 {
+        return InteractionResult::invalid(
+            "The pen scratches across the page, but the Death Note's ink has run dry. Nothing takes."
+                .to_string(),
+        );
+    }
+    state.death_note.ink -= 1;
+    if let Some(book) = state.book_entry_mut(DEATH_NOTE_ID) {
+        book.set_page(page, name);
+    }
 
-        InteractionResult::ActionSuccess {
-            message: if food_found > 0 {
-                "You rummage through the bushes and come away with something to eat and a handful of useful materials."
-                    .to_string()
-            } else {
-                "You rummage through the brush and find useful materials.".to_string()
-            },
-            time_cost: 1, // 10 mins
-            energy_cost: 5.0,
+    let species = state
+        .wildlife
+        .iter()
+        .filter(|w| w.alive && !w.tamed)
+        .map(|w| w.species)
+        .find(|sp| {
+            let n = sp.name();
+            wanted == n || wanted == format!("{}s", n) || wanted == format!("{}es", n)
+        });
+    let mut message = match species {
+        Some(species) => {
+            let ticks = rand::thread_rng().gen_range(1..=3);
+            state.death_note.pending.push((species, ticks));
+            format!(
+                "You write '{}'. The ink sinks into the paper like water into sand. Somewhere, a {} pauses.",
+                name,
+                species.name()
+            )
         }
-    } else {
-        InteractionResult::ActionSuccess {
-            message: "You search for a while but find nothing useful.".to_string(),
-            time_cost: 1,
-            energy_cost: 3.0,
+        None => {
+            state.death_note.fading.push(page);
+            format!(
+                "You write '{}'. The letters already look faint, as if the page doesn't quite believe them.",
+                name
+            )
         }
     };
-    drops
+    if state.death_note.ink == 0 {
+        message.push_str(" The last of the ink goes into the final stroke.");
+    }
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 1,
+        energy_cost: 1.0,
+    }
 }
 
-fn try_chop_firewood(state: &mut GameState, tool: &Item) -> InteractionResult {
-    if !matches!(state.player.room, Some(Room::WoodShed)) {
-        return InteractionResult::Failure("Go to the wood shed to chop firewood.".to_string());
+/// Sweep, stack, and straighten the cabin's main room.
+pub fn try_tidy(state: &mut GameState) -> InteractionResult {
+    if !matches!(state.player.room, Some(Room::CabinMain)) {
+        return InteractionResult::invalid(
+            "There's nothing here to tidy. The cabin's main room is where your things gather."
+                .to_string(),
+        );
     }
-    // ... (Simplified logic for brevity, using ActionSuccess)
-    if let Some(wood_shed) = state.wood_shed_state_mut() {
-        if wood_shed.logs > 0 {
-            wood_shed.logs -= 1;
-            state.player.inventory.add(Item::Firewood, 3);
-            state.player.skills.improve("woodcutting", 2);
-            let result = InteractionResult::ActionSuccess {
-                message: "You chop a log into firewood.".to_string(),
-                time_cost: 2,
-                energy_cost: 10.0,
-            };
-            state.damage_tool(tool, 2, "splitting firewood");
-            result
-        } else {
-            InteractionResult::Failure("No logs in the shed.".to_string())
-        }
+    let Some(cabin) = state.cabin_state_mut() else {
+        return InteractionResult::invalid("The cabin seems missing its details.".to_string());
+    };
+    if cabin.tidiness >= 95.0 && cabin.snow_drifts() == 0 {
+        return InteractionResult::invalid(
+            "The cabin is already neat. You straighten a chair anyway and leave it be."
+                .to_string(),
+        );
+    }
+
+    let before = cabin.tidiness;
+    let drifts = cabin.tidy();
+    let mut message = if before < 35.0 {
+        "You roll up your sleeves and set the cabin to rights: shaking out the rug, sweeping grit from the corners, and stacking everything that had piled up on the floor.".to_string()
     } else {
-        InteractionResult::Failure("The wood shed isn't available right now.".to_string())
+        "You sweep the floorboards, wipe the dust from the sill, and stack the loose things neatly by the wall.".to_string()
+    };
+    if drifts > 0 {
+        message.push_str(" You shovel the blown-in snow back out the door.");
+    }
+    message.push_str(" The room feels calmer for it.");
+
+    state.player.modify_mood(6.0);
+    InteractionResult::ActionSuccess {
+        message,
+        time_cost: 3,
+        energy_cost: 4.0,
     }
 }
 
-// Re-implement tree chopping with ActionSuccess
-fn try_chop_tree(state: &mut GameState, _map: &WorldMap, tool: &Item) -> InteractionResult {
-    let player_pos = state.player.position;
-    let Some(tree) = state.objects.find_tree_mut_at(&player_pos) else {
-        return InteractionResult::Failure(
-            "There isn't a standing tree right here to chop.".to_string(),
-        );
+/// Take a seat on nearby furniture. Getting up happens on the next move.
+pub fn try_sit(target: Option<&str>, state: &mut GameState, map: &WorldMap) -> InteractionResult {
+    let wanted = target.map(|t| t.trim().to_lowercase()).filter(|t| !t.is_empty());
+    let seat = state
+        .seats_here()
+        .into_iter()
+        .filter_map(|po| po.object.as_seat().map(|s| (po.id.clone(), s.kind)))
+        .find(|(_, kind)| match &wanted {
+            Some(w) => {
+                let name = kind.name();
+                name.contains(w.as_str())
+                    || w.contains(name)
+                    || (w.contains("chair") && *kind == SeatKind::Armchair)
+            }
+            None => true,
+        });
+    let Some((seat_id, kind)) = seat else {
+        return InteractionResult::invalid(match wanted {
+            Some(w) => format!("There's no {} here to sit on.", w),
+            None => "There's nowhere to sit here. You could always sit on the ground and meditate."
+                .to_string(),
+        });
     };
-    if tree.felled {
-        return InteractionResult::Failure("This tree has already been felled.".to_string());
+    if state.player.seated.as_deref() == Some(seat_id.as_str()) {
+        return InteractionResult::invalid(format!(
+            "You're already sitting in the {}.",
+            kind.name()
+        ));
     }
 
-    if matches!(tree.kind, crate::entity::TreeType::Bamboo) {
-        tree.felled = true;
-        state.player.inventory.add(Item::Bamboo, 2);
-        state.player.skills.improve("woodcutting", 3);
-        let result = InteractionResult::ActionSuccess {
-            message: "You slice through the bamboo. The stalks fall neatly.".to_string(),
-            time_cost: 2,
-            energy_cost: 10.0,
-        };
-        state.damage_tool(tool, 1, "cutting bamboo");
-        return result;
+    state.player.seated = Some(seat_id);
+    if kind == SeatKind::Armchair {
+        state.player.step_to(CabinSpot::Hearth);
     }
+    let by_fire = state.seated_by_fire(map);
+    let tod = state.time.time_of_day();
 
-    tree.felled = true;
-    state.player.inventory.add(Item::Log, 2);
-    state.player.inventory.add(Item::Kindling, 1);
-    state.player.inventory.add(Item::Bark, 1);
-    state.player.skills.improve("woodcutting", 5);
-
-    let result = InteractionResult::ActionSuccess {
-        message: "You fell a tree! Timber!".to_string(),
-        time_cost: 6, // 1 hour
-        energy_cost: 20.0,
+    let mut message = match kind {
+        SeatKind::Armchair if by_fire => "You sink into the armchair by the fire. Its warmth wraps around you; this is a fine place to read or let your thoughts settle.".to_string(),
+        SeatKind::Armchair => "You sink into the worn armchair beside the cold hearth. The cushions sag comfortably.".to_string(),
+        SeatKind::TerraceBench => "You settle onto the terrace bench, the lake spread out below you.".to_string(),
+        SeatKind::Stool | SeatKind::Bench if by_fire => format!(
+            "You sit on the {} beside the campfire, close enough to feel its warmth.",
+            kind.name()
+        ),
+        SeatKind::Stool | SeatKind::Bench => format!("You sit down on the {}.", kind.name()),
     };
-    state.damage_tool(tool, 3, "chopping a tree");
-    result
-}
 
-fn handle_add_fuel(state: &mut GameState, item: Item) -> InteractionResult {
-    state.player.inventory.remove(&item, 1);
-    if let Some(cabin) = state.cabin_state_mut() {
-        if cabin.fireplace.add_fuel_item(item) {
-            state.player.skills.improve("fire_making", 1);
-            let time_cost = if matches!(item, Item::Log | Item::Firewood) {
-                2
-            } else {
-                1
-            };
-            let energy_cost = if matches!(item, Item::Log | Item::Firewood) {
-                3.0
-            } else {
-                1.0
-            };
-            return InteractionResult::ActionSuccess {
-                message: format!("You add {} to the fire.", item.name()),
-                time_cost,
-                energy_cost,
-            };
-        }
+    if kind == SeatKind::TerraceBench && matches!(tod, TimeOfDay::Dawn | TimeOfDay::Dusk) {
+        // The bench faces the lake: the best seat for the light at either end of the day.
+        state.player.modify_mood(8.0);
+        message.push_str(if matches!(tod, TimeOfDay::Dawn) {
+            " Across the water the peaks catch the first light, pink and gold, and for a while you simply watch."
+        } else {
+            " The sky over the lake burns orange and violet as the sun goes down. You let it hold your attention."
+        });
+    } else {
+        state.player.modify_mood(1.0);
     }
-    state.player.inventory.add(item, 1);
-    InteractionResult::Failure("It won't burn.".to_string())
+
+    InteractionResult::Success(message)
 }
 
-fn handle_light_fire(state: &mut GameState) -> InteractionResult {
-    if let Some(cabin) = state.cabin_state_mut() {
-        if cabin.fireplace.ignite() {
-            state.player.skills.improve("fire_making", 2);
-            return InteractionResult::ActionSuccess {
-                message: "You strike a match and the fire catches!".to_string(),
-                time_cost: 1,
-                energy_cost: 1.0,
-            };
-        } else {
-            return InteractionResult::Failure(
-                "You need tinder and fuel to start a fire.".to_string(),
-            );
-        }
+/// Perch a small mountable item (the duck, above all) somewhere it can ride
+/// along or keep watch: a shoulder, the raft's prow, the table, or the mantel.
+/// Take something from the pack into hand. Whatever that crowds out of
+/// the hands goes back in the pack.
+pub fn try_hold(item_name: &str, state: &mut GameState) -> InteractionResult {
+    let Some(item) = state.resolve_item(item_name) else {
+        return InteractionResult::invalid(format!("You don't know what '{}' is.", item_name));
+    };
+    let label = state.display_name(&item);
+    if !state.player.inventory.has(&item, 1) {
+        return InteractionResult::missing(item, format!("You don't have a {}.", label));
+    }
+    if state.player.hands.main == Some(item) {
+        return InteractionResult::invalid(format!("You already have the {} in hand.", label));
+    }
+    let mut message = format!("You take the {} in hand.", label);
+    if let Some(stowed) = state.player.hands.take_up(item) {
+        message.push_str(&format!(
+            " You stow the {} to free a hand.",
+            state.display_name(&stowed)
+        ));
     }
-    InteractionResult::Failure("There's no hearth here.".to_string())
+    InteractionResult::Success(message)
 }
 
-fn handle_consumption(state: &mut GameState, item: Item) -> InteractionResult {
-    state.player.inventory.remove(&item, 1);
-    let message = match item {
-        Item::Apple => {
-            state.player.modify_fullness(15.0);
-            "You eat the apple.".to_string()
-        }
-        Item::WildBerry => {
-            state.player.modify_fullness(5.0);
-            state.player.modify_mood(2.0);
-            "You snack on the berries.".to_string()
-        }
-        Item::Date => {
-            state.player.modify_fullness(10.0);
-            state.player.modify_hydration(8.0);
-            state.player.modify_mood(2.0);
-            "Sweet dates revive you with a burst of sugar and moisture.".to_string()
-        }
-        Item::CleanWater => {
-            state.player.modify_hydration(25.0);
-            state.player.modify_energy(2.0);
-            "You drink the clean water. It tastes refreshing.".to_string()
-        }
-        Item::MuddyWater => {
-            state.player.modify_hydration(8.0);
-            state.player.modify_health(-4.0);
-            state.player.modify_mood(-3.0);
-            "You choke down the muddy water. It sits poorly in your stomach.".to_string()
-        }
-        Item::SmallFish | Item::Fish => {
-            state.player.modify_fullness(14.0);
-            state.player.modify_health(-1.0);
-            state.player.modify_mood(-2.0);
-            "You swallow the raw fish. It's briny and not entirely pleasant.".to_string()
-        }
-        Item::BigFish => {
-            state.player.modify_fullness(22.0);
-            state.player.modify_health(-2.0);
-            state.player.modify_mood(-3.0);
-            "You eat chunks of raw fish. It fills you, though it sits heavy.".to_string()
-        }
-        Item::CookedFish => {
-            state.player.modify_fullness(30.0);
-            state.player.modify_mood(4.0);
-            "You eat the warm, cooked fish. Protein and warmth spread through you.".to_string()
-        }
-        Item::RawMeat => {
-            state.player.modify_fullness(18.0);
-            state.player.modify_health(-2.0);
-            state.player.modify_mood(-3.0);
-            "You chew the raw meat. It fills you, but your stomach protests.".to_string()
-        }
-        Item::CookedMeat => {
-            state.player.modify_fullness(32.0);
-            state.player.modify_mood(6.0);
-            "You eat the cooked meat. Rich warmth and strength spread through your body."
-                .to_string()
-        }
-        Item::CookedBerries => {
-            state.player.modify_fullness(12.0);
-            state.player.modify_mood(6.0);
-            "You munch on the roasted berries. Sweet and tart.".to_string()
-        }
-        Item::HerbalTea => {
-            state.player.modify_hydration(15.0);
-            state.player.modify_mood(5.0);
-            state.player.modify_warmth(3.0);
-            "You sip the herbal tea, feeling calm and warm.".to_string()
+/// Put something in hand back in the pack, or everything with "all".
+pub fn try_stow(item_name: &str, state: &mut GameState) -> InteractionResult {
+    if state.player.hands.is_empty() {
+        return InteractionResult::invalid("Your hands are already empty.".to_string());
+    }
+    let held: Vec<Item> = if is_all_phrase(item_name) {
+        state.player.hands.items().collect()
+    } else {
+        let Some(item) = state.resolve_item(item_name) else {
+            return InteractionResult::invalid(format!("You don't know what '{}' is.", item_name));
+        };
+        if !state.player.hands.holds(&item) {
+            return InteractionResult::invalid(format!(
+                "You aren't holding the {}.",
+                state.display_name(&item)
+            ));
         }
-        _ => format!("You consume the {}.", item.name()),
+        vec![item]
     };
-
-    InteractionResult::ActionSuccess {
-        message,
-        time_cost: 1,
-        energy_cost: 0.0,
+    for item in &held {
+        state.player.hands.stow(item);
     }
+    let names: Vec<String> = held.iter().map(|i| state.display_name(i)).collect();
+    InteractionResult::Success(format!("You put the {} away.", names.join(" and the ")))
 }
 
-pub fn try_fish(
-    state: &mut GameState,
-    map: &WorldMap,
-    gear_hint: Option<&str>,
-) -> InteractionResult {
-    let pos = state.player.position;
-    let mut near_water = false;
+pub fn try_mount(item_name: &str, target: &str, state: &mut GameState) -> InteractionResult {
+    let Some(item) = state.resolve_item(item_name) else {
+        return InteractionResult::invalid(format!("You don't know what '{}' is.", item_name));
+    };
+    let label = state.display_name(&item);
+    if !item.is_mountable() {
+        return InteractionResult::invalid(format!(
+            "The {} won't perch anywhere; it isn't that kind of thing.",
+            label
+        ));
+    }
+    if !state.player.inventory.has(&item, 1) {
+        return InteractionResult::missing(
+            item,
+            format!("You need to be holding the {} to perch it somewhere.", label),
+        );
+    }
 
-    'outer: for dr in -1..=1 {
-        for dc in -1..=1 {
-            let check = Position::new(pos.row + dr, pos.col + dc);
-            if let Some((r, c)) = check.as_usize() {
-                if let Some(tile) = map.get_tile(r, c) {
-                    if matches!(tile.biome, Biome::Lake | Biome::Oasis) {
-                        near_water = true;
-                        break 'outer;
-                    }
-                }
-            }
+    let target = target.trim().to_lowercase();
+    if let Some(placed) = place_on_surface(&target, state, item) {
+        return placed;
+    }
+
+    if target.contains("shoulder") {
+        if let Some(existing) = state.player.mounts.shoulder {
+            return InteractionResult::invalid(format!(
+                "The {} is already riding on your shoulder.",
+                state.display_name(&existing)
+            ));
         }
+        state.player.inventory.remove(&item, 1);
+        state.player.mounts.shoulder = Some(item);
+        let message = if item == Item::RubberDuck {
+            format!(
+                "You set the {} on your shoulder. It wobbles, then settles, facing forward as if it means to see where you're going.",
+                label
+            )
+        } else {
+            format!("You settle the {} on your shoulder.", label)
+        };
+        return InteractionResult::ItemLost(item, message);
     }
 
-    if !near_water {
-        return InteractionResult::Failure(
-            "You need to be right by the lake or oasis shore to fish.".to_string(),
-        );
+    if target.contains("raft") || target.contains("prow") || target.contains("bow") {
+        if !state.player.inventory.has(&Item::Raft, 1) && !state.raft_within_reach() {
+            return InteractionResult::invalid(
+                "You'd need the raft with you to perch anything on its prow.".to_string(),
+            );
+        }
+        if let Some(existing) = state.player.mounts.raft_prow {
+            return InteractionResult::invalid(format!(
+                "The {} already rides on the raft's prow.",
+                state.display_name(&existing)
+            ));
+        }
+        state.player.inventory.remove(&item, 1);
+        state.player.mounts.raft_prow = Some(item);
+        let message = if item == Item::RubberDuck {
+            format!(
+                "You wedge the {} into a notch at the raft's prow. A proper figurehead.",
+                label
+            )
+        } else {
+            format!("You lash the {} to the raft's prow.", label)
+        };
+        return InteractionResult::ItemLost(item, message);
     }
 
-    if state.player.energy < 5.0 {
-        return InteractionResult::Failure("You are too exhausted to fish right now.".to_string());
+    InteractionResult::invalid(
+        "Where should it perch? Try your shoulder, the raft, the table, or the mantel."
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Inventory, Tree, Wildlife};
+
+    fn outdoors() -> (GameState, WorldMap) {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.player.room = None;
+        state.player.inside = None;
+        state.player.position = Position::new(3, 3);
+        (state, map)
     }
 
-    let has_rod = state.player.inventory.has(&Item::FishingRod, 1);
-    let wants_rod = gear_hint
-        .map(|g| {
-            let lower = g.to_lowercase();
-            lower.contains("rod") || lower.contains("pole")
-        })
-        .unwrap_or(has_rod);
-    if wants_rod && !has_rod {
-        return InteractionResult::Failure(
-            "You reach for a rod, but you don't have one with you.".to_string(),
+    fn with_tree(state: &mut GameState, kind: TreeType) -> Position {
+        let pos = state.player.position;
+        state.objects.placed.retain(|p| p.position != pos);
+        state.objects.add(
+            "test-tree",
+            pos,
+            WorldObject::new(ObjectKind::Tree(Tree::new(pos, kind))),
         );
+        pos
     }
-    let using_rod = wants_rod && has_rod;
 
-    let weather_here = state.weather.get_for_position(pos.row, pos.col);
-    let tod = state.time.time_of_day();
-    let stormy = matches!(
-        weather_here,
-        Weather::HeavyRain | Weather::HeavySnow | Weather::Blizzard | Weather::Sandstorm
-    );
+    #[test]
+    fn examining_a_fruiting_apple_tree_counts_its_fruit() {
+        let (mut state, map) = outdoors();
+        let pos = with_tree(&mut state, TreeType::Apple);
+        let tree = state.objects.find_tree_mut_at(&pos).unwrap();
+        tree.fruit_stage = FruitStage::Ripe;
+        tree.fruit_count = 4;
+        while state.player.skills.get(Skill::Observation) < 30 {
+            state.player.skills.improve(Skill::Observation, 255);
+        }
+        let text = examine("apple tree", &state, &map);
+        assert!(text.contains("ripened"), "{}", text);
+        assert!(text.contains("You count 4 apples"), "{}", text);
+    }
 
-    let mut outcomes: Vec<(&str, u32)> = if using_rod {
-        vec![("small", 45), ("big", 18), ("trash", 12), ("nothing", 25)]
-    } else {
-        vec![("small", 25), ("big", 6), ("trash", 20), ("nothing", 49)]
-    };
+    #[test]
+    fn a_tapped_pine_shows_its_tap() {
+        let (mut state, mut map) = outdoors();
+        with_tree(&mut state, TreeType::Pine);
+        state.player.inventory.add(Item::Knife, 1);
+        assert!(!examine("pine", &state, &map).contains("tap cut"));
+        try_use("knife", Some("pine tree"), &mut state, &mut map);
+        assert!(state.player.inventory.has(&Item::Sap, 1));
+        assert!(examine("pine", &state, &map).contains("tap cut"));
+    }
 
-    if matches!(tod, TimeOfDay::Dawn | TimeOfDay::Dusk | TimeOfDay::Evening) {
-        outcomes[0].1 += 6;
-        outcomes[1].1 += 4;
-        outcomes[3].1 = outcomes[3].1.saturating_sub(8);
+    #[test]
+    fn a_felled_tree_is_a_stump() {
+        let (mut state, map) = outdoors();
+        let pos = with_tree(&mut state, TreeType::Birch);
+        state.objects.find_tree_mut_at(&pos).unwrap().fell(1);
+        assert!(examine("stump", &state, &map).contains("birch stump"));
     }
 
-    if stormy {
-        outcomes[0].1 = outcomes[0].1.saturating_sub(5);
-        outcomes[1].1 = outcomes[1].1.saturating_sub(3);
-        outcomes[2].1 += 6;
-        outcomes[3].1 += 6;
+    #[test]
+    fn nests_and_hives_show_up_on_examine() {
+        let (mut state, map) = outdoors();
+        let (pos, kind, nest) = (-20..20)
+            .flat_map(|row| (-20..20).map(move |col| Position::new(row, col)))
+            .flat_map(|pos| [(pos, TreeType::Pine), (pos, TreeType::Apple)])
+            .find_map(|(pos, kind)| Tree::new(pos, kind).nest().map(|n| (pos, kind, n)))
+            .unwrap();
+        state.player.position = pos;
+        with_tree(&mut state, kind);
+        assert!(examine("tree", &state, &map).contains(nest.line()));
     }
 
-    let skill_bonus = (state.player.effective_skill("survival") as u32 / 12)
-        + (state.player.effective_skill("observation") as u32 / 20);
-    if skill_bonus > 0 {
-        outcomes[0].1 += skill_bonus;
-        outcomes[3].1 = outcomes[3].1.saturating_sub(skill_bonus.min(outcomes[3].1));
+    #[test]
+    fn things_named_after_trees_are_not_trees() {
+        let (mut state, map) = outdoors();
+        with_tree(&mut state, TreeType::Pine);
+        let tree_text = examine("pine", &state, &map);
+        for phrase in ["pinecone", "pine nuts", "birch bark"] {
+            assert_ne!(examine(phrase, &state, &map), tree_text, "{}", phrase);
+        }
+        state.player.inventory.add(Item::Pinecone, 1);
+        assert!(examine("pinecone", &state, &map).contains(Item::Pinecone.description()));
+        assert_eq!(examine("pine", &state, &map), tree_text);
     }
 
-    let total: u32 = outcomes.iter().map(|(_, w)| *w).sum::<u32>().max(1);
-    let roll = rand::thread_rng().gen_range(0..total);
-    let mut cursor = 0;
-    let chosen = outcomes
-        .iter()
-        .find(|(_, weight)| {
-            cursor += *weight;
-            roll < cursor
-        })
-        .map(|(name, _)| *name)
-        .unwrap_or("nothing");
+    #[test]
+    fn catching_what_isnt_about_names_it() {
+        let (mut state, map) = outdoors();
+        state.time.hour = 12;
+        let text = match try_catch("lights", &mut state, &map) {
+            InteractionResult::Failure(Failure::InvalidTarget(text)) => text,
+            _ => panic!("caught something at noon"),
+        };
+        assert!(text.starts_with("You don't see any fireflies"), "{}", text);
+    }
 
-    let mut time_cost = if using_rod { 2 } else { 1 };
-    let mut energy_cost = if using_rod { 5.0 } else { 4.0 };
-    if stormy {
-        time_cost += 1;
-        energy_cost += 2.0;
+    #[test]
+    fn seats_set_down_together_keep_their_own_ids() {
+        let (mut state, mut map) = outdoors();
+        state.player.inventory.add(Item::WoodenStool, 2);
+        try_drop("stool", &mut state, &mut map);
+        try_drop("stool", &mut state, &mut map);
+        let ids: Vec<&str> = state
+            .objects
+            .placed
+            .iter()
+            .filter(|p| p.position == state.player.position)
+            .filter(|p| matches!(p.object.kind, ObjectKind::Seat(_)))
+            .map(|p| p.id.as_str())
+            .collect();
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
     }
 
-    let message = match chosen {
-        "small" => {
-            if !state.player.inventory.add(Item::SmallFish, 1) {
-                return InteractionResult::Failure(
-                    "Your pack is too heavy to stow the fish.".to_string(),
-                );
-            }
-            state.player.skills.improve("survival", 2);
-            state.player.skills.improve("observation", 1);
-            "You feel a quick tug and pull up a small fish, cool and slick in your hand."
-                .to_string()
-        }
-        "big" => {
-            if !state.player.inventory.add(Item::BigFish, 1) {
-                return InteractionResult::Failure(
-                    "The catch is too heavy for your current pack.".to_string(),
-                );
-            }
-            state.player.skills.improve("survival", 3);
-            state.player.skills.improve("observation", 1);
-            time_cost += 1;
-            energy_cost += 1.0;
-            "A strong pull bends your line. After a short struggle you haul in a hefty fish."
-                .to_string()
-        }
-        "trash" => {
-            if !state.player.inventory.add(Item::Driftwood, 1) {
-                return InteractionResult::Failure(
-                    "You snag some driftwood, but you're carrying too much to keep it.".to_string(),
-                );
+    #[test]
+    fn roasting_trains_cooking_and_a_practiced_cook_saves_a_tick() {
+        let mut spent = Vec::new();
+        for level in [19, 20] {
+            let mut map = WorldMap::new();
+            let mut state = GameState::new(&map);
+            let (w, clear) = (&mut state.weather, Weather::Clear);
+            (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+            state.player.room = Some(Room::CabinMain);
+            state.cabin_state_mut().unwrap().fireplace.state = FireState::Burning;
+            while state.player.skills.get(Skill::Cooking) < level {
+                state.player.skills.improve(Skill::Cooking, 1);
             }
-            state.player.skills.improve("survival", 1);
-            "Your line goes taut on something lifeless. You drag in a piece of driftwood."
-                .to_string()
-        }
-        _ => {
-            state.player.skills.improve("survival", 1);
-            "You wait with quiet patience, but nothing bites this time.".to_string()
+            state.player.inventory.add(Item::WildBerry, 2);
+            let day = state.time.day;
+            let xp = state.player.skills.gained_on(Skill::Cooking, day).0;
+
+            let roast = try_use(Item::WildBerry.name(), Some("fire"), &mut state, &mut map);
+            let InteractionResult::ActionSuccess { time_cost, .. } = roast else {
+                panic!("the berries didn't roast at cooking {}", level);
+            };
+            assert!(state.player.inventory.has(&Item::CookedBerries, 1));
+            assert!(state.player.skills.gained_on(Skill::Cooking, day).0 > xp);
+            spent.push(time_cost);
         }
-    };
+        assert_eq!(spent, [2, 1]);
+    }
 
-    if using_rod {
-        state.damage_tool(&Item::FishingRod, 1, "casting for fish");
+    #[test]
+    fn every_kind_of_throw_lands_where_it_should() {
+        let (mut state, mut map) = outdoors();
+        let here = state.player.position;
+        let on_ground = |map: &WorldMap, spots: &[Position], item: Item| -> u32 {
+            let count = |p: &Position| map.tile_at(p).map(|t| t.items.count(&item));
+            spots.iter().filter_map(count).sum()
+        };
+        let lost = |result: &InteractionResult| matches!(result, InteractionResult::ItemLost(..));
+
+        // A stone at a rabbit either hurts it or sends it running
+        let beside = [here.move_in_direction(Direction::East)];
+        state.wildlife = vec![Wildlife::new(Species::Rabbit, beside[0])];
+        let wariness = state.wildlife[0].wariness;
+        let stones = on_ground(&map, &beside, Item::Stone);
+        state.player.inventory.add(Item::Stone, 1);
+        let at_rabbit = try_throw("stone", Some("rabbit"), None, false, &mut state, &mut map);
+        assert!(matches!(at_rabbit, InteractionResult::ActionSuccess { .. }));
+        assert_eq!(on_ground(&map, &beside, Item::Stone), stones + 1);
+        let rabbit = state.wildlife.first();
+        assert!(rabbit.map(|w| w.wariness > wariness).unwrap_or(true));
+
+        // Food thrown to a dog feeds it where it stands
+        state.wildlife = vec![Wildlife::new(Species::Dog, beside[0])];
+        state.player.inventory.add(Item::RawMeat, 1);
+        let to_dog = try_throw("raw meat", Some("dog"), None, false, &mut state, &mut map);
+        assert!(matches!(to_dog, InteractionResult::ActionSuccess { .. }));
+        assert!(state.wildlife[0].tamed);
+        assert!(!state.player.inventory.has(&Item::RawMeat, 1));
+        state.wildlife.clear();
+
+        // Anything else lands a pace or two off in the direction thrown
+        let dirs = [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ];
+        let dir = *dirs
+            .iter()
+            .find(|d| {
+                let near = here.move_in_direction(**d);
+                [near, near.move_in_direction(**d)]
+                    .iter()
+                    .all(|p| map.tile_at(p).is_some() && !map.is_open_water(p))
+            })
+            .unwrap();
+        let near = here.move_in_direction(dir);
+        let landings = [near, near.move_in_direction(dir)];
+        let sticks = on_ground(&map, &landings, Item::Stick);
+        state.player.inventory.add(Item::Stick, 1);
+        let stick = try_throw("stick", None, Some(dir), false, &mut state, &mut map);
+        assert!(lost(&stick));
+        assert_eq!(on_ground(&map, &landings, Item::Stick), sticks + 1);
+
+        // The card case bursts open where it lands
+        state.player.inventory.add(Item::CardCase, 1);
+        state.card_case_cards_inside = 52;
+        let case = try_throw("card case", None, Some(dir), false, &mut state, &mut map);
+        assert!(lost(&case));
+        let census = state.card_census(&map);
+        assert_eq!((census.in_case, census.on_ground), (0, 52));
+
+        // The duck is allowed, but it costs
+        state.player.inventory.add(Item::RubberDuck, 1);
+        let mood = state.player.mood;
+        let duck = try_throw("rubber duck", None, Some(dir), false, &mut state, &mut map);
+        assert!(lost(&duck));
+        assert!(state.player.mood < mood);
+        assert!(state.duck_throw_achievement);
+        assert_eq!(on_ground(&map, &landings, Item::RubberDuck), 1);
+
+        // Into the lake only when the loss is accepted
+        let (shore, out) = map
+            .positions()
+            .filter(|p| map.can_stand_at(p))
+            .flat_map(|p| dirs.map(|d| (p, d)))
+            .find(|(p, d)| map.is_open_water(&p.move_in_direction(*d)))
+            .unwrap();
+        state.player.position = shore;
+        state.player.inventory.add(Item::Stone, 1);
+        let kept = try_throw("stone", None, Some(out), false, &mut state, &mut map);
+        assert!(!lost(&kept));
+        assert!(state.player.inventory.has(&Item::Stone, 1));
+        let sunk = try_throw("stone", None, Some(out), true, &mut state, &mut map);
+        assert!(lost(&sunk));
+        assert!(!state.player.inventory.has(&Item::Stone, 1));
     }
 
-    InteractionResult::ActionSuccess {
-        message,
-        time_cost,
-        energy_cost,
+    #[test]
+    fn a_hurt_arm_makes_chopping_miss_more() {
+        let (mut state, _) = outdoors();
+        let healthy = chop_success_chance(&state.player);
+        let arm = state.player.body.part_mut(BodyPartKind::ArmRight).unwrap();
+        arm.hp = arm.max_hp * 0.1;
+        let hurt = chop_success_chance(&state.player);
+        assert!(hurt < healthy - 0.1, "{} vs {}", hurt, healthy);
+
+        // A splint gives some of it back
+        let arm = state.player.body.part_mut(BodyPartKind::ArmRight).unwrap();
+        arm.add_condition(PartCondition::Splinted);
+        let splinted = chop_success_chance(&state.player);
+        assert!(hurt < splinted && splinted < healthy);
     }
-}
 
-// New Create command handler
-pub fn try_create(item_name: &str, state: &mut GameState) -> InteractionResult {
-    let target_item = match Item::from_str(item_name) {
-        Some(i) => i,
-        None => return InteractionResult::Failure(format!("Unknown item '{}'.", item_name)),
-    };
+    #[test]
+    fn builds_want_their_tools_and_their_ground_and_wear_the_tools() {
+        let refusal = |result: InteractionResult| match result {
+            InteractionResult::Failure(Failure::InvalidTarget(text)) => text,
+            _ => panic!("the build should have been turned down"),
+        };
+        let fit = |state: &mut GameState, map: &mut WorldMap, item: &str| {
+            try_use(item, Some("blueprint"), state, map)
+        };
+        let fresh = |known: Item| {
+            let (mut state, map) = outdoors();
+            state.player.inventory = Inventory::new();
+            state.player.known_blueprints.insert(known);
+            (state, map)
+        };
 
-    let recipe_available = Blueprint::new(target_item).is_some();
-    if !recipe_available {
-        return InteractionResult::Failure(format!("You don't know how to craft a {}.", item_name));
+        // A fishing rod can't be whittled without a knife, and blunts it
+        let (mut state, mut map) = fresh(Item::FishingRod);
+        let text = refusal(try_create("fishing rod", &mut state, &map));
+        assert!(text.contains("You need a knife on hand"));
+        let inv = &mut state.player.inventory;
+        inv.add(Item::Knife, 1);
+        inv.add(Item::Bamboo, 1);
+        inv.add(Item::Stick, 1);
+        inv.add(Item::Cordage, 1);
+        try_create("fishing rod", &mut state, &map);
+        for material in ["bamboo", "stick", "cordage"] {
+            fit(&mut state, &mut map, material);
+        }
+        assert!(state.player.inventory.has(&Item::FishingRod, 1));
+        let max = Player::tool_max_durability(&Item::Knife).unwrap();
+        assert!(state.player.tool_durability[&Item::Knife] < max);
+
+        // A raft is lashed at the water's edge, and nowhere else
+        let (mut state, mut map) = fresh(Item::Raft);
+        let inv = &mut state.player.inventory;
+        inv.add(Item::Axe, 1);
+        inv.add(Item::Log, 2);
+        inv.add(Item::Cordage, 2);
+        inv.add(Item::Stick, 1);
+        let inland = map
+            .positions()
+            .find(|p| map.can_stand_at(p) && !is_near_water(p, &map))
+            .unwrap();
+        let shore = map
+            .positions()
+            .find(|p| map.can_stand_at(p) && is_near_water(p, &map))
+            .unwrap();
+        state.player.position = inland;
+        let text = refusal(try_create("raft", &mut state, &map));
+        assert!(text.contains("must be built outdoors beside water"));
+        state.player.room = Some(Room::CabinMain);
+        let text = refusal(try_create("raft", &mut state, &map));
+        assert!(text.starts_with("There's no room for that in here."));
+        state.player.room = None;
+        state.player.position = shore;
+        try_create("raft", &mut state, &map);
+        for material in ["log", "log", "cordage", "cordage"] {
+            fit(&mut state, &mut map, material);
+        }
+        // Checked again before the last piece goes in
+        state.player.position = inland;
+        let text = refusal(fit(&mut state, &mut map, "stick"));
+        assert!(text.ends_with("You keep the stick in hand for now."));
+        state.player.position = shore;
+        fit(&mut state, &mut map, "stick");
+        assert!(state.player.inventory.has(&Item::Raft, 1));
+        let max = Player::tool_max_durability(&Item::Axe).unwrap();
+        assert!(state.player.tool_durability[&Item::Axe] < max);
+
+        // A campfire goes on open ground, not on the trodden path
+        let (mut state, map) = fresh(Item::Campfire);
+        let ground = |path: bool| {
+            map.positions()
+                .find(|p| map.can_stand_at(p) && (map.biome_at(p) == Some(Biome::Path)) == path)
+                .unwrap()
+        };
+        state.player.position = ground(true);
+        let text = refusal(try_create("campfire", &mut state, &map));
+        assert!(text.contains("must be built outdoors, off the path"));
+        state.player.position = ground(false);
+        assert!(!matches!(
+            try_create("campfire", &mut state, &map),
+            InteractionResult::Failure(_)
+        ));
     }
 
-    state.refresh_blueprint_knowledge(true);
+    #[test]
+    fn a_dead_end_use_points_at_what_would_work_from_here() {
+        let (mut state, mut map) = outdoors();
+        state.player.inventory = Inventory::new();
+        let mut tried = |item: &str, target: Option<&str>, state: &mut GameState| {
+            let result = try_use(item, target, state, &mut map);
+            let InteractionResult::Failure(Failure::InvalidTarget(text)) = result else {
+                panic!("'use {}' should have been a dead end", item);
+            };
+            text
+        };
 
-    if !state.knows_blueprint(target_item) {
-        let mut msg = format!(
-            "You haven't learned the {} blueprint yet.",
-            target_item.name()
+        // A knife and something to whittle: the knife is pointed at the log
+        state.player.inventory.add(Item::Knife, 1);
+        state.player.inventory.add(Item::Log, 1);
+        let knife = "You can't use the knife that way. Try: use knife on log → kindling.";
+        assert_eq!(tried("knife", None, &mut state), knife);
+        assert_eq!(tried("knife", Some("moon"), &mut state), knife);
+        assert_eq!(
+            tried("log", None, &mut state),
+            "You can't use the log that way. Try: use knife on log → kindling."
+        );
+        // Never more than two, in table order
+        state.player.inventory.add(Item::Stick, 1);
+        state.player.inventory.add(Item::Bamboo, 1);
+        assert_eq!(
+            tried("knife", None, &mut state),
+            "You can't use the knife that way. Try: use knife on log → kindling; use knife on stick → kindling."
+        );
+
+        // Nothing works yet: say what's missing, or where to go
+        state.player.inventory.add(Item::Stone, 1);
+        assert_eq!(
+            tried("stone", None, &mut state),
+            "You can't use the stone that way. Try: to get a sharp stone with the stone, carry 2 stone."
+        );
+        state.player.inventory.add(Item::Matchbox, 1);
+        assert_eq!(
+            tried("matchbox", None, &mut state),
+            "You can't use the matchbox that way. Try: to get a lit fire with the matchbox, be at the cabin hearth, about 4 tiles to the north-west."
         );
-        if let Some(hint) = state.blueprint_hint_text(target_item) {
-            msg.push(' ');
-            msg.push_str(hint);
-        }
-        let known = state.known_blueprint_names();
-        if !known.is_empty() {
-            msg.push_str(&format!(" Known blueprints: {}.", known.join(", ")));
-        }
-        return InteractionResult::Failure(msg);
     }
 
-    let bp = Blueprint::new(target_item).unwrap();
-    let progress = bp.progress_summary();
-    let time_cost = bp.time_cost;
-    state.player.active_project = Some(bp);
-    InteractionResult::Success(format!(
-        "You lay out plans for a {}. Requires: {}. Total build time: {} mins.",
-        target_item.name(),
-        progress,
-        time_cost
-    ))
-}
+    #[test]
+    fn chopping_makes_nearby_animals_warier_and_wariness_makes_approach_harder() {
+        let (mut state, mut map) = outdoors();
+        let here = state.player.position;
+        with_tree(&mut state, TreeType::Pine);
+        let near = Position::new(here.row, here.col + 2);
+        let far = Position::new(here.row + 12, here.col);
+        state.wildlife = vec![
+            Wildlife::new(Species::Rabbit, near),
+            Wildlife::new(Species::Rabbit, far),
+        ];
+
+        // The axe rings out to the nearby rabbit, not the distant one
+        try_chop_tree(&mut state, &mut map, &Item::Axe);
+        assert!(state.wildlife[0].wariness > 0.0);
+        assert_eq!(state.wildlife[1].wariness, 0.0);
+
+        // Skill helps; wariness, distance and a restless animal all count against
+        let chance = |skill, wary, dist| approach_chance(skill, wary, dist, Behavior::Moving);
+        assert!(chance(60.0, 0.0, 2.0) > chance(0.0, 0.0, 2.0));
+        assert!(chance(0.0, 50.0, 2.0) < chance(0.0, 0.0, 2.0));
+        assert!(chance(0.0, 0.0, 4.0) < chance(0.0, 0.0, 1.0));
+        let asleep = approach_chance(0.0, 0.0, 1.0, Behavior::Sleeping);
+        let alert = approach_chance(0.0, 0.0, 1.0, Behavior::Alert);
+        assert!(asleep > alert);
+        let hopeless = approach_chance(0.0, 100.0, 5.0, Behavior::Alert);
+        assert!((hopeless - 0.05).abs() < 1e-6, "never quite impossible");
+
+        // Borne out in practice: a calm sleeper lets you in far more often
+        let beside = Position::new(here.row, here.col + 1);
+        let mut reached = |wariness, behavior| {
+            (0..200)
+                .filter(|_| {
+                    let mut rabbit = Wildlife::new(Species::Rabbit, beside);
+                    (rabbit.wariness, rabbit.behavior) = (wariness, behavior);
+                    state.wildlife = vec![rabbit];
+                    let result = try_approach("rabbit", &mut state, &map);
+                    matches!(result, InteractionResult::ActionSuccess { message, .. }
+                        if message.contains("almost within reach"))
+                })
+                .count()
+        };
+        let calm = reached(0.0, Behavior::Sleeping);
+        let skittish = reached(Wildlife::MAX_WARINESS, Behavior::Alert);
+        assert!(calm > 100 && skittish < 40, "{} vs {}", calm, skittish);
+    }
 
-pub fn write_on_book(text: &str, target: &str, state: &mut GameState) -> InteractionResult {
-    let content = text.trim();
-    if content.is_empty() {
-        return InteractionResult::Failure("Provide text to write, e.g., 'write 제목:My Book on 빈 책' or 'write 페이지1:Hello on book-3'.".to_string());
-    }
-
-    let lower = content.to_lowercase();
-    let is_title = lower.starts_with("제목:") || lower.starts_with("title:");
-    let is_page = lower.starts_with("페이지") || lower.starts_with("page");
-
-    if is_title {
-        let title = content
-            .split_once(':')
-            .map(|(_, t)| t.trim())
-            .unwrap_or("")
-            .to_string();
-        if title.is_empty() {
-            return InteractionResult::Failure(
-                "Please provide a title after '제목:' or 'title:'.".to_string(),
-            );
-        }
-        if !state.player.inventory.has(&Item::BlankBook, 1) {
-            return InteractionResult::Failure(
-                "You need a blank book to bind a title.".to_string(),
-            );
-        }
-        state.player.inventory.remove(&Item::BlankBook, 1);
-        state.player.inventory.add(Item::Book, 1);
-        let id = state.generate_book_id();
-        let entry = BookEntry::new(id.clone(), title, true);
-        state.register_book(entry);
-        state.add_player_book(&id);
-        return InteractionResult::ActionSuccess {
-            message: format!("You title the book and bind it. Book ID: {}.", id),
-            time_cost: 1,
-            energy_cost: 1.0,
+    #[test]
+    fn bulk_moves_pick_by_category_and_stop_partway_when_full() {
+        let (mut state, mut map) = outdoors();
+        let here = state.player.position;
+        let stopped = |result: &InteractionResult| {
+            matches!(result, InteractionResult::ActionSuccess { message, .. }
+                if message.contains("Then you have to stop"))
         };
+        let lying = |map: &WorldMap, item: Item| map.tile_at(&here).unwrap().items.count(&item);
+        state.player.inventory = Inventory::new();
+        let tile = map.tile_at_mut(&here).unwrap();
+        tile.items.items.clear();
+        tile.items.add(Item::Apple, 3);
+        tile.items.add(Item::Log, 20);
+        tile.items.add(Item::Stone, 2);
+
+        // Food only: the logs and stones stay where they are
+        let food = try_take_all("all food", &mut state, &mut map);
+        assert!(matches!(food, InteractionResult::ActionSuccess { .. }));
+        assert!(state.player.inventory.has(&Item::Apple, 3));
+        assert_eq!((lying(&map, Item::Log), lying(&map, Item::Stone)), (20, 2));
+
+        // Twenty logs are more than anyone can carry
+        let all = try_take_all("all", &mut state, &mut map);
+        assert!(stopped(&all));
+        let carried = state.player.inventory.count(&Item::Log);
+        assert!(carried > 0 && carried < 20);
+        assert_eq!(lying(&map, Item::Log) + carried, 20);
+        let inventory = &state.player.inventory;
+        assert!(inventory.current_weight() <= inventory.max_weight);
+
+        // In the shed the fuel goes to the stack, and the rest only as far
+        // as the workbench has room
+        state.player.room = Some(Room::WoodShed);
+        let logs = state.wood_shed_state().unwrap().logs;
+        state.player.inventory = Inventory::new();
+        state.player.inventory.add(Item::Log, 2);
+        state.player.inventory.add(Item::Stone, 30);
+        let fuel = try_store("all fuel", &mut state, &mut map);
+        assert!(matches!(fuel, InteractionResult::ActionSuccess { .. }));
+        assert_eq!(state.wood_shed_state().unwrap().logs, logs + 2);
+        assert!(state.player.inventory.has(&Item::Stone, 30));
+        let materials = try_store("all materials", &mut state, &mut map);
+        assert!(stopped(&materials));
+        let left = state.player.inventory.count(&Item::Stone);
+        assert!(left > 0 && left < 30);
     }
 
-    if !is_page {
-        return InteractionResult::Failure("Unsupported write format. Use '제목:<title>' for blank books or '페이지<number>:<text>' for existing books.".to_string());
+    #[test]
+    fn examine_names_uses_only_for_known_blueprints_and_remembers_first_pickup() {
+        let (mut state, mut map) = outdoors();
+        let here = state.player.position;
+        state.player.inventory.add(Item::PlantFiber, 1);
+        state.player.known_blueprints.clear();
+        let fiber = examine("plant fiber", &state, &map);
+        assert!(!fiber.contains("Used in"), "{}", fiber);
+        state.player.known_blueprints.insert(Item::Cordage);
+        let fiber = examine("plant fiber", &state, &map);
+        assert!(fiber.contains("Used in: cordage (needs 3)."), "{}", fiber);
+        assert!(!fiber.contains("knife"), "{}", fiber);
+
+        // A tool remembers where it first came to hand, and only the first time
+        map.tile_at_mut(&here).unwrap().items.add(Item::Knife, 1);
+        try_take("knife", &mut state, &mut map);
+        let day = state.time.day;
+        let first = format!("You first picked it up at {},{}", here.row, here.col);
+        let knife = examine("knife", &state, &map);
+        assert!(knife.contains(&first), "{}", knife);
+        assert!(knife.contains(&format!("on day {}.", day)), "{}", knife);
+        try_drop("knife", &mut state, &mut map);
+        let there = Position::new(here.row + 1, here.col);
+        map.tile_at_mut(&there).unwrap().items.add(Item::Knife, 1);
+        state.player.position = there;
+        state.time.day += 1;
+        try_take("knife", &mut state, &mut map);
+        assert!(state.player.inventory.has(&Item::Knife, 1));
+        let knife = examine("knife", &state, &map);
+        assert!(knife.contains(&first), "{}", knife);
+        assert!(knife.contains(&format!("on day {}.", day)), "{}", knife);
+
+        // Everyday materials carry no story
+        assert!(state.item_provenance(&Item::PlantFiber).is_none());
     }
 
-    let (page_spec, body) = match content.split_once(':') {
-        Some(parts) => parts,
-        None => {
-            return InteractionResult::Failure(
-                "Use '페이지<number>:<text>' to write a page.".to_string(),
-            )
+    #[test]
+    fn every_tool_action_wears_its_tool_down_until_it_breaks_once() {
+        use crate::entity::Body;
+
+        type Setup = fn(&mut GameState);
+        let actions: [(Item, Option<&str>, Setup); 7] = [
+            (Item::Knife, Some("stick"), |_| {}),
+            (Item::Knife, Some("log"), |_| {}),
+            (Item::Knife, Some("reeds"), |_| {}),
+            (Item::Knife, Some("bamboo"), |_| {}),
+            (Item::Axe, Some("tree"), |s| {
+                with_tree(s, TreeType::Pine);
+            }),
+            (Item::Axe, Some("chopping block"), |s| {
+                s.player.room = Some(Room::WoodShed);
+                s.wood_shed_state_mut().unwrap().chopping_block.has_log = true;
+            }),
+            (Item::Shovel, None, |s| {
+                let pos = s.player.position;
+                s.sands.bury(pos, vec![(Item::Stone, 1)]);
+            }),
+        ];
+
+        // What the knife works on, carried afresh before every cut
+        let stock = [
+            (Item::Stick, 1),
+            (Item::Log, 1),
+            (Item::Reeds, 2),
+            (Item::Bamboo, 1),
+        ];
+        for (tool, target, setup) in actions {
+            let (mut state, mut map) = outdoors();
+            let max = Player::tool_max_durability(&tool).unwrap();
+            let (mut left, mut breaks) = (max, 0);
+            for _ in 0..=max {
+                state.player.room = None;
+                state.player.inventory = Inventory::new();
+                state.player.inventory.add(tool, 1);
+                for (item, qty) in stock {
+                    state.player.inventory.add(item, qty);
+                }
+                state.player.body = Body::human_default();
+                state.player.energy = 100.0;
+                setup(&mut state);
+                state.pending_messages.clear();
+                try_use(tool.name(), target, &mut state, &mut map);
+                let broke = |m: &String| m.contains("breaks while");
+                breaks += state.pending_messages.iter().filter(|m| broke(m)).count();
+                if !state.player.inventory.has(&tool, 1) {
+                    break;
+                }
+                let (now, _) = state.player.tool_condition(&tool).unwrap();
+                assert!(now < left, "{:?} on {:?} didn't wear it", tool, target);
+                left = now;
+            }
+            assert_eq!(breaks, 1, "{:?} on {:?}", tool, target);
         }
-    };
 
-    let digits: String = page_spec.chars().filter(|c| c.is_ascii_digit()).collect();
-    let page_num: usize = digits.parse().unwrap_or(0);
-    if page_num == 0 {
-        return InteractionResult::Failure(
-            "Specify a page number like 페이지1 or page2.".to_string(),
-        );
+        // A tool picked up starts out tracked at full strength
+        let (mut state, mut map) = outdoors();
+        let here = state.player.position;
+        map.tile_at_mut(&here).unwrap().items.add(Item::Shovel, 1);
+        try_take("shovel", &mut state, &mut map);
+        let full = Player::tool_max_durability(&Item::Shovel);
+        let tracked = state.player.tool_durability.get(&Item::Shovel).copied();
+        assert_eq!(tracked, full);
     }
 
-    let book_id = {
-        if let Some(start) = target.find('(') {
-            if let Some(end) = target[start + 1..].find(')') {
-                target[start + 1..start + 1 + end].trim().to_string()
-            } else {
-                target.trim().to_string()
-            }
-        } else {
-            target.trim().to_string()
+    #[test]
+    fn a_book_is_titled_written_and_read_the_same_way_in_either_language() {
+        let text = |result: InteractionResult| match result {
+            InteractionResult::Success(message)
+            | InteractionResult::ItemObtained(_, message)
+            | InteractionResult::ItemLost(_, message)
+            | InteractionResult::ActionSuccess { message, .. } => message,
+            InteractionResult::Failure(failure) => failure.into_message(),
+        };
+        let english = ["blank book", "title:", "page1:", "Snow Days", "next"];
+        let korean = ["빈 책", "제목:", "페이지1:", "눈 오는 날", "다음"];
+        for (lang, words) in [(Language::English, english), (Language::Korean, korean)] {
+            let [blank, title_key, page_key, title, next] = words;
+            let (mut state, mut map) = outdoors();
+            state.player.language = lang;
+            state.player.inventory.add(Item::BlankBook, 1);
+            let syntax = write_syntax(lang);
+            assert!(examine("blank book", &state, &map).contains(syntax));
+
+            // Half-formed attempts are explained in the player's own language
+            let bad = text(write_on_book("scribbles", blank, &mut state));
+            assert!(bad.contains(syntax), "{}", bad);
+
+            let bound = write_on_book(&format!("{}{}", title_key, title), blank, &mut state);
+            assert!(matches!(bound, InteractionResult::ActionSuccess { .. }));
+            let id = state.find_book_id(title).expect("found by its title");
+            assert!(!state.player.inventory.has(&Item::BlankBook, 1));
+
+            let line = format!("{}{}", page_key, "The lake froze overnight.");
+            let written = write_on_book(&line, title, &mut state);
+            assert!(matches!(written, InteractionResult::ActionSuccess { .. }));
+            let target = format!("{} {}", title, next);
+            let read = text(try_use("book", Some(&target), &mut state, &mut map));
+            let page = format!("[{}] — Page 1: The lake froze", id);
+            assert!(read.contains(&page), "{}", read);
         }
-    };
-
-    if book_id.is_empty() {
-        return InteractionResult::Failure(
-            "Please specify which book to write in (e.g., on book-3).".to_string(),
-        );
     }
 
-    let book_in_cabin = matches!(state.player.room, Some(Room::CabinMain))
-        && state
-            .cabin_state()
-            .map(|c| c.book_ids.iter().any(|b| b == &book_id))
-            .unwrap_or(false);
-    if !state.player_has_book(&book_id) && !book_in_cabin {
-        return InteractionResult::Failure(
-            "You need to hold the book (or be next to it in the cabin) to write in it.".to_string(),
-        );
+    #[test]
+    fn a_raft_and_cordage_can_be_swapped_without_their_materials_mixing() {
+        let (mut state, mut map) = outdoors();
+        let shore = map
+            .positions()
+            .find(|p| map.can_stand_at(p) && is_near_water(p, &map))
+            .unwrap();
+        state.player.position = shore;
+        state.player.inventory = Inventory::new();
+        for (item, count) in [(Item::Axe, 1), (Item::Log, 2), (Item::Stick, 1)] {
+            state.player.inventory.add(item, count);
+        }
+        state.player.inventory.add(Item::PlantFiber, 6);
+        state.player.known_blueprints.insert(Item::Raft);
+        state.player.known_blueprints.insert(Item::Cordage);
+        let fit = |state: &mut GameState, map: &mut WorldMap, item: &str| {
+            try_use(item, Some("blueprint"), state, map)
+        };
+        let invested = |bp: &Blueprint, item: Item| {
+            let current = bp.current.get(&item).copied().unwrap_or(0);
+            current + bp.stand_ins.get(&item).copied().unwrap_or(0)
+        };
+        let paused = |state: &GameState, item: Item| {
+            let mut projects = state.paused_projects.iter();
+            projects.find(|bp| bp.target_item == item).cloned().unwrap()
+        };
+
+        // The raft is laid out at the shore and half fitted
+        try_create("raft", &mut state, &map);
+        for material in ["log", "log", "stick", "plant fiber"] {
+            fit(&mut state, &mut map, material);
+        }
+        let raft = state.player.active_project.clone().unwrap();
+        assert_eq!(raft.anchor, Some(shore));
+        assert_eq!(invested(&raft, Item::PlantFiber), 1);
+
+        // Laying out cordage sets the raft aside with what's in it
+        let r = try_create("cordage", &mut state, &map);
+        let InteractionResult::Success(text) = r else {
+            panic!("cordage should have been laid out");
+        };
+        assert!(text.contains("its materials kept with it"), "{}", text);
+        fit(&mut state, &mut map, "plant fiber");
+        fit(&mut state, &mut map, "plant fiber");
+        let cordage = state.player.active_project.clone().unwrap();
+        assert_eq!(invested(&cordage, Item::PlantFiber), 2);
+        assert_eq!(invested(&cordage, Item::Log), 0);
+        assert_eq!(invested(&paused(&state, Item::Raft), Item::PlantFiber), 1);
+        assert_eq!(state.player.inventory.count(&Item::PlantFiber), 3);
+
+        // Both come back from a save as they were
+        let name = format!("rubber-duck-projects-{}.json", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        state.save(&path).unwrap();
+        state = GameState::load_or_new(&path, &map);
+        let _ = std::fs::remove_file(&path);
+        let raft = paused(&state, Item::Raft);
+        assert_eq!((raft.id, raft.anchor), (1, Some(shore)));
+        assert_eq!(invested(&raft, Item::Log), 2);
+        assert_eq!(state.player.active_project.as_ref().unwrap().id, 2);
+
+        // The raft is only taken up again where it was laid out
+        state.player.position = Position::new(shore.row, shore.col + 3);
+        let away = try_projects("switch", Some("raft"), &mut state);
+        assert!(matches!(away, InteractionResult::Failure(_)));
+        state.player.position = shore;
+        let back = try_projects("switch", Some("#1"), &mut state);
+        assert!(matches!(back, InteractionResult::Success(_)));
+        let raft = state.player.active_project.clone().unwrap();
+        assert_eq!(invested(&raft, Item::PlantFiber), 1);
+        let cordage = paused(&state, Item::Cordage);
+        assert_eq!(invested(&cordage, Item::PlantFiber), 2);
+
+        // Finishing the cordage leaves the raft as it was
+        try_projects("switch", Some("cordage"), &mut state);
+        fit(&mut state, &mut map, "plant fiber");
+        assert!(state.player.inventory.has(&Item::Cordage, 1));
+        assert!(state.player.active_project.is_none());
+        let raft = paused(&state, Item::Raft);
+        assert_eq!(invested(&raft, Item::Log), 2);
+        assert_eq!(invested(&raft, Item::PlantFiber), 1);
+        assert_eq!(state.paused_projects.len(), 1);
+
+        // Abandoned, the raft hands back exactly what went into it
+        try_projects("abandon", Some("raft"), &mut state);
+        let pack = &state.player.inventory;
+        assert_eq!(pack.count(&Item::Log), 2);
+        assert_eq!(pack.count(&Item::Stick), 1);
+        assert_eq!(pack.count(&Item::PlantFiber), 3);
+        assert!(state.paused_projects.is_empty());
     }
 
-    let Some(book) = state.book_entry_mut(&book_id) else {
-        return InteractionResult::Failure("That book ID doesn't exist.".to_string());
-    };
-    if !book.writable {
-        return InteractionResult::Failure("This book cannot be written in.".to_string());
+    #[test]
+    fn talk_with_nobody_near_goes_to_the_fire_the_cave_or_the_air_and_the_journal() {
+        let (mut state, map) = outdoors();
+        state.player.inventory = Inventory::new();
+        let (w, clear) = (&mut state.weather, Weather::Clear);
+        (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+        let mild = map
+            .positions()
+            .find(|p| {
+                let tile = map.tile_at(p).unwrap();
+                map.can_stand_at(p) && tile.biome == Biome::SpringForest
+            })
+            .unwrap();
+        state.player.position = mild;
+        let answered = |result: InteractionResult, pool: &[&str]| {
+            let InteractionResult::Success(text) = result else {
+                panic!("talking to yourself should always come off");
+            };
+            let reply = text.lines().nth(1).unwrap_or_default();
+            assert!(pool.iter().any(|r| reply.starts_with(r)), "{}", text);
+            text
+        };
+        let wrote = |state: &GameState| state.journal.last().cloned().unwrap_or_default();
+
+        // Out in the open air, with the duck nowhere near
+        assert!(!duck_in_earshot(&state));
+        assert_eq!(SoliloquyScene::of(&state, &map), SoliloquyScene::Open);
+        let mood = state.player.mood;
+        let open = talk_to_yourself(Some("still water"), &mut state, &map);
+        let text = answered(open, OPEN_REPLIES);
+        assert!(text.starts_with("You, to yourself: \"still water\""));
+        assert_eq!(wrote(&state), "Said aloud to no one: \"still water\"");
+        assert!((state.player.mood - mood - SOLILOQUY_MOOD).abs() < 0.01);
+
+        // Falling snow swallows it instead
+        let region = WorldMap::region_of(mild.row, mild.col);
+        state.weather.force_front(region, Weather::HeavySnow, 10);
+        let snow = talk_to_yourself(Some("so cold"), &mut state, &map);
+        answered(snow, SNOW_REPLIES);
+
+        // Nothing said, nothing written down
+        let entries = state.journal.len();
+        answered(talk_to_yourself(None, &mut state, &map), SNOW_REPLIES);
+        assert_eq!(state.journal.len(), entries);
+
+        // In the cave, the walls give it back
+        state.player.inside = Some(crate::persistence::CAVE_ID.to_string());
+        assert_eq!(SoliloquyScene::of(&state, &map), SoliloquyScene::Cave);
+        let cave = talk_to_yourself(Some("hello"), &mut state, &map);
+        answered(cave, CAVE_REPLIES);
+        assert_eq!(wrote(&state), "Said aloud in the cave: \"hello\"");
+
+        // By a lit hearth it's the fire that hears, and a cold one doesn't
+        state.player.inside = None;
+        state.player.room = Some(Room::CabinMain);
+        assert_eq!(SoliloquyScene::of(&state, &map), SoliloquyScene::Open);
+        state.cabin_state_mut().unwrap().fireplace.state = FireState::Burning;
+        assert_eq!(SoliloquyScene::of(&state, &map), SoliloquyScene::Fire);
+        let fire = talk_to_yourself(Some("thank you"), &mut state, &map);
+        let text = answered(fire, FIRE_REPLIES);
+        assert!(text.starts_with("You, to the fire: \"thank you\""));
+        assert_eq!(wrote(&state), "Said to the fire: \"thank you\"");
+
+        // With the duck in hand, it's the duck who listens
+        state.player.inventory.add(Item::RubberDuck, 1);
+        assert!(duck_in_earshot(&state));
     }
 
-    book.set_page(page_num - 1, body.trim());
-    InteractionResult::ActionSuccess {
-        message: format!(
-            "You write on page {} of {} ({})",
-            page_num, book.title, book.id
-        ),
-        time_cost: 1,
-        energy_cost: 1.0,
+    #[test]
+    fn bare_hands_grab_fish_in_the_shallows_less_often_than_a_rod_lands_them() {
+        let (mut state, mut map) = outdoors();
+        let (w, clear) = (&mut state.weather, Weather::Clear);
+        (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+        (state.time.hour, state.time.minute) = (12, 0);
+        let shore = map
+            .positions()
+            .find(|p| {
+                is_near_water(p, &map)
+                    && map.can_stand_at(p)
+                    && FishingSpot::at(&map, p, false, 15.0) == Some(FishingSpot::Shallows)
+                    && map.tile_at(p).unwrap().biome == Biome::SpringForest
+            })
+            .unwrap();
+        state.player.position = shore;
+        let fish = [Item::SmallFish, Item::Fish, Item::BigFish, Item::Pupfish];
+        let catches = |state: &mut GameState, map: &mut WorldMap, target: Option<&str>| {
+            let mut caught = 0;
+            for _ in 0..300 {
+                state.player.energy = 100.0;
+                state.fishing_pressure.clear();
+                state.player.inventory = Inventory::new();
+                if target.is_none() {
+                    state.player.inventory.add(Item::FishingRod, 1);
+                }
+                match target {
+                    Some(target) => try_use("hands", Some(target), state, map),
+                    None => try_fish(state, map, Some("rod")),
+                };
+                let pack = &state.player.inventory;
+                caught += fish.iter().filter(|f| pack.has(f, 1)).count();
+            }
+            caught
+        };
+
+        // The tutorial's "use hands on water" works at the water's edge
+        let grabbed = catches(&mut state, &mut map, Some("water"));
+        let landed = catches(&mut state, &mut map, None);
+        assert!(grabbed > 30, "{} grabbed by hand", grabbed);
+        assert!(grabbed * 3 < landed * 2, "{} to {}", grabbed, landed);
+
+        // Away from it, hands have nothing to grab at
+        state.player.position = Position::new(shore.row + 40, shore.col + 40);
+        let dry = try_use("hands", Some("water"), &mut state, &mut map);
+        assert!(matches!(dry, InteractionResult::Failure(_)));
     }
 }
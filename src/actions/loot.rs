@@ -0,0 +1,318 @@
+//! What foraging turns up, biome by biome. Every biome shares the common
+//! finds (sticks, fiber, stones, herbs) and adds its own, so balancing a
+//! drop means editing a row here rather than the forage handler.
+
+use crate::entity::Item;
+use crate::world::{Biome, Weather};
+use rand::Rng;
+
+/// One thing foraging can turn up.
+pub struct LootEntry {
+    pub item: Item,
+    /// Chance per roll before skill and tools.
+    pub chance: f64,
+    /// Added to the chance per point of foraging skill.
+    pub per_skill: f64,
+    /// Added to the chance when foraging with a blade.
+    pub tool_bonus: f64,
+    /// The chance never climbs past this.
+    pub max_chance: f64,
+    /// Independent rolls; each success yields one.
+    pub rolls: u32,
+    /// Scales the chance with the weather over the tile: snow buries the
+    /// forest floor, rain brings mushrooms up.
+    pub weather: fn(Weather) -> f64,
+    /// Said when this turns up, for finds worth a mention.
+    pub note: Option<&'static str>,
+}
+
+fn any_weather(_: Weather) -> f64 {
+    1.0
+}
+
+fn under_snow(weather: Weather) -> f64 {
+    match weather {
+        Weather::LightSnow => 0.7,
+        Weather::HeavySnow | Weather::Blizzard => 0.4,
+        _ => 1.0,
+    }
+}
+
+fn after_rain(weather: Weather) -> f64 {
+    match weather {
+        Weather::LightRain | Weather::Fog => 1.5,
+        Weather::HeavyRain => 2.0,
+        _ => 1.0,
+    }
+}
+
+fn in_sandstorm(weather: Weather) -> f64 {
+    match weather {
+        // Wind strips the sand off whatever lay buried.
+        Weather::Sandstorm => 1.5,
+        _ => 1.0,
+    }
+}
+
+const fn entry(item: Item, chance: f64, rolls: u32) -> LootEntry {
+    LootEntry {
+        item,
+        chance,
+        per_skill: 0.0,
+        tool_bonus: 0.0,
+        max_chance: 0.9,
+        rolls,
+        weather: any_weather,
+        note: None,
+    }
+}
+
+/// Finds anywhere there's undergrowth to rummage in.
+const COMMON: &[LootEntry] = &[
+    LootEntry {
+        max_chance: 1.0,
+        ..entry(Item::Stick, 1.0, 1)
+    },
+    LootEntry {
+        per_skill: 0.01,
+        max_chance: 0.8,
+        ..entry(Item::Stick, 0.3, 1)
+    },
+    LootEntry {
+        per_skill: 0.005,
+        tool_bonus: 0.15,
+        max_chance: 0.85,
+        ..entry(Item::PlantFiber, 0.35, 2)
+    },
+    entry(Item::Stone, 0.25, 1),
+    entry(Item::WildHerbs, 0.12, 1),
+    LootEntry {
+        weather: under_snow,
+        ..entry(Item::DryLeaves, 0.3, 1)
+    },
+];
+
+const BERRIES_LUSH: LootEntry = LootEntry {
+    per_skill: 0.005,
+    ..entry(Item::WildBerry, 0.5, 3)
+};
+
+const SPRING_FOREST: &[LootEntry] = &[
+    BERRIES_LUSH,
+    LootEntry {
+        weather: after_rain,
+        note: Some("Mushrooms have pushed up through the leaf litter."),
+        ..entry(Item::Mushroom, 0.15, 1)
+    },
+];
+
+const MIXED_FOREST: &[LootEntry] = &[
+    BERRIES_LUSH,
+    LootEntry {
+        per_skill: 0.004,
+        note: Some("Acorns lie scattered under an oak."),
+        ..entry(Item::Acorn, 0.25, 2)
+    },
+];
+
+const WINTER_FOREST: &[LootEntry] = &[
+    LootEntry {
+        per_skill: 0.005,
+        weather: under_snow,
+        ..entry(Item::WildBerry, 0.25, 3)
+    },
+    LootEntry {
+        per_skill: 0.004,
+        weather: under_snow,
+        note: Some("You pick pine nuts out of cones half-buried in the snow."),
+        ..entry(Item::PineNuts, 0.25, 2)
+    },
+    LootEntry {
+        weather: under_snow,
+        ..entry(Item::Pinecone, 0.2, 1)
+    },
+];
+
+const LAKE: &[LootEntry] = &[
+    LootEntry {
+        per_skill: 0.005,
+        ..entry(Item::WildBerry, 0.4, 3)
+    },
+    LootEntry {
+        tool_bonus: 0.15,
+        note: Some("You cut an armful of reeds from the shallows."),
+        ..entry(Item::Reeds, 0.35, 2)
+    },
+];
+
+const OASIS: &[LootEntry] = &[
+    BERRIES_LUSH,
+    LootEntry {
+        note: Some("A date has dropped from one of the palms."),
+        ..entry(Item::Date, 0.2, 1)
+    },
+];
+
+const DESERT: &[LootEntry] = &[
+    entry(Item::Date, 0.15, 1),
+    LootEntry {
+        weather: in_sandstorm,
+        note: Some("A nodule of flint glints grey among the pebbles."),
+        ..entry(Item::Flint, 0.2, 1)
+    },
+];
+
+const BAMBOO_GROVE: &[LootEntry] = &[
+    BERRIES_LUSH,
+    LootEntry {
+        tool_bonus: 0.1,
+        weather: after_rain,
+        note: Some("A fat bamboo shoot has broken the soil."),
+        ..entry(Item::BambooShoot, 0.25, 1)
+    },
+];
+
+const OPEN_GROUND: &[LootEntry] = &[LootEntry {
+    per_skill: 0.005,
+    ..entry(Item::WildBerry, 0.25, 3)
+}];
+
+/// The biome's own finds, on top of `COMMON`.
+pub fn biome_loot(biome: Biome) -> &'static [LootEntry] {
+    match biome {
+        Biome::SpringForest => SPRING_FOREST,
+        Biome::MixedForest => MIXED_FOREST,
+        Biome::WinterForest => WINTER_FOREST,
+        Biome::Lake => LAKE,
+        Biome::Oasis => OASIS,
+        Biome::Desert => DESERT,
+        Biome::BambooGrove => BAMBOO_GROVE,
+        Biome::Path | Biome::Clearing => OPEN_GROUND,
+    }
+}
+
+/// One successful rummage: what turned up, and anything worth remarking on.
+#[derive(Default)]
+pub struct ForageHaul {
+    pub items: Vec<(Item, u32)>,
+    pub notes: Vec<&'static str>,
+}
+
+impl ForageHaul {
+    fn add(&mut self, item: Item, qty: u32) {
+        match self.items.iter_mut().find(|(i, _)| *i == item) {
+            Some(entry) => entry.1 += qty,
+            None => self.items.push((item, qty)),
+        }
+    }
+
+    pub fn has_food(&self) -> bool {
+        self.items.iter().any(|(i, _)| i.is_food())
+    }
+}
+
+/// Roll the common table and the biome's own.
+pub fn roll_forage(
+    biome: Biome,
+    skill: u8,
+    with_blade: bool,
+    weather: Weather,
+    rng: &mut impl Rng,
+) -> ForageHaul {
+    let mut haul = ForageHaul::default();
+    for loot in COMMON.iter().chain(biome_loot(biome)) {
+        let mut chance = loot.chance + loot.per_skill * skill as f64;
+        if with_blade {
+            chance += loot.tool_bonus;
+        }
+        let chance = (chance * (loot.weather)(weather)).clamp(0.0, loot.max_chance);
+        let found = (0..loot.rolls).filter(|_| rng.gen_bool(chance)).count() as u32;
+        if found > 0 {
+            haul.add(loot.item, found);
+            if let Some(note) = loot.note {
+                haul.notes.push(note);
+            }
+        }
+    }
+    haul
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const UNIQUE_FINDS: [(Biome, Item); 6] = [
+        (Biome::WinterForest, Item::PineNuts),
+        (Biome::Lake, Item::Reeds),
+        (Biome::Oasis, Item::Date),
+        (Biome::Desert, Item::Flint),
+        (Biome::BambooGrove, Item::BambooShoot),
+        (Biome::MixedForest, Item::Acorn),
+    ];
+
+    const ALL_BIOMES: [Biome; 9] = [
+        Biome::SpringForest,
+        Biome::MixedForest,
+        Biome::WinterForest,
+        Biome::Lake,
+        Biome::Oasis,
+        Biome::Desert,
+        Biome::BambooGrove,
+        Biome::Path,
+        Biome::Clearing,
+    ];
+
+    /// Items a novice can expect from one rummage under a clear sky.
+    fn expected_haul(biome: Biome) -> f64 {
+        COMMON
+            .iter()
+            .chain(biome_loot(biome))
+            .map(|loot| loot.chance.min(loot.max_chance) * loot.rolls as f64)
+            .sum()
+    }
+
+    #[test]
+    fn every_biome_turns_up_its_own_find() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for (biome, unique) in UNIQUE_FINDS {
+            assert!(biome_loot(biome).iter().any(|l| l.item == unique));
+            let found = (0..200).any(|_| {
+                let haul = roll_forage(biome, 0, false, Weather::Clear, &mut rng);
+                haul.items.iter().any(|(i, _)| *i == unique)
+            });
+            assert!(found, "{:?} never gave up {:?}", biome, unique);
+        }
+        for food in [Item::PineNuts, Item::Acorn, Item::BambooShoot, Item::Date] {
+            assert!(food.is_food());
+        }
+        // Nowhere else has them
+        for (biome, unique) in UNIQUE_FINDS {
+            let elsewhere = ALL_BIOMES
+                .iter()
+                .filter(|b| **b != biome)
+                .filter(|b| biome_loot(**b).iter().any(|l| l.item == unique))
+                .count();
+            assert!(elsewhere <= 1, "{:?} turns up too widely", unique);
+        }
+    }
+
+    #[test]
+    fn no_biome_is_far_richer_than_another() {
+        let hauls: Vec<f64> = ALL_BIOMES.iter().map(|b| expected_haul(*b)).collect();
+        let least = hauls.iter().cloned().fold(f64::MAX, f64::min);
+        let most = hauls.iter().cloned().fold(0.0, f64::max);
+        assert!(least >= 2.0, "{:?}", hauls);
+        assert!(most <= least * 1.75, "{:?}", hauls);
+
+        // Skill and a blade help, but never past each entry's ceiling
+        for biome in ALL_BIOMES {
+            for loot in COMMON.iter().chain(biome_loot(biome)) {
+                assert!(loot.chance <= loot.max_chance);
+                assert!(loot.max_chance <= 1.0);
+                assert!(loot.per_skill >= 0.0 && loot.tool_bonus >= 0.0);
+            }
+        }
+    }
+}
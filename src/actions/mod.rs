@@ -1,7 +1,15 @@
+pub mod concerns;
 pub mod crafting;
 pub mod interaction;
+pub mod loot;
 pub mod movement;
+pub mod plan;
+pub mod suggest;
+pub mod targets;
 
+pub use concerns::*;
 pub use crafting::*;
 pub use interaction::*;
 pub use movement::*;
+pub use plan::*;
+pub use suggest::*;
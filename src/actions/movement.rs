@@ -1,5 +1,10 @@
-use crate::entity::{Player, Room};
-use crate::world::{Direction, ObjectKind, ObjectRegistry, Position, TileType, WorldMap};
+use crate::entity::{CabinSpot, Item, Player, Room, Skill};
+use crate::persistence::{GameState, CAVE_ID, RAFT_ID};
+use crate::world::{
+    Direction, Enterable, ObjectKind, ObjectRegistry, PlacedObject, Position, Weather,
+    WindStrength, WorldMap,
+};
+use rand::Rng;
 
 pub enum MoveResult {
     Success(String),
@@ -8,6 +13,120 @@ pub enum MoveResult {
     RoomTransition(String),
 }
 
+/// What keeps the player from taking a step across open ground. The exit
+/// list and a refused step both word it from here, so they never disagree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Barrier {
+    /// Past the edge of the map.
+    EdgeOfWorld,
+    /// Open water, with nothing to cross it on.
+    Water,
+    /// The player's raft, moored where they'd step.
+    MooredRaft,
+    /// The cabin's log wall, on any side but the front.
+    CabinWall,
+    /// The cabin's front door, shut.
+    CabinDoorClosed,
+    /// A wall, boulder or the like standing in the way.
+    Solid(String),
+    /// The dark beyond the cave mouth.
+    DarkCave,
+}
+
+impl Barrier {
+    /// How the exit list notes it, after the name of where it leads.
+    pub fn note(&self) -> String {
+        match self {
+            Barrier::EdgeOfWorld => "the edge of the world".to_string(),
+            Barrier::Water => "need the raft".to_string(),
+            Barrier::MooredRaft => "board it to cross".to_string(),
+            Barrier::CabinWall => "the door faces south".to_string(),
+            Barrier::CabinDoorClosed => "door closed".to_string(),
+            Barrier::Solid(name) => format!("blocked by the {}", name),
+            Barrier::DarkCave => "too dark to go deeper".to_string(),
+        }
+    }
+
+    /// What a refused step says, carrying the same note.
+    pub fn refusal(&self) -> String {
+        match self {
+            Barrier::EdgeOfWorld => {
+                "You cannot go that way - the path ends at the edge of the world.".to_string()
+            }
+            Barrier::Water => format!(
+                "The lake's cool waters block your path ({}). You would need a boat to continue.",
+                self.note()
+            ),
+            Barrier::MooredRaft => format!(
+                "Your raft bobs at its mooring there, but you can't walk on water ({}).",
+                self.note()
+            ),
+            Barrier::CabinWall => format!(
+                "The cabin's log wall blocks your way ({}); go round to the front.",
+                self.note()
+            ),
+            Barrier::CabinDoorClosed => format!(
+                "You stand before the cabin ({}). Perhaps you should try to open it.",
+                self.note()
+            ),
+            Barrier::Solid(name) => format!("The {} stands in your way ({}).", name, self.note()),
+            Barrier::DarkCave => format!(
+                "The cave beyond is pitch black ({}). Without a reliable light source and proper gear, you don't dare.",
+                self.note()
+            ),
+        }
+    }
+}
+
+/// What stops a step from `from` toward `dir` across open ground, if
+/// anything. Both `try_move` and the exit list go by this.
+pub fn step_barrier(
+    from: &Position,
+    dir: Direction,
+    map: &WorldMap,
+    objects: &ObjectRegistry,
+    cabin_open: bool,
+) -> Option<Barrier> {
+    // Deeper cave exploration past the entrance isn't possible for now
+    if dir == Direction::East && objects.objects_at(from).iter().any(|o| o.id == CAVE_ID) {
+        return Some(Barrier::DarkCave);
+    }
+    let to = from.move_in_direction(dir);
+    let Some((row, col)) = map.index_of(&to) else {
+        return Some(Barrier::EdgeOfWorld);
+    };
+    let objects_there = objects.objects_at(&to);
+    if !map.is_walkable(row, col) {
+        return Some(
+            if objects_there
+                .iter()
+                .any(|o| matches!(o.object.kind, ObjectKind::Raft(_)))
+            {
+                Barrier::MooredRaft
+            } else {
+                Barrier::Water
+            },
+        );
+    }
+    if objects_there
+        .iter()
+        .any(|o| matches!(o.object.kind, ObjectKind::Cabin(_)))
+    {
+        if dir != Direction::North {
+            return Some(Barrier::CabinWall);
+        }
+        if !cabin_open {
+            return Some(Barrier::CabinDoorClosed);
+        }
+    }
+    objects_there
+        .iter()
+        .find(|o| {
+            o.object.anchored && matches!(o.object.kind, ObjectKind::Wall | ObjectKind::Boulder)
+        })
+        .map(|o| Barrier::Solid(o.object.display_name()))
+}
+
 /// Move the player in a direction
 pub fn try_move(
     player: &mut Player,
@@ -18,44 +137,31 @@ pub fn try_move(
 ) -> MoveResult {
     // If in a room, movement works differently
     if let Some(room) = &player.room {
-        return handle_room_movement(player, dir, room.clone(), cabin_open);
+        let shed_open = shed_door_open(objects);
+        return handle_room_movement(player, dir, room.clone(), map, objects, shed_open);
     }
 
-    let new_pos = player.position.move_in_direction(dir);
-
-    // Special-case: prevent deeper cave exploration past the entrance for now
-    if objects
-        .objects_at(&player.position)
-        .iter()
-        .any(|o| o.id == "east_cave_entrance")
-        && matches!(dir, Direction::East)
-    {
-        return MoveResult::Blocked(
-            "The cave beyond is pitch black. Without a reliable light source and proper gear, you don't dare go any deeper."
-                .to_string(),
-        );
+    // Walking off from inside a structure takes you back out of it
+    if player.inside.is_some() {
+        let inside = player.inside.take();
+        let result = try_move(player, dir, map, objects, cabin_open);
+        if matches!(result, MoveResult::Blocked(_) | MoveResult::InvalidDirection(_)) {
+            player.inside = inside;
+        }
+        return result;
     }
 
-    // Check bounds
-    if !new_pos.is_valid() {
-        return MoveResult::Blocked(
-            "You cannot go that way - the path ends at the edge of the world.".to_string(),
+    // Out on the ground there's nowhere to climb to
+    if matches!(dir, Direction::Up | Direction::Down) {
+        return MoveResult::InvalidDirection(
+            "There's no way up or down from here. Try north, south, east, or west.".to_string(),
         );
     }
 
-    let (row, col) = new_pos.as_usize().unwrap();
-
-    // Check if walkable
-    if !map.is_walkable(row, col) {
-        let tile = map.get_tile(row, col);
-        let reason = match tile.map(|t| &t.tile_type) {
-            Some(TileType::Lake) => {
-                "The lake's cool waters block your path. You would need a boat to continue."
-            }
-            _ => "Something blocks your way.",
-        };
-        return MoveResult::Blocked(reason.to_string());
+    if let Some(barrier) = step_barrier(&player.position, dir, map, objects, cabin_open) {
+        return MoveResult::Blocked(barrier.refusal());
     }
+    let new_pos = player.position.move_in_direction(dir);
 
     // Check cabin entrance via objects
     if objects
@@ -63,14 +169,11 @@ pub fn try_move(
         .iter()
         .any(|o| matches!(o.object.kind, ObjectKind::Cabin(_)))
     {
-        if !cabin_open {
-            return MoveResult::Blocked(
-                "You stand before the cabin. The wooden door is closed. Perhaps you should try to open it.".to_string()
-            );
-        }
+        player.entered_from = Some(player.position);
         player.position = new_pos;
         player.mark_visited();
         player.enter_room(Room::CabinMain);
+        player.step_to(CabinSpot::Door);
         return MoveResult::RoomTransition(
             "You push open the door and step into the cabin.".to_string(),
         );
@@ -98,13 +201,15 @@ fn handle_room_movement(
     player: &mut Player,
     dir: Direction,
     current_room: Room,
-    _cabin_open: bool,
+    map: &WorldMap,
+    objects: &ObjectRegistry,
+    shed_open: bool,
 ) -> MoveResult {
     match (&current_room, dir) {
         // From cabin main room
         (Room::CabinMain, Direction::South) => {
-            player.exit_room();
-            player.face(Direction::South);
+            let door = door_tile(objects, &Room::CabinMain);
+            leave_room(player, map, objects, door);
             MoveResult::RoomTransition(
                 "You step out through the cabin door into the cool air.".to_string(),
             )
@@ -141,9 +246,12 @@ fn handle_room_movement(
             player.room = Some(Room::CabinTerrace);
             MoveResult::RoomTransition("You walk around to the terrace.".to_string())
         }
+        (Room::WoodShed, Direction::South) if !shed_open => MoveResult::Blocked(
+            "The shed door is latched shut. You need to open it first.".to_string(),
+        ),
         (Room::WoodShed, Direction::South) => {
-            player.exit_room();
-            player.face(Direction::South);
+            let door = door_tile(objects, &Room::WoodShed);
+            leave_room(player, map, objects, door);
             MoveResult::RoomTransition("You exit the wood shed and return outside.".to_string())
         }
 
@@ -151,6 +259,92 @@ fn handle_room_movement(
     }
 }
 
+/// The outdoor tile each way out leads to: the cabin's front door and the
+/// shed door open south, and the terrace steps run down to the north.
+fn door_tile(objects: &ObjectRegistry, room: &Room) -> Option<Position> {
+    let (id, side) = match room {
+        Room::CabinMain => ("cabin", Direction::South),
+        Room::CabinTerrace => ("cabin", Direction::North),
+        Room::WoodShed => ("wood_shed", Direction::South),
+    };
+    objects
+        .find(id)
+        .map(|po| po.position.move_in_direction(side))
+}
+
+/// The step that brings the player closest to the cabin's front door across
+/// open ground, as a compass would point it. `None` once they're there.
+pub fn home_step(player: &Player, map: &WorldMap, objects: &ObjectRegistry) -> Option<Direction> {
+    let door = door_tile(objects, &Room::CabinMain)?;
+    let here = player.position;
+    if here == door {
+        return None;
+    }
+    [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ]
+    .into_iter()
+    .map(|dir| (dir, here.move_in_direction(dir)))
+    .filter(|(_, next)| open_ground(map, objects, *next))
+    .min_by(|(_, a), (_, b)| a.distance_to(&door).total_cmp(&b.distance_to(&door)))
+    .map(|(dir, _)| dir)
+}
+
+/// Somewhere outside the player can stand.
+fn open_ground(map: &WorldMap, objects: &ObjectRegistry, pos: Position) -> bool {
+    map.can_stand_at(&pos)
+        && !objects
+            .objects_at(&pos)
+            .iter()
+            .any(|o| matches!(o.object.kind, ObjectKind::Cabin(_) | ObjectKind::WoodShed(_)))
+}
+
+/// Step out of a room onto `preferred`, or the front door if that's blocked.
+/// Returns whether the preferred tile had to be given up.
+fn leave_room(
+    player: &mut Player,
+    map: &WorldMap,
+    objects: &ObjectRegistry,
+    preferred: Option<Position>,
+) -> bool {
+    let room = player.room.clone();
+    let door = room.as_ref().and_then(|r| door_tile(objects, r));
+    let candidates = [preferred, door, door_tile(objects, &Room::CabinMain)];
+    let landing = candidates
+        .into_iter()
+        .flatten()
+        .find(|p| open_ground(map, objects, *p));
+    let rerouted = preferred.is_some() && landing != preferred;
+    if let Some(pos) = landing {
+        let from = player.position;
+        player.position = pos;
+        player.mark_visited();
+        let dr = pos.row - from.row;
+        let dc = pos.col - from.col;
+        player.face(match (dr.signum(), dc.signum()) {
+            (-1, _) => Direction::North,
+            (_, 1) => Direction::East,
+            (_, -1) => Direction::West,
+            _ => Direction::South,
+        });
+    }
+    player.exit_room();
+    rerouted
+}
+
+fn shed_door_open(objects: &ObjectRegistry) -> bool {
+    objects
+        .find("wood_shed")
+        .and_then(|p| match &p.object.kind {
+            ObjectKind::WoodShed(shed) => Some(shed.door_open),
+            _ => None,
+        })
+        .unwrap_or(true)
+}
+
 fn dir_name(dir: Direction) -> &'static str {
     match dir {
         Direction::North => "north",
@@ -162,6 +356,27 @@ fn dir_name(dir: Direction) -> &'static str {
     }
 }
 
+/// Cross the cabin's main room to one of its spots. Only a few steps, so it
+/// takes no time.
+pub fn try_move_to_spot(player: &mut Player, spot: CabinSpot) -> MoveResult {
+    if player.room != Some(Room::CabinMain) {
+        return MoveResult::InvalidDirection(format!(
+            "There's no {} to cross to here; that's inside the cabin.",
+            spot.name()
+        ));
+    }
+    if player.cabin_spot == Some(spot) {
+        return MoveResult::InvalidDirection(spot.standing().to_string());
+    }
+    player.cabin_spot = Some(spot);
+    MoveResult::Success(match spot {
+        CabinSpot::Hearth => "You cross to the hearth.".to_string(),
+        CabinSpot::Table => "You step over to the table.".to_string(),
+        CabinSpot::Window => "You go to the window and look out.".to_string(),
+        CabinSpot::Door => "You step over to the doorway.".to_string(),
+    })
+}
+
 /// Enter a location at current position
 pub fn try_enter(
     player: &mut Player,
@@ -171,75 +386,463 @@ pub fn try_enter(
     cabin_open: bool,
 ) -> MoveResult {
     let normalized = target.to_lowercase();
-    let cabin_pos = objects
-        .find("cabin")
-        .map(|p| p.position)
-        .unwrap_or_else(|| Position::new(6, 5));
-
-    // Check if trying to enter cabin (either on cabin tile or adjacent to it)
-    if normalized.contains("cabin") || normalized.contains("door") || normalized.contains("house") {
-        let distance = player.position.distance_to(&cabin_pos);
-
-        // Must be on or adjacent to cabin
-        if distance > 1.5 {
-            return MoveResult::InvalidDirection(
-                "You're too far from the cabin to enter it.".to_string(),
-            );
-        }
 
-        if !cabin_open {
-            return MoveResult::Blocked(
-                "The cabin door is closed. You need to open it first.".to_string(),
-            );
+    // Nearest placed object that can be entered and answers to the target
+    let mut nearest: Option<(&PlacedObject, Enterable, f32)> = None;
+    for po in &objects.placed {
+        let Some(entry) = po.object.entry() else {
+            continue;
+        };
+        if po.object.is_hidden() {
+            continue;
+        }
+        if !po.object.matches_entry_target(&normalized) {
+            continue;
+        }
+        // Only the cabin can be walked into from one of its own rooms
+        if (player.room.is_some() || player.inside.is_some())
+            && entry != Enterable::Room(Room::CabinMain)
+        {
+            continue;
         }
+        let distance = player.position.distance_to(&po.position);
+        if nearest.as_ref().map(|(_, _, d)| distance < *d).unwrap_or(true) {
+            nearest = Some((po, entry, distance));
+        }
+    }
 
-        player.position = cabin_pos; // Move to cabin position
-        player.enter_room(Room::CabinMain);
-        return MoveResult::RoomTransition("You step into the cozy cabin.".to_string());
+    let Some((po, entry, distance)) = nearest else {
+        return MoveResult::InvalidDirection(format!(
+            "You don't see a '{}' to enter here.",
+            target
+        ));
+    };
+    let name = po.object.display_name();
+
+    // Must be on or adjacent to it
+    if distance > 1.5 {
+        return MoveResult::InvalidDirection(format!(
+            "You're too far from the {} to enter it.",
+            name
+        ));
     }
 
-    // Check for entering wood shed from outside
-    if player.room.is_none() {
-        if let Some(shed_pos) = objects.find("wood_shed").map(|p| p.position) {
-            if player.position.distance_to(&shed_pos) < 2.0
-                && (normalized.contains("shed") || normalized.contains("wood"))
-            {
-                player.enter_room(Room::WoodShed);
-                return MoveResult::RoomTransition("You enter the small wood shed.".to_string());
+    match entry {
+        Enterable::Room(Room::CabinMain) => {
+            if !cabin_open {
+                return MoveResult::Blocked(
+                    "The cabin door is closed. You need to open it first.".to_string(),
+                );
+            }
+            // From outside, in through the front door only
+            if player.room.is_none() {
+                let front = po.position.move_in_direction(Direction::South);
+                if player.position != front && player.position != po.position {
+                    return MoveResult::Blocked(
+                        "The cabin's door is on its south side. Go round to the front to get in."
+                            .to_string(),
+                    );
+                }
+                if player.position == front {
+                    player.entered_from = Some(front);
+                }
             }
+            let from_outside = player.room.is_none();
+            player.position = po.position; // Move to cabin position
+            player.inside = None;
+            player.enter_room(Room::CabinMain);
+            if from_outside {
+                player.step_to(CabinSpot::Door);
+            }
+            MoveResult::RoomTransition("You step into the cozy cabin.".to_string())
         }
-    }
-
-    // Check for entering the east-side cave entrance from outside
-    if player.room.is_none() && normalized.contains("cave") {
-        if let Some(cave) = objects.find("east_cave_entrance") {
-            let cave_pos = cave.position;
-            let distance = player.position.distance_to(&cave_pos);
-            if distance > 1.5 {
-                return MoveResult::InvalidDirection(
-                    "You're too far from the cave entrance to step inside.".to_string(),
+        Enterable::Room(Room::WoodShed) => {
+            if !shed_door_open(objects) {
+                return MoveResult::Blocked(
+                    "The wood shed door is latched shut. You need to open it first.".to_string(),
                 );
             }
-
-            player.position = cave_pos;
+            if player.position != po.position {
+                player.entered_from = Some(player.position);
+            }
+            player.position = po.position;
+            player.enter_room(Room::WoodShed);
+            MoveResult::RoomTransition("You enter the small wood shed.".to_string())
+        }
+        Enterable::Room(room) => {
+            let message = format!("You step into the {}.", room.name());
+            player.enter_room(room);
+            MoveResult::RoomTransition(message)
+        }
+        Enterable::Inside => {
+            player.position = po.position;
+            player.inside = Some(po.id.clone());
             player.mark_visited();
-            return MoveResult::Success(
+            let message = if po.id == "east_cave_entrance" {
                 "You step into the mouth of the cave. Just beyond the entrance, darkness swallows the passage; without proper light and gear, you decide not to go any deeper yet."
-                    .to_string(),
+                    .to_string()
+            } else {
+                format!("You duck inside the {}.", name)
+            };
+            MoveResult::RoomTransition(message)
+        }
+        Enterable::Aboard => {
+            player.position = po.position;
+            player.inside = Some(po.id.clone());
+            player.mark_visited();
+            MoveResult::RoomTransition(format!(
+                "You climb aboard the {}. It dips, then steadies under your weight.",
+                name
+            ))
+        }
+    }
+}
+
+/// Paddle the raft one tile across open water, with the player aboard.
+pub fn try_paddle(state: &mut GameState, dir: Direction, map: &mut WorldMap) -> MoveResult {
+    if matches!(dir, Direction::Up | Direction::Down) {
+        return MoveResult::InvalidDirection("The raft only goes across the water.".to_string());
+    }
+    let Some(raft_pos) = state.raft().map(|po| po.position) else {
+        state.player.inside = None;
+        return MoveResult::Blocked("There's no raft under you anymore.".to_string());
+    };
+    let mut target = raft_pos.move_in_direction(dir);
+    if !map.is_open_water(&target) {
+        return MoveResult::Blocked(if map.can_stand_at(&target) {
+            "The raft's nose bumps against the bank. Disembark to go ashore.".to_string()
+        } else {
+            "There's no open water that way.".to_string()
+        });
+    }
+
+    let weather = state.weather.get_for_position(raft_pos.row, raft_pos.col);
+    let stormy = matches!(
+        weather,
+        Weather::Blizzard | Weather::HeavyRain | Weather::HeavySnow | Weather::Sandstorm
+    );
+    let mut rng = rand::thread_rng();
+    if stormy && rng.gen_bool(0.04) {
+        let message = state.wreck_raft(map);
+        return MoveResult::Success(message);
+    }
+
+    // A hard crosswind sets the raft a tile downwind of where you aimed
+    let mut note = String::new();
+    let wind = state.weather.wind;
+    if wind.strength >= WindStrength::Strong && wind.is_crosswind(dir) {
+        let blown = target.move_in_direction(wind.downwind());
+        if map.is_open_water(&blown) {
+            target = blown;
+            note = format!(
+                " The wind out of the {} shoves you off course to the {}.",
+                wind.from.name(),
+                wind.downwind().name()
             );
         }
     }
 
-    MoveResult::InvalidDirection(format!("You don't see a '{}' to enter here.", target))
+    if let Some(raft) = state.objects.find_mut(RAFT_ID) {
+        raft.position = target;
+    }
+    if rng.gen_bool(0.3) {
+        state.player.skills.improve(Skill::Observation, 1);
+    }
+    if rng.gen_bool(0.1) && state.player.inventory.add(Item::Driftwood, 1) {
+        note.push_str(" You fish a floating piece of driftwood out of the water.");
+    }
+    if state.player.mounts.raft_prow == Some(Item::RubberDuck) {
+        // The figurehead bobbing over every ripple never stops being funny.
+        state.player.modify_mood(0.5);
+    }
+    let player = &mut state.player;
+    player.position = target;
+    player.mark_visited();
+    player.face(dir);
+    player.modify_energy(if stormy { -3.0 } else { -2.0 });
+    let verb = if stormy {
+        "fight the chop and paddle"
+    } else {
+        "paddle"
+    };
+    MoveResult::Success(format!("You {} {}.{}", verb, dir_name(dir), note))
 }
 
 /// Exit current interior location
-pub fn try_exit(player: &mut Player) -> MoveResult {
-    if player.room.is_some() {
-        player.exit_room();
-        player.face(Direction::South);
-        MoveResult::RoomTransition("You step outside.".to_string())
+pub fn try_exit(player: &mut Player, map: &WorldMap, objects: &ObjectRegistry) -> MoveResult {
+    if let Some(room) = player.room.clone() {
+        // Back the way you came, except off the terrace, which has its own steps
+        let (preferred, message) = match room {
+            Room::CabinTerrace => (
+                door_tile(objects, &room),
+                "You go down the terrace steps.",
+            ),
+            _ => (
+                player.entered_from.or_else(|| door_tile(objects, &room)),
+                "You step outside.",
+            ),
+        };
+        let rerouted = leave_room(player, map, objects, preferred);
+        let mut text = message.to_string();
+        if rerouted {
+            text.push_str(if room == Room::CabinTerrace {
+                " They run straight down into the water, so you pick your way round to the front door instead."
+            } else {
+                " The way you came in is blocked now, so you come out by the front door."
+            });
+        }
+        MoveResult::RoomTransition(text)
+    } else if player.inside.as_deref() == Some(RAFT_ID) {
+        disembark(player, map)
+    } else if let Some(id) = player.inside.take() {
+        let name = objects
+            .find(&id)
+            .map(|po| po.object.display_name())
+            .unwrap_or_else(|| "shelter".to_string());
+        MoveResult::RoomTransition(format!("You step back out of the {}.", name))
     } else {
         MoveResult::InvalidDirection("You are already outside.".to_string())
     }
 }
+
+/// Step off the raft onto land, leaving it moored where it floats.
+fn disembark(player: &mut Player, map: &WorldMap) -> MoveResult {
+    if map.can_stand_at(&player.position) {
+        player.inside = None;
+        return MoveResult::RoomTransition("You step off the raft onto the bank.".to_string());
+    }
+    let order = [
+        player.facing,
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+    let Some(shore) = order
+        .iter()
+        .map(|d| player.position.move_in_direction(*d))
+        .find(|p| map.can_stand_at(p))
+    else {
+        return MoveResult::InvalidDirection(
+            "There's only water around you. Paddle closer to the shore before you disembark."
+                .to_string(),
+        );
+    };
+    player.inside = None;
+    player.position = shore;
+    player.mark_visited();
+    MoveResult::RoomTransition(
+        "You step off onto the shore, leaving the raft moored in the shallows.".to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::{Raft, Wind, WorldObject};
+
+    fn entered(result: &MoveResult) -> bool {
+        matches!(result, MoveResult::RoomTransition(_))
+    }
+
+    #[test]
+    fn the_cabin_terrace_and_shed_still_come_and_go_the_same_way() {
+        let map = WorldMap::new();
+        let state = GameState::new(&map);
+        let objects = &state.objects;
+        let mut player = state.player.clone();
+        player.exit_room();
+        player.inside = None;
+
+        // The cabin only lets you in through an open front door
+        let cabin = objects.find("cabin").unwrap().position;
+        let front = cabin.move_in_direction(Direction::South);
+        player.position = front;
+        let shut = try_enter(&mut player, "cabin", &map, objects, false);
+        assert!(matches!(shut, MoveResult::Blocked(_)));
+        let opened = try_enter(&mut player, "cabin", &map, objects, true);
+        assert!(entered(&opened));
+        assert_eq!(player.room, Some(Room::CabinMain));
+        assert_eq!(player.position, cabin);
+        assert!(entered(&try_exit(&mut player, &map, objects)));
+        assert_eq!((player.room.clone(), player.position), (None, front));
+
+        // Through to the terrace, and down its steps
+        try_enter(&mut player, "cabin", &map, objects, true);
+        let terrace = try_move(&mut player, Direction::North, &map, objects, true);
+        assert!(entered(&terrace));
+        assert_eq!(player.room, Some(Room::CabinTerrace));
+        assert!(entered(&try_exit(&mut player, &map, objects)));
+        assert!(player.room.is_none());
+        assert!(map.can_stand_at(&player.position));
+
+        // The shed lets you back out where you stood
+        let shed = objects.find("wood_shed").unwrap().position;
+        let beside = shed.move_in_direction(Direction::South);
+        player.position = beside;
+        let opened = try_enter(&mut player, "shed", &map, objects, true);
+        assert!(entered(&opened));
+        assert_eq!(player.room, Some(Room::WoodShed));
+        assert!(entered(&try_exit(&mut player, &map, objects)));
+        assert_eq!((player.room.clone(), player.position), (None, beside));
+    }
+
+    #[test]
+    fn a_placed_lean_to_can_be_ducked_into_and_out_of() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let spot = Position::new(3, 3);
+        let lean_to = WorldObject::new(ObjectKind::GenericStructure("lean-to".to_string()))
+            .with_enterable(Enterable::Inside);
+        state.objects.add("lean-to-1", spot, lean_to);
+        let objects = &state.objects;
+        let mut player = state.player.clone();
+        player.exit_room();
+        player.inside = None;
+
+        player.position = Position::new(3, 6);
+        let far = try_enter(&mut player, "lean-to", &map, objects, true);
+        assert!(matches!(far, MoveResult::InvalidDirection(_)));
+
+        player.position = spot.move_in_direction(Direction::West);
+        let inside = try_enter(&mut player, "lean-to", &map, objects, true);
+        assert!(entered(&inside));
+        assert_eq!(player.inside.as_deref(), Some("lean-to-1"));
+        assert_eq!(player.position, spot);
+        assert!(player.room.is_none());
+
+        let out = try_exit(&mut player, &map, objects);
+        let MoveResult::RoomTransition(text) = out else {
+            panic!("couldn't leave the lean-to");
+        };
+        assert!(text.contains("lean-to"), "{}", text);
+        assert!(player.inside.is_none());
+    }
+
+    #[test]
+    fn the_raft_carries_you_over_water_and_stays_where_it_s_moored() {
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let (w, clear) = (&mut state.weather, Weather::Clear);
+        (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+        w.wind.strength = WindStrength::Calm;
+        state.player.exit_room();
+        state.player.inside = None;
+
+        // A shore with two tiles of open water straight out from it
+        let dirs = [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ];
+        let (shore, out) = map
+            .positions()
+            .filter(|p| map.can_stand_at(p))
+            .flat_map(|p| dirs.map(|d| (p, d)))
+            .find(|(p, d)| {
+                let near = p.move_in_direction(*d);
+                map.is_open_water(&near) && map.is_open_water(&near.move_in_direction(*d))
+            })
+            .unwrap();
+        let mooring = shore.move_in_direction(out);
+        let raft = WorldObject::new(ObjectKind::Raft(Raft::default()));
+        state.objects.add(RAFT_ID, mooring, raft);
+        state.player.position = shore;
+
+        let boarded = try_enter(&mut state.player, "raft", &map, &state.objects, true);
+        assert!(entered(&boarded));
+        assert_eq!(state.player.inside.as_deref(), Some(RAFT_ID));
+        assert_eq!(state.player.position, mooring);
+
+        let energy = state.player.energy;
+        let farther = mooring.move_in_direction(out);
+        let paddled = try_paddle(&mut state, out, &mut map);
+        assert!(matches!(paddled, MoveResult::Success(_)));
+        assert_eq!(state.raft().unwrap().position, farther);
+        assert_eq!(state.player.position, farther);
+        assert!(state.player.energy < energy);
+
+        // Back in, but no further than the water goes
+        let back = out.opposite();
+        try_paddle(&mut state, back, &mut map);
+        let aground = try_paddle(&mut state, back, &mut map);
+        assert!(matches!(aground, MoveResult::Blocked(_)));
+        assert_eq!(state.raft().unwrap().position, mooring);
+
+        // Still aboard, and still moored there, after a reload
+        let save = format!("rubber-duck-raft-{}.json", std::process::id());
+        let path = std::env::temp_dir().join(save);
+        state.save(&path).unwrap();
+        let mut state = GameState::load_or_new(&path, &map);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(state.player.inside.as_deref(), Some(RAFT_ID));
+        assert_eq!(state.raft().unwrap().position, mooring);
+
+        assert!(entered(&try_exit(&mut state.player, &map, &state.objects)));
+        assert!(state.player.inside.is_none());
+        assert!(map.can_stand_at(&state.player.position));
+        assert_eq!(state.raft().unwrap().position, mooring);
+    }
+
+    #[test]
+    fn a_hard_crosswind_sets_the_raft_downwind_only_over_open_water() {
+        let mut map = WorldMap::new();
+        let dirs = [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ];
+        // Paddle `dir` from `start` with the wind out of `from`
+        let paddle = |start: Position, dir, from, strength, map: &mut WorldMap| {
+            let mut state = GameState::new(map);
+            let (w, clear) = (&mut state.weather, Weather::Clear);
+            (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+            w.wind = Wind { from, strength };
+            state.player.exit_room();
+            let raft = WorldObject::new(ObjectKind::Raft(Raft::default()));
+            state.objects.add(RAFT_ID, start, raft);
+            state.player.position = start;
+            state.player.inside = Some(RAFT_ID.to_string());
+            let MoveResult::Success(text) = try_paddle(&mut state, dir, map) else {
+                panic!("the raft should move over open water");
+            };
+            (state.raft().unwrap().position, text)
+        };
+        // Water ahead, with the tile downwind of it open (blown) or not (held)
+        let crossings: Vec<_> = map
+            .positions()
+            .filter(|p| map.is_open_water(p))
+            .flat_map(|p| dirs.map(|d| (p, d, p.move_in_direction(d))))
+            .filter(|(_, _, ahead)| map.is_open_water(ahead))
+            .flat_map(|(p, d, ahead)| {
+                dirs.into_iter()
+                    .filter(move |from| *from != d && *from != d.opposite())
+                    .map(move |from| (p, d, ahead, from))
+            })
+            .collect();
+        let downwind = |ahead: Position, from: Direction| ahead.move_in_direction(from.opposite());
+
+        let &(start, dir, ahead, from) = crossings
+            .iter()
+            .find(|(_, _, ahead, from)| map.is_open_water(&downwind(*ahead, *from)))
+            .unwrap();
+        let (calm, text) = paddle(start, dir, from, WindStrength::Breeze, &mut map);
+        assert_eq!(calm, ahead);
+        assert!(!text.contains("off course"));
+        let (blown, text) = paddle(start, dir, from, WindStrength::Gale, &mut map);
+        assert_eq!(blown, downwind(ahead, from));
+        assert!(text.contains("off course"));
+        // Into or with the wind, it only pushes on the raft's nose or tail
+        let (head_on, _) = paddle(start, dir, dir, WindStrength::Gale, &mut map);
+        assert_eq!(head_on, ahead);
+
+        let &(start, dir, ahead, from) = crossings
+            .iter()
+            .find(|(_, _, ahead, from)| !map.is_open_water(&downwind(*ahead, *from)))
+            .unwrap();
+        let (held, _) = paddle(start, dir, from, WindStrength::Gale, &mut map);
+        assert_eq!(held, ahead);
+    }
+}
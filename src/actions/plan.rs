@@ -0,0 +1,909 @@
+//! `plan`: the next few commands toward a goal, worked out from the state
+//! as it stands. It only reads, so asking never costs time. Every `use`
+//! step is looked up in the same table as `help use`, and build steps come
+//! from the recipes, so a plan never offers a combination the game refuses.
+
+use crate::actions::{check_use, near_water, nearest_water};
+use crate::descriptions::distance_phrase;
+use crate::entity::{Blueprint, FireState, FuelStage, Item, Room, Workspace};
+use crate::persistence::GameState;
+use crate::world::{Position, Region, Weather, WorldMap};
+
+/// How many steps a plan lists at most; the rest wait for the next ask.
+const MAX_STEPS: usize = 8;
+
+/// Bulk fuel, in the fireplace's own units, that sees a fire through the night.
+const NIGHT_FUEL: f32 = 90.0;
+
+/// Below this, a need is worth seeing to before setting out.
+const TRAVEL_NEED: f32 = 40.0;
+
+/// The goals `plan` knows, as offered when it doesn't recognise one.
+pub const PLAN_GOALS: &[&str] = &[
+    "warm night",
+    "cook dinner",
+    "build <item>",
+    "explore <region>",
+];
+
+struct Step {
+    command: String,
+    note: Option<String>,
+    /// What has to change before the step can work at all.
+    blocked: Option<String>,
+}
+
+/// Steps gathered in order, remembering what the earlier ones will have
+/// changed so later ones are judged against that.
+struct Planner<'a> {
+    state: &'a GameState,
+    map: &'a WorldMap,
+    steps: Vec<Step>,
+    /// Items earlier steps put in hand.
+    gained: Vec<(Item, u32)>,
+    /// Items earlier steps used up.
+    spent: Vec<(Item, u32)>,
+    /// Where earlier steps leave the player: a room, or `None` outdoors.
+    room: Option<Room>,
+    /// What earlier steps leave the player aboard, if anything.
+    inside: Option<String>,
+}
+
+impl<'a> Planner<'a> {
+    fn new(state: &'a GameState, map: &'a WorldMap) -> Self {
+        Self {
+            state,
+            map,
+            steps: Vec::new(),
+            gained: Vec::new(),
+            spent: Vec::new(),
+            room: state.player.room.clone(),
+            inside: state.player.inside.clone(),
+        }
+    }
+
+    fn step(&mut self, command: impl Into<String>, note: Option<String>) {
+        self.steps.push(Step {
+            command: command.into(),
+            note,
+            blocked: None,
+        });
+    }
+
+    fn blocked(&mut self, command: impl Into<String>, why: impl Into<String>) {
+        self.steps.push(Step {
+            command: command.into(),
+            note: None,
+            blocked: Some(why.into()),
+        });
+    }
+
+    fn count(&self, item: &Item) -> u32 {
+        let tally = |list: &[(Item, u32)]| -> u32 {
+            list.iter().filter(|(i, _)| i == item).map(|(_, n)| n).sum()
+        };
+        (self.state.player.inventory.count(item) + tally(&self.gained))
+            .saturating_sub(tally(&self.spent))
+    }
+
+    /// Everything carried once earlier steps are done, in pack order.
+    fn on_hand(&self) -> impl Iterator<Item = Item> + '_ {
+        let slots = self.state.player.inventory.slots.iter().map(|s| s.item);
+        slots
+            .chain(self.gained.iter().map(|(i, _)| *i))
+            .filter(|i| self.has(*i))
+    }
+
+    fn has(&self, item: Item) -> bool {
+        self.count(&item) > 0
+    }
+
+    fn gain(&mut self, item: Item, count: u32) {
+        self.gained.push((item, count));
+    }
+
+    fn spend(&mut self, item: Item, count: u32) {
+        self.spent.push((item, count));
+    }
+
+    /// The first of `items` on hand or coming, if any.
+    fn holding(&self, items: &[Item]) -> Option<Item> {
+        items.iter().copied().find(|i| self.has(*i))
+    }
+
+    fn outdoors(&self) -> bool {
+        self.room.is_none() && self.inside.is_none()
+    }
+
+    /// Step outside, if earlier steps leave the player indoors.
+    fn go_outside(&mut self) {
+        if self.room.is_some() {
+            let from = self.room.take().map(|r| r.name()).unwrap_or("room");
+            self.step("exit", Some(format!("out of the {}", from)));
+        } else if let Some(inside) = self.inside.take() {
+            self.step("exit", Some(format!("off the {}", inside)));
+        }
+    }
+
+    /// Walk into the cabin or the wood shed.
+    fn go_to(&mut self, room: Room, object: &str, target: &str) {
+        if self.room.as_ref() == Some(&room) {
+            return;
+        }
+        if room != Room::CabinMain || self.room.as_ref() != Some(&Room::CabinTerrace) {
+            self.go_outside();
+        }
+        let note = self.state.objects.find(object).and_then(|po| {
+            let pos = self.state.player.position;
+            (pos.distance_to(&po.position) > 1.5 || self.room.is_some())
+                .then(|| format!("it's {}", distance_phrase(&pos, &po.position)))
+        });
+        let door_open = match room {
+            Room::WoodShed => self.state.wood_shed_state().map(|s| s.door_open),
+            _ => self.state.cabin_state().map(|c| c.door_open),
+        };
+        if self.room.is_none() && door_open == Some(false) {
+            self.step(format!("open {} door", target), None);
+        }
+        self.step(format!("enter {}", target), note);
+        self.room = Some(room);
+    }
+
+    /// `use item on target`, checked against the table. Steps the game has
+    /// no pattern for are left out and `false` returned.
+    fn use_on(&mut self, item: Item, target: &str, note: Option<String>) -> bool {
+        let Some(check) = check_use(item, target, self.state, self.map) else {
+            return false;
+        };
+        let command = format!("use {} on {}", item.name(), target);
+        if !self.has(item) {
+            self.blocked(command, format!("you have no {}", item.name()));
+            return true;
+        }
+        if let Some(missing) = check.missing(|i| self.count(i)) {
+            self.blocked(command, format!("{} first", missing));
+            return true;
+        }
+        let there = match &check.room {
+            Some(room) => self.room.as_ref() == Some(room),
+            None => self.room == self.state.player.room,
+        };
+        let note = match (check.away.filter(|_| !there), note) {
+            (Some(away), Some(note)) => Some(format!("{} first; {}", away, note)),
+            (Some(away), None) => Some(format!("{} first", away)),
+            (None, note) => note,
+        };
+        self.step(command, note);
+        true
+    }
+
+    /// Rummage in the undergrowth for `item`, which it turns up in time.
+    fn forage_for(&mut self, item: Item) {
+        self.go_outside();
+        self.step(
+            "use hands on bush",
+            Some(format!("forage for {}", item.name())),
+        );
+        self.gain(item, 1);
+    }
+
+    /// Get a knife or an axe in hand, from the shed floor if that's where it lies.
+    fn fetch_tool(&mut self, tools: &[Item], name: &str) -> Option<Item> {
+        if let Some(tool) = self.holding(tools) {
+            return Some(tool);
+        }
+        if tools.contains(&Item::Axe)
+            && self
+                .state
+                .wood_shed_state()
+                .map(|s| s.axe_on_floor)
+                .unwrap_or(false)
+        {
+            self.go_to(Room::WoodShed, "wood_shed", "wood shed");
+            self.step("take axe", Some("it's lying on the shed floor".to_string()));
+            self.gain(Item::Axe, 1);
+            return Some(Item::Axe);
+        }
+        self.blocked(format!("find {}", name), "you aren't carrying one");
+        None
+    }
+
+    fn render(self, title: &str) -> String {
+        let mut text = format!("**Plan: {}**\n", title);
+        if self.steps.is_empty() {
+            text.push_str("Nothing stands in the way; you're already set.");
+            return text;
+        }
+        let more = self.steps.len().saturating_sub(MAX_STEPS);
+        for (i, step) in self.steps.iter().take(MAX_STEPS).enumerate() {
+            text.push_str(&format!("{}. {}", i + 1, step.command));
+            if let Some(why) = &step.blocked {
+                text.push_str(&format!(" — blocked: {}", why));
+            } else if let Some(note) = &step.note {
+                text.push_str(&format!(" — {}", note));
+            }
+            text.push('\n');
+        }
+        if more > 0 {
+            text.push_str(&format!(
+                "…and {} more step{} after that; ask again once these are done.",
+                more,
+                if more == 1 { "" } else { "s" }
+            ));
+        }
+        text.trim_end().to_string()
+    }
+}
+
+/// Firewood from the shed: stacked, or split from a log there.
+fn fetch_firewood(p: &mut Planner) {
+    let (firewood, logs) = p
+        .state
+        .wood_shed_state()
+        .map(|s| (s.firewood, s.logs))
+        .unwrap_or((0, 0));
+    if firewood > 0 {
+        p.go_to(Room::WoodShed, "wood_shed", "wood shed");
+        p.step(
+            "take firewood",
+            Some(format!("{} split and stacked in the shed", firewood)),
+        );
+        p.gain(Item::Firewood, 1);
+        return;
+    }
+    if logs > 0 || p.has(Item::Log) {
+        let Some(axe) = p.fetch_tool(&[Item::Axe, Item::StoneAxe], "an axe") else {
+            return;
+        };
+        p.go_to(Room::WoodShed, "wood_shed", "wood shed");
+        if !p.has(Item::Log) {
+            p.step("take log", Some(format!("{} in the shed", logs)));
+            p.gain(Item::Log, 1);
+        }
+        p.use_on(Item::Log, "chopping block", None);
+        p.use_on(axe, "chopping block", Some("split it".to_string()));
+        p.spend(Item::Log, 1);
+        p.gain(Item::Firewood, 2);
+        return;
+    }
+    let Some(axe) = p.fetch_tool(&[Item::Axe, Item::StoneAxe], "an axe") else {
+        return;
+    };
+    p.go_outside();
+    p.use_on(
+        axe,
+        "tree",
+        Some("the shed is empty; cut a log".to_string()),
+    );
+    p.gain(Item::Log, 1);
+}
+
+/// Something that goes into the grate at `stage`, from the pack or made.
+fn fuel_in_hand(p: &Planner, stage: FuelStage) -> Option<Item> {
+    p.on_hand()
+        .filter(|i| i.fuel_stage().map(|(s, _)| s) == Some(stage))
+        .find(|i| check_use(*i, "fire", p.state, p.map).is_some())
+}
+
+fn warm_night(p: &mut Planner) {
+    let Some(fire) = p.state.cabin_state().map(|c| c.fireplace.clone()) else {
+        p.blocked("enter cabin", "there's no cabin standing to shelter in");
+        return;
+    };
+
+    if fire.state != FireState::Cold {
+        if fire.fuel < NIGHT_FUEL {
+            if fuel_in_hand(p, FuelStage::Bulk).is_none() {
+                fetch_firewood(p);
+            }
+            if let Some(fuel) = fuel_in_hand(p, FuelStage::Bulk) {
+                p.go_to(Room::CabinMain, "cabin", "cabin");
+                p.use_on(fuel, "fire", Some("enough to last until dawn".to_string()));
+            }
+        }
+        p.go_to(Room::CabinMain, "cabin", "cabin");
+        p.step("sleep", Some("by the fire, with the door shut".to_string()));
+        return;
+    }
+
+    // Gather everything first, so the laying and lighting happen in one go;
+    // what's already in the cabin is picked up last, on the way to the hearth.
+    let stock = cabin_stock(p.state);
+    let stocked = |p: &Planner, stage: FuelStage| {
+        stock.iter().copied().find(|(item, _)| {
+            item.fuel_stage().map(|(s, _)| s) == Some(stage)
+                && check_use(*item, "fire", p.state, p.map).is_some()
+        })
+    };
+    let mut takes = Vec::new();
+    let mut lay = Vec::new();
+    if fire.tinder <= 0.0 {
+        if fuel_in_hand(p, FuelStage::Tinder).is_none() {
+            match stocked(p, FuelStage::Tinder) {
+                Some(found) => takes.push(found),
+                None => p.forage_for(Item::DryLeaves),
+            }
+        }
+        lay.push(FuelStage::Tinder);
+    }
+    if fire.kindling <= 0.0 {
+        if fuel_in_hand(p, FuelStage::Kindling).is_none() {
+            match stocked(p, FuelStage::Kindling) {
+                Some(found) => takes.push(found),
+                None => {
+                    p.forage_for(Item::Stick);
+                    if let Some(knife) = p.holding(&[Item::Knife, Item::StoneKnife]) {
+                        p.use_on(knife, "stick", Some("whittle kindling".to_string()));
+                        p.spend(Item::Stick, 1);
+                        p.gain(Item::Kindling, 1);
+                    }
+                }
+            }
+        }
+        lay.push(FuelStage::Kindling);
+    }
+    if fire.fuel <= 0.0 {
+        if fuel_in_hand(p, FuelStage::Bulk).is_none() {
+            fetch_firewood(p);
+        }
+        lay.push(FuelStage::Bulk);
+    }
+    if !p.has(Item::Matchbox) {
+        takes.extend(
+            stock
+                .iter()
+                .copied()
+                .find(|(item, _)| *item == Item::Matchbox),
+        );
+    }
+
+    p.go_to(Room::CabinMain, "cabin", "cabin");
+    for (item, place) in takes {
+        p.step(
+            format!("take {}", item.name()),
+            Some(format!("it's {}", place)),
+        );
+        p.gain(item, 1);
+    }
+    for stage in lay {
+        if let Some(item) = fuel_in_hand(p, stage) {
+            p.use_on(item, "fire", None);
+        }
+    }
+    p.use_on(Item::Matchbox, "fire", Some("light it".to_string()));
+    p.step("sleep", Some("once it's burning well".to_string()));
+}
+
+/// What lies loose in the cabin's main room, with where: on the floor or
+/// on one of its surfaces.
+fn cabin_stock(state: &GameState) -> Vec<(Item, &'static str)> {
+    let mut stock: Vec<(Item, &'static str)> = Vec::new();
+    if let Some(cabin) = state.cabin_state() {
+        for item in cabin
+            .items
+            .list()
+            .into_iter()
+            .chain(cabin.table_items.list())
+        {
+            stock.push((*item, "in the cabin"));
+        }
+    }
+    for id in GameState::room_surfaces(&Room::CabinMain) {
+        if let Some(surface) = state
+            .objects
+            .find(id)
+            .and_then(|po| po.object.surface.as_ref())
+        {
+            let place = GameState::surface_phrases(id).0;
+            stock.extend(surface.items.list().into_iter().map(|item| (*item, place)));
+        }
+    }
+    stock
+}
+
+fn cook_dinner(p: &mut Planner) {
+    const COOKED: &[Item] = &[
+        Item::CookedFish,
+        Item::CookedMeat,
+        Item::CookedBerries,
+        Item::RoastedNuts,
+        Item::RoastedShoot,
+    ];
+    if let Some(food) = &p.state.on_the_fire {
+        p.go_to(Room::CabinMain, "cabin", "cabin");
+        p.step(
+            format!("take {} off the fire", food.label()),
+            Some("it's already grilling".to_string()),
+        );
+        return;
+    }
+    if let Some(meal) = p.holding(COOKED) {
+        p.step(
+            format!("eat {}", meal.name()),
+            Some("it's already cooked".to_string()),
+        );
+        return;
+    }
+
+    let raw = |p: &Planner| {
+        p.on_hand()
+            .find(|i| i.is_food() && check_use(*i, "fire", p.state, p.map).is_some())
+    };
+    if raw(p).is_none() {
+        let pos = p.state.player.position;
+        if p.outdoors() && near_water(&pos, p.map) {
+            p.step("fish", Some("you're at the water".to_string()));
+            p.gain(Item::Fish, 1);
+        } else if let Some(water) = nearest_water(&pos, p.map) {
+            p.go_outside();
+            p.step(
+                "fish",
+                Some(format!("at the water, {}", distance_phrase(&pos, &water))),
+            );
+            p.gain(Item::Fish, 1);
+        } else {
+            p.forage_for(Item::WildBerry);
+        }
+    }
+    let Some(food) = raw(p) else {
+        return;
+    };
+
+    let lit = p
+        .state
+        .cabin_state()
+        .map(|c| c.fireplace.state != FireState::Cold)
+        .unwrap_or(false);
+    p.go_to(Room::CabinMain, "cabin", "cabin");
+    if lit {
+        p.use_on(food, "fire", None);
+        if matches!(
+            food,
+            Item::Fish | Item::SmallFish | Item::Pupfish | Item::BigFish | Item::RawMeat
+        ) {
+            p.step(
+                format!("take {} off the fire", food.name()),
+                Some("once it smells about ready".to_string()),
+            );
+        }
+    } else {
+        p.blocked(
+            format!("use {} on fire", food.name()),
+            "the hearth is cold; `plan warm night` lights it",
+        );
+    }
+}
+
+fn build(p: &mut Planner, item: Item) {
+    let Some(fresh) = Blueprint::new(item) else {
+        p.blocked(
+            format!("create {}", item.name()),
+            format!("there's no way to build a {}", item.name()),
+        );
+        return;
+    };
+    if !p.state.knows_blueprint(item) {
+        let why = p
+            .state
+            .blueprint_hint_text(item)
+            .unwrap_or("you haven't learned how to make one yet");
+        p.blocked(format!("create {}", item.name()), why);
+        return;
+    }
+    let active = p
+        .state
+        .player
+        .active_project
+        .as_ref()
+        .filter(|bp| bp.target_item == item);
+    let bp = active.cloned().unwrap_or(fresh);
+
+    for need in bp.tools_needed() {
+        p.fetch_tool(need.accepts(), need.name());
+    }
+
+    let mut short: Vec<(Item, u32)> = bp
+        .missing_materials()
+        .into_iter()
+        .filter_map(|(material, missing)| {
+            let on_hand = p.count(&material)
+                + bp.substitutes_for(material)
+                    .map(|s| p.count(&s.item) / s.per_unit)
+                    .sum::<u32>();
+            missing
+                .checked_sub(on_hand)
+                .filter(|g| *g > 0)
+                .map(|g| (material, g))
+        })
+        .collect();
+    short.sort_by_key(|(material, _)| material.name());
+    for (material, gap) in short {
+        gather(p, material, gap);
+    }
+
+    if let Some(ws) = bp.workspace() {
+        let pos = p.state.player.position;
+        let beside_water = near_water(&pos, p.map);
+        match ws {
+            Workspace::BesideWater if !beside_water || !p.outdoors() => {
+                p.go_outside();
+                if let Some(water) = nearest_water(&pos, p.map).filter(|_| !beside_water) {
+                    p.step(
+                        "move",
+                        Some(format!(
+                            "to the water's edge, {}",
+                            distance_phrase(&pos, &water)
+                        )),
+                    );
+                }
+            }
+            Workspace::OpenGround if !p.outdoors() => p.go_outside(),
+            _ => {}
+        }
+    }
+    if active.is_none() {
+        if let Some(other) = &p.state.player.active_project {
+            p.step(
+                format!("create {}", item.name()),
+                Some(format!("this sets aside your {}", other.target_item.name())),
+            );
+        } else {
+            p.step(format!("create {}", item.name()), None);
+        }
+    }
+    p.step(
+        "use materials on blueprint",
+        Some("repeat until it's built".to_string()),
+    );
+}
+
+/// Where to get `gap` more of a build material.
+fn gather(p: &mut Planner, material: Item, gap: u32) {
+    let note = format!("{} x{} still short", material.name(), gap);
+    match material {
+        Item::Log => {
+            if let Some(axe) = p.fetch_tool(&[Item::Axe, Item::StoneAxe], "an axe") {
+                p.go_outside();
+                p.use_on(axe, "tree", Some(note));
+            }
+        }
+        Item::Kindling => {
+            if let Some(knife) = p.fetch_tool(&[Item::Knife, Item::StoneKnife], "a knife") {
+                if !p.has(Item::Stick) {
+                    p.forage_for(Item::Stick);
+                }
+                p.use_on(knife, "stick", Some(note));
+            }
+        }
+        Item::Cordage => {
+            if let Some(knife) = p.fetch_tool(&[Item::Knife, Item::StoneKnife], "a knife") {
+                if p.count(&Item::Reeds) < 2 * gap {
+                    p.go_outside();
+                    let pos = p.state.player.position;
+                    let note = match nearest_water(&pos, p.map) {
+                        Some(w) if !near_water(&pos, p.map) => format!(
+                            "forage for reeds by the water, {}",
+                            distance_phrase(&pos, &w)
+                        ),
+                        _ => "forage for reeds along the shore".to_string(),
+                    };
+                    p.step("use hands on bush", Some(note));
+                    p.gain(Item::Reeds, 2 * gap);
+                }
+                p.use_on(knife, "reeds", Some(note));
+            }
+        }
+        Item::SharpStone => {
+            if p.count(&Item::Stone) < 3 {
+                p.forage_for(Item::Stone);
+                p.gain(Item::Stone, 2);
+            }
+            p.use_on(Item::Stone, "stone", Some(note));
+        }
+        Item::Stick | Item::PlantFiber | Item::Stone | Item::WildHerbs | Item::DryLeaves => {
+            p.go_outside();
+            p.step("use hands on bush", Some(note));
+        }
+        _ => p.blocked(
+            format!("find {}", material.name()),
+            format!("{}; nothing nearby is sure to give it", note),
+        ),
+    }
+}
+
+/// The nearest walkable tile inside `region`.
+fn nearest_in(region: Region, from: &Position, map: &WorldMap) -> Option<Position> {
+    map.positions()
+        .filter(|pos| WorldMap::region_of(pos.row, pos.col) == region)
+        .filter(|pos| map.tile_at(pos).map(|t| t.walkable).unwrap_or(false))
+        .min_by(|a, b| from.distance_to(a).total_cmp(&from.distance_to(b)))
+}
+
+fn explore(p: &mut Planner, region: Region) {
+    let state = p.state;
+    let name = state.region_names.name(region).to_string();
+    let pos = state.player.position;
+    let player = &state.player;
+
+    if player.hydration < TRAVEL_NEED {
+        let note = format!("hydration is {:.0}", player.hydration);
+        let carried = player.inventory.slots.iter().any(|s| s.item.is_drink());
+        if carried || (p.outdoors() && near_water(&pos, p.map)) {
+            p.step("drink", Some(note));
+        } else if let Some(water) = nearest_water(&pos, p.map) {
+            p.go_outside();
+            p.step(
+                "drink",
+                Some(format!(
+                    "{}; water is {}",
+                    note,
+                    distance_phrase(&pos, &water)
+                )),
+            );
+        } else {
+            p.blocked("drink", format!("{} and there's no water in reach", note));
+        }
+    }
+    if player.fullness < TRAVEL_NEED {
+        match player
+            .inventory
+            .slots
+            .iter()
+            .map(|s| s.item)
+            .find(|i| i.is_food())
+        {
+            Some(food) => p.step(
+                format!("eat {}", food.name()),
+                Some(format!("fullness is {:.0}", player.fullness)),
+            ),
+            None => p.blocked("eat", "nothing to eat in your pack; `plan cook dinner`"),
+        }
+    }
+    if state.time.time_of_day().is_night() {
+        p.step(
+            "sleep",
+            Some(format!(
+                "it's {:02}:{:02} and dark; set out at first light",
+                state.time.hour, state.time.minute
+            )),
+        );
+    } else if player.energy < TRAVEL_NEED {
+        p.step(
+            "sleep",
+            Some(format!("energy is low ({:.0})", player.energy)),
+        );
+    }
+    let weather = state.weather.for_region(region);
+    if matches!(
+        weather,
+        Weather::Blizzard | Weather::Sandstorm | Weather::HeatWave | Weather::HeavySnow
+    ) {
+        p.step(
+            "forecast",
+            Some(format!(
+                "{} is in a {} right now; wait for it to pass",
+                name,
+                weather.name()
+            )),
+        );
+    }
+
+    p.go_outside();
+    if p.outdoors() && WorldMap::region_of(pos.row, pos.col) == region {
+        p.step("look", Some(format!("you're already in {}", name)));
+        return;
+    }
+    let dir = region.direction().name();
+    let note = nearest_in(region, &pos, p.map)
+        .map(|edge| format!("{} begins {}", name, distance_phrase(&pos, &edge)));
+    p.step(format!("move {}", dir), note);
+    p.step("look", Some(format!("take in {} when you arrive", name)));
+}
+
+/// An ordered list of commands toward `goal`, read off the state without
+/// changing it.
+pub fn plan_for(goal: &str, state: &GameState, map: &WorldMap) -> String {
+    let goal = goal.trim().to_lowercase();
+    let mut p = Planner::new(state, map);
+
+    let build_target = ["build ", "make ", "craft "]
+        .iter()
+        .find_map(|prefix| goal.strip_prefix(prefix));
+    let explore_target = ["explore ", "go ", "visit "]
+        .iter()
+        .find_map(|prefix| goal.strip_prefix(prefix));
+
+    let title = if let Some(rest) = build_target {
+        let rest = rest.trim_start_matches("a ").trim_start_matches("an ");
+        let Some(item) = Item::from_str(rest) else {
+            return format!("There's nothing called '{}' to build.", rest);
+        };
+        build(&mut p, item);
+        format!("build {}", item.name())
+    } else if let Some(rest) = explore_target {
+        let Some(region) = state.region_names.find(rest) else {
+            return format!(
+                "No region answers to '{}'. Try north, east, south or west.",
+                rest
+            );
+        };
+        explore(&mut p, region);
+        format!("explore {}", state.region_names.name(region))
+    } else if goal.contains("warm") || goal.contains("night") || goal.contains("fire") {
+        warm_night(&mut p);
+        "a warm night".to_string()
+    } else if goal.contains("cook") || goal.contains("dinner") || goal.contains("meal") {
+        cook_dinner(&mut p);
+        "cook dinner".to_string()
+    } else {
+        return format!(
+            "No plan for '{}'. Goals I can plan: {}.",
+            goal,
+            PLAN_GOALS.join(", ")
+        );
+    };
+    p.render(&title)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::Inventory;
+
+    /// Midday in the cabin, fed and rested, with a cold hearth and empty hands.
+    fn settled(map: &WorldMap) -> GameState {
+        let mut state = GameState::new(map);
+        let (w, clear) = (&mut state.weather, Weather::Clear);
+        (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+        state.player.room = Some(Room::CabinMain);
+        state.player.position = state.objects.find("cabin").unwrap().position;
+        (state.time.hour, state.time.minute) = (12, 0);
+        let player = &mut state.player;
+        (player.fullness, player.hydration, player.energy) = (90.0, 90.0, 90.0);
+        player.inventory = Inventory::new();
+        state
+    }
+
+    /// The numbered lines of a plan, without the heading.
+    fn steps(goal: &str, state: &GameState, map: &WorldMap) -> Vec<String> {
+        let plan = plan_for(goal, state, map);
+        plan.lines().skip(1).map(str::to_string).collect()
+    }
+
+    fn light(state: &mut GameState, fuel: f32) {
+        let fire = &mut state.cabin_state_mut().unwrap().fireplace;
+        (fire.state, fire.fuel) = (FireState::Burning, fuel);
+    }
+
+    #[test]
+    fn a_warm_night_fetches_what_the_fire_lacks_and_never_ticks() {
+        let map = WorldMap::new();
+        let mut state = settled(&map);
+        let before = serde_json::to_string(&state).unwrap();
+
+        // A cold hearth and an empty pack: the axe off the shed floor, a log
+        // split, and the rest waits beyond the cap
+        let plan = steps("warm night", &state, &map);
+        assert_eq!(plan.len(), MAX_STEPS + 1, "{:?}", plan);
+        assert_eq!(plan[2], "3. take axe — it's lying on the shed floor");
+        assert_eq!(plan[5], "6. use axe on chopping block — split it");
+        assert!(plan[MAX_STEPS].starts_with("…and "));
+        assert_eq!(serde_json::to_string(&state).unwrap(), before);
+
+        // With no axe anywhere, the missing tool is the block
+        state.wood_shed_state_mut().unwrap().axe_on_floor = false;
+        let plan = steps("warm night", &state, &map);
+        assert_eq!(plan[0], "1. find an axe — blocked: you aren't carrying one");
+        assert_eq!(plan[6], "7. use matchbox on fire — light it");
+
+        // Already burning: top it up from the stack, then sleep
+        light(&mut state, 20.0);
+        state.wood_shed_state_mut().unwrap().firewood = 4;
+        let plan = steps("keep the fire going tonight", &state, &map);
+        let last = plan.last().unwrap();
+        assert!(plan[2].starts_with("3. take firewood"), "{:?}", plan);
+        assert!(plan
+            .iter()
+            .any(|s| s.contains("use firewood on fire — enough to last until dawn")));
+        assert!(last.ends_with("sleep — by the fire, with the door shut"));
+
+        // Burning with enough wood: just sleep
+        light(&mut state, NIGHT_FUEL);
+        let plan = steps("warm night", &state, &map);
+        assert_eq!(plan, vec!["1. sleep — by the fire, with the door shut"]);
+    }
+
+    #[test]
+    fn cook_dinner_eats_what_is_cooked_and_needs_a_lit_hearth() {
+        let map = WorldMap::new();
+        let mut state = settled(&map);
+
+        // Raw fish in hand but a cold hearth
+        state.player.inventory.add(Item::Fish, 1);
+        let plan = steps("cook dinner", &state, &map);
+        let cold =
+            "1. use raw fish on fire — blocked: the hearth is cold; `plan warm night` lights it";
+        assert_eq!(plan, vec![cold]);
+
+        // Lit, so on it goes and off again when ready
+        light(&mut state, 50.0);
+        let plan = steps("cook a meal", &state, &map);
+        assert_eq!(plan[0], "1. use raw fish on fire");
+        assert!(plan[1].starts_with("2. take raw fish off the fire"));
+
+        // Something already cooked is simply eaten
+        state.player.inventory.add(Item::CookedFish, 1);
+        let plan = steps("dinner", &state, &map);
+        assert_eq!(plan, vec!["1. eat cooked fish — it's already cooked"]);
+    }
+
+    #[test]
+    fn build_flags_an_unknown_blueprint_and_gathers_what_is_short() {
+        let map = WorldMap::new();
+        let mut state = settled(&map);
+        let plan = steps("build raft", &state, &map);
+        assert_eq!(plan.len(), 1);
+        assert!(plan[0].starts_with("1. create raft — blocked: "));
+
+        // Known, with the axe, logs and stick in hand but no cordage or knife
+        state.player.known_blueprints.insert(Item::Raft);
+        for (item, count) in [(Item::Axe, 1), (Item::Log, 2), (Item::Stick, 1)] {
+            state.player.inventory.add(item, count);
+        }
+        let plan = steps("build a raft", &state, &map);
+        assert_eq!(
+            plan[0],
+            "1. find a knife — blocked: you aren't carrying one"
+        );
+        assert!(
+            !plan.iter().any(|s| s.contains("use axe on tree")),
+            "{:?}",
+            plan
+        );
+
+        // With a knife, reeds are cut into the cordage still short
+        state.player.inventory.add(Item::Knife, 1);
+        let plan = steps("build raft", &state, &map);
+        assert!(plan
+            .iter()
+            .any(|s| s.contains("use knife on reeds — cordage x2 still short")));
+        assert!(plan.iter().any(|s| s.ends_with("create raft")));
+        assert!(plan.last().unwrap().contains("use materials on blueprint"));
+        assert!(!plan.iter().any(|s| s.contains("blocked")), "{:?}", plan);
+    }
+
+    #[test]
+    fn explore_sees_to_needs_night_and_weather_before_setting_out() {
+        let map = WorldMap::new();
+        let mut state = settled(&map);
+        let plan = steps("explore west", &state, &map);
+        assert_eq!(
+            plan[1],
+            "2. move west — the Glass Desert begins just to the west"
+        );
+
+        // Thirsty at night, with a sandstorm where it's headed
+        state.player.hydration = 20.0;
+        state.time.hour = 23;
+        state.weather.west = Weather::Sandstorm;
+        let plan = steps("go west", &state, &map);
+        assert_eq!(
+            plan[1],
+            "2. drink — hydration is 20; water is just to the north"
+        );
+        assert_eq!(
+            plan[2],
+            "3. sleep — it's 23:00 and dark; set out at first light"
+        );
+        assert!(plan[3].starts_with("4. forecast — the Glass Desert is in a sandstorm"));
+
+        // Somewhere already reached, and somewhere that isn't a region
+        let plan = steps("explore east", &state, &map);
+        assert!(plan
+            .last()
+            .unwrap()
+            .ends_with("you're already in the Eastern Pines"));
+        assert!(plan_for("explore atlantis", &state, &map).starts_with("No region answers"));
+        assert!(plan_for("dance", &state, &map).starts_with("No plan for 'dance'"));
+    }
+}
@@ -0,0 +1,566 @@
+//! The `use X on Y` patterns the game understands, kept in one table so the
+//! `help use` listing, the hints after a failed `use` and the books that
+//! teach them never disagree.
+
+use crate::descriptions::distance_phrase;
+use crate::entity::{Item, Room};
+use crate::persistence::GameState;
+use crate::world::{Biome, ObjectKind, Position, WorldMap};
+
+/// Where a pattern has to be carried out.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Site {
+    Anywhere,
+    /// Out of doors, anywhere there's ground to search.
+    Outdoors,
+    /// At the edge of the lake or the oasis.
+    Water,
+    /// Inside the cabin, by the fireplace.
+    Hearth,
+    /// Inside the wood shed.
+    Shed,
+    /// On a tile with a standing tree.
+    Tree,
+    /// Inside the desert ruin.
+    Ruin,
+    /// Standing over a carcass.
+    Carcass,
+}
+
+struct UsePattern {
+    /// Any of these can be the item being used; none means bare hands.
+    items: &'static [Item],
+    /// What to type as the target.
+    target: &'static str,
+    /// Something that has to be carried, with how many.
+    needs: Option<(Item, u32)>,
+    site: Site,
+    /// What comes of it, in a word or two.
+    result: &'static str,
+}
+
+const KNIVES: &[Item] = &[Item::Knife, Item::StoneKnife];
+const AXES: &[Item] = &[Item::Axe, Item::StoneAxe];
+const HANDS: &[Item] = &[];
+
+const USE_PATTERNS: &[UsePattern] = &[
+    UsePattern {
+        items: HANDS,
+        target: "bush",
+        needs: None,
+        site: Site::Outdoors,
+        result: "foraged sticks, fibres and berries",
+    },
+    UsePattern {
+        items: HANDS,
+        target: "water",
+        needs: None,
+        site: Site::Water,
+        result: "a fish now and then, if you're quick",
+    },
+    UsePattern {
+        items: AXES,
+        target: "tree",
+        needs: None,
+        site: Site::Tree,
+        result: "logs",
+    },
+    UsePattern {
+        items: &[Item::Log],
+        target: "chopping block",
+        needs: None,
+        site: Site::Shed,
+        result: "a log set up to split",
+    },
+    UsePattern {
+        items: AXES,
+        target: "chopping block",
+        needs: None,
+        site: Site::Shed,
+        result: "firewood",
+    },
+    UsePattern {
+        items: KNIVES,
+        target: "log",
+        needs: Some((Item::Log, 1)),
+        site: Site::Anywhere,
+        result: "kindling",
+    },
+    UsePattern {
+        items: KNIVES,
+        target: "stick",
+        needs: Some((Item::Stick, 1)),
+        site: Site::Anywhere,
+        result: "kindling",
+    },
+    UsePattern {
+        items: KNIVES,
+        target: "bamboo",
+        needs: Some((Item::Bamboo, 1)),
+        site: Site::Anywhere,
+        result: "paper",
+    },
+    UsePattern {
+        items: &[Item::Stone],
+        target: "stone",
+        needs: Some((Item::Stone, 2)),
+        site: Site::Anywhere,
+        result: "a sharp stone",
+    },
+    UsePattern {
+        items: KNIVES,
+        target: "reeds",
+        needs: Some((Item::Reeds, 2)),
+        site: Site::Anywhere,
+        result: "cordage",
+    },
+    UsePattern {
+        items: &[Item::Flint],
+        target: "stone",
+        needs: Some((Item::Stone, 1)),
+        site: Site::Anywhere,
+        result: "two sharp stones",
+    },
+    UsePattern {
+        items: &[
+            Item::Knife,
+            Item::StoneKnife,
+            Item::Axe,
+            Item::StoneAxe,
+            Item::SharpStone,
+        ],
+        target: "carcass",
+        needs: None,
+        site: Site::Carcass,
+        result: "meat and hide",
+    },
+    UsePattern {
+        items: &[Item::Stone],
+        target: "lake",
+        needs: None,
+        site: Site::Water,
+        result: "a skipped stone",
+    },
+    UsePattern {
+        items: &[Item::Shovel],
+        target: "sand",
+        needs: None,
+        site: Site::Ruin,
+        result: "a chamber dug clear",
+    },
+    UsePattern {
+        items: &[Item::FireflyJar],
+        target: "sky",
+        needs: None,
+        site: Site::Anywhere,
+        result: "freed fireflies",
+    },
+    UsePattern {
+        items: &[Item::Paper],
+        target: "paper",
+        needs: Some((Item::Paper, 5)),
+        site: Site::Anywhere,
+        result: "a blank book",
+    },
+    UsePattern {
+        items: &[
+            Item::DryLeaves,
+            Item::Bark,
+            Item::Pinecone,
+            Item::Kindling,
+            Item::Stick,
+            Item::Log,
+            Item::Firewood,
+        ],
+        target: "fire",
+        needs: None,
+        site: Site::Hearth,
+        result: "fuel for the fire",
+    },
+    UsePattern {
+        items: &[Item::Matchbox],
+        target: "fire",
+        needs: None,
+        site: Site::Hearth,
+        result: "a lit fire",
+    },
+    UsePattern {
+        items: &[
+            Item::Fish,
+            Item::SmallFish,
+            Item::Pupfish,
+            Item::BigFish,
+            Item::RawMeat,
+            Item::WildBerry,
+            Item::PineNuts,
+            Item::Acorn,
+            Item::BambooShoot,
+        ],
+        target: "fire",
+        needs: None,
+        site: Site::Hearth,
+        result: "a cooked meal",
+    },
+];
+
+/// Why a pattern can't be done right now, or `None` if it can.
+fn blocker(pattern: &UsePattern, state: &GameState, map: &WorldMap) -> Option<String> {
+    if let Some((need, count)) = pattern.needs {
+        if state.player.inventory.count(&need) < count {
+            return Some(needs_phrase(need, count));
+        }
+    }
+    site_blocker(pattern.site, state, map)
+}
+
+fn needs_phrase(need: Item, count: u32) -> String {
+    if count > 1 {
+        format!("carry {} {}", count, need.name())
+    } else {
+        format!("carry some {}", need.name())
+    }
+}
+
+/// Where the player has to go first for a pattern done at `site`.
+fn site_blocker(site: Site, state: &GameState, map: &WorldMap) -> Option<String> {
+    let pos = state.player.position;
+    let outdoors = state.player.room.is_none() && state.player.inside.is_none();
+    match site {
+        Site::Anywhere => None,
+        Site::Outdoors => (!outdoors).then(|| "be outdoors".to_string()),
+        Site::Carcass => {
+            let carcass = state
+                .objects
+                .objects_at(&pos)
+                .iter()
+                .any(|po| matches!(po.object.kind, ObjectKind::Corpse(_)));
+            (!(outdoors && carcass)).then(|| "stand over a carcass".to_string())
+        }
+        Site::Water => {
+            if outdoors && near_water(&pos, map) {
+                None
+            } else {
+                Some(match nearest_water(&pos, map) {
+                    Some(water) => format!("be by the water, {}", distance_phrase(&pos, &water)),
+                    None => "be by the water".to_string(),
+                })
+            }
+        }
+        Site::Hearth => {
+            if matches!(state.player.room, Some(Room::CabinMain)) {
+                None
+            } else if let Some(cabin) = state.objects.find("cabin") {
+                Some(format!(
+                    "be at the cabin hearth, {}",
+                    distance_phrase(&pos, &cabin.position)
+                ))
+            } else {
+                Some("be at the cabin hearth".to_string())
+            }
+        }
+        Site::Shed => {
+            if matches!(state.player.room, Some(Room::WoodShed)) {
+                None
+            } else {
+                Some("be in the wood shed".to_string())
+            }
+        }
+        Site::Ruin => {
+            if state.in_ruin() {
+                None
+            } else {
+                Some("be inside the desert ruin".to_string())
+            }
+        }
+        Site::Tree => {
+            let standing = state
+                .objects
+                .find_tree_at(&pos)
+                .map(|t| !t.felled)
+                .unwrap_or(false);
+            if outdoors && standing {
+                None
+            } else {
+                Some("stand beside a tree".to_string())
+            }
+        }
+    }
+}
+
+/// Whether the lake or the oasis is within a step of `pos`.
+pub fn near_water(pos: &Position, map: &WorldMap) -> bool {
+    (-1..=1).any(|dr| (-1..=1).any(|dc| is_water(&Position::new(pos.row + dr, pos.col + dc), map)))
+}
+
+fn is_water(pos: &Position, map: &WorldMap) -> bool {
+    map.tile_at(pos)
+        .map(|t| matches!(t.biome, Biome::Lake | Biome::Oasis))
+        .unwrap_or(false)
+}
+
+/// The closest lake or oasis tile, ties broken by scan order.
+pub fn nearest_water(from: &Position, map: &WorldMap) -> Option<Position> {
+    let mut best: Option<(f32, Position)> = None;
+    for pos in map.positions() {
+        if !is_water(&pos, map) {
+            continue;
+        }
+        let d = from.distance_to(&pos);
+        if best.map(|(bd, _)| d < bd).unwrap_or(true) {
+            best = Some((d, pos));
+        }
+    }
+    best.map(|(_, p)| p)
+}
+
+/// How `use item on target` stands against the table right now.
+pub struct UseCheck {
+    /// What has to be carried alongside the item, with how many.
+    pub needs: Option<(Item, u32)>,
+    /// The room it has to be done in, for those done indoors.
+    pub room: Option<Room>,
+    /// Where the player would have to go first, from where they stand.
+    pub away: Option<String>,
+}
+
+impl UseCheck {
+    /// What is missing when `have` says how many of something are on hand.
+    pub fn missing(&self, have: impl Fn(&Item) -> u32) -> Option<String> {
+        let (need, count) = self.needs?;
+        (have(&need) < count).then(|| needs_phrase(need, count))
+    }
+}
+
+/// Looks `use item on target` up in the table. `None` if the game has no
+/// such pattern, so callers can't offer a combination that never works.
+pub fn check_use(item: Item, target: &str, state: &GameState, map: &WorldMap) -> Option<UseCheck> {
+    let pattern = USE_PATTERNS
+        .iter()
+        .find(|p| p.target == target && p.items.contains(&item))?;
+    Some(UseCheck {
+        needs: pattern.needs,
+        room: match pattern.site {
+            Site::Hearth => Some(Room::CabinMain),
+            Site::Shed => Some(Room::WoodShed),
+            _ => None,
+        },
+        away: site_blocker(pattern.site, state, map),
+    })
+}
+
+fn command(item: Item, pattern: &UsePattern) -> String {
+    format!(
+        "use {} on {} → {}",
+        item.name(),
+        pattern.target,
+        pattern.result
+    )
+}
+
+/// Up to two commands that would work for `item` from where the player
+/// stands, in table order. When nothing works yet, the closest miss says
+/// what is missing instead.
+pub fn suggest_uses(item: Item, state: &GameState, map: &WorldMap) -> Vec<String> {
+    let mut ready = Vec::new();
+    let mut missing = Vec::new();
+
+    if let Some(bp) = &state.player.active_project {
+        let mut preview = bp.clone();
+        if preview.add_material(item) {
+            ready.push(format!(
+                "use {} on blueprint → {}",
+                item.name(),
+                bp.target_item.name()
+            ));
+        }
+    }
+
+    for pattern in USE_PATTERNS {
+        if pattern.items.contains(&item) {
+            match blocker(pattern, state, map) {
+                None => ready.push(command(item, pattern)),
+                Some(why) => missing.push(format!(
+                    "to get {} with the {}, {}",
+                    pattern.result,
+                    item.name(),
+                    why
+                )),
+            }
+            continue;
+        }
+        // The item is the material: point at a tool the player already holds.
+        if pattern.needs.map(|(need, _)| need) == Some(item) {
+            if let Some(tool) = pattern
+                .items
+                .iter()
+                .find(|t| state.player.inventory.has(t, 1))
+            {
+                if blocker(pattern, state, map).is_none() {
+                    ready.push(command(*tool, pattern));
+                }
+            }
+        }
+    }
+
+    if ready.is_empty() {
+        missing.truncate(1);
+        return missing;
+    }
+    ready.truncate(2);
+    ready
+}
+
+/// The whole table as a help page.
+pub fn use_help() -> String {
+    let mut text = String::from("**Using things on things:**\n");
+    for pattern in USE_PATTERNS {
+        let mut items: Vec<&str> = pattern.items.iter().map(|i| i.name()).collect();
+        if items.is_empty() {
+            items.push("hands");
+        }
+        let mut line = format!(
+            "- use {} on {} → {}",
+            items.join("/"),
+            pattern.target,
+            pattern.result
+        );
+        if let Some((need, count)) = pattern.needs {
+            if count > 1 {
+                line.push_str(&format!(" (needs {} {})", count, need.name()));
+            }
+        }
+        match pattern.site {
+            Site::Anywhere => {}
+            Site::Outdoors => line.push_str(", outdoors"),
+            Site::Carcass => line.push_str(", standing over a carcass"),
+            Site::Water => line.push_str(", at the water's edge"),
+            Site::Hearth => line.push_str(", at the cabin hearth"),
+            Site::Shed => line.push_str(", in the wood shed"),
+            Site::Tree => line.push_str(", standing at a tree"),
+            Site::Ruin => line.push_str(", inside the desert ruin"),
+        }
+        text.push_str(&line);
+        text.push('\n');
+    }
+    text.push_str("- use [material] on blueprint → progress on the active project");
+    text
+}
+
+/// Words prose puts in place of an item ("use each on fire") rather than
+/// naming one.
+const STAND_INS: &[&str] = &["each", "it", "them", "one", "this", "that"];
+
+/// Other names prose gives a pattern's target.
+const TARGET_ALIASES: &[(&str, &str)] = &[
+    ("block", "chopping block"),
+    ("pig", "carcass"),
+    ("corpse", "carcass"),
+    ("bushes", "bush"),
+    ("shallows", "water"),
+];
+
+/// Words that end the target of a `use` in running prose.
+const CLAUSE_WORDS: &[&str] = &["or", "and", "to", "then", "when", "while", "if", "for"];
+
+/// Every "use X on Y" in `text` that the table doesn't back, as written.
+/// Book pages are held to this, so what they teach stays something that
+/// works.
+pub fn untrue_use_phrases(text: &str) -> Vec<String> {
+    let words: Vec<(String, bool)> = text
+        .split_whitespace()
+        .map(|w| {
+            let ends_clause = w.ends_with(|c: char| ",.;:!?)'\"`".contains(c));
+            let core = w
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase();
+            (core, ends_clause)
+        })
+        .collect();
+    let mut untrue = Vec::new();
+    for (start, (word, ends)) in words.iter().enumerate() {
+        if word != "use" || *ends {
+            continue;
+        }
+        let Some(on) = words[start + 1..]
+            .iter()
+            .take(4)
+            .position(|(w, _)| w == "on")
+            .map(|i| start + 1 + i)
+        else {
+            continue;
+        };
+        let item_words = &words[start + 1..on];
+        if item_words.is_empty() || item_words.iter().any(|(_, ends)| *ends) {
+            continue;
+        }
+        let item = item_words
+            .iter()
+            .map(|(w, _)| w.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if STAND_INS.contains(&item.as_str()) {
+            continue;
+        }
+        let mut target_words = Vec::new();
+        for (w, ends) in words[on + 1..].iter().take(3) {
+            if CLAUSE_WORDS.contains(&w.as_str()) {
+                break;
+            }
+            target_words.push(w.as_str());
+            if *ends {
+                break;
+            }
+        }
+        let target = target_words.join(" ");
+        let target = TARGET_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == target)
+            .map(|(_, canonical)| canonical.to_string())
+            .unwrap_or(target);
+        let hands = matches!(item.as_str(), "hand" | "hands" | "bare hands");
+        let backed = USE_PATTERNS.iter().any(|p| {
+            p.target == target
+                && if hands {
+                    p.items.is_empty()
+                } else {
+                    Item::from_str(&item)
+                        .map(|i| p.items.contains(&i))
+                        .unwrap_or(false)
+                }
+        });
+        if !backed {
+            untrue.push(format!("use {} on {}", item, target_words.join(" ")));
+        }
+    }
+    untrue
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_use_a_printed_book_teaches_is_one_the_game_knows() {
+        let map = WorldMap::new();
+        let state = GameState::new(&map);
+        let mut taught = 0;
+        for book in state.books.values().filter(|b| !b.writable) {
+            for page in &book.pages {
+                taught += page.matches("use ").count();
+                let untrue = untrue_use_phrases(page);
+                assert!(untrue.is_empty(), "'{}' teaches {:?}", book.title, untrue);
+            }
+        }
+        assert!(taught > 5, "only {} uses taught", taught);
+
+        // Prose around the pattern doesn't matter; the pattern does
+        let page = "Use hands on bush to forage, then use axe on block, or use each on fire.";
+        assert!(untrue_use_phrases(page).is_empty());
+        assert!(untrue_use_phrases("Try use hands on water or near the shore.").is_empty());
+        let made_up = "Just use spoon on moon, or use hands on sky.";
+        assert_eq!(
+            untrue_use_phrases(made_up),
+            ["use spoon on moon", "use hands on sky"]
+        );
+    }
+}
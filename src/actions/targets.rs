@@ -0,0 +1,498 @@
+//! Working out which fixed thing a command means: the doors, windows and
+//! furniture that `open`, `close`, `examine`, `kick` and placing things act on. Input is
+//! split into words and matched against each fixture's names, English and
+//! Korean alike, and only what's within reach of the player's room or tile
+//! is offered when the phrasing could mean more than one.
+
+use crate::entity::{Item, Room};
+use crate::persistence::GameState;
+
+/// What the player is trying to do, which narrows what they could mean.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Verb {
+    Open,
+    Close,
+    Examine,
+    Kick,
+    /// Setting something down on a surface: `use book on table`.
+    Place,
+}
+
+impl Verb {
+    fn name(&self) -> &'static str {
+        match self {
+            Verb::Open => "open",
+            Verb::Close => "close",
+            Verb::Examine => "examine",
+            Verb::Kick => "kick",
+            Verb::Place => "put it on",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Fixture {
+    CabinDoor,
+    ShedDoor,
+    Windows,
+    Shutters,
+    CardCase,
+    Hearth,
+    Mantel,
+    Table,
+    ChoppingBlock,
+    Workbench,
+    Tree,
+}
+
+impl Fixture {
+    const ALL: [Fixture; 11] = [
+        Fixture::CabinDoor,
+        Fixture::ShedDoor,
+        Fixture::Windows,
+        Fixture::Shutters,
+        Fixture::CardCase,
+        Fixture::Hearth,
+        Fixture::Mantel,
+        Fixture::Table,
+        Fixture::ChoppingBlock,
+        Fixture::Workbench,
+        Fixture::Tree,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Fixture::CabinDoor => "cabin door",
+            Fixture::ShedDoor => "shed door",
+            Fixture::Windows => "windows",
+            Fixture::Shutters => "shutters",
+            Fixture::CardCase => "card case",
+            Fixture::Hearth => "hearth",
+            Fixture::Mantel => "mantelpiece",
+            Fixture::Table => "table",
+            Fixture::ChoppingBlock => "chopping block",
+            Fixture::Workbench => "workbench",
+            Fixture::Tree => "tree",
+        }
+    }
+
+    /// Every name this fixture answers to, one phrase per entry.
+    fn names(&self) -> &'static [&'static str] {
+        match self {
+            Fixture::CabinDoor => &[
+                "door",
+                "front door",
+                "cabin door",
+                "cabin",
+                "entrance",
+                "문",
+                "현관",
+                "현관문",
+                "오두막",
+                "오두막 문",
+            ],
+            Fixture::ShedDoor => &[
+                "door",
+                "shed door",
+                "shed",
+                "wood shed",
+                "woodshed",
+                "문",
+                "헛간",
+                "헛간 문",
+                "창고",
+                "창고 문",
+                "장작 창고",
+            ],
+            Fixture::Windows => &["window", "cabin window", "glass", "창문", "창", "유리창"],
+            Fixture::Shutters => &["shutter", "window shutter", "덧문", "덧창", "셔터"],
+            Fixture::CardCase => &[
+                "card case",
+                "card",
+                "case",
+                "deck",
+                "카드",
+                "카드 케이스",
+                "카드갑",
+                "카드 상자",
+            ],
+            Fixture::Hearth => &[
+                "hearth",
+                "fire",
+                "fireplace",
+                "chimney",
+                "벽난로",
+                "난로",
+                "불",
+            ],
+            Fixture::Mantel => &[
+                "mantel",
+                "mantelpiece",
+                "mantle",
+                "shelf",
+                "벽난로 선반",
+                "선반",
+            ],
+            Fixture::Table => &["table", "kitchen table", "desk", "탁자", "식탁", "테이블"],
+            Fixture::ChoppingBlock => &["chopping block", "block", "stump", "모탕", "도끼 받침"],
+            Fixture::Workbench => &["workbench", "work bench", "bench", "작업대"],
+            Fixture::Tree => &["tree", "trunk", "나무"],
+        }
+    }
+
+    fn accepts(&self, verb: Verb) -> bool {
+        match verb {
+            Verb::Open | Verb::Close => matches!(
+                self,
+                Fixture::CabinDoor
+                    | Fixture::ShedDoor
+                    | Fixture::Windows
+                    | Fixture::Shutters
+                    | Fixture::CardCase
+            ),
+            Verb::Examine => *self != Fixture::Tree,
+            Verb::Kick => matches!(self, Fixture::Tree | Fixture::CabinDoor | Fixture::ShedDoor),
+            Verb::Place => matches!(self, Fixture::Mantel | Fixture::Table),
+        }
+    }
+
+    /// Whether `verb` would change nothing: opening what's already open,
+    /// closing what's already shut.
+    fn is_noop(&self, verb: Verb, state: &GameState) -> bool {
+        let open = match self {
+            Fixture::CabinDoor => state.cabin_state().map(|c| c.door_open),
+            Fixture::ShedDoor => state.wood_shed_state().map(|w| w.door_open),
+            Fixture::Windows => state.cabin_state().map(|c| c.windows_open),
+            Fixture::Shutters => state.cabin_state().map(|c| !c.shutters_closed),
+            Fixture::CardCase => Some(state.card_case_open),
+            _ => None,
+        };
+        match (verb, open) {
+            (Verb::Open, Some(open)) => open,
+            (Verb::Close, Some(open)) => !open,
+            _ => false,
+        }
+    }
+
+    /// Whether the player is where they could reach it.
+    pub fn in_scope(&self, state: &GameState) -> bool {
+        let room = state.player.room.as_ref();
+        let outdoors = room.is_none() && state.player.inside.is_none();
+        let near = |id: &str| {
+            outdoors
+                && state
+                    .objects
+                    .find(id)
+                    .map(|p| p.position.distance_to(&state.player.position) <= 1.5)
+                    .unwrap_or(false)
+        };
+        match self {
+            Fixture::CabinDoor | Fixture::Windows | Fixture::Shutters => {
+                matches!(room, Some(Room::CabinMain)) || near("cabin")
+            }
+            Fixture::ShedDoor => matches!(room, Some(Room::WoodShed)) || near("wood_shed"),
+            Fixture::CardCase => {
+                state.player.inventory.has(&Item::CardCase, 1)
+                    || matches!(room, Some(Room::CabinMain))
+            }
+            Fixture::Hearth | Fixture::Mantel | Fixture::Table => {
+                matches!(room, Some(Room::CabinMain))
+            }
+            Fixture::ChoppingBlock | Fixture::Workbench => matches!(room, Some(Room::WoodShed)),
+            Fixture::Tree => {
+                outdoors
+                    && state
+                        .objects
+                        .find_tree_at(&state.player.position)
+                        .is_some_and(|t| !t.felled)
+            }
+        }
+    }
+}
+
+/// What a phrase turned out to mean.
+pub enum Resolved {
+    /// One fixture. `whole` is set when every word of the phrase went to
+    /// naming it, so "book on the table" doesn't count as the table.
+    Found {
+        fixture: Fixture,
+        whole: bool,
+    },
+    /// Several fit equally well; the player has to pick.
+    Ambiguous(Vec<Fixture>),
+    Unknown,
+}
+
+const STOP_WORDS: &[&str] = &[
+    "the",
+    "a",
+    "an",
+    "of",
+    "to",
+    "on",
+    "at",
+    "in",
+    "into",
+    "my",
+    "your",
+    "please",
+    "open",
+    "close",
+    "shut",
+    "examine",
+    "inspect",
+    "look",
+    "kick",
+    "좀",
+    "열어",
+    "열기",
+    "열다",
+    "닫아",
+    "닫기",
+    "닫다",
+    "살펴봐",
+    "살펴보기",
+    "봐",
+    "보기",
+    "차기",
+    "걷어차",
+];
+
+const PRONOUNS: &[&str] = &[
+    "it", "them", "that", "this", "one", "그거", "이거", "저거", "그것", "이것", "저것",
+];
+
+/// Korean particles that cling to the end of a noun: 문을, 헛간의, 나무에서.
+const PARTICLES: &[&str] = &["에서", "을", "를", "이", "가", "은", "는", "의", "에", "도"];
+
+fn strip_particle(word: &str) -> &str {
+    if word.is_ascii() {
+        return word;
+    }
+    for p in PARTICLES {
+        if let Some(stem) = word.strip_suffix(p) {
+            if !stem.is_empty() {
+                return stem;
+            }
+        }
+    }
+    word
+}
+
+/// Lowercased words, with particles and filler dropped.
+fn tokenize(input: &str) -> Vec<String> {
+    input
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| strip_particle(w).to_string())
+        .filter(|w| !STOP_WORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// Whether one word of input stands for one word of a name. Plurals count,
+/// and Korean written without spaces ("헛간문") still finds "헛간".
+fn word_matches(word: &str, name: &str) -> bool {
+    if word == name || word.strip_suffix('s') == Some(name) {
+        return true;
+    }
+    !word.is_ascii() && name.chars().count() >= 2 && word.contains(name)
+}
+
+/// How many words of the input go to naming this fixture.
+fn coverage(fixture: Fixture, words: &[String]) -> usize {
+    let mut covered = vec![false; words.len()];
+    for name in fixture.names() {
+        let parts: Vec<&str> = name.split(' ').collect();
+        if parts.len() > words.len() {
+            continue;
+        }
+        for start in 0..=words.len() - parts.len() {
+            let hit = parts
+                .iter()
+                .enumerate()
+                .all(|(i, part)| word_matches(&words[start + i], part));
+            if hit {
+                covered[start..start + parts.len()]
+                    .iter_mut()
+                    .for_each(|c| *c = true);
+            }
+        }
+    }
+    covered.iter().filter(|c| **c).count()
+}
+
+/// Of several fixtures, the ones `verb` would actually change, unless it
+/// would change none of them.
+fn drop_noops(options: Vec<Fixture>, state: &GameState, verb: Verb) -> Vec<Fixture> {
+    let live: Vec<Fixture> = options
+        .iter()
+        .copied()
+        .filter(|f| !f.is_noop(verb, state))
+        .collect();
+    if live.is_empty() {
+        options
+    } else {
+        live
+    }
+}
+
+/// Work out which fixture `input` means for `verb`. Among equally good
+/// matches, what the player can reach wins, and then what `verb` would
+/// change; a bare "it" means whatever is within reach, if there's only one
+/// such thing. A phrase that fits several things, none of them in reach,
+/// means nothing here.
+pub fn resolve_fixture(input: &str, state: &GameState, verb: Verb) -> Resolved {
+    let words = tokenize(input);
+    let candidates = Fixture::ALL.into_iter().filter(|f| f.accepts(verb));
+
+    if words.iter().all(|w| PRONOUNS.contains(&w.as_str())) {
+        let here: Vec<Fixture> = candidates.filter(|f| f.in_scope(state)).collect();
+        let here = drop_noops(here, state, verb);
+        return match here.as_slice() {
+            [] => Resolved::Unknown,
+            [only] => Resolved::Found {
+                fixture: *only,
+                whole: true,
+            },
+            _ => Resolved::Ambiguous(here),
+        };
+    }
+
+    let scored: Vec<(Fixture, usize)> = candidates
+        .map(|f| (f, coverage(f, &words)))
+        .filter(|(_, c)| *c > 0)
+        .collect();
+    let Some(best) = scored.iter().map(|(_, c)| *c).max() else {
+        return Resolved::Unknown;
+    };
+    let whole = best == words.len();
+    let top: Vec<Fixture> = scored
+        .into_iter()
+        .filter(|(_, c)| *c == best)
+        .map(|(f, _)| f)
+        .collect();
+    let near: Vec<Fixture> = top.iter().copied().filter(|f| f.in_scope(state)).collect();
+    let options = match (near.is_empty(), top.len()) {
+        // Named outright, just out of reach: say so rather than shrug
+        (true, 1) => top,
+        (true, _) => return Resolved::Unknown,
+        (false, _) => drop_noops(near, state, verb),
+    };
+    match options.as_slice() {
+        [only] => Resolved::Found {
+            fixture: *only,
+            whole,
+        },
+        _ => Resolved::Ambiguous(options),
+    }
+}
+
+/// "Which do you mean to open: the cabin door, the windows, or the shutters?"
+pub fn ambiguity_message(options: &[Fixture], verb: Verb) -> String {
+    let labels: Vec<String> = options
+        .iter()
+        .map(|f| format!("the {}", f.label()))
+        .collect();
+    let list = match labels.as_slice() {
+        [] => String::new(),
+        [one] => one.clone(),
+        [a, b] => format!("{} or {}", a, b),
+        [rest @ .., last] => format!("{}, or {}", rest.join(", "), last),
+    };
+    format!("Which do you mean to {}: {}?", verb.name(), list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::Position;
+
+    fn in_cabin() -> GameState {
+        let mut state = GameState::default();
+        state.player.room = Some(Room::CabinMain);
+        state
+    }
+
+    fn found(resolved: Resolved) -> Option<(Fixture, bool)> {
+        match resolved {
+            Resolved::Found { fixture, whole } => Some((fixture, whole)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn doors_out_of_reach_arent_offered() {
+        let mut state = GameState::default();
+        state.player.room = None;
+        state.player.inside = None;
+        state.player.position = Position::new(4, 0);
+        for phrase in ["door", "문"] {
+            assert!(
+                matches!(
+                    resolve_fixture(phrase, &state, Verb::Open),
+                    Resolved::Unknown
+                ),
+                "{}",
+                phrase
+            );
+        }
+        assert_eq!(
+            found(resolve_fixture("shed door", &state, Verb::Open)),
+            Some((Fixture::ShedDoor, true))
+        );
+    }
+
+    #[test]
+    fn what_is_already_open_isnt_offered_to_open() {
+        let mut state = in_cabin();
+        state.cabin_state_mut().unwrap().door_open = true;
+        match resolve_fixture("it", &state, Verb::Open) {
+            Resolved::Ambiguous(options) => assert!(!options.contains(&Fixture::CabinDoor)),
+            Resolved::Found { fixture, .. } => assert_ne!(fixture, Fixture::CabinDoor),
+            Resolved::Unknown => panic!("nothing to open"),
+        }
+        assert_eq!(
+            found(resolve_fixture("door", &state, Verb::Open)),
+            Some((Fixture::CabinDoor, true))
+        );
+    }
+
+    #[test]
+    fn placing_finds_surfaces_by_any_name() {
+        let state = in_cabin();
+        for (phrase, fixture) in [
+            ("table", Fixture::Table),
+            ("the desk", Fixture::Table),
+            ("탁자", Fixture::Table),
+            ("식탁에", Fixture::Table),
+            ("mantel", Fixture::Mantel),
+            ("shelf", Fixture::Mantel),
+            ("벽난로 선반", Fixture::Mantel),
+        ] {
+            assert_eq!(
+                found(resolve_fixture(phrase, &state, Verb::Place)),
+                Some((fixture, true)),
+                "{}",
+                phrase
+            );
+        }
+    }
+
+    #[test]
+    fn placing_ignores_what_only_mentions_a_surface() {
+        let state = in_cabin();
+        assert!(matches!(
+            resolve_fixture("tablecloth", &state, Verb::Place),
+            Resolved::Unknown
+        ));
+        assert!(matches!(
+            resolve_fixture("crate by the table", &state, Verb::Place),
+            Resolved::Found { whole: false, .. }
+        ));
+        assert!(matches!(
+            resolve_fixture("cabin door", &state, Verb::Place),
+            Resolved::Unknown
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+//! How the player's mood colours what they see outdoors. Never more than one
+//! phrase per description changes: the time of day in the intro or the
+//! opening of the sky line, swapped for a warmer or a more subdued twin.
+//! Which of the two is picked comes from a generator seeded by place and
+//! hour, so looking twice in the same hour reads the same.
+
+use crate::entity::Player;
+use crate::world::{Position, WorldTime};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Mood at or above which the warm set is used.
+pub const WARM_MOOD: f32 = 80.0;
+/// Mood at or below which the subdued set is used.
+pub const SUBDUED_MOOD: f32 = 30.0;
+/// Connection at which the place reads warmly whatever the mood.
+pub const WARM_CONNECTION: f32 = 75.0;
+/// How long the calm after meditating keeps things warm.
+pub const AFTERGLOW_MINUTES: u32 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Affect {
+    Warm,
+    Plain,
+    Subdued,
+}
+
+/// Each line: the plain phrase, its warm twin, its subdued twin.
+const ALTERNATES: &[(&str, &str, &str)] = &[
+    // Intro
+    (
+        "As dawn breaks",
+        "As a kind dawn breaks",
+        "As a pale dawn breaks",
+    ),
+    (
+        "In the bright morning light",
+        "In the fresh, clear morning light",
+        "In the thin morning light",
+    ),
+    (
+        "Under the midday sun",
+        "Under a generous midday sun",
+        "Under the flat midday sun",
+    ),
+    (
+        "In the lazy afternoon",
+        "In the easy, unhurried afternoon",
+        "In the long, slow afternoon",
+    ),
+    (
+        "As dusk settles",
+        "As dusk settles gently",
+        "As dusk closes in",
+    ),
+    (
+        "In the soft evening darkness",
+        "In the welcoming evening dark",
+        "In the gathering evening darkness",
+    ),
+    (
+        "Under the night sky",
+        "Under a friendly night sky",
+        "Under the wide, indifferent night sky",
+    ),
+    (
+        "In the deep midnight hours",
+        "In the deep, peaceful midnight hours",
+        "In the long midnight hours",
+    ),
+    // Sky
+    (
+        "The eastern horizon glows with the soft pink and gold of dawn.",
+        "The eastern horizon warms with pink and gold, a dawn worth waking for.",
+        "A thin grey-pink light seeps up along the eastern horizon.",
+    ),
+    (
+        "The morning sky is a brilliant azure blue.",
+        "The morning sky is a clean, open blue that makes the day feel roomy.",
+        "The morning sky is blue, and very far away.",
+    ),
+    (
+        "The sun blazes directly overhead in a cloudless sky.",
+        "The sun stands high and generous in a cloudless sky.",
+        "The sun hangs overhead in a flat, cloudless sky.",
+    ),
+    (
+        "The afternoon sun casts long, golden shadows.",
+        "The afternoon sun lays long, honeyed shadows across the ground.",
+        "The afternoon sun stretches the shadows long and thin.",
+    ),
+    (
+        "The sky is painted in shades of orange, purple, and deep rose as the sun descends.",
+        "The sky glows orange, purple and deep rose as the sun goes down, unhurried and lovely.",
+        "The colour drains slowly out of the sky as the sun goes down.",
+    ),
+    (
+        "The first stars begin to appear in the darkening sky.",
+        "The first stars come out one by one, like lamps being lit.",
+        "A few stars show, pale and distant, in the darkening sky.",
+    ),
+    (
+        "A canopy of stars stretches across the velvet darkness.",
+        "Stars crowd the velvet dark, close enough to feel like company.",
+        "Stars prick the darkness, cold and very far off.",
+    ),
+    (
+        "The world sleeps under a blanket of infinite stars.",
+        "The world sleeps soundly under a blanket of stars.",
+        "The world sleeps, and the stars keep their distance.",
+    ),
+    (
+        "Scattered clouds drift lazily across the sky.",
+        "Fat, unhurried clouds wander across the sky.",
+        "Clouds drift across the sky, going nowhere in particular.",
+    ),
+    (
+        "A thick layer of gray clouds covers the sky.",
+        "A soft grey lid of cloud tucks the sky in.",
+        "A heavy grey layer of cloud presses down on the sky.",
+    ),
+    (
+        "A gentle rain falls from gray skies.",
+        "A gentle rain falls, soft and almost companionable.",
+        "A thin rain falls from gray skies, steady and cheerless.",
+    ),
+];
+
+impl Affect {
+    /// The set the player sees through right now. The calm after meditating
+    /// and a deep connection to the place both hold off the subdued set.
+    pub fn of(player: &Player, time: &WorldTime) -> Self {
+        if player.afterglow_until > time.tick
+            || player.connection >= WARM_CONNECTION
+            || player.mood >= WARM_MOOD
+        {
+            Affect::Warm
+        } else if player.mood <= SUBDUED_MOOD {
+            Affect::Subdued
+        } else {
+            Affect::Plain
+        }
+    }
+
+    /// `text` with its first phrase that has a twin swapped for it, or
+    /// `None` if there's nothing to swap.
+    fn recolor(&self, text: &str) -> Option<String> {
+        ALTERNATES.iter().find_map(|(plain, warm, subdued)| {
+            let twin = match self {
+                Affect::Warm => warm,
+                Affect::Subdued => subdued,
+                Affect::Plain => return None,
+            };
+            text.contains(plain).then(|| text.replacen(plain, twin, 1))
+        })
+    }
+
+    /// Recolour one of the intro and the sky line, never both. The one tried
+    /// first is drawn from a generator seeded by place and hour; if it has no
+    /// twin the other gets its chance.
+    pub fn tint(&self, intro: &mut String, sky: &mut String, pos: &Position, time: &WorldTime) {
+        if *self == Affect::Plain {
+            return;
+        }
+        let seed = ((pos.row as i64 as u64) << 40)
+            ^ ((pos.col as i64 as u64) << 20)
+            ^ (time.day as u64 * 24 + time.hour as u64);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let (first, second) = if rng.gen_bool(0.5) {
+            (intro, sky)
+        } else {
+            (sky, intro)
+        };
+        if let Some(text) = self.recolor(first) {
+            *first = text;
+        } else if let Some(text) = self.recolor(second) {
+            *second = text;
+        }
+    }
+}
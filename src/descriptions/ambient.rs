@@ -0,0 +1,99 @@
+//! Small life that comes and goes with the hour and the weather: not
+//! tracked as wildlife, only noticed while the conditions hold.
+
+use crate::world::{
+    calculate_temperature, Biome, Position, RegionalWeather, TimeOfDay, Weather, WorldMap,
+    WorldTime,
+};
+
+/// Fireflies only rise on a properly warm evening.
+const FIREFLY_MIN_TEMP: f32 = 14.0;
+/// Frogs go quiet once the air turns cold.
+const FROG_MIN_TEMP: f32 = 8.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmbientLife {
+    Fireflies,
+    Frogs,
+}
+
+impl AmbientLife {
+    /// What is out around `pos` right now. Deterministic for a given place,
+    /// hour and weather, so repeated looks agree.
+    pub fn around(
+        pos: &Position,
+        map: &WorldMap,
+        time: &WorldTime,
+        weather: &RegionalWeather,
+    ) -> Vec<AmbientLife> {
+        let Some(biome) = map.biome_at(pos) else {
+            return Vec::new();
+        };
+        if biome == Biome::Lake {
+            return Vec::new();
+        }
+        let temp = calculate_temperature(map, weather, time, pos, false, 0.0);
+        let sky = weather.get_for_position(pos.row, pos.col);
+        let tod = time.time_of_day();
+        let by_water = near_water(pos, map);
+        let mut out = Vec::new();
+
+        let evening = matches!(tod, TimeOfDay::Dusk | TimeOfDay::Evening | TimeOfDay::Night);
+        let still_air = matches!(sky, Weather::Clear | Weather::Cloudy | Weather::HeatWave);
+        let edge = by_water
+            || matches!(
+                biome,
+                Biome::SpringForest | Biome::MixedForest | Biome::Clearing | Biome::Oasis
+            );
+        if evening && still_air && edge && temp >= FIREFLY_MIN_TEMP {
+            out.push(AmbientLife::Fireflies);
+        }
+
+        let croaking = !matches!(tod, TimeOfDay::Noon | TimeOfDay::Afternoon);
+        let frozen = matches!(
+            sky,
+            Weather::LightSnow | Weather::HeavySnow | Weather::Blizzard | Weather::Sandstorm
+        );
+        if by_water && croaking && !frozen && temp >= FROG_MIN_TEMP {
+            out.push(AmbientLife::Frogs);
+        }
+        out
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AmbientLife::Fireflies => "fireflies",
+            AmbientLife::Frogs => "frogs",
+        }
+    }
+
+    /// Words that point `catch` at it.
+    pub fn matches(&self, target: &str) -> bool {
+        let t = target.to_lowercase();
+        match self {
+            AmbientLife::Fireflies => t.contains("firefl") || t.contains("light"),
+            AmbientLife::Frogs => t.contains("frog") || t.contains("toad"),
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            AmbientLife::Fireflies => {
+                "Fireflies drift up out of the grass, blinking slow green-gold lights against the dark."
+            }
+            AmbientLife::Frogs => {
+                "Frogs are calling from the reeds at the water's edge, a chorus that stops whenever you step closer."
+            }
+        }
+    }
+}
+
+fn near_water(pos: &Position, map: &WorldMap) -> bool {
+    (-1..=1).any(|dr| {
+        (-1..=1).any(|dc| {
+            map.biome_at(&Position::new(pos.row + dr, pos.col + dc))
+                .map(|b| matches!(b, Biome::Lake | Biome::Oasis))
+                .unwrap_or(false)
+        })
+    })
+}
@@ -1,10 +1,121 @@
+use super::{Affect, AmbientLife};
+use crate::actions::movement::{step_barrier, Barrier};
 use crate::entity::*;
 use crate::world::*;
 use rand::seq::SliceRandom;
 use rand::Rng;
+use std::collections::HashMap;
 
 pub struct DescriptionGenerator;
 
+/// Name an item the way the player knows it, preferring any custom name.
+fn item_label(item: &Item, custom_names: &HashMap<Item, String>) -> String {
+    custom_names
+        .get(item)
+        .cloned()
+        .unwrap_or_else(|| item.name().to_string())
+}
+
+/// What sits on a piece of furniture, by name.
+fn surface_labels(
+    objects: &ObjectRegistry,
+    id: &str,
+    custom_names: &HashMap<Item, String>,
+) -> Vec<String> {
+    objects
+        .find(id)
+        .and_then(|p| p.object.surface.as_ref())
+        .map(|s| s.items.labels(|i| item_label(i, custom_names)))
+        .unwrap_or_default()
+}
+
+/// Seasoned wood in the shed, logs and firewood together, before it reads
+/// as stacks of the player's own making.
+const NEAT_STACKS_SEASONED: u32 = 20;
+/// Letters from the duck before they read as a pile on the table.
+const LETTER_PILE: u32 = 2;
+/// How close to the cabin the raft counts as moored below the terrace.
+const MOORING_RANGE: f32 = 4.0;
+
+/// One line on the season for each of the cabin's rooms.
+fn room_season_line(room: &Room, season: Season) -> &'static str {
+    match (room, season) {
+        (Room::CabinMain, Season::Spring) => {
+            "Through the windows the clearing is greening with spring."
+        }
+        (Room::CabinMain, Season::Summer) => "The summer air hangs warm and still in the room.",
+        (Room::CabinMain, Season::Autumn) => {
+            "The smell of fallen leaves drifts in around the door frame."
+        }
+        (Room::CabinMain, Season::Winter) => {
+            "Winter presses at the windows, and the corners of the room hold the cold."
+        }
+        (Room::CabinTerrace, Season::Spring) => {
+            "Below, the shore is greening, and new reeds push up along the water's edge."
+        }
+        (Room::CabinTerrace, Season::Summer) => {
+            "The boards are warm underfoot, and the lake lies glassy in the summer air."
+        }
+        (Room::CabinTerrace, Season::Autumn) => {
+            "Leaves have drifted against the railing, and the woods across the water are turning."
+        }
+        (Room::CabinTerrace, Season::Winter) => {
+            "Frost silvers the railing, and the lake's edges have gone grey and still."
+        }
+        (Room::WoodShed, Season::Spring) => {
+            "Spring damp has got into the shed; the boards smell of wet pine."
+        }
+        (Room::WoodShed, Season::Summer) => {
+            "In the summer heat the resin in the walls softens and beads."
+        }
+        (Room::WoodShed, Season::Autumn) => {
+            "Dry leaves have blown in under the door and gathered in the corners."
+        }
+        (Room::WoodShed, Season::Winter) => {
+            "Frost rimes the gaps between the boards, and your breath hangs in the air."
+        }
+    }
+}
+
+/// The stools and benches the player has made and set down in `room`.
+fn crafted_seats(objects: &ObjectRegistry, room: &Room) -> Option<String> {
+    let count = |kind: SeatKind| {
+        objects
+            .placed
+            .iter()
+            .filter_map(|p| p.object.as_seat())
+            .filter(|s| s.kind == kind && s.room.as_ref() == Some(room))
+            .count()
+    };
+    let seats: Vec<String> = [SeatKind::Stool, SeatKind::Bench]
+        .into_iter()
+        .filter_map(|kind| match count(kind) {
+            0 => None,
+            1 => Some(format!("a {}", kind.name())),
+            n => Some(format!("{} {}s", n, kind.name())),
+        })
+        .collect();
+    (!seats.is_empty()).then(|| seats.join(" and "))
+}
+
+/// The raft as seen from the terrace: moored below, or out on the lake.
+fn raft_from_terrace(objects: &ObjectRegistry, map: &WorldMap) -> Option<&'static str> {
+    let raft = objects.find("raft")?;
+    let afloat = map
+        .tile_at(&raft.position)
+        .is_some_and(|t| matches!(t.tile_type, TileType::Lake));
+    if !afloat {
+        return None;
+    }
+    let cabin = objects.find("cabin")?.position;
+    let drifted = matches!(&raft.object.kind, ObjectKind::Raft(r) if r.drifted);
+    if !drifted && raft.position.distance_to(&cabin) <= MOORING_RANGE {
+        Some("Below the terrace your raft rides at its mooring, nudging at the shore with each small wave.")
+    } else {
+        Some("Far out on the water you can pick out your raft, a small dark shape on the lake.")
+    }
+}
+
 /// Ambient sounds based on biome, weather, and time
 fn ambient_sounds(biome: Biome, weather: Weather, time: TimeOfDay) -> Vec<&'static str> {
     let mut sounds = Vec::new();
@@ -108,6 +219,42 @@ fn ambient_sounds(biome: Biome, weather: Weather, time: TimeOfDay) -> Vec<&'stat
     sounds
 }
 
+/// Little things a shoulder-riding duck seems to notice.
+const SHOULDER_DUCK_NOTES: &[&str] = &[
+    "The duck on your shoulder tilts with each step, gazing steadily ahead.",
+    "From your shoulder the duck seems to study the path, unhurried.",
+    "The duck on your shoulder faces the wind as though it has somewhere to be.",
+    "A breeze catches the duck on your shoulder and it rocks, then settles again.",
+    "The duck's painted eyes seem fixed on something you hadn't noticed.",
+];
+
+/// Occasionally surface an observation from the duck riding on the player's shoulder.
+/// A word on what the player has out in their hands, if anything.
+fn in_hand_note(player: &Player, custom_names: &HashMap<Item, String>) -> Option<String> {
+    let held: Vec<String> = player
+        .hands
+        .items()
+        .filter(|item| player.inventory.has(item, 1))
+        .map(|item| format!("the {}", item_label(&item, custom_names)))
+        .collect();
+    if held.is_empty() {
+        return None;
+    }
+    Some(format!("You have {} in hand.", held.join(" and ")))
+}
+
+fn shoulder_duck_note(player: &Player) -> Option<String> {
+    if player.mounts.shoulder != Some(Item::RubberDuck) {
+        return None;
+    }
+    let mut rng = rand::thread_rng();
+    if rng.gen_bool(0.35) {
+        SHOULDER_DUCK_NOTES.choose(&mut rng).map(|s| s.to_string())
+    } else {
+        None
+    }
+}
+
 /// Get a random ambient sound for the current conditions
 fn get_ambient_sound(biome: Biome, weather: Weather, time: TimeOfDay) -> Option<String> {
     let sounds = ambient_sounds(biome, weather, time);
@@ -123,28 +270,116 @@ fn get_ambient_sound(biome: Biome, weather: Weather, time: TimeOfDay) -> Option<
     }
 }
 
+/// A distant sound the wind brings from upwind: waves on the lake, or at
+/// night a wolf out in the snowy forest. Stronger winds carry further.
+fn carried_sound(origin: &Position, map: &WorldMap, wind: &Wind, time: TimeOfDay) -> Option<String> {
+    let here = map.biome_at(origin);
+    let night = matches!(time, TimeOfDay::Night | TimeOfDay::Midnight);
+    let mut pos = *origin;
+    for _ in 0..wind.carry_range() {
+        pos = pos.move_in_direction(wind.from);
+        let biome = map.biome_at(&pos)?;
+        if Some(biome) == here {
+            continue;
+        }
+        match biome {
+            Biome::Lake => {
+                return Some(format!(
+                    "On the {} wind comes the faint slap of waves from the lake.",
+                    wind.from.name()
+                ))
+            }
+            Biome::WinterForest if night => {
+                return Some(format!(
+                    "Carried on the {} wind, far off, a wolf howls.",
+                    wind.from.name()
+                ))
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The parts of the world a description of the player's surroundings reads.
+pub struct LocationContext<'a> {
+    pub player: &'a Player,
+    pub map: &'a WorldMap,
+    pub time: &'a WorldTime,
+    pub weather: &'a RegionalWeather,
+    pub wildlife: &'a [Wildlife],
+    pub objects: &'a ObjectRegistry,
+    pub custom_names: &'a HashMap<Item, String>,
+    pub region_names: &'a RegionNames,
+    /// Letters the duck has written so far, which pile up on the table.
+    pub letters_written: u32,
+}
+
 impl DescriptionGenerator {
     /// Generate a full description of the player's current location
     pub fn describe_location(
-        player: &Player,
-        map: &WorldMap,
-        time: &WorldTime,
-        weather: &RegionalWeather,
-        wildlife: &[Wildlife],
-        objects: &ObjectRegistry,
+        ctx: &LocationContext,
+        detail: Option<HiddenDetail>,
+        forage: Option<String>,
+        sightings: &mut Vec<(Species, f32)>,
     ) -> String {
-        let cabin_ref = objects.find("cabin").and_then(|p| p.object.as_cabin());
-        let wood_shed_ref = objects
-            .find("wood_shed")
-            .and_then(|p| p.object.as_wood_shed());
+        let LocationContext {
+            player,
+            map,
+            time,
+            weather,
+            wildlife,
+            objects,
+            custom_names,
+            ..
+        } = *ctx;
+
+        let seated = player
+            .seated
+            .as_deref()
+            .and_then(|id| objects.find(id))
+            .map(|po| {
+                let prep = match po.object.as_seat().map(|s| s.kind) {
+                    Some(SeatKind::Armchair) => "in",
+                    _ => "on",
+                };
+                format!("You are sitting {} the {}.", prep, po.object.display_name())
+            });
 
         // If in a room, describe that instead
         if let Some(room) = &player.room {
-            return Self::describe_room(room, cabin_ref, wood_shed_ref, time, weather, objects);
+            let room_desc = Self::describe_room(room, ctx);
+            let spot = match (room, player.cabin_spot, &seated) {
+                (Room::CabinMain, Some(spot), None) => Some(spot.standing().to_string()),
+                _ => None,
+            };
+            let lead: Vec<String> = seated
+                .into_iter()
+                .chain(spot)
+                .chain(shoulder_duck_note(player))
+                .collect();
+            let room_desc = if *room == Room::CabinMain && player.connection >= 75.0 {
+                format!(
+                    "{}\n\nThe cabin feels like home now: your things where you left them, the door you know the weight of.",
+                    room_desc
+                )
+            } else {
+                room_desc
+            };
+            let note = Self::cabin_spot_note(player, map, time, weather, wildlife, objects);
+            let room_desc = match note {
+                Some(note) => format!("{}\n\n{}", room_desc, note),
+                None => room_desc,
+            };
+            return if lead.is_empty() {
+                room_desc
+            } else {
+                format!("{}\n\n{}", lead.join(" "), room_desc)
+            };
         }
 
         let player_pos = player.position;
-        let (row, col) = match player_pos.as_usize() {
+        let (row, col) = match map.index_of(&player_pos) {
             Some(p) => p,
             None => return "You seem to be nowhere.".to_string(),
         };
@@ -154,19 +389,34 @@ impl DescriptionGenerator {
             None => return "You're in an indescribable void.".to_string(),
         };
 
+        // Inside a placed structure rather than out on the tile
+        if let Some(po) = player.inside.as_deref().and_then(|id| objects.find(id)) {
+            let inside_desc = Self::describe_inside(po, tile.biome, map, time, weather, objects);
+            return match seated {
+                Some(line) => format!("{}\n\n{}", line, inside_desc),
+                None => inside_desc,
+            };
+        }
+
         let mut description = String::new();
 
-        // Time and weather intro
-        description.push_str(&Self::time_weather_intro(
-            time,
-            weather,
-            &player_pos,
-            tile.biome,
-        ));
+        // Time and weather intro, and the sky, one of them coloured by mood
+        let mut intro = Self::time_weather_intro(time, weather, &player_pos, tile.biome);
+        let mut sky = describe_sky(time, weather, player_pos.row, player_pos.col, tile.biome);
+        Affect::of(player, time).tint(&mut intro, &mut sky, &player_pos, time);
+        description.push_str(&intro);
 
         // Main location description
         description.push_str("\n\n");
         description.push_str(&Self::tile_description(tile, row, col, player.facing, map));
+        if tile.elevation >= BARE_ELEVATION {
+            description.push_str(" The ground here rises into a bare, rocky knoll, the highest point for miles; the treetops fall away below you on every side.");
+        } else if tile.elevation >= 1.0 {
+            description.push_str(" The ground climbs steadily here toward a rocky knoll.");
+        }
+        if tile.outcrop {
+            description.push_str(" Grey boulders shoulder up out of the ground here, ringed with a spill of loose stone.");
+        }
 
         // Trees or objects on this tile
         if let Some(tree) = objects.find_tree_at(&player.position) {
@@ -176,7 +426,11 @@ impl DescriptionGenerator {
             }
         }
 
-        let objects_here = objects.objects_at(&player.position);
+        let objects_here: Vec<_> = objects
+            .objects_at(&player.position)
+            .into_iter()
+            .filter(|o| !o.object.is_indoor_furniture())
+            .collect();
         if !objects_here.is_empty() {
             let names: Vec<_> = objects_here
                 .iter()
@@ -185,6 +439,18 @@ impl DescriptionGenerator {
             description.push_str("\n\n");
             description.push_str(&format!("Here you notice: {}.", names.join(", ")));
         }
+        if let Some(detail) = detail {
+            description.push_str("\n\n");
+            description.push_str(detail.notice());
+        }
+        if let Some(line) = forage {
+            description.push_str("\n\n");
+            description.push_str(&line);
+        }
+        if let Some(line) = &seated {
+            description.push_str("\n\n");
+            description.push_str(line);
+        }
 
         // Items on the ground at this tile
         if let Some(tile) = map.get_tile(row, col) {
@@ -194,10 +460,11 @@ impl DescriptionGenerator {
                 .iter()
                 .filter(|(_, qty)| *qty > 0)
                 .map(|(item, qty)| {
+                    let label = item_label(item, custom_names);
                     if *qty > 1 {
-                        format!("{} x{}", item.name(), qty)
+                        format!("{} x{}", label, qty)
                     } else {
-                        item.name().to_string()
+                        label
                     }
                 })
                 .collect();
@@ -210,7 +477,7 @@ impl DescriptionGenerator {
 
         let mut visible_objects = Vec::new();
         for po in objects.visible_from(&player.position) {
-            if po.position == player.position {
+            if po.position == player.position || po.object.is_indoor_furniture() {
                 continue;
             }
             let dir = direction_to(&player.position, &po.position);
@@ -226,16 +493,10 @@ impl DescriptionGenerator {
 
         // Sky description
         description.push_str("\n\n");
-        description.push_str(&describe_sky(
-            time,
-            weather,
-            player_pos.row,
-            player_pos.col,
-            tile.biome,
-        ));
+        description.push_str(&sky);
 
         // Visible wildlife (scaled by observation and weather)
-        let observation = player.effective_skill("observation") as f32;
+        let observation = player.effective_skill(Skill::Observation) as f32;
         let mut detection_radius = 2.5 + observation / 25.0;
         let current_weather = weather.get_for_position(player_pos.row, player_pos.col);
         if matches!(
@@ -266,6 +527,7 @@ impl DescriptionGenerator {
                 } else {
                     "a short distance away"
                 };
+                sightings.push((w.species, distance));
                 let mut line = w.describe();
                 if observation >= 15.0 {
                     line.push(' ');
@@ -282,10 +544,39 @@ impl DescriptionGenerator {
             description.push_str("\n\n");
             description.push_str(&sound);
         }
+        if let Some(sound) = carried_sound(&player_pos, map, &weather.wind, time.time_of_day()) {
+            description.push_str("\n\n");
+            description.push_str(&sound);
+        }
+        for life in AmbientLife::around(&player_pos, map, time, weather) {
+            description.push_str("\n\n");
+            description.push_str(life.description());
+        }
+        if player.inventory.has(&Item::FireflyJar, 1) && time.time_of_day().is_night() {
+            description.push_str("\n\n");
+            description.push_str(if player.hands.holds(&Item::FireflyJar) {
+                "The fireflies in your jar pulse a soft green, just enough light to see your hands by."
+            } else {
+                "A faint green glow leaks from the firefly jar in your pack. Held up, it would light your way."
+            });
+        }
+        if let Some(note) = in_hand_note(player, custom_names) {
+            description.push_str("\n\n");
+            description.push_str(&note);
+        }
+
+        if let Some(note) = shoulder_duck_note(player) {
+            description.push_str("\n\n");
+            description.push_str(&note);
+        }
 
         // Exits
         description.push_str("\n\n");
-        let cabin_open = cabin_ref.map(|c| c.door_open).unwrap_or(false);
+        let cabin_open = objects
+            .find("cabin")
+            .and_then(|p| p.object.as_cabin())
+            .map(|c| c.door_open)
+            .unwrap_or(false);
         description.push_str(&Self::describe_exits(&player_pos, map, objects, cabin_open));
 
         description
@@ -339,9 +630,9 @@ impl DescriptionGenerator {
         row: usize,
         _col: usize,
         facing: Direction,
-        _map: &WorldMap,
+        map: &WorldMap,
     ) -> String {
-        let world_row = row as i32 - MAP_ORIGIN_ROW;
+        let world_row = row as i32 - map.extent();
         match &tile.tile_type {
             TileType::Path => {
                 let ahead = match facing {
@@ -413,6 +704,81 @@ impl DescriptionGenerator {
         }
     }
 
+    /// What the player's spot in the cabin lets them take in: the yard
+    /// through the window, or the sounds outside from the doorway.
+    fn cabin_spot_note(
+        player: &Player,
+        map: &WorldMap,
+        time: &WorldTime,
+        weather: &RegionalWeather,
+        wildlife: &[Wildlife],
+        objects: &ObjectRegistry,
+    ) -> Option<String> {
+        if player.room != Some(Room::CabinMain) {
+            return None;
+        }
+        let pos = player.position;
+        let sky = weather.get_for_position(pos.row, pos.col);
+        match player.cabin_spot? {
+            CabinSpot::Window => {
+                // The window looks out over the front yard
+                let yard = pos.move_in_direction(Direction::South);
+                let mut seen: Vec<String> = Vec::new();
+                if let Some(tile) = map.tile_at(&yard) {
+                    seen.extend(
+                        tile.items
+                            .items
+                            .iter()
+                            .filter(|(item, qty)| *qty > 0 && *item != Item::Stone)
+                            .map(|(item, _)| item.name().to_string()),
+                    );
+                }
+                seen.extend(
+                    objects
+                        .objects_at(&yard)
+                        .iter()
+                        .filter(|po| !po.object.is_hidden())
+                        .map(|po| po.object.display_name()),
+                );
+                seen.extend(
+                    wildlife
+                        .iter()
+                        .filter(|w| w.alive && w.position == yard)
+                        .map(|w| w.species.name().to_string()),
+                );
+                let outside = match sky {
+                    Weather::Clear | Weather::HeatWave if time.time_of_day().is_night() => {
+                        "the night is clear"
+                    }
+                    _ if time.time_of_day().is_night() => "it's too dark to make out the sky",
+                    Weather::Clear => "the sky is clear",
+                    Weather::HeatWave => "the air shimmers with heat",
+                    Weather::Cloudy => "clouds drift over the treetops",
+                    Weather::Overcast => "the sky is a flat grey",
+                    Weather::LightRain => "a light rain is falling",
+                    Weather::HeavyRain => "rain is pouring down",
+                    Weather::Fog => "fog hangs close around the cabin",
+                    Weather::Sandstorm => "blown sand hisses against the glass",
+                    Weather::LightSnow => "a few flakes of snow are drifting down",
+                    Weather::HeavySnow => "snow is falling thick and fast",
+                    Weather::Blizzard => "a blizzard is howling past the glass",
+                };
+                let sky_line = format!("Through the window, {}.", outside);
+                Some(if seen.is_empty() {
+                    format!("{} The front yard is empty.", sky_line)
+                } else {
+                    format!("{} Out in the front yard: {}.", sky_line, seen.join(", "))
+                })
+            }
+            CabinSpot::Door => {
+                let biome = map.biome_at(&pos).unwrap_or(Biome::Clearing);
+                get_ambient_sound(biome, sky, time.time_of_day())
+                    .map(|sound| format!("From the doorway you can hear outside. {}", sound))
+            }
+            _ => None,
+        }
+    }
+
     fn describe_exits(
         player_pos: &Position,
         map: &WorldMap,
@@ -430,47 +796,59 @@ impl DescriptionGenerator {
         ];
 
         for dir in directions {
-            let next_pos = player_pos.move_in_direction(dir);
-            if !next_pos.is_valid() {
+            // The same barriers a step runs into, so the list never promises a way that isn't
+            let barrier = step_barrier(player_pos, dir, map, objects, cabin_open);
+            if barrier == Some(Barrier::EdgeOfWorld) {
                 continue;
             }
-            let Some((new_row, new_col)) = next_pos.as_usize() else {
+            let next_pos = player_pos.move_in_direction(dir);
+            let Some(tile) = map.tile_at(&next_pos) else {
                 continue;
             };
 
-            if let Some(tile) = map.get_tile(new_row, new_col) {
-                let objects_here = objects.objects_at(&next_pos);
-                let mut exit_desc = match &tile.tile_type {
-                    TileType::Lake => format!("{}: the lake waters", dir_str(dir)),
-                    TileType::Path => format!("{}: the forest path", dir_str(dir)),
-                    TileType::Clearing => format!("{}: a small clearing", dir_str(dir)),
-                    TileType::Forest(biome) => format!("{}: {}", dir_str(dir), biome.name()),
-                };
-
-                if objects_here
-                    .iter()
-                    .any(|o| matches!(o.object.kind, ObjectKind::Cabin(_)))
-                {
-                    exit_desc = if cabin_open {
-                        format!("{}: the cabin (door open)", dir_str(dir))
-                    } else {
-                        format!("{}: the cabin (door closed)", dir_str(dir))
-                    };
-                } else if objects_here
-                    .iter()
-                    .any(|o| matches!(o.object.kind, ObjectKind::WoodShed(_)))
-                {
-                    exit_desc = format!("{}: wood shed", dir_str(dir));
-                } else if objects_here
-                    .iter()
-                    .any(|o| o.id == "east_cave_entrance"
-                        || matches!(&o.object.kind, ObjectKind::GenericStructure(name) if name.to_lowercase().contains("cave")))
-                {
-                    exit_desc = format!("{}: a dark cave entrance", dir_str(dir));
-                }
+            let objects_here = objects.objects_at(&next_pos);
+            let mut place = match &tile.tile_type {
+                TileType::Lake => "the lake waters".to_string(),
+                TileType::Path => "the forest path".to_string(),
+                TileType::Clearing => "a small clearing".to_string(),
+                TileType::Forest(biome) => biome.name().to_string(),
+            };
 
-                exits.push(exit_desc);
+            if objects_here
+                .iter()
+                .any(|o| matches!(o.object.kind, ObjectKind::Cabin(_)))
+            {
+                place = match barrier {
+                    Some(Barrier::CabinWall) => "the cabin wall".to_string(),
+                    Some(_) => "the cabin".to_string(),
+                    None => "the cabin (door open)".to_string(),
+                };
+            } else if let Some(shed) = objects_here.iter().find_map(|o| match &o.object.kind {
+                ObjectKind::WoodShed(s) => Some(s),
+                _ => None,
+            }) {
+                place = if shed.door_open {
+                    "wood shed (door open)".to_string()
+                } else {
+                    "wood shed (door closed)".to_string()
+                };
+            } else if objects_here
+                .iter()
+                .any(|o| matches!(o.object.kind, ObjectKind::Raft(_)))
+            {
+                place = "your raft, moored in the water".to_string();
+            } else if objects_here
+                .iter()
+                .any(|o| o.id == "east_cave_entrance"
+                    || matches!(&o.object.kind, ObjectKind::GenericStructure(name) if name.to_lowercase().contains("cave")))
+            {
+                place = "a dark cave entrance".to_string();
             }
+
+            exits.push(match barrier {
+                Some(barrier) => format!("{}: {} ({})", dir_str(dir), place, barrier.note()),
+                None => format!("{}: {}", dir_str(dir), place),
+            });
         }
 
         if exits.is_empty() {
@@ -480,18 +858,125 @@ impl DescriptionGenerator {
         }
     }
 
-    fn describe_room(
-        room: &Room,
-        cabin: Option<&Cabin>,
-        wood_shed: Option<&WoodShed>,
+    fn describe_inside(
+        po: &PlacedObject,
+        biome: Biome,
+        map: &WorldMap,
         time: &WorldTime,
         weather: &RegionalWeather,
         objects: &ObjectRegistry,
     ) -> String {
+        let outside = match weather.get_for_position(po.position.row, po.position.col) {
+            Weather::LightRain | Weather::HeavyRain => " Rain patters just beyond the opening.",
+            Weather::LightSnow | Weather::HeavySnow => " Snow drifts past the opening.",
+            Weather::Blizzard => " The blizzard howls past outside, unable to reach you.",
+            Weather::Sandstorm => " Sand hisses past the opening.",
+            _ if !time.time_of_day().is_daytime() => " Outside, the night is dark and still.",
+            _ => "",
+        };
+
+        if matches!(po.object.kind, ObjectKind::Raft(_)) {
+            let mut exits = Vec::new();
+            for dir in [
+                Direction::North,
+                Direction::South,
+                Direction::East,
+                Direction::West,
+            ] {
+                let Some(tile) = map.tile_at(&po.position.move_in_direction(dir))
+                else {
+                    continue;
+                };
+                if matches!(tile.tile_type, TileType::Lake) {
+                    exits.push(format!("{}: open water", dir_str(dir)));
+                } else {
+                    exits.push(format!("{}: the shore (disembark)", dir_str(dir)));
+                }
+            }
+            let afloat = map
+                .tile_at(&po.position)
+                .map(|t| matches!(t.tile_type, TileType::Lake))
+                .unwrap_or(false);
+            let lead = if afloat {
+                "You're aboard the raft, afloat on the lake. Water slaps softly against the lashed logs, and the shore looks lower and farther than it should."
+            } else {
+                "You're sitting on the raft, still drawn up on the bank."
+            };
+            let exposed = match weather.get_for_position(po.position.row, po.position.col) {
+                Weather::Blizzard => " The blizzard tears at you; there's no shelter out here.",
+                Weather::LightRain | Weather::HeavyRain => " Rain hisses on the water all around you.",
+                Weather::LightSnow | Weather::HeavySnow => {
+                    " Snow falls into the dark water and vanishes."
+                }
+                _ => "",
+            };
+            return format!("{}{}\n\n**Exits:** {}", lead, exposed, exits.join(" | "));
+        }
+
+        if let Some(ruin) = po.object.as_ruin() {
+            let deeper = if ruin.fully_cleared() {
+                "Deeper: the hall of inscriptions and the inner chamber"
+            } else if ruin.chambers_cleared > 0 {
+                "Deeper: the hall of inscriptions (the way on is still sand)"
+            } else {
+                "Deeper: blocked by sand (a shovel would clear it)"
+            };
+            return format!(
+                "{}{}\n\n**Exits:** Out: back into the {} | {}",
+                ruin.interior(),
+                outside,
+                biome.name(),
+                deeper
+            );
+        }
+
+        if po.id == "east_cave_entrance" {
+            let bear = objects
+                .placed
+                .iter()
+                .find_map(|p| p.object.as_cave_bear())
+                .map(|b| format!(" {}", b.sense()))
+                .unwrap_or_default();
+            return format!(
+                "You stand just inside the mouth of the cave. Cold air breathes out of the dark, smelling of wet stone and old leaves, and a few steps in the daylight gives up entirely.{}{}\n\n\
+                **Exits:** Out: back into the {} | East: deeper into the dark (too dark to go on)",
+                bear,
+                outside,
+                biome.name()
+            );
+        }
+
+        format!(
+            "You're inside the {}, sheltered from the open air.{}\n\n**Exits:** Out: back into the {}",
+            po.object.display_name(),
+            outside,
+            biome.name()
+        )
+    }
+
+    fn describe_room(room: &Room, ctx: &LocationContext) -> String {
+        let LocationContext {
+            map,
+            time,
+            weather,
+            objects,
+            custom_names,
+            region_names,
+            letters_written,
+            ..
+        } = *ctx;
+        let cabin = objects.find("cabin").and_then(|p| p.object.as_cabin());
+        let wood_shed = objects
+            .find("wood_shed")
+            .and_then(|p| p.object.as_wood_shed());
         match room {
-            Room::CabinMain => Self::describe_cabin_main(cabin, objects, time),
-            Room::CabinTerrace => Self::describe_cabin_terrace(time, weather),
-            Room::WoodShed => Self::describe_wood_shed(wood_shed),
+            Room::CabinMain => {
+                Self::describe_cabin_main(cabin, objects, time, custom_names, letters_written)
+            }
+            Room::CabinTerrace => {
+                Self::describe_cabin_terrace(time, weather, objects, map, region_names)
+            }
+            Room::WoodShed => Self::describe_wood_shed(wood_shed, objects, time, custom_names),
         }
     }
 
@@ -499,6 +984,8 @@ impl DescriptionGenerator {
         cabin: Option<&Cabin>,
         objects: &ObjectRegistry,
         time: &WorldTime,
+        custom_names: &HashMap<Item, String>,
+        letters_written: u32,
     ) -> String {
         let Some(cabin) = cabin else {
             return "You are in a sparse wooden room, though something feels missing here."
@@ -507,9 +994,15 @@ impl DescriptionGenerator {
         let tod = time.time_of_day();
 
         let light = match (tod, &cabin.fireplace.state) {
+            (_, FireState::Roaring | FireState::Burning) if cabin.shutters_closed => {
+                "Firelight fills the shuttered room, warm and close."
+            }
             (_, FireState::Roaring | FireState::Burning) => {
                 "Warm firelight dances across the walls, casting flickering shadows."
             }
+            _ if cabin.shutters_closed => {
+                "The shutters are closed; only thin seams of light edge around them."
+            }
             (TimeOfDay::Morning | TimeOfDay::Noon | TimeOfDay::Afternoon, _) => {
                 "Soft daylight filters through the windows."
             }
@@ -517,7 +1010,7 @@ impl DescriptionGenerator {
             _ => "The room is dark, save for faint moonlight through the windows.",
         };
 
-        let fireplace_desc = cabin.fireplace.state.description();
+        let fireplace_desc = cabin.fireplace.description();
 
         // Ambient sounds for cabin
         let ambient = match &cabin.fireplace.state {
@@ -533,26 +1026,50 @@ impl DescriptionGenerator {
             }
         };
 
-        let items_on_ground: Vec<&str> = cabin.items.iter().map(|i| i.name()).collect();
+        let orderly = cabin.is_orderly();
+        let upkeep = if orderly {
+            "The room is swept and orderly; everything has its place."
+        } else if cabin.tidiness >= 35.0 {
+            "A lived-in clutter has crept over the room."
+        } else {
+            "Dust furs the sills and grit crunches underfoot; the room has been let go."
+        };
 
-        let items_desc = if items_on_ground.is_empty() {
-            String::new()
+        // A tidy room keeps firewood on the rack.
+        let racked = if orderly {
+            cabin.items.count(&Item::Firewood)
         } else {
-            format!(
-                "\n\nScattered about you notice: {}.",
-                items_on_ground.join(", ")
-            )
+            0
         };
+        let mut loose = cabin.items.clone();
+        if racked > 0 {
+            loose.take_all(&Item::Firewood);
+        }
+        let items_on_ground = loose.labels(|i| item_label(i, custom_names));
+
+        let mut items_desc = String::new();
+        if racked > 0 {
+            items_desc.push_str(&format!(
+                "\n\nFirewood is stacked in the rack beside the hearth ({} {}).",
+                racked,
+                if racked == 1 { "piece" } else { "pieces" }
+            ));
+        }
+        if !items_on_ground.is_empty() {
+            let lead = if orderly {
+                "Stacked neatly along the wall"
+            } else if cabin.tidiness >= 35.0 {
+                "Scattered about you notice"
+            } else {
+                "Strewn across the floor"
+            };
+            items_desc.push_str(&format!("\n\n{}: {}.", lead, items_on_ground.join(", ")));
+        }
 
         let table_items = objects
             .find("cabin_table")
             .and_then(|p| p.object.surface.as_ref())
-            .map(|s| {
-                s.items
-                    .iter()
-                    .map(|i| i.name().to_string())
-                    .collect::<Vec<_>>()
-            })
+            .map(|s| s.items.labels(|i| item_label(i, custom_names)))
             .unwrap_or_else(|| cabin.table_item_names());
         let table_desc = if table_items.is_empty() {
             "A sturdy wooden table sits at the center, its surface worn smooth by time.".to_string()
@@ -563,28 +1080,123 @@ impl DescriptionGenerator {
             )
         };
 
+        let mantel_items = objects
+            .find("cabin_mantel")
+            .and_then(|p| p.object.surface.as_ref())
+            .map(|s| s.items.labels(|i| item_label(i, custom_names)));
+        let mantel_desc = match mantel_items {
+            Some(items) if items.is_empty() => {
+                "A wooden mantelpiece above it stands bare.".to_string()
+            }
+            Some(items) => format!(
+                "A wooden mantelpiece above it holds: {}.",
+                items.join(", ")
+            ),
+            None => "A wooden mantelpiece above it holds various curious items.".to_string(),
+        };
+
+        for (id, lead) in [
+            ("cabin_hearth_basket", "In the rush basket by the hearth"),
+            ("cabin_window_sill", "On the window sill"),
+        ] {
+            let held = surface_labels(objects, id, custom_names);
+            if !held.is_empty() {
+                items_desc.push_str(&format!("\n\n{}: {}.", lead, held.join(", ")));
+            }
+        }
+
+        let openings = match (cabin.windows_open, cabin.shutters_closed) {
+            (true, true) => "The windows stand open behind closed shutters.",
+            (true, false) => "The windows are propped open, letting outside air drift through.",
+            (false, true) => "The shutters are fastened over the windows.",
+            (false, false) => "",
+        };
+        let openings = if openings.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", openings)
+        };
+
+        let drafts = match cabin.snow_drifts() {
+            0 => "",
+            1 => "\n\nA small drift of snow has blown in across the floorboards.",
+            _ => "\n\nSnow has drifted in across the floorboards in cold, wet heaps.",
+        };
+
+        let door = if cabin.door_open { "door open" } else { "door closed" };
+
+        // What the player has added to the place over time
+        let mut made_here = String::new();
+        if let Some(seats) = crafted_seats(objects, &Room::CabinMain) {
+            made_here.push_str(&format!(
+                " Among the old pieces stand things of your own making: {}.",
+                seats
+            ));
+        }
+        let books_on_table = objects
+            .find("cabin_table")
+            .and_then(|p| p.object.surface.as_ref())
+            .map(|s| s.items.count(&Item::Book))
+            .unwrap_or(0);
+        let letters = letters_written.min(books_on_table);
+        let table_desc = if letters >= LETTER_PILE {
+            format!(
+                "{} The duck's letters have begun to pile up at one end of it, {} of them now.",
+                table_desc, letters
+            )
+        } else {
+            table_desc
+        };
+
         format!(
-            "You are in the main room of the cabin. {}\n\n\
+            "You are in the main room of the cabin. {}{} {}\n\n\
             A stone fireplace dominates one wall. {} \
-            A wooden mantelpiece above it holds various curious items. \
-            Worn but comfortable furniture fills the space - wooden chairs and a faded rug that has seen better days. \
-            {}{}{}\n\n\
-            **Exits:** North to terrace | West to wood shed | South to outside",
-            light, fireplace_desc, table_desc, ambient, items_desc
+            {} \
+            Worn but comfortable furniture fills the space - a sagging armchair drawn up to the hearth, wooden chairs, and a faded rug that has seen better days.{} \
+            {} {}{}{}{}\n\n\
+            **Exits:** North to terrace | West to wood shed | South to outside ({})",
+            light,
+            openings,
+            room_season_line(&Room::CabinMain, time.season()),
+            fireplace_desc,
+            mantel_desc,
+            made_here,
+            table_desc,
+            upkeep,
+            ambient,
+            drafts,
+            items_desc,
+            door
         )
     }
 
-    fn describe_cabin_terrace(time: &WorldTime, weather: &RegionalWeather) -> String {
+    fn describe_cabin_terrace(
+        time: &WorldTime,
+        weather: &RegionalWeather,
+        objects: &ObjectRegistry,
+        map: &WorldMap,
+        region_names: &RegionNames,
+    ) -> String {
         let tod = time.time_of_day();
 
         let mut description = String::from(
             "You step onto the wooden terrace behind the cabin. The view opens up before you - \
-            the lake stretches out, framed by the varied landscapes of this strange place.\n\n",
+            the lake stretches out, framed by the varied landscapes of this strange place. \
+            A weathered bench sits against the railing, facing the water. ",
         );
+        description.push_str(room_season_line(&Room::CabinTerrace, time.season()));
+        if let Some(raft) = raft_from_terrace(objects, map) {
+            description.push(' ');
+            description.push_str(raft);
+        }
+        description.push_str("\n\n");
 
         // Describe each direction
-        description.push_str("**To the West:** ");
-        let west_weather = weather.west;
+        description.push_str(&format!(
+            "**To the West, {}:** ",
+            region_names.name(Region::GlassDesert)
+        ));
+        let west_weather = weather.for_region(Region::GlassDesert);
         description.push_str(match west_weather {
             Weather::HeatWave | Weather::Clear if matches!(tod, TimeOfDay::Morning | TimeOfDay::Noon | TimeOfDay::Afternoon) =>
                 "The desert shimmers in the heat. Palm trees dot the oasis edge, and you can just make out ripples in the cooler waters where desert meets lake.",
@@ -593,8 +1205,11 @@ impl DescriptionGenerator {
             _ => "The desert landscape glows golden, its oasis a verdant jewel against the sand.",
         });
 
-        description.push_str("\n\n**To the North:** ");
-        let north_weather = weather.north;
+        description.push_str(&format!(
+            "\n\n**To the North, {}:** ",
+            region_names.name(Region::NorthernWoods)
+        ));
+        let north_weather = weather.for_region(Region::NorthernWoods);
         description.push_str(match (tod, north_weather) {
             (TimeOfDay::Dawn, _) =>
                 "The distant snow-capped peaks catch the first light of dawn, glowing pink and gold. The lake's surface mirrors the spectacular colors. It's breathtaking.",
@@ -605,8 +1220,11 @@ impl DescriptionGenerator {
             _ => "The temperate forest stretches north to where distant mountains scrape the sky.",
         });
 
-        description.push_str("\n\n**To the East:** ");
-        let east_weather = weather.east;
+        description.push_str(&format!(
+            "\n\n**To the East, {}:** ",
+            region_names.name(Region::EasternPines)
+        ));
+        let east_weather = weather.for_region(Region::EasternPines);
         description.push_str(match (tod, east_weather) {
             (_, Weather::Blizzard) =>
                 "A fierce blizzard obscures the eastern shore. You can barely make out the shapes of snow-laden trees.",
@@ -617,12 +1235,19 @@ impl DescriptionGenerator {
             _ => "Snow blankets the eastern shore, evergreens standing like frozen sentinels.",
         });
 
-        description.push_str("\n\n**Exits:** South back to cabin | West to wood shed");
+        description.push_str(
+            "\n\n**Exits:** South back to cabin | West to wood shed | Out: down the terrace steps to the north",
+        );
 
         description
     }
 
-    fn describe_wood_shed(wood_shed: Option<&WoodShed>) -> String {
+    fn describe_wood_shed(
+        wood_shed: Option<&WoodShed>,
+        objects: &ObjectRegistry,
+        time: &WorldTime,
+        custom_names: &HashMap<Item, String>,
+    ) -> String {
         let Some(wood_shed) = wood_shed else {
             return "An empty shed stands here, but its contents are unclear.".to_string();
         };
@@ -633,10 +1258,20 @@ impl DescriptionGenerator {
             "The axe's usual spot on the floor is empty."
         };
 
+        let seasoning = |item: Item| {
+            let green = wood_shed.green.count(&item);
+            if green == 0 {
+                String::new()
+            } else {
+                format!(" ({} seasoned, {} still green)", wood_shed.seasoned(&item), green)
+            }
+        };
+
         let log_desc = if wood_shed.logs > 0 {
             format!(
-                "A pile of unsplit logs leans against the wall - {} remain.",
-                wood_shed.logs
+                "A pile of unsplit logs leans against the wall - {} remain{}.",
+                wood_shed.logs,
+                seasoning(Item::Log)
             )
         } else {
             "The log pile is empty.".to_string()
@@ -644,52 +1279,98 @@ impl DescriptionGenerator {
 
         let firewood_desc = if wood_shed.firewood > 0 {
             format!(
-                "Split firewood is stacked neatly nearby - {} pieces.",
-                wood_shed.firewood
+                "Split firewood is stacked neatly nearby - {} pieces{}.",
+                wood_shed.firewood,
+                seasoning(Item::Firewood)
             )
         } else {
             "There's no split firewood.".to_string()
         };
 
+        // Enough dry wood put by shows whose work it is
+        let seasoned = wood_shed.seasoned(&Item::Log) + wood_shed.seasoned(&Item::Firewood);
+        let stacks = if seasoned >= NEAT_STACKS_SEASONED {
+            " Your own work shows here: the seasoned wood stands in neat, tight stacks along the back wall, ends squared off, ready for the cold."
+        } else {
+            ""
+        };
+
         let block_desc = if wood_shed.chopping_block.has_log {
             "A log sits ready on the chopping block."
         } else {
             "The chopping block stands empty, its surface scarred from use."
         };
 
+        let bench = surface_labels(objects, "shed_workbench", custom_names);
+        let bench_desc = if bench.is_empty() {
+            "A rough workbench stands under the gap-boarded window, bare.".to_string()
+        } else {
+            format!(
+                "A rough workbench stands under the gap-boarded window, holding: {}.",
+                bench.join(", ")
+            )
+        };
+
+        let (door_desc, door_exit) = if wood_shed.door_open {
+            ("The shed door hangs open to the yard.", "door open")
+        } else {
+            ("The shed door is latched shut.", "door closed")
+        };
+
         format!(
             "You're in the small wood shed attached to the cabin. The air smells of sawdust and pine resin. \
-            Dust motes drift in the light filtering through gaps in the wooden walls.\n\n\
-            {} {} {} {}\n\n\
-            **Exits:** East to cabin | North to terrace | South to outside",
-            axe_desc, log_desc, firewood_desc, block_desc
+            Dust motes drift in the light filtering through gaps in the wooden walls. {}\n\n\
+            {} {} {}{} {} {} {}\n\n\
+            **Exits:** East to cabin | North to terrace | South to outside ({})",
+            room_season_line(&Room::WoodShed, time.season()),
+            axe_desc,
+            log_desc,
+            firewood_desc,
+            stacks,
+            block_desc,
+            bench_desc,
+            door_desc,
+            door_exit
         )
     }
 
     /// Describe what the player sees in a specific direction
     pub fn look_direction(
         dir: Direction,
-        player: &Player,
-        map: &WorldMap,
-        time: &WorldTime,
-        weather: &RegionalWeather,
-        wildlife: &[Wildlife],
-        objects: &ObjectRegistry,
+        ctx: &LocationContext,
+        sightings: &mut Vec<(Species, f32)>,
     ) -> String {
+        let LocationContext {
+            player,
+            map,
+            weather,
+            wildlife,
+            objects,
+            ..
+        } = *ctx;
+        let observation = player.effective_skill(Skill::Observation);
+
         // If in terrace, special viewing
         if matches!(player.room, Some(Room::CabinTerrace)) {
-            return Self::terrace_look_direction(
+            let mut desc = Self::terrace_look_direction(dir, ctx, observation, sightings);
+            // The raised terrace lets you see a few tiles farther than the ground does.
+            if let Some(chain) = Self::distant_feature_chain(
                 dir,
-                time,
-                weather,
                 &player.position,
-                wildlife,
-                player.effective_skill("observation"),
-            );
+                map,
+                weather,
+                objects,
+                observation,
+                3.0,
+            ) {
+                desc.push_str("\n\n");
+                desc.push_str(&chain);
+            }
+            return desc;
         }
 
         let look_pos = player.position.move_in_direction(dir);
-        if !look_pos.is_valid() {
+        if !map.contains(&look_pos) {
             return match dir {
                 Direction::North => {
                     "You gaze north, but there's nothing beyond the world's edge.".to_string()
@@ -701,7 +1382,7 @@ impl DescriptionGenerator {
             };
         }
 
-        let Some((look_row, look_col)) = look_pos.as_usize() else {
+        let Some((look_row, look_col)) = map.index_of(&look_pos) else {
             return "You can't see in that direction.".to_string();
         };
         let tile = map.get_tile(look_row, look_col).unwrap();
@@ -738,6 +1419,7 @@ impl DescriptionGenerator {
                         TreeType::Birch => "slender birch",
                         TreeType::Apple => "sturdy apple tree",
                         TreeType::Bamboo => "cluster of bamboo",
+                        TreeType::DatePalm => "date palm",
                     });
                     desc.push('.');
                 }
@@ -746,9 +1428,12 @@ impl DescriptionGenerator {
 
         for obj in objects_here {
             match obj.object.kind {
-                ObjectKind::Cabin(_) => {
+                ObjectKind::Cabin(ref cabin) => {
                     desc.push(' ');
                     desc.push_str("The cabin sits there, solid and welcoming.");
+                    if cabin.weather_vane {
+                        desc.push_str(" A weather vane turns on its roof ridge.");
+                    }
                 }
                 ObjectKind::WoodShed(_) => {
                     desc.push(' ');
@@ -761,6 +1446,10 @@ impl DescriptionGenerator {
                     desc.push(' ');
                     desc.push_str(&format!("You notice a {}.", obj.object.display_name()));
                 }
+                ObjectKind::Seat(ref seat) if seat.room.is_none() => {
+                    desc.push(' ');
+                    desc.push_str(&format!("You notice a {}.", obj.object.display_name()));
+                }
                 _ => {}
             }
         }
@@ -785,9 +1474,23 @@ impl DescriptionGenerator {
             .take(2)
             .collect();
 
+        if let Some(chain) = Self::distant_feature_chain(
+            dir,
+            &player.position,
+            map,
+            weather,
+            objects,
+            observation,
+            map.elevation_at(&player.position),
+        ) {
+            desc.push('\n');
+            desc.push_str(&chain);
+        }
+
         if !wildlife_there.is_empty() {
             desc.push('\n');
             for w in wildlife_there {
+                sightings.push((w.species, player.position.distance_to(&w.position)));
                 desc.push_str(&w.describe());
                 desc.push(' ');
             }
@@ -796,6 +1499,304 @@ impl DescriptionGenerator {
         desc
     }
 
+    /// Look at one named object, landmark, or `row,col` tile from where the
+    /// player stands. Things out of sight get a rough heading instead.
+    pub fn look_at(
+        target: &str,
+        player: &Player,
+        map: &WorldMap,
+        weather: &RegionalWeather,
+        objects: &ObjectRegistry,
+    ) -> String {
+        let origin = player.position;
+        let query = target.trim().to_lowercase();
+        let observation = player.effective_skill(Skill::Observation);
+        let range = Self::visibility_range(
+            observation,
+            weather.get_for_position(origin.row, origin.col),
+            0.0,
+        );
+
+        if let Some(pos) = parse_coordinates(&query) {
+            return Self::look_at_tile(pos, &origin, range, map, objects);
+        }
+
+        if let Some(po) = nearest_named(objects.visible_from(&origin), &origin, &query) {
+            let mut desc = format!(
+                "The {}, {}.",
+                po.object.display_name(),
+                distance_phrase(&origin, &po.position)
+            );
+            if let Some(state) = Self::visible_state(po, map) {
+                desc.push(' ');
+                desc.push_str(&state);
+            }
+            return desc;
+        }
+
+        if let Some(biome) = landmark_biome(&query) {
+            return Self::look_at_landmark(biome, &origin, range, map);
+        }
+
+        let known: Vec<&PlacedObject> = objects
+            .placed
+            .iter()
+            .filter(|p| !p.object.is_hidden())
+            .collect();
+        if let Some(po) = nearest_named(known, &origin, &query) {
+            return format!(
+                "You can't make out the {} from here. Head roughly {}; it's about {} tiles away.",
+                po.object.display_name(),
+                bearing_name(&origin, &po.position),
+                origin.distance_to(&po.position).round() as i32
+            );
+        }
+
+        format!("You don't see anything called '{}' from here.", target.trim())
+    }
+
+    fn look_at_tile(
+        pos: Position,
+        origin: &Position,
+        range: f32,
+        map: &WorldMap,
+        objects: &ObjectRegistry,
+    ) -> String {
+        let Some(tile) = map.tile_at(&pos) else {
+            return "That's beyond the edge of the world.".to_string();
+        };
+        if origin.distance_to(&pos) > range + 0.01 {
+            return format!(
+                "({}, {}) is too far to make out from here. Head roughly {}; it's about {} tiles away.",
+                pos.row,
+                pos.col,
+                bearing_name(origin, &pos),
+                origin.distance_to(&pos).round() as i32
+            );
+        }
+        let mut desc = format!(
+            "({}, {}): {}, {}.",
+            pos.row,
+            pos.col,
+            capitalize(tile.biome.name()),
+            distance_phrase(origin, &pos)
+        );
+        let seen: Vec<String> = objects
+            .objects_at(&pos)
+            .into_iter()
+            .filter(|po| {
+                !po.object.is_indoor_furniture()
+                    && origin.distance_to(&po.position) <= po.object.visibility_range() as f32 + 0.01
+            })
+            .map(|po| po.object.display_name())
+            .collect();
+        if !seen.is_empty() {
+            desc.push_str(&format!(" You can make out: {}.", seen.join(", ")));
+        }
+        desc
+    }
+
+    fn look_at_landmark(biome: Biome, origin: &Position, range: f32, map: &WorldMap) -> String {
+        let here = map.biome_at(origin);
+        if here == Some(biome) {
+            return format!("You're standing in the {} right now.", biome.name());
+        }
+        let mut best: Option<(Position, f32)> = None;
+        for pos in map.positions() {
+            if map.biome_at(&pos) != Some(biome) {
+                continue;
+            }
+            let d = origin.distance_to(&pos);
+            if best.map(|(_, bd)| d < bd).unwrap_or(true) {
+                best = Some((pos, d));
+            }
+        }
+        let Some((pos, d)) = best else {
+            return format!("As far as you know, there's no {} anywhere out here.", biome.name());
+        };
+        if d > range + 0.01 {
+            return format!(
+                "The {} is out of sight from here. Head roughly {}; the nearest edge is about {} tiles away.",
+                biome.name(),
+                bearing_name(origin, &pos),
+                d.round() as i32
+            );
+        }
+        format!(
+            "{}, {}.",
+            capitalize(Self::distant_biome_glimpse(biome)),
+            distance_phrase(origin, &pos)
+        )
+    }
+
+    /// What can be seen of an object's state from a distance.
+    fn visible_state(po: &PlacedObject, map: &WorldMap) -> Option<String> {
+        match &po.object.kind {
+            ObjectKind::Cabin(cabin) => {
+                let door = if cabin.door_open {
+                    "The door stands open."
+                } else {
+                    "The door is shut."
+                };
+                let smoke = match cabin.fireplace.state {
+                    FireState::Roaring | FireState::Burning => "Smoke rises steadily from the chimney.",
+                    FireState::Smoldering => "A thin thread of smoke leaks from the chimney.",
+                    FireState::Cold => "No smoke rises from the chimney.",
+                };
+                Some(format!("{} {}", door, smoke))
+            }
+            ObjectKind::WoodShed(shed) => Some(if shed.logs + shed.firewood > 0 {
+                "Wood is stacked under its roof.".to_string()
+            } else {
+                "It looks nearly empty.".to_string()
+            }),
+            ObjectKind::Tree(tree) if tree.felled => Some("Only a stump is left.".to_string()),
+            ObjectKind::Tree(tree) if tree.fruit_count > 0 => Some(format!(
+                "{} fruit hang{} from its branches.",
+                tree.fruit_count,
+                if tree.fruit_count == 1 { "s" } else { "" }
+            )),
+            ObjectKind::Tree(tree) if tree.fruit_max > 0 => Some(
+                match tree.fruit_stage {
+                    FruitStage::Bare | FruitStage::Blossom => tree.stage_line(),
+                    FruitStage::Setting | FruitStage::Ripe => None,
+                }
+                .unwrap_or("Its branches are bare of fruit for now.")
+                .to_string(),
+            ),
+            ObjectKind::Raft(_) => {
+                let afloat = map
+                    .tile_at(&po.position)
+                    .map(|t| matches!(t.tile_type, TileType::Lake))
+                    .unwrap_or(false);
+                Some(if afloat {
+                    "It rides at its mooring on the water.".to_string()
+                } else {
+                    "It's drawn up on the bank.".to_string()
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// How many tiles out the player can make things out, given skill,
+    /// the weather where they stand, and any height they're looking from.
+    fn visibility_range(observation: u8, weather: Weather, elevation_bonus: f32) -> f32 {
+        let base = 2.0 + observation as f32 / 8.0 + elevation_bonus;
+        (base * weather.visibility_modifier()).max(1.0)
+    }
+
+    /// Scan outward past the adjacent tile and name the nearest few notable
+    /// features in order. Sharper eyes follow the chain further.
+    fn distant_feature_chain(
+        dir: Direction,
+        origin: &Position,
+        map: &WorldMap,
+        weather: &RegionalWeather,
+        objects: &ObjectRegistry,
+        observation: u8,
+        elevation_bonus: f32,
+    ) -> Option<String> {
+        let local_weather = weather.get_for_position(origin.row, origin.col);
+        let range = Self::visibility_range(observation, local_weather, elevation_bonus);
+        let max_features = (1 + observation as usize / 15).min(4);
+
+        let mut features: Vec<String> = Vec::new();
+        let mut seen_lake = false;
+        let mut last_biome: Option<Biome> = map.biome_at(&origin.move_in_direction(dir));
+        let mut pos = origin.move_in_direction(dir);
+        let mut distance = 1;
+
+        loop {
+            pos = pos.move_in_direction(dir);
+            distance += 1;
+            if distance as f32 > range {
+                break;
+            }
+            if !map.contains(&pos) {
+                if dir == Direction::North {
+                    features.push("the mountains rising at the edge of sight".to_string());
+                }
+                break;
+            }
+            let Some((row, col)) = map.index_of(&pos) else {
+                break;
+            };
+            let Some(tile) = map.get_tile(row, col) else {
+                break;
+            };
+
+            match &tile.tile_type {
+                TileType::Lake if !seen_lake => {
+                    seen_lake = true;
+                    features.push("the lake glinting".to_string());
+                }
+                TileType::Lake => {}
+                TileType::Clearing => features.push("a clearing".to_string()),
+                TileType::Path => {}
+                TileType::Forest(biome) => {
+                    if last_biome != Some(*biome) {
+                        features.push(Self::distant_biome_glimpse(*biome).to_string());
+                    }
+                }
+            }
+            last_biome = Some(tile.biome);
+
+            for obj in objects.objects_at(&pos) {
+                let reach = obj.object.visibility_range() as f32;
+                match &obj.object.kind {
+                    ObjectKind::Cabin(cabin) => {
+                        if distance as f32 <= reach + 0.01 {
+                            features.push("the cabin's roofline".to_string());
+                        } else if !matches!(cabin.fireplace.state, FireState::Cold) {
+                            features.push("faint chimney smoke".to_string());
+                        }
+                    }
+                    ObjectKind::Tree(_) | ObjectKind::Seat(_) => {}
+                    _ if obj.object.is_indoor_furniture() => {}
+                    _ if distance as f32 <= reach + 0.01 => {
+                        features.push(format!("a {}", obj.object.display_name()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if features.is_empty() {
+            return None;
+        }
+        let truncated = features.len() > max_features;
+        features.truncate(max_features);
+
+        let mut chain = String::from("Farther out: ");
+        for (i, feature) in features.iter().enumerate() {
+            match i {
+                0 => chain.push_str(feature),
+                1 => chain.push_str(&format!(", then {} beyond", feature)),
+                _ => chain.push_str(&format!(", and {} farther {}", feature, dir_str(dir).to_lowercase())),
+            }
+        }
+        chain.push('.');
+        if truncated {
+            chain.push_str(" Past that, the details blur together.");
+        }
+        Some(chain)
+    }
+
+    fn distant_biome_glimpse(biome: Biome) -> &'static str {
+        match biome {
+            Biome::Desert => "sand dunes shimmering",
+            Biome::Oasis => "the green of an oasis",
+            Biome::SpringForest => "fresh spring woods",
+            Biome::WinterForest => "snow-laden evergreens",
+            Biome::MixedForest => "mixed woodland",
+            Biome::Clearing => "a clearing",
+            Biome::BambooGrove => "a bamboo grove",
+            Biome::Lake => "the lake glinting",
+            Biome::Path => "a winding path",
+        }
+    }
+
     fn distant_biome_description(biome: Biome) -> String {
         match biome {
             Biome::Desert => {
@@ -820,18 +1821,25 @@ impl DescriptionGenerator {
 
     fn terrace_look_direction(
         dir: Direction,
-        time: &WorldTime,
-        weather: &RegionalWeather,
-        player_pos: &Position,
-        wildlife: &[Wildlife],
+        ctx: &LocationContext,
         observation: u8,
+        sightings: &mut Vec<(Species, f32)>,
     ) -> String {
+        let LocationContext {
+            time,
+            weather,
+            wildlife,
+            region_names,
+            ..
+        } = *ctx;
+        let player_pos = &ctx.player.position;
         let tod = time.time_of_day();
 
         let mut desc = match dir {
             Direction::West => {
-                let w = weather.west;
-                format!("You gaze west over the lake toward the desert. {}",
+                let w = weather.for_region(Region::GlassDesert);
+                format!("You gaze west over the lake toward {}. {}",
+                    region_names.name(Region::GlassDesert),
                     match (tod, w) {
                         (_, Weather::Sandstorm) => "A massive sandstorm churns on the horizon, an awe-inspiring wall of dust and wind.",
                         (TimeOfDay::Noon, _) => "The desert blazes under the midday sun. Heat mirages dance above the dunes.",
@@ -841,7 +1849,8 @@ impl DescriptionGenerator {
                 )
             }
             Direction::North => {
-                format!("You look north across the lake toward the mountains. {}",
+                format!("You look north across the lake, over {} to the mountains. {}",
+                    region_names.name(Region::NorthernWoods),
                     match tod {
                         TimeOfDay::Dawn => "The sunrise paints the distant peaks in brilliant pink and gold - a sight that could move anyone to tears.",
                         TimeOfDay::Dusk => "The mountains glow amber in the fading light, their eternal snows touched with rose.",
@@ -851,8 +1860,9 @@ impl DescriptionGenerator {
                 )
             }
             Direction::East => {
-                let w = weather.east;
-                format!("You turn your gaze eastward to the snowy forest. {}",
+                let w = weather.for_region(Region::EasternPines);
+                format!("You turn your gaze eastward to the snowy forest of {}. {}",
+                    region_names.name(Region::EasternPines),
                     match (tod, w) {
                         (TimeOfDay::Evening | TimeOfDay::Night | TimeOfDay::Midnight, Weather::Clear | Weather::LightSnow) =>
                             "The aurora borealis dances in ethereal curtains of green and purple, reflecting off the frozen lake. It's magical.",
@@ -892,6 +1902,7 @@ impl DescriptionGenerator {
             } else {
                 "in the distance"
             };
+            sightings.push((w.species, distance));
             let mut line = w.describe();
             if observation >= 12 {
                 line.push(' ');
@@ -922,6 +1933,89 @@ fn dir_str(dir: Direction) -> &'static str {
     }
 }
 
+/// Compass heading from one tile to another, spelled out.
+fn bearing_name(from: &Position, to: &Position) -> &'static str {
+    match direction_to(from, to) {
+        "N" => "north",
+        "S" => "south",
+        "E" => "east",
+        "W" => "west",
+        "NE" => "north-east",
+        "NW" => "north-west",
+        "SE" => "south-east",
+        "SW" => "south-west",
+        _ => "nearby",
+    }
+}
+
+/// "right here" or "about N tiles to the north-east".
+pub fn distance_phrase(from: &Position, to: &Position) -> String {
+    let d = from.distance_to(to).round() as i32;
+    match d {
+        0 => "right here".to_string(),
+        1 => format!("just to the {}", bearing_name(from, to)),
+        _ => format!("about {} tiles to the {}", d, bearing_name(from, to)),
+    }
+}
+
+/// The closest object whose name matches `query`, leaving out things that
+/// can't be seen from outdoors.
+fn nearest_named<'a>(
+    candidates: Vec<&'a PlacedObject>,
+    origin: &Position,
+    query: &str,
+) -> Option<&'a PlacedObject> {
+    candidates
+        .into_iter()
+        .filter(|po| {
+            if po.object.is_indoor_furniture() {
+                return false;
+            }
+            let name = po.object.display_name().to_lowercase();
+            name.contains(query)
+                || query.contains(&name)
+                || name
+                    .split_whitespace()
+                    .any(|word| word.len() > 2 && query.contains(word))
+        })
+        .min_by(|a, b| {
+            origin
+                .distance_to(&a.position)
+                .total_cmp(&origin.distance_to(&b.position))
+        })
+}
+
+/// Parse "2,3" or "2 3" as a row, column pair.
+fn parse_coordinates(query: &str) -> Option<Position> {
+    let mut parts = query
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|p| !p.is_empty());
+    let row = parts.next()?.parse().ok()?;
+    let col = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(Position::new(row, col))
+}
+
+/// Landmark words that name a kind of terrain rather than an object.
+fn landmark_biome(query: &str) -> Option<Biome> {
+    let table: [(&[&str], Biome); 8] = [
+        (&["lake", "water", "shore"], Biome::Lake),
+        (&["desert", "dune", "sand"], Biome::Desert),
+        (&["oasis"], Biome::Oasis),
+        (&["bamboo"], Biome::BambooGrove),
+        (&["clearing"], Biome::Clearing),
+        (&["path", "trail"], Biome::Path),
+        (&["snow", "winter"], Biome::WinterForest),
+        (&["spring", "temperate"], Biome::SpringForest),
+    ];
+    table
+        .iter()
+        .find(|(words, _)| words.iter().any(|w| query.contains(w)))
+        .map(|(_, biome)| *biome)
+}
+
 fn direction_to(from: &Position, to: &Position) -> &'static str {
     let dr = to.row - from.row;
     let dc = to.col - from.col;
@@ -937,3 +2031,388 @@ fn direction_to(from: &Position, to: &Position) -> &'static str {
         _ => "nearby",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persistence::GameState;
+
+    #[test]
+    fn describing_reads_rooms_and_tiles_from_the_context() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.player.room = Some(Room::CabinMain);
+        let mut sightings = Vec::new();
+        let inside = DescriptionGenerator::describe_location(
+            &state.location_context(&map),
+            None,
+            None,
+            &mut sightings,
+        );
+        assert!(inside.contains("**Exits:**"), "{}", inside);
+        state.player.room = None;
+        state.player.position = Position::new(2, 0);
+        let outside = DescriptionGenerator::describe_location(
+            &state.location_context(&map),
+            None,
+            None,
+            &mut sightings,
+        );
+        assert_ne!(inside, outside);
+        assert!(outside.contains("**Exits:**"), "{}", outside);
+    }
+
+    #[test]
+    fn room_furniture_cant_be_looked_at_from_outdoors() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.player.room = None;
+        state.player.inside = None;
+        state.player.position = Position::new(2, 2);
+        for name in [
+            "mantelpiece",
+            "table",
+            "hearth basket",
+            "window sill",
+            "workbench",
+        ] {
+            let seen = DescriptionGenerator::look_at(
+                name,
+                &state.player,
+                &map,
+                &state.weather,
+                &state.objects,
+            );
+            assert!(
+                seen.starts_with("You don't see anything"),
+                "{}: {}",
+                name,
+                seen
+            );
+        }
+        let cabin = DescriptionGenerator::look_at(
+            "cabin",
+            &state.player,
+            &map,
+            &state.weather,
+            &state.objects,
+        );
+        assert!(cabin.starts_with("The cabin"), "{}", cabin);
+    }
+
+    #[test]
+    fn the_cabin_reads_differently_in_each_tidiness_band() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.player.room = Some(Room::CabinMain);
+        state.cabin_state_mut().unwrap().items.add(Item::Stone, 2);
+        let bands = [
+            (90.0, "swept and orderly", "Stacked neatly along the wall"),
+            (50.0, "lived-in clutter", "Scattered about you notice"),
+            (10.0, "let go", "Strewn across the floor"),
+        ];
+        for (tidiness, room, floor) in bands {
+            state.cabin_state_mut().unwrap().tidiness = tidiness;
+            let seen = DescriptionGenerator::describe_location(
+                &state.location_context(&map),
+                None,
+                None,
+                &mut Vec::new(),
+            );
+            assert!(seen.contains(room), "{}: {}", tidiness, seen);
+            assert!(seen.contains(floor), "{}: {}", tidiness, seen);
+        }
+    }
+
+    #[test]
+    fn sharper_eyes_follow_the_view_further_and_fog_cuts_it_short() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let chain = |state: &GameState, dir: Direction, from: &Position, observation: u8| {
+            let weather = &state.weather;
+            let seen = DescriptionGenerator::distant_feature_chain(
+                dir,
+                from,
+                &map,
+                weather,
+                &state.objects,
+                observation,
+                0.0,
+            );
+            // Each feature after the first is joined on with a comma
+            seen.map(|c| c.matches(", ").count() + 1).unwrap_or(0)
+        };
+        let set = |state: &mut GameState, weather: Weather| {
+            let w = &mut state.weather;
+            (w.north, w.south, w.east, w.west) = (weather, weather, weather, weather);
+        };
+
+        set(&mut state, Weather::Clear);
+        let dirs = [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ];
+        let views: Vec<(Direction, Position)> = map
+            .positions()
+            .filter(|p| map.can_stand_at(p))
+            .flat_map(|p| dirs.map(|d| (d, p)))
+            .collect();
+        let (dir, from) = *views
+            .iter()
+            .find(|(d, p)| chain(&state, *d, p, 40) >= 3)
+            .unwrap();
+        // A glance at most for a beginner: one feature if it's close enough
+        assert!(chain(&state, dir, &from, 5) <= 1);
+        for (d, p) in &views {
+            assert!(chain(&state, *d, p, 5) <= chain(&state, *d, p, 40));
+        }
+
+        set(&mut state, Weather::Fog);
+        assert_eq!(chain(&state, dir, &from, 40), 0);
+    }
+
+    #[test]
+    fn look_finds_things_by_part_of_a_name_by_landmark_or_points_the_way() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.player.exit_room();
+        let (w, clear) = (&mut state.weather, Weather::Clear);
+        (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+        let cabin = state.objects.find("cabin").unwrap().position;
+        state.player.position = Position::new(cabin.row + 2, cabin.col);
+        let look = |target: &str, state: &GameState| {
+            DescriptionGenerator::look_at(
+                target,
+                &state.player,
+                &map,
+                &state.weather,
+                &state.objects,
+            )
+        };
+
+        let cabin_seen = look("cab", &state);
+        assert!(cabin_seen.starts_with("The cabin, about 2 tiles to the north."));
+        assert!(cabin_seen.contains("The door is shut."));
+        let here = Position::new(cabin.row + 2, cabin.col);
+        assert_eq!(state.player.position, here);
+
+        let desert = look("the desert", &state);
+        assert!(desert.starts_with("The scorching desert is out of sight from here."));
+        assert!(desert.contains("Head roughly west"));
+        let tile = look(&format!("{},{}", cabin.row, cabin.col), &state);
+        assert!(tile.contains("You can make out: cabin."));
+
+        let cave = look("cave", &state);
+        assert!(cave.starts_with("You can't make out the cave entrance from here."));
+        assert!(cave.contains("Head roughly north-east"));
+        assert!(look("zzz", &state).starts_with("You don't see anything called 'zzz'"));
+    }
+
+    #[test]
+    fn the_exit_list_notes_what_a_step_would_run_into() {
+        use crate::actions::movement::{try_move, MoveResult};
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.player.exit_room();
+        state.player.inside = None;
+        let exits = |state: &GameState, from: Position, open: bool| {
+            DescriptionGenerator::describe_exits(&from, &map, &state.objects, open)
+        };
+        let refused = |state: &GameState, from: Position, dir: Direction, open: bool| {
+            let mut player = state.player.clone();
+            player.position = from;
+            match try_move(&mut player, dir, &map, &state.objects, open) {
+                MoveResult::Blocked(text) => text,
+                _ => panic!("stepped {} from {:?}", dir_str(dir), from),
+            }
+        };
+
+        // Open water wants the raft; with it moored there, boarding it
+        let sides = [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ];
+        let (shore, out) = map
+            .positions()
+            .filter(|p| map.can_stand_at(p))
+            .flat_map(|p| sides.map(|d| (p, d)))
+            .find(|(p, d)| map.is_open_water(&p.move_in_direction(*d)))
+            .unwrap();
+        let lake = format!("{}: the lake waters (need the raft)", dir_str(out));
+        assert!(exits(&state, shore, true).contains(&lake));
+        assert!(refused(&state, shore, out, true).contains("(need the raft)"));
+        let raft = WorldObject::new(ObjectKind::Raft(Raft::default()));
+        let mooring = shore.move_in_direction(out);
+        state.objects.add("raft", mooring, raft);
+        let raft = "your raft, moored in the water (board it to cross)";
+        let moored = format!("{}: {}", dir_str(out), raft);
+        assert!(exits(&state, shore, true).contains(&moored));
+        assert!(refused(&state, shore, out, true).contains("(board it to cross)"));
+
+        // A wall set down across open ground shuts that way
+        let (from, dir) = map
+            .positions()
+            .filter(|p| map.can_stand_at(p) && state.objects.objects_at(p).is_empty())
+            .flat_map(|p| sides.map(|d| (p, d)))
+            .find(|(p, d)| {
+                let to = p.move_in_direction(*d);
+                map.can_stand_at(&to) && state.objects.objects_at(&to).is_empty()
+            })
+            .unwrap();
+        let exit = |state: &GameState| {
+            let listed = exits(state, from, true);
+            let way = format!("{}: ", dir_str(dir));
+            let line = listed.split(" | ").find(|e| e.contains(&way));
+            line.map(str::to_string).unwrap_or(listed)
+        };
+        let line = exit(&state);
+        assert!(!line.contains("blocked by"), "{}", line);
+        let wall = WorldObject::new(ObjectKind::Wall);
+        let spot = from.move_in_direction(dir);
+        state.objects.add("wall-1", spot, wall);
+        let line = exit(&state);
+        assert!(line.ends_with("(blocked by the wall)"), "{}", line);
+        assert!(refused(&state, from, dir, true).contains("(blocked by the wall)"));
+
+        // The cabin from the front, shut and open, and from the side
+        let cabin = state.objects.find("cabin").unwrap().position;
+        let front = cabin.move_in_direction(Direction::South);
+        assert!(exits(&state, front, false).contains("North: the cabin (door closed)"));
+        assert!(refused(&state, front, Direction::North, false).contains("(door closed)"));
+        assert!(exits(&state, front, true).contains("North: the cabin (door open)"));
+        let side = cabin.move_in_direction(Direction::West);
+        let wall = "East: the cabin wall (the door faces south)";
+        assert!(exits(&state, side, true).contains(wall));
+        let round = refused(&state, side, Direction::East, true);
+        assert!(round.contains("(the door faces south)"), "{}", round);
+    }
+
+    #[test]
+    fn mood_changes_exactly_one_sentence_of_the_same_view() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let (w, clear) = (&mut state.weather, Weather::Clear);
+        (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+        (state.time.hour, state.time.minute) = (8, 0);
+        state.player.room = None;
+        state.player.inside = None;
+        state.player.position = Position::new(2, 2);
+        state.player.connection = 0.0;
+        state.wildlife.clear();
+        // Ambient sounds come and go at random; everything else holds still
+        let biome = map.tile_at(&state.player.position).unwrap().biome;
+        let sounds = ambient_sounds(biome, Weather::Clear, state.time.time_of_day());
+        let view = |state: &GameState, mood: f32| {
+            let mut state = state.clone();
+            state.player.mood = mood;
+            let ctx = state.location_context(&map);
+            let seen = DescriptionGenerator::describe_location(&ctx, None, None, &mut Vec::new());
+            seen.split(['\n', '.'])
+                .map(str::trim)
+                .filter(|s| !s.is_empty() && !sounds.iter().any(|a| a.starts_with(s)))
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        let changed = |a: &[String], b: &[String]| {
+            assert_eq!(a.len(), b.len(), "{:?}\n{:?}", a, b);
+            a.iter().zip(b).filter(|(x, y)| x != y).count()
+        };
+
+        // The same hour in the same place reads the same at an even mood
+        let plain = view(&state, 55.0);
+        assert_eq!(plain, view(&state, 55.0));
+        let warm = view(&state, 90.0);
+        let subdued = view(&state, 15.0);
+        assert_eq!(changed(&plain, &warm), 1);
+        assert_eq!(changed(&plain, &subdued), 1);
+        assert_eq!(changed(&warm, &subdued), 1);
+        assert_eq!(warm, view(&state, 95.0));
+
+        // A deep connection, or the calm after meditating, holds the warm set
+        state.player.connection = 90.0;
+        assert_eq!(view(&state, 15.0), warm);
+        state.player.connection = 0.0;
+        state.player.afterglow_until = state.time.tick + 1;
+        assert_eq!(view(&state, 15.0), warm);
+    }
+
+    #[test]
+    fn the_shed_terrace_and_cabin_read_differently_once_the_player_has_settled_in() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let rooms = [Room::CabinMain, Room::CabinTerrace, Room::WoodShed];
+        let render = |state: &GameState, letters: u32| {
+            let ctx = LocationContext {
+                letters_written: letters,
+                ..state.location_context(&map)
+            };
+            let describe = |room| DescriptionGenerator::describe_room(&room, &ctx);
+            rooms.clone().map(describe)
+        };
+        let early = render(&state, 0);
+        // Nothing random: the same state reads the same every time
+        assert_eq!(early, render(&state, 0));
+        assert_eq!(state.time.season(), Season::Summer);
+        for (room, seen) in rooms.iter().zip(&early) {
+            let season = room_season_line(room, Season::Summer);
+            assert!(seen.contains(season), "{:?}: {}", room, seen);
+        }
+        let [cabin, terrace, shed] = &early;
+        assert!(!cabin.contains("of your own making"), "{}", cabin);
+        assert!(!cabin.contains("begun to pile up"), "{}", cabin);
+        assert!(!terrace.contains("your raft"), "{}", terrace);
+        assert!(!shed.contains("neat, tight stacks"), "{}", shed);
+
+        // Into the winter: wood put by, seats made, letters on the table and a
+        // raft moored below
+        state.time.day = 2 * WorldTime::DAYS_PER_SEASON + 4;
+        let shed_state = state.wood_shed_state_mut().unwrap();
+        shed_state.logs = NEAT_STACKS_SEASONED;
+        shed_state.green = GreenWood::default();
+        for (id, kind) in [("stool", SeatKind::Stool), ("bench", SeatKind::Bench)] {
+            let room = Some(Room::CabinMain);
+            let seat = WorldObject::new(ObjectKind::Seat(Seat { kind, room }));
+            state.objects.add(id, Position::new(0, 0), seat);
+        }
+        state.table_surface_mut().unwrap().items.add(Item::Book, 3);
+        let home = state.objects.find("cabin").unwrap().position;
+        let mooring = map
+            .positions()
+            .filter(|p| map.is_open_water(p))
+            .find(|p| p.distance_to(&home) <= MOORING_RANGE)
+            .unwrap();
+        let raft = WorldObject::new(ObjectKind::Raft(Raft::default()));
+        state.objects.add("raft", mooring, raft);
+
+        let late = render(&state, 3);
+        assert_eq!(late, render(&state, 3));
+        assert_eq!(state.time.season(), Season::Winter);
+        for ((room, seen), before) in rooms.iter().zip(&late).zip(&early) {
+            let season = room_season_line(room, Season::Winter);
+            assert!(seen.contains(season), "{:?}: {}", room, seen);
+            assert_ne!(seen, before);
+        }
+        let [cabin, terrace, shed] = &late;
+        let made = "things of your own making: a wooden stool and a wooden bench.";
+        assert!(cabin.contains(made), "{}", cabin);
+        let pile = "letters have begun to pile up at one end of it, 3 of them now.";
+        assert!(cabin.contains(pile), "{}", cabin);
+        assert!(terrace.contains("rides at its mooring"), "{}", terrace);
+        assert!(shed.contains("neat, tight stacks"), "{}", shed);
+
+        // A single letter isn't a pile yet, and a raft that has drifted off
+        // is seen far out instead
+        let [cabin, ..] = render(&state, 1);
+        assert!(!cabin.contains("pile up"), "{}", cabin);
+        let raft = state.objects.find_mut("raft").unwrap();
+        if let ObjectKind::Raft(r) = &mut raft.object.kind {
+            r.drifted = true;
+        }
+        let [_, terrace, _] = render(&state, 3);
+        assert!(terrace.contains("Far out on the water"), "{}", terrace);
+    }
+}
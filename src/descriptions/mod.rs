@@ -1,2 +1,6 @@
+pub mod affect;
+pub mod ambient;
 pub mod generator;
+pub use affect::*;
+pub use ambient::*;
 pub use generator::*;
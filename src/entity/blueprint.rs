@@ -1,12 +1,93 @@
 use crate::entity::Item;
+use crate::world::Position;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// A tool that has to be on hand to finish a build. Either the forged or
+/// the stone version will do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolNeed {
+    Knife,
+    Axe,
+}
+
+impl ToolNeed {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ToolNeed::Knife => "a knife",
+            ToolNeed::Axe => "an axe",
+        }
+    }
+
+    /// Items that satisfy the need, best first.
+    pub fn accepts(&self) -> &'static [Item] {
+        match self {
+            ToolNeed::Knife => &[Item::Knife, Item::StoneKnife],
+            ToolNeed::Axe => &[Item::Axe, Item::StoneAxe],
+        }
+    }
+}
+
+/// Where a build has to be put together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Workspace {
+    /// Outdoors, within a step of the lake or the oasis.
+    BesideWater,
+    /// Outdoors on open ground, not on a trodden path.
+    OpenGround,
+}
+
+impl Workspace {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Workspace::BesideWater => "must be built outdoors beside water",
+            Workspace::OpenGround => "must be built outdoors, off the path",
+        }
+    }
+}
+
+/// A stand-in a blueprint will take for one of its materials: `per_unit`
+/// pieces of `item` fill the place of one `for_item`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Substitute {
+    pub for_item: Item,
+    pub item: Item,
+    pub per_unit: u32,
+}
+
+impl Substitute {
+    /// Carried weight spent to fill one unit this way.
+    fn weight_per_unit(&self) -> f32 {
+        self.item.weight() * self.per_unit as f32
+    }
+}
+
+const CORDAGE_OR_FIBER: Substitute = Substitute {
+    for_item: Item::Cordage,
+    item: Item::PlantFiber,
+    per_unit: 3,
+};
+const LOG_OR_FIREWOOD: Substitute = Substitute {
+    for_item: Item::Log,
+    item: Item::Firewood,
+    per_unit: 2,
+};
+const LOG_OR_DRIFTWOOD: Substitute = Substitute {
+    for_item: Item::Log,
+    item: Item::Driftwood,
+    per_unit: 1,
+};
 
 #[derive(Clone, Copy)]
 struct BlueprintRecipe {
     target_item: Item,
     required: &'static [(Item, u32)],
+    /// Stand-ins accepted for some of the required materials.
+    substitutes: &'static [Substitute],
     time_cost: u32, // minutes
+    tools: &'static [ToolNeed],
+    workspace: Option<Workspace>,
 }
 
 const BLUEPRINT_RECIPES: &[BlueprintRecipe] = &[
@@ -17,45 +98,171 @@ const BLUEPRINT_RECIPES: &[BlueprintRecipe] = &[
             (Item::Stick, 1),
             (Item::PlantFiber, 1),
         ],
+        substitutes: &[],
         time_cost: 30,
+        tools: &[],
+        workspace: None,
     },
     BlueprintRecipe {
         target_item: Item::StoneAxe,
         required: &[(Item::SharpStone, 1), (Item::Stick, 1), (Item::Cordage, 1)],
+        substitutes: &[CORDAGE_OR_FIBER],
         time_cost: 40,
+        tools: &[],
+        workspace: None,
     },
     BlueprintRecipe {
         target_item: Item::Campfire,
         required: &[(Item::Stone, 4), (Item::Kindling, 1), (Item::Log, 2)],
+        substitutes: &[LOG_OR_FIREWOOD, LOG_OR_DRIFTWOOD],
         time_cost: 20,
+        tools: &[],
+        workspace: Some(Workspace::OpenGround),
     },
     BlueprintRecipe {
         target_item: Item::Cordage,
         required: &[(Item::PlantFiber, 3)],
+        substitutes: &[],
         time_cost: 10,
+        tools: &[],
+        workspace: None,
     },
     BlueprintRecipe {
         target_item: Item::FishingRod,
         required: &[(Item::Bamboo, 1), (Item::Stick, 1), (Item::Cordage, 1)],
+        substitutes: &[CORDAGE_OR_FIBER],
         time_cost: 35,
+        tools: &[ToolNeed::Knife],
+        workspace: None,
     },
     BlueprintRecipe {
         target_item: Item::Raft,
         required: &[(Item::Log, 2), (Item::Cordage, 2), (Item::Stick, 1)],
+        substitutes: &[CORDAGE_OR_FIBER, LOG_OR_DRIFTWOOD],
         time_cost: 80,
+        tools: &[ToolNeed::Axe],
+        workspace: Some(Workspace::BesideWater),
+    },
+    BlueprintRecipe {
+        target_item: Item::WoodenStool,
+        required: &[(Item::Log, 1), (Item::Stick, 3), (Item::Cordage, 1)],
+        substitutes: &[CORDAGE_OR_FIBER],
+        time_cost: 40,
+        tools: &[],
+        workspace: None,
+    },
+    BlueprintRecipe {
+        target_item: Item::WoodenBench,
+        required: &[(Item::Log, 2), (Item::Stick, 2), (Item::Cordage, 2)],
+        substitutes: &[CORDAGE_OR_FIBER],
+        time_cost: 60,
+        tools: &[],
+        workspace: None,
+    },
+    BlueprintRecipe {
+        target_item: Item::HideWrap,
+        required: &[(Item::RawHide, 1), (Item::PlantFiber, 2)],
+        substitutes: &[],
+        time_cost: 30,
+        tools: &[],
+        workspace: None,
+    },
+    BlueprintRecipe {
+        target_item: Item::Bandage,
+        required: &[(Item::PlantFiber, 2), (Item::WildHerbs, 1)],
+        substitutes: &[],
+        time_cost: 10,
+        tools: &[],
+        workspace: None,
+    },
+    BlueprintRecipe {
+        target_item: Item::Thermometer,
+        required: &[(Item::Bamboo, 1), (Item::PlantFiber, 1)],
+        substitutes: &[],
+        time_cost: 25,
+        tools: &[],
+        workspace: None,
+    },
+    BlueprintRecipe {
+        target_item: Item::SignalFire,
+        required: &[(Item::Log, 6), (Item::Stick, 6), (Item::PlantFiber, 3)],
+        substitutes: &[LOG_OR_FIREWOOD, LOG_OR_DRIFTWOOD],
+        time_cost: 60,
+        tools: &[ToolNeed::Axe],
+        workspace: Some(Workspace::OpenGround),
+    },
+    BlueprintRecipe {
+        target_item: Item::Shovel,
+        required: &[(Item::Log, 1), (Item::Stick, 1), (Item::Cordage, 1)],
+        substitutes: &[CORDAGE_OR_FIBER],
+        time_cost: 40,
+        tools: &[ToolNeed::Knife],
+        workspace: None,
+    },
+    BlueprintRecipe {
+        target_item: Item::ClayJar,
+        required: &[(Item::Clay, 2), (Item::PlantFiber, 1)],
+        substitutes: &[],
+        time_cost: 30,
+        tools: &[],
+        workspace: None,
+    },
+    BlueprintRecipe {
+        target_item: Item::WeatherVane,
+        required: &[(Item::Stick, 2), (Item::SharpStone, 1), (Item::Cordage, 1)],
+        substitutes: &[CORDAGE_OR_FIBER],
+        time_cost: 45,
+        tools: &[],
+        workspace: None,
     },
 ];
 
+fn quantity_label(item: Item, qty: u32) -> String {
+    if qty > 1 {
+        format!("{} x{}", item.name(), qty)
+    } else {
+        item.name().to_string()
+    }
+}
+
 fn recipe_for(target: Item) -> Option<&'static BlueprintRecipe> {
     BLUEPRINT_RECIPES.iter().find(|r| r.target_item == target)
 }
 
+/// Every blueprint an item goes into, with how many it takes.
+pub fn recipes_using(item: Item) -> &'static [(Item, u32)] {
+    static USES: OnceLock<HashMap<Item, Vec<(Item, u32)>>> = OnceLock::new();
+    USES.get_or_init(|| {
+        let mut uses: HashMap<Item, Vec<(Item, u32)>> = HashMap::new();
+        for recipe in BLUEPRINT_RECIPES {
+            for &(part, qty) in recipe.required {
+                uses.entry(part).or_default().push((recipe.target_item, qty));
+            }
+        }
+        uses
+    })
+    .get(&item)
+    .map(|v| v.as_slice())
+    .unwrap_or(&[])
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Blueprint {
     pub target_item: Item,
     pub required: HashMap<Item, u32>,
     pub current: HashMap<Item, u32>,
+    /// Stand-in pieces fitted so far, counted as they went in.
+    #[serde(default)]
+    pub stand_ins: HashMap<Item, u32>,
     pub time_cost: u32, // Total time required in minutes
+    /// Numbers the project among any set aside; 0 for one laid out before
+    /// projects could be paused.
+    #[serde(default)]
+    pub id: u32,
+    /// Where a build bound to its site was laid out. It can only be picked
+    /// up again there.
+    #[serde(default)]
+    pub anchor: Option<Position>,
 }
 
 impl Blueprint {
@@ -67,15 +274,93 @@ impl Blueprint {
             target_item: recipe.target_item,
             required,
             current: HashMap::new(),
+            stand_ins: HashMap::new(),
             time_cost: recipe.time_cost,
+            id: 0,
+            anchor: None,
         })
     }
 
+    /// Whether any material has gone in yet.
+    pub fn invested(&self) -> bool {
+        self.current
+            .values()
+            .chain(self.stand_ins.values())
+            .any(|n| *n > 0)
+    }
+
+    /// Tools that must be in hand to finish, worn a little on completion.
+    pub fn tools_needed(&self) -> &'static [ToolNeed] {
+        recipe_for(self.target_item).map(|r| r.tools).unwrap_or(&[])
+    }
+
+    pub fn workspace(&self) -> Option<Workspace> {
+        recipe_for(self.target_item).and_then(|r| r.workspace)
+    }
+
+    /// Stand-ins this blueprint takes for one of its materials.
+    pub fn substitutes_for(&self, material: Item) -> impl Iterator<Item = &'static Substitute> {
+        recipe_for(self.target_item)
+            .map(|r| r.substitutes)
+            .unwrap_or(&[])
+            .iter()
+            .filter(move |s| s.for_item == material)
+    }
+
+    fn substitute_by(&self, stand_in: Item) -> Option<&'static Substitute> {
+        recipe_for(self.target_item)?
+            .substitutes
+            .iter()
+            .find(|s| s.item == stand_in && self.required.contains_key(&s.for_item))
+    }
+
+    /// Whether this item goes into the blueprint, as itself or as a stand-in.
+    pub fn accepts(&self, item: &Item) -> bool {
+        self.required.contains_key(item) || self.substitute_by(*item).is_some()
+    }
+
+    /// Units of a material in place, counting whole sets of stand-ins.
+    fn fulfilled(&self, material: Item) -> u32 {
+        let direct = self.current.get(&material).copied().unwrap_or(0);
+        let stood_in: u32 = self
+            .substitutes_for(material)
+            .map(|s| self.stand_ins.get(&s.item).copied().unwrap_or(0) / s.per_unit)
+            .sum();
+        direct + stood_in
+    }
+
+    /// Tool and site requirements as a sentence, if there are any.
+    pub fn requirements_note(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        let tools = self.tools_needed();
+        if !tools.is_empty() {
+            let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
+            parts.push(format!("needs {} on hand", names.join(" and ")));
+        }
+        if let Some(ws) = self.workspace() {
+            parts.push(ws.describe().to_string());
+        }
+        if parts.is_empty() {
+            return None;
+        }
+        let mut note = parts.join("; ");
+        note[..1].make_ascii_uppercase();
+        note.push('.');
+        Some(note)
+    }
+
     pub fn add_material(&mut self, item: Item) -> bool {
-        if let Some(req_qty) = self.required.get(&item) {
-            let cur_qty = self.current.entry(item).or_insert(0);
-            if *cur_qty < *req_qty {
-                *cur_qty += 1;
+        if let Some(req_qty) = self.required.get(&item).copied() {
+            if self.fulfilled(item) < req_qty {
+                *self.current.entry(item).or_insert(0) += 1;
+                return true;
+            }
+            return false;
+        }
+        if let Some(sub) = self.substitute_by(item) {
+            let req_qty = self.required.get(&sub.for_item).copied().unwrap_or(0);
+            if self.fulfilled(sub.for_item) < req_qty {
+                *self.stand_ins.entry(item).or_insert(0) += 1;
                 return true;
             }
         }
@@ -83,31 +368,82 @@ impl Blueprint {
     }
 
     pub fn is_complete(&self) -> bool {
-        for (item, qty) in &self.required {
-            let cur = self.current.get(item).unwrap_or(&0);
-            if cur < qty {
-                return false;
-            }
-        }
-        true
+        self.required
+            .iter()
+            .all(|(item, qty)| self.fulfilled(*item) >= *qty)
     }
 
     pub fn missing_materials(&self) -> Vec<(Item, u32)> {
         let mut missing = Vec::new();
         for (item, qty) in &self.required {
-            let cur = self.current.get(item).unwrap_or(&0);
-            if cur < qty {
+            let cur = self.fulfilled(*item);
+            if cur < *qty {
                 missing.push((*item, qty - cur));
             }
         }
         missing
     }
 
+    /// What's still short once everything carried is counted, one entry per
+    /// material with its stand-ins, e.g. "log x2, cordage x1 (or plant fiber x3)".
+    pub fn still_needed(&self, held: impl Fn(&Item) -> u32) -> Vec<String> {
+        let mut short: Vec<String> = self
+            .missing_materials()
+            .into_iter()
+            .filter_map(|(item, missing)| {
+                let on_hand = held(&item)
+                    + self
+                        .substitutes_for(item)
+                        .map(|s| held(&s.item) / s.per_unit)
+                        .sum::<u32>();
+                let gap = missing.checked_sub(on_hand).filter(|g| *g > 0)?;
+                let mut label = format!("{} x{}", item.name(), gap);
+                let alternatives: Vec<String> = self
+                    .substitutes_for(item)
+                    .map(|s| format!("{} x{}", s.item.name(), gap * s.per_unit))
+                    .collect();
+                if !alternatives.is_empty() {
+                    label.push_str(&format!(" (or {})", alternatives.join(" or ")));
+                }
+                Some(label)
+            })
+            .collect();
+        short.sort();
+        short
+    }
+
+    /// The material to fit next: for the slot `wanted` fills (or the first
+    /// unfinished one), whichever held option weighs least per unit.
+    pub fn choose_material(&self, wanted: Option<Item>, held: impl Fn(&Item) -> u32) -> Option<Item> {
+        let slots: Vec<Item> = match wanted {
+            Some(item) if self.required.contains_key(&item) => vec![item],
+            Some(item) => vec![self.substitute_by(item)?.for_item],
+            None => self
+                .progress_entries()
+                .into_iter()
+                .filter(|(_, cur, req)| cur < req)
+                .map(|(item, _, _)| item)
+                .collect(),
+        };
+        slots.into_iter().find_map(|slot| {
+            let direct = (held(&slot) > 0).then_some((slot, slot.weight()));
+            direct
+                .into_iter()
+                .chain(
+                    self.substitutes_for(slot)
+                        .filter(|s| held(&s.item) > 0)
+                        .map(|s| (s.item, s.weight_per_unit())),
+                )
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(item, _)| item)
+        })
+    }
+
     pub fn progress_entries(&self) -> Vec<(Item, u32, u32)> {
         let mut entries: Vec<(Item, u32, u32)> = self
             .required
-            .iter()
-            .map(|(item, req)| (*item, *self.current.get(item).unwrap_or(&0), *req))
+            .keys()
+            .map(|item| (*item, self.fulfilled(*item), self.required[item]))
             .collect();
         entries.sort_by_key(|(item, _, _)| item.name());
         entries
@@ -117,25 +453,93 @@ impl Blueprint {
         let parts: Vec<String> = self
             .progress_entries()
             .into_iter()
-            .map(|(item, cur, req)| format!("{} {}/{}", item.name(), cur, req))
+            .map(|(item, cur, req)| {
+                let mut part = format!("{} {}/{}", item.name(), cur, req);
+                let alternatives: Vec<String> = self
+                    .substitutes_for(item)
+                    .map(|s| {
+                        let fitted = self.stand_ins.get(&s.item).copied().unwrap_or(0) % s.per_unit;
+                        let mut alt = format!("{} per one", quantity_label(s.item, s.per_unit));
+                        if fitted > 0 && cur < req {
+                            alt.push_str(&format!(", {} fitted toward the next", fitted));
+                        }
+                        alt
+                    })
+                    .collect();
+                if !alternatives.is_empty() && cur < req {
+                    part.push_str(&format!(" [or {}]", alternatives.join(" or ")));
+                }
+                part
+            })
             .collect();
         parts.join(", ")
     }
 
     pub fn status_description(&self) -> String {
-        if self.is_complete() {
-            return format!(
+        let mut text = if self.is_complete() {
+            format!(
                 "Blueprint for {} is ready to assemble. Total build time: {} mins.",
                 self.target_item.name(),
                 self.time_cost
-            );
+            )
+        } else {
+            format!(
+                "Blueprint for {}. Progress: {}. Total build time: {} mins.",
+                self.target_item.name(),
+                self.progress_summary(),
+                self.time_cost
+            )
+        };
+        if let Some(note) = self.requirements_note() {
+            text.push(' ');
+            text.push_str(&note);
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plant_fiber_stands_in_for_cordage_and_finishes_the_build() {
+        let mut bp = Blueprint::new(Item::StoneAxe).unwrap();
+        let none = bp.still_needed(|_| 0);
+        assert!(none.contains(&"cordage x1 (or plant fiber x3)".to_string()));
+        assert!(bp.progress_summary().contains("cordage 0/1 [or "));
+
+        // Three fibers in the pack cover the cordage
+        let held = |item: &Item| match item {
+            Item::SharpStone | Item::Stick => 1,
+            Item::PlantFiber => 3,
+            _ => 0,
+        };
+        assert!(bp.still_needed(held).is_empty());
+        let fiber = Some(Item::PlantFiber);
+        assert_eq!(bp.choose_material(Some(Item::Cordage), held), fiber);
+
+        // With both on hand, the lighter way in per unit wins
+        let both = |item: &Item| held(item) + u32::from(*item == Item::Cordage);
+        let lighter = if Item::Cordage.weight() <= CORDAGE_OR_FIBER.weight_per_unit() {
+            Item::Cordage
+        } else {
+            Item::PlantFiber
+        };
+        assert_eq!(bp.choose_material(Some(Item::Cordage), both), Some(lighter));
+
+        let fitted = [Item::SharpStone, Item::Stick, Item::PlantFiber];
+        for item in fitted.into_iter().chain([Item::PlantFiber]) {
+            assert!(bp.add_material(item));
         }
+        assert!(!bp.is_complete());
+        assert!(bp.progress_summary().contains("2 fitted toward the next"));
+        assert!(bp.add_material(Item::PlantFiber));
+        assert!(bp.is_complete());
+        assert!(bp.missing_materials().is_empty());
 
-        format!(
-            "Blueprint for {}. Progress: {}. Total build time: {} mins.",
-            self.target_item.name(),
-            self.progress_summary(),
-            self.time_cost
-        )
+        // Nothing more goes in once the slot is filled either way
+        assert!(!bp.add_material(Item::PlantFiber));
+        assert!(!bp.add_material(Item::Cordage));
     }
 }
@@ -19,6 +19,69 @@ pub enum BodyPartKind {
     Tail,
 }
 
+impl BodyPartKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BodyPartKind::Head => "head",
+            BodyPartKind::Torso => "torso",
+            BodyPartKind::ArmLeft => "left arm",
+            BodyPartKind::ArmRight => "right arm",
+            BodyPartKind::LegLeft => "left leg",
+            BodyPartKind::LegRight => "right leg",
+            BodyPartKind::FrontLeftLeg => "front left leg",
+            BodyPartKind::FrontRightLeg => "front right leg",
+            BodyPartKind::BackLeftLeg => "back left leg",
+            BodyPartKind::BackRightLeg => "back right leg",
+            BodyPartKind::Tail => "tail",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<BodyPartKind> {
+        let s = s.to_lowercase();
+        let left = s.contains("left");
+        let right = s.contains("right");
+        if s.contains("head") {
+            Some(BodyPartKind::Head)
+        } else if s.contains("torso") || s.contains("chest") || s.contains("body") {
+            Some(BodyPartKind::Torso)
+        } else if s.contains("arm") || s.contains("hand") || s.contains("finger") {
+            match (left, right) {
+                (true, false) => Some(BodyPartKind::ArmLeft),
+                (false, true) => Some(BodyPartKind::ArmRight),
+                _ => None,
+            }
+        } else if s.contains("leg") || s.contains("foot") || s.contains("knee") {
+            match (left, right) {
+                (true, false) => Some(BodyPartKind::LegLeft),
+                (false, true) => Some(BodyPartKind::LegRight),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// Something ongoing about a body part beyond its raw hit points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PartCondition {
+    Bleeding,
+    Bandaged,
+    Splinted,
+    Frostnipped,
+}
+
+impl PartCondition {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PartCondition::Bleeding => "bleeding",
+            PartCondition::Bandaged => "bandaged",
+            PartCondition::Splinted => "splinted",
+            PartCondition::Frostnipped => "frostnipped",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BodyPart {
     pub kind: BodyPartKind,
@@ -28,6 +91,8 @@ pub struct BodyPart {
     pub movement: bool,
     pub manipulation: bool,
     pub cognition: bool,
+    #[serde(default)]
+    pub conditions: Vec<PartCondition>,
 }
 
 impl BodyPart {
@@ -47,6 +112,7 @@ impl BodyPart {
             movement,
             manipulation,
             cognition,
+            conditions: Vec::new(),
         }
     }
 
@@ -61,6 +127,39 @@ impl BodyPart {
     pub fn is_broken(&self) -> bool {
         self.hp <= 0.0
     }
+
+    /// How well the part works; a splint keeps even a bad limb half-useful.
+    pub fn function_ratio(&self) -> f32 {
+        if self.has(PartCondition::Splinted) {
+            self.ratio().max(0.5)
+        } else {
+            self.ratio()
+        }
+    }
+
+    pub fn has(&self, condition: PartCondition) -> bool {
+        self.conditions.contains(&condition)
+    }
+
+    pub fn add_condition(&mut self, condition: PartCondition) {
+        if !self.has(condition) {
+            self.conditions.push(condition);
+        }
+    }
+
+    pub fn clear_condition(&mut self, condition: PartCondition) {
+        self.conditions.retain(|c| *c != condition);
+    }
+
+    pub fn condition_description(&self) -> &'static str {
+        match self.ratio() {
+            r if r >= 0.95 => "unhurt",
+            r if r >= 0.75 => "bruised",
+            r if r >= 0.5 => "hurt",
+            r if r > 0.0 => "badly injured",
+            _ => "useless",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -159,7 +258,7 @@ impl Body {
         if movers.is_empty() {
             1.0
         } else {
-            let sum: f32 = movers.iter().map(|p| p.ratio()).sum();
+            let sum: f32 = movers.iter().map(|p| p.function_ratio()).sum();
             (sum / movers.len() as f32).clamp(0.0, 1.0)
         }
     }
@@ -169,7 +268,7 @@ impl Body {
         if manipulators.is_empty() {
             1.0
         } else {
-            let sum: f32 = manipulators.iter().map(|p| p.ratio()).sum();
+            let sum: f32 = manipulators.iter().map(|p| p.function_ratio()).sum();
             (sum / manipulators.len() as f32).clamp(0.0, 1.0)
         }
     }
@@ -177,6 +276,64 @@ impl Body {
     pub fn is_vital_broken(&self) -> bool {
         self.parts.iter().any(|p| p.vital && p.is_broken())
     }
+
+    pub fn part(&self, kind: BodyPartKind) -> Option<&BodyPart> {
+        self.parts.iter().find(|p| p.kind == kind)
+    }
+
+    pub fn part_mut(&mut self, kind: BodyPartKind) -> Option<&mut BodyPart> {
+        self.parts.iter_mut().find(|p| p.kind == kind)
+    }
+
+    /// The part in the worst shape, for treatments given without a target.
+    pub fn most_injured(&self) -> Option<BodyPartKind> {
+        self.parts
+            .iter()
+            .filter(|p| p.ratio() < 1.0 || p.has(PartCondition::Bleeding))
+            .min_by(|a, b| {
+                let a_score = a.ratio() - if a.has(PartCondition::Bleeding) { 1.0 } else { 0.0 };
+                let b_score = b.ratio() - if b.has(PartCondition::Bleeding) { 1.0 } else { 0.0 };
+                a_score
+                    .partial_cmp(&b_score)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|p| p.kind)
+    }
+
+    pub fn bleeding_parts(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|p| p.has(PartCondition::Bleeding))
+            .count()
+    }
+
+    /// One tick of natural recovery. Treated parts mend faster; bandages and
+    /// splints come off once they've done their job.
+    pub fn heal_tick(&mut self, rng: &mut impl Rng, well_fed: bool) {
+        for part in &mut self.parts {
+            if part.has(PartCondition::Bleeding) {
+                if part.has(PartCondition::Bandaged) || rng.gen_bool(0.08) {
+                    part.clear_condition(PartCondition::Bleeding);
+                }
+                continue;
+            }
+            if !well_fed || part.hp >= part.max_hp {
+                continue;
+            }
+            let rate = if part.has(PartCondition::Bandaged) {
+                0.06
+            } else if part.has(PartCondition::Splinted) {
+                0.04
+            } else {
+                0.02
+            };
+            part.hp = (part.hp + part.max_hp * rate / 10.0).min(part.max_hp);
+            if part.ratio() >= 0.95 {
+                part.clear_condition(PartCondition::Bandaged);
+                part.clear_condition(PartCondition::Splinted);
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -215,19 +372,7 @@ impl Body {
     }
 
     pub fn describe_hit(&self, hit: &BodyHitEvent, name: &str) -> String {
-        let part_name = match hit.part {
-            BodyPartKind::Head => "head",
-            BodyPartKind::Torso => "torso",
-            BodyPartKind::ArmLeft => "left arm",
-            BodyPartKind::ArmRight => "right arm",
-            BodyPartKind::LegLeft => "left leg",
-            BodyPartKind::LegRight => "right leg",
-            BodyPartKind::FrontLeftLeg => "front left leg",
-            BodyPartKind::FrontRightLeg => "front right leg",
-            BodyPartKind::BackLeftLeg => "back left leg",
-            BodyPartKind::BackRightLeg => "back right leg",
-            BodyPartKind::Tail => "tail",
-        };
+        let part_name = hit.part.name();
 
         if hit.killed {
             format!(
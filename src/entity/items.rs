@@ -0,0 +1,647 @@
+//! What every item is, kept in one table: what it's called, how heavy it
+//! is, how it burns, what eating it does, whether it keeps, and what kind
+//! of thing it is. The lookups on `Item` all read from here, so adding an
+//! item means adding one entry, and the match won't compile without it.
+
+use super::{FuelStage, Item, ItemCategory, Player};
+use ItemCategory::{Food, Fuel, Material, Other, Tool};
+
+const PLAIN_DESCRIPTION: &str = "A useful item.";
+
+/// How an item burns: the heat it gives loose on an open fire, and which
+/// part of a laid fire it feeds and how much of it (tinder in grams,
+/// kindling in pieces, bulk wood in fuel units).
+#[derive(Debug, Clone, Copy)]
+pub struct Burn {
+    pub value: f32,
+    pub stage: FuelStage,
+    pub amount: f32,
+}
+
+/// What eating or drinking an item does, and how it's told.
+#[derive(Debug, Clone, Copy)]
+pub struct Nourishment {
+    pub drink: bool,
+    pub fullness: f32,
+    pub hydration: f32,
+    pub mood: f32,
+    pub health: f32,
+    pub energy: f32,
+    pub warmth: f32,
+    pub message: &'static str,
+}
+
+impl Nourishment {
+    pub fn apply(&self, player: &mut Player) {
+        player.modify_fullness(self.fullness);
+        player.modify_hydration(self.hydration);
+        player.modify_health(self.health);
+        player.modify_mood(self.mood);
+        player.modify_energy(self.energy);
+        player.modify_warmth(self.warmth);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ItemProfile {
+    pub name: &'static str,
+    /// Other words the player might use for it.
+    pub aliases: &'static [&'static str],
+    pub description: &'static str,
+    pub weight: f32,
+    pub category: ItemCategory,
+    pub fuel: Option<Burn>,
+    pub nourishment: Option<Nourishment>,
+    /// Goes off if it's kept too long.
+    pub perishable: bool,
+}
+
+const fn item(name: &'static str, category: ItemCategory, weight: f32) -> ItemProfile {
+    ItemProfile {
+        name,
+        aliases: &[],
+        description: PLAIN_DESCRIPTION,
+        weight,
+        category,
+        fuel: None,
+        nourishment: None,
+        perishable: false,
+    }
+}
+
+const fn tinder(value: f32, grams: f32) -> Burn {
+    Burn {
+        value,
+        stage: FuelStage::Tinder,
+        amount: grams,
+    }
+}
+
+const fn kindling(value: f32, pieces: f32) -> Burn {
+    Burn {
+        value,
+        stage: FuelStage::Kindling,
+        amount: pieces,
+    }
+}
+
+const fn bulk(value: f32) -> Burn {
+    Burn {
+        value,
+        stage: FuelStage::Bulk,
+        amount: value,
+    }
+}
+
+const fn meal(fullness: f32, mood: f32, message: &'static str) -> Nourishment {
+    Nourishment {
+        drink: false,
+        fullness,
+        hydration: 0.0,
+        mood,
+        health: 0.0,
+        energy: 0.0,
+        warmth: 0.0,
+        message,
+    }
+}
+
+const fn drink(hydration: f32, mood: f32, message: &'static str) -> Nourishment {
+    Nourishment {
+        drink: true,
+        fullness: 0.0,
+        hydration,
+        ..meal(0.0, mood, message)
+    }
+}
+
+const RAW_FISH: Nourishment = Nourishment {
+    health: -1.0,
+    ..meal(
+        14.0,
+        -2.0,
+        "You swallow the raw fish. It's briny and not entirely pleasant.",
+    )
+};
+
+impl Item {
+    /// Everything the table knows about this item.
+    pub fn profile(&self) -> ItemProfile {
+        match self {
+            Item::Axe => ItemProfile {
+                aliases: &["hatchet", "iron axe"],
+                description: "A sturdy woodcutting axe with a worn hickory handle.",
+                ..item("axe", Tool, 3.0)
+            },
+            Item::StoneAxe => ItemProfile {
+                aliases: &["primitive axe", "hand axe"],
+                description: "A crude axe made by tying a sharp stone to a stick.",
+                ..item("stone axe", Tool, 0.1)
+            },
+            Item::Knife => ItemProfile {
+                aliases: &["hunting knife", "steel knife"],
+                description: "A sharp hunting knife, essential for survival.",
+                ..item("knife", Tool, 0.1)
+            },
+            Item::StoneKnife => ItemProfile {
+                aliases: &["flint knife", "shard"],
+                description: "A rough blade knapped from stone. Sharp enough to cut.",
+                ..item("stone knife", Tool, 0.1)
+            },
+            Item::Matchbox => ItemProfile {
+                aliases: &["matches", "match box"],
+                ..item("matchbox", Tool, 0.1)
+            },
+            Item::FishingRod => ItemProfile {
+                aliases: &["rod", "fishing pole", "pole"],
+                description: "A simple wooden fishing rod with cordage for line.",
+                ..item("fishing rod", Tool, 1.0)
+            },
+            Item::SmallFish => ItemProfile {
+                aliases: &["small fish", "tiny fish"],
+                description: "A small, wriggling fish.",
+                nourishment: Some(RAW_FISH),
+                perishable: true,
+                ..item("small fish", Food, 0.8)
+            },
+            Item::BigFish => ItemProfile {
+                aliases: &["big fish", "large fish"],
+                description: "A hefty fish that will make a hearty meal.",
+                nourishment: Some(Nourishment {
+                    health: -2.0,
+                    ..meal(
+                        22.0,
+                        -3.0,
+                        "You eat chunks of raw fish. It fills you, though it sits heavy.",
+                    )
+                }),
+                perishable: true,
+                ..item("big fish", Food, 1.5)
+            },
+            Item::Pupfish => ItemProfile {
+                aliases: &["pupfish", "desert fish"],
+                description: "A finger-length desert fish, iridescent blue. Found nowhere but the oasis pool.",
+                nourishment: Some(RAW_FISH),
+                perishable: true,
+                ..item("oasis pupfish", Food, 0.5)
+            },
+            Item::Fish => ItemProfile {
+                aliases: &["fish", "raw fish"],
+                description: "A fresh fish, still slick from the water.",
+                nourishment: Some(RAW_FISH),
+                perishable: true,
+                ..item("raw fish", Food, 1.0)
+            },
+            Item::Raft => ItemProfile {
+                aliases: &["raft", "wooden raft"],
+                description: "A lashed-together raft sturdy enough for short lake trips.",
+                ..item("raft", Other, 8.0)
+            },
+            Item::Log => ItemProfile {
+                aliases: &["unsplit log", "wood"],
+                fuel: Some(bulk(60.0)),
+                ..item("log", Fuel, 5.0)
+            },
+            Item::Stick => ItemProfile {
+                aliases: &["branch", "twig", "wood stick"],
+                description: "A sturdy stick. Useful for crafting or as fuel.",
+                fuel: Some(kindling(5.0, 0.5)),
+                ..item("stick", Fuel, 0.1)
+            },
+            Item::Firewood => ItemProfile {
+                aliases: &["split firewood", "split wood"],
+                fuel: Some(bulk(30.0)),
+                ..item("firewood", Fuel, 0.1)
+            },
+            Item::Kindling => ItemProfile {
+                aliases: &["fire starter", "shavings"],
+                fuel: Some(kindling(10.0, 1.0)),
+                ..item("kindling", Fuel, 0.1)
+            },
+            Item::LitKindling => ItemProfile {
+                aliases: &["burning kindling", "lit tinder"],
+                fuel: Some(kindling(10.0, 1.0)),
+                ..item("lit kindling", Fuel, 0.1)
+            },
+            Item::Charcoal => ItemProfile {
+                aliases: &["coal", "burnt wood"],
+                fuel: Some(bulk(40.0)),
+                ..item("charcoal", Fuel, 0.1)
+            },
+            Item::Ash => ItemProfile {
+                aliases: &["ashes"],
+                ..item("ash", Other, 0.1)
+            },
+            Item::Stone => ItemProfile {
+                aliases: &["rock", "pebble"],
+                description: "A smooth stone. Could be knapped into a tool.",
+                ..item("stone", Material, 0.5)
+            },
+            Item::SharpStone => ItemProfile {
+                aliases: &["sharp rock", "flint flake"],
+                description: "A stone with a razor-sharp edge.",
+                ..item("sharp stone", Material, 0.1)
+            },
+            Item::PlantFiber => ItemProfile {
+                aliases: &["fiber", "grass", "straw"],
+                description: "Tough plant fibers gathered from bushes.",
+                ..item("plant fiber", Material, 0.1)
+            },
+            Item::Cordage => ItemProfile {
+                aliases: &["rope", "string", "twine"],
+                description: "A crude rope braided from plant fibers.",
+                ..item("cordage", Material, 0.1)
+            },
+            Item::Sap => ItemProfile {
+                aliases: &["resin", "pitch"],
+                ..item("tree sap", Material, 0.1)
+            },
+            Item::Clay => ItemProfile {
+                aliases: &["lump of clay", "wet clay"],
+                description: "A cool, sticky lump of grey clay dug from the lakeshore. Shaped and dried by a fire, it holds its form.",
+                ..item("clay", Material, 0.8)
+            },
+            Item::Pinecone => ItemProfile {
+                aliases: &["pine cone"],
+                fuel: Some(tinder(5.0, 20.0)),
+                ..item("pinecone", Fuel, 0.1)
+            },
+            Item::Mushroom => ItemProfile {
+                aliases: &["fungus", "toadstool"],
+                perishable: true,
+                ..item("mushroom", Other, 0.1)
+            },
+            Item::WildBerry => ItemProfile {
+                aliases: &["berries", "wild berries", "berry"],
+                nourishment: Some(meal(5.0, 2.0, "You snack on the berries.")),
+                perishable: true,
+                ..item("wild berry", Food, 0.1)
+            },
+            Item::Feather => ItemProfile {
+                aliases: &["bird feather"],
+                ..item("feather", Material, 0.1)
+            },
+            Item::Driftwood => ItemProfile {
+                aliases: &["drift wood"],
+                ..item("driftwood", Material, 0.1)
+            },
+            Item::Bark => ItemProfile {
+                aliases: &["bark", "tree bark", "birch bark"],
+                fuel: Some(tinder(6.0, 15.0)),
+                ..item("strip of bark", Fuel, 0.1)
+            },
+            Item::DryLeaves => ItemProfile {
+                aliases: &["leaves", "leaf bundle", "tinder"],
+                fuel: Some(tinder(3.0, 10.0)),
+                ..item("dry leaves", Fuel, 0.1)
+            },
+            Item::WildHerbs => ItemProfile {
+                aliases: &["herbs", "wild herbs"],
+                ..item("wild herbs", Other, 0.1)
+            },
+            Item::Apple => ItemProfile {
+                aliases: &["fruit", "red apple"],
+                nourishment: Some(meal(15.0, 0.0, "You eat the apple.")),
+                ..item("apple", Food, 0.1)
+            },
+            Item::Date => ItemProfile {
+                aliases: &["palm fruit"],
+                nourishment: Some(Nourishment {
+                    hydration: 8.0,
+                    ..meal(
+                        10.0,
+                        2.0,
+                        "Sweet dates revive you with a burst of sugar and moisture.",
+                    )
+                }),
+                ..item("date", Food, 0.1)
+            },
+            Item::PineNuts => ItemProfile {
+                aliases: &["pine nut", "pinenuts"],
+                description: "A handful of small, oily pine nuts picked from cones under the snow.",
+                nourishment: Some(meal(
+                    6.0,
+                    1.0,
+                    "You crack the pine nuts one by one. Rich and faintly resinous.",
+                )),
+                ..item("pine nuts", Food, 0.1)
+            },
+            Item::Acorn => ItemProfile {
+                aliases: &["acorns", "oak nut"],
+                description: "A glossy acorn. Bitter raw; roasting tames it.",
+                nourishment: Some(meal(
+                    3.0,
+                    -2.0,
+                    "The raw acorn is mouth-puckeringly bitter. Roasting would help.",
+                )),
+                ..item("acorn", Food, 0.1)
+            },
+            Item::BambooShoot => ItemProfile {
+                aliases: &["bamboo shoots", "shoot", "shoots"],
+                description: "A pale, tender bamboo shoot. It needs cooking before it's safe to eat.",
+                nourishment: Some(Nourishment {
+                    health: -2.0,
+                    ..meal(
+                        4.0,
+                        0.0,
+                        "The raw shoot is bitter and leaves your mouth tingling unpleasantly. It wanted cooking.",
+                    )
+                }),
+                ..item("bamboo shoot", Food, 0.1)
+            },
+            Item::Reeds => ItemProfile {
+                aliases: &["reed", "rushes"],
+                description: "Long, pliant lakeshore reeds. Split and twisted, they make good cordage.",
+                ..item("reeds", Material, 0.1)
+            },
+            Item::Flint => ItemProfile {
+                aliases: &["flint nodule", "flintstone"],
+                description: "A waxy grey nodule of flint. It flakes to a far keener edge than river stone.",
+                ..item("flint", Material, 0.4)
+            },
+            Item::Bamboo => ItemProfile {
+                aliases: &["bamboo stalk", "stalk", "canebamboo"],
+                description: "A straight, light bamboo stalk harvested near the lake.",
+                fuel: Some(kindling(8.0, 1.0)),
+                ..item("bamboo", Fuel, 1.0)
+            },
+            Item::Paper => ItemProfile {
+                aliases: &["sheet", "paper sheet"],
+                description: "A thin sheet of paper made from bamboo pulp.",
+                fuel: Some(tinder(1.0, 5.0)),
+                ..item("paper", Material, 0.05)
+            },
+            Item::BlankBook => ItemProfile {
+                aliases: &["blank book", "empty book", "notebook blank"],
+                description: "A blank book with crisp pages, ready for a title.",
+                ..item("blank book", Other, 0.3)
+            },
+            Item::Book => ItemProfile {
+                aliases: &["notebook", "journal", "book"],
+                description: "A bound book. Check its ID to read or write pages.",
+                ..item("book", Other, 0.4)
+            },
+            Item::TutorialBook => ItemProfile {
+                aliases: &["tutorial", "guide book", "tutorial book"],
+                description: "A short guide left in the cabin.",
+                ..item("tutorial book", Other, 0.4)
+            },
+            Item::DeathNote => ItemProfile {
+                aliases: &["death note", "black notebook", "cursed book"],
+                description: "A black notebook with ominous weight.",
+                ..item("death note", Other, 0.4)
+            },
+            Item::BookOfFishing => ItemProfile {
+                aliases: &["fishing book", "book of fishing", "angler guide"],
+                description: "A slim guide on casting, bait, and rod making.",
+                ..item("book of fishing", Other, 0.4)
+            },
+            Item::Campfire => ItemProfile {
+                aliases: &["fire", "fire pit"],
+                description: "A ring of stones with wood, ready to be lit.",
+                ..item("campfire", Other, 0.1)
+            },
+            Item::SignalFire => ItemProfile {
+                aliases: &["signal pyre", "beacon", "pyre"],
+                description: "A tall cone of logs stuffed with green boughs, built to throw a column of smoke high above the trees. It wants open high ground and a clear sky.",
+                ..item("signal fire", Other, 12.0)
+            },
+            Item::WoodenStool => ItemProfile {
+                aliases: &["stool"],
+                description: "A squat three-legged stool lashed from log and sticks. Set it down to sit on.",
+                ..item("wooden stool", Other, 3.0)
+            },
+            Item::WoodenBench => ItemProfile {
+                aliases: &["bench"],
+                description: "A rough plank bench, heavy but steady. Set it down outdoors to sit on.",
+                ..item("wooden bench", Other, 6.0)
+            },
+            Item::CardCase => ItemProfile {
+                aliases: &["card case", "case", "deck case"],
+                description: "A worn leather card case that rattles softly when moved.",
+                ..item("card case", Other, 0.2)
+            },
+            Item::PlayingCard => ItemProfile {
+                aliases: &["card", "playing card", "poker card"],
+                description: "A single playing card, edges a little frayed.",
+                ..item("playing card", Other, 0.01)
+            },
+            Item::OldBook => ItemProfile {
+                aliases: &["book", "old book", "leather-bound book"],
+                fuel: Some(tinder(8.0, 60.0)),
+                ..item("old leather-bound book", Other, 0.4)
+            },
+            Item::StrangeCompass => ItemProfile {
+                aliases: &["compass", "odd compass"],
+                ..item("strange compass", Other, 0.1)
+            },
+            Item::AncientMap => ItemProfile {
+                aliases: &["map", "weathered map", "old map"],
+                ..item("ancient map", Other, 0.1)
+            },
+            Item::TeaCup => ItemProfile {
+                aliases: &["cup", "tea cup", "teacup", "ceramic cup"],
+                ..item("ceramic tea cup", Tool, 0.1)
+            },
+            Item::WoolBlanket => ItemProfile {
+                aliases: &["blanket", "warm blanket", "wool blanket"],
+                ..item("wool blanket", Other, 0.1)
+            },
+            Item::Kettle => ItemProfile {
+                aliases: &["copper kettle", "empty kettle"],
+                ..item("copper kettle", Tool, 0.1)
+            },
+            Item::WaterKettle => ItemProfile {
+                aliases: &["water kettle", "kettle with water", "filled kettle"],
+                ..item("kettle with water", Tool, 0.1)
+            },
+            Item::HotWaterKettle => ItemProfile {
+                aliases: &[
+            "hot water",
+            "hot kettle",
+            "hot water kettle",
+            "boiling water",
+        ],
+                ..item("kettle with hot water", Tool, 0.1)
+            },
+            Item::MuddyWater => ItemProfile {
+                aliases: &["muddy water", "dirty water"],
+                description: "A container of unfiltered water. Boil before drinking.",
+                nourishment: Some(Nourishment {
+                    health: -4.0,
+                    ..drink(
+                        8.0,
+                        -3.0,
+                        "You choke down the muddy water. It sits poorly in your stomach.",
+                    )
+                }),
+                ..item("muddy water", Food, 0.6)
+            },
+            Item::CleanWater => ItemProfile {
+                aliases: &["clean water", "boiled water", "safe water"],
+                description: "Clear, boiled water that looks safe to drink.",
+                nourishment: Some(Nourishment {
+                    energy: 2.0,
+                    ..drink(25.0, 0.0, "You drink the clean water. It tastes refreshing.")
+                }),
+                ..item("clean water", Food, 0.6)
+            },
+            Item::CookedFish => ItemProfile {
+                aliases: &["grilled fish", "cooked fish"],
+                description: "Tender cooked fish, still steaming gently.",
+                nourishment: Some(meal(
+                    30.0,
+                    4.0,
+                    "You eat the warm, cooked fish. Protein and warmth spread through you.",
+                )),
+                perishable: true,
+                ..item("cooked fish", Food, 0.4)
+            },
+            Item::CookedBerries => ItemProfile {
+                aliases: &["roasted berries", "cooked berries"],
+                description: "Roasted berries that smell sweet and tart.",
+                nourishment: Some(meal(
+                    12.0,
+                    6.0,
+                    "You munch on the roasted berries. Sweet and tart.",
+                )),
+                perishable: true,
+                ..item("roasted berries", Food, 0.2)
+            },
+            Item::RoastedNuts => ItemProfile {
+                aliases: &["toasted nuts"],
+                description: "Nuts roasted until fragrant and toasty.",
+                nourishment: Some(meal(
+                    14.0,
+                    4.0,
+                    "You eat the roasted nuts by the handful. Warm, toasty and filling.",
+                )),
+                ..item("roasted nuts", Food, 0.1)
+            },
+            Item::RoastedShoot => ItemProfile {
+                aliases: &["roasted shoot", "cooked bamboo shoot"],
+                description: "A bamboo shoot roasted soft and sweet.",
+                nourishment: Some(meal(
+                    12.0,
+                    3.0,
+                    "The roasted shoot is tender and sweet, like young corn.",
+                )),
+                ..item("roasted bamboo shoot", Food, 0.1)
+            },
+            Item::HerbalTea => ItemProfile {
+                aliases: &["tea", "herbal tea", "cup of tea"],
+                nourishment: Some(Nourishment {
+                    warmth: 3.0,
+                    ..drink(15.0, 5.0, "You sip the herbal tea, feeling calm and warm.")
+                }),
+                ..item("cup of herbal tea", Food, 0.1)
+            },
+            Item::RubberDuck => ItemProfile {
+                aliases: &["duck", "yellow duck", "rubber ducky", "sage"],
+                ..item("rubber duck", Other, 0.1)
+            },
+            Item::RawMeat => ItemProfile {
+                aliases: &["meat", "raw meat", "fresh meat"],
+                description: "Fresh cuts of raw meat from a recent kill.",
+                nourishment: Some(Nourishment {
+                    health: -2.0,
+                    ..meal(
+                        18.0,
+                        -3.0,
+                        "You chew the raw meat. It fills you, but your stomach protests.",
+                    )
+                }),
+                perishable: true,
+                ..item("raw meat", Food, 0.5)
+            },
+            Item::CookedMeat => ItemProfile {
+                aliases: &["cooked meat", "grilled meat"],
+                description: "Cooked meat, savory and filling.",
+                nourishment: Some(meal(
+                    32.0,
+                    6.0,
+                    "You eat the cooked meat. Rich warmth and strength spread through your body.",
+                )),
+                perishable: true,
+                ..item("cooked meat", Food, 0.4)
+            },
+            Item::RawHide => ItemProfile {
+                aliases: &["hide", "raw hide", "animal hide"],
+                description: "A raw animal hide that still needs tanning.",
+                perishable: true,
+                ..item("raw hide", Material, 0.7)
+            },
+            Item::HideWrap => ItemProfile {
+                aliases: &["hide wrap", "wrap", "hide cloak"],
+                description: "A rough wrap stitched from raw hide and plant fiber. Stiff, but it holds warmth.",
+                ..item("hide wrap", Material, 1.0)
+            },
+            Item::Thermometer => ItemProfile {
+                aliases: &["thermometer", "bamboo thermometer"],
+                description: "A slim bamboo tube with a bead of resin that creeps along notched marks as the air warms. Crude, but it reads true.",
+                ..item("thermometer", Tool, 0.2)
+            },
+            Item::WeatherVane => ItemProfile {
+                aliases: &["vane", "weather vane", "weathervane"],
+                description: "A stick-and-stone arrow balanced on a pivot. Mounted up high, it shows which way the weather is coming from.",
+                ..item("weather vane", Other, 1.5)
+            },
+            Item::Bandage => ItemProfile {
+                aliases: &["bandage", "dressing"],
+                description: "A strip of soft plant fiber packed with crushed herbs. Wrap it around a wound to stop bleeding and help it mend.",
+                ..item("bandage", Tool, 0.1)
+            },
+            Item::Shovel => ItemProfile {
+                aliases: &["shovel", "spade", "wooden shovel"],
+                description: "A broad blade of split log lashed to a stick handle. Good for moving snow, earth, or a great deal of sand.",
+                ..item("wooden shovel", Tool, 1.5)
+            },
+            Item::ClayJar => ItemProfile {
+                aliases: &["jar", "clay jar", "empty jar"],
+                description: "A small, lopsided jar of fire-hardened clay with a fiber-stoppered neck.",
+                ..item("clay jar", Tool, 0.4)
+            },
+            Item::FireflyJar => ItemProfile {
+                aliases: &["firefly jar", "jar of fireflies", "fireflies"],
+                description: "A clay jar with a loose fiber stopper, full of slow green winks of light. They won't last long shut away in there.",
+                ..item("firefly jar", Other, 0.4)
+            },
+            Item::Lodestone => ItemProfile {
+                aliases: &["lodestone", "black stone", "magnet stone"],
+                description: "A heavy, palm-sized lump of black stone, worn smooth by hands. Iron filings cling to it, and the strange compass's needle swings to follow it.",
+                ..item("lodestone", Material, 0.6)
+            },
+            Item::AnimalFat => ItemProfile {
+                aliases: &["fat", "animal fat"],
+                description: "Rendered animal fat, useful for cooking or as fuel.",
+                fuel: Some(bulk(20.0)),
+                ..item("animal fat", Fuel, 0.3)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::objects::ALL_ITEMS;
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn every_item_has_a_whole_profile() {
+        let mut names = HashSet::new();
+        for item in ALL_ITEMS {
+            let profile = item.profile();
+            assert!(!profile.name.is_empty(), "{:?}", item);
+            assert!(names.insert(profile.name), "{} named twice", profile.name);
+            assert!(profile.weight > 0.0, "{} weighs nothing", profile.name);
+            assert_eq!(Item::from_str(profile.name), Some(item));
+            if let Some(burn) = profile.fuel {
+                assert!(burn.value > 0.0 && burn.amount > 0.0, "{}", profile.name);
+            }
+            if let Some(food) = profile.nourishment {
+                assert!(!food.message.is_empty(), "{}", profile.name);
+            }
+        }
+    }
+}
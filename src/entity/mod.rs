@@ -1,15 +1,19 @@
 pub mod blueprint;
 pub mod book;
 pub mod body;
+pub mod items;
 pub mod objects;
 pub mod player;
 pub mod trees;
+pub mod vitals;
 pub mod wildlife;
 
 pub use blueprint::*;
 pub use book::*;
 pub use body::*;
+pub use items::*;
 pub use objects::*;
 pub use player::*;
 pub use trees::*;
+pub use vitals::*;
 pub use wildlife::*;
@@ -1,3 +1,4 @@
+use super::Nourishment;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -11,6 +12,7 @@ pub enum Item {
     FishingRod,
     SmallFish,
     BigFish,
+    Pupfish,
     Fish,
     Raft,
 
@@ -29,6 +31,7 @@ pub enum Item {
     PlantFiber, // From bushes/grass
     Cordage,    // Rope from fiber
     Sap,        // Tree resin
+    Clay,       // Dug from the lakeshore
 
     // Found items
     Pinecone,
@@ -41,6 +44,11 @@ pub enum Item {
     WildHerbs,     // For tea
     Apple,         // Fruit from nearby trees
     Date,          // From oasis
+    PineNuts,      // From winter forest
+    Acorn,         // From mixed forest
+    BambooShoot,   // From bamboo grove
+    Reeds,         // From the lakeshore
+    Flint,         // From the desert
     Bamboo,        // From bamboo grove
     Paper,         // Crafted from bamboo
     BlankBook,     // Unbound/writable book
@@ -51,6 +59,9 @@ pub enum Item {
 
     // Structures / Placeables (as items for blueprint targets)
     Campfire,
+    SignalFire,
+    WoodenStool,
+    WoodenBench,
 
     // Play / misc
     CardCase,
@@ -69,15 +80,25 @@ pub enum Item {
     CleanWater,
     CookedFish,
     CookedBerries,
+    RoastedNuts,
+    RoastedShoot,
     HerbalTea, // Finished tea!
     RubberDuck,
     RawMeat,
     CookedMeat,
     RawHide,
+    HideWrap,
+    Thermometer,
+    WeatherVane,
+    Bandage,
+    Shovel,
+    ClayJar,
+    FireflyJar,
+    Lodestone,
     AnimalFat,
 }
 
-const ALL_ITEMS: [Item; 60] = [
+pub(super) const ALL_ITEMS: [Item; 80] = [
     Item::Axe,
     Item::StoneAxe,
     Item::Knife,
@@ -86,6 +107,7 @@ const ALL_ITEMS: [Item; 60] = [
     Item::FishingRod,
     Item::SmallFish,
     Item::BigFish,
+    Item::Pupfish,
     Item::Fish,
     Item::Raft,
     Item::Log,
@@ -100,6 +122,7 @@ const ALL_ITEMS: [Item; 60] = [
     Item::PlantFiber,
     Item::Cordage,
     Item::Sap,
+    Item::Clay,
     Item::Pinecone,
     Item::Mushroom,
     Item::WildBerry,
@@ -110,6 +133,11 @@ const ALL_ITEMS: [Item; 60] = [
     Item::WildHerbs,
     Item::Apple,
     Item::Date,
+    Item::PineNuts,
+    Item::Acorn,
+    Item::BambooShoot,
+    Item::Reeds,
+    Item::Flint,
     Item::Bamboo,
     Item::Paper,
     Item::BlankBook,
@@ -118,6 +146,9 @@ const ALL_ITEMS: [Item; 60] = [
     Item::DeathNote,
     Item::BookOfFishing,
     Item::Campfire,
+    Item::SignalFire,
+    Item::WoodenStool,
+    Item::WoodenBench,
     Item::CardCase,
     Item::PlayingCard,
     Item::OldBook,
@@ -132,148 +163,40 @@ const ALL_ITEMS: [Item; 60] = [
     Item::CleanWater,
     Item::CookedFish,
     Item::CookedBerries,
+    Item::RoastedNuts,
+    Item::RoastedShoot,
     Item::HerbalTea,
     Item::RubberDuck,
     Item::RawMeat,
     Item::CookedMeat,
     Item::RawHide,
+    Item::HideWrap,
+    Item::Thermometer,
+    Item::WeatherVane,
+    Item::Bandage,
+    Item::Shovel,
+    Item::ClayJar,
+    Item::FireflyJar,
+    Item::Lodestone,
     Item::AnimalFat,
 ];
 
+// Every variant, in declaration order; a gap or a duplicate fails the build.
+const _: () = {
+    let mut i = 0;
+    while i < ALL_ITEMS.len() {
+        assert!(ALL_ITEMS[i] as usize == i);
+        i += 1;
+    }
+};
+
 impl Item {
     pub fn name(&self) -> &'static str {
-        match self {
-            Item::Axe => "axe",
-            Item::StoneAxe => "stone axe",
-            Item::Knife => "knife",
-            Item::StoneKnife => "stone knife",
-            Item::Matchbox => "matchbox",
-            Item::FishingRod => "fishing rod",
-            Item::SmallFish => "small fish",
-            Item::BigFish => "big fish",
-            Item::Fish => "raw fish",
-            Item::Raft => "raft",
-            Item::Log => "log",
-            Item::Stick => "stick",
-            Item::Firewood => "firewood",
-            Item::Kindling => "kindling",
-            Item::LitKindling => "lit kindling",
-            Item::Charcoal => "charcoal",
-            Item::Ash => "ash",
-            Item::Stone => "stone",
-            Item::SharpStone => "sharp stone",
-            Item::PlantFiber => "plant fiber",
-            Item::Cordage => "cordage",
-            Item::Sap => "tree sap",
-            Item::Pinecone => "pinecone",
-            Item::Mushroom => "mushroom",
-            Item::WildBerry => "wild berry",
-            Item::Feather => "feather",
-            Item::Driftwood => "driftwood",
-            Item::Bark => "strip of bark",
-            Item::DryLeaves => "dry leaves",
-            Item::WildHerbs => "wild herbs",
-            Item::Apple => "apple",
-            Item::Date => "date",
-            Item::Bamboo => "bamboo",
-            Item::Paper => "paper",
-            Item::BlankBook => "blank book",
-            Item::Book => "book",
-            Item::TutorialBook => "tutorial book",
-            Item::DeathNote => "death note",
-            Item::BookOfFishing => "book of fishing",
-            Item::Campfire => "campfire",
-            Item::CardCase => "card case",
-            Item::PlayingCard => "playing card",
-            Item::OldBook => "old leather-bound book",
-            Item::StrangeCompass => "strange compass",
-            Item::AncientMap => "ancient map",
-            Item::TeaCup => "ceramic tea cup",
-            Item::WoolBlanket => "wool blanket",
-            Item::Kettle => "copper kettle",
-            Item::WaterKettle => "kettle with water",
-            Item::HotWaterKettle => "kettle with hot water",
-            Item::MuddyWater => "muddy water",
-            Item::CleanWater => "clean water",
-            Item::CookedFish => "cooked fish",
-            Item::CookedBerries => "roasted berries",
-            Item::HerbalTea => "cup of herbal tea",
-            Item::RubberDuck => "rubber duck",
-            Item::RawMeat => "raw meat",
-            Item::CookedMeat => "cooked meat",
-            Item::RawHide => "raw hide",
-            Item::AnimalFat => "animal fat",
-        }
+        self.profile().name
     }
 
     pub fn aliases(&self) -> &'static [&'static str] {
-        match self {
-            Item::Axe => &["hatchet", "iron axe"],
-            Item::StoneAxe => &["primitive axe", "hand axe"],
-            Item::Knife => &["hunting knife", "steel knife"],
-            Item::StoneKnife => &["flint knife", "shard"],
-            Item::Matchbox => &["matches", "match box"],
-            Item::FishingRod => &["rod", "fishing pole", "pole"],
-            Item::SmallFish => &["small fish", "tiny fish"],
-            Item::BigFish => &["big fish", "large fish"],
-            Item::Fish => &["fish", "raw fish"],
-            Item::Raft => &["raft", "wooden raft"],
-            Item::Log => &["unsplit log", "wood"],
-            Item::Stick => &["branch", "twig", "wood stick"],
-            Item::Firewood => &["split firewood", "split wood"],
-            Item::Kindling => &["tinder", "fire starter", "shavings"],
-            Item::LitKindling => &["burning kindling", "lit tinder"],
-            Item::Charcoal => &["coal", "burnt wood"],
-            Item::Ash => &["ashes"],
-            Item::Stone => &["rock", "pebble"],
-            Item::SharpStone => &["sharp rock", "flint flake"],
-            Item::PlantFiber => &["fiber", "grass", "straw"],
-            Item::Cordage => &["rope", "string", "twine"],
-            Item::Sap => &["resin", "pitch"],
-            Item::Pinecone => &["pine cone"],
-            Item::Mushroom => &["fungus", "toadstool"],
-            Item::WildBerry => &["berries", "wild berries", "berry"],
-            Item::Feather => &["bird feather"],
-            Item::Driftwood => &["drift wood"],
-            Item::Bark => &["bark", "tree bark", "birch bark"],
-            Item::DryLeaves => &["leaves", "leaf bundle"],
-            Item::WildHerbs => &["herbs", "wild herbs"],
-            Item::Apple => &["fruit", "red apple"],
-            Item::Date => &["palm fruit"],
-            Item::Bamboo => &["bamboo stalk", "stalk", "canebamboo"],
-            Item::Paper => &["sheet", "paper sheet"],
-            Item::BlankBook => &["blank book", "empty book", "notebook blank"],
-            Item::Book => &["notebook", "journal", "book"],
-            Item::TutorialBook => &["tutorial", "guide book", "tutorial book"],
-            Item::DeathNote => &["death note", "black notebook", "cursed book"],
-            Item::BookOfFishing => &["fishing book", "book of fishing", "angler guide"],
-            Item::Campfire => &["fire", "fire pit"],
-            Item::CardCase => &["card case", "case", "deck case"],
-            Item::PlayingCard => &["card", "playing card", "poker card"],
-            Item::OldBook => &["book", "old book", "leather-bound book"],
-            Item::StrangeCompass => &["compass", "odd compass"],
-            Item::AncientMap => &["map", "weathered map", "old map"],
-            Item::TeaCup => &["cup", "tea cup", "teacup", "ceramic cup"],
-            Item::WoolBlanket => &["blanket", "warm blanket", "wool blanket"],
-            Item::Kettle => &["copper kettle", "empty kettle"],
-            Item::WaterKettle => &["water kettle", "kettle with water", "filled kettle"],
-            Item::HotWaterKettle => &[
-                "hot water",
-                "hot kettle",
-                "hot water kettle",
-                "boiling water",
-            ],
-            Item::MuddyWater => &["muddy water", "dirty water"],
-            Item::CleanWater => &["clean water", "boiled water", "safe water"],
-            Item::CookedFish => &["grilled fish", "cooked fish"],
-            Item::CookedBerries => &["roasted berries", "cooked berries"],
-            Item::HerbalTea => &["tea", "herbal tea", "cup of tea"],
-            Item::RubberDuck => &["duck", "yellow duck", "rubber ducky", "sage"],
-            Item::RawMeat => &["meat", "raw meat", "fresh meat"],
-            Item::CookedMeat => &["cooked meat", "grilled meat"],
-            Item::RawHide => &["hide", "raw hide", "animal hide"],
-            Item::AnimalFat => &["fat", "animal fat"],
-        }
+        self.profile().aliases
     }
 
     fn candidate_names(&self) -> impl Iterator<Item = &'static str> {
@@ -297,111 +220,90 @@ impl Item {
     }
 
     pub fn description(&self) -> &'static str {
-        match self {
-            Item::Axe => "A sturdy woodcutting axe with a worn hickory handle.",
-            Item::StoneAxe => "A crude axe made by tying a sharp stone to a stick.",
-            Item::Knife => "A sharp hunting knife, essential for survival.",
-            Item::StoneKnife => "A rough blade knapped from stone. Sharp enough to cut.",
-            Item::Stick => "A sturdy stick. Useful for crafting or as fuel.",
-            Item::Stone => "A smooth stone. Could be knapped into a tool.",
-            Item::SharpStone => "A stone with a razor-sharp edge.",
-            Item::PlantFiber => "Tough plant fibers gathered from bushes.",
-            Item::Cordage => "A crude rope braided from plant fibers.",
-            Item::Campfire => "A ring of stones with wood, ready to be lit.",
-            Item::Fish => "A fresh fish, still slick from the water.",
-            Item::SmallFish => "A small, wriggling fish.",
-            Item::BigFish => "A hefty fish that will make a hearty meal.",
-            Item::Bamboo => "A straight, light bamboo stalk harvested near the lake.",
-            Item::Paper => "A thin sheet of paper made from bamboo pulp.",
-            Item::BlankBook => "A blank book with crisp pages, ready for a title.",
-            Item::Book => "A bound book. Check its ID to read or write pages.",
-            Item::TutorialBook => "A short guide left in the cabin.",
-            Item::DeathNote => "A black notebook with ominous weight.",
-            Item::FishingRod => "A simple wooden fishing rod with cordage for line.",
-            Item::BookOfFishing => "A slim guide on casting, bait, and rod making.",
-            Item::Raft => "A lashed-together raft sturdy enough for short lake trips.",
-            Item::MuddyWater => "A container of unfiltered water. Boil before drinking.",
-            Item::CleanWater => "Clear, boiled water that looks safe to drink.",
-            Item::CookedFish => "Tender cooked fish, still steaming gently.",
-            Item::CookedBerries => "Roasted berries that smell sweet and tart.",
-            Item::CardCase => "A worn leather card case that rattles softly when moved.",
-            Item::PlayingCard => "A single playing card, edges a little frayed.",
-            Item::RawMeat => "Fresh cuts of raw meat from a recent kill.",
-            Item::CookedMeat => "Cooked meat, savory and filling.",
-            Item::RawHide => "A raw animal hide that still needs tanning.",
-            Item::AnimalFat => "Rendered animal fat, useful for cooking or as fuel.",
-            _ => "A useful item.",
-        }
+        self.profile().description
     }
 
     pub fn weight(&self) -> f32 {
-        match self {
-            Item::Log => 5.0,
-            Item::Stone => 0.5,
-            Item::Axe => 3.0,
-            Item::Bamboo => 1.0,
-            Item::Paper => 0.05,
-            Item::BlankBook => 0.3,
-            Item::Book
-            | Item::TutorialBook
-            | Item::DeathNote
-            | Item::OldBook
-            | Item::BookOfFishing => 0.4,
-            Item::Fish => 1.0,
-            Item::SmallFish => 0.8,
-            Item::BigFish => 1.5,
-            Item::MuddyWater | Item::CleanWater => 0.6,
-            Item::CookedFish => 0.4,
-            Item::CookedBerries => 0.2,
-            Item::FishingRod => 1.0,
-            Item::Raft => 8.0,
-            Item::CardCase => 0.2,
-            Item::PlayingCard => 0.01,
-            Item::RawMeat => 0.5,
-            Item::CookedMeat => 0.4,
-            Item::RawHide => 0.7,
-            Item::AnimalFat => 0.3,
-            _ => 0.1,
-        }
+        self.profile().weight
     }
 
     pub fn fuel_value(&self) -> Option<f32> {
-        match self {
-            Item::Firewood => Some(30.0),
-            Item::Kindling => Some(10.0),
-            Item::LitKindling => Some(10.0),
-            Item::Log => Some(60.0),
-            Item::Stick => Some(5.0),
-            Item::Pinecone => Some(5.0),
-            Item::Bamboo => Some(8.0),
-            Item::Paper => Some(1.0),
-            Item::Bark => Some(6.0),
-            Item::DryLeaves => Some(3.0),
-            Item::Charcoal => Some(40.0),
-            Item::OldBook => Some(8.0),
-            Item::AnimalFat => Some(20.0),
-            _ => None,
-        }
+        self.profile().fuel.map(|f| f.value)
+    }
+
+    /// Which part of a laid fire this feeds, and how much of it: tinder in
+    /// grams, kindling in pieces, bulk wood in fuel units.
+    pub fn fuel_stage(&self) -> Option<(FuelStage, f32)> {
+        self.profile().fuel.map(|f| (f.stage, f.amount))
+    }
+
+    /// Small things that can perch on a mount point: a shoulder, a prow, a ledge.
+    pub fn is_mountable(&self) -> bool {
+        matches!(self, Item::RubberDuck | Item::StrangeCompass)
     }
 
     pub fn is_flammable(&self) -> bool {
         self.fuel_value().is_some()
     }
 
-    pub fn is_tinder(&self) -> bool {
+    /// Things that can be eaten.
+    pub fn is_food(&self) -> bool {
+        self.nourishment().map(|n| !n.drink).unwrap_or(false)
+    }
+
+    /// Things that can be drunk.
+    pub fn is_drink(&self) -> bool {
+        self.nourishment().map(|n| n.drink).unwrap_or(false)
+    }
+
+    /// What eating or drinking it does, for things that can be.
+    pub fn nourishment(&self) -> Option<Nourishment> {
+        self.profile().nourishment
+    }
+
+    /// Whether it goes off if kept too long.
+    pub fn is_perishable(&self) -> bool {
+        self.profile().perishable
+    }
+
+    /// Tools, lights and the map, which come out of the pack into the hand
+    /// when used.
+    pub fn is_handheld(&self) -> bool {
         matches!(
             self,
-            Item::Kindling
+            Item::Axe
+                | Item::StoneAxe
+                | Item::Knife
+                | Item::StoneKnife
+                | Item::FishingRod
+                | Item::Shovel
+                | Item::FireflyJar
                 | Item::LitKindling
-                | Item::Pinecone
-                | Item::Bark
-                | Item::DryLeaves
-                | Item::OldBook
-                | Item::Paper
-                | Item::PlantFiber
+                | Item::AncientMap
         )
     }
 
+    /// Things there is only ever one of in the world: the duck, the card
+    /// case and the named books. Wherever one goes, it never stacks and is
+    /// never made again.
+    pub fn is_unique(&self) -> bool {
+        Item::UNIQUE.contains(self)
+    }
+
+    pub const UNIQUE: [Item; 6] = [
+        Item::RubberDuck,
+        Item::CardCase,
+        Item::TutorialBook,
+        Item::OldBook,
+        Item::DeathNote,
+        Item::BookOfFishing,
+    ];
+
+    /// The broad kind of thing this is, for sorting a pack by the armful.
+    pub fn category(&self) -> ItemCategory {
+        self.profile().category
+    }
+
     pub fn from_str(s: &str) -> Option<Item> {
         let query = s.trim();
         if query.is_empty() {
@@ -421,6 +323,38 @@ impl Item {
     }
 }
 
+/// How `store all <category>` and friends group items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemCategory {
+    Fuel,
+    Food,
+    Material,
+    Tool,
+    Other,
+}
+
+impl ItemCategory {
+    pub fn parse(s: &str) -> Option<ItemCategory> {
+        match s.trim().to_lowercase().as_str() {
+            "fuel" | "wood" => Some(ItemCategory::Fuel),
+            "food" | "foods" | "provisions" => Some(ItemCategory::Food),
+            "material" | "materials" => Some(ItemCategory::Material),
+            "tool" | "tools" => Some(ItemCategory::Tool),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ItemCategory::Fuel => "fuel",
+            ItemCategory::Food => "food",
+            ItemCategory::Material => "materials",
+            ItemCategory::Tool => "tools",
+            ItemCategory::Other => "odds and ends",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FireState {
     Cold,
@@ -467,93 +401,339 @@ impl FireState {
     }
 }
 
+/// The three layers of a fire: fine tinder takes the match, kindling grows
+/// the flame, and bulk wood only catches from a fire already burning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuelStage {
+    Tinder,
+    Kindling,
+    Bulk,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Fireplace {
     pub state: FireState,
+    /// Grams of tinder in the grate.
+    #[serde(default)]
+    pub tinder: f32,
+    /// Pieces of kindling in the grate.
+    #[serde(default)]
+    pub kindling: f32,
+    /// Bulk fuel: logs, split wood, charcoal.
     pub fuel: f32,
-    pub tinder_ready: bool,
+    /// Whether the bulk fuel has caught; until then it just sits there.
+    #[serde(default)]
+    pub bulk_lit: bool,
+    /// Saves from before staged fuel only knew tinder had been laid.
+    #[serde(default, rename = "tinder_ready", skip_serializing)]
+    legacy_tinder_ready: bool,
+    /// Coals banked under ash: slower burn, less heat.
+    #[serde(default)]
+    pub banked: bool,
+    /// Ticks of extra heat left from the last stoke.
+    #[serde(default)]
+    pub stoke_boost: u8,
+    /// Stokes left before the current fuel load needs replenishing.
+    #[serde(default = "Fireplace::default_stokes")]
+    pub stokes_left: u8,
 }
 
 impl Fireplace {
+    const STOKES_PER_LOAD: u8 = 2;
+    /// Tinder and kindling needed under the fuel before a match will take.
+    const TINDER_TO_LIGHT: f32 = 5.0;
+    const KINDLING_TO_LIGHT: f32 = 1.0;
+    /// Burned per ten minutes once alight.
+    const TINDER_BURN: f32 = 10.0;
+    const KINDLING_BURN: f32 = 0.5;
+    /// Bulk fuel above which a fire roars, and below which it falls to embers.
+    const ROARING_FUEL: f32 = 40.0;
+    const EMBER_FUEL: f32 = 10.0;
+
+    fn default_stokes() -> u8 {
+        Self::STOKES_PER_LOAD
+    }
+
     pub fn new() -> Self {
         Self {
             state: FireState::Cold,
+            tinder: 0.0,
+            kindling: 0.0,
             fuel: 0.0,
-            tinder_ready: false,
+            bulk_lit: false,
+            legacy_tinder_ready: false,
+            banked: false,
+            stoke_boost: 0,
+            stokes_left: Self::STOKES_PER_LOAD,
+        }
+    }
+
+    /// Bring a fireplace saved under the single-fuel model into stages: a
+    /// lit fire's fuel is already burning, and a cold one that had tinder
+    /// laid gets the tinder and kindling it would have needed.
+    pub fn migrate_legacy(&mut self) {
+        let unstaged = self.tinder <= 0.0 && self.kindling <= 0.0 && !self.bulk_lit;
+        if unstaged && self.state != FireState::Cold {
+            self.bulk_lit = true;
+        }
+        if std::mem::take(&mut self.legacy_tinder_ready) && self.state == FireState::Cold {
+            self.tinder = self.tinder.max(Self::TINDER_TO_LIGHT * 2.0);
+            self.kindling = self.kindling.max(Self::KINDLING_TO_LIGHT);
+        }
+    }
+
+    fn add_stage(&mut self, stage: FuelStage, amount: f32) {
+        match stage {
+            FuelStage::Tinder => self.tinder += amount,
+            FuelStage::Kindling => self.kindling += amount,
+            FuelStage::Bulk => self.fuel += amount,
+        }
+        if stage == FuelStage::Bulk {
+            self.stokes_left = Self::STOKES_PER_LOAD;
+        }
+        self.update_state();
+    }
+
+    /// Lay `item` in the grate or feed it to the fire. Returns which stage
+    /// it went to, or `None` if it won't burn.
+    pub fn add_fuel_item(&mut self, item: Item) -> Option<FuelStage> {
+        let (stage, amount) = item.fuel_stage()?;
+        // Kindling that's already alight lights whatever tinder it lands on.
+        if item == Item::LitKindling && self.state == FireState::Cold {
+            self.state = FireState::Smoldering;
         }
+        self.add_stage(stage, amount);
+        Some(stage)
+    }
+
+    /// Green wood gives about half its heat and mostly smoke.
+    pub fn add_green_fuel_item(&mut self, item: Item) -> Option<FuelStage> {
+        let (stage, amount) = item.fuel_stage()?;
+        self.add_stage(stage, amount * 0.5);
+        Some(stage)
     }
 
-    pub fn add_fuel_item(&mut self, item: Item) -> bool {
-        if let Some(value) = item.fuel_value() {
-            self.fuel += value;
-            if item.is_tinder() {
-                self.tinder_ready = true;
+    /// Tinder and kindling are both laid: a match will take.
+    pub fn is_laid(&self) -> bool {
+        self.state == FireState::Cold
+            && self.tinder >= Self::TINDER_TO_LIGHT
+            && self.kindling >= Self::KINDLING_TO_LIGHT
+    }
+
+    /// Put a match to the grate. The tinder catches only with kindling over
+    /// it for the flame to climb into.
+    pub fn ignite(&mut self) -> Result<(), String> {
+        if self.state != FireState::Cold {
+            return Err("The fire is already lit.".to_string());
+        }
+        if self.tinder < Self::TINDER_TO_LIGHT {
+            return Err(if self.fuel > 0.0 || self.kindling > 0.0 {
+                "The match just blackens the wood. It needs tinder underneath to catch: paper, dry leaves, bark, a pinecone."
+                    .to_string()
+            } else {
+                "You need tinder and kindling in the grate before a match will do anything."
+                    .to_string()
+            });
+        }
+        if self.kindling < Self::KINDLING_TO_LIGHT {
+            return Err(
+                "The tinder flares and curls away to nothing. Lay kindling over it so the flame has somewhere to go."
+                    .to_string(),
+            );
+        }
+        self.state = FireState::Smoldering;
+        Ok(())
+    }
+
+    /// What adding fuel of `stage` just did, given how the fire stands now.
+    pub fn placement_note(&self, stage: FuelStage) -> &'static str {
+        match (self.state, stage) {
+            (FireState::Cold, _) if self.is_laid() => {
+                "Tinder and kindling are laid; it's ready for a match."
             }
-            self.update_state();
-            true
+            (FireState::Cold, FuelStage::Tinder) => {
+                "It'll take a match once there's kindling laid over it."
+            }
+            (FireState::Cold, FuelStage::Kindling) => {
+                "It still needs something fine underneath to catch: paper, dry leaves, bark."
+            }
+            (FireState::Cold, FuelStage::Bulk) => {
+                "Logs alone won't take a match; lay tinder and kindling under them."
+            }
+            (FireState::Smoldering, FuelStage::Tinder) => "It flares briefly among the embers.",
+            (FireState::Smoldering, FuelStage::Kindling) => {
+                "The small wood crackles as it catches."
+            }
+            (FireState::Smoldering, FuelStage::Bulk) if !self.bulk_lit => {
+                "It sits heavily on the weak flames; they'll need kindling to climb into it."
+            }
+            (FireState::Smoldering, FuelStage::Bulk) => "It starts to char at the edges.",
+            (_, FuelStage::Tinder) => "It flashes up and is gone in a moment.",
+            (_, FuelStage::Kindling) => "It catches at once, and the flames jump higher.",
+            (_, FuelStage::Bulk) => "The flames wrap around it.",
+        }
+    }
+
+    /// Rake the coals and open up the fuel: a short burst of heat, and a smoldering
+    /// fire with something left to burn catches properly again.
+    pub fn stoke(&mut self) -> Result<String, String> {
+        if self.state == FireState::Cold {
+            return Err("There's nothing burning to stoke.".to_string());
+        }
+        if self.stokes_left == 0 {
+            return Err(
+                "You've already rearranged this load as well as it'll go. It needs fresh fuel."
+                    .to_string(),
+            );
+        }
+        self.stokes_left -= 1;
+        self.stoke_boost = 3;
+        let was_banked = std::mem::take(&mut self.banked);
+        let prev = self.state;
+        self.update_state();
+
+        let mut msg = if was_banked {
+            "You rake the banked coals out from under the ash and open up the fire.".to_string()
         } else {
-            false
+            "You shift the logs and rake the coals, letting air in underneath.".to_string()
+        };
+        if prev == FireState::Smoldering && self.state != FireState::Smoldering {
+            msg.push_str(" Flames climb back up through the fuel.");
+        } else {
+            msg.push_str(" The fire brightens for a while.");
         }
+        Ok(msg)
     }
 
-    pub fn ignite(&mut self) -> bool {
-        if self.state != FireState::Cold || self.fuel < 5.0 || !self.tinder_ready {
-            return false;
+    /// Bury the coals under ash for a long, low burn.
+    pub fn bank(&mut self) -> Result<String, String> {
+        if self.state == FireState::Cold {
+            return Err("There are no live coals to bank.".to_string());
         }
-        self.state = FireState::Smoldering;
-        self.tinder_ready = false;
-        true
+        if self.banked {
+            return Err("The fire is already banked low under its ash.".to_string());
+        }
+        self.banked = true;
+        self.stoke_boost = 0;
+        Ok(
+            "You push the coals together and cover them with a blanket of ash. The fire settles into a low, patient glow that should last the night."
+                .to_string(),
+        )
     }
 
-    pub fn clear_tinder(&mut self) {
-        self.tinder_ready = false;
+    /// Put the fire out. Returns how much charcoal can be salvaged from the fuel
+    /// that hadn't burned through yet.
+    pub fn extinguish(&mut self) -> u32 {
+        let charcoal = ((self.fuel / 20.0).floor() as u32).min(3);
+        self.state = FireState::Cold;
+        self.tinder = 0.0;
+        self.kindling = 0.0;
+        self.fuel = 0.0;
+        self.bulk_lit = false;
+        self.banked = false;
+        self.stoke_boost = 0;
+        self.stokes_left = Self::STOKES_PER_LOAD;
+        charcoal
     }
 
-    pub fn update(&mut self) -> Option<String> {
+    /// Bulk fuel burned per ten minutes; nothing until the bulk has caught.
+    pub fn fuel_per_tick(&self) -> f32 {
+        if !self.bulk_lit {
+            return 0.0;
+        }
         let consumption = self.state.fuel_consumption();
+        if self.banked {
+            consumption * 0.4
+        } else {
+            consumption
+        }
+    }
+
+    /// Burn one tick's worth of fuel; `scale` is the tick's length relative
+    /// to ten minutes. Tinder goes first, kindling once the flame has
+    /// climbed into it, and bulk wood only after it has caught.
+    pub fn update(&mut self, scale: f32) -> Option<String> {
+        if self.state != FireState::Cold {
+            self.tinder = (self.tinder - Self::TINDER_BURN * scale).max(0.0);
+            if self.state != FireState::Smoldering || self.bulk_lit {
+                let rate = if self.banked { 0.4 } else { 1.0 };
+                self.kindling = (self.kindling - Self::KINDLING_BURN * scale * rate).max(0.0);
+            }
+        }
+        let consumption = self.fuel_per_tick() * scale;
         if consumption > 0.0 {
             self.fuel = (self.fuel - consumption).max(0.0);
         }
+        self.stoke_boost = self.stoke_boost.saturating_sub(1);
 
         let prev_state = self.state;
         self.update_state();
 
         if prev_state != FireState::Cold && self.state == FireState::Cold {
+            self.banked = false;
+            self.stoke_boost = 0;
             Some("The fire dies down, leaving only faint wisps of smoke.".to_string())
         } else {
             None
         }
     }
 
+    /// Settle the fire's state from what's left in each stage. Only a match
+    /// brings a cold hearth to life.
     fn update_state(&mut self) {
-        self.state = match self.fuel {
-            f if f <= 0.0 => FireState::Cold,
-            f if f < 10.0 => {
-                if self.state == FireState::Cold {
-                    FireState::Cold
-                } else {
-                    FireState::Smoldering
-                }
-            }
-            f if f < 40.0 => {
-                if self.state == FireState::Cold {
-                    FireState::Cold
-                } else {
-                    FireState::Burning
-                }
-            }
-            _ => {
-                if self.state == FireState::Cold {
-                    FireState::Cold
-                } else {
-                    FireState::Roaring
-                }
-            }
+        if self.state == FireState::Cold {
+            return;
+        }
+        // Bulk wood only catches from a fire that is already burning well
+        if matches!(self.state, FireState::Burning | FireState::Roaring) && self.fuel > 0.0 {
+            self.bulk_lit = true;
+        }
+        let bulk = if self.bulk_lit { self.fuel } else { 0.0 };
+        self.state = if bulk >= Self::ROARING_FUEL {
+            FireState::Roaring
+        } else if bulk >= Self::EMBER_FUEL
+            || self.kindling > 0.0
+            || (bulk > 0.0 && self.stoke_boost > 0)
+        {
+            FireState::Burning
+        } else if bulk > 0.0 || self.tinder > 0.0 {
+            FireState::Smoldering
+        } else {
+            FireState::Cold
         };
+        if self.state == FireState::Cold {
+            self.bulk_lit = false;
+        }
     }
 
     pub fn heat_output(&self) -> f32 {
-        self.state.heat_output()
+        let base = self.state.heat_output();
+        // Kindling alone burns bright but thin
+        let base = if self.state == FireState::Burning && !self.bulk_lit {
+            base * 0.6
+        } else {
+            base
+        };
+        let base = if self.banked { base * 0.6 } else { base };
+        if self.stoke_boost > 0 {
+            base + 5.0
+        } else {
+            base
+        }
+    }
+
+    pub fn description(&self) -> String {
+        if self.banked && self.state != FireState::Cold {
+            return "The fire is banked: live coals glow dully beneath a careful layer of ash, giving off a gentle, steady warmth.".to_string();
+        }
+        if self.is_laid() {
+            return "The hearth is cold, but tinder and kindling are laid in the grate, waiting for a match.".to_string();
+        }
+        if self.state == FireState::Cold && self.fuel > 0.0 {
+            return "Wood sits in the cold grate over old ash, with nothing laid beneath it to catch."
+                .to_string();
+        }
+        self.state.description().to_string()
     }
 }
 
@@ -566,11 +746,17 @@ impl Default for Fireplace {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChoppingBlock {
     pub has_log: bool,
+    /// How long the log on the block has dried, if it's still green.
+    #[serde(default)]
+    pub green: Option<u32>,
 }
 
 impl ChoppingBlock {
     pub fn new() -> Self {
-        Self { has_log: false }
+        Self {
+            has_log: false,
+            green: None,
+        }
     }
 }
 
@@ -584,11 +770,24 @@ impl Default for ChoppingBlock {
 pub struct Cabin {
     pub door_open: bool,
     pub fireplace: Fireplace,
-    pub items: Vec<Item>,
+    pub items: LocationItems,
     #[serde(default)]
-    pub table_items: Vec<Item>,
+    pub table_items: LocationItems,
     #[serde(default)]
     pub book_ids: Vec<String>,
+    #[serde(default)]
+    pub windows_open: bool,
+    #[serde(default)]
+    pub shutters_closed: bool,
+    /// 0-100; how swept and ordered the main room is.
+    #[serde(default = "Cabin::default_tidiness")]
+    pub tidiness: f32,
+    /// A weather vane mounted on the roof ridge.
+    #[serde(default)]
+    pub weather_vane: bool,
+    /// Heaps of snow blown in through the door or windows.
+    #[serde(default)]
+    pub drifts: u32,
 }
 
 impl Cabin {
@@ -596,60 +795,90 @@ impl Cabin {
         Self {
             door_open: false,
             fireplace: Fireplace::new(),
-            items: vec![
-                Item::Matchbox,
-                Item::Kindling,
-                Item::Kindling,
-                Item::OldBook,
-                Item::TutorialBook,
-                Item::DeathNote,
-                Item::StrangeCompass,
-                Item::AncientMap,
-                Item::TeaCup,
-                Item::WoolBlanket,
-                Item::Kettle,
-                Item::WildHerbs,
-            ],
-            table_items: vec![Item::RubberDuck, Item::CardCase],
+            items: LocationItems {
+                items: vec![
+                    (Item::Matchbox, 1),
+                    (Item::DryLeaves, 1),
+                    (Item::Kindling, 2),
+                    (Item::OldBook, 1),
+                    (Item::TutorialBook, 1),
+                    (Item::DeathNote, 1),
+                    (Item::StrangeCompass, 1),
+                    (Item::AncientMap, 1),
+                    (Item::WoolBlanket, 1),
+                ],
+            },
+            table_items: LocationItems {
+                items: vec![(Item::RubberDuck, 1), (Item::CardCase, 1)],
+            },
             book_ids: Vec::new(),
+            windows_open: false,
+            shutters_closed: false,
+            tidiness: Self::default_tidiness(),
+            weather_vane: false,
+            drifts: 0,
         }
     }
 
+    fn default_tidiness() -> f32 {
+        70.0
+    }
+
+    /// Slow settling of dust, plus extra wear from clutter and blown-in snow.
+    pub fn decay_tidiness(&mut self) {
+        let mut loss = 0.02;
+        if self.items.total() > 12 {
+            loss += 0.02;
+        }
+        loss += self.snow_drifts() as f32 * 0.05;
+        self.tidiness = (self.tidiness - loss).clamp(0.0, 100.0);
+    }
+
+    /// Sweep out snow, stack the floor items, and restore order. Returns the
+    /// number of snow drifts cleared.
+    pub fn tidy(&mut self) -> usize {
+        let drifts = self.snow_drifts();
+        self.drifts = 0;
+        self.items.items.sort_by_key(|(i, _)| i.name());
+        self.tidiness = 100.0;
+        drifts
+    }
+
+    pub fn is_orderly(&self) -> bool {
+        self.tidiness >= 70.0
+    }
+
+    /// Whether any opening is letting outside air into the main room.
+    pub fn is_drafty(&self) -> bool {
+        self.door_open || self.windows_open
+    }
+
+    pub fn snow_drifts(&self) -> usize {
+        self.drifts as usize
+    }
+
     pub fn has_item(&self, item: &Item) -> bool {
         self.items.contains(item)
     }
 
     pub fn take_item(&mut self, item: &Item) -> bool {
-        if let Some(idx) = self.items.iter().position(|i| i == item) {
-            self.items.remove(idx);
-            true
-        } else {
-            false
-        }
+        self.items.take(item)
     }
 
     pub fn add_item(&mut self, item: Item) {
-        self.items.push(item);
+        self.items.add(item, 1);
     }
 
     pub fn add_table_item(&mut self, item: Item) {
-        self.table_items.push(item);
+        self.table_items.add(item, 1);
     }
 
     pub fn take_table_item(&mut self, item: &Item) -> bool {
-        if let Some(idx) = self.table_items.iter().position(|i| i == item) {
-            self.table_items.remove(idx);
-            true
-        } else {
-            false
-        }
+        self.table_items.take(item)
     }
 
     pub fn table_item_names(&self) -> Vec<String> {
-        self.table_items
-            .iter()
-            .map(|i| i.name().to_string())
-            .collect()
+        self.table_items.labels(|i| i.name().to_string())
     }
 }
 
@@ -665,17 +894,159 @@ pub struct WoodShed {
     pub firewood: u32,
     pub axe_on_floor: bool,
     pub chopping_block: ChoppingBlock,
+    #[serde(default = "WoodShed::default_door_open")]
+    pub door_open: bool,
+    /// The part of `logs` and `firewood` that is still green. Older saves
+    /// have none, so their stock counts as seasoned.
+    #[serde(default)]
+    pub green: GreenWood,
 }
 
 impl WoodShed {
+    fn default_door_open() -> bool {
+        true
+    }
+
     pub fn new() -> Self {
         Self {
             logs: 6,
             firewood: 0,
             axe_on_floor: true,
             chopping_block: ChoppingBlock::new(),
+            door_open: Self::default_door_open(),
+            green: GreenWood::default(),
+        }
+    }
+
+    pub fn seasoned(&self, item: &Item) -> u32 {
+        let total = match item {
+            Item::Log => self.logs,
+            Item::Firewood => self.firewood,
+            _ => 0,
+        };
+        total.saturating_sub(self.green.count(item))
+    }
+
+    /// Take a log or piece of firewood out of the stack, seasoned wood first.
+    /// Returns `Some(ticks dried)` when only green wood was left.
+    pub fn take_wood(&mut self, item: &Item) -> Option<Option<u32>> {
+        let seasoned = self.seasoned(item);
+        let stock = match item {
+            Item::Log => &mut self.logs,
+            Item::Firewood => &mut self.firewood,
+            _ => return None,
+        };
+        if *stock == 0 {
+            return None;
+        }
+        *stock -= 1;
+        if seasoned > 0 {
+            return Some(None);
+        }
+        Some(self.green.take(item))
+    }
+}
+
+/// A stack of freshly cut wood drying out together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WoodBatch {
+    pub item: Item,
+    pub qty: u32,
+    pub seasoned_ticks: u32,
+}
+
+/// Green logs and firewood, tracked per batch so each dries on its own
+/// clock. Anything not listed here has already seasoned.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GreenWood {
+    pub batches: Vec<WoodBatch>,
+}
+
+impl GreenWood {
+    /// Ten-minute ticks a batch needs under cover before it burns properly
+    /// (three days).
+    pub const SEASONING_TICKS: u32 = 3 * 24 * 6;
+
+    pub fn add(&mut self, item: Item, qty: u32, seasoned_ticks: u32) {
+        if qty == 0 {
+            return;
+        }
+        if let Some(batch) = self
+            .batches
+            .iter_mut()
+            .find(|b| b.item == item && b.seasoned_ticks == seasoned_ticks)
+        {
+            batch.qty += qty;
+        } else {
+            self.batches.push(WoodBatch {
+                item,
+                qty,
+                seasoned_ticks,
+            });
         }
     }
+
+    pub fn count(&self, item: &Item) -> u32 {
+        self.batches
+            .iter()
+            .filter(|b| &b.item == item)
+            .map(|b| b.qty)
+            .sum()
+    }
+
+    /// Take one green piece, the driest first, returning how long it has dried.
+    pub fn take(&mut self, item: &Item) -> Option<u32> {
+        let idx = self
+            .batches
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| &b.item == item && b.qty > 0)
+            .max_by_key(|(_, b)| b.seasoned_ticks)
+            .map(|(i, _)| i)?;
+        let ticks = self.batches[idx].seasoned_ticks;
+        self.batches[idx].qty -= 1;
+        if self.batches[idx].qty == 0 {
+            self.batches.remove(idx);
+        }
+        Some(ticks)
+    }
+
+    /// Forget the freshest pieces until at most `max` green `item` remain,
+    /// e.g. after some were burned, whittled or built with.
+    pub fn trim(&mut self, item: &Item, max: u32) {
+        let mut excess = self.count(item).saturating_sub(max);
+        while excess > 0 {
+            let Some(idx) = self
+                .batches
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| &b.item == item && b.qty > 0)
+                .min_by_key(|(_, b)| b.seasoned_ticks)
+                .map(|(i, _)| i)
+            else {
+                break;
+            };
+            let taken = excess.min(self.batches[idx].qty);
+            self.batches[idx].qty -= taken;
+            excess -= taken;
+            if self.batches[idx].qty == 0 {
+                self.batches.remove(idx);
+            }
+        }
+    }
+
+    /// Dry every batch a little, returning how many pieces finished seasoning.
+    pub fn age(&mut self, ticks: u32) -> u32 {
+        let mut seasoned = 0;
+        for batch in &mut self.batches {
+            batch.seasoned_ticks += ticks;
+            if batch.seasoned_ticks >= Self::SEASONING_TICKS {
+                seasoned += batch.qty;
+            }
+        }
+        self.batches.retain(|b| b.seasoned_ticks < Self::SEASONING_TICKS);
+        seasoned
+    }
 }
 
 impl Default for WoodShed {
@@ -684,19 +1055,56 @@ impl Default for WoodShed {
     }
 }
 
+/// Things lying somewhere, stacked by kind. Older saves kept some of these
+/// as a plain list with one entry per thing; those load stacked.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "StoredItems")]
 pub struct LocationItems {
     pub items: Vec<(Item, u32)>,
 }
 
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredItems {
+    Stacked { items: Vec<(Item, u32)> },
+    Listed(Vec<Item>),
+}
+
+impl From<StoredItems> for LocationItems {
+    fn from(stored: StoredItems) -> Self {
+        let mut items = LocationItems::new();
+        match stored {
+            StoredItems::Stacked { items: stacks } => {
+                for (item, qty) in stacks {
+                    items.add(item, qty);
+                }
+            }
+            StoredItems::Listed(list) => {
+                for item in list {
+                    items.add(item, 1);
+                }
+            }
+        }
+        items
+    }
+}
+
 impl LocationItems {
     pub fn new() -> Self {
         Self { items: Vec::new() }
     }
 
+    /// Add to the stack of that kind. A one-of-a-kind item already here
+    /// stays the only one.
     pub fn add(&mut self, item: Item, qty: u32) {
+        if qty == 0 {
+            return;
+        }
+        let qty = if item.is_unique() { 1 } else { qty };
         if let Some((_, existing)) = self.items.iter_mut().find(|(i, _)| i == &item) {
-            *existing += qty;
+            if !item.is_unique() {
+                *existing += qty;
+            }
         } else {
             self.items.push((item, qty));
         }
@@ -714,6 +1122,31 @@ impl LocationItems {
         }
     }
 
+    /// Take the whole stack of a kind, returning how many there were.
+    pub fn take_all(&mut self, item: &Item) -> u32 {
+        match self.items.iter().position(|(i, _)| i == item) {
+            Some(idx) => self.items.remove(idx).1,
+            None => 0,
+        }
+    }
+
+    pub fn contains(&self, item: &Item) -> bool {
+        self.count(item) > 0
+    }
+
+    pub fn count(&self, item: &Item) -> u32 {
+        self.items
+            .iter()
+            .filter(|(i, _)| i == item)
+            .map(|(_, q)| *q)
+            .sum()
+    }
+
+    /// Every thing here, counted one by one.
+    pub fn total(&self) -> u32 {
+        self.items.iter().map(|(_, q)| *q).sum()
+    }
+
     pub fn list(&self) -> Vec<&Item> {
         self.items
             .iter()
@@ -721,6 +1154,19 @@ impl LocationItems {
             .map(|(i, _)| i)
             .collect()
     }
+
+    /// Each stack as it reads in a description: "apple x10", or just the
+    /// name when there's one.
+    pub fn labels(&self, name: impl Fn(&Item) -> String) -> Vec<String> {
+        self.items
+            .iter()
+            .filter(|(_, q)| *q > 0)
+            .map(|(i, q)| match q {
+                1 => name(i),
+                _ => format!("{} x{}", name(i), q),
+            })
+            .collect()
+    }
 }
 
 impl Default for LocationItems {
@@ -728,3 +1174,62 @@ impl Default for LocationItems {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drifts_are_not_floor_items() {
+        let mut cabin = Cabin::new();
+        let floor = cabin.items.total();
+        cabin.drifts = 2;
+        assert_eq!(cabin.snow_drifts(), 2);
+        assert_eq!(cabin.items.total(), floor);
+        assert_eq!(cabin.tidy(), 2);
+        assert_eq!(cabin.snow_drifts(), 0);
+    }
+
+    #[test]
+    fn saves_without_drifts_load_with_none() {
+        let mut json = serde_json::to_value(Cabin::new()).unwrap();
+        json.as_object_mut().unwrap().remove("drifts");
+        let cabin: Cabin = serde_json::from_value(json).unwrap();
+        assert_eq!(cabin.snow_drifts(), 0);
+    }
+
+    #[test]
+    fn logs_alone_will_not_light_but_a_laid_fire_catches_and_takes_them() {
+        // However many logs go in, a match on its own does nothing
+        let mut logs_only = Fireplace::new();
+        for _ in 0..3 {
+            assert_eq!(logs_only.add_fuel_item(Item::Log), Some(FuelStage::Bulk));
+        }
+        assert!(!logs_only.is_laid());
+        assert!(logs_only.ignite().is_err());
+        assert_eq!(logs_only.state, FireState::Cold);
+        logs_only.update(1.0);
+        assert_eq!(logs_only.state, FireState::Cold);
+
+        // Tinder without kindling flares and goes out
+        let mut staged = Fireplace::new();
+        staged.add_fuel_item(Item::Pinecone);
+        assert!(staged.ignite().is_err());
+
+        // Tinder, kindling, then logs: the match takes and the logs catch
+        staged.add_fuel_item(Item::Kindling);
+        staged.add_fuel_item(Item::Log);
+        assert!(staged.is_laid());
+        assert!(staged.ignite().is_ok());
+        assert_eq!(staged.state, FireState::Smoldering);
+        assert!(!staged.bulk_lit);
+        staged.update(1.0);
+        assert_eq!(staged.state, FireState::Burning);
+        staged.update(1.0);
+        assert!(staged.bulk_lit);
+        let before = staged.fuel;
+        staged.update(1.0);
+        assert!(staged.fuel < before);
+        assert!(staged.heat_output() > 0.0);
+    }
+}
@@ -1,10 +1,83 @@
-use super::body::{Body, BodyHitEvent};
+use super::body::{Body, BodyHitEvent, BodyPartKind, PartCondition};
 use super::blueprint::Blueprint;
 use super::objects::Item;
 use crate::world::{Direction, Position};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Sets of tiles, like those visited, are saved as a bitset over the
+/// rectangle they span, one bit per tile row by row and written as hex,
+/// rather than a list of every position. Older saves hold the plain list.
+mod position_bits {
+    use crate::world::Position;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashSet;
+
+    #[derive(Serialize, Deserialize)]
+    struct Bits {
+        top: i32,
+        left: i32,
+        width: i32,
+        bits: String,
+    }
+
+    pub fn serialize<S>(visited: &HashSet<Position>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let top = visited.iter().map(|p| p.row).min().unwrap_or(0);
+        let bottom = visited.iter().map(|p| p.row).max().unwrap_or(-1);
+        let left = visited.iter().map(|p| p.col).min().unwrap_or(0);
+        let right = visited.iter().map(|p| p.col).max().unwrap_or(-1);
+        let width = right - left + 1;
+        let tiles = (width.max(0) * (bottom - top + 1).max(0)) as usize;
+        let mut nibbles = vec![0u8; tiles.div_ceil(4)];
+        for p in visited {
+            let i = ((p.row - top) * width + (p.col - left)) as usize;
+            nibbles[i / 4] |= 8 >> (i % 4);
+        }
+        let bits = nibbles
+            .iter()
+            .map(|n| char::from_digit(*n as u32, 16).unwrap_or('0'))
+            .collect();
+        Bits {
+            top,
+            left,
+            width: width.max(0),
+            bits,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<HashSet<Position>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Saved {
+            Bits(Bits),
+            Legacy(Vec<Position>),
+        }
+        Ok(match Saved::deserialize(deserializer)? {
+            Saved::Legacy(list) => list.into_iter().collect(),
+            Saved::Bits(b) if b.width <= 0 => HashSet::new(),
+            Saved::Bits(b) => b
+                .bits
+                .chars()
+                .enumerate()
+                .flat_map(|(n, c)| {
+                    let nibble = c.to_digit(16).unwrap_or(0);
+                    (0..4)
+                        .filter(move |bit| nibble & (8 >> bit) != 0)
+                        .map(move |bit| (n * 4 + bit) as i32)
+                })
+                .map(|i| Position::new(b.top + i / b.width, b.left + i % b.width))
+                .collect(),
+        })
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillProgress {
@@ -18,16 +91,93 @@ impl SkillProgress {
     }
 }
 
-const SKILL_IDS: &[&str] = &[
-    "woodcutting",
-    "fire_making",
-    "observation",
-    "foraging",
-    "stonemasonry",
-    "survival",
-    "tailoring",
-    "cooking",
-];
+/// The skills the player trains by doing. Every gain and every check goes
+/// through one of these, so a misspelt skill can't slip through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Skill {
+    Woodcutting,
+    FireMaking,
+    Observation,
+    Foraging,
+    Stonemasonry,
+    Survival,
+    Tailoring,
+    Cooking,
+}
+
+impl Skill {
+    pub const ALL: [Skill; 8] = [
+        Skill::Woodcutting,
+        Skill::FireMaking,
+        Skill::Observation,
+        Skill::Foraging,
+        Skill::Stonemasonry,
+        Skill::Survival,
+        Skill::Tailoring,
+        Skill::Cooking,
+    ];
+
+    /// The key the skill's progress is saved under.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Skill::Woodcutting => "woodcutting",
+            Skill::FireMaking => "fire_making",
+            Skill::Observation => "observation",
+            Skill::Foraging => "foraging",
+            Skill::Stonemasonry => "stonemasonry",
+            Skill::Survival => "survival",
+            Skill::Tailoring => "tailoring",
+            Skill::Cooking => "cooking",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Skill::Woodcutting => "woodcutting",
+            Skill::FireMaking => "fire making",
+            Skill::Observation => "observation",
+            Skill::Foraging => "foraging",
+            Skill::Stonemasonry => "stonemasonry",
+            Skill::Survival => "survival",
+            Skill::Tailoring => "tailoring",
+            Skill::Cooking => "cooking",
+        }
+    }
+
+    /// What raises the skill, for the skills listing.
+    pub fn trained_by(&self) -> &'static str {
+        match self {
+            Skill::Woodcutting => "chopping trees, splitting logs on the block, whittling kindling",
+            Skill::FireMaking => "lighting fires, feeding and tending them, signal fires",
+            Skill::Observation => {
+                "searching, approaching and catching animals, fishing, skipping stones, paddling"
+            }
+            Skill::Foraging => "gathering wild plants, brewing herbal tea",
+            Skill::Stonemasonry => "knapping sharp stones, making stone tools",
+            Skill::Survival => "fishing, first aid, butchering, building a campfire, hunting",
+            Skill::Tailoring => "twisting cordage, making paper and books, working hides",
+            Skill::Cooking => "cooking meals over the hearth",
+        }
+    }
+
+    /// Work for the hands, which injured arms make clumsy.
+    pub fn uses_hands(&self) -> bool {
+        !matches!(self, Skill::Observation)
+    }
+}
+
+/// Experience and levels each skill has gained since the day began.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkillGains {
+    pub day: u32,
+    #[serde(default)]
+    pub xp: HashMap<String, u32>,
+    #[serde(default)]
+    pub levels: HashMap<String, u8>,
+}
+
+/// Observation points a completed set of field notes is worth for good.
+pub const NATURALIST_BONUS: f32 = 5.0;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Skills {
@@ -45,96 +195,126 @@ pub struct Skills {
     pub cooking: u8, // 1-100
     #[serde(default)]
     pub progress: HashMap<String, SkillProgress>,
+    /// What today's practice has earned, cleared each morning.
+    #[serde(default)]
+    pub today: SkillGains,
 }
 
 impl Skills {
     pub fn new() -> Self {
         let mut progress = HashMap::new();
-        for id in SKILL_IDS {
-            progress.insert((*id).to_string(), SkillProgress::new(10));
+        for skill in Skill::ALL {
+            progress.insert(
+                skill.id().to_string(),
+                SkillProgress::new(Self::STARTING_LEVEL),
+            );
         }
         Self {
-            woodcutting: 10,
-            fire_making: 10,
-            observation: 10,
-            foraging: 10,
-            stonemasonry: 10,
-            survival: 10,
-            tailoring: 10,
-            cooking: 10,
+            woodcutting: Self::STARTING_LEVEL,
+            fire_making: Self::STARTING_LEVEL,
+            observation: Self::STARTING_LEVEL,
+            foraging: Self::STARTING_LEVEL,
+            stonemasonry: Self::STARTING_LEVEL,
+            survival: Self::STARTING_LEVEL,
+            tailoring: Self::STARTING_LEVEL,
+            cooking: Self::STARTING_LEVEL,
             progress,
+            today: SkillGains::default(),
         }
     }
 
+    /// Where every skill starts.
+    pub const STARTING_LEVEL: u8 = 10;
+
     fn xp_to_next(level: u8) -> u32 {
         10 + level as u32 * 5
     }
 
-    fn level_slot(&mut self, skill: &str) -> Option<&mut u8> {
+    fn level_slot(&mut self, skill: Skill) -> &mut u8 {
         match skill {
-            "woodcutting" => Some(&mut self.woodcutting),
-            "fire_making" => Some(&mut self.fire_making),
-            "observation" => Some(&mut self.observation),
-            "foraging" => Some(&mut self.foraging),
-            "stonemasonry" => Some(&mut self.stonemasonry),
-            "survival" => Some(&mut self.survival),
-            "tailoring" => Some(&mut self.tailoring),
-            "cooking" => Some(&mut self.cooking),
-            _ => None,
+            Skill::Woodcutting => &mut self.woodcutting,
+            Skill::FireMaking => &mut self.fire_making,
+            Skill::Observation => &mut self.observation,
+            Skill::Foraging => &mut self.foraging,
+            Skill::Stonemasonry => &mut self.stonemasonry,
+            Skill::Survival => &mut self.survival,
+            Skill::Tailoring => &mut self.tailoring,
+            Skill::Cooking => &mut self.cooking,
         }
     }
 
-    fn field_level(&self, skill: &str) -> u8 {
+    fn field_level(&self, skill: Skill) -> u8 {
         match skill {
-            "woodcutting" => self.woodcutting,
-            "fire_making" => self.fire_making,
-            "observation" => self.observation,
-            "foraging" => self.foraging,
-            "stonemasonry" => self.stonemasonry,
-            "survival" => self.survival,
-            "tailoring" => self.tailoring,
-            "cooking" => self.cooking,
-            _ => 0,
+            Skill::Woodcutting => self.woodcutting,
+            Skill::FireMaking => self.fire_making,
+            Skill::Observation => self.observation,
+            Skill::Foraging => self.foraging,
+            Skill::Stonemasonry => self.stonemasonry,
+            Skill::Survival => self.survival,
+            Skill::Tailoring => self.tailoring,
+            Skill::Cooking => self.cooking,
         }
     }
 
-    fn progress_entry(&mut self, skill: &str) -> Option<&mut SkillProgress> {
-        if !SKILL_IDS.contains(&skill) {
-            return None;
-        }
+    fn progress_entry(&mut self, skill: Skill) -> &mut SkillProgress {
         let seed_level = self.field_level(skill);
-        Some(
-            self.progress
-                .entry(skill.to_string())
-                .or_insert_with(|| SkillProgress::new(seed_level)),
-        )
+        self.progress
+            .entry(skill.id().to_string())
+            .or_insert_with(|| SkillProgress::new(seed_level))
     }
 
-    pub fn improve(&mut self, skill: &str, amount: u8) {
-        let Some(level_after) = ({
-            let Some(progress) = self.progress_entry(skill) else {
-                return;
-            };
-            progress.xp = progress.xp.saturating_add(amount as u32);
-            while progress.xp >= Self::xp_to_next(progress.level) && progress.level < 100 {
-                progress.xp -= Self::xp_to_next(progress.level);
-                progress.level = progress.level.saturating_add(1);
-            }
-            Some(progress.level)
-        }) else {
-            return;
-        };
-        if let Some(level_ref) = self.level_slot(skill) {
-            *level_ref = level_after;
+    pub fn improve(&mut self, skill: Skill, amount: u8) {
+        let progress = self.progress_entry(skill);
+        let level_before = progress.level;
+        progress.xp = progress.xp.saturating_add(amount as u32);
+        while progress.xp >= Self::xp_to_next(progress.level) && progress.level < 100 {
+            progress.xp -= Self::xp_to_next(progress.level);
+            progress.level = progress.level.saturating_add(1);
+        }
+        let level_after = progress.level;
+        *self.level_slot(skill) = level_after;
+
+        let today = &mut self.today;
+        *today.xp.entry(skill.id().to_string()).or_default() += amount as u32;
+        if level_after > level_before {
+            *today.levels.entry(skill.id().to_string()).or_default() += level_after - level_before;
         }
     }
 
-    pub fn get(&self, skill: &str) -> u8 {
+    pub fn get(&self, skill: Skill) -> u8 {
         self.progress
-            .get(skill)
+            .get(skill.id())
             .map(|p| p.level)
             .unwrap_or_else(|| self.field_level(skill))
     }
+
+    /// Never practised: still at the starting level with nothing toward the next.
+    pub fn untried(&self, skill: Skill) -> bool {
+        let xp = self.progress.get(skill.id()).map(|p| p.xp).unwrap_or(0);
+        self.get(skill) <= Self::STARTING_LEVEL && xp == 0
+    }
+
+    /// Start counting the day's gains afresh.
+    pub fn start_day(&mut self, day: u32) {
+        if self.today.day != day {
+            self.today = SkillGains {
+                day,
+                ..SkillGains::default()
+            };
+        }
+    }
+
+    /// Experience and levels gained in `skill` on `day`, if it's the day
+    /// being counted.
+    pub fn gained_on(&self, skill: Skill, day: u32) -> (u32, u8) {
+        if self.today.day != day {
+            return (0, 0);
+        }
+        (
+            self.today.xp.get(skill.id()).copied().unwrap_or(0),
+            self.today.levels.get(skill.id()).copied().unwrap_or(0),
+        )
+    }
 }
 
 impl Default for Skills {
@@ -165,6 +345,46 @@ impl Room {
     }
 }
 
+/// Where in the cabin's main room the player is standing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CabinSpot {
+    Hearth,
+    Table,
+    Window,
+    Door,
+}
+
+impl CabinSpot {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CabinSpot::Hearth => "hearth",
+            CabinSpot::Table => "table",
+            CabinSpot::Window => "window",
+            CabinSpot::Door => "door",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<CabinSpot> {
+        match s.trim().to_lowercase().as_str() {
+            "hearth" | "fire" | "fireplace" => Some(CabinSpot::Hearth),
+            "table" => Some(CabinSpot::Table),
+            "window" => Some(CabinSpot::Window),
+            "door" | "doorway" => Some(CabinSpot::Door),
+            _ => None,
+        }
+    }
+
+    /// "You're standing ..." for the room description.
+    pub fn standing(&self) -> &'static str {
+        match self {
+            CabinSpot::Hearth => "You're standing by the hearth.",
+            CabinSpot::Table => "You're standing at the table.",
+            CabinSpot::Window => "You're standing at the window.",
+            CabinSpot::Door => "You're standing in the doorway.",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InventorySlot {
     pub item: Item,
@@ -253,14 +473,206 @@ impl Default for Inventory {
     }
 }
 
+/// Small items perched on the player's person or gear instead of carried.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Mounts {
+    #[serde(default)]
+    pub shoulder: Option<Item>,
+    #[serde(default)]
+    pub raft_prow: Option<Item>,
+}
+
+impl Mounts {
+    pub fn has(&self, item: &Item) -> bool {
+        self.shoulder.as_ref() == Some(item) || self.raft_prow.as_ref() == Some(item)
+    }
+
+    /// Lift an item down from whichever perch holds it.
+    pub fn take(&mut self, item: &Item) -> Option<&'static str> {
+        if self.shoulder.as_ref() == Some(item) {
+            self.shoulder = None;
+            return Some("your shoulder");
+        }
+        if self.raft_prow.as_ref() == Some(item) {
+            self.raft_prow = None;
+            return Some("the raft's prow");
+        }
+        None
+    }
+}
+
+/// What the player has out in their hands. Held things still count in the
+/// pack; this only records which of them are in hand rather than stowed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Hands {
+    #[serde(default)]
+    pub main: Option<Item>,
+    #[serde(default)]
+    pub off: Option<Item>,
+}
+
+impl Hands {
+    pub fn holds(&self, item: &Item) -> bool {
+        self.main.as_ref() == Some(item) || self.off.as_ref() == Some(item)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.main.is_none() && self.off.is_none()
+    }
+
+    /// Whatever is in hand, main hand first.
+    pub fn items(&self) -> impl Iterator<Item = Item> + '_ {
+        self.main.iter().chain(self.off.iter()).copied()
+    }
+
+    /// Take `item` into the main hand. What was there moves to the other
+    /// hand, and anything already in that one is returned to be stowed.
+    pub fn take_up(&mut self, item: Item) -> Option<Item> {
+        if self.main == Some(item) {
+            return None;
+        }
+        if self.off == Some(item) {
+            self.off = self.main.take();
+            self.main = Some(item);
+            return None;
+        }
+        let displaced = self.main.replace(item)?;
+        self.off.replace(displaced)
+    }
+
+    /// Put `item` away. False if it wasn't in hand.
+    pub fn stow(&mut self, item: &Item) -> bool {
+        if self.main.as_ref() == Some(item) {
+            self.main = self.off.take();
+            true
+        } else if self.off.as_ref() == Some(item) {
+            self.off = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Let go of anything no longer in the pack: eaten, dropped, thrown
+    /// or burnt down.
+    pub fn sync(&mut self, inventory: &Inventory) {
+        for item in [self.off, self.main].into_iter().flatten() {
+            if !inventory.has(&item, 1) {
+                self.stow(&item);
+            }
+        }
+    }
+}
+
+/// Whether loose items that land at the player's feet go straight into the pack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum AutoPickup {
+    Off,
+    /// Only small things, at or under `AutoPickup::LIGHT_WEIGHT` each.
+    #[default]
+    Light,
+    All,
+}
+
+impl AutoPickup {
+    pub const LIGHT_WEIGHT: f32 = 0.5;
+
+    pub fn from_str(s: &str) -> Option<AutoPickup> {
+        match s.trim().to_lowercase().as_str() {
+            "off" | "none" => Some(AutoPickup::Off),
+            "light" => Some(AutoPickup::Light),
+            "all" | "everything" => Some(AutoPickup::All),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AutoPickup::Off => "off",
+            AutoPickup::Light => "light",
+            AutoPickup::All => "all",
+        }
+    }
+
+    pub fn wants(&self, item: &Item) -> bool {
+        match self {
+            AutoPickup::Off => false,
+            AutoPickup::Light => item.weight() <= Self::LIGHT_WEIGHT,
+            AutoPickup::All => true,
+        }
+    }
+}
+
+/// The language instructions and syntax hints are given in. Korean and
+/// English commands are accepted either way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Korean,
+}
+
+impl Language {
+    pub fn from_str(s: &str) -> Option<Language> {
+        match s.trim().to_lowercase().as_str() {
+            "en" | "english" | "영어" => Some(Language::English),
+            "ko" | "korean" | "한국어" | "한글" => Some(Language::Korean),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Korean => "ko",
+        }
+    }
+
+    /// The English or Korean wording, whichever is active.
+    pub fn pick<'a>(&self, english: &'a str, korean: &'a str) -> &'a str {
+        match self {
+            Language::English => english,
+            Language::Korean => korean,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
+    // Identity; with no name the game speaks to "you"
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub pronouns: Option<Pronouns>,
+    #[serde(default)]
+    pub auto_pickup: AutoPickup,
+    #[serde(default)]
+    pub language: Language,
+    /// Whether each tool call and its reply go into the session transcript.
+    #[serde(default = "Player::default_record_transcript")]
+    pub record_transcript: bool,
+
     // Position
     pub position: Position,
     pub facing: Direction,
     pub room: Option<Room>,
-    #[serde(default = "Player::default_visited")]
+    /// Id of the placed structure the player has stepped inside, if any.
+    #[serde(default)]
+    pub inside: Option<String>,
+    /// The outdoor tile the player walked into the cabin or shed from.
+    #[serde(default)]
+    pub entered_from: Option<Position>,
+    /// Where in the cabin's main room, if anywhere in particular.
+    #[serde(default)]
+    pub cabin_spot: Option<CabinSpot>,
+    #[serde(default = "Player::default_visited", with = "position_bits")]
     pub visited: HashSet<Position>,
+    /// Tiles known only from the ancient map: drawn, but never walked.
+    #[serde(default, with = "position_bits")]
+    pub charted: HashSet<Position>,
+    /// The last few tiles walked, newest last. New trees keep off them.
+    #[serde(default)]
+    pub recent_steps: VecDeque<Position>,
     #[serde(default = "Player::default_known_blueprints")]
     pub known_blueprints: HashSet<Item>,
     #[serde(default = "Player::default_tool_durability")]
@@ -279,9 +691,25 @@ pub struct Player {
     pub hydration: f32, // 0-100 (thirst)
     #[serde(default = "Player::default_cognition")]
     pub cognition: f32, // 0-100 (mental sharpness)
+    /// 0-100, slow to move: how rooted the player feels here. Talking,
+    /// writing, small rituals and caring for a companion raise it; long
+    /// stretches of nothing but work wear it down. It softens mood losses.
+    #[serde(default = "Player::default_connection")]
+    pub connection: f32,
+    /// Sand in the eyes and hair from a desert storm, 0-100. It dulls
+    /// observation until washed off at the water.
+    #[serde(default)]
+    pub grit: f32,
+    /// Tick until which the calm after meditating colours what the player
+    /// sees warmly.
+    #[serde(default)]
+    pub afterglow_until: u64,
 
     // Progression
     pub skills: Skills,
+    /// Completed the field notes: a practised eye for anything that moves.
+    #[serde(default)]
+    pub naturalist: bool,
     pub inventory: Inventory,
 
     // Crafting
@@ -291,19 +719,62 @@ pub struct Player {
     pub book_ids: Vec<String>,
     #[serde(default)]
     pub book_progress: HashMap<String, usize>,
+    /// Object id of the seat the player is resting on, if any.
+    #[serde(default)]
+    pub seated: Option<String>,
+    #[serde(default)]
+    pub mounts: Mounts,
+    #[serde(default)]
+    pub hands: Hands,
+}
+
+/// How hard one action is on the tool doing it. Every tool-using action wears
+/// its tool exactly once, by one of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolWear {
+    /// A cut, a stroke, a scoop: whittling, foraging, butchering, a missed swing.
+    Light,
+    /// Work that jars the whole tool: splitting a log, a blow against a bear.
+    Heavy,
+    /// Bringing down a standing tree.
+    Felling,
+}
+
+impl ToolWear {
+    pub fn amount(self) -> u32 {
+        match self {
+            ToolWear::Light => 1,
+            ToolWear::Heavy => 2,
+            ToolWear::Felling => 3,
+        }
+    }
 }
 
 impl Player {
+    fn default_record_transcript() -> bool {
+        true
+    }
+
     pub fn new() -> Self {
         let mut visited = HashSet::new();
         let start_pos = Position::new(5, 0);
         visited.insert(start_pos);
 
         Self {
+            name: None,
+            pronouns: None,
+            auto_pickup: AutoPickup::default(),
+            language: Language::default(),
+            record_transcript: true,
             position: start_pos, // Start south of cabin on the path
             facing: Direction::North,
             room: None,
+            inside: None,
+            entered_from: None,
+            cabin_spot: None,
             visited,
+            charted: HashSet::new(),
+            recent_steps: VecDeque::new(),
             known_blueprints: HashSet::new(),
             tool_durability: HashMap::new(),
             body: Body::human_default(),
@@ -315,12 +786,19 @@ impl Player {
             fullness: Self::default_fullness(),
             hydration: Self::default_hydration(),
             cognition: Self::default_cognition(),
+            connection: Self::default_connection(),
+            grit: 0.0,
+            afterglow_until: 0,
 
             skills: Skills::new(),
+            naturalist: false,
             inventory: Inventory::new(),
             active_project: None,
             book_ids: Vec::new(),
             book_progress: HashMap::new(),
+            seated: None,
+            mounts: Mounts::default(),
+            hands: Hands::default(),
         }
     }
 
@@ -355,14 +833,35 @@ impl Player {
 
     pub fn enter_room(&mut self, room: Room) {
         self.room = Some(room);
+        self.cabin_spot = None;
     }
 
     pub fn exit_room(&mut self) {
         self.room = None;
+        self.entered_from = None;
+        self.cabin_spot = None;
     }
 
+    /// Cross to a spot in the cabin as part of doing something there.
+    pub fn step_to(&mut self, spot: CabinSpot) {
+        if self.room == Some(Room::CabinMain) {
+            self.cabin_spot = Some(spot);
+        }
+    }
+
+    /// Tiles kept in `recent_steps`.
+    pub const RECENT_STEPS: usize = 40;
+
     pub fn mark_visited(&mut self) {
         self.visited.insert(self.position);
+        self.charted.remove(&self.position);
+        if self.recent_steps.back() != Some(&self.position) {
+            self.recent_steps.retain(|p| *p != self.position);
+            self.recent_steps.push_back(self.position);
+            if self.recent_steps.len() > Self::RECENT_STEPS {
+                self.recent_steps.pop_front();
+            }
+        }
     }
 
     pub fn tool_max_durability(item: &Item) -> Option<u32> {
@@ -372,10 +871,18 @@ impl Player {
             Item::Knife => Some(70),
             Item::StoneKnife => Some(40),
             Item::FishingRod => Some(50),
+            Item::Shovel => Some(80),
             _ => None,
         }
     }
 
+    /// Durability left on a tool, as "left/max"; `None` for things that don't wear.
+    pub fn tool_condition(&self, item: &Item) -> Option<(u32, u32)> {
+        let max = Self::tool_max_durability(item)?;
+        let left = self.tool_durability.get(item).copied().unwrap_or(max);
+        Some((left, max))
+    }
+
     pub fn modify_health(&mut self, delta: f32) {
         self.health = (self.health + delta).clamp(0.0, 100.0);
     }
@@ -395,6 +902,78 @@ impl Player {
         Some(hit)
     }
 
+    /// Hurt one specific part, mirroring into the health bar like `apply_body_damage`.
+    pub fn injure_part(&mut self, kind: BodyPartKind, damage: f32, bleeding: bool) {
+        if let Some(part) = self.body.part_mut(kind) {
+            part.hp = (part.hp - damage).max(0.0);
+            if bleeding {
+                part.add_condition(PartCondition::Bleeding);
+            }
+            self.modify_health(-damage);
+            self.modify_mood(-damage.min(5.0));
+        }
+    }
+
+    /// Part-by-part account of the player's body and what each injury costs.
+    pub fn body_report(&self) -> String {
+        let mut lines = vec!["**Your Body:**".to_string()];
+        for part in &self.body.parts {
+            let mut line = format!(
+                "- {}: {}",
+                capitalize(part.kind.name()),
+                part.condition_description()
+            );
+            if !part.conditions.is_empty() {
+                let mut conditions: Vec<String> =
+                    part.conditions.iter().map(|c| c.name().to_string()).collect();
+                if part.has(PartCondition::Bandaged) || part.has(PartCondition::Splinted) {
+                    conditions.push(format!("{:.0}% mended", part.ratio() * 100.0));
+                }
+                line.push_str(&format!(" ({})", conditions.join(", ")));
+            }
+            if part.ratio() < 0.75 {
+                let penalty = if part.manipulation {
+                    Some("chopping and crafting are harder")
+                } else if part.movement {
+                    Some("walking costs more energy")
+                } else if part.cognition {
+                    Some("thinking is slower")
+                } else {
+                    None
+                };
+                if let Some(penalty) = penalty {
+                    line.push_str(&format!(" - {}", penalty));
+                }
+            }
+            lines.push(line);
+        }
+        lines.push(format!(
+            "\nHands: {:.0}% | Legs: {:.0}%",
+            self.body.manipulation_factor() * 100.0,
+            self.body.movement_factor() * 100.0
+        ));
+        if self.body.bleeding_parts() > 0 {
+            lines.push("You are bleeding. A bandage would stop it.".to_string());
+        }
+        lines.join("\n")
+    }
+
+    /// One line for `examine self` when something is wrong.
+    pub fn body_summary(&self) -> Option<String> {
+        let hurt: Vec<String> = self
+            .body
+            .parts
+            .iter()
+            .filter(|p| p.ratio() < 0.95 || !p.conditions.is_empty())
+            .map(|p| format!("your {} is {}", p.kind.name(), p.condition_description()))
+            .collect();
+        if hurt.is_empty() {
+            None
+        } else {
+            Some(format!("Physically, {}.", hurt.join(", ")))
+        }
+    }
+
     pub fn modify_warmth(&mut self, delta: f32) {
         self.warmth = (self.warmth + delta).clamp(0.0, 100.0);
     }
@@ -404,9 +983,34 @@ impl Player {
     }
 
     pub fn modify_mood(&mut self, delta: f32) {
+        let delta = if delta < 0.0 {
+            delta * self.mood_loss_factor()
+        } else {
+            delta
+        };
         self.mood = (self.mood + delta).clamp(0.0, 100.0);
     }
 
+    pub fn modify_connection(&mut self, delta: f32) {
+        self.connection = (self.connection + delta).clamp(0.0, 100.0);
+    }
+
+    /// How hard mood losses land: harder for someone adrift, softer for
+    /// someone who has put down roots.
+    pub fn mood_loss_factor(&self) -> f32 {
+        1.15 - 0.45 * self.connection.clamp(0.0, 100.0) / 100.0
+    }
+
+    pub fn connection_description(&self) -> &'static str {
+        match self.connection {
+            c if c < 15.0 => "adrift, as if nothing here is yours",
+            c if c < 35.0 => "like a visitor here",
+            c if c < 55.0 => "yourself settling in, bit by bit",
+            c if c < 75.0 => "at ease in this place",
+            _ => "rooted here",
+        }
+    }
+
     pub fn modify_fullness(&mut self, delta: f32) {
         self.fullness = (self.fullness + delta).clamp(0.0, 100.0);
     }
@@ -426,7 +1030,7 @@ impl Player {
     }
 
     /// Effective skill level after cognition penalties/bonuses
-    pub fn effective_skill(&self, skill: &str) -> u8 {
+    pub fn effective_skill(&self, skill: Skill) -> u8 {
         let base = self.skills.get(skill) as f32;
         let cognition_factor = match self.cognition {
             c if c >= 90.0 => 1.05,
@@ -438,25 +1042,23 @@ impl Player {
 
         // Start from cognition, then apply gentle penalties for injured hands/arms on physical skills.
         let mut factor = cognition_factor;
-        let requires_hands = matches!(
-            skill,
-            "woodcutting"
-                | "fire_making"
-                | "foraging"
-                | "stonemasonry"
-                | "survival"
-                | "tailoring"
-                | "cooking"
-        );
-        if requires_hands {
+        if skill.uses_hands() {
             let hand_health = self.body.manipulation_factor();
             // Even with badly injured arms you can still attempt tasks,
             // but precision drops sharply as hand health falls.
             let hand_factor = 0.4 + hand_health * 0.6;
             factor *= hand_factor;
         }
+        // Sand in the eyes: at worst you see half as much
+        let mut bonus = 0.0;
+        if skill == Skill::Observation {
+            factor *= 1.0 - self.grit.clamp(0.0, 100.0) / 200.0;
+            if self.naturalist {
+                bonus = NATURALIST_BONUS;
+            }
+        }
 
-        let value = (base * factor).round().clamp(1.0, 100.0);
+        let value = (base * factor + bonus).round().clamp(1.0, 100.0);
         value as u8
     }
 
@@ -514,7 +1116,7 @@ impl Player {
     }
 
     pub fn status_summary(&self) -> String {
-        format!(
+        let mut text = format!(
             "You feel {} and {}. Your energy level is {}. You are {} and {}. Your mind feels {}.",
             self.comfort_description(),
             self.mood_description(),
@@ -522,7 +1124,12 @@ impl Player {
             self.fullness_description(),
             self.hydration_description(),
             self.cognition_description(),
-        )
+        );
+        text.push_str(&format!(" You feel {}.", self.connection_description()));
+        if self.grit >= 1.0 {
+            text.push_str(" Storm grit stings your eyes and stiffens your hair; water would wash it out.");
+        }
+        text
     }
 
     fn default_fullness() -> f32 {
@@ -534,6 +1141,92 @@ impl Player {
     fn default_cognition() -> f32 {
         100.0
     }
+    fn default_connection() -> f32 {
+        30.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pronouns {
+    pub subject: String,
+    pub object: String,
+    pub possessive: String,
+}
+
+impl Pronouns {
+    /// Parse "she/her", "he/him", "they/them", or a custom "xe/xem/xyr".
+    pub fn parse(s: &str) -> Option<Pronouns> {
+        let parts: Vec<String> = s
+            .split('/')
+            .map(|p| p.trim().to_lowercase())
+            .filter(|p| !p.is_empty())
+            .collect();
+        let make = |a: &str, b: &str, c: &str| Pronouns {
+            subject: a.to_string(),
+            object: b.to_string(),
+            possessive: c.to_string(),
+        };
+        match parts.first().map(String::as_str) {
+            Some("she") if parts.len() <= 2 => Some(make("she", "her", "her")),
+            Some("he") if parts.len() <= 2 => Some(make("he", "him", "his")),
+            Some("they") if parts.len() <= 2 => Some(make("they", "them", "their")),
+            _ if parts.len() == 3 => Some(make(&parts[0], &parts[1], &parts[2])),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        format!("{}/{}", self.subject, self.object)
+    }
+}
+
+impl Player {
+    /// Fill a text template in the player's voice. Placeholders:
+    /// `{name}`/`{Name}` (the player's name, or "you"), `{them}`, `{their}`,
+    /// and `{s}` (the verb ending: "you write" but "Ada writes").
+    /// Keeping the wording in templates lets it be swapped out whole.
+    pub fn render(&self, template: &str) -> String {
+        let name = self.name.as_deref();
+        let (object, possessive) = match (&self.pronouns, name) {
+            (Some(p), _) => (p.object.clone(), p.possessive.clone()),
+            (None, Some(n)) => (n.to_string(), format!("{}'s", n)),
+            (None, None) => ("you".to_string(), "your".to_string()),
+        };
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start..];
+            let Some(end) = after.find('}') else {
+                out.push_str(after);
+                return out;
+            };
+            let key = &after[1..end];
+            match key {
+                "name" => out.push_str(name.unwrap_or("you")),
+                "Name" => out.push_str(&capitalize(name.unwrap_or("you"))),
+                "them" => out.push_str(&object),
+                "their" => out.push_str(&possessive),
+                "s" => {
+                    if name.is_some() {
+                        out.push('s');
+                    }
+                }
+                _ => out.push_str(&after[..=end]),
+            }
+            rest = &after[end + 1..];
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+pub fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
 }
 
 impl Default for Player {
@@ -541,3 +1234,46 @@ impl Default for Player {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_day_s_gains_start_afresh_each_day() {
+        let mut skills = Skills::new();
+        skills.start_day(1);
+        skills.improve(Skill::FireMaking, 60);
+        skills.improve(Skill::FireMaking, 5);
+        assert_eq!(skills.gained_on(Skill::FireMaking, 1), (65, 1));
+        let level = skills.get(Skill::FireMaking);
+
+        // Starting the same day again keeps what it has
+        skills.start_day(1);
+        assert_eq!(skills.gained_on(Skill::FireMaking, 1), (65, 1));
+
+        skills.start_day(2);
+        assert_eq!(skills.gained_on(Skill::FireMaking, 2), (0, 0));
+        assert_eq!(skills.gained_on(Skill::FireMaking, 1), (0, 0));
+        assert_eq!(skills.get(Skill::FireMaking), level);
+    }
+
+    #[test]
+    fn connection_softens_mood_losses_but_not_gains() {
+        let after = |connection: f32, delta: f32| {
+            let mut player = Player::new();
+            (player.mood, player.connection) = (50.0, connection);
+            player.modify_mood(delta);
+            player.mood
+        };
+        let adrift = 50.0 - after(0.0, -10.0);
+        let settling = 50.0 - after(50.0, -10.0);
+        let rooted = 50.0 - after(100.0, -10.0);
+        assert!(adrift > 10.0 && rooted < 10.0);
+        assert!(adrift > settling && settling > rooted);
+        assert!((rooted - 7.0).abs() < 1e-3);
+
+        // Good moments land the same however at home you feel
+        assert_eq!(after(0.0, 10.0), after(100.0, 10.0));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::entity::objects::Item;
-use crate::world::Position;
+use crate::world::{Position, Season};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +9,35 @@ pub enum TreeType {
     Birch,
     Apple,
     Bamboo,
+    DatePalm,
+}
+
+/// Where a fruit tree is in its year. Trees that bear nothing stay `Bare`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FruitStage {
+    #[default]
+    Bare,
+    Blossom,
+    Setting,
+    Ripe,
+}
+
+/// Something living up in a tree's branches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Nest {
+    Birds,
+    Bees,
+}
+
+impl Nest {
+    pub fn line(&self) -> &'static str {
+        match self {
+            Nest::Birds => "A bird's nest of twigs and moss is tucked into a fork high up.",
+            Nest::Bees => {
+                "Bees drone in and out of a hollow in the trunk; there's a hive in there."
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,9 +51,22 @@ pub struct Tree {
     pub fruit_count: u8,
     #[serde(default)]
     pub fruit_max: u8,
+    #[serde(default)]
+    pub fruit_stage: FruitStage,
+    /// Fire damage taken; at `CHAR_AT` the tree is a charred husk.
+    #[serde(default)]
+    pub scorch: u8,
+    /// Day the tree came down; the stump rots away some while after.
+    #[serde(default)]
+    pub felled_day: Option<u32>,
+    /// A tap has been cut in the bark to bleed sap.
+    #[serde(default)]
+    pub tapped: bool,
 }
 
 impl Tree {
+    pub const CHAR_AT: u8 = 3;
+
     pub fn new(position: Position, kind: TreeType) -> Self {
         let mut tree = Self {
             position,
@@ -34,32 +76,137 @@ impl Tree {
             felled: false,
             fruit_count: 0,
             fruit_max: 0,
+            fruit_stage: FruitStage::Bare,
+            scorch: 0,
+            felled_day: None,
+            tapped: false,
         };
         tree.apply_kind_defaults();
         tree
     }
 
-    pub fn with_random_fruit(position: Position, kind: TreeType, rng: &mut impl Rng) -> Self {
+    /// A new tree partway through `season`, with whatever fruit the season
+    /// allows already on it.
+    pub fn with_random_fruit(
+        position: Position,
+        kind: TreeType,
+        season: Season,
+        rng: &mut impl Rng,
+    ) -> Self {
         let mut tree = Self::new(position, kind);
-        if tree.fruit_max > 0 {
-            let start = rng.gen_range(0..=tree.fruit_max.min(3));
-            tree.fruit_count = start;
+        tree.set_season(season);
+        let cap = tree.fruit_cap();
+        if cap > 0 {
+            tree.fruit_count = rng.gen_range(0..=cap.min(3));
         }
         tree
     }
 
+    /// What a tree of `kind` is doing in `season`. Apples blossom in spring,
+    /// set fruit through summer and ripen in autumn. Date palms flower in the
+    /// cool of winter, set in spring, ripen in the summer heat and rest once
+    /// the crop is in.
+    pub fn stage_in(kind: TreeType, season: Season) -> FruitStage {
+        match (kind, season) {
+            (TreeType::Apple, Season::Spring) | (TreeType::DatePalm, Season::Winter) => {
+                FruitStage::Blossom
+            }
+            (TreeType::Apple, Season::Summer) | (TreeType::DatePalm, Season::Spring) => {
+                FruitStage::Setting
+            }
+            (TreeType::Apple, Season::Autumn) | (TreeType::DatePalm, Season::Summer) => {
+                FruitStage::Ripe
+            }
+            _ => FruitStage::Bare,
+        }
+    }
+
+    /// Bring the tree in line with `season`. Whatever fruit is left drops
+    /// away once the tree goes bare or into blossom again.
+    pub fn set_season(&mut self, season: Season) {
+        let stage = Self::stage_in(self.kind, season);
+        if stage == self.fruit_stage {
+            return;
+        }
+        self.fruit_stage = stage;
+        self.fruit_count = self.fruit_count.min(self.fruit_cap());
+    }
+
+    /// The most fruit the tree carries at its stage: half a crop while it's
+    /// still setting, all of it once ripe.
+    pub fn fruit_cap(&self) -> u8 {
+        match self.fruit_stage {
+            FruitStage::Setting => self.fruit_max / 2,
+            FruitStage::Ripe => self.fruit_max,
+            FruitStage::Bare | FruitStage::Blossom => 0,
+        }
+    }
+
+    pub fn is_ripe(&self) -> bool {
+        self.fruit_stage == FruitStage::Ripe && self.has_fruit()
+    }
+
+    /// The chance a kick shakes fruit loose, and the most it can bring down.
+    /// Unripe fruit clings; ripe fruit barely needs asking.
+    pub fn kick_yield(&self) -> (f64, u8) {
+        match self.fruit_stage {
+            FruitStage::Setting => (0.3, 1),
+            FruitStage::Ripe => (0.75, 2),
+            FruitStage::Bare | FruitStage::Blossom => (0.0, 0),
+        }
+    }
+
+    /// A sentence on where the tree is in its year, for fruit trees.
+    pub fn stage_line(&self) -> Option<&'static str> {
+        if self.felled || self.is_charred() {
+            return None;
+        }
+        let line = match (self.kind, self.fruit_stage) {
+            (TreeType::Apple, FruitStage::Blossom) => {
+                "Pink-white blossom crowds its branches; there's no fruit yet."
+            }
+            (TreeType::Apple, FruitStage::Setting) => {
+                "Small green apples are setting among the leaves, still hard and sour."
+            }
+            (TreeType::Apple, FruitStage::Ripe) => "Its apples have ripened red and heavy.",
+            (TreeType::Apple, FruitStage::Bare) => "Its branches stand bare for the winter.",
+            (TreeType::DatePalm, FruitStage::Blossom) => {
+                "Creamy flower stalks hang beneath its fronds."
+            }
+            (TreeType::DatePalm, FruitStage::Setting) => {
+                "Strands of hard green dates hang beneath its fronds."
+            }
+            (TreeType::DatePalm, FruitStage::Ripe) => {
+                "Heavy strands of amber dates hang beneath its fronds."
+            }
+            (TreeType::DatePalm, FruitStage::Bare) => {
+                "Its fronds rest between crops, the old fruit stalks hanging empty."
+            }
+            _ => return None,
+        };
+        Some(line)
+    }
+
     pub fn description(&self) -> &'static str {
+        if self.is_charred() {
+            return match self.kind {
+                TreeType::Bamboo => "Blackened bamboo stalks stand split and hollow, burnt through.",
+                _ => "A charred husk of a tree, its bark burnt to black scale. There's no timber left in it, only charcoal.",
+            };
+        }
         match self.kind {
             TreeType::Pine => "A tall pine stands here, sap-heavy and straight.",
             TreeType::Birch => "A slender birch with pale bark and delicate branches.",
-            TreeType::Apple => "A hardy apple tree, its branches often heavy with fruit.",
+            TreeType::Apple => "A hardy apple tree with a gnarled, low-branching trunk.",
             TreeType::Bamboo => "A cluster of bamboo stalks sways softly in the breeze.",
+            TreeType::DatePalm => "A date palm, its scaly trunk topped by a crown of long fronds.",
         }
     }
 
     pub fn fruit_item(&self) -> Option<Item> {
         match self.kind {
             TreeType::Apple => Some(Item::Apple),
+            TreeType::DatePalm => Some(Item::Date),
             _ => None,
         }
     }
@@ -80,18 +227,70 @@ impl Tree {
         all
     }
 
-    pub fn tick_growth(&mut self, rng: &mut impl Rng) {
-        if self.felled || self.fruit_max == 0 || self.fruit_count >= self.fruit_max {
+    /// Bring the tree down on `day`, leaving a stump.
+    pub fn fell(&mut self, day: u32) {
+        self.felled = true;
+        self.felled_day = Some(day);
+    }
+
+    /// Pines give resin and birches sap; the rest won't run.
+    pub fn can_be_tapped(&self) -> bool {
+        matches!(self.kind, TreeType::Pine | TreeType::Birch) && !self.felled && !self.is_charred()
+    }
+
+    /// Birds nest in some pines and birches, bees in some apple trees.
+    /// Which ones follows from where the tree stands.
+    pub fn nest(&self) -> Option<Nest> {
+        if self.felled || self.is_charred() {
+            return None;
+        }
+        let mut h = (self.position.row as i64 as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ (self.position.col as i64 as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+        h ^= h >> 31;
+        h = h.wrapping_mul(0x94D0_49BB_1331_11EB);
+        h ^= h >> 29;
+        match self.kind {
+            TreeType::Pine | TreeType::Birch if h.is_multiple_of(6) => Some(Nest::Birds),
+            TreeType::Apple if h.is_multiple_of(4) => Some(Nest::Bees),
+            _ => None,
+        }
+    }
+
+    pub fn is_charred(&self) -> bool {
+        self.scorch >= Self::CHAR_AT
+    }
+
+    /// One tick in the flames. Returns true the moment the tree chars through.
+    pub fn burn(&mut self) -> bool {
+        if self.felled || self.is_charred() {
+            return false;
+        }
+        self.scorch += 1;
+        self.fruit_count = 0;
+        self.is_charred()
+    }
+
+    /// Follow the calendar, and let fruit come on: slowly while it's
+    /// setting, quickly once it ripens.
+    pub fn tick_growth(&mut self, season: Season, rng: &mut impl Rng) {
+        if self.felled || self.is_charred() || self.fruit_max == 0 {
             return;
         }
-        if rng.gen_bool(0.18) {
-            self.fruit_count = (self.fruit_count + 1).min(self.fruit_max);
+        self.set_season(season);
+        let chance = match self.fruit_stage {
+            FruitStage::Setting => 0.08,
+            FruitStage::Ripe => 0.25,
+            FruitStage::Bare | FruitStage::Blossom => return,
+        };
+        if self.fruit_count < self.fruit_cap() && rng.gen_bool(chance) {
+            self.fruit_count += 1;
         }
     }
 
     pub fn apply_kind_defaults(&mut self) {
         self.fruit_max = match self.kind {
             TreeType::Apple => 6,
+            TreeType::DatePalm => 8,
             _ => 0,
         };
         if self.hits_required == 0 {
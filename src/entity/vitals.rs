@@ -0,0 +1,215 @@
+use super::Player;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// How far a vital has to recover past a threshold before the band counts as
+/// left, so a value hovering on the line doesn't chatter.
+const BAND_MARGIN: f32 = 2.0;
+
+/// One step into trouble, with what the player feels going in and coming back out.
+struct Level {
+    at: f32,
+    enter: &'static str,
+    leave: &'static str,
+}
+
+/// A vital watched for threshold crossings. Levels run from mildest to worst;
+/// `rising` gauges get worse as the value climbs.
+struct Gauge {
+    name: &'static str,
+    read: fn(&Player) -> f32,
+    rising: bool,
+    levels: &'static [Level],
+}
+
+const GAUGES: &[Gauge] = &[
+    Gauge {
+        name: "energy",
+        read: |p| p.energy,
+        rising: false,
+        levels: &[
+            Level {
+                at: 30.0,
+                enter: "Tiredness settles into your limbs. Rest soon, or the work will start to go badly.",
+                leave: "The heaviness lifts from your limbs; you feel rested enough again.",
+            },
+            Level {
+                at: 10.0,
+                enter: "You are exhausted. Your body is close to giving out, and every action is a struggle.",
+                leave: "The worst of the exhaustion passes, though you're still tired.",
+            },
+        ],
+    },
+    Gauge {
+        name: "mood",
+        read: |p| p.mood,
+        rising: false,
+        levels: &[Level {
+            at: 25.0,
+            enter: "A grey gloom settles over you. Everything feels like more effort than it's worth.",
+            leave: "The gloom lifts. The world looks a little brighter again.",
+        }],
+    },
+    Gauge {
+        name: "cold",
+        read: |p| p.warmth,
+        rising: false,
+        levels: &[Level {
+            at: 25.0,
+            enter: "You're dangerously cold. Your fingers have gone numb; get to a fire or shelter.",
+            leave: "Warmth creeps back into your hands. The worst of the cold has passed.",
+        }],
+    },
+    Gauge {
+        name: "heat",
+        read: |p| p.warmth,
+        rising: true,
+        levels: &[Level {
+            at: 80.0,
+            enter: "You're overheating. Sweat stings your eyes; find shade or water.",
+            leave: "You've cooled down; the heat no longer presses on you.",
+        }],
+    },
+    Gauge {
+        name: "fullness",
+        read: |p| p.fullness,
+        rising: false,
+        levels: &[
+            Level {
+                at: 20.0,
+                enter: "You're getting hungry. Your stomach has started to complain.",
+                leave: "Your hunger is satisfied for now.",
+            },
+            Level {
+                at: 10.0,
+                enter: "Your stomach growls painfully. You need to eat soon.",
+                leave: "The gnawing in your stomach eases, though you could still eat.",
+            },
+        ],
+    },
+    Gauge {
+        name: "hydration",
+        read: |p| p.hydration,
+        rising: false,
+        levels: &[
+            Level {
+                at: 20.0,
+                enter: "You're thirsty. Your throat feels dry.",
+                leave: "Your thirst is quenched.",
+            },
+            Level {
+                at: 10.0,
+                enter: "Your mouth is dry and head swims. Drink water soon.",
+                leave: "Your head clears a little, but you're still thirsty.",
+            },
+        ],
+    },
+    Gauge {
+        name: "health",
+        read: |p| p.health,
+        rising: false,
+        levels: &[
+            Level {
+                at: 30.0,
+                enter: "You're badly hurt. Every movement reminds you of it.",
+                leave: "You're mending; the pain has dulled to an ache.",
+            },
+            Level {
+                at: 10.0,
+                enter: "You are close to collapse. One more blow could be the end.",
+                leave: "You've pulled back from the brink, but you're still badly hurt.",
+            },
+        ],
+    },
+];
+
+impl Gauge {
+    /// How many levels deep `value` sits, given how deep it was last time.
+    fn severity(&self, value: f32, held: usize) -> usize {
+        self.levels
+            .iter()
+            .enumerate()
+            .take_while(|(i, level)| {
+                let margin = if *i < held { BAND_MARGIN } else { 0.0 };
+                if self.rising {
+                    value > level.at - margin
+                } else {
+                    value < level.at + margin
+                }
+            })
+            .count()
+    }
+}
+
+/// The band each vital was last seen in, so crossings are told once.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VitalBands {
+    #[serde(default)]
+    held: HashMap<String, usize>,
+}
+
+impl VitalBands {
+    /// Compare every vital with the band it was last seen in. Returns one
+    /// line for each that has sunk into a worse band or climbed out of one.
+    pub fn update(&mut self, player: &Player) -> Vec<String> {
+        let mut lines = Vec::new();
+        for gauge in GAUGES {
+            let held = self.held.get(gauge.name).copied().unwrap_or(0);
+            let now = gauge.severity((gauge.read)(player), held);
+            if now > held {
+                lines.push(gauge.levels[now - 1].enter.to_string());
+            } else if now < held {
+                lines.push(gauge.levels[now].leave.to_string());
+            }
+            if now == 0 {
+                self.held.remove(gauge.name);
+            } else {
+                self.held.insert(gauge.name.to_string(), now);
+            }
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_band_is_told_once_going_in_and_once_coming_out() {
+        let mut player = Player::new();
+        let mut bands = VitalBands::default();
+        player.energy = 50.0;
+        player.mood = 80.0;
+        assert!(bands.update(&player).is_empty());
+
+        // Down into tiredness, lingering there, back out past the margin
+        let mut told = Vec::new();
+        for delta in [-22.0, -3.0, 2.0, -1.0, 3.0, 4.0, 20.0] {
+            player.modify_energy(delta);
+            told.extend(bands.update(&player));
+        }
+        assert_eq!(told.len(), 2);
+        assert!(told[0].starts_with("Tiredness settles"));
+        assert!(told[1].starts_with("The heaviness lifts"));
+
+        // Straight through to exhaustion and back up one band at a time
+        player.modify_energy(-65.0);
+        assert_eq!(bands.update(&player), vec![GAUGES[0].levels[1].enter]);
+        player.modify_energy(15.0);
+        assert_eq!(bands.update(&player), vec![GAUGES[0].levels[1].leave]);
+        player.modify_energy(20.0);
+        assert_eq!(bands.update(&player), vec![GAUGES[0].levels[0].leave]);
+
+        // Gloom works the same way, and hunger has joined them
+        let mut told = Vec::new();
+        for delta in [-60.0, -5.0, 40.0] {
+            player.modify_mood(delta);
+            told.extend(bands.update(&player));
+        }
+        assert_eq!(told.len(), 2);
+        player.fullness = 15.0;
+        assert_eq!(bands.update(&player).len(), 1);
+        assert!(bands.update(&player).is_empty());
+    }
+}
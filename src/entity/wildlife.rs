@@ -1,7 +1,10 @@
-use crate::entity::Body;
-use crate::world::{Biome, Direction, Position, RegionalWeather, TimeOfDay, Weather, WorldMap};
+use crate::entity::{capitalize, Body};
+use crate::world::{
+    Biome, Direction, Position, RegionalWeather, TileType, TimeOfDay, Weather, WorldMap,
+};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -110,6 +113,19 @@ impl Species {
         }
     }
 
+    /// Species that can take to open water.
+    pub fn can_swim(&self) -> bool {
+        matches!(
+            self,
+            Species::Duck | Species::Fish | Species::Heron | Species::Frog | Species::Dragonfly
+        )
+    }
+
+    /// Species that never leave the water.
+    pub fn water_only(&self) -> bool {
+        matches!(self, Species::Fish)
+    }
+
     pub fn native_biomes(&self) -> Vec<Biome> {
         match self {
             Species::Deer
@@ -188,6 +204,123 @@ impl Species {
         )
     }
 
+    /// Small birds that come to sit on a roof.
+    pub fn perches_on_roofs(&self) -> bool {
+        matches!(self, Species::Songbird | Species::Woodpecker)
+    }
+
+    /// Foxes, which raid food left out overnight.
+    pub fn raids_caches(&self) -> bool {
+        matches!(self, Species::Fox | Species::DesertFox | Species::SnowFox)
+    }
+
+    /// Deer and their kin, which keep clear of a campfire.
+    pub fn shies_from_fire(&self) -> bool {
+        matches!(
+            self,
+            Species::Deer | Species::Caribou | Species::Elk | Species::Moose
+        )
+    }
+
+    /// What you only notice from a step or two away.
+    pub fn close_detail(&self) -> &'static str {
+        match self {
+            Species::Rabbit | Species::SnowHare => {
+                "You can see its whiskers twitch, and the pink inside one ear lit by the light behind it."
+            }
+            Species::Deer | Species::Elk | Species::Moose | Species::Caribou => {
+                "Its breath clouds in short puffs. A burr is caught in the coarse hair of its flank."
+            }
+            Species::Squirrel => {
+                "Its cheeks bulge with something, and its tail flicks in little question marks."
+            }
+            Species::Songbird | Species::Woodpecker => {
+                "Each feather on its throat lifts and settles with its heartbeat."
+            }
+            Species::Fox | Species::DesertFox | Species::SnowFox => {
+                "Its amber eyes track you without blinking; the black tips of its ears never stop turning."
+            }
+            Species::DesertLizard => {
+                "Its throat pulses, and the scales along its back are finer than beadwork."
+            }
+            Species::Duck => "Water beads and rolls off its back. It mutters to itself.",
+            Species::Heron => "It stands on one leg, so still that only its yellow eye moves.",
+            Species::Frog => "Its throat balloons and empties; its skin is wet jade.",
+            Species::Wolf => "Frost rims the fur of its muzzle. It has seen you, and it is deciding.",
+            Species::Goat | Species::Sheep => {
+                "Its strange sideways pupils regard you while it keeps on chewing."
+            }
+            Species::Boar | Species::Pig => {
+                "Its snout is crusted with soil; it snuffles and grunts between mouthfuls."
+            }
+            Species::Camel => "Long lashes sweep over its half-closed eyes as it chews.",
+            _ => "Up close it looks smaller and more alive than from a distance: every breath shows.",
+        }
+    }
+
+    /// Every species that can turn up in the valley, in the order the
+    /// field notes list them. Seeing each one completes the notes.
+    pub const FIELD_GUIDE: [Species; 32] = [
+        Species::Deer,
+        Species::Rabbit,
+        Species::Squirrel,
+        Species::Songbird,
+        Species::Horse,
+        Species::Elk,
+        Species::Bear,
+        Species::Lynx,
+        Species::DesertLizard,
+        Species::Scorpion,
+        Species::DesertFox,
+        Species::Hawk,
+        Species::Rattlesnake,
+        Species::Camel,
+        Species::Hyena,
+        Species::SnowFox,
+        Species::Owl,
+        Species::Wolf,
+        Species::Caribou,
+        Species::SnowHare,
+        Species::Moose,
+        Species::Duck,
+        Species::Fish,
+        Species::Heron,
+        Species::Frog,
+        Species::Dragonfly,
+        Species::Pig,
+        Species::Boar,
+        Species::Goat,
+        Species::Sheep,
+        Species::Dog,
+        Species::Cat,
+    ];
+
+    /// A field-notes page on this species, as much as the player has seen.
+    pub fn field_note(&self, detail: SightingDetail) -> String {
+        let haunts: Vec<&str> = self.native_biomes().iter().map(|b| b.name()).collect();
+        let mut note = format!(
+            "{}\n{}. Found in the {}.",
+            capitalize(self.name()),
+            detail.heading(),
+            haunts.join(" and the ")
+        );
+        if detail >= SightingDetail::Watched {
+            note.push(' ');
+            note.push_str(self.activity_schedule().habit());
+            note.push(' ');
+            note.push_str(if self.is_predator() {
+                "A hunter; the smaller animals keep an eye on it."
+            } else {
+                "Wary, and quick to bolt from anything that hunts."
+            });
+        }
+        if detail >= SightingDetail::Studied {
+            note.push(' ');
+            note.push_str(self.close_detail());
+        }
+        note
+    }
+
     /// Generate a description snippet for this animal doing an action
     pub fn describe_action(&self, behavior: Behavior) -> String {
         let name = self.name();
@@ -365,6 +498,47 @@ impl ActivitySchedule {
     }
 }
 
+impl ActivitySchedule {
+    /// How the field notes sum up when an animal keeps its hours.
+    pub fn habit(&self) -> &'static str {
+        match self {
+            ActivitySchedule::Diurnal => "Out in daylight; hard to find after dark.",
+            ActivitySchedule::Nocturnal => "Comes out after dark and is rarely seen by day.",
+            ActivitySchedule::Crepuscular => {
+                "Busiest at dawn and dusk, resting through the bright hours."
+            }
+        }
+    }
+}
+
+/// How closely the player has watched a species, by how near it was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SightingDetail {
+    Glimpsed,
+    Watched,
+    Studied,
+}
+
+impl SightingDetail {
+    pub fn at_distance(distance: f32) -> Self {
+        if distance < 1.5 {
+            SightingDetail::Studied
+        } else if distance < 3.0 {
+            SightingDetail::Watched
+        } else {
+            SightingDetail::Glimpsed
+        }
+    }
+
+    fn heading(&self) -> &'static str {
+        match self {
+            SightingDetail::Glimpsed => "Glimpsed from a distance",
+            SightingDetail::Watched => "Watched for a while from close by",
+            SightingDetail::Studied => "Studied from almost within reach",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Behavior {
     Sleeping,
@@ -472,9 +646,17 @@ pub struct Wildlife {
     pub tamed: bool,
     #[serde(default)]
     pub name: Option<String>,
+    /// How much this animal distrusts the player, 0-100. Loud work and
+    /// missed attacks raise it; quiet days wear it back down.
+    #[serde(default)]
+    pub wariness: f32,
 }
 
 impl Wildlife {
+    pub const MAX_WARINESS: f32 = 100.0;
+    /// Wariness lost each quiet tick, about fourteen points a day.
+    const CALM_PER_TICK: f32 = 0.1;
+
     pub fn new(species: Species, position: Position) -> Self {
         Self {
             id: Uuid::new_v4(),
@@ -485,6 +667,62 @@ impl Wildlife {
             alive: true,
             tamed: false,
             name: None,
+            wariness: 0.0,
+        }
+    }
+
+    /// Something alarming happened nearby.
+    pub fn alarm(&mut self, amount: f32) {
+        if self.tamed {
+            return;
+        }
+        self.wariness = (self.wariness + amount).clamp(0.0, Self::MAX_WARINESS);
+    }
+
+    /// How close, in tiles, this animal lets the player come before it moves off.
+    pub fn keep_distance(&self) -> f32 {
+        1.0 + self.wariness / 25.0
+    }
+
+    /// Bolt one step away from `from`.
+    pub fn flee_from(
+        &mut self,
+        from: Position,
+        map: &WorldMap,
+        structures: &HashSet<Position>,
+        rng: &mut impl Rng,
+    ) -> bool {
+        self.behavior = Behavior::Fleeing;
+        let away = self.away_from(from);
+        self.step(away, map, structures, rng)
+    }
+
+    /// Wander one step closer to `to`.
+    pub fn step_toward(
+        &mut self,
+        to: Position,
+        map: &WorldMap,
+        structures: &HashSet<Position>,
+        rng: &mut impl Rng,
+    ) -> bool {
+        let toward = self.away_from(to).opposite();
+        self.step(toward, map, structures, rng)
+    }
+
+    /// The direction that takes this animal furthest from `from`.
+    fn away_from(&self, from: Position) -> Direction {
+        let dr = self.position.row - from.row;
+        let dc = self.position.col - from.col;
+        if dr.abs() >= dc.abs() {
+            if dr >= 0 {
+                Direction::South
+            } else {
+                Direction::North
+            }
+        } else if dc >= 0 {
+            Direction::East
+        } else {
+            Direction::West
         }
     }
 
@@ -492,13 +730,43 @@ impl Wildlife {
         true
     }
 
-    pub fn update(&mut self, time: TimeOfDay, map: &WorldMap, weather: &RegionalWeather) {
+    pub fn update(
+        &mut self,
+        time: TimeOfDay,
+        map: &WorldMap,
+        weather: &RegionalWeather,
+        structures: &HashSet<Position>,
+        player: Option<Position>,
+    ) {
         // Tamed companions mostly let the game state drive their movement.
         if self.tamed && matches!(self.species, Species::Dog | Species::Cat) {
             self.behavior = Behavior::Moving;
             return;
         }
         let mut rng = rand::thread_rng();
+        // Spawned somewhere it can't stand, such as a building: walk out
+        if !self.can_occupy(self.position, map, structures) {
+            let dir = [
+                Direction::North,
+                Direction::South,
+                Direction::East,
+                Direction::West,
+            ][rng.gen_range(0..4)];
+            self.step(dir, map, structures, &mut rng);
+            return;
+        }
+        self.wariness = (self.wariness - Self::CALM_PER_TICK).max(0.0);
+
+        // Prey that has learned to distrust the player keeps its distance
+        let crowded = player.filter(|p| {
+            !self.species.is_predator() && self.position.distance_to(p) < self.keep_distance()
+        });
+        if let Some(player) = crowded.filter(|_| rng.gen_bool((0.5 + self.wariness / 200.0) as f64))
+        {
+            self.flee_from(player, map, structures, &mut rng);
+            return;
+        }
+
         let weather_here = weather.get_for_position(self.position.row, self.position.col);
 
         let severe = matches!(
@@ -539,17 +807,71 @@ impl Wildlife {
                 Direction::West,
             ];
             let dir = directions[rng.gen_range(0..4)];
-            let new_pos = self.position.move_in_direction(dir);
-            if new_pos.is_valid() {
-                if let Some((r, c)) = new_pos.as_usize() {
-                    if let Some(tile) = map.get_tile(r, c) {
-                        if self.species.native_biomes().contains(&tile.biome) {
-                            self.position = new_pos;
-                        }
-                    }
-                }
+            self.step(dir, map, structures, &mut rng);
+        }
+    }
+
+    /// Whether this animal can stand on `pos`: on the map, clear of
+    /// structures, and on land or water as its kind allows.
+    pub fn can_occupy(
+        &self,
+        pos: Position,
+        map: &WorldMap,
+        structures: &HashSet<Position>,
+    ) -> bool {
+        if structures.contains(&pos) {
+            return false;
+        }
+        if map.tile_at(&pos).is_none() {
+            return false;
+        }
+        if is_land(pos, map) {
+            !self.species.water_only()
+        } else {
+            self.species.can_swim() && is_open_water(pos, map)
+        }
+    }
+
+    /// Out of the water, where the player can reach it.
+    pub fn on_land(&self, map: &WorldMap) -> bool {
+        is_land(self.position, map)
+    }
+
+    /// Take one step, preferring `dir` and sidestepping anything in the way:
+    /// the two perpendicular directions are tried next, then turning back.
+    /// Wild animals also keep to their home biomes; companions go wherever
+    /// they can stand. Returns whether the animal moved.
+    pub fn step(
+        &mut self,
+        dir: Direction,
+        map: &WorldMap,
+        structures: &HashSet<Position>,
+        rng: &mut impl Rng,
+    ) -> bool {
+        let mut sides = match dir {
+            Direction::North | Direction::South => [Direction::East, Direction::West],
+            _ => [Direction::North, Direction::South],
+        };
+        if rng.gen_bool(0.5) {
+            sides.swap(0, 1);
+        }
+        let native = self.species.native_biomes();
+        for d in [dir, sides[0], sides[1], dir.opposite()] {
+            let next = self.position.move_in_direction(d);
+            if !self.can_occupy(next, map, structures) {
+                continue;
+            }
+            let in_range = self.tamed
+                || map
+                    .biome_at(&next)
+                    .map(|b| native.contains(&b))
+                    .unwrap_or(false);
+            if in_range {
+                self.position = next;
+                return true;
             }
         }
+        false
     }
 
     pub fn describe(&self) -> String {
@@ -567,6 +889,21 @@ impl Wildlife {
     }
 }
 
+/// Walkable ground that isn't lake.
+pub fn is_land(pos: Position, map: &WorldMap) -> bool {
+    map
+        .tile_at(&pos)
+        .map(|t| t.walkable && !matches!(t.tile_type, TileType::Lake))
+        .unwrap_or(false)
+}
+
+fn is_open_water(pos: Position, map: &WorldMap) -> bool {
+    map
+        .tile_at(&pos)
+        .map(|t| matches!(t.tile_type, TileType::Lake))
+        .unwrap_or(false)
+}
+
 /// Spawn initial wildlife for the world
 pub fn spawn_wildlife() -> Vec<Wildlife> {
     let mut wildlife = Vec::new();
@@ -614,3 +951,64 @@ pub fn spawn_wildlife() -> Vec<Wildlife> {
 
     wildlife
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// A deer's home ground right at the water's edge.
+    fn lakeshore(map: &WorldMap) -> (Position, Direction) {
+        let deer = Wildlife::new(Species::Deer, Position::new(0, 0));
+        let structures = HashSet::new();
+        map.positions()
+            .filter(|p| deer.can_occupy(*p, map, &structures))
+            .filter(|p| {
+                map.biome_at(p)
+                    .is_some_and(|b| Species::Deer.native_biomes().contains(&b))
+            })
+            .find_map(|p| {
+                [
+                    Direction::North,
+                    Direction::South,
+                    Direction::East,
+                    Direction::West,
+                ]
+                .into_iter()
+                .find(|d| {
+                    map.tile_at(&p.move_in_direction(*d))
+                        .is_some_and(|t| matches!(t.tile_type, TileType::Lake))
+                })
+                .map(|d| (p, d))
+            })
+            .expect("no lakeshore on the default map")
+    }
+
+    #[test]
+    fn a_deer_by_the_lake_never_walks_into_it() {
+        let map = WorldMap::new();
+        let weather = RegionalWeather::new();
+        let structures = HashSet::new();
+        let (shore, lakeward) = lakeshore(&map);
+        let mut deer = Wildlife::new(Species::Deer, shore);
+        let mut rng = StdRng::seed_from_u64(200);
+        for tick in 0..200 {
+            // Half the time it bolts from someone standing inland
+            if tick % 2 == 0 {
+                let inland = deer.position.move_in_direction(lakeward.opposite());
+                deer.flee_from(inland, &map, &structures, &mut rng);
+            } else {
+                deer.behavior = Behavior::Moving;
+                deer.update(TimeOfDay::Noon, &map, &weather, &structures, None);
+            }
+            let tile = map.tile_at(&deer.position).unwrap();
+            assert!(
+                !matches!(tile.tile_type, TileType::Lake),
+                "tick {}: deer in the lake at {:?}",
+                tick,
+                deer.position
+            );
+        }
+    }
+}
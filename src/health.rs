@@ -0,0 +1,95 @@
+//! A cheap liveness signal for operators who keep the server running for a
+//! long time: how long it's been up, where the world's clock stands, how
+//! much is queued for the player, and whether saves are landing. The server
+//! notes the world after every call; shared with the web thread, which
+//! renders it for `GET /healthz`.
+
+use crate::persistence::save_health::{self, SaveHealth};
+use serde::Serialize;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Where the world stood after the last call.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WorldPulse {
+    pub day: u32,
+    pub tick: u64,
+    /// Messages waiting to go out with the next reply.
+    pub pending_messages: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    /// "ok", or "degraded" while saves are failing.
+    pub status: &'static str,
+    pub uptime_secs: u64,
+    pub world: WorldPulse,
+    pub save: SaveHealth,
+    /// Whether a background ticker is alive. There isn't one: the world only
+    /// moves when a tool is called, so this is always null.
+    pub ticker_alive: Option<bool>,
+}
+
+impl Report {
+    pub fn healthy(&self) -> bool {
+        !self.save.failing()
+    }
+}
+
+struct Registry {
+    started: Instant,
+    world: WorldPulse,
+}
+
+fn registry() -> &'static Mutex<Registry> {
+    static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        Mutex::new(Registry {
+            started: Instant::now(),
+            world: WorldPulse::default(),
+        })
+    })
+}
+
+fn with_registry<T>(f: impl FnOnce(&mut Registry) -> T) -> T {
+    let mut guard = registry().lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut guard)
+}
+
+/// Note where the world stands now.
+pub fn record_world(pulse: WorldPulse) {
+    with_registry(|r| r.world = pulse);
+}
+
+/// Everything `GET /healthz` reports.
+pub fn report() -> Report {
+    let save = save_health::current();
+    let (uptime_secs, world) = with_registry(|r| (r.started.elapsed().as_secs(), r.world.clone()));
+    Report {
+        status: if save.failing() { "degraded" } else { "ok" },
+        uptime_secs,
+        world,
+        save,
+        ticker_alive: None,
+    }
+}
+
+/// The one-line footer for `status`: "World day 14, last saved 3s ago".
+pub fn footer(day: u32) -> String {
+    let saved = match save_health::current().last_saved_at {
+        Some(at) => {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(at);
+            let secs = now.saturating_sub(at);
+            match secs {
+                0..=59 => format!("last saved {}s ago", secs),
+                60..=3599 => format!("last saved {} min ago", secs / 60),
+                _ => format!("last saved {} h ago", secs / 3600),
+            }
+        }
+        None => "not saved yet".to_string(),
+    };
+    format!("World day {}, {}", day, saved)
+}
@@ -1,12 +1,14 @@
 mod actions;
 mod descriptions;
 mod entity;
+mod health;
 mod mcp;
+mod metrics;
 mod persistence;
 mod world;
 
 use anyhow::Result;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::thread;
 use std::time::Duration;
 use tiny_http::{Method, Request, Response, Server};
@@ -24,7 +26,8 @@ fn main() -> Result<()> {
     tracing::info!("A text-based healing nature simulation");
 
     // Determine state file path
-    let state_path = get_state_path();
+    // Pick up where an earlier run left off, even if it had to save elsewhere
+    let state_path = persistence::save_health::resume_path(&get_state_path());
     tracing::info!("State file: {:?}", state_path);
     let log_path = get_log_path(&state_path);
 
@@ -40,9 +43,9 @@ fn main() -> Result<()> {
 
     // Create and run the MCP server
     let mut server = mcp::McpServer::new(state_path, log_path);
-    server.run()?;
-
-    Ok(())
+    let outcome = server.run();
+    tracing::info!("{}", metrics::summary_line());
+    outcome
 }
 
 fn get_state_path() -> PathBuf {
@@ -83,11 +86,16 @@ fn start_web_server(state_path: PathBuf, log_path: PathBuf) {
             }
         };
 
-        let map = world::WorldMap::new();
+        // Until the game has answered a call, the world as the seed made it
+        let (seed, extent) = persistence::GameState::saved_map_settings(&state_path)
+            .unwrap_or_else(|| (world::world_seed_from_env(), world::map_extent_from_env()));
+        let generated = world::WorldMap::generate(seed, extent);
         loop {
             match server.recv_timeout(Duration::from_millis(250)) {
                 Ok(Some(request)) => {
-                    handle_http_request(request, &state_path, &log_path, &map);
+                    let live = world::live::latest_map();
+                    let map = live.as_deref().unwrap_or(&generated);
+                    handle_http_request(request, &state_path, &log_path, map);
                 }
                 Ok(None) => continue,
                 Err(e) => {
@@ -99,12 +107,9 @@ fn start_web_server(state_path: PathBuf, log_path: PathBuf) {
     });
 }
 
-fn handle_http_request(
-    rq: Request,
-    state_path: &PathBuf,
-    log_path: &PathBuf,
-    map: &world::WorldMap,
-) {
+fn handle_http_request(rq: Request, state_path: &Path, log_path: &Path, map: &world::WorldMap) {
+    // Follow the save if it has moved to the fallback since startup
+    let state_path = &persistence::save_health::save_path(state_path);
     let url = rq.url().to_string();
     let method = rq.method().clone();
     match (method, url.as_str()) {
@@ -129,6 +134,34 @@ fn handle_http_request(
                 ),
             );
         }
+        (Method::Get, "/metrics") => {
+            let body = metrics::render_prometheus();
+            let _ = rq.respond(
+                Response::from_string(body).with_header(
+                    tiny_http::Header::from_bytes(
+                        &b"Content-Type"[..],
+                        &b"text/plain; version=0.0.4"[..],
+                    )
+                    .unwrap(),
+                ),
+            );
+        }
+        (Method::Get, "/healthz") => {
+            let report = health::report();
+            let status = if report.healthy() { 200 } else { 503 };
+            let body = serde_json::to_string(&report).unwrap_or_default();
+            let _ = rq.respond(
+                Response::from_string(body)
+                    .with_status_code(status)
+                    .with_header(
+                        tiny_http::Header::from_bytes(
+                            &b"Content-Type"[..],
+                            &b"application/json"[..],
+                        )
+                        .unwrap(),
+                    ),
+            );
+        }
         (Method::Get, "/log") => {
             let body = build_log_json(log_path);
             let _ = rq.respond(
@@ -138,6 +171,44 @@ fn handle_http_request(
                 ),
             );
         }
+        (Method::Get, "/transcript") => {
+            let entries = persistence::transcript::latest(state_path)
+                .map(|path| persistence::transcript::read_entries(&path))
+                .unwrap_or_default();
+            let body = persistence::transcript::render_html(&entries);
+            let _ = rq.respond(
+                Response::from_string(body).with_header(
+                    tiny_http::Header::from_bytes(
+                        &b"Content-Type"[..],
+                        &b"text/html; charset=utf-8"[..],
+                    )
+                    .unwrap(),
+                ),
+            );
+        }
+        (Method::Get, "/events.json") => {
+            let body = build_events_json(log_path);
+            let _ = rq.respond(
+                Response::from_string(body).with_header(
+                    tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+                        .unwrap(),
+                ),
+            );
+        }
+        (Method::Get, u) if u == "/tile" || u.starts_with("/tile?") => {
+            let (status, body) = persistence::tile_report::tile_json(&url, state_path, map);
+            let _ = rq.respond(
+                Response::from_string(body)
+                    .with_status_code(status)
+                    .with_header(
+                        tiny_http::Header::from_bytes(
+                            &b"Content-Type"[..],
+                            &b"application/json"[..],
+                        )
+                        .unwrap(),
+                    ),
+            );
+        }
         _ => {
             let _ = rq.respond(Response::from_string("Not Found").with_status_code(404));
         }
@@ -159,6 +230,16 @@ pre { margin:0; font-size:14px; line-height:16px; }
 .logline { margin:0 0 8px 0; padding:8px; background:#141b26; border-radius:6px; border:1px solid #1f2935; }
 .badge { display:inline-block; padding:2px 6px; margin-right:6px; border-radius:4px; font-size:12px; background:#233149; color:#9cc3ff; }
 .legend { margin:0 0 8px 0; }
+.now { margin:0 0 12px 0; padding:8px; background:#141b26; border-radius:6px; border:1px solid #1f2935; font-size:13px; }
+.now .regions span { margin-right:10px; }
+.now .concern { margin-top:4px; color:#ffb38a; }
+.badge.fire { background:#4a2418; color:#ffab76; }
+.badge.food { background:#2f3a1a; color:#c8e07d; }
+.badge.wildlife { background:#1d3a2c; color:#8fe0b0; }
+.badge.weather { background:#1d2c45; color:#9dc7ff; }
+.badge.discovery { background:#3d3316; color:#ffd166; }
+.logline .when { float:right; font-size:11px; color:#6b7687; }
+.logline .text { white-space:pre-wrap; }
 </style>
 </head>
 <body>
@@ -166,6 +247,7 @@ pre { margin:0; font-size:14px; line-height:16px; }
   <div id="map"><pre id="map-pre"></pre></div>
   <div class="panel">
     <h2>Activity</h2>
+    <div id="now" class="now"></div>
     <div id="log"></div>
   </div>
 </div>
@@ -181,6 +263,7 @@ const palette = {
    Clearing:'#e0d9c7',
   Cabin:'#ffd166',
   WoodShed:'#f48fb1',
+  Raft:'#c98b4a',
   Player:'#ffda5a'
 };
 
@@ -199,9 +282,10 @@ function renderMap(data) {
       const tile = data.tiles[r][c];
       const isPlayer = data.player && data.player.row === r && data.player.col === c;
       const visited = tile.visited !== false;
+      const charted = !visited && tile.charted === true;
       const glyph = (() => {
         if (isPlayer) return '@';
-        if (!visited) return '?';
+        if (!visited && !charted) return '?';
         switch (tile.tile) {
           case 'Cabin': return 'C';
           case 'WoodShed': return 'W';
@@ -209,6 +293,7 @@ function renderMap(data) {
           case 'Path': return '#';
           case 'Lake': return '~';
           case 'CaveEntrance': return '>';
+          case 'Raft': return 'R';
           case 'Forest': return tile.biome === 'WinterForest' ? '^' : tile.biome === 'Desert' ? '.' : 'T';
           default: return '.';
         }
@@ -217,7 +302,9 @@ function renderMap(data) {
         ? palette.Player
         : visited
           ? (palette[tile.biome] || '#9ea7b8')
-          : '#3a4353';
+          : charted
+            ? '#5d6878'
+            : '#3a4353';
       line += `<span style="color:${color}">${glyph}</span>`;
     }
     lines.push(line);
@@ -225,22 +312,47 @@ function renderMap(data) {
   pre.innerHTML = lines.join('<br>');
 }
 
-function renderLog(lines) {
+const icons = {
+  fire:'🔥', food:'🍲', wildlife:'🐾', weather:'⛅', discovery:'✨', travel:'🧭', log:'•'
+};
+
+function escapeHtml(s) {
+  return s.replace(/[&<>"]/g, ch => ({'&':'&amp;','<':'&lt;','>':'&gt;','"':'&quot;'}[ch]));
+}
+
+function renderNow(now) {
+  const el = document.getElementById('now');
+  if (!now) { el.innerHTML = ''; return; }
+  const regions = now.regions
+    .map(r => `<span title="${escapeHtml(r.weather)}">${r.glyph} ${escapeHtml(r.region)}</span>`)
+    .join('');
+  const concern = now.concern
+    ? `<div class="concern">⚠ ${escapeHtml(now.concern)}</div>`
+    : '';
+  el.innerHTML = `<div>${escapeHtml(now.time)} · ${now.time_of_day}</div>`
+    + `<div class="regions">${regions}</div>${concern}`;
+}
+
+function renderLog(events) {
   const logEl = document.getElementById('log');
   logEl.innerHTML = '';
-  lines.slice(-50).reverse().forEach(line => {
+  events.slice(-50).reverse().forEach(ev => {
     const div = document.createElement('div');
     div.className = 'logline';
-    div.innerHTML = `<span class="badge">log</span>${line}`;
+    const when = new Date(ev.time * 1000).toLocaleTimeString();
+    div.innerHTML = `<span class="badge ${ev.category}">${icons[ev.category] || '•'} ${ev.category}</span>`
+      + `<span class="badge">${escapeHtml(ev.tool)}</span><span class="when">${when}</span>`
+      + `<div class="text">${escapeHtml(ev.text)}</div>`;
     logEl.appendChild(div);
   });
 }
 
 async function tick() {
   try {
-    const [state, log] = await Promise.all([fetchJson('/state'), fetchJson('/log')]);
+    const [state, events] = await Promise.all([fetchJson('/state'), fetchJson('/events.json')]);
     renderMap(state);
-    renderLog(log);
+    renderNow(state.now);
+    renderLog(events);
   } catch (e) {
     console.error(e);
   } finally {
@@ -260,6 +372,24 @@ struct StateView {
     height: usize,
     player: Option<PositionView>,
     tiles: Vec<Vec<TileView>>,
+    /// The live strip above the activity log; absent until there's a save.
+    now: Option<NowView>,
+}
+
+#[derive(serde::Serialize)]
+struct NowView {
+    time: String,
+    time_of_day: &'static str,
+    regions: Vec<RegionWeatherView>,
+    /// The most pressing line from `status`'s concerns, if anything looms.
+    concern: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+struct RegionWeatherView {
+    region: String,
+    weather: &'static str,
+    glyph: &'static str,
 }
 
 #[derive(serde::Serialize)]
@@ -273,23 +403,23 @@ struct TileView {
     biome: String,
     tile: String,
     visited: bool,
+    /// Known from the ancient map but never walked.
+    charted: bool,
 }
 
-fn build_state_json(state_path: &PathBuf, map: &world::WorldMap) -> String {
+fn build_state_json(state_path: &Path, map: &world::WorldMap) -> String {
     let loaded_state = persistence::GameState::load(state_path).ok();
     let object_view = loaded_state.as_ref().map(|s| &s.objects);
     let visited_view = loaded_state.as_ref().map(|s| &s.player.visited);
+    let charted_view = loaded_state.as_ref().map(|s| &s.player.charted);
     let player_world_pos = loaded_state.as_ref().map(|s| s.player.position);
 
-    let mut tiles = Vec::with_capacity(world::map::MAP_HEIGHT);
-    for r in 0..world::map::MAP_HEIGHT {
-        let mut row = Vec::with_capacity(world::map::MAP_WIDTH);
-        for c in 0..world::map::MAP_WIDTH {
+    let mut tiles = Vec::with_capacity(map.height());
+    for r in 0..map.height() {
+        let mut row = Vec::with_capacity(map.width());
+        for c in 0..map.width() {
             if let Some(t) = map.get_tile(r, c) {
-                let world_pos = world::Position::new(
-                    r as i32 - world::map::MAP_ORIGIN_ROW,
-                    c as i32 - world::map::MAP_ORIGIN_COL,
-                );
+                let world_pos = map.position_of(r, c);
 
                 let mut tile = match t.tile_type {
                     world::TileType::Lake => "Lake",
@@ -306,6 +436,12 @@ fn build_state_json(state_path: &PathBuf, map: &world::WorldMap) -> String {
                         .any(|o| matches!(o.object.kind, world::ObjectKind::Cabin(_)))
                     {
                         tile = "Cabin".to_string();
+                    } else if objects
+                        .objects_at(&world_pos)
+                        .iter()
+                        .any(|o| matches!(o.object.kind, world::ObjectKind::Raft(_)))
+                    {
+                        tile = "Raft".to_string();
                     } else if objects
                         .objects_at(&world_pos)
                         .iter()
@@ -327,10 +463,16 @@ fn build_state_json(state_path: &PathBuf, map: &world::WorldMap) -> String {
                     None => true,
                 } || player_world_pos.map(|p| p == world_pos).unwrap_or(false);
 
+                let charted = !visited
+                    && charted_view
+                        .map(|set| set.contains(&world_pos))
+                        .unwrap_or(false);
+
                 row.push(TileView {
                     biome: t.biome.name().to_string(),
                     tile,
                     visited,
+                    charted,
                 });
             }
         }
@@ -339,21 +481,39 @@ fn build_state_json(state_path: &PathBuf, map: &world::WorldMap) -> String {
 
     let mut player_pos = None;
     if let Some(state) = loaded_state.as_ref() {
-        if let Some((row, col)) = state.player.position.as_usize() {
+        if let Some((row, col)) = map.index_of(&state.player.position) {
             player_pos = Some(PositionView { row, col });
         }
     }
 
+    let now = loaded_state.as_ref().map(|state| NowView {
+        time: state.time.formatted_time(),
+        time_of_day: state.time.time_of_day().name(),
+        regions: world::Region::ALL
+            .iter()
+            .map(|&region| {
+                let weather = state.weather.for_region(region);
+                RegionWeatherView {
+                    region: state.region_names.name(region).to_string(),
+                    weather: weather.name(),
+                    glyph: weather.glyph(),
+                }
+            })
+            .collect(),
+        concern: actions::top_concern(state, map),
+    });
+
     serde_json::to_string(&StateView {
-        width: world::map::MAP_WIDTH,
-        height: world::map::MAP_HEIGHT,
+        width: map.width(),
+        height: map.height(),
         player: player_pos,
         tiles,
+        now,
     })
     .unwrap_or_else(|_| "{}".to_string())
 }
 
-fn build_log_json(log_path: &PathBuf) -> String {
+fn build_log_json(log_path: &Path) -> String {
     use std::fs;
     if let Ok(data) = fs::read_to_string(log_path) {
         let mut lines: Vec<_> = data.lines().map(|s| s.to_string()).collect();
@@ -365,3 +525,224 @@ fn build_log_json(log_path: &PathBuf) -> String {
         "[]".to_string()
     }
 }
+
+/// One tool call from the web log, sorted into a badge for the activity feed.
+#[derive(serde::Serialize)]
+struct EventView {
+    time: u64,
+    tool: String,
+    category: &'static str,
+    text: String,
+}
+
+/// Which badge a logged tool call wears. The tool decides most; `use`,
+/// `talk` and the like go by what the first line of their result is about.
+fn event_category(tool: &str, text: &str) -> &'static str {
+    match tool {
+        "tend_fire" => return "fire",
+        "eat" | "drink" | "fish" => return "food",
+        "approach" | "catch" | "rescue" | "mount" | "throw" => return "wildlife",
+        "forecast" => return "weather",
+        "search" | "examine" => return "discovery",
+        "move" | "look" | "enter" | "exit" | "board" | "disembark" => return "travel",
+        _ => {}
+    }
+    let first = text.lines().next().unwrap_or("").to_lowercase();
+    let words: Vec<&str> = first
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+        .collect();
+    // Phrases match anywhere; single words only at the start of a word, so
+    // "eat" finds "eating" but not "heat".
+    let about = |keys: &[&str]| {
+        keys.iter().any(|k| {
+            if k.contains(' ') {
+                first.contains(k)
+            } else {
+                words.iter().any(|w| w.starts_with(k))
+            }
+        })
+    };
+    if about(&["fire", "flame", "hearth", "ember", "kindling"]) {
+        "fire"
+    } else if about(&["cook", "eat", "meal", "fish", "berries", "drink"]) {
+        "food"
+    } else if about(&[
+        "rabbit",
+        "deer",
+        "fox",
+        "bird",
+        "squirrel",
+        "boar",
+        "bear",
+        "wolf",
+        "dog",
+        "pig",
+        "companion",
+    ]) {
+        "wildlife"
+    } else if about(&["you find", "you found", "discover", "hidden", "you notice"]) {
+        "discovery"
+    } else if about(&["rain", "snow", "storm", "fog", "wind"]) {
+        "weather"
+    } else {
+        "log"
+    }
+}
+
+/// The web log as categorized events. Each call was written as
+/// `[timestamp] [tool] text`; lines without that prefix continue the text
+/// of the call above them.
+fn build_events_json(log_path: &Path) -> String {
+    let data = std::fs::read_to_string(log_path).unwrap_or_default();
+    let mut events: Vec<EventView> = Vec::new();
+    for line in data.lines() {
+        let entry = line.strip_prefix('[').and_then(|rest| {
+            let (time, rest) = rest.split_once("] [")?;
+            let (tool, text) = rest.split_once("] ")?;
+            Some((time.parse::<u64>().ok()?, tool, text))
+        });
+        match (entry, events.last_mut()) {
+            (Some((time, tool, text)), _) => events.push(EventView {
+                time,
+                tool: tool.to_string(),
+                category: "log",
+                text: text.to_string(),
+            }),
+            (None, Some(last)) => {
+                last.text.push('\n');
+                last.text.push_str(line);
+            }
+            (None, None) => {}
+        }
+    }
+    if events.len() > 100 {
+        events.drain(..events.len() - 100);
+    }
+    for event in &mut events {
+        event.category = event_category(&event.tool, &event.text);
+    }
+    serde_json::to_string(&events).unwrap_or_else(|_| "[]".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("rubber-duck-web-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn the_event_feed_sorts_each_call_under_a_badge() {
+        let log_path = scratch("events").join("web_log.txt");
+        let mut log = String::from("stray line before any call\n");
+        log.push_str("[100] [fish] A small fish bites.\n");
+        log.push_str("[101] [use] The kindling catches and the flame climbs.\nIt crackles.\n");
+        log.push_str("[102] [talk] A rabbit twitches its ears at you.\n");
+        log.push_str("[103] [wait] The heat shimmers; you wait.\n");
+        log.push_str("[104] [use] You find a key under the floorboard.\n");
+        std::fs::write(&log_path, &log).unwrap();
+
+        let events: Value = serde_json::from_str(&build_events_json(&log_path)).unwrap();
+        let events = events.as_array().unwrap();
+        let categories: Vec<&str> = events
+            .iter()
+            .map(|e| e["category"].as_str().unwrap())
+            .collect();
+        assert_eq!(categories, ["food", "fire", "wildlife", "log", "discovery"]);
+        assert_eq!(events[1]["time"], 101);
+        assert_eq!(events[1]["tool"], "use");
+        let text = events[1]["text"].as_str().unwrap();
+        assert_eq!(
+            text,
+            "The kindling catches and the flame climbs.\nIt crackles."
+        );
+
+        // Only the latest hundred are served
+        let long: String = (0..120)
+            .map(|i| format!("[{}] [look] Trees.\n", i))
+            .collect();
+        std::fs::write(&log_path, long).unwrap();
+        let events: Value = serde_json::from_str(&build_events_json(&log_path)).unwrap();
+        let events = events.as_array().unwrap();
+        assert_eq!(events.len(), 100);
+        assert_eq!(events[0]["time"], 20);
+        assert_eq!(events[99]["category"], "travel");
+    }
+
+    #[test]
+    fn the_state_payload_carries_a_now_strip_once_there_is_a_save() {
+        let state_path = scratch("now").join("world_state.json");
+        let map = world::WorldMap::new();
+        let view: Value = serde_json::from_str(&build_state_json(&state_path, &map)).unwrap();
+        assert!(view["now"].is_null());
+
+        let mut state = persistence::GameState::new(&map);
+        let weather = &mut state.weather;
+        (weather.north, weather.south) = (world::Weather::Clear, world::Weather::Fog);
+        (weather.east, weather.west) = (world::Weather::Blizzard, world::Weather::Sandstorm);
+        state.player.hydration = 5.0;
+        state.save(&state_path).unwrap();
+
+        let view: Value = serde_json::from_str(&build_state_json(&state_path, &map)).unwrap();
+        let now = &view["now"];
+        assert_eq!(now["time"], state.time.formatted_time());
+        assert_eq!(now["time_of_day"], state.time.time_of_day().name());
+        let regions = now["regions"].as_array().unwrap();
+        assert_eq!(regions.len(), 4);
+        let west = regions
+            .iter()
+            .find(|r| r["weather"] == world::Weather::Sandstorm.name())
+            .unwrap();
+        assert_eq!(west["glyph"], "🌪");
+        assert_eq!(
+            west["region"],
+            state.region_names.name(world::Region::GlassDesert)
+        );
+        let concern = now["concern"].as_str().unwrap();
+        assert!(concern.contains("→"), "{}", concern);
+
+        // The page reads both from the payload rather than from log text
+        let html = build_index_html();
+        assert!(html.contains(r#"<div id="now" class="now"></div>"#));
+        assert!(html.contains("fetchJson('/events.json')"));
+        assert!(html.contains("renderNow(state.now);"));
+        for category in ["fire", "food", "wildlife", "weather", "discovery"] {
+            assert!(html.contains(&format!(".badge.{} {{", category)));
+        }
+    }
+
+    #[test]
+    fn charted_tiles_render_dimly_until_they_are_walked() {
+        let state_path = scratch("charted").join("world_state.json");
+        let map = world::WorldMap::new();
+        let mut state = persistence::GameState::new(&map);
+        state.study_ancient_map(&map);
+        let walked = *state.player.charted.iter().next().unwrap();
+        state.player.charted.remove(&walked);
+        state.player.visited.insert(walked);
+        state.save(&state_path).unwrap();
+
+        let view: Value = serde_json::from_str(&build_state_json(&state_path, &map)).unwrap();
+        let tile = |pos: &world::Position| {
+            let (r, c) = map.index_of(pos).unwrap();
+            view["tiles"][r][c].clone()
+        };
+        for pos in &state.player.charted {
+            assert_eq!(tile(pos)["charted"], true);
+            assert_eq!(tile(pos)["visited"], false);
+        }
+        assert_eq!(tile(&walked)["charted"], false);
+        assert_eq!(tile(&walked)["visited"], true);
+
+        let html = build_index_html();
+        assert!(html.contains("const charted = !visited && tile.charted === true;"));
+        assert!(html.contains("if (!visited && !charted) return '?';"));
+    }
+}
@@ -0,0 +1,196 @@
+//! Cutting JSON-RPC messages out of the input stream. Not every client
+//! writes exactly one message per line: some batch two onto a line, some
+//! flush one across several. Messages are read as complete JSON values
+//! wherever the newlines fall, with a cap on how much is held waiting for
+//! one to finish.
+
+use serde::de::IgnoredAny;
+use std::io::{self, BufRead};
+
+/// Bytes held waiting for a message to finish before it's given up on.
+pub const MAX_MESSAGE_BYTES: usize = 4 * 1024 * 1024;
+
+/// One thing read off the stream.
+#[derive(Debug)]
+pub enum Frame {
+    /// A complete JSON value, exactly as written.
+    Message(String),
+    /// Bytes that can't be a message, dropped through the end of their
+    /// line so the next one can still be read.
+    Garbage { oversized: bool },
+}
+
+pub struct MessageReader<R> {
+    input: R,
+    buf: Vec<u8>,
+    /// Set after garbage, until the rest of its line has gone by.
+    skip_line: bool,
+    /// Whether the buffer may hold a whole message it didn't before. An
+    /// unfinished one isn't parsed again until a chunk could close it.
+    worth_parsing: bool,
+}
+
+impl<R: BufRead> MessageReader<R> {
+    pub fn new(input: R) -> Self {
+        Self {
+            input,
+            buf: Vec::new(),
+            skip_line: false,
+            worth_parsing: true,
+        }
+    }
+
+    /// The next frame, or `None` once the input ends.
+    pub fn next_frame(&mut self) -> io::Result<Option<Frame>> {
+        loop {
+            if self.skip_line {
+                match self.buf.iter().position(|b| *b == b'\n') {
+                    Some(newline) => {
+                        self.buf.drain(..=newline);
+                        self.skip_line = false;
+                        self.worth_parsing = true;
+                    }
+                    None => self.buf.clear(),
+                }
+            }
+            if !self.skip_line && self.worth_parsing {
+                if let Some(frame) = self.take_frame() {
+                    return Ok(Some(frame));
+                }
+            }
+
+            let chunk = self.input.fill_buf()?;
+            if chunk.is_empty() {
+                // The input ended partway through something
+                let unfinished = !self.skip_line && !self.buf.is_empty();
+                self.buf.clear();
+                return Ok(unfinished.then_some(Frame::Garbage { oversized: false }));
+            }
+            let read = chunk.len();
+            self.worth_parsing = chunk.iter().any(|b| matches!(b, b'}' | b']'))
+                || self.buf.len() + read > MAX_MESSAGE_BYTES;
+            self.buf.extend_from_slice(chunk);
+            self.input.consume(read);
+        }
+    }
+
+    /// Cut the first complete value off the buffer, if there's one yet.
+    fn take_frame(&mut self) -> Option<Frame> {
+        let Some(start) = self.buf.iter().position(|b| !b.is_ascii_whitespace()) else {
+            self.buf.clear();
+            return None;
+        };
+        let mut values =
+            serde_json::Deserializer::from_slice(&self.buf[start..]).into_iter::<IgnoredAny>();
+        match values.next()? {
+            Ok(_) => {
+                let end = start + values.byte_offset();
+                let oversized = end - start > MAX_MESSAGE_BYTES;
+                let text = String::from_utf8_lossy(&self.buf[start..end]).into_owned();
+                self.buf.drain(..end);
+                Some(if oversized {
+                    Frame::Garbage { oversized }
+                } else {
+                    Frame::Message(text)
+                })
+            }
+            Err(e) if e.is_eof() => {
+                if self.buf.len() - start > MAX_MESSAGE_BYTES {
+                    self.drop_line(start);
+                    Some(Frame::Garbage { oversized: true })
+                } else {
+                    self.worth_parsing = false;
+                    None
+                }
+            }
+            Err(_) => {
+                self.drop_line(start);
+                Some(Frame::Garbage { oversized: false })
+            }
+        }
+    }
+
+    /// Drop the line the garbage at `start` is on, or everything so far and
+    /// the rest of the line as it comes if it hasn't ended yet.
+    fn drop_line(&mut self, start: usize) {
+        match self.buf[start..].iter().position(|b| *b == b'\n') {
+            Some(newline) => {
+                self.buf.drain(..=start + newline);
+            }
+            None => {
+                self.buf.clear();
+                self.skip_line = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufReader, Cursor};
+
+    /// Everything read off `input`, with garbage shown as a marker.
+    fn frames(input: impl BufRead) -> Vec<String> {
+        let mut reader = MessageReader::new(input);
+        let mut out = Vec::new();
+        while let Some(frame) = reader.next_frame().unwrap() {
+            out.push(match frame {
+                Frame::Message(text) => text,
+                Frame::Garbage { oversized: false } => "<garbage>".to_string(),
+                Frame::Garbage { oversized: true } => "<oversized>".to_string(),
+            });
+        }
+        out
+    }
+
+    fn text(input: &str) -> Vec<String> {
+        frames(Cursor::new(input.as_bytes().to_vec()))
+    }
+
+    #[test]
+    fn two_messages_on_one_line_are_two_messages() {
+        assert_eq!(text("{\"id\":1}{\"id\":2}\n"), ["{\"id\":1}", "{\"id\":2}"]);
+    }
+
+    #[test]
+    fn a_message_split_across_reads_comes_out_whole() {
+        let message = "{\"jsonrpc\":\"2.0\",\"id\":7,\"params\":{\"list\":[1,2,3]}}";
+        let input = format!("{}\n{}", message, message);
+        for chunk in [1, 2, 5, 13] {
+            let reads = BufReader::with_capacity(chunk, Cursor::new(input.clone().into_bytes()));
+            assert_eq!(frames(reads), [message, message], "{} bytes a read", chunk);
+        }
+    }
+
+    #[test]
+    fn whitespace_between_messages_is_passed_over() {
+        let input = "  \n{\"id\":1} \t\r\n\n   {\"id\":2}\n  \n";
+        assert_eq!(text(input), ["{\"id\":1}", "{\"id\":2}"]);
+    }
+
+    #[test]
+    fn a_message_cut_off_by_the_end_of_input_is_garbage() {
+        assert_eq!(
+            text("{\"id\":1}\n{\"id\":2,\"met"),
+            ["{\"id\":1}", "<garbage>"]
+        );
+    }
+
+    #[test]
+    fn garbage_is_dropped_to_the_end_of_its_line() {
+        let input = "not json at all {\"id\":0}\n{\"id\":1}\n";
+        assert_eq!(text(input), ["<garbage>", "{\"id\":1}"]);
+    }
+
+    #[test]
+    fn a_message_past_the_cap_is_dropped_and_the_next_still_read() {
+        let huge = format!("{{\"pad\":\"{}\"}}", "x".repeat(MAX_MESSAGE_BYTES));
+        let input = format!("{}\n{{\"id\":1}}\n", huge);
+        assert_eq!(text(&input), ["<oversized>", "{\"id\":1}"]);
+
+        // Still unfinished when it passes the cap, read a piece at a time
+        let reads = BufReader::with_capacity(64 * 1024, Cursor::new(input.into_bytes()));
+        assert_eq!(frames(reads), ["<oversized>", "{\"id\":1}"]);
+    }
+}
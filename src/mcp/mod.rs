@@ -1,3 +1,4 @@
+pub mod framing;
 pub mod protocol;
 pub mod server;
 pub mod tools;
@@ -2,11 +2,15 @@ use anyhow::Result;
 use serde_json::{json, Value};
 use std::io::{BufRead, Write};
 
+use super::framing::{Frame, MessageReader, MAX_MESSAGE_BYTES};
 use super::protocol::*;
 use super::tools::*;
 use crate::actions::*;
 use crate::descriptions::*;
 use crate::entity::*;
+use crate::persistence::save_health;
+use crate::persistence::snapshots::{self, PendingSnapshot};
+use crate::persistence::transcript::{self, Transcript, TranscriptEntry};
 use crate::persistence::*;
 use crate::world::*;
 
@@ -14,49 +18,87 @@ pub struct McpServer {
     world: World,
     initialized: bool,
     log_path: std::path::PathBuf,
+    transcript: Transcript,
+    /// Whether the current call's action was turned down before it was
+    /// tried: nothing by that name, nothing in hand, the wrong place, too
+    /// tired. Counted as a failure in the metrics, though the player just
+    /// reads why.
+    declined: bool,
 }
 
 impl McpServer {
     pub fn new(state_path: std::path::PathBuf, log_path: std::path::PathBuf) -> Self {
         Self {
+            transcript: Transcript::new(&state_path),
             world: World::new(state_path),
             initialized: false,
             log_path,
+            declined: false,
         }
     }
 
     /// Run the MCP server, reading from stdin and writing to stdout
     pub fn run(&mut self) -> Result<()> {
         let stdin = std::io::stdin();
-        let mut stdout = std::io::stdout();
+        self.serve(stdin.lock(), std::io::stdout())
+    }
 
+    /// Serve JSON-RPC from any reader to any writer, so the protocol loop can
+    /// be driven by an in-memory pair as well as real stdio. Messages are
+    /// usually one per line but needn't be; see `framing`.
+    pub fn serve(&mut self, input: impl BufRead, mut output: impl Write) -> Result<()> {
         tracing::info!("MCP Server starting...");
 
-        for line in stdin.lock().lines() {
-            let line = line?;
-            if line.is_empty() {
-                continue;
-            }
-
-            tracing::debug!("Received: {}", line);
-
-            let response = self.handle_message(&line);
+        self.note_health();
+        let mut reader = MessageReader::new(input);
+        while let Some(frame) = reader.next_frame()? {
+            let response = match frame {
+                Frame::Message(message) => {
+                    tracing::debug!("Received: {}", message);
+                    self.handle_message(&message)
+                }
+                Frame::Garbage { oversized: true } => {
+                    tracing::error!("Dropped a message over {} bytes", MAX_MESSAGE_BYTES);
+                    JsonRpcResponse::error(None, JsonRpcError::message_too_large(MAX_MESSAGE_BYTES))
+                }
+                Frame::Garbage { oversized: false } => {
+                    tracing::error!("Dropped input that isn't JSON");
+                    JsonRpcResponse::error(None, JsonRpcError::parse_error())
+                }
+            };
 
             let response_json = serde_json::to_string(&response)?;
             tracing::debug!("Sending: {}", response_json);
 
-            writeln!(stdout, "{}", response_json)?;
-            stdout.flush()?;
+            writeln!(output, "{}", response_json)?;
+            output.flush()?;
 
             // Save state after each interaction
+            let saving_to = self.world.state_path.clone();
             if let Err(e) = self.world.save() {
                 tracing::warn!("Failed to save state: {}", e);
             }
+            // Saving has moved to the fallback; the transcript goes beside it
+            if self.world.state_path != saving_to {
+                self.transcript = Transcript::new(&self.world.state_path);
+            }
+            self.note_health();
+            crate::world::live::share_map(&self.world.map);
         }
 
         Ok(())
     }
 
+    /// Tell the health report where the world stands.
+    fn note_health(&self) {
+        let state = &self.world.state;
+        crate::health::record_world(crate::health::WorldPulse {
+            day: state.time.day,
+            tick: state.time.tick,
+            pending_messages: state.pending_messages.len(),
+        });
+    }
+
     fn handle_message(&mut self, message: &str) -> JsonRpcResponse {
         let request: JsonRpcRequest = match serde_json::from_str(message) {
             Ok(r) => r,
@@ -76,6 +118,8 @@ impl McpServer {
             }
             "tools/list" => self.handle_tools_list(id),
             "tools/call" => self.handle_tools_call(id, request.params),
+            "resources/list" => self.handle_resources_list(id),
+            "resources/read" => self.handle_resources_read(id, request.params),
             method => {
                 tracing::warn!("Unknown method: {}", method);
                 JsonRpcResponse::error(id, JsonRpcError::method_not_found(method))
@@ -92,6 +136,10 @@ impl McpServer {
                 tools: Some(ToolsCapability {
                     list_changed: false,
                 }),
+                resources: Some(ResourcesCapability {
+                    subscribe: false,
+                    list_changed: false,
+                }),
             },
             server_info: ServerInfo {
                 name: "rubber-duck-mcp".to_string(),
@@ -108,6 +156,48 @@ impl McpServer {
         JsonRpcResponse::success(id, serde_json::to_value(result).unwrap())
     }
 
+    fn handle_resources_list(&self, id: Option<Value>) -> JsonRpcResponse {
+        let resources = get_resource_definitions();
+        let result = ResourcesListResult { resources };
+        JsonRpcResponse::success(id, serde_json::to_value(result).unwrap())
+    }
+
+    fn handle_resources_read(&self, id: Option<Value>, params: Option<Value>) -> JsonRpcResponse {
+        let Some(read) = params.and_then(|p| serde_json::from_value::<ReadResourceParams>(p).ok())
+        else {
+            return JsonRpcResponse::error(
+                id,
+                JsonRpcError::invalid_params("Missing resource uri"),
+            );
+        };
+        if read.uri != TRANSCRIPT_URI {
+            return JsonRpcResponse::error(
+                id,
+                JsonRpcError::invalid_params(&format!("Unknown resource: {}", read.uri)),
+            );
+        }
+        let entries = self
+            .transcript
+            .path()
+            .map(transcript::read_entries)
+            .unwrap_or_default();
+        let mut text = transcript::render_text(&entries);
+        if !self.world.state.player.record_transcript {
+            text = format!(
+                "*Recording is off; turn it on with settings transcript on.*\n\n{}",
+                text
+            );
+        }
+        let result = ReadResourceResult {
+            contents: vec![ResourceContents {
+                uri: read.uri,
+                mime_type: "text/markdown".to_string(),
+                text,
+            }],
+        };
+        JsonRpcResponse::success(id, serde_json::to_value(result).unwrap())
+    }
+
     fn handle_tools_call(&mut self, id: Option<Value>, params: Option<Value>) -> JsonRpcResponse {
         let call_params: CallToolParams = match params.and_then(|p| serde_json::from_value(p).ok())
         {
@@ -129,16 +219,48 @@ impl McpServer {
     }
 
     fn execute_tool(&mut self, name: &str, args: &Option<Value>) -> CallToolResult {
-        let result = match name {
+        let started = std::time::Instant::now();
+        // Unknown names share one series so callers can't grow the registry
+        let mut metric_name = name;
+        if let Err(msg) = validate_args(name, args) {
+            crate::metrics::record_tool(name, true, started.elapsed());
+            let result = CallToolResult::error(msg);
+            self.record_transcript(name, args, &result);
+            return result;
+        }
+        let sheltered = self.world.state.sheltered();
+        let mood = self.world.state.player.mood;
+        let tick = self.world.state.time.tick;
+        self.declined = false;
+        let mut result = match name {
             "look" => self.cmd_look(args),
             "move" => self.cmd_move(args),
             "enter" => self.cmd_enter(args),
-            "exit" => self.cmd_exit(args),
+            "exit" | "disembark" => self.cmd_exit(args),
+            "board" => self.cmd_board(args),
             "examine" => self.cmd_examine(args),
+            "search" => self.cmd_search(args),
+            "catch" => self.cmd_catch(args),
+            "approach" => self.cmd_approach(args),
+            "rescue" => self.cmd_rescue(args),
             "take" => self.cmd_take(args),
             "drop" => self.cmd_drop(args),
+            "store" => self.cmd_store(args),
             "use" => self.cmd_use(args),
             "fish" => self.cmd_fish(args),
+            "tend_fire" => self.cmd_tend_fire(args),
+            "tidy" => self.cmd_tidy(args),
+            "sit" => self.cmd_sit(args),
+            "mount" => self.cmd_mount(args),
+            "hold" => self.cmd_hold(args),
+            "stow" => self.cmd_stow(args),
+            "throw" => self.cmd_throw(args),
+            "lakeside" => self.cmd_lakeside(args),
+            "help" => self.cmd_help(args),
+            "forecast" => self.cmd_forecast(args),
+            "body" => self.cmd_body(args),
+            "settings" => self.cmd_settings(args),
+            "saves" => self.cmd_saves(args),
             "create" => self.cmd_create(args),
             "write" => self.cmd_write(args),
             "open" => self.cmd_open(args),
@@ -146,6 +268,7 @@ impl McpServer {
             "inventory" => self.cmd_inventory(args),
             "status" => self.cmd_status(args),
             "meditate" => self.cmd_meditate(args),
+            "eat" => self.cmd_eat(args),
             "drink" => self.cmd_drink(args),
             "sleep" => self.cmd_sleep(args),
             "wait" => self.cmd_wait(args),
@@ -155,11 +278,87 @@ impl McpServer {
             "simulate" => self.cmd_simulate(args),
             "time" => self.cmd_time(args),
             "skills" => self.cmd_skills(args),
-            _ => CallToolResult::error(format!("Unknown tool: {}", name)),
+            "plan" => self.cmd_plan(args),
+            "projects" => self.cmd_projects(args),
+            "debug_tile" if debug_tools_enabled() => self.cmd_debug_tile(args),
+            _ => {
+                metric_name = "unknown";
+                CallToolResult::error(format!("Unknown tool: {}", name))
+            }
+        };
+
+        if result.is_error != Some(true) {
+            self.world.state.note_tool(metric_name);
+        }
+        // The week's high and low points go in the duck's letter
+        if let Some(ToolContent::Text { text }) = result.content.first() {
+            let state = &mut self.world.state;
+            let moved = state.player.mood - mood;
+            state.letters.week.note_moment(state.time.day, moved, text);
+        }
+        self.world.state.audit_cards(&mut self.world.map);
+        self.world.state.audit_unique_items(&mut self.world.map);
+        self.world.state.keep_player_on_map(&self.world.map);
+        let player = &mut self.world.state.player;
+        player.hands.sync(&player.inventory);
+        self.world.state.check_vital_bands();
+        let failed = result.is_error == Some(true) || self.declined;
+        crate::metrics::record_tool(metric_name, failed, started.elapsed());
+
+        // Stepping out into foul weather, say what it's like and what's at hand
+        if sheltered && !self.world.state.sheltered() {
+            if let (Some(advisory), Some(ToolContent::Text { text })) = (
+                self.world.state.weather_advisory(),
+                result.content.first_mut(),
+            ) {
+                *text = format!("{}\n\n{}", advisory, text);
+            }
+        }
+
+        // An urgent need leads the reply; anything the player spends time on
+        // lets the body run on again, but looking around or idling doesn't
+        let acted = name != "simulate" && self.world.state.time.tick != tick;
+        let urgent = if acted {
+            self.world.state.urgent_need.take()
+        } else {
+            self.world.state.urgent_need.clone()
         };
+        if let (Some(line), Some(ToolContent::Text { text })) =
+            (urgent, result.content.first_mut())
+        {
+            *text = format!("**{}**\n\n{}", line, text);
+        }
 
         // Append any pending messages (like fire warnings)
-        self.append_pending_messages(result)
+        let mut result = self.append_pending_messages(result);
+
+        // Saving has been failing, or has just recovered: say so up front
+        if let (Some(notice), Some(ToolContent::Text { text })) =
+            (save_health::take_notice(), result.content.first_mut())
+        {
+            *text = format!("**{}**\n\n{}", notice, text);
+        }
+        self.record_transcript(name, args, &result);
+        result
+    }
+
+    /// Keep the call and the reply exactly as it goes out, unless the
+    /// player has turned recording off.
+    fn record_transcript(&mut self, name: &str, args: &Option<Value>, result: &CallToolResult) {
+        if !self.world.state.player.record_transcript {
+            return;
+        }
+        let entry = TranscriptEntry {
+            time: timestamp().parse().unwrap_or(0),
+            game_time: self.world.state.time.formatted_time(),
+            tool: name.to_string(),
+            arguments: args.clone(),
+            text: extract_text(result).unwrap_or_default(),
+            is_error: result.is_error == Some(true),
+        };
+        if let Err(e) = self.transcript.record(&entry) {
+            tracing::warn!("Failed to record transcript: {}", e);
+        }
     }
 
     fn append_pending_messages(&mut self, mut result: CallToolResult) -> CallToolResult {
@@ -184,10 +383,10 @@ impl McpServer {
         for dr in -1..=1 {
             for dc in -1..=1 {
                 let pos = Position::new(pr + dr, pc + dc);
-                if !pos.is_valid() {
+                if !self.world.map.contains(&pos) {
                     continue;
                 }
-                if let Some((r, c)) = pos.as_usize() {
+                if let Some((r, c)) = self.world.map.index_of(&pos) {
                     if let Some(tile) = self.world.map.get_tile(r, c) {
                         if matches!(tile.biome, Biome::Lake | Biome::Oasis) {
                             return true;
@@ -199,34 +398,158 @@ impl McpServer {
         false
     }
 
+    /// Turn an interaction outcome into a tool result, spending any time and
+    /// energy an `ActionSuccess` costs.
+    fn finish_interaction(&mut self, result: InteractionResult) -> CallToolResult {
+        match result {
+            InteractionResult::Success(msg) => CallToolResult::text(msg),
+            // Bad luck still spent the effort; anything else was never tried
+            InteractionResult::Failure(Failure::Chance {
+                message,
+                time_cost,
+                energy_cost,
+            }) => {
+                let time_str = self.pass_time(time_cost);
+                self.world.state.player.modify_energy(-energy_cost);
+                CallToolResult::text(format!("{}{}", message, time_str))
+            }
+            // Point at the recipe when the missing piece is something the player can make
+            InteractionResult::Failure(Failure::MissingItem { item, message })
+                if self.world.state.knows_blueprint(item) =>
+            {
+                self.declined = true;
+                CallToolResult::text(format!(
+                    "{} You know how to make {}: try 'create {}'.",
+                    message,
+                    item.name(),
+                    item.name()
+                ))
+            }
+            InteractionResult::Failure(failure) => {
+                CallToolResult::text(self.failure_message(failure))
+            }
+            InteractionResult::ItemObtained(_, msg) | InteractionResult::ItemLost(_, msg) => {
+                CallToolResult::text(msg)
+            }
+            InteractionResult::ActionSuccess {
+                message,
+                time_cost,
+                energy_cost,
+            } => {
+                // Pass time and drain energy; hard work holds off recovery
+                self.world.state.exerting = energy_cost >= 3.0 * time_cost.max(1) as f32;
+                let time_str = self.pass_time(time_cost);
+                self.world.state.exerting = false;
+                self.world.state.player.modify_energy(-energy_cost);
+                CallToolResult::text(format!("{}{}", message, time_str))
+            }
+        }
+    }
+
+    /// What the player is told when an action doesn't come off. Bad luck
+    /// was still an attempt; anything else was turned down.
+    fn failure_message(&mut self, failure: Failure) -> String {
+        if !matches!(failure, Failure::Chance { .. }) {
+            self.declined = true;
+        }
+        failure.into_message()
+    }
+
+    /// Run the ticks an action's `time_cost` works out to at the current
+    /// clock granularity, returning the " (took N mins)" suffix.
+    fn pass_time(&mut self, time_cost: u32) -> String {
+        if time_cost == 0 {
+            return String::new();
+        }
+        let ticks = self.world.state.time.ticks_for_cost(time_cost);
+        for _ in 0..ticks {
+            self.world.tick();
+        }
+        format!(
+            " (took {} mins)",
+            self.world.state.time.minutes_for_ticks(ticks)
+        )
+    }
+
+    /// Full description of wherever the player currently is.
+    fn describe_here(&mut self) -> String {
+        let mut sightings = Vec::new();
+        let state = &self.world.state;
+        let description = DescriptionGenerator::describe_location(
+            &state.location_context(&self.world.map),
+            state.noticed_detail(&self.world.map),
+            state.forage_hint(&self.world.map),
+            &mut sightings,
+        );
+        self.world.state.record_sightings(&sightings);
+        let state = &self.world.state;
+        let notes: Vec<String> = state
+            .wildfire_note()
+            .or_else(|| state.cooking_note())
+            .into_iter()
+            .chain(state.camp_wildlife_note())
+            .collect();
+        if notes.is_empty() {
+            description
+        } else {
+            format!("{}\n\n{}", description, notes.join("\n\n"))
+        }
+    }
+
+    /// Which way home lies, for the end of an outdoor move.
+    fn home_bearing_line(&self, familiar: bool) -> Option<String> {
+        let state = &self.world.state;
+        if state.player.room.is_some() || state.player.inside.is_some() {
+            return None;
+        }
+        let cabin = state.objects.find("cabin")?.position;
+        let exact = state.player.inventory.has(&Item::StrangeCompass, 1);
+        let pos = state.player.position;
+        if !exact && state.weather.get_for_position(pos.row, pos.col) == Weather::Sandstorm {
+            return Some(
+                "The storm has turned you around. Every way looks the same, and you can't tell where the cabin lies."
+                    .to_string(),
+            );
+        }
+        Some(home_bearing(&state.player.position, &cabin, exact, familiar))
+    }
+
     // Command implementations
 
-    fn cmd_look(&self, args: &Option<Value>) -> CallToolResult {
+    fn cmd_look(&mut self, args: &Option<Value>) -> CallToolResult {
         let direction = get_string_arg(args, "direction");
 
-        let text = if let Some(dir_str) = direction {
-            if let Some(dir) = Direction::from_str(&dir_str) {
-                DescriptionGenerator::look_direction(
-                    dir,
-                    &self.world.state.player,
+        let text = if let Some(target) = get_string_arg(args, "target") {
+            let player = &self.world.state.player;
+            if matches!(player.room, Some(Room::CabinMain) | Some(Room::WoodShed))
+                || player.inside.is_some() && !self.world.state.aboard_raft()
+            {
+                "Walls block your view. Step outside to look at things in the distance."
+                    .to_string()
+            } else {
+                DescriptionGenerator::look_at(
+                    &target,
+                    player,
                     &self.world.map,
-                    &self.world.state.time,
                     &self.world.state.weather,
-                    &self.world.state.wildlife,
                     &self.world.state.objects,
                 )
+            }
+        } else if let Some(dir_str) = direction {
+            if let Some(dir) = Direction::from_str(&dir_str) {
+                let mut sightings = Vec::new();
+                let view = DescriptionGenerator::look_direction(
+                    dir,
+                    &self.world.state.location_context(&self.world.map),
+                    &mut sightings,
+                );
+                self.world.state.record_sightings(&sightings);
+                view
             } else {
                 format!("'{}' is not a valid direction.", dir_str)
             }
         } else {
-            DescriptionGenerator::describe_location(
-                &self.world.state.player,
-                &self.world.map,
-                &self.world.state.time,
-                &self.world.state.weather,
-                &self.world.state.wildlife,
-                &self.world.state.objects,
-            )
+            self.describe_here()
         };
 
         CallToolResult::text(text)
@@ -240,10 +563,61 @@ impl McpServer {
             }
         };
 
-        let dir = match Direction::from_str(&dir_str) {
-            Some(d) => d,
-            None => {
-                return CallToolResult::error(format!("'{}' is not a valid direction.", dir_str))
+        // A few steps across the cabin to a spot, not a journey: no time passes
+        if let Some(spot) = CabinSpot::from_str(&dir_str) {
+            let stood = self.world.state.stand_up();
+            let text = match try_move_to_spot(&mut self.world.state.player, spot) {
+                MoveResult::Success(msg) => format!("{}\n\n{}", msg, self.describe_here()),
+                MoveResult::Blocked(msg)
+                | MoveResult::InvalidDirection(msg)
+                | MoveResult::RoomTransition(msg) => msg,
+            };
+            return CallToolResult::text(match stood {
+                Some(line) => format!("{} {}", line, text),
+                None => text,
+            });
+        }
+
+        // Following the compass home: one step nearer the cabin door, slowly
+        let heading_home = dir_str.eq_ignore_ascii_case("home");
+        let dir = if heading_home {
+            if self.world.state.player.room.is_some() || self.world.state.player.inside.is_some()
+            {
+                return CallToolResult::error("You're not out in the open.".to_string());
+            }
+            if !self
+                .world
+                .state
+                .player
+                .inventory
+                .has(&Item::StrangeCompass, 1)
+            {
+                return CallToolResult::error(
+                    "Without a compass to hold a heading, 'home' is only a guess. Pick a direction."
+                        .to_string(),
+                );
+            }
+            match home_step(
+                &self.world.state.player,
+                &self.world.map,
+                &self.world.state.objects,
+            ) {
+                Some(d) => d,
+                None => {
+                    return CallToolResult::text(
+                        "You're standing at the cabin door already.".to_string(),
+                    )
+                }
+            }
+        } else {
+            match Direction::from_str(&dir_str) {
+                Some(d) => d,
+                None => {
+                    return CallToolResult::error(format!(
+                        "'{}' is not a valid direction.",
+                        dir_str
+                    ))
+                }
             }
         };
 
@@ -254,46 +628,66 @@ impl McpServer {
             .map(|c| c.door_open)
             .unwrap_or(false);
 
-        let result = try_move(
-            &mut self.world.state.player,
-            dir,
-            &self.world.map,
-            &self.world.state.objects,
-            cabin_open,
-        );
+        let stood = self.world.state.stand_up();
+        let visited_before = self.world.state.player.visited.len();
+        let result = if self.world.state.aboard_raft() {
+            try_paddle(&mut self.world.state, dir, &mut self.world.map)
+        } else {
+            try_move(
+                &mut self.world.state.player,
+                dir,
+                &self.world.map,
+                &self.world.state.objects,
+                cabin_open,
+            )
+        };
+        let familiar = self.world.state.player.visited.len() == visited_before;
+        // Fresh dunes from past storms are slow, tiring going
+        let dune = matches!(result, MoveResult::Success(_))
+            && !self.world.state.aboard_raft()
+            && self.world.state.dune_at(&self.world.state.player.position);
+        if dune {
+            self.world.state.player.modify_energy(-2.0);
+            self.pass_time(1);
+        }
 
-        // Tick the world after movement
-        self.world.tick();
+        // Tick the world after movement; picking a way by compass is slower
+        self.pass_time(1);
+        if heading_home && matches!(result, MoveResult::Success(_)) {
+            self.pass_time(1);
+        }
 
         // Possibly trigger one-time cabin tutorial hint when entering the cabin
         self.world.state.maybe_trigger_tutorial_hint();
 
         let text = match result {
             MoveResult::Success(msg) => {
-                let location_desc = DescriptionGenerator::describe_location(
-                    &self.world.state.player,
-                    &self.world.map,
-                    &self.world.state.time,
-                    &self.world.state.weather,
-                    &self.world.state.wildlife,
-                    &self.world.state.objects,
-                );
-                format!("{}\n\n{}", msg, location_desc)
+                let location_desc = self.describe_here();
+                let msg = if dune {
+                    format!(
+                        "{} A dune the storms piled up lies across the way, and you flounder up and over it, sand sliding out from under every step.",
+                        msg
+                    )
+                } else {
+                    msg
+                };
+                let mut text = format!("{}\n\n{}", msg, location_desc);
+                if let Some(line) = self.home_bearing_line(familiar) {
+                    text.push_str(&format!("\n\n{}", line));
+                }
+                text
             }
             MoveResult::Blocked(msg) => msg,
             MoveResult::InvalidDirection(msg) => msg,
             MoveResult::RoomTransition(msg) => {
-                let location_desc = DescriptionGenerator::describe_location(
-                    &self.world.state.player,
-                    &self.world.map,
-                    &self.world.state.time,
-                    &self.world.state.weather,
-                    &self.world.state.wildlife,
-                    &self.world.state.objects,
-                );
+                let location_desc = self.describe_here();
                 format!("{}\n\n{}", msg, location_desc)
             }
         };
+        let text = match stood {
+            Some(line) => format!("{} {}", line, text),
+            None => text,
+        };
 
         CallToolResult::text(text)
     }
@@ -312,6 +706,11 @@ impl McpServer {
             .cabin_state()
             .map(|c| c.door_open)
             .unwrap_or(false);
+        let stood = self.world.state.stand_up();
+        // Checkpoint from the doorstep, in case the cave goes badly
+        let outside = (!self.world.state.in_cave())
+            .then(|| serde_json::to_string_pretty(&self.world.state).ok())
+            .flatten();
         let result = try_enter(
             &mut self.world.state.player,
             &location,
@@ -319,57 +718,76 @@ impl McpServer {
             &self.world.state.objects,
             cabin_open,
         );
+        if let Some(json) = outside.filter(|_| self.world.state.in_cave()) {
+            let name = format!("cave-day-{}", self.world.state.time.day);
+            self.world
+                .state
+                .pending_snapshots
+                .push(PendingSnapshot { name, json });
+        }
 
         // If we just entered the cabin, surface the tutorial hint once
         self.world.state.maybe_trigger_tutorial_hint();
 
         let text = match result {
             MoveResult::Success(msg) | MoveResult::RoomTransition(msg) => {
-                let location_desc = DescriptionGenerator::describe_location(
-                    &self.world.state.player,
-                    &self.world.map,
-                    &self.world.state.time,
-                    &self.world.state.weather,
-                    &self.world.state.wildlife,
-                    &self.world.state.objects,
-                );
+                let location_desc = self.describe_here();
                 format!("{}\n\n{}", msg, location_desc)
             }
             MoveResult::Blocked(msg) | MoveResult::InvalidDirection(msg) => msg,
         };
+        let text = match stood {
+            Some(line) => format!("{} {}", line, text),
+            None => text,
+        };
 
         CallToolResult::text(text)
     }
 
+    fn cmd_board(&mut self, args: &Option<Value>) -> CallToolResult {
+        let target = get_string_arg(args, "target").unwrap_or_else(|| "raft".to_string());
+        self.cmd_enter(&Some(json!({ "location": target })))
+    }
+
     fn cmd_exit(&mut self, _args: &Option<Value>) -> CallToolResult {
-        let result = try_exit(&mut self.world.state.player);
+        let stood = self.world.state.stand_up();
+        let result = try_exit(
+            &mut self.world.state.player,
+            &self.world.map,
+            &self.world.state.objects,
+        );
 
         let text = match result {
             MoveResult::RoomTransition(msg) => {
-                let location_desc = DescriptionGenerator::describe_location(
-                    &self.world.state.player,
-                    &self.world.map,
-                    &self.world.state.time,
-                    &self.world.state.weather,
-                    &self.world.state.wildlife,
-                    &self.world.state.objects,
-                );
+                let location_desc = self.describe_here();
                 format!("{}\n\n{}", msg, location_desc)
             }
             MoveResult::InvalidDirection(msg) => msg,
             _ => "Unexpected result".to_string(),
         };
+        let text = match stood {
+            Some(line) => format!("{} {}", line, text),
+            None => text,
+        };
 
         CallToolResult::text(text)
     }
 
-    fn cmd_examine(&self, args: &Option<Value>) -> CallToolResult {
+    fn cmd_examine(&mut self, args: &Option<Value>) -> CallToolResult {
         let target = match get_string_arg(args, "target") {
             Some(t) => t,
             None => return CallToolResult::error("Please specify what to examine.".to_string()),
         };
 
-        let text = examine(&target, &self.world.state);
+        if let Some(text) = self
+            .world
+            .state
+            .claim_hidden_detail(&target, &mut self.world.map)
+        {
+            return CallToolResult::text(text);
+        }
+
+        let text = examine(&target, &self.world.state, &self.world.map);
 
         CallToolResult::text(text)
     }
@@ -380,11 +798,22 @@ impl McpServer {
             None => return CallToolResult::error("Please specify an item to take.".to_string()),
         };
 
+        if let Some(result) = try_take_off_fire(&item, &mut self.world.state) {
+            return self.finish_interaction(result);
+        }
+        if let Some(result) = try_take_from(&item, &mut self.world.state) {
+            return self.finish_interaction(result);
+        }
+        if is_all_phrase(&item) {
+            let result = try_take_all(&item, &mut self.world.state, &mut self.world.map);
+            return self.finish_interaction(result);
+        }
+
         let result = try_take(&item, &mut self.world.state, &mut self.world.map);
 
         let text = match result {
             InteractionResult::Success(msg) => msg,
-            InteractionResult::Failure(msg) => msg,
+            InteractionResult::Failure(f) => self.failure_message(f),
             InteractionResult::ItemObtained(_, msg) => msg,
             InteractionResult::ItemLost(_, msg) => msg,
             _ => "Action not supported here".to_string(),
@@ -399,11 +828,19 @@ impl McpServer {
             None => return CallToolResult::error("Please specify an item to drop.".to_string()),
         };
 
+        if let Some(result) = try_put_in(&item, &mut self.world.state) {
+            return self.finish_interaction(result);
+        }
+        if is_all_phrase(&item) {
+            let result = try_drop_all(&item, &mut self.world.state, &mut self.world.map);
+            return self.finish_interaction(result);
+        }
+
         let result = try_drop(&item, &mut self.world.state, &mut self.world.map);
 
         let text = match result {
             InteractionResult::Success(msg) => msg,
-            InteractionResult::Failure(msg) => msg,
+            InteractionResult::Failure(f) => self.failure_message(f),
             InteractionResult::ItemObtained(_, msg) => msg,
             InteractionResult::ItemLost(_, msg) => msg,
             _ => "Action not supported here".to_string(),
@@ -412,6 +849,12 @@ impl McpServer {
         CallToolResult::text(text)
     }
 
+    fn cmd_store(&mut self, args: &Option<Value>) -> CallToolResult {
+        let item = get_string_arg(args, "item").unwrap_or_else(|| "all".to_string());
+        let result = try_store(&item, &mut self.world.state, &mut self.world.map);
+        self.finish_interaction(result)
+    }
+
     fn cmd_use(&mut self, args: &Option<Value>) -> CallToolResult {
         let item = match get_string_arg(args, "item") {
             Some(i) => i,
@@ -428,30 +871,7 @@ impl McpServer {
             &mut self.world.map,
         );
 
-        match result {
-            InteractionResult::Success(msg) => CallToolResult::text(msg),
-            InteractionResult::Failure(msg) => CallToolResult::text(msg),
-            InteractionResult::ItemObtained(_, msg) => CallToolResult::text(msg),
-            InteractionResult::ItemLost(_, msg) => CallToolResult::text(msg),
-            InteractionResult::ActionSuccess {
-                message,
-                time_cost,
-                energy_cost,
-            } => {
-                // Pass time and drain energy
-                for _ in 0..time_cost {
-                    self.world.tick();
-                }
-                self.world.state.player.modify_energy(-energy_cost);
-
-                let time_str = if time_cost > 0 {
-                    format!(" (took {} mins)", time_cost * 10)
-                } else {
-                    "".to_string()
-                };
-                CallToolResult::text(format!("{}{}", message, time_str))
-            }
-        }
+        self.finish_interaction(result)
     }
 
     fn cmd_create(&mut self, args: &Option<Value>) -> CallToolResult {
@@ -460,29 +880,39 @@ impl McpServer {
             None => return CallToolResult::error("Please specify an item to create.".to_string()),
         };
 
-        let result = try_create(&item, &mut self.world.state);
+        let result = try_create(&item, &mut self.world.state, &self.world.map);
 
         match result {
             InteractionResult::Success(msg) => CallToolResult::text(msg),
-            InteractionResult::Failure(msg) => CallToolResult::text(msg),
+            InteractionResult::Failure(f) => CallToolResult::text(self.failure_message(f)),
             _ => CallToolResult::error("Unexpected result".to_string()),
         }
     }
 
     fn cmd_write(&mut self, args: &Option<Value>) -> CallToolResult {
+        let lang = self.world.state.player.language;
         let text = match get_string_arg(args, "text") {
             Some(t) => t,
             None => {
-                return CallToolResult::error(
-                    "Please provide text to write (제목: or 페이지N:).".to_string(),
-                )
+                return CallToolResult::error(format!(
+                    "{} {}",
+                    lang.pick(
+                        "Please provide text to write (title: or pageN:).",
+                        "쓸 내용을 입력하세요 (제목: 또는 페이지N:).",
+                    ),
+                    write_syntax(lang)
+                ))
             }
         };
         let target = match get_string_arg(args, "target") {
             Some(t) => t,
             None => {
                 return CallToolResult::error(
-                    "Please specify a target book (e.g., '빈 책' or 'book-1').".to_string(),
+                    lang.pick(
+                        "Please specify a target book (e.g., 'blank book' or 'book-1').",
+                        "어느 책인지 알려 주세요 (예: '빈 책' 또는 'book-1').",
+                    )
+                    .to_string(),
                 )
             }
         };
@@ -491,21 +921,14 @@ impl McpServer {
 
         match result {
             InteractionResult::Success(msg) => CallToolResult::text(msg),
-            InteractionResult::Failure(msg) => CallToolResult::text(msg),
+            InteractionResult::Failure(f) => CallToolResult::text(self.failure_message(f)),
             InteractionResult::ActionSuccess {
                 message,
                 time_cost,
                 energy_cost,
             } => {
-                for _ in 0..time_cost {
-                    self.world.tick();
-                }
+                let time_str = self.pass_time(time_cost);
                 self.world.state.player.modify_energy(-energy_cost);
-                let time_str = if time_cost > 0 {
-                    format!(" (took {} mins)", time_cost * 10)
-                } else {
-                    "".to_string()
-                };
                 CallToolResult::text(format!("{}{}", message, time_str))
             }
             _ => CallToolResult::error("Unexpected result".to_string()),
@@ -522,7 +945,7 @@ impl McpServer {
 
         let text = match result {
             InteractionResult::Success(msg) => msg,
-            InteractionResult::Failure(msg) => msg,
+            InteractionResult::Failure(f) => self.failure_message(f),
             _ => "Unexpected result".to_string(),
         };
 
@@ -539,7 +962,7 @@ impl McpServer {
 
         let text = match result {
             InteractionResult::Success(msg) => msg,
-            InteractionResult::Failure(msg) => msg,
+            InteractionResult::Failure(f) => self.failure_message(f),
             _ => "Unexpected result".to_string(),
         };
 
@@ -548,19 +971,40 @@ impl McpServer {
 
     fn cmd_inventory(&self, _args: &Option<Value>) -> CallToolResult {
         let items = self.world.state.player.inventory.list();
+        let mounts = &self.world.state.player.mounts;
 
-        if items.is_empty() {
+        if items.is_empty() && mounts.shoulder.is_none() && mounts.raft_prow.is_none() {
             return CallToolResult::text("You are not carrying anything.".to_string());
         }
 
         let mut text = String::from("**Inventory:**\n");
         for (item, qty) in items {
-            if qty == 1 {
-                text.push_str(&format!("- {}\n", item.name()));
+            let label = self.world.state.labeled_name(&item);
+            let mut notes = Vec::new();
+            if qty > 1 {
+                notes.push(format!("x{}", qty));
+            }
+            if let Some((left, max)) = self.world.state.player.tool_condition(&item) {
+                notes.push(format!("condition {}/{}", left, max));
+            }
+            if notes.is_empty() {
+                text.push_str(&format!("- {}\n", label));
             } else {
-                text.push_str(&format!("- {} (x{})\n", item.name(), qty));
+                text.push_str(&format!("- {} ({})\n", label, notes.join(", ")));
             }
         }
+        if let Some(item) = &mounts.shoulder {
+            text.push_str(&format!(
+                "- On your shoulder: {}\n",
+                self.world.state.labeled_name(item)
+            ));
+        }
+        if let Some(item) = &mounts.raft_prow {
+            text.push_str(&format!(
+                "- On the raft's prow: {}\n",
+                self.world.state.labeled_name(item)
+            ));
+        }
 
         // Show active project if any
         if let Some(bp) = &self.world.state.player.active_project {
@@ -569,6 +1013,12 @@ impl McpServer {
                 bp.status_description()
             ));
         }
+        if !self.world.state.paused_projects.is_empty() {
+            text.push_str("\n**Set Aside:**\n");
+            for bp in &self.world.state.paused_projects {
+                text.push_str(&format!("- #{} {}\n", bp.id, bp.target_item.name()));
+            }
+        }
 
         if !self.world.state.player.book_ids.is_empty() {
             text.push_str("\n**Books:**\n");
@@ -591,16 +1041,25 @@ impl McpServer {
     fn cmd_status(&self, _args: &Option<Value>) -> CallToolResult {
         let player = &self.world.state.player;
 
-        let text = format!(
+        let recovering = if self.world.state.is_recovering() {
+            " (recovering)"
+        } else {
+            ""
+        };
+        let mut text = format!(
             "**Your Status:**\n\n\
-            Health: {:.0}/100\n\
+            Health: {:.0}/100{}\n\
+            Temperature: {}\n\
             Warmth: {:.0}/100 ({})\n\
             Energy: {:.0}/100 ({})\n\
             Mood: {:.0}/100 ({})\n\
             Fullness: {:.0}/100 ({})\n\
-            Hydration: {:.0}/100 ({})\n\n\
+            Hydration: {:.0}/100 ({})\n\
+            Hands: {}\n\n\
             {}",
             player.health,
+            recovering,
+            self.world.state.temperature_readout(&self.world.map),
             player.warmth,
             player.comfort_description(),
             player.energy,
@@ -611,9 +1070,27 @@ impl McpServer {
             player.fullness_description(),
             player.hydration,
             player.hydration_description(),
+            self.world
+                .state
+                .in_hand()
+                .unwrap_or_else(|| "empty".to_string()),
             player.status_summary()
         );
 
+        if let Some(goal) = self.world.state.rescue.progress_line() {
+            text.push_str(&format!("\n\n{}", goal));
+        }
+
+        if let Some(concerns) = concerns_section(&self.world.state, &self.world.map) {
+            text.push_str(&format!("\n\n{}", concerns));
+        }
+
+        text.push_str(&format!("\n\n{}", save_health::current().status_line()));
+
+        text.push_str(&format!(
+            "\n\n{}",
+            crate::health::footer(self.world.state.time.day)
+        ));
         CallToolResult::text(text)
     }
 
@@ -622,6 +1099,7 @@ impl McpServer {
         let room = self.world.state.player.room.clone();
 
         let near_water = self.is_near_water();
+        let fireside_seat = self.world.state.seated_by_fire(&self.world.map);
         let cozy_fire = matches!(room, Some(Room::CabinMain))
             && self
                 .world
@@ -630,7 +1108,7 @@ impl McpServer {
                 .map(|c| !matches!(c.fireplace.state, FireState::Cold))
                 .unwrap_or(false);
 
-        let (row, col) = position.as_usize().unwrap_or((5, 5));
+        let (row, col) = self.world.map.index_of(&position).unwrap_or((5, 5));
         let biome = self
             .world
             .map
@@ -638,6 +1116,12 @@ impl McpServer {
             .unwrap_or(Biome::MixedForest);
 
         let setting = match room {
+            Some(Room::CabinMain) if fireside_seat => {
+                "You let the armchair take your weight, the fire close and steady before you."
+            }
+            None if fireside_seat => {
+                "You sit easy beside the campfire, its heat on your face and the dark at your back."
+            }
             Some(Room::CabinMain) if cozy_fire => {
                 "You settle near the fireplace, letting its warmth seep into your hands."
             }
@@ -653,7 +1137,7 @@ impl McpServer {
         };
 
         // Let a little time pass while meditating
-        self.world.tick();
+        self.pass_time(1);
 
         let mut mood_gain = 12.0;
         if near_water {
@@ -662,13 +1146,19 @@ impl McpServer {
         if cozy_fire {
             mood_gain += 2.0;
         }
+        if fireside_seat {
+            mood_gain *= 2.0;
+        }
 
         let energy_gain = 5.0;
         let warmth_gain = if cozy_fire { 6.0 } else { 0.0 };
 
+        let afterglow = self.world.state.time.tick
+            + self.world.state.time.ticks_for_minutes(AFTERGLOW_MINUTES) as u64;
         let player = &mut self.world.state.player;
         player.modify_mood(mood_gain);
         player.modify_energy(energy_gain);
+        player.afterglow_until = player.afterglow_until.max(afterglow);
         if warmth_gain > 0.0 {
             player.modify_warmth(warmth_gain);
         }
@@ -708,9 +1198,42 @@ You feel calmer and a bit more refreshed. It is now {}.",
         CallToolResult::text(text)
     }
 
-    fn cmd_drink(&mut self, _args: &Option<Value>) -> CallToolResult {
+    fn cmd_eat(&mut self, args: &Option<Value>) -> CallToolResult {
+        let item = match get_string_arg(args, "item") {
+            Some(i) => i,
+            None => return CallToolResult::error("Please specify what to eat.".to_string()),
+        };
+        let quantity = args
+            .as_ref()
+            .and_then(|a| a.get("quantity"))
+            .and_then(|v| v.as_u64())
+            .map(|q| q.max(1) as u32);
+        let result = try_consume(&item, quantity, false, &mut self.world.state);
+        self.finish_interaction(result)
+    }
+
+    fn cmd_drink(&mut self, args: &Option<Value>) -> CallToolResult {
+        if let Some(item) = get_string_arg(args, "item") {
+            let quantity = args
+                .as_ref()
+                .and_then(|a| a.get("quantity"))
+                .and_then(|v| v.as_u64())
+                .map(|q| q.max(1) as u32);
+            let result = try_consume(&item, quantity, true, &mut self.world.state);
+            return self.finish_interaction(result);
+        }
+
+        // Water you carry comes first; muddy water only when there's nothing better
+        if self.world.state.player.inventory.has(&Item::CleanWater, 1) {
+            let result = try_consume("clean water", None, true, &mut self.world.state);
+            return self.finish_interaction(result);
+        }
         let near_water = self.is_near_water();
         if !near_water {
+            if self.world.state.player.inventory.has(&Item::MuddyWater, 1) {
+                let result = try_consume("muddy water", None, true, &mut self.world.state);
+                return self.finish_interaction(result);
+            }
             return CallToolResult::error(
                 "You need to be right by the lake to drink the water.".to_string(),
             );
@@ -721,7 +1244,7 @@ You feel calmer and a bit more refreshed. It is now {}.",
         self.world.state.player.modify_mood(2.0);
 
         // A quick sip still passes a little time
-        self.world.tick();
+        self.pass_time(1);
 
         CallToolResult::text(
             "You kneel and cup cold lake water in your hands, drinking deeply. It tastes clean and refreshing.".to_string()
@@ -731,31 +1254,166 @@ You feel calmer and a bit more refreshed. It is now {}.",
     fn cmd_fish(&mut self, args: &Option<Value>) -> CallToolResult {
         let gear = get_string_arg(args, "gear");
         let result = try_fish(&mut self.world.state, &self.world.map, gear.as_deref());
+        self.finish_interaction(result)
+    }
 
-        match result {
-            InteractionResult::Success(msg) | InteractionResult::Failure(msg) => {
-                CallToolResult::text(msg)
-            }
-            InteractionResult::ItemObtained(_, msg) | InteractionResult::ItemLost(_, msg) => {
-                CallToolResult::text(msg)
+    fn cmd_mount(&mut self, args: &Option<Value>) -> CallToolResult {
+        let item = match get_string_arg(args, "item") {
+            Some(i) => i,
+            None => {
+                return CallToolResult::error("Please specify what to perch.".to_string())
             }
-            InteractionResult::ActionSuccess {
-                message,
-                time_cost,
-                energy_cost,
-            } => {
-                for _ in 0..time_cost {
-                    self.world.tick();
-                }
-                self.world.state.player.modify_energy(-energy_cost);
-                let time_str = if time_cost > 0 {
-                    format!(" (took {} mins)", time_cost * 10)
-                } else {
-                    "".to_string()
-                };
-                CallToolResult::text(format!("{}{}", message, time_str))
+        };
+        let target = match get_string_arg(args, "target") {
+            Some(t) => t,
+            None => {
+                return CallToolResult::error(
+                    "Please specify where: shoulder, raft, table, or mantel.".to_string(),
+                )
             }
-        }
+        };
+        let result = try_mount(&item, &target, &mut self.world.state);
+        self.finish_interaction(result)
+    }
+
+    fn cmd_hold(&mut self, args: &Option<Value>) -> CallToolResult {
+        let item = match get_string_arg(args, "item") {
+            Some(i) => i,
+            None => return CallToolResult::error("Please specify what to hold.".to_string()),
+        };
+        let result = try_hold(&item, &mut self.world.state);
+        self.finish_interaction(result)
+    }
+
+    fn cmd_stow(&mut self, args: &Option<Value>) -> CallToolResult {
+        let item = get_string_arg(args, "item").unwrap_or_else(|| "all".to_string());
+        let result = try_stow(&item, &mut self.world.state);
+        self.finish_interaction(result)
+    }
+
+    fn cmd_approach(&mut self, args: &Option<Value>) -> CallToolResult {
+        let target = match get_string_arg(args, "target") {
+            Some(t) => t,
+            None => return CallToolResult::error("Please specify what to approach.".to_string()),
+        };
+        let result = try_approach(&target, &mut self.world.state, &self.world.map);
+        self.finish_interaction(result)
+    }
+
+    fn cmd_throw(&mut self, args: &Option<Value>) -> CallToolResult {
+        let item = match get_string_arg(args, "item") {
+            Some(i) => i,
+            None => return CallToolResult::error("Please specify what to throw.".to_string()),
+        };
+        let target = get_string_arg(args, "target");
+        let direction = get_string_arg(args, "direction").and_then(|d| Direction::from_str(&d));
+        let accept_loss = get_bool_arg(args, "accept_loss");
+        let result = try_throw(
+            &item,
+            target.as_deref(),
+            direction,
+            accept_loss,
+            &mut self.world.state,
+            &mut self.world.map,
+        );
+        self.finish_interaction(result)
+    }
+
+    fn cmd_lakeside(&mut self, args: &Option<Value>) -> CallToolResult {
+        let action = match get_string_arg(args, "action") {
+            Some(a) => a,
+            None => return CallToolResult::text(lakeside_help()),
+        };
+        let result = try_lakeside_ritual(&action, &mut self.world.state, &self.world.map);
+        self.finish_interaction(result)
+    }
+
+    fn cmd_search(&mut self, _args: &Option<Value>) -> CallToolResult {
+        let result = try_search(&mut self.world.state, &mut self.world.map);
+        self.finish_interaction(result)
+    }
+
+    /// Answer the rescuers once the signal fire has been seen: go home and
+    /// end the run, or stay on.
+    fn cmd_rescue(&mut self, args: &Option<Value>) -> CallToolResult {
+        if self.world.state.rescue.ending != Some(RescueEnding::Awaiting) {
+            return CallToolResult::error(
+                "No one has come for you. Signal fires on the knoll, three clear days, might change that."
+                    .to_string(),
+            );
+        }
+        let confirm = get_bool_arg(args, "confirm");
+        match get_string_arg(args, "choice").as_deref() {
+            Some("stay") => {
+                self.world.state.stay_after_rescue();
+                CallToolResult::text(
+                    "You walk down to meet them, shake hands, share the last of the tea, and send them home with thanks. Their lanterns fade back into the trees. The cabin is quiet again, and it feels more like yours than ever.".to_string(),
+                )
+            }
+            Some("leave") if confirm => {
+                let summary = self.world.state.run_summary();
+                self.world.map = WorldMap::generate(world_seed_from_env(), map_extent_from_env());
+                self.world.state = GameState::new(&self.world.map);
+                CallToolResult::text(format!(
+                    "You bank the fire one last time, set the duck on the mantel, and follow the lanterns out through the trees.\n\n{}\n\nThe story ends here. The next call wakes someone new on the path south of the cabin.",
+                    summary
+                ))
+            }
+            Some("leave") => CallToolResult::text(
+                "Leaving ends this stay for good: the world starts over afterwards. Call 'rescue' with choice 'leave' and confirm true if you're sure.".to_string(),
+            ),
+            _ => CallToolResult::error(
+                "Choose 'leave' (with confirm true) to go home, or 'stay'.".to_string(),
+            ),
+        }
+    }
+
+    fn cmd_catch(&mut self, args: &Option<Value>) -> CallToolResult {
+        let target = match get_string_arg(args, "target") {
+            Some(t) => t,
+            None => {
+                return CallToolResult::error(
+                    "Say what to catch, e.g. fireflies or a frog.".to_string(),
+                )
+            }
+        };
+        let result = try_catch(&target, &mut self.world.state, &self.world.map);
+        self.finish_interaction(result)
+    }
+
+    fn cmd_help(&self, args: &Option<Value>) -> CallToolResult {
+        let text = match get_string_arg(args, "topic").as_deref() {
+            Some("lakeside") => lakeside_help(),
+            Some("food") => food_help(),
+            Some("use") => use_help(),
+            _ => "Help topics: lakeside, food, use. Every tool also describes itself in its own listing."
+                .to_string(),
+        };
+        CallToolResult::text(text)
+    }
+
+    fn cmd_sit(&mut self, args: &Option<Value>) -> CallToolResult {
+        let target = get_string_arg(args, "target");
+        let result = try_sit(target.as_deref(), &mut self.world.state, &self.world.map);
+        self.finish_interaction(result)
+    }
+
+    fn cmd_tidy(&mut self, _args: &Option<Value>) -> CallToolResult {
+        let result = try_tidy(&mut self.world.state);
+        self.finish_interaction(result)
+    }
+
+    fn cmd_tend_fire(&mut self, args: &Option<Value>) -> CallToolResult {
+        let mode = match get_string_arg(args, "mode") {
+            Some(m) => m,
+            None => {
+                return CallToolResult::error(
+                    "Please choose how to tend the fire: stoke, bank, or extinguish.".to_string(),
+                )
+            }
+        };
+        let result = try_tend_fire(&mode, &mut self.world.state);
+        self.finish_interaction(result)
     }
 
     fn cmd_sleep(&mut self, _args: &Option<Value>) -> CallToolResult {
@@ -763,42 +1421,101 @@ You feel calmer and a bit more refreshed. It is now {}.",
             let p = &self.world.state.player;
             p.fullness >= 60.0 && p.hydration >= 50.0
         };
-
-        // Advance time while sleeping (about an hour)
-        for _ in 0..6 {
+        // Closed shutters keep the night quiet and dark.
+        let shuttered = matches!(self.world.state.player.room, Some(Room::CabinMain))
+            && self.world.state.time.time_of_day().is_night()
+            && self
+                .world
+                .state
+                .cabin_state()
+                .map(|c| c.shutters_closed)
+                .unwrap_or(false);
+        let watched = matches!(self.world.state.player.room, Some(Room::CabinMain))
+            && self.world.state.duck_on_mantel();
+
+        // Advance time while sleeping (about an hour); the body mends
+        // faster asleep, as long as it's fed and warm
+        let health_before = self.world.state.player.health;
+        let ticks = self.world.state.time.ticks_for_minutes(60);
+        let mut watch = self.world.state.begin_night_watch();
+        let mut rng = rand::thread_rng();
+        let mut events = Vec::new();
+        let mut slept = 0;
+        self.world.state.sleeping = true;
+        for _ in 0..ticks {
             self.world.tick();
+            slept += 1;
+            let event = self
+                .world
+                .state
+                .night_event(&mut watch, &mut self.world.map, &mut rng);
+            if let Some(event) = event {
+                events.push(event);
+                if event.wakes() {
+                    break;
+                }
+            }
         }
+        self.world.state.sleeping = false;
+        let mended = self.world.state.player.health > health_before;
+        let woken = events.iter().any(|e| e.wakes());
 
-        // Restore stats
+        // Restore stats, less for a broken night
+        let rest = slept as f32 / ticks.max(1) as f32;
         let player = &mut self.world.state.player;
-        player.modify_energy(25.0);
-        player.modify_mood(6.0);
+        player.modify_energy(if shuttered { 28.0 } else { 25.0 } * rest);
+        player.modify_mood(if watched { 8.0 } else { 6.0 });
+        if watched {
+            player.modify_energy(2.0);
+        }
         player.modify_fullness(-5.0);
         player.modify_hydration(-5.0);
-        if well_fed {
-            player.modify_health(15.0);
-        } else {
-            player.modify_health(5.0);
-        }
 
-        let text = if well_fed {
+        let text = if well_fed && mended {
             "You curl up and drift into a deep, satisfying sleep. With a full belly and quenched thirst, your body mends itself."
+        } else if well_fed {
+            "You curl up and drift into a deep, satisfying sleep, and wake clear-headed."
         } else {
             "You doze for a while. It's not the most comfortable rest, but it helps a bit."
         };
 
-        CallToolResult::text(format!("{}\n\nYou wake feeling more rested.", text))
+        let shutter_note = if shuttered {
+            "\n\nBehind the closed shutters the dark stayed soft and unbroken."
+        } else {
+            ""
+        };
+        let watch_note = if watched {
+            "\n\nThe duck kept its watch from the mantelpiece all night."
+        } else {
+            ""
+        };
+
+        let night: String = events
+            .iter()
+            .map(|e| format!("\n\n{}", e.describe()))
+            .collect();
+        let wake = if woken {
+            ""
+        } else {
+            "\n\nYou wake feeling more rested."
+        };
+
+        CallToolResult::text(format!(
+            "{}{}{}{}{}",
+            text, shutter_note, watch_note, night, wake
+        ))
     }
 
     fn cmd_wait(&mut self, args: &Option<Value>) -> CallToolResult {
         let duration = get_string_arg(args, "duration").unwrap_or_else(|| "short".to_string());
 
-        let ticks = match duration.as_str() {
-            "short" => 1,
-            "medium" => 3,
-            "long" => 6,
-            _ => 1,
+        let minutes = match duration.as_str() {
+            "short" => 10,
+            "medium" => 30,
+            "long" => 60,
+            _ => 10,
         };
+        let ticks = self.world.state.time.ticks_for_minutes(minutes);
 
         for _ in 0..ticks {
             self.world.tick();
@@ -832,8 +1549,13 @@ You feel calmer and a bit more refreshed. It is now {}.",
         CallToolResult::text(text)
     }
 
-    fn cmd_kick(&mut self, _args: &Option<Value>) -> CallToolResult {
-        let result = kick_tree(&mut self.world.state);
+    fn cmd_kick(&mut self, args: &Option<Value>) -> CallToolResult {
+        let target = get_string_arg(args, "target");
+        let result = kick(
+            target.as_deref(),
+            &mut self.world.state,
+            &mut self.world.map,
+        );
 
         let text = match result {
             CraftResult::Success(msg) => msg,
@@ -847,14 +1569,27 @@ You feel calmer and a bit more refreshed. It is now {}.",
     fn cmd_talk(&mut self, args: &Option<Value>) -> CallToolResult {
         let message = get_string_arg(args, "message");
         let duck_name = self.world.state.display_name(&Item::RubberDuck);
-        let result = talk_to_animal_companion(message.as_deref(), &self.world.state)
-            .unwrap_or_else(|| {
-                talk_to_rubber_duck(message.as_deref(), &self.world.state, &duck_name)
-            });
+        // Talking aloud echoes in the cave; shouting carries further
+        let loud = message.as_deref().map(|m| m.ends_with('!')).unwrap_or(false);
+        self.world.state.make_noise(if loud { 2 } else { 1 });
+        if loud && self.world.state.player.room.is_none() {
+            self.world.state.startle_wildlife(10.0, 4.0);
+        }
+        let heard = talk_to_animal_companion(message.as_deref(), &self.world.state).or_else(|| {
+            duck_in_earshot(&self.world.state)
+                .then(|| talk_to_rubber_duck(message.as_deref(), &self.world.state, &duck_name))
+        });
+        let result = match heard {
+            Some(result) => {
+                self.world.state.player.modify_mood(TALK_MOOD);
+                result
+            }
+            None => talk_to_yourself(message.as_deref(), &mut self.world.state, &self.world.map),
+        };
 
         let text = match result {
             InteractionResult::Success(msg) => msg,
-            InteractionResult::Failure(msg) => msg,
+            InteractionResult::Failure(f) => self.failure_message(f),
             InteractionResult::ItemObtained(_, msg) => msg,
             InteractionResult::ItemLost(_, msg) => msg,
             _ => "Action not supported.".to_string(),
@@ -868,12 +1603,37 @@ You feel calmer and a bit more refreshed. It is now {}.",
             Some(i) => i,
             None => return CallToolResult::error("Please specify which item to name.".to_string()),
         };
+        let clear = get_bool_arg(args, "clear");
         let new_name = match get_string_arg(args, "name") {
+            _ if clear => String::new(),
             Some(n) => n,
-            None => return CallToolResult::error("Please provide a name.".to_string()),
+            None => {
+                return CallToolResult::error(
+                    "Please provide a name, or clear: true to remove one.".to_string(),
+                )
+            }
         };
 
-        let item = match Item::from_str(&item_str) {
+        if matches!(
+            item_str.trim().to_lowercase().as_str(),
+            "self" | "me" | "myself" | "player"
+        ) {
+            let pronouns = get_string_arg(args, "pronouns");
+            return self.name_self(&new_name, pronouns.as_deref());
+        }
+
+        if let Some(region) = self.world.state.region_names.find(&item_str) {
+            return CallToolResult::text(self.world.state.name_region(region, &new_name));
+        }
+
+        if let Some(id) = self.world.state.find_book_id(&item_str) {
+            return match self.world.state.rename_book(&id, &new_name) {
+                Ok(msg) => CallToolResult::text(msg),
+                Err(err) => CallToolResult::error(err),
+            };
+        }
+
+        let item = match self.world.state.resolve_item(&item_str) {
             Some(i) => i,
             None => {
                 match self
@@ -893,17 +1653,71 @@ You feel calmer and a bit more refreshed. It is now {}.",
             );
         }
 
+        if new_name.trim().is_empty() {
+            return match self.world.state.custom_name(&item) {
+                Some(old) => {
+                    let text = format!(
+                        "You stop calling the {} '{}'. It's just a {} again.",
+                        item.name(),
+                        old,
+                        item.name()
+                    );
+                    self.world.state.set_custom_name(item, "");
+                    CallToolResult::text(text)
+                }
+                None => CallToolResult::error(format!("The {} has no name to clear.", item.name())),
+            };
+        }
+        if let Some(conflict) = self.world.state.name_conflict(&new_name, Some(item), None) {
+            return CallToolResult::error(conflict);
+        }
+
         self.world.state.set_custom_name(item, &new_name);
         let display = self.world.state.display_name(&item);
         CallToolResult::text(format!("You name the {} '{}'.", item.name(), display))
     }
 
+    fn name_self(&mut self, new_name: &str, pronouns: Option<&str>) -> CallToolResult {
+        let player = &mut self.world.state.player;
+        let trimmed = new_name.trim();
+        if trimmed.is_empty() || matches!(trimmed.to_lowercase().as_str(), "none" | "clear") {
+            player.name = None;
+            player.pronouns = None;
+            return CallToolResult::text(
+                "You let the name go. The world will simply call you 'you' again.".to_string(),
+            );
+        }
+        player.name = Some(trimmed.to_string());
+        let mut text = format!("From now on, you are {}.", trimmed);
+        if let Some(p) = pronouns {
+            match Pronouns::parse(p) {
+                Some(parsed) => {
+                    text.push_str(&format!(" Pronouns: {}.", parsed.label()));
+                    player.pronouns = Some(parsed);
+                }
+                None => {
+                    text.push_str(&format!(
+                        " (Couldn't read '{}' as pronouns; try 'she/her', 'he/him', 'they/them', or 'xe/xem/xyr'.)",
+                        p
+                    ));
+                }
+            }
+        }
+        CallToolResult::text(text)
+    }
+
     fn cmd_simulate(&mut self, args: &Option<Value>) -> CallToolResult {
+        if let Some(name) = get_string_arg(args, "scenario") {
+            return self.run_scenario(&name);
+        }
         let ticks = get_int_arg(args, "ticks", 1).clamp(1, 10) as usize;
 
+        // Nobody is choosing anything while these pass
+        self.world.state.idle_ticking = true;
         for _ in 0..ticks {
             self.world.tick();
         }
+        self.world.state.idle_ticking = false;
 
         let time_desc = self.world.state.time.time_description();
         let text = format!(
@@ -914,6 +1728,112 @@ You feel calmer and a bit more refreshed. It is now {}.",
         CallToolResult::text(text)
     }
 
+    /// Run a named stretch through the ordinary tick loop and recap it.
+    fn run_scenario(&mut self, name: &str) -> CallToolResult {
+        let Some(scenario) = Scenario::parse(name) else {
+            let names: Vec<&str> = Scenario::ALL.iter().map(|s| s.name()).collect();
+            return CallToolResult::error(format!(
+                "'{}' isn't a scenario. Try one of: {}.",
+                name,
+                names.join(", ")
+            ));
+        };
+        if scenario.forces_weather() && !debug_tools_enabled() {
+            return CallToolResult::error(format!(
+                "The {} scenario bends the weather, so it only runs with the developer tools on ({}=1).",
+                scenario.name(),
+                DEBUG_ENV
+            ));
+        }
+
+        let state = &mut self.world.state;
+        let ticks = scenario.ticks(&state.time);
+        let pos = state.player.position;
+        let region = WorldMap::region_of(pos.row, pos.col);
+        if let Some(weather) = scenario.front_for(region) {
+            state.weather.force_front(region, weather, ticks);
+        }
+        let started = state.time.formatted_time();
+        let needs_before = [
+            state.player.fullness,
+            state.player.hydration,
+            state.player.warmth,
+        ];
+        let mut watch = scenario
+            .keeps_night_watch()
+            .then(|| state.begin_night_watch());
+        let mut skies = vec![state.weather.get_for_position(pos.row, pos.col)];
+        let mut events = Vec::new();
+        let mut rng = rand::thread_rng();
+
+        // Nobody is choosing anything while these pass
+        state.idle_ticking = true;
+        state.event_rate = scenario.event_rate();
+        for _ in 0..ticks {
+            self.world.tick();
+            let state = &mut self.world.state;
+            if let Some(watch) = watch.as_mut() {
+                if let Some(event) = state.night_event(watch, &mut self.world.map, &mut rng) {
+                    events.push(event);
+                }
+            }
+            let here = state.player.position;
+            let sky = state.weather.get_for_position(here.row, here.col);
+            if skies.last() != Some(&sky) {
+                skies.push(sky);
+            }
+        }
+        let state = &mut self.world.state;
+        state.idle_ticking = false;
+        state.event_rate = GameState::usual_event_rate();
+
+        let minutes = state.time.minutes_for_ticks(ticks);
+        // A long stretch keeps only where the weather started and ended
+        let skies = if skies.len() > 6 {
+            format!(
+                "{} → … → {} ({} changes)",
+                skies[0].name(),
+                skies[skies.len() - 1].name(),
+                skies.len() - 1
+            )
+        } else {
+            let names: Vec<&str> = skies.iter().map(|w| w.name()).collect();
+            names.join(" → ")
+        };
+        let player = &state.player;
+        let needs_after = [player.fullness, player.hydration, player.warmth];
+        let needs: Vec<String> = ["fullness", "hydration", "warmth"]
+            .iter()
+            .zip(needs_before.iter().zip(needs_after.iter()))
+            .map(|(name, (before, after))| format!("{} {:.0} → {:.0}", name, before, after))
+            .collect();
+        let mut text = format!(
+            "**Scenario:** {}, {} tick(s) ({}h {:02}m)\n\
+            **From:** {} **to** {}\n\
+            **Weather here:** {}\n\
+            **Needs:** {}",
+            scenario.name(),
+            ticks,
+            minutes / 60,
+            minutes % 60,
+            started,
+            state.time.formatted_time(),
+            skies,
+            needs.join(", ")
+        );
+        if scenario.keeps_night_watch() {
+            text.push_str("\n**In the night:** ");
+            if events.is_empty() {
+                text.push_str("nothing stirred.");
+            } else {
+                let lines: Vec<String> = events.iter().map(|e| e.describe()).collect();
+                text.push_str(&lines.join(" "));
+            }
+        }
+        text.push_str(&format!("\n\nIt is now {}.", state.time.time_description()));
+        CallToolResult::text(text)
+    }
+
     fn cmd_time(&self, _args: &Option<Value>) -> CallToolResult {
         let time = &self.world.state.time;
         let weather = &self.world.state.weather;
@@ -924,41 +1844,233 @@ You feel calmer and a bit more refreshed. It is now {}.",
         let text = format!(
             "**Time:** {}\n\
             **Day:** {}\n\
-            **Weather:** {}",
+            **Season:** {} (day {} of {})\n\
+            **Weather:** {}\n\
+            **Temperature:** {}",
             time.time_description(),
             time.day,
-            current_weather.name()
+            time.season().name(),
+            time.day_of_season(),
+            WorldTime::DAYS_PER_SEASON,
+            current_weather.name(),
+            self.world.state.temperature_readout(&self.world.map)
         );
 
         CallToolResult::text(text)
     }
 
-    fn cmd_skills(&self, _args: &Option<Value>) -> CallToolResult {
-        let skills = &self.world.state.player.skills;
+    fn cmd_saves(&mut self, args: &Option<Value>) -> CallToolResult {
+        let action = get_string_arg(args, "action").unwrap_or_else(|| "list".to_string());
+        let path = self.world.state_path.clone();
+        if action != "restore-snapshot" {
+            let saved = snapshots::list(&path);
+            if saved.is_empty() {
+                return CallToolResult::text(
+                    "No snapshots yet. One is taken each dawn, on the cave's doorstep, and whenever you finish a blueprint.".to_string(),
+                );
+            }
+            let mut text = String::from("Snapshots, newest first:\n");
+            for snapshot in &saved {
+                text.push_str(&format!(
+                    "- {} (day {}, saved {})\n",
+                    snapshot.name,
+                    snapshot.day,
+                    snapshots::age_phrase(snapshot.saved_at)
+                ));
+            }
+            text.push_str("Restore one with {\"action\": \"restore-snapshot\", \"name\": ...}.");
+            return CallToolResult::text(text);
+        }
 
+        let Some(name) = get_string_arg(args, "name") else {
+            return CallToolResult::error(
+                "Which snapshot? Use the saves tool's list to see their names.".to_string(),
+            );
+        };
+        if !snapshots::list(&path).iter().any(|s| s.name == name) {
+            return CallToolResult::error(format!(
+                "There's no snapshot called '{}'. Use the saves tool's list to see their names.",
+                name
+            ));
+        }
+        // Keep where things stand now, so the restore can itself be undone
+        let undo = format!("before-restore-day-{}", self.world.state.time.day);
+        self.world.state.milestone(undo.clone());
+        if let Err(e) = snapshots::restore(&path, &name) {
+            return CallToolResult::error(format!("Couldn't restore '{}': {}", name, e));
+        }
+        // Written only now, so pruning can't take the snapshot being restored
+        self.world.flush_snapshots();
+        self.world = World::new(path);
+        let here = self.describe_here();
         let text = format!(
-            "**Skills:**\n\n\
-            Woodcutting: {}/100\n\
-            Fire Making: {}/100\n\
-            Observation: {}/100\n\
-            Foraging: {}/100\n\
-            Stonemasonry: {}/100\n\
-            Survival: {}/100\n\
-            Tailoring: {}/100\n\
-            Cooking: {}/100",
-            skills.woodcutting,
-            skills.fire_making,
-            skills.observation,
-            skills.foraging,
-            skills.stonemasonry,
-            skills.survival,
-            skills.tailoring,
-            skills.cooking
+            "You blink, and the world settles back to snapshot {}: {}. (Where you were is kept as {}.)\n\n{}",
+            name,
+            self.world.state.time.formatted_time(),
+            undo,
+            here
         );
-
         CallToolResult::text(text)
     }
 
+    fn cmd_settings(&mut self, args: &Option<Value>) -> CallToolResult {
+        if let Some(minutes) = args
+            .as_ref()
+            .and_then(|a| a.get("minutes_per_tick"))
+            .and_then(|v| v.as_u64())
+        {
+            if !self.world.state.time.set_minutes_per_tick(minutes as u32) {
+                return CallToolResult::error(format!(
+                    "{} isn't an option for minutes per tick. Use 5, 10, or 20.",
+                    minutes
+                ));
+            }
+        }
+        if let Some(goal) = get_string_arg(args, "goal") {
+            let rescue = &mut self.world.state.rescue;
+            match goal.as_str() {
+                "rescue" => rescue.active = true,
+                "none" if rescue.ending.is_some() => {
+                    return CallToolResult::error(
+                        "You've already been found; that story is written.".to_string(),
+                    )
+                }
+                "none" => rescue.active = false,
+                _ => {
+                    return CallToolResult::error(format!(
+                        "'{}' isn't a goal. Use rescue or none.",
+                        goal
+                    ))
+                }
+            }
+            self.world.state.refresh_blueprint_knowledge(true);
+        }
+        let goal_line = self.world.state.rescue.progress_line().unwrap_or_else(|| {
+            "Goal: none (set goal to rescue to signal for a way home)".to_string()
+        });
+        let minutes_per_tick = self.world.state.time.minutes_per_tick;
+        let player = &mut self.world.state.player;
+        if let Some(mode) = get_string_arg(args, "auto_pickup") {
+            match AutoPickup::from_str(&mode) {
+                Some(m) => player.auto_pickup = m,
+                None => {
+                    return CallToolResult::error(format!(
+                        "'{}' isn't an auto-pickup mode. Use off, light, or all.",
+                        mode
+                    ))
+                }
+            }
+        }
+        if let Some(lang) = get_string_arg(args, "language") {
+            match Language::from_str(&lang) {
+                Some(l) => player.language = l,
+                None => {
+                    return CallToolResult::error(format!(
+                        "'{}' isn't a language. Use en or ko.",
+                        lang
+                    ))
+                }
+            }
+        }
+        if let Some(mode) = get_string_arg(args, "transcript") {
+            match mode.as_str() {
+                "on" => player.record_transcript = true,
+                "off" => player.record_transcript = false,
+                _ => {
+                    return CallToolResult::error(format!(
+                        "'{}' isn't a transcript setting. Use on or off.",
+                        mode
+                    ))
+                }
+            }
+        }
+        CallToolResult::text(format!(
+            "**Settings:**\nAuto-pickup: {} (off: leave everything on the ground; light: pick up small things up to {:.1} kg; all: pick up whatever fits)\nMinutes per tick: {} (5 for slow, contemplative play; 10 standard; 20 brisk)\nLanguage: {} (for instructions and syntax hints; commands work in either)\nTranscript: {} (each call and reply kept under transcripts/ beside your save, readable as {})\n{}",
+            player.auto_pickup.name(),
+            AutoPickup::LIGHT_WEIGHT,
+            minutes_per_tick,
+            player.language.name(),
+            if player.record_transcript { "on" } else { "off" },
+            TRANSCRIPT_URI,
+            goal_line
+        ))
+    }
+
+    fn cmd_body(&self, _args: &Option<Value>) -> CallToolResult {
+        CallToolResult::text(self.world.state.player.body_report())
+    }
+
+    fn cmd_forecast(&self, _args: &Option<Value>) -> CallToolResult {
+        if matches!(
+            self.world.state.player.room,
+            Some(Room::CabinMain) | Some(Room::WoodShed)
+        ) {
+            return CallToolResult::text(
+                "You can't read the sky from in here. Step outside or onto the terrace.".to_string(),
+            );
+        }
+        CallToolResult::text(self.world.state.forecast())
+    }
+
+    fn cmd_plan(&self, args: &Option<Value>) -> CallToolResult {
+        let Some(goal) = get_string_arg(args, "goal") else {
+            return CallToolResult::error("Please say what to plan for.".to_string());
+        };
+        CallToolResult::text(plan_for(&goal, &self.world.state, &self.world.map))
+    }
+
+    fn cmd_projects(&mut self, args: &Option<Value>) -> CallToolResult {
+        let action = get_string_arg(args, "action").unwrap_or_else(|| "list".to_string());
+        let project = get_string_arg(args, "project");
+        let result = try_projects(&action, project.as_deref(), &mut self.world.state);
+        self.finish_interaction(result)
+    }
+
+    fn cmd_skills(&self, _args: &Option<Value>) -> CallToolResult {
+        let skills = &self.world.state.player.skills;
+        let today = self.world.state.time.day;
+
+        let lines: Vec<String> = Skill::ALL
+            .iter()
+            .map(|&skill| {
+                let level = skills.get(skill);
+                let name = capitalize(skill.name());
+                if skills.untried(skill) {
+                    return format!(
+                        "{}: {}/100 (not practised yet)\n  to start: {}",
+                        name,
+                        level,
+                        skill.trained_by()
+                    );
+                }
+                let gained = match skills.gained_on(skill, today) {
+                    (_, levels) if levels > 0 => format!(" (+{} today)", levels),
+                    (xp, _) if xp > 0 => " (practised today)".to_string(),
+                    _ => String::new(),
+                };
+                format!(
+                    "{}: {}/100{}\n  trained by: {}",
+                    name,
+                    level,
+                    gained,
+                    skill.trained_by()
+                )
+            })
+            .collect();
+
+        CallToolResult::text(format!("**Skills:**\n\n{}", lines.join("\n")))
+    }
+
+    fn cmd_debug_tile(&self, args: &Option<Value>) -> CallToolResult {
+        let here = self.world.state.player.position;
+        let row = get_int_arg(args, "row", here.row as i64) as i32;
+        let col = get_int_arg(args, "col", here.col as i64) as i32;
+        match TileReport::gather(&self.world.map, &self.world.state, Position::new(row, col)) {
+            Some(report) => CallToolResult::text(report.to_string()),
+            None => CallToolResult::error(format!("{},{} is off the map.", row, col)),
+        }
+    }
+
     fn append_web_log(&self, line: &str) {
         use std::fs::OpenOptions;
         use std::io::Write;
@@ -990,3 +2102,1073 @@ fn timestamp() -> String {
         Err(_) => "0".to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A server saving into its own scratch directory.
+    fn server(name: &str) -> McpServer {
+        let dir = std::env::temp_dir().join(format!(
+            "rubber-duck-server-{}-{}",
+            std::process::id(),
+            name
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        McpServer::new(dir.join("world_state.json"), dir.join("web_log.txt"))
+    }
+
+    fn call(id: u64, tool: &str, arguments: Value) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "tools/call",
+            "params": { "name": tool, "arguments": arguments },
+        })
+    }
+
+    /// Feed `messages` through `serve`, one per line, and parse the replies.
+    fn exchange(server: &mut McpServer, messages: &[Value]) -> Vec<Value> {
+        let input: String = messages.iter().map(|m| format!("{}\n", m)).collect();
+        let mut output = Vec::new();
+        server
+            .serve(std::io::Cursor::new(input), &mut output)
+            .unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    /// Run one tool over the wire, insisting it isn't an error.
+    fn play(server: &mut McpServer, tool: &str, arguments: Value) -> String {
+        let reply = exchange(server, &[call(1, tool, arguments)]).remove(0);
+        assert_eq!(reply["id"], 1);
+        assert!(reply["error"].is_null(), "{}: {}", tool, reply);
+        assert_ne!(reply["result"]["isError"], true, "{}: {}", tool, reply);
+        reply["result"]["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// A new game, stood at the cabin's front door.
+    fn at_the_door(name: &str) -> McpServer {
+        let mut server = server(name);
+        server.world.state.player.position = Position::new(1, 0);
+        server
+    }
+
+    /// Somewhere to stand with the lake right beside it.
+    fn on_the_shore(server: &McpServer) -> Position {
+        let map = &server.world.map;
+        let sides = [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ];
+        map.positions()
+            .filter(|p| map.can_stand_at(p))
+            .find(|p| {
+                sides
+                    .iter()
+                    .any(|d| map.is_open_water(&p.move_in_direction(*d)))
+            })
+            .unwrap()
+    }
+
+    fn fire(server: &McpServer) -> FireState {
+        server.world.state.cabin_state().unwrap().fireplace.state
+    }
+
+    #[test]
+    fn a_missing_item_passes_no_time() {
+        let mut server = server("missing-item");
+        let tick = server.world.state.time.tick;
+        server.finish_interaction(InteractionResult::missing(
+            Item::Axe,
+            "You need an axe for that.",
+        ));
+        assert_eq!(server.world.state.time.tick, tick);
+    }
+
+    #[test]
+    fn bad_luck_still_passes_the_time_it_took() {
+        let mut server = server("chance");
+        let tick = server.world.state.time.tick;
+        let ticks = server.world.state.time.ticks_for_cost(1) as u64;
+        let result =
+            server.finish_interaction(InteractionResult::chance("The line snaps.", 1, 1.0));
+        assert!(ticks >= 1);
+        assert_eq!(server.world.state.time.tick, tick + ticks);
+        assert!(extract_text(&result).unwrap().contains("took"));
+    }
+
+    #[test]
+    fn the_tutorial_fire_can_be_lit_over_the_wire() {
+        let mut server = at_the_door("fire");
+        let replies = exchange(
+            &mut server,
+            &[
+                json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+                json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" }),
+            ],
+        );
+        assert_eq!(replies.len(), 2);
+        assert_eq!(replies[0]["id"], 1);
+        assert!(replies[0]["result"]["protocolVersion"].is_string());
+        let tools = replies[1]["result"]["tools"].as_array().unwrap();
+        assert!(tools.iter().any(|t| t["name"] == "tend_fire"));
+
+        play(&mut server, "open", json!({ "target": "door" }));
+        assert!(play(&mut server, "enter", json!({ "location": "cabin" })).contains("cabin"));
+        play(&mut server, "move", json!({ "direction": "hearth" }));
+        for item in ["kindling", "dry leaves", "matchbox"] {
+            play(&mut server, "take", json!({ "item": item }));
+        }
+        for item in ["dry leaves", "kindling"] {
+            let lay = json!({ "item": item, "target": "fireplace" });
+            play(&mut server, "use", lay);
+        }
+        assert_eq!(fire(&server), FireState::Cold);
+
+        // A gust down the chimney can snuff a match; strike until it takes
+        let strike = json!({ "item": "matchbox", "target": "fireplace" });
+        for _ in 0..10 {
+            if fire(&server) != FireState::Cold {
+                break;
+            }
+            play(&mut server, "use", strike.clone());
+        }
+        assert_ne!(fire(&server), FireState::Cold);
+        assert!(play(&mut server, "tend_fire", json!({ "mode": "stoke" })).contains("took"));
+
+        // Every reply was saved as it went out
+        let saved = GameState::load(&server.world.state_path).unwrap();
+        assert_ne!(
+            saved.cabin_state().unwrap().fireplace.state,
+            FireState::Cold
+        );
+    }
+
+    #[test]
+    fn a_whole_day_goes_by_over_the_wire() {
+        let mut server = at_the_door("day");
+        let day = server.world.state.time.day;
+        let tick = server.world.state.time.tick;
+        play(&mut server, "open", json!({ "target": "door" }));
+        play(&mut server, "enter", json!({ "location": "cabin" }));
+        for hour in 0..24 {
+            let text = play(&mut server, "wait", json!({ "duration": "long" }));
+            assert!(text.contains("Time passes"), "hour {}: {}", hour, text);
+        }
+        play(&mut server, "status", json!({}));
+
+        let state = &server.world.state;
+        assert_eq!(state.time.day, day + 1);
+        assert!(state.time.tick - tick >= state.time.ticks_for_minutes(24 * 60) as u64);
+        assert!(state.player.health > 0.0);
+        let saved = GameState::load(&server.world.state_path).unwrap();
+        assert_eq!(saved.time.tick, state.time.tick);
+    }
+
+    #[test]
+    fn a_reloaded_server_carries_on_where_it_stopped() {
+        let mut server = at_the_door("reload");
+        play(&mut server, "open", json!({ "target": "door" }));
+        play(&mut server, "enter", json!({ "location": "cabin" }));
+        play(&mut server, "move", json!({ "direction": "hearth" }));
+        play(&mut server, "take", json!({ "item": "matchbox" }));
+        play(&mut server, "wait", json!({ "duration": "medium" }));
+        let tick = server.world.state.time.tick;
+        let state_path = server.world.state_path.clone();
+        let log_path = server.log_path.clone();
+        drop(server);
+
+        let mut server = McpServer::new(state_path, log_path);
+        assert_eq!(server.world.state.time.tick, tick);
+        assert_eq!(server.world.state.player.room, Some(Room::CabinMain));
+        assert!(play(&mut server, "inventory", json!({})).contains("matchbox"));
+        assert!(play(&mut server, "look", json!({})).contains("fireplace"));
+    }
+
+    #[test]
+    fn an_urgent_need_holds_until_the_player_spends_time() {
+        let mut server = server("urgent-need");
+        let need = "You are badly hungry.".to_string();
+        // Word about saving, from other tests sharing the health report, may go first
+        let leads = |reply: &CallToolResult| extract_text(reply).unwrap().contains("**You are");
+        server.world.state.urgent_need = Some(need.clone());
+        for tool in ["status", "look", "skills", "inventory"] {
+            let reply = server.execute_tool(tool, &Some(json!({})));
+            assert!(leads(&reply), "{}", tool);
+            assert_eq!(server.world.state.urgent_need.as_ref(), Some(&need));
+        }
+        let reply = server.execute_tool("wait", &Some(json!({ "duration": "short" })));
+        assert!(leads(&reply));
+        assert_eq!(server.world.state.urgent_need, None);
+    }
+
+    /// Game minutes since the first morning.
+    fn clock(server: &McpServer) -> u32 {
+        let time = &server.world.state.time;
+        (time.day * 24 + time.hour as u32) * 60 + time.minute as u32
+    }
+
+    #[test]
+    fn everyday_actions_take_the_same_time_at_any_tick_length() {
+        let mut spent = Vec::new();
+        for minutes in [5, 10] {
+            let mut server = server(&format!("tick-{}", minutes));
+            assert!(server.world.state.time.set_minutes_per_tick(minutes));
+            // On the shore, with the lake to drink from and land to walk on
+            let compass = [
+                Direction::North,
+                Direction::South,
+                Direction::East,
+                Direction::West,
+            ];
+            let map = &server.world.map;
+            let extent = map.extent();
+            let (shore, onward) = (-extent..=extent)
+                .flat_map(|row| (-extent..=extent).map(move |col| Position::new(row, col)))
+                .filter(|p| map.can_stand_at(p))
+                .filter(|p| {
+                    compass
+                        .iter()
+                        .any(|d| map.is_open_water(&p.move_in_direction(*d)))
+                })
+                .find_map(|p| {
+                    let dir = compass
+                        .into_iter()
+                        .find(|d| map.can_stand_at(&p.move_in_direction(*d)))?;
+                    Some((p, dir))
+                })
+                .unwrap();
+            server.world.state.player.position = shore;
+
+            let mut costs = Vec::new();
+            for (tool, args) in [
+                ("drink", json!({})),
+                ("meditate", json!({})),
+                ("move", json!({ "direction": onward.name() })),
+            ] {
+                let before = clock(&server);
+                let reply = server.execute_tool(tool, &Some(args));
+                assert_ne!(reply.is_error, Some(true), "{}", tool);
+                costs.push(clock(&server) - before);
+            }
+            spent.push(costs);
+        }
+        assert_eq!(spent[0], [10, 10, 10]);
+        assert_eq!(spent[1], spent[0]);
+    }
+
+    #[test]
+    fn the_web_view_s_tile_agrees_with_the_game() {
+        let mut server = at_the_door("web-tile");
+        let door = server.world.state.player.position;
+        server.world.state.player.inventory.add(Item::Knife, 1);
+        play(&mut server, "drop", json!({ "item": "knife" }));
+        // Coming up empty-handed still leaves the patch marked as foraged
+        let forage = call(1, "use", json!({ "item": "hands", "target": "ground" }));
+        exchange(&mut server, &[forage]);
+
+        let url = format!("/tile?row={}&col={}", door.row, door.col);
+        let (status, body) = tile_json(&url, &server.world.state_path, &server.world.map);
+        assert_eq!(status, 200, "{}", body);
+        let served: Value = serde_json::from_str(&body).unwrap();
+        let report = TileReport::gather(&server.world.map, &server.world.state, door).unwrap();
+        assert_eq!(served, serde_json::to_value(&report).unwrap(), "{}", report);
+        let on_the_ground = |name: &str| report.ground_items.iter().any(|(n, _)| n == name);
+        assert!(on_the_ground(Item::Knife.name()), "{}", report);
+        assert!(report.forage.is_some(), "{}", report);
+    }
+
+    #[test]
+    fn turned_down_actions_count_as_failures_in_the_metrics() {
+        let mut server = at_the_door("metrics");
+        server.execute_tool("look", &None);
+        server.execute_tool("look", &None);
+        // Nothing by that name to pick up
+        let reply = server.execute_tool("take", &Some(json!({ "item": "unicorn" })));
+        assert_ne!(reply.is_error, Some(true));
+
+        let exposition = crate::metrics::render_prometheus();
+        for sample in [
+            "rubber_duck_tool_calls_total{tool=\"look\"} 2",
+            "rubber_duck_tool_failures_total{tool=\"look\"} 0",
+            "rubber_duck_tool_calls_total{tool=\"take\"} 1",
+            "rubber_duck_tool_failures_total{tool=\"take\"} 1",
+        ] {
+            assert!(exposition.lines().any(|l| l == sample), "{}", exposition);
+        }
+    }
+
+    #[test]
+    fn tea_can_be_made_from_the_lake_with_the_use_tool_alone() {
+        let mut server = server("tea");
+        for item in [Item::Kettle, Item::TeaCup, Item::WildHerbs] {
+            server.world.state.player.inventory.add(item, 1);
+        }
+        server.world.state.player.position = on_the_shore(&server);
+        let fill = json!({ "item": "kettle", "target": "lake" });
+        play(&mut server, "use", fill);
+        let inventory = &server.world.state.player.inventory;
+        assert!(inventory.has(&Item::WaterKettle, 1));
+
+        // Back in the cabin with a fire going
+        server.world.state.player.position = Position::new(1, 0);
+        play(&mut server, "open", json!({ "target": "door" }));
+        play(&mut server, "enter", json!({ "location": "cabin" }));
+        play(&mut server, "move", json!({ "direction": "hearth" }));
+        let hearth = &mut server.world.state.cabin_state_mut().unwrap().fireplace;
+        hearth.state = FireState::Burning;
+        hearth.fuel = 10.0;
+        hearth.bulk_lit = true;
+
+        let boil = json!({ "item": "water kettle", "target": "fire" });
+        play(&mut server, "use", boil);
+        let brew = json!({ "item": "wild herbs", "target": "cup" });
+        play(&mut server, "use", brew);
+        let inventory = &server.world.state.player.inventory;
+        assert!(inventory.has(&Item::HerbalTea, 1));
+        assert!(inventory.has(&Item::Kettle, 1));
+
+        let mood = server.world.state.player.mood;
+        play(&mut server, "use", json!({ "item": "herbal tea" }));
+        let player = &server.world.state.player;
+        assert!(!player.inventory.has(&Item::HerbalTea, 1));
+        assert!(player.inventory.has(&Item::TeaCup, 1));
+        assert!(player.mood > mood);
+    }
+
+    #[test]
+    fn a_renamed_axe_goes_by_its_new_name_everywhere() {
+        let mut server = at_the_door("trusty");
+        server.world.state.player.inventory.add(Item::Axe, 1);
+        let rename = json!({ "item": "axe", "name": "Trusty" });
+        play(&mut server, "name", rename);
+        assert!(play(&mut server, "inventory", json!({})).contains("Trusty (axe)"));
+
+        // The new name is a fine item argument to every tool that takes one
+        let trusty = Some(json!({ "item": "Trusty" }));
+        for tool in ["hold", "stow", "drop", "take", "use"] {
+            assert_eq!(validate_args(tool, &trusty), Ok(()));
+        }
+        assert!(play(&mut server, "drop", json!({ "item": "Trusty" })).contains("Trusty"));
+        assert!(!server.world.state.player.inventory.has(&Item::Axe, 1));
+        assert!(play(&mut server, "take", json!({ "item": "Trusty" })).contains("Trusty"));
+        assert!(play(&mut server, "hold", json!({ "item": "Trusty" })).contains("Trusty"));
+
+        // And it's still Trusty after a restart
+        let state_path = server.world.state_path.clone();
+        let log_path = server.log_path.clone();
+        drop(server);
+        let mut reloaded = McpServer::new(state_path, log_path);
+        assert!(play(&mut reloaded, "inventory", json!({})).contains("Trusty (axe)"));
+    }
+
+    #[test]
+    fn the_armchair_by_a_lit_fire_makes_reading_free_and_doubles_meditation() {
+        let mut costs = Vec::new();
+        for seated in [false, true] {
+            let mut server = at_the_door(&format!("armchair-{}", seated));
+            play(&mut server, "open", json!({ "target": "door" }));
+            play(&mut server, "enter", json!({ "location": "cabin" }));
+            let hearth = &mut server.world.state.cabin_state_mut().unwrap().fireplace;
+            hearth.state = FireState::Burning;
+            hearth.fuel = 50.0;
+            hearth.bulk_lit = true;
+            if seated {
+                play(&mut server, "sit", json!({ "target": "armchair" }));
+            }
+            assert_eq!(server.world.state.seated_by_fire(&server.world.map), seated);
+
+            let player = &mut server.world.state.player;
+            (player.energy, player.mood) = (50.0, 20.0);
+            let page = json!({ "item": "tutorial book", "target": "nextpage" });
+            play(&mut server, "use", page);
+            let read = 50.0 - server.world.state.player.energy;
+            play(&mut server, "meditate", json!({}));
+            let calmed = server.world.state.player.mood - 20.0;
+            costs.push((read, calmed));
+        }
+        let (standing, seated) = (costs[0], costs[1]);
+        assert!(standing.0 > 0.0, "{:?}", costs);
+        assert_eq!(seated.0, 0.0, "{:?}", costs);
+        assert!((seated.1 - standing.1 * 2.0).abs() < 1.0, "{:?}", costs);
+    }
+
+    #[test]
+    fn a_chosen_name_shows_in_the_journal_and_clearing_it_brings_back_you() {
+        /// Make peace with a hare, and read what gets written about it.
+        fn reconcile(server: &mut McpServer) -> String {
+            let state = &mut server.world.state;
+            state.kills.push(KillRecord {
+                corpse_id: "corpse-hare".to_string(),
+                species: Species::SnowHare,
+                tick: state.time.tick,
+                butchered: true,
+                cooked: true,
+                hide_used: false,
+                wasted: false,
+                mourned: false,
+                reconciled: false,
+            });
+            state.pending_messages.clear();
+            state.note_kill_hide_used();
+            state.pending_messages.join("\n")
+        }
+        let mut server = server("name-self");
+
+        let named = json!({ "item": "self", "name": "Mara", "pronouns": "they/them" });
+        play(&mut server, "name", named);
+        let entry = reconcile(&mut server);
+        let lead = "A quiet settles in them. Mara writes:";
+        assert!(entry.contains(lead), "{}", entry);
+
+        let cleared = json!({ "item": "self", "clear": true });
+        play(&mut server, "name", cleared);
+        assert!(server.world.state.player.name.is_none());
+        let entry = reconcile(&mut server);
+        let lead = "A quiet settles in you. You write:";
+        assert!(entry.contains(lead), "{}", entry);
+    }
+
+    #[test]
+    fn eat_takes_a_count_and_a_bare_drink_reaches_for_the_flask_first() {
+        let mut server = at_the_door("eat-and-drink");
+        let inv = &mut server.world.state.player.inventory;
+        *inv = Inventory::new();
+        inv.add(Item::WildBerry, 5);
+        inv.add(Item::Log, 1);
+        inv.add(Item::CleanWater, 1);
+        inv.add(Item::MuddyWater, 1);
+        server.world.state.player.fullness = 20.0;
+        let held = |server: &McpServer, item| server.world.state.player.inventory.count(&item);
+        let berries = json!({ "item": "berries", "quantity": 3 });
+
+        play(&mut server, "eat", berries);
+        assert_eq!(held(&server, Item::WildBerry), 2);
+        play(&mut server, "eat", json!({ "item": "2 berries" }));
+        assert_eq!(held(&server, Item::WildBerry), 0);
+        let log = play(&mut server, "eat", json!({ "item": "log" }));
+        assert!(log.contains("help food"));
+        assert_eq!(held(&server, Item::Log), 1);
+
+        // Clean water first, even with the lake right there
+        let shore = on_the_shore(&server);
+        server.world.state.player.position = shore;
+        play(&mut server, "drink", json!({}));
+        assert_eq!(held(&server, Item::CleanWater), 0);
+        assert_eq!(held(&server, Item::MuddyWater), 1);
+        // Then the lake itself, which beats a muddy flask
+        play(&mut server, "drink", json!({}));
+        assert_eq!(held(&server, Item::MuddyWater), 1);
+        // And muddy water only when there's no lake to kneel at
+        server.world.state.player.position = Position::new(1, 0);
+        play(&mut server, "drink", json!({}));
+        assert_eq!(held(&server, Item::MuddyWater), 0);
+    }
+
+    #[test]
+    fn the_ruin_is_dug_out_a_chamber_at_a_time_over_several_visits() {
+        let mut server = server("ruin");
+        let site = server.world.state.objects.find(RUIN_ID).unwrap().position;
+        server.world.state.player.exit_room();
+        server.world.state.player.position = site;
+        server.world.state.player.inventory.add(Item::Shovel, 1);
+
+        // Just another dune until it's been found
+        play(&mut server, "enter", json!({ "location": "ruin" }));
+        assert!(!server.world.state.in_ruin());
+        server.world.state.discover_ruin();
+
+        // Two loads a visit, with a restart between each
+        let shovel = json!({ "item": "shovel", "target": "sand" });
+        let mut progress = Vec::new();
+        for _ in 0..3 {
+            server.world.state.player.energy = 100.0;
+            play(&mut server, "enter", json!({ "location": "ruin" }));
+            assert!(server.world.state.in_ruin());
+            for _ in 0..2 {
+                play(&mut server, "use", shovel.clone());
+            }
+            play(&mut server, "exit", json!({}));
+            assert!(!server.world.state.in_ruin());
+
+            let state_path = server.world.state_path.clone();
+            let log_path = server.log_path.clone();
+            drop(server);
+            server = McpServer::new(state_path, log_path);
+            let ruin = server.world.state.ruin().unwrap();
+            let read = server.world.state.ruin_inscriptions().len();
+            progress.push((ruin.chambers_cleared, ruin.sand_moved, read));
+        }
+        assert_eq!(progress, [(0, 2, 1), (1, 1, 2), (2, 0, 3)]);
+        assert!(server.world.state.player.inventory.has(&Item::Lodestone, 1));
+
+        // Nothing left to dig
+        server.world.state.player.energy = 100.0;
+        play(&mut server, "enter", json!({ "location": "ruin" }));
+        let done = play(&mut server, "use", shovel);
+        assert!(done.starts_with("The chambers are dug clear."));
+    }
+
+    #[test]
+    fn an_evening_by_the_fire_mends_you_and_a_neglected_one_does_not() {
+        let evening = |cared_for: bool| {
+            let mut server = at_the_door(&format!("mending-{}", cared_for));
+            let state = &mut server.world.state;
+            let (w, clear) = (&mut state.weather, Weather::Clear);
+            (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+            state.player.health = 60.0;
+            if cared_for {
+                play(&mut server, "open", json!({ "target": "door" }));
+                play(&mut server, "enter", json!({ "location": "cabin" }));
+                let player = &mut server.world.state.player;
+                (player.fullness, player.hydration, player.warmth) = (95.0, 95.0, 55.0);
+            } else {
+                let player = &mut server.world.state.player;
+                (player.fullness, player.hydration, player.warmth) = (30.0, 30.0, 25.0);
+            }
+            let mut health = Vec::new();
+            let mut recovering = Vec::new();
+            for _ in 0..30 {
+                if cared_for {
+                    let hearth = &mut server.world.state.cabin_state_mut().unwrap().fireplace;
+                    (hearth.state, hearth.bulk_lit, hearth.fuel) = (FireState::Burning, true, 50.0);
+                }
+                let status = play(&mut server, "status", json!({}));
+                recovering.push(status.contains("(recovering)"));
+                server.world.state.tick_with_map(&mut server.world.map);
+                health.push(server.world.state.player.health);
+            }
+            (server, health, recovering)
+        };
+
+        let (mut server, health, recovering) = evening(true);
+        assert!(recovering.iter().all(|r| *r));
+        assert!(health.windows(2).all(|w| w[1] > w[0]));
+        assert!((health[29] - 75.0).abs() < 0.01);
+        // Sleep is a multiplier on the same mending, not a lump sum
+        server.world.state.sleeping = true;
+        server.world.state.tick_with_map(&mut server.world.map);
+        assert!((server.world.state.player.health - 77.0).abs() < 0.01);
+
+        let (_, health, recovering) = evening(false);
+        assert!(recovering.iter().all(|r| !*r));
+        assert!(health.iter().all(|h| *h == 60.0));
+    }
+
+    #[test]
+    fn three_clear_days_of_smoke_bring_rescue_and_the_choice_to_leave_or_stay() {
+        let awaiting = Some(RescueEnding::Awaiting);
+        let rescued = |name: &str| {
+            let mut server = server(name);
+            let knoll = server.world.map.highest_point();
+            let state = &mut server.world.state;
+            state.player.exit_room();
+            state.player.position = knoll;
+            state.objects.placed.retain(|po| po.position != knoll);
+            state.player.inventory.add(Item::Matchbox, 1);
+            state.player.inventory.add(Item::SignalFire, 4);
+            play(&mut server, "settings", json!({ "goal": "rescue" }));
+
+            let light = |server: &mut McpServer, sky: Weather| {
+                let (w, day) = (&mut server.world.state.weather, sky);
+                (w.north, w.south, w.east, w.west) = (day, day, day, day);
+                server.world.state.player.energy = 100.0;
+                play(server, "drop", json!({ "item": "signal fire" }));
+                let matchbox = json!({ "item": "matchbox", "target": "signal fire" });
+                play(server, "use", matchbox)
+            };
+            assert!(light(&mut server, Weather::Clear).contains("(1/3 clear days.)"));
+            // Once a day, and only under a clear sky
+            assert!(light(&mut server, Weather::Clear).contains("already sent smoke up today"));
+            server.world.state.time.day += 1;
+            assert!(light(&mut server, Weather::Overcast).contains("Wait for a clear day"));
+            assert!(light(&mut server, Weather::Clear).contains("(2/3 clear days.)"));
+            assert_ne!(server.world.state.rescue.ending, awaiting);
+            server.world.state.time.day += 1;
+            assert!(light(&mut server, Weather::Clear).contains("The choice is yours"));
+            assert_eq!(server.world.state.rescue.ending, awaiting);
+            let chapter = server.world.state.journal.last().unwrap();
+            assert!(chapter.starts_with("The last chapter, in your own hand"));
+            server
+        };
+
+        let mut server = rescued("rescue-stay");
+        play(&mut server, "rescue", json!({ "choice": "stay" }));
+        assert_eq!(server.world.state.rescue.ending, Some(RescueEnding::Stayed));
+        let day = server.world.state.time.day;
+        let mood = server.world.state.player.mood;
+        server.world.state.player.warmth = 30.0;
+        server.world.state.tick_with_map(&mut server.world.map);
+        assert!(server.world.state.player.mood > mood);
+        assert_eq!(server.world.state.time.day, day);
+
+        let mut server = rescued("rescue-leave");
+        let unsure = play(&mut server, "rescue", json!({ "choice": "leave" }));
+        assert!(unsure.starts_with("Leaving ends this stay for good"));
+        assert_eq!(server.world.state.rescue.ending, awaiting);
+        let leave = json!({ "choice": "leave", "confirm": true });
+        let goodbye = play(&mut server, "rescue", leave);
+        assert!(goodbye.contains("**Your stay, in sum:**"));
+        assert!(goodbye.contains("signalled for rescue"));
+        assert_eq!(server.world.state.rescue.ending, None);
+        assert!(server.world.state.rescue.signal_days.is_empty());
+    }
+
+    #[test]
+    fn what_you_do_in_the_cabin_carries_you_to_the_spot_for_it() {
+        let mut server = at_the_door("cabin-spots");
+        server.world.state.player.inventory.add(Item::Stone, 1);
+        server.world.state.player.inventory.add(Item::Log, 1);
+        let spot = |server: &McpServer| server.world.state.player.cabin_spot;
+        play(&mut server, "open", json!({ "target": "door" }));
+        play(&mut server, "enter", json!({ "location": "cabin" }));
+        assert_eq!(spot(&server), Some(CabinSpot::Door));
+
+        play(&mut server, "move", json!({ "direction": "window" }));
+        assert_eq!(spot(&server), Some(CabinSpot::Window));
+        let on_table = json!({ "item": "stone", "target": "table" });
+        play(&mut server, "use", on_table);
+        assert_eq!(spot(&server), Some(CabinSpot::Table));
+        let on_fire = json!({ "item": "log", "target": "fire" });
+        play(&mut server, "use", on_fire);
+        assert_eq!(spot(&server), Some(CabinSpot::Hearth));
+
+        // Kept across a restart, and left behind at the door
+        play(&mut server, "move", json!({ "direction": "window" }));
+        let state_path = server.world.state_path.clone();
+        let log_path = server.log_path.clone();
+        drop(server);
+        let mut server = McpServer::new(state_path, log_path);
+        assert_eq!(spot(&server), Some(CabinSpot::Window));
+        play(&mut server, "exit", json!({}));
+        assert_eq!(spot(&server), None);
+    }
+
+    #[test]
+    fn things_set_down_in_the_cabin_stay_where_you_stood_and_come_back_from_there_first() {
+        let mut server = at_the_door("cabin-surfaces");
+        server.world.state.player.inventory.add(Item::Stone, 6);
+        play(&mut server, "open", json!({ "target": "door" }));
+        play(&mut server, "enter", json!({ "location": "cabin" }));
+        let held = |server: &McpServer, id: &str| {
+            let surface = server.world.state.surface(id).unwrap();
+            surface.items.count(&Item::Stone)
+        };
+        let stone = json!({ "item": "stone" });
+
+        play(&mut server, "move", json!({ "direction": "hearth" }));
+        let dropped = play(&mut server, "drop", stone.clone());
+        assert!(dropped.contains("in the basket by the hearth"));
+        // The sill holds four; the fifth goes on the floor
+        play(&mut server, "move", json!({ "direction": "window" }));
+        for _ in 0..4 {
+            play(&mut server, "drop", stone.clone());
+        }
+        let spilled = play(&mut server, "drop", stone.clone());
+        assert!(spilled.starts_with("There's no room left on the window sill"));
+        assert_eq!(held(&server, HEARTH_BASKET_ID), 1);
+        assert_eq!(held(&server, WINDOW_SILL_ID), 4);
+        let floor = &server.world.state.cabin_state().unwrap().items;
+        assert_eq!(floor.count(&Item::Stone), 1);
+
+        let room = play(&mut server, "look", json!({}));
+        assert!(room.contains("In the rush basket by the hearth: stone."));
+        assert!(room.contains("On the window sill: "));
+
+        // What's within reach comes first, then the rest of the room
+        play(&mut server, "move", json!({ "direction": "hearth" }));
+        let took = play(&mut server, "take", stone.clone());
+        assert!(took.contains("from the basket by the hearth"));
+        let took = play(&mut server, "take", stone);
+        assert!(!took.contains("from the basket by the hearth"));
+        assert_eq!(held(&server, HEARTH_BASKET_ID), 0);
+        assert_eq!(held(&server, WINDOW_SILL_ID), 4);
+    }
+
+    #[test]
+    fn a_log_set_on_the_block_splits_into_the_stack_or_the_axe_glances_off() {
+        let in_the_shed = |name: &str| {
+            let mut server = server(name);
+            let state = &mut server.world.state;
+            let shed = state.objects.find("wood_shed").unwrap().position;
+            let player = &mut state.player;
+            player.position = shed;
+            player.room = Some(Room::WoodShed);
+            player.inventory = Inventory::new();
+            player.inventory.add(Item::Axe, 1);
+            player.inventory.add(Item::Log, 1);
+            server
+        };
+        let axe = json!({ "item": "axe", "target": "chopping block" });
+        let log = json!({ "item": "log", "target": "chopping block" });
+        let block = |server: &McpServer| {
+            let shed = server.world.state.wood_shed_state().unwrap();
+            (shed.chopping_block.has_log, shed.firewood)
+        };
+
+        let mut server = in_the_shed("block-split");
+        let bare = play(&mut server, "use", axe.clone());
+        assert!(bare.starts_with("The chopping block is bare."));
+        let (_, stacked) = block(&server);
+        play(&mut server, "use", log.clone());
+        assert!(!server.world.state.player.inventory.has(&Item::Log, 1));
+        assert_eq!(block(&server), (true, stacked));
+        while block(&server).0 {
+            server.world.state.player.energy = 100.0;
+            server.world.state.player.tool_durability.remove(&Item::Axe);
+            play(&mut server, "use", axe.clone());
+        }
+        assert!(block(&server).1 >= stacked + 2);
+
+        // Weak arms and no skill: sooner or later the blade finds a leg or hand
+        let mut server = in_the_shed("block-glance");
+        for part in [BodyPartKind::ArmLeft, BodyPartKind::ArmRight] {
+            server.world.state.player.injure_part(part, 60.0, false);
+        }
+        let mut wounded = false;
+        for _ in 0..300 {
+            let player = &mut server.world.state.player;
+            player.energy = 100.0;
+            player.tool_durability.remove(&Item::Axe);
+            if !block(&server).0 {
+                server.world.state.player.inventory.add(Item::Log, 1);
+                play(&mut server, "use", log.clone());
+            }
+            let swing = play(&mut server, "use", axe.clone());
+            if swing.contains("The blade follows through into your") {
+                assert!(block(&server).0);
+                assert!(server.world.state.player.body.bleeding_parts() > 0);
+                wounded = true;
+                break;
+            }
+        }
+        assert!(wounded);
+    }
+
+    #[test]
+    fn move_home_needs_the_compass_and_steps_toward_the_door() {
+        let mut server = at_the_door("move-home");
+        let door = server.world.state.player.position;
+        let start = Position::new(door.row + 4, door.col + 2);
+        server.world.state.player.position = start;
+        let home = json!({ "direction": "home" });
+
+        let guess = exchange(&mut server, &[call(1, "move", home.clone())]).remove(0);
+        assert_eq!(guess["result"]["isError"], true);
+        assert_eq!(server.world.state.player.position, start);
+
+        let pack = &mut server.world.state.player.inventory;
+        pack.add(Item::StrangeCompass, 1);
+        let mut steps = 0;
+        loop {
+            server.world.state.player.energy = 100.0;
+            let before = server.world.state.player.position.distance_to(&door);
+            let text = play(&mut server, "move", home.clone());
+            if text == "You're standing at the cabin door already." {
+                break;
+            }
+            assert!(server.world.state.player.position.distance_to(&door) < before);
+            assert!(text.contains("The compass puts the cabin"));
+            steps += 1;
+            assert!(steps <= 6);
+        }
+        assert_eq!(server.world.state.player.position, door);
+    }
+
+    #[test]
+    fn cargo_stowed_on_the_raft_stays_aboard_over_time_and_a_reload() {
+        let mut server = server("raft-cargo");
+        let shore = on_the_shore(&server);
+        let state = &mut server.world.state;
+        state.player.exit_room();
+        state.player.position = shore;
+        state.player.inventory.add(Item::Raft, 1);
+        play(&mut server, "use", json!({ "item": "raft" }));
+        let aboard = |server: &McpServer, item: Item| {
+            let raft = server.world.state.objects.find(RAFT_ID).unwrap();
+            let hold = raft.object.storage.as_ref();
+            hold.map_or(0, |s| s.items.count(&item))
+        };
+
+        server.world.state.player.inventory.add(Item::Log, 3);
+        play(&mut server, "drop", json!({ "item": "3 log into raft" }));
+        assert_eq!(aboard(&server, Item::Log), 3);
+        assert!(!server.world.state.player.inventory.has(&Item::Log, 1));
+        play(&mut server, "take", json!({ "item": "log from raft" }));
+        assert_eq!(aboard(&server, Item::Log), 2);
+        assert!(server.world.state.player.inventory.has(&Item::Log, 1));
+
+        // Only as much as the deck holds goes aboard
+        server.world.state.player.inventory.add(Item::Stone, 20);
+        let stones = json!({ "item": "all stone into raft" });
+        play(&mut server, "drop", stones);
+        let room = Raft::CARGO_CAPACITY - 2;
+        assert_eq!(aboard(&server, Item::Stone), room);
+        let kept = server.world.state.player.inventory.count(&Item::Stone);
+        assert_eq!(kept, 20 - room);
+
+        for _ in 0..50 {
+            server.world.state.tick_with_map(&mut server.world.map);
+        }
+        assert_eq!(aboard(&server, Item::Log), 2);
+        server.world.save().unwrap();
+        let state_path = server.world.state_path.clone();
+        let log_path = server.log_path.clone();
+        drop(server);
+        let server = McpServer::new(state_path, log_path);
+        assert_eq!(aboard(&server, Item::Log), 2);
+        assert_eq!(aboard(&server, Item::Stone), room);
+    }
+
+    #[test]
+    fn name_retitles_books_clears_names_and_refuses_ones_in_use() {
+        let mut server = server("name-books");
+        let refused = |server: &mut McpServer, arguments: Value| {
+            let reply = exchange(server, &[call(1, "name", arguments)]).remove(0);
+            assert_eq!(reply["result"]["isError"], true, "{}", reply);
+            reply["result"]["content"][0]["text"]
+                .as_str()
+                .unwrap()
+                .to_string()
+        };
+        let state = &mut server.world.state;
+        let mut shelf = Vec::new();
+        for writable in [true, false] {
+            let id = state.generate_book_id();
+            state.register_book(BookEntry::new(id.clone(), "Untitled", writable));
+            state.add_player_book(&id);
+            shelf.push(id);
+        }
+        state.player.inventory.add(Item::Knife, 1);
+        state.player.inventory.add(Item::Stick, 1);
+        let (diary, printed) = (shelf[0].clone(), shelf[1].clone());
+
+        // A writable book takes a new title, by id and then by title
+        let named = |item: &str, name: &str| json!({ "item": item, "name": name });
+        play(&mut server, "name", named(&diary, "Lake Diary"));
+        play(&mut server, "name", named("lake diary", "Winter"));
+        let state = &server.world.state;
+        assert_eq!(state.book_entry(&diary).unwrap().title, "Winter");
+        assert_eq!(state.find_book_id("winter"), Some(diary.clone()));
+        let text = refused(&mut server, named(&printed, "Mine"));
+        assert!(text.contains("isn't yours to retitle"));
+
+        // A custom name wins over everything else when resolving
+        play(&mut server, "name", named("knife", "Sharp"));
+        assert_eq!(server.world.state.resolve_item("sharp"), Some(Item::Knife));
+
+        // Another item's name, a book's title, or an item's own name is taken
+        let text = refused(&mut server, named("stick", "sharp"));
+        assert!(text.contains("You already call your knife 'Sharp'"));
+        let text = refused(&mut server, named("stick", "Winter"));
+        assert!(text.contains(&format!("already a book called 'Winter' [{}]", diary)));
+        let text = refused(&mut server, named("stick", "axe"));
+        assert!(text.contains("'axe' already means the axe"));
+        let text = refused(&mut server, named(&diary, "Sharp"));
+        assert!(text.contains("You already call your knife"));
+        assert_eq!(server.world.state.custom_name(&Item::Stick), None);
+
+        // Clearing frees the name for something else
+        let clear = json!({ "item": "sharp", "clear": true });
+        let text = play(&mut server, "name", clear);
+        assert!(text.contains("It's just a knife again"));
+        assert_eq!(server.world.state.custom_name(&Item::Knife), None);
+        let text = refused(&mut server, json!({ "item": "knife", "clear": true }));
+        assert!(text.contains("has no name to clear"));
+        play(&mut server, "name", named("stick", "Sharp"));
+        assert_eq!(server.world.state.resolve_item("sharp"), Some(Item::Stick));
+    }
+
+    #[test]
+    fn each_dawn_leaves_one_snapshot_and_restoring_one_swaps_the_world_back() {
+        let mut server = server("snapshots");
+        let state_path = server.world.state_path.clone();
+        let log_path = server.log_path.clone();
+        let time = &mut server.world.state.time;
+        (time.hour, time.minute) = (3, 0);
+        let start = time.day;
+
+        // Simulated bursts through three dawns, checkpointed once each
+        let burst = Some(json!({ "ticks": 10 }));
+        while server.world.state.time.day < start + 2 || server.world.state.time.hour < 9 {
+            server.execute_tool("simulate", &burst);
+        }
+        let pending = server.world.state.pending_snapshots.iter();
+        let dawns: Vec<String> = pending
+            .map(|s| s.name.clone())
+            .filter(|n| n.starts_with("day-"))
+            .collect();
+        let expected: Vec<String> = (start..=start + 2).map(|d| format!("day-{}", d)).collect();
+        assert_eq!(dawns, expected);
+
+        // The writer finishes before the server lets go of the world
+        server.world.save().unwrap();
+        drop(server);
+        let listed: Vec<String> = snapshots::list(&state_path)
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        assert_eq!(listed.len(), 3, "{:?}", listed);
+        assert!(expected.iter().all(|n| listed.contains(n)));
+
+        // Restoring puts the world back to that morning, and keeps the present
+        let mut server = McpServer::new(state_path.clone(), log_path);
+        server.world.state.player.inventory.add(Item::Pinecone, 1);
+        let later = server.world.state.time.clone();
+        let name = &expected[1];
+        let restore = |name: &str| json!({ "action": "restore-snapshot", "name": name });
+        let text = play(&mut server, "saves", restore(name));
+        assert!(text.contains(&format!("snapshot {}", name)));
+        let time = &server.world.state.time;
+        assert_eq!((time.day, time.hour), (start + 1, 5));
+        assert!(time.tick < later.tick);
+        assert!(!server.world.state.player.inventory.has(&Item::Pinecone, 1));
+        let reply = exchange(&mut server, &[call(1, "saves", restore("nope"))]);
+        assert_eq!(reply[0]["result"]["isError"], true);
+        drop(server);
+        let undo = format!("before-restore-day-{}", later.day);
+        assert!(snapshots::list(&state_path).iter().any(|s| s.name == undo));
+    }
+
+    #[test]
+    fn stepping_out_into_foul_weather_warns_once_a_spell_with_the_gear_at_hand() {
+        let mut server = at_the_door("advisory");
+        let region = WorldMap::region_of(1, 0);
+        let state = &mut server.world.state;
+        (state.time.hour, state.time.minute) = (12, 0);
+        state.player.inventory = Inventory::new();
+        state.player.inventory.add(Item::WoolBlanket, 1);
+        state.weather.force_front(region, Weather::Blizzard, 500);
+        let warned = |text: &str| text.starts_with("You pause on the threshold.");
+
+        // The first step out into the blizzard says so, with the blanket
+        play(&mut server, "open", json!({ "target": "door" }));
+        play(&mut server, "enter", json!({ "location": "cabin" }));
+        let out = play(&mut server, "exit", json!({}));
+        assert!(warned(&out), "{}", out);
+        assert!(out.contains("A blizzard is howling"), "{}", out);
+        let warmth = server.world.state.player.warmth;
+        let blanket = format!("Your warmth is {:.0}; you have the wool blanket", warmth);
+        assert!(out.contains(&blanket), "{}", out);
+        assert!(!out.contains("It's dark"), "{}", out);
+        assert!(!out.contains("no light"), "{}", out);
+
+        // Not again while the same storm blows
+        play(&mut server, "enter", json!({ "location": "cabin" }));
+        let again = play(&mut server, "exit", json!({}));
+        assert!(!warned(&again), "{}", again);
+
+        // Once it clears, the next storm earns a fresh warning
+        play(&mut server, "enter", json!({ "location": "cabin" }));
+        let state = &mut server.world.state;
+        state.weather.force_front(region, Weather::Clear, 20);
+        for _ in 0..10 {
+            state.tick_with_map(&mut server.world.map);
+        }
+        assert_eq!(server.world.state.advised_weather, None);
+        let state = &mut server.world.state;
+        state.weather.force_front(region, Weather::Blizzard, 500);
+        state.player.inventory = Inventory::new();
+        state.player.inventory.add(Item::FireflyJar, 1);
+        (state.time.hour, state.time.minute) = (23, 0);
+        let night = play(&mut server, "exit", json!({}));
+        assert!(warned(&night), "{}", night);
+        assert!(night.contains("nothing warm to pull"), "{}", night);
+        assert!(night.contains("It's dark, too."), "{}", night);
+        assert!(night.contains("firefly jar is packed away"), "{}", night);
+
+        // A different kind of foul weather is a new spell in itself
+        play(&mut server, "enter", json!({ "location": "cabin" }));
+        let state = &mut server.world.state;
+        state.weather.force_front(region, Weather::HeatWave, 500);
+        (state.time.hour, state.time.minute) = (12, 0);
+        state.player.inventory.add(Item::CleanWater, 1);
+        let heat = play(&mut server, "exit", json!({}));
+        assert!(warned(&heat), "{}", heat);
+        assert!(heat.contains("The heat out here is fierce"), "{}", heat);
+        assert!(heat.contains("to drink."), "{}", heat);
+        assert!(!heat.contains("Your warmth"), "{}", heat);
+    }
+
+    /// One `GET /healthz` through the web handler, as an operator would see it.
+    fn healthz(http: &tiny_http::Server, server: &McpServer) -> (u16, Value) {
+        use std::io::Read;
+        let port = http.server_addr().to_ip().unwrap().port();
+        let mut stream = std::net::TcpStream::connect(("127.0.0.1", port)).unwrap();
+        let request = "GET /healthz HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n";
+        stream.write_all(request.as_bytes()).unwrap();
+        let rq = http.recv().unwrap();
+        let world = &server.world;
+        crate::handle_http_request(rq, &world.state_path, &server.log_path, &world.map);
+        let mut reply = String::new();
+        stream.read_to_string(&mut reply).unwrap();
+        let (head, body) = reply.split_once("\r\n\r\n").unwrap();
+        let code = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (code, serde_json::from_str(body).unwrap())
+    }
+
+    #[test]
+    fn healthz_reports_the_world_the_save_and_the_backlog_after_a_session() {
+        let mut server = at_the_door("healthz");
+        play(&mut server, "look", json!({}));
+        play(&mut server, "wait", json!({ "duration": "long" }));
+        let status = play(&mut server, "status", json!({}));
+        let day = server.world.state.time.day;
+        assert!(
+            status.contains(&format!("World day {}, last saved ", day)),
+            "{}",
+            status
+        );
+
+        let queued = ["A note on the table.", "A wind from the east."];
+        server.world.state.pending_messages = queued.map(String::from).to_vec();
+        let http = tiny_http::Server::http(("127.0.0.1", 0)).unwrap();
+        // Other servers in this run note their own worlds; note ours until
+        // it's ours the report shows
+        let mut found = None;
+        for _ in 0..100 {
+            server.world.save().unwrap();
+            exchange(&mut server, &[]);
+            let (code, report) = healthz(&http, &server);
+            let bytes = std::fs::metadata(&server.world.state_path).unwrap().len();
+            let ours = report["world"]["tick"] == server.world.state.time.tick;
+            if ours && report["save"]["save_bytes"] == bytes {
+                found = Some((code, report));
+                break;
+            }
+        }
+        let (code, report) = found.expect("the report never showed this world");
+
+        let world = &report["world"];
+        assert_eq!(world["day"], day);
+        assert_eq!(world["pending_messages"], queued.len());
+        assert!(report["uptime_secs"].is_u64());
+        assert!(report["ticker_alive"].is_null());
+
+        let save = &report["save"];
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let saved_at = save["last_saved_at"].as_u64().unwrap();
+        assert!(now - saved_at < 60, "saved {}s ago", now - saved_at);
+        // Saves failing elsewhere in this run may mark the report degraded
+        let failures = save["consecutive_failures"].as_u64().unwrap();
+        let failing = failures >= save_health::FAILURES_BEFORE_WARNING as u64;
+        assert_eq!(report["status"], if failing { "degraded" } else { "ok" });
+        assert_eq!(code, if failing { 503 } else { 200 });
+        if failures == 0 {
+            assert!(save["last_error"].is_null());
+        }
+    }
+}
@@ -1,18 +1,77 @@
-use super::protocol::ToolDefinition;
+use super::protocol::{ResourceDefinition, ToolDefinition};
 use serde_json::{json, Value};
+use std::sync::OnceLock;
+
+/// Environment variable that turns on the developer tools (`debug_tile`).
+pub const DEBUG_ENV: &str = "RUBBER_DUCK_DEBUG";
+
+/// Whether `RUBBER_DUCK_DEBUG` is set to anything but empty, 0 or false.
+pub fn debug_tools_enabled() -> bool {
+    std::env::var(DEBUG_ENV)
+        .map(|v| !matches!(v.trim(), "" | "0" | "false"))
+        .unwrap_or(false)
+}
+
+/// The session transcript, as a resource.
+pub const TRANSCRIPT_URI: &str = "duck://transcript";
+
+/// Get all available resource definitions
+pub fn get_resource_definitions() -> Vec<ResourceDefinition> {
+    vec![ResourceDefinition {
+        uri: TRANSCRIPT_URI.to_string(),
+        name: "Session transcript".to_string(),
+        description: "Every tool call this session and the reply it got, in order. Recording can be turned off with settings transcript off.".to_string(),
+        mime_type: "text/markdown".to_string(),
+    }]
+}
 
 /// Get all available tool definitions
 pub fn get_tool_definitions() -> Vec<ToolDefinition> {
+    let mut tools = game_tool_definitions();
+    if debug_tools_enabled() {
+        tools.extend(debug_tool_definitions());
+    }
+    tools
+}
+
+/// The developer tools, offered only with `RUBBER_DUCK_DEBUG` set.
+fn debug_tool_definitions() -> Vec<ToolDefinition> {
+    vec![
+        ToolDefinition {
+            name: "debug_tile".to_string(),
+            description: "Developer tool: dump everything known about one tile (biome, walkability, ground items, forage node, buried items, objects with ids, wildlife). Defaults to the player's tile.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "row": {
+                        "type": "integer",
+                        "description": "World row of the tile. Example: {\"row\": 1}"
+                    },
+                    "col": {
+                        "type": "integer",
+                        "description": "World column of the tile. Example: {\"col\": 0}"
+                    }
+                }
+            }),
+        },
+    ]
+}
+
+fn game_tool_definitions() -> Vec<ToolDefinition> {
     vec![
         ToolDefinition {
             name: "look".to_string(),
-            description: "Observe your surroundings. Without a direction, describes your current location in detail. With a direction (north/south/east/west), describes what you see in that direction.".to_string(),
+            description: "Observe your surroundings. Without a direction, describes your current location in detail. With a direction (north/south/east/west), describes what you see in that direction. With a target (an object like 'cabin', a landmark like 'lake', or a 'row,col' tile), describes that one thing from where you stand.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
+                    "target": {
+                        "type": "string",
+                        "description": "Optional thing to look at without moving: an object, a landmark, or a tile as 'row,col'. Example: {\"target\": \"lake\"}"
+                    },
                     "direction": {
                         "type": "string",
-                        "description": "Optional direction to look: north, south, east, west",
+                        "description": "Optional direction to look. Example: {\"direction\": \"north\"}",
                         "enum": ["north", "south", "east", "west", "n", "s", "e", "w"]
                     }
                 }
@@ -26,8 +85,8 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
                 "properties": {
                     "direction": {
                         "type": "string",
-                        "description": "Direction to move: north, south, east, west",
-                        "enum": ["north", "south", "east", "west", "n", "s", "e", "w"]
+                        "description": "Direction to move; up and down only matter where there's a way up or down. In the cabin's main room, hearth, table, window or door crosses the room to that spot. With the compass in hand, home takes a slow step toward the cabin door. Example: {\"direction\": \"north\"}",
+                        "enum": ["north", "south", "east", "west", "up", "down", "n", "s", "e", "w", "u", "d", "hearth", "table", "window", "door", "home"]
                     }
                 },
                 "required": ["direction"]
@@ -35,21 +94,43 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
         },
         ToolDefinition {
             name: "enter".to_string(),
-            description: "Enter a building or location, such as the cabin or wood shed.".to_string(),
+            description: "Enter a building or structure nearby, such as the cabin, wood shed, or cave mouth.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "location": {
                         "type": "string",
-                        "description": "The location to enter (e.g., 'cabin', 'shed')"
+                        "description": "The structure to enter. Example: {\"location\": \"cabin\"}"
                     }
                 },
                 "required": ["location"]
             }),
         },
+        ToolDefinition {
+            name: "board".to_string(),
+            description: "Climb aboard a vehicle nearby, such as your raft once it's in the water. While aboard, 'move' paddles across the lake.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "target": {
+                        "type": "string",
+                        "description": "What to board; defaults to the raft. Example: {\"target\": \"raft\"}",
+                        "default": "raft"
+                    }
+                }
+            }),
+        },
+        ToolDefinition {
+            name: "disembark".to_string(),
+            description: "Step off the raft onto an adjacent bank. It stays moored where you leave it.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {}
+            }),
+        },
         ToolDefinition {
             name: "exit".to_string(),
-            description: "Exit the current indoor location and return outside.".to_string(),
+            description: "Exit the current room or structure and return outside.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {}
@@ -63,7 +144,7 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
                 "properties": {
                     "target": {
                         "type": "string",
-                        "description": "What to examine (e.g., 'fireplace', 'axe', 'strange compass')"
+                        "description": "What to examine. Example: {\"target\": \"fireplace\"}"
                     }
                 },
                 "required": ["target"]
@@ -71,13 +152,13 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
         },
         ToolDefinition {
             name: "take".to_string(),
-            description: "Pick up an item and add it to your inventory.".to_string(),
+            description: "Pick up an item and add it to your inventory. 'all' takes everything here at once, or everything of one item or category (fuel, food, materials, tools).".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "item": {
                         "type": "string",
-                        "description": "The item to pick up"
+                        "description": "The item to pick up, optionally with a count, or 'all' / 'all <item or category>'. Add 'from <object>' to unload something like the raft, or 'off the fire' to pull what's grilling. Example: {\"item\": \"3 stones\"}, {\"item\": \"all firewood\"}, {\"item\": \"2 logs from raft\"}"
                     }
                 },
                 "required": ["item"]
@@ -85,13 +166,27 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
         },
         ToolDefinition {
             name: "drop".to_string(),
-            description: "Drop an item from your inventory.".to_string(),
+            description: "Drop an item from your inventory. 'all' sets down everything, or everything of one item or category, in one go.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "item": {
+                        "type": "string",
+                        "description": "The item to drop, or 'all' / 'all <item or category>'. Add 'in <object>' to stow it in something like the raft. Example: {\"item\": \"log\"}, {\"item\": \"all\"}, {\"item\": \"all logs in raft\"}"
+                    }
+                },
+                "required": ["item"]
+            }),
+        },
+        ToolDefinition {
+            name: "store".to_string(),
+            description: "Unload your pack into the cabin or the wood shed in one go: everything, one item, or a category (fuel, food, materials, tools).".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "item": {
                         "type": "string",
-                        "description": "The item to drop"
+                        "description": "What to store: 'all', 'all <category>' or 'all <item>'. Example: {\"item\": \"all fuel\"}"
                     }
                 },
                 "required": ["item"]
@@ -99,17 +194,17 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
         },
         ToolDefinition {
             name: "use".to_string(),
-            description: "Use an item/tool on a target. Examples: use axe on tree (chop), use knife on branch (whittle), use log on blueprint (build).".to_string(),
+            description: "Use an item/tool on a target. Examples: use axe on tree (chop), use knife on branch (whittle), use log on blueprint (build), use materials on blueprint (fit the next material needed).".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "item": {
                         "type": "string",
-                        "description": "The item/tool to use (Subject)"
+                        "description": "The item or tool to use. Example: {\"item\": \"axe\", \"target\": \"tree\"}"
                     },
                     "target": {
                         "type": "string",
-                        "description": "The target to use it on (Object). E.g. 'tree', 'blueprint', 'rock'"
+                        "description": "What to use it on; leave out to use the item by itself. Example: {\"target\": \"blueprint\"}"
                     }
                 },
                 "required": ["item"]
@@ -123,7 +218,7 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
                 "properties": {
                     "item": {
                         "type": "string",
-                        "description": "The item you want to craft"
+                        "description": "The item to plan a blueprint for. Example: {\"item\": \"campfire\"}"
                     }
                 },
                 "required": ["item"]
@@ -131,17 +226,17 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
         },
         ToolDefinition {
             name: "write".to_string(),
-            description: "Write a title or page in a book. Examples: write 제목:My Journal on 빈 책, write 페이지1:Hello on book-3.".to_string(),
+            description: "Write a title or page in a book; title:/제목: and pageN:/페이지N: mean the same. Examples: write title:My Journal on blank book, write 제목:일기 on 빈 책, write page1:Hello on book-3, write 페이지1:안녕 on 일기.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "text": {
                         "type": "string",
-                        "description": "Text to write, starting with 제목: or 페이지<number>:"
+                        "description": "Text to write, starting with 제목: or 페이지<number>:. Example: {\"text\": \"페이지1:Hello\", \"target\": \"book-3\"}"
                     },
                     "target": {
                         "type": "string",
-                        "description": "Target book or blank book (e.g., '빈 책' or 'book-3')"
+                        "description": "The book to write in: 'blank book'/'빈 책' for a title, otherwise a book id or title. Example: {\"target\": \"blank book\"}"
                     }
                 },
                 "required": ["text", "target"]
@@ -149,13 +244,13 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
         },
         ToolDefinition {
             name: "open".to_string(),
-            description: "Open a door or container.".to_string(),
+            description: "Open a door, window, shutters, or container.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "target": {
                         "type": "string",
-                        "description": "What to open (e.g., 'door', 'cabin door')"
+                        "description": "What to open. Example: {\"target\": \"shed door\"}"
                     }
                 },
                 "required": ["target"]
@@ -163,13 +258,13 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
         },
         ToolDefinition {
             name: "close".to_string(),
-            description: "Close a door or container.".to_string(),
+            description: "Close a door, window, shutters, or container.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "target": {
                         "type": "string",
-                        "description": "What to close"
+                        "description": "What to close. Example: {\"target\": \"shutters\"}"
                     }
                 },
                 "required": ["target"]
@@ -185,20 +280,28 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
         },
         ToolDefinition {
             name: "name".to_string(),
-            description: "Give an item a custom name. Example: name rubber duck as 'James'.".to_string(),
+            description: "Give an item a custom name. Example: name rubber duck as 'James'. Regions can be renamed too (e.g. 'east' or 'Glass Desert'), and books you can write in can be retitled by id or title. Name 'self' to give yourself a name (and optionally pronouns); name yourself 'none' to go back to plain 'you'. Pass clear: true instead of a name to remove one. A name already used by another item, a book or an item's own name is refused.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "item": {
                         "type": "string",
-                        "description": "The item to name"
+                        "description": "The item, companion, book or region to name, or 'self'. Example: {\"item\": \"rubber duck\", \"name\": \"James\"}"
                     },
                     "name": {
                         "type": "string",
-                        "description": "The new name to give the item"
+                        "description": "The new name; 'none' when naming yourself clears it. Example: {\"name\": \"James\"}"
+                    },
+                    "pronouns": {
+                        "type": "string",
+                        "description": "Optional, only when naming yourself. Example: {\"item\": \"self\", \"name\": \"Sam\", \"pronouns\": \"they/them\"}"
+                    },
+                    "clear": {
+                        "type": "boolean",
+                        "description": "Remove the current name instead of giving a new one. Example: {\"item\": \"James\", \"clear\": true}"
                     }
                 },
-                "required": ["item", "name"]
+                "required": ["item"]
             }),
         },
         ToolDefinition {
@@ -219,23 +322,56 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
         },
         ToolDefinition {
             name: "talk".to_string(),
-            description: "Talk to the rubber duck for silent wisdom.".to_string(),
+            description: "Talk to the rubber duck for silent wisdom, or to a companion nearby. With neither in earshot you talk to yourself, or to the fire, and the words go in your journal.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "message": {
                         "type": "string",
-                        "description": "What you say to the rubber duck"
+                        "description": "What you say aloud; end with '!' to shout. Example: {\"message\": \"I'm stuck on a bug\"}"
                     }
                 }
             }),
         },
+        ToolDefinition {
+            name: "eat".to_string(),
+            description: "Eat something you're carrying, such as an apple, berries, or cooked fish.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "item": {
+                        "type": "string",
+                        "description": "What to eat, optionally with a count. Example: {\"item\": \"3 berries\"}"
+                    },
+                    "quantity": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 20,
+                        "default": 1,
+                        "description": "How many to eat. Example: {\"item\": \"apple\", \"quantity\": 2}"
+                    }
+                },
+                "required": ["item"]
+            }),
+        },
         ToolDefinition {
             name: "drink".to_string(),
-            description: "Drink from the lake or available water to restore hydration.".to_string(),
+            description: "Drink to restore hydration. Name an item (clean water, herbal tea) to drink it; with no item, you drink carried clean water first, otherwise from the lake or an oasis beside you.".to_string(),
             input_schema: json!({
                 "type": "object",
-                "properties": {}
+                "properties": {
+                    "item": {
+                        "type": "string",
+                        "description": "Optional drink you're carrying. Example: {\"item\": \"herbal tea\"}"
+                    },
+                    "quantity": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 20,
+                        "default": 1,
+                        "description": "How many to drink. Example: {\"item\": \"clean water\", \"quantity\": 2}"
+                    }
+                }
             }),
         },
         ToolDefinition {
@@ -246,11 +382,207 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
                 "properties": {
                     "gear": {
                         "type": "string",
-                        "description": "Optional: 'hands' or 'rod'. Defaults to what you have."
+                        "enum": ["hands", "rod"],
+                        "description": "Optional; defaults to the best gear you carry. Example: {\"gear\": \"rod\"}"
+                    }
+                }
+            }),
+        },
+        ToolDefinition {
+            name: "tend_fire".to_string(),
+            description: "Tend the cabin fire. Stoke it for a burst of heat, bank it for a slow overnight burn, or extinguish it with water to salvage charcoal. Out in the open, 'extinguish' beats out or douses burning ground on or next to your tile.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "mode": {
+                        "type": "string",
+                        "description": "How to tend the fire. Example: {\"mode\": \"bank\"}",
+                        "enum": ["stoke", "bank", "extinguish"]
+                    }
+                },
+                "required": ["mode"]
+            }),
+        },
+        ToolDefinition {
+            name: "mount".to_string(),
+            description: "Perch a small item, like the rubber duck, somewhere it can ride along or keep watch: your shoulder, the raft's prow, the cabin table, or the mantelpiece. Use 'take' to lift it down again.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "item": {
+                        "type": "string",
+                        "description": "The item to perch. Example: {\"item\": \"rubber duck\", \"target\": \"shoulder\"}"
+                    },
+                    "target": {
+                        "type": "string",
+                        "enum": ["shoulder", "raft", "table", "mantel"],
+                        "description": "Where to perch it. Example: {\"target\": \"mantel\"}"
+                    }
+                },
+                "required": ["item", "target"]
+            }),
+        },
+        ToolDefinition {
+            name: "hold".to_string(),
+            description: "Take something you carry in hand. You have two hands; taking up a third thing stows whatever was in your other hand. Tools and lights you use come to hand on their own.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "item": {
+                        "type": "string",
+                        "description": "The item to hold. Example: {\"item\": \"firefly jar\"}"
+                    }
+                },
+                "required": ["item"]
+            }),
+        },
+        ToolDefinition {
+            name: "stow".to_string(),
+            description: "Put something you're holding back in your pack, or 'all' to empty both hands.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "item": {
+                        "type": "string",
+                        "description": "The held item to put away, or 'all'. Example: {\"item\": \"axe\"}",
+                        "default": "all"
+                    }
+                }
+            }),
+        },
+        ToolDefinition {
+            name: "throw".to_string(),
+            description: "Throw something. Stones thrown at nearby wildlife may hit or scare it off, food tossed to a companion feeds it, and anything else lands a pace or two away in the direction you choose.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "item": {
+                        "type": "string",
+                        "description": "The item to throw. Example: {\"item\": \"stone\", \"target\": \"hare\"}"
+                    },
+                    "target": {
+                        "type": "string",
+                        "description": "Optional animal within a few tiles to aim at. Example: {\"target\": \"dog\"}"
+                    },
+                    "direction": {
+                        "type": "string",
+                        "enum": ["north", "south", "east", "west"],
+                        "description": "Which way to throw when not aiming at something. Example: {\"direction\": \"east\"}"
+                    },
+                    "accept_loss": {
+                        "type": "boolean",
+                        "default": false,
+                        "description": "Throw even if the item would land in the lake and be lost. Example: {\"accept_loss\": true}"
+                    }
+                },
+                "required": ["item"]
+            }),
+        },
+        ToolDefinition {
+            name: "search".to_string(),
+            description: "Search the spot you're standing on carefully for anything easy to miss. Sharp-eyed players notice such things without searching; examine what you find to take a closer look.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {}
+            }),
+        },
+        ToolDefinition {
+            name: "catch".to_string(),
+            description: "Catch small life that is out right now: fireflies on warm evenings (into a clay jar, if you carry one) or a frog in the reeds. Use the firefly jar later to let them go.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "target": {
+                        "type": "string",
+                        "description": "What to catch. Example: {\"target\": \"fireflies\"}"
+                    }
+                },
+                "required": ["target"]
+            }),
+        },
+        ToolDefinition {
+            name: "approach".to_string(),
+            description: "Creep up on an animal you can see, one careful step at a time. Observation and survival help; wary animals, ones you've startled or hunted, are harder. Get close enough and you can study it and sketch it in your journal.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "target": {
+                        "type": "string",
+                        "description": "The animal to approach. Example: {\"target\": \"rabbit\"}"
+                    }
+                },
+                "required": ["target"]
+            }),
+        },
+        ToolDefinition {
+            name: "rescue".to_string(),
+            description: "Answer the people who saw your signal fire: leave with them (ends this run, needs confirm) or stay on.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "choice": {
+                        "type": "string",
+                        "enum": ["leave", "stay"],
+                        "description": "leave goes home and ends the run; stay sends them off. Example: {\"choice\": \"leave\", \"confirm\": true}"
+                    },
+                    "confirm": {
+                        "type": "boolean",
+                        "default": false,
+                        "description": "Must be true to leave. Example: {\"confirm\": true}"
+                    }
+                },
+                "required": ["choice"]
+            }),
+        },
+        ToolDefinition {
+            name: "lakeside".to_string(),
+            description: "Small restful things to do at the water's edge: skip a stone, dip your feet on a warm day, watch the ripples, or wash off sandstorm grit. Repeating one too often in a day gives less back.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "action": {
+                        "type": "string",
+                        "enum": ["skip_stone", "dip_feet", "watch_ripples", "wash"],
+                        "description": "What to do; leave out to list the options. Example: {\"action\": \"skip_stone\"}"
+                    }
+                }
+            }),
+        },
+        ToolDefinition {
+            name: "help".to_string(),
+            description: "Read a short guide on a topic, such as 'lakeside' or 'food'.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "topic": {
+                        "type": "string",
+                        "enum": ["lakeside", "food", "use"],
+                        "description": "The topic to read about. Example: {\"topic\": \"use\"}"
+                    }
+                }
+            }),
+        },
+        ToolDefinition {
+            name: "sit".to_string(),
+            description: "Sit on nearby furniture, such as the armchair by the fire, the terrace bench, or a stool you've set down. You get up again when you next move.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "target": {
+                        "type": "string",
+                        "description": "Optional seat; defaults to the nearest one. Example: {\"target\": \"armchair\"}"
                     }
                 }
             }),
         },
+        ToolDefinition {
+            name: "tidy".to_string(),
+            description: "Tidy the cabin's main room: sweep, dust, and stack loose items. Takes a little time and energy, and a well-kept home lifts your mood.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {}
+            }),
+        },
         ToolDefinition {
             name: "sleep".to_string(),
             description: "Sleep for a while. Restores energy and, when well-fed and hydrated, heals you.".to_string(),
@@ -267,7 +599,7 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
                 "properties": {
                     "duration": {
                         "type": "string",
-                        "description": "How long to wait: short (10 min), medium (30 min), or long (1 hour)",
+                        "description": "short (10 min), medium (30 min), or long (1 hour). Example: {\"duration\": \"long\"}",
                         "enum": ["short", "medium", "long"],
                         "default": "short"
                     }
@@ -276,29 +608,34 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
         },
         ToolDefinition {
             name: "kick".to_string(),
-            description: "Kick a nearby tree to try and shake loose any fruit.".to_string(),
+            description: "Kick the tree you're standing at to try and shake loose any fruit, or a door you're beside.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "target": {
                         "type": "string",
-                        "description": "Optional target to kick (e.g., 'tree')"
+                        "description": "What to kick; the tree underfoot if left out. Example: {\"target\": \"shed door\"}"
                     }
                 }
             }),
         },
         ToolDefinition {
             name: "simulate".to_string(),
-            description: "Advance the world simulation by a number of ticks. Each tick is about 10 minutes of game time.".to_string(),
+            description: "Advance the world simulation by a number of ticks. Each tick is 10 minutes of game time unless changed in settings. Or name a scenario to run a whole stretch and get a recap of it: overnight (on to dawn, keeping the night watch), quiet week (seven days with fewer chance events), or storm (a front held over your region; developer tools only).".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "ticks": {
                         "type": "integer",
-                        "description": "Number of simulation ticks to advance (1-10)",
+                        "description": "Number of simulation ticks to advance. Example: {\"ticks\": 3}",
                         "minimum": 1,
                         "maximum": 10,
                         "default": 1
+                    },
+                    "scenario": {
+                        "type": "string",
+                        "enum": ["overnight", "storm", "quiet week"],
+                        "description": "A named stretch to run instead of a tick count. Example: {\"scenario\": \"overnight\"}"
                     }
                 }
             }),
@@ -311,17 +648,225 @@ pub fn get_tool_definitions() -> Vec<ToolDefinition> {
                 "properties": {}
             }),
         },
+        ToolDefinition {
+            name: "settings".to_string(),
+            description: "View or change game settings. auto_pickup controls whether things that fall at your feet (kicked fruit, scattered cards) go straight into your pack; minutes_per_tick sets how finely time advances; language picks English or Korean for instructions; transcript turns session recording on or off.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "auto_pickup": {
+                        "type": "string",
+                        "enum": ["off", "light", "all"],
+                        "description": "off, light (small items only), or all (anything that fits). Example: {\"auto_pickup\": \"light\"}"
+                    },
+                    "minutes_per_tick": {
+                        "type": "integer",
+                        "enum": [5, 10, 20],
+                        "description": "Game minutes per simulation tick; actions take the same game time either way. Example: {\"minutes_per_tick\": 5}"
+                    },
+                    "goal": {
+                        "type": "string",
+                        "enum": ["none", "rescue"],
+                        "description": "An optional long goal; rescue means signal fires on three clear days. Example: {\"goal\": \"rescue\"}"
+                    },
+                    "language": {
+                        "type": "string",
+                        "enum": ["en", "ko"],
+                        "description": "Language for instructions and syntax hints (en or ko); commands are accepted in both. Example: {\"language\": \"ko\"}"
+                    },
+                    "transcript": {
+                        "type": "string",
+                        "enum": ["on", "off"],
+                        "description": "Whether each call and its reply are kept in the session transcript beside your save. Example: {\"transcript\": \"off\"}"
+                    }
+                }
+            }),
+        },
+        ToolDefinition {
+            name: "saves".to_string(),
+            description: "List the milestone snapshots kept beside your save (each dawn, the cave's doorstep, every finished blueprint), or roll the world back to one. Restoring first snapshots where you are now, so it can be undone.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "action": {
+                        "type": "string",
+                        "enum": ["list", "restore-snapshot"],
+                        "description": "list the snapshots, or restore-snapshot to load one. Example: {\"action\": \"restore-snapshot\", \"name\": \"day-3\"}",
+                        "default": "list"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "The snapshot to restore, as shown by list. Example: {\"name\": \"cave-day-4\"}"
+                    }
+                }
+            }),
+        },
+        ToolDefinition {
+            name: "body".to_string(),
+            description: "Check your body part by part: injuries, bleeding, frostnip, bandages and splints, and how each one is slowing you down.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {}
+            }),
+        },
+        ToolDefinition {
+            name: "forecast".to_string(),
+            description: "Read the sky for what the weather will do next. A weather vane mounted on the cabin gives a much more detailed reading.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {}
+            }),
+        },
         ToolDefinition {
             name: "skills".to_string(),
-            description: "View your current skill levels.".to_string(),
+            description: "View your current skill levels, what each gained today, and what trains it.".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {}
             }),
         },
+        ToolDefinition {
+            name: "projects".to_string(),
+            description: "See the build you're working on and up to three set aside, switch between them, or abandon one. Set-aside builds keep the materials already fitted, and those laid out on site are picked up again only there.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "action": {
+                        "type": "string",
+                        "enum": ["list", "switch", "resume", "pause", "abandon"],
+                        "description": "list them; switch to or resume a set-aside one; pause the current one; or abandon one, taking its materials back. Example: {\"action\": \"switch\", \"project\": \"raft\"}",
+                        "default": "list"
+                    },
+                    "project": {
+                        "type": "string",
+                        "description": "A set-aside project, by number or by what it builds. Resume without one picks up the last set aside; abandon without one drops the current build. Example: {\"project\": \"#2\"}"
+                    }
+                }
+            }),
+        },
+        ToolDefinition {
+            name: "plan".to_string(),
+            description: "Outline the next steps toward a goal from where things stand, flagging what blocks each one. Only looks; takes no time.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "goal": {
+                        "type": "string",
+                        "description": "What you want to do: \"warm night\", \"cook dinner\", \"build raft\", \"explore east\""
+                    }
+                },
+                "required": ["goal"]
+            }),
+        },
     ]
 }
 
+/// The definitions built once, for argument checks on every call.
+fn cached_definitions() -> &'static [ToolDefinition] {
+    static DEFINITIONS: OnceLock<Vec<ToolDefinition>> = OnceLock::new();
+    DEFINITIONS.get_or_init(get_tool_definitions)
+}
+
+/// Check a call's arguments against the tool's own schema: required
+/// fields, declared names only, types, enums and integer bounds. Unknown
+/// tools pass through so dispatch can report them.
+pub fn validate_args(tool: &str, args: &Option<Value>) -> Result<(), String> {
+    let Some(def) = cached_definitions().iter().find(|d| d.name == tool) else {
+        return Ok(());
+    };
+    let schema = &def.input_schema;
+    let empty = serde_json::Map::new();
+    let given = match args {
+        None | Some(Value::Null) => &empty,
+        Some(Value::Object(map)) => map,
+        Some(_) => return Err(format!("Arguments to '{}' must be an object.", tool)),
+    };
+    let props = schema["properties"].as_object().unwrap_or(&empty);
+
+    if let Some(required) = schema["required"].as_array() {
+        for key in required.iter().filter_map(|k| k.as_str()) {
+            if !given.contains_key(key) {
+                return Err(format!("'{}' needs a '{}' argument.", tool, key));
+            }
+        }
+    }
+
+    for (key, value) in given {
+        let Some(prop) = props.get(key) else {
+            let mut known: Vec<&str> = props.keys().map(|k| k.as_str()).collect();
+            known.sort();
+            return Err(if known.is_empty() {
+                format!("'{}' takes no arguments (got '{}').", tool, key)
+            } else {
+                format!(
+                    "'{}' has no '{}' argument. It takes: {}.",
+                    tool,
+                    key,
+                    known.join(", ")
+                )
+            });
+        };
+        let kind = prop["type"].as_str().unwrap_or("string");
+        let type_ok = match kind {
+            "string" => value.is_string(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "boolean" => value.is_boolean(),
+            _ => true,
+        };
+        if !type_ok {
+            let article = if kind == "integer" { "an" } else { "a" };
+            return Err(format!(
+                "'{}' for '{}' should be {} {}.",
+                key, tool, article, kind
+            ));
+        }
+        if let Some(options) = prop["enum"].as_array() {
+            let matches = match value.as_str() {
+                Some(text) => options
+                    .iter()
+                    .any(|o| o.as_str().map(|o| o.eq_ignore_ascii_case(text.trim())) == Some(true)),
+                None => options.contains(value),
+            };
+            if !matches {
+                let listed: Vec<String> = options
+                    .iter()
+                    .map(|o| {
+                        o.as_str()
+                            .map(str::to_string)
+                            .unwrap_or_else(|| o.to_string())
+                    })
+                    .collect();
+                return Err(format!(
+                    "'{}' isn't a valid {} for '{}'. Use one of: {}.",
+                    value
+                        .as_str()
+                        .map(str::to_string)
+                        .unwrap_or_else(|| value.to_string()),
+                    key,
+                    tool,
+                    listed.join(", ")
+                ));
+            }
+        }
+        if let Some(n) = value.as_i64() {
+            let min = prop["minimum"].as_i64();
+            let max = prop["maximum"].as_i64();
+            if min.map(|m| n < m).unwrap_or(false) || max.map(|m| n > m).unwrap_or(false) {
+                return Err(format!(
+                    "'{}' for '{}' must be between {} and {}.",
+                    key,
+                    tool,
+                    min.map(|m| m.to_string())
+                        .unwrap_or_else(|| "any".to_string()),
+                    max.map(|m| m.to_string())
+                        .unwrap_or_else(|| "any".to_string())
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Parse tool arguments helper
 pub fn get_string_arg(args: &Option<Value>, key: &str) -> Option<String> {
     args.as_ref()
@@ -330,9 +875,83 @@ pub fn get_string_arg(args: &Option<Value>, key: &str) -> Option<String> {
         .map(|s| s.to_string())
 }
 
+pub fn get_bool_arg(args: &Option<Value>, key: &str) -> bool {
+    args.as_ref()
+        .and_then(|v| v.get(key))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
 pub fn get_int_arg(args: &Option<Value>, key: &str, default: i64) -> i64 {
     args.as_ref()
         .and_then(|v| v.get(key))
         .and_then(|v| v.as_i64())
         .unwrap_or(default)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The tool names dispatched to each `cmd_*` handler in the server, and
+    /// the argument names that handler reads.
+    fn arguments_read() -> Vec<(Vec<String>, Vec<String>)> {
+        let source = include_str!("server.rs");
+        let mut handlers = Vec::new();
+        for line in source.lines() {
+            let Some((names, call)) = line.trim().split_once("=> self.cmd_") else {
+                continue;
+            };
+            let names = names.split(" if ").next().unwrap_or(names);
+            let names = names
+                .split('|')
+                .map(|n| n.trim().trim_matches('"').to_string())
+                .collect();
+            let handler = call.split('(').next().unwrap_or(call);
+            let start = source
+                .find(&format!("fn cmd_{}(", handler))
+                .unwrap_or_else(|| panic!("no handler cmd_{}", handler));
+            let body = &source[start..];
+            let end = body[1..]
+                .find("\n    fn ")
+                .map_or(body.len(), |end| end + 1);
+            let body = &body[..end];
+            let keys = body
+                .split("_arg(args, \"")
+                .skip(1)
+                .filter_map(|rest| rest.split('"').next())
+                .map(str::to_string)
+                .collect();
+            handlers.push((names, keys));
+        }
+        handlers
+    }
+
+    #[test]
+    fn every_argument_the_server_reads_is_in_the_tool_s_schema() {
+        let mut definitions = game_tool_definitions();
+        definitions.extend(debug_tool_definitions());
+        let handlers = arguments_read();
+        for (names, keys) in &handlers {
+            let defined: Vec<&ToolDefinition> = definitions
+                .iter()
+                .filter(|d| names.contains(&d.name))
+                .collect();
+            assert!(!defined.is_empty(), "{:?} has no schema", names);
+            for def in defined {
+                for key in keys {
+                    assert!(
+                        def.input_schema["properties"].get(key).is_some(),
+                        "'{}' reads '{}' but its schema doesn't declare it",
+                        def.name,
+                        key
+                    );
+                }
+            }
+        }
+        // Every read in the server belongs to some tool's handler
+        let reads = include_str!("server.rs").matches("_arg(args, \"").count();
+        let attributed: usize = handlers.iter().map(|(_, keys)| keys.len()).sum();
+        assert_eq!(attributed, reads);
+    }
+}
@@ -0,0 +1,225 @@
+//! A tiny in-process metrics registry for server operators: per-tool call
+//! counts, failures and time spent, plus a histogram of world tick times.
+//! Shared with the web thread, which renders it for `GET /metrics`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+#[cfg(not(test))]
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+/// Upper bounds, in seconds, of the tick duration histogram buckets.
+const TICK_BUCKETS: [f64; 7] = [0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.1];
+
+#[derive(Debug, Default, Clone)]
+struct ToolStats {
+    calls: u64,
+    failures: u64,
+    seconds: f64,
+}
+
+#[derive(Debug, Default)]
+struct Metrics {
+    tools: BTreeMap<String, ToolStats>,
+    /// Cumulative counts per bucket in `TICK_BUCKETS`.
+    tick_buckets: [u64; TICK_BUCKETS.len()],
+    tick_count: u64,
+    tick_seconds: f64,
+}
+
+/// Escape a label value for the exposition format.
+fn label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(not(test))]
+fn registry() -> &'static Mutex<Metrics> {
+    static REGISTRY: OnceLock<Mutex<Metrics>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(Metrics::default()))
+}
+
+#[cfg(not(test))]
+fn with_registry<T>(f: impl FnOnce(&mut Metrics) -> T) -> T {
+    let mut guard = registry().lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut guard)
+}
+
+/// Tests run side by side; each keeps its own counts so they can be exact.
+#[cfg(test)]
+fn with_registry<T>(f: impl FnOnce(&mut Metrics) -> T) -> T {
+    thread_local! {
+        static LOCAL: std::cell::RefCell<Metrics> = std::cell::RefCell::new(Metrics::default());
+    }
+    LOCAL.with(|m| f(&mut m.borrow_mut()))
+}
+
+/// Count one tool call and how long it took.
+pub fn record_tool(name: &str, failed: bool, elapsed: Duration) {
+    with_registry(|m| {
+        let stats = m.tools.entry(name.to_string()).or_default();
+        stats.calls += 1;
+        if failed {
+            stats.failures += 1;
+        }
+        stats.seconds += elapsed.as_secs_f64();
+    });
+}
+
+/// Count one world tick into the duration histogram.
+pub fn record_tick(elapsed: Duration) {
+    let secs = elapsed.as_secs_f64();
+    with_registry(|m| {
+        for (bound, count) in TICK_BUCKETS.iter().zip(m.tick_buckets.iter_mut()) {
+            if secs <= *bound {
+                *count += 1;
+            }
+        }
+        m.tick_count += 1;
+        m.tick_seconds += secs;
+    });
+}
+
+/// Everything in the Prometheus text exposition format.
+pub fn render_prometheus() -> String {
+    with_registry(|m| {
+        let mut out = String::new();
+        out.push_str("# HELP rubber_duck_tool_calls_total Tool calls handled, by tool.\n");
+        out.push_str("# TYPE rubber_duck_tool_calls_total counter\n");
+        for (name, s) in &m.tools {
+            let _ = writeln!(
+                out,
+                "rubber_duck_tool_calls_total{{tool=\"{}\"}} {}",
+                label(name),
+                s.calls
+            );
+        }
+        out.push_str("# HELP rubber_duck_tool_failures_total Tool calls that returned an error.\n");
+        out.push_str("# TYPE rubber_duck_tool_failures_total counter\n");
+        for (name, s) in &m.tools {
+            let _ = writeln!(
+                out,
+                "rubber_duck_tool_failures_total{{tool=\"{}\"}} {}",
+                label(name),
+                s.failures
+            );
+        }
+        out.push_str("# HELP rubber_duck_tool_seconds_total Time spent handling tool calls.\n");
+        out.push_str("# TYPE rubber_duck_tool_seconds_total counter\n");
+        for (name, s) in &m.tools {
+            let _ = writeln!(
+                out,
+                "rubber_duck_tool_seconds_total{{tool=\"{}\"}} {:.6}",
+                label(name),
+                s.seconds
+            );
+        }
+        out.push_str("# HELP rubber_duck_tick_seconds Time taken by one world tick.\n");
+        out.push_str("# TYPE rubber_duck_tick_seconds histogram\n");
+        for (bound, count) in TICK_BUCKETS.iter().zip(m.tick_buckets.iter()) {
+            let _ = writeln!(
+                out,
+                "rubber_duck_tick_seconds_bucket{{le=\"{}\"}} {}",
+                bound, count
+            );
+        }
+        let _ = writeln!(
+            out,
+            "rubber_duck_tick_seconds_bucket{{le=\"+Inf\"}} {}",
+            m.tick_count
+        );
+        let _ = writeln!(out, "rubber_duck_tick_seconds_sum {:.6}", m.tick_seconds);
+        let _ = writeln!(out, "rubber_duck_tick_seconds_count {}", m.tick_count);
+        out
+    })
+}
+
+/// One line for the shutdown log.
+pub fn summary_line() -> String {
+    with_registry(|m| {
+        let calls: u64 = m.tools.values().map(|s| s.calls).sum();
+        let failures: u64 = m.tools.values().map(|s| s.failures).sum();
+        let busiest = m
+            .tools
+            .iter()
+            .max_by_key(|(_, s)| s.calls)
+            .map(|(name, s)| format!(", busiest {} ({})", name, s.calls))
+            .unwrap_or_default();
+        let mean_tick_ms = if m.tick_count > 0 {
+            m.tick_seconds * 1000.0 / m.tick_count as f64
+        } else {
+            0.0
+        };
+        format!(
+            "metrics: {} tool calls, {} failed{}; {} ticks, mean {:.3} ms",
+            calls, failures, busiest, m.tick_count, mean_tick_ms
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check `text` against the exposition format: every sample belongs to
+    /// a family announced by HELP and TYPE lines, has well-formed labels and
+    /// a number for its value.
+    fn assert_well_formed(text: &str) {
+        let mut families: Vec<(String, String)> = Vec::new();
+        for line in text.lines() {
+            if let Some(help) = line.strip_prefix("# HELP ") {
+                let (name, doc) = help.split_once(' ').expect(line);
+                assert!(!doc.is_empty(), "{}", line);
+                families.push((name.to_string(), String::new()));
+            } else if let Some(kind) = line.strip_prefix("# TYPE ") {
+                let (name, kind) = kind.split_once(' ').expect(line);
+                let family = families.last_mut().expect(line);
+                assert_eq!(family.0, name, "TYPE without HELP: {}", line);
+                assert!(
+                    matches!(kind, "counter" | "gauge" | "histogram"),
+                    "{}",
+                    line
+                );
+                family.1 = kind.to_string();
+            } else {
+                let (series, value) = line.rsplit_once(' ').expect(line);
+                assert!(value.parse::<f64>().is_ok(), "{}", line);
+                let (name, labels) = match series.split_once('{') {
+                    Some((name, rest)) => (name, rest.strip_suffix('}').expect(line)),
+                    None => (series, ""),
+                };
+                for pair in labels.split(',').filter(|p| !p.is_empty()) {
+                    let (key, quoted) = pair.split_once('=').expect(line);
+                    assert!(key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+                    assert!(quoted.len() >= 2 && quoted.starts_with('"') && quoted.ends_with('"'));
+                }
+                let (family, kind) = families.last().expect(line);
+                let belongs = match kind.as_str() {
+                    "histogram" => ["_bucket", "_sum", "_count"]
+                        .iter()
+                        .any(|suffix| name == format!("{}{}", family, suffix)),
+                    _ => name == family,
+                };
+                assert!(belongs, "{} outside its family {}", line, family);
+            }
+        }
+    }
+
+    #[test]
+    fn the_metrics_render_in_the_exposition_format() {
+        record_tool("look", false, Duration::from_millis(3));
+        record_tool("look", false, Duration::from_millis(2));
+        record_tool("odd \"name\"\n", true, Duration::from_millis(1));
+        record_tick(Duration::from_micros(300));
+        record_tick(Duration::from_secs(1));
+
+        let text = render_prometheus();
+        assert_well_formed(&text);
+        assert!(text.contains("rubber_duck_tool_calls_total{tool=\"look\"} 2\n"));
+        assert!(text.contains("rubber_duck_tick_seconds_bucket{le=\"0.0005\"} 1\n"));
+        assert!(text.contains("rubber_duck_tick_seconds_bucket{le=\"+Inf\"} 2\n"));
+        assert!(text.contains("rubber_duck_tick_seconds_count 2\n"));
+    }
+}
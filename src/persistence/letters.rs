@@ -0,0 +1,281 @@
+//! The duck's weekly letters: a tally of the week kept as it happens, and
+//! the letter written from it at the dawn a week is out. Lines are picked
+//! from templates by what the week holds, never by chance, so the same week
+//! always reads the same.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use super::PracticeTally;
+
+/// Days a letter covers.
+pub const LETTER_DAYS: u32 = 7;
+/// Mood the first read of a letter gives.
+pub const LETTER_MOOD_BONUS: f32 = 6.0;
+/// Mood swings smaller than this aren't worth a line in a letter.
+const MOMENT_THRESHOLD: f32 = 1.0;
+
+/// A single moment that moved the player's mood.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Moment {
+    pub day: u32,
+    pub mood: f32,
+    /// The first sentence of what the player was told.
+    pub what: String,
+}
+
+/// What has happened since the week began.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WeekLog {
+    /// The day the week began; 0 until the first dawn in an older save.
+    #[serde(default)]
+    pub start_day: u32,
+    /// The practice tally as it stood when the week began.
+    #[serde(default)]
+    pub practice_at_start: PracticeTally,
+    #[serde(default)]
+    pub meals: u32,
+    #[serde(default)]
+    pub storms: u32,
+    /// Whether a storm was blowing over the player at the last look.
+    #[serde(default)]
+    pub in_storm: bool,
+    #[serde(default)]
+    pub best: Option<Moment>,
+    #[serde(default)]
+    pub worst: Option<Moment>,
+}
+
+impl WeekLog {
+    pub fn begin(day: u32, practice: &PracticeTally) -> Self {
+        Self {
+            start_day: day,
+            practice_at_start: practice.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Count a storm once, as it arrives over the player.
+    pub fn note_weather(&mut self, stormy: bool) {
+        if stormy && !self.in_storm {
+            self.storms += 1;
+        }
+        self.in_storm = stormy;
+    }
+
+    /// Keep the reply if it moved the mood more than anything else this week.
+    pub fn note_moment(&mut self, day: u32, mood: f32, reply: &str) {
+        if mood.abs() < MOMENT_THRESHOLD {
+            return;
+        }
+        let what = first_sentence(reply);
+        if what.is_empty() {
+            return;
+        }
+        let moment = Moment { day, mood, what };
+        if mood > 0.0 {
+            if self.best.as_ref().is_none_or(|b| mood > b.mood) {
+                self.best = Some(moment);
+            }
+        } else if self.worst.as_ref().is_none_or(|w| mood < w.mood) {
+            self.worst = Some(moment);
+        }
+    }
+}
+
+/// The letters so far and the week being written up next.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DuckLetters {
+    #[serde(default)]
+    pub week: WeekLog,
+    /// Book ids of the letters written, oldest first.
+    #[serde(default)]
+    pub written: Vec<String>,
+    /// Letters already read, which have given their lift.
+    #[serde(default)]
+    pub read: HashSet<String>,
+}
+
+fn first_sentence(text: &str) -> String {
+    let line = text.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+    let line = line.trim().trim_matches('*');
+    let end = line
+        .find(". ")
+        .or_else(|| line.find("! "))
+        .map(|i| i + 1)
+        .unwrap_or(line.len());
+    line[..end].trim().to_string()
+}
+
+fn plural(n: u32) -> String {
+    if n == 1 { "" } else { "s" }.to_string()
+}
+
+/// Fill `{field}` slots from `fields`. A template naming a field that isn't
+/// there yields nothing, which is how a line leaves itself out.
+fn fill(template: &str, fields: &HashMap<&str, String>) -> Option<String> {
+    let mut out = String::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let close = open + rest[open..].find('}')?;
+        out.push_str(fields.get(&rest[open + 1..close])?);
+        rest = &rest[close + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+/// The first of a line's variants that can be filled, starting from one
+/// chosen by the week so consecutive letters don't repeat themselves.
+fn line(variants: &[&str], week: u32, fields: &HashMap<&str, String>) -> Option<String> {
+    let start = week.saturating_sub(1) as usize % variants.len();
+    (0..variants.len()).find_map(|i| fill(variants[(start + i) % variants.len()], fields))
+}
+
+const GREETING: &[&str] = &[
+    "Dear {name},",
+    "{name},",
+    "To {name}, from the mantelpiece,",
+];
+const UNNAMED_GREETING: &[&str] = &[
+    "To the one who keeps carrying me around{unnamed}",
+    "To whom it may concern (you){unnamed}",
+];
+const OPENING: &[&str] = &[
+    "Week {week} is over: days {first} to {last}. I was there for all of it, mostly sitting still.",
+    "Another week, the {week_ordinal}. Days {first} to {last}. I have been keeping count. Nobody asked me to.",
+];
+const TREES: &[&str] = &[
+    "You felled {trees} tree{trees_s}. The forest has noticed. I have said nothing.",
+    "Trees felled: {trees}. I counted every one. Someone had to.",
+];
+const NO_TREES: &[&str] =
+    &["You felled no trees{no_trees}. The forest remains suspicious of you anyway."];
+const MEALS: &[&str] = &[
+    "You cooked {meals} meal{meals_s}. I was offered none. I am rubber, but still.",
+    "{meals} meal{meals_s} cooked. The smell reached the mantelpiece every time.",
+];
+const FIRES: &[&str] = &[
+    "You lit {fires} fire{fires_s}. Warm. I approve of warm, in principle.",
+    "{fires} fire{fires_s} lit. I watched the sparks. They did not watch me back.",
+];
+const STORMS: &[&str] = &[
+    "{storms} storm{storms_s} came through, and you weathered {storms_them}. So did I, by being inanimate.",
+    "Storms: {storms}. You are still here. So am I. Neither of us is surprised.",
+];
+const QUIET: &[&str] = &["It was a quiet week{quiet}. I respect a quiet week."];
+const BEST: &[&str] = &[
+    "Best moment: day {best_day}. \"{best_what}\" You looked pleased. I could tell.",
+    "If I had to choose a highlight, and I do, it was day {best_day}: \"{best_what}\"",
+];
+const WORST: &[&str] = &[
+    "Worst moment: day {worst_day}. \"{worst_what}\" We don't need to talk about it. I'll listen anyway.",
+    "Day {worst_day} was the low point: \"{worst_what}\" I said nothing. I always say nothing. It helps.",
+];
+const CLOSING: &[&str] = &[
+    "Same time next week.\n- The Duck",
+    "Yours, in silence,\n- The Duck",
+    "Keep going. I'll be here. I have no choice in the matter.\n- The Duck",
+];
+
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (1, 11) | (2, 12) | (3, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// The letter for a finished week, as pages: the tally, then the moments.
+pub fn write_letter(
+    number: u32,
+    log: &WeekLog,
+    practice: &PracticeTally,
+    last_day: u32,
+    name: Option<&str>,
+) -> Vec<String> {
+    let mut fields: HashMap<&str, String> = HashMap::new();
+    fields.insert("week", number.to_string());
+    fields.insert("week_ordinal", ordinal(number));
+    fields.insert("first", log.start_day.to_string());
+    fields.insert("last", last_day.to_string());
+    match name {
+        Some(name) => fields.insert("name", name.to_string()),
+        None => fields.insert("unnamed", ",".to_string()),
+    };
+
+    let start = &log.practice_at_start;
+    let trees = practice.trees_felled.saturating_sub(start.trees_felled);
+    let fires = practice.fires_lit.saturating_sub(start.fires_lit);
+    let counts = [
+        ("trees", trees),
+        ("fires", fires),
+        ("meals", log.meals),
+        ("storms", log.storms),
+    ];
+    for (key, n) in counts {
+        if n > 0 {
+            fields.insert(key, n.to_string());
+        }
+    }
+    fields.insert("trees_s", plural(trees));
+    fields.insert("fires_s", plural(fires));
+    fields.insert("meals_s", plural(log.meals));
+    fields.insert("storms_s", plural(log.storms));
+    fields.insert(
+        "storms_them",
+        if log.storms == 1 { "it" } else { "them all" }.to_string(),
+    );
+    if trees == 0 {
+        fields.insert("no_trees", String::new());
+    }
+    if counts.iter().all(|(_, n)| *n == 0) {
+        fields.insert("quiet", String::new());
+    }
+    if let Some(best) = &log.best {
+        fields.insert("best_day", best.day.to_string());
+        fields.insert("best_what", best.what.clone());
+    }
+    if let Some(worst) = &log.worst {
+        fields.insert("worst_day", worst.day.to_string());
+        fields.insert("worst_what", worst.what.clone());
+    }
+
+    let pick = |variants: &[&str]| line(variants, number, &fields);
+    let greeting = pick(GREETING).or_else(|| pick(UNNAMED_GREETING));
+    let tally: Vec<String> = [OPENING, TREES, NO_TREES, MEALS, FIRES, STORMS, QUIET]
+        .iter()
+        .filter_map(|variants| pick(variants))
+        .collect();
+    let moments: Vec<String> = [BEST, WORST, CLOSING]
+        .iter()
+        .filter_map(|variants| pick(variants))
+        .collect();
+
+    let first_page = match greeting {
+        Some(greeting) => format!("{}\n\n{}", greeting, tally.join(" ")),
+        None => tally.join(" "),
+    };
+    vec![first_page, moments.join("\n\n")]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_week_keeps_its_highest_and_lowest_moments() {
+        let mut week = WeekLog::default();
+        week.note_moment(1, 5.0, "You catch a trout.");
+        week.note_moment(2, 9.0, "The fire roars into life.");
+        week.note_moment(3, 6.0, "You find some berries.");
+        week.note_moment(4, -7.0, "The rain soaks you through.");
+        week.note_moment(5, -4.0, "A splinter.");
+        assert_eq!(week.best.as_ref().map(|m| m.day), Some(2));
+        assert_eq!(week.worst.as_ref().map(|m| m.day), Some(4));
+    }
+}
@@ -1,2 +1,8 @@
+pub mod letters;
+pub mod save_health;
+pub mod snapshots;
 pub mod state;
+pub mod tile_report;
+pub mod transcript;
 pub use state::*;
+pub use tile_report::*;
@@ -0,0 +1,258 @@
+//! Whether the world is actually reaching the disk. A save follows every
+//! call; a run of failures (a read-only directory, a full disk) means
+//! progress is being lost, which the player is told in the game and which
+//! `status` and `GET /healthz` report. Shared with the web thread.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Failed saves in a row before the player is warned and a fallback tried.
+pub const FAILURES_BEFORE_WARNING: u32 = 3;
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SaveHealth {
+    pub consecutive_failures: u32,
+    /// The most recent failure, kept until a save goes through.
+    pub last_error: Option<String>,
+    /// Seconds since the epoch of the last save that went through.
+    pub last_saved_at: Option<u64>,
+    /// Bytes that save came to.
+    pub save_bytes: Option<u64>,
+    /// Where saves go now that the usual file couldn't be written.
+    pub fallback: Option<PathBuf>,
+    /// Whether the fallback has been tried; it's only tried once.
+    #[serde(skip)]
+    pub fallback_tried: bool,
+    /// Whether the player has been told saving is failing, so they can be
+    /// told when it recovers.
+    #[serde(skip)]
+    pub warned: bool,
+}
+
+impl SaveHealth {
+    pub fn failing(&self) -> bool {
+        self.consecutive_failures >= FAILURES_BEFORE_WARNING
+    }
+
+    /// One line for `status`.
+    pub fn status_line(&self) -> String {
+        if self.failing() {
+            return format!(
+                "Saving: FAILING for the last {} calls ({}). Progress is not being kept.",
+                self.consecutive_failures,
+                self.last_error.as_deref().unwrap_or("unknown error")
+            );
+        }
+        match &self.fallback {
+            Some(path) => format!(
+                "Saving: to {} (the usual save file couldn't be written).",
+                path.display()
+            ),
+            None => "Saving: every step is being kept.".to_string(),
+        }
+    }
+}
+
+fn registry() -> &'static Mutex<SaveHealth> {
+    static REGISTRY: OnceLock<Mutex<SaveHealth>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(SaveHealth::default()))
+}
+
+fn with_health<T>(f: impl FnOnce(&mut SaveHealth) -> T) -> T {
+    let mut guard = registry().lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut guard)
+}
+
+/// The save health as it stands.
+pub fn current() -> SaveHealth {
+    with_health(|h| h.clone())
+}
+
+/// A save of `bytes` went through.
+pub fn record_success(bytes: u64) {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    with_health(|h| {
+        h.consecutive_failures = 0;
+        h.last_error = None;
+        h.last_saved_at = Some(now);
+        h.save_bytes = Some(bytes);
+    });
+}
+
+/// A save failed. Returns whether this is the failure that should send
+/// saving to the fallback path.
+pub fn record_failure(error: &str) -> bool {
+    with_health(|h| {
+        h.consecutive_failures += 1;
+        h.last_error = Some(error.to_string());
+        let switch = h.failing() && !h.fallback_tried;
+        if switch {
+            h.fallback_tried = true;
+        }
+        switch
+    })
+}
+
+/// Saves go to `path` from now on.
+pub fn record_fallback(path: &Path) {
+    with_health(|h| {
+        h.fallback = Some(path.to_path_buf());
+        h.warned = true;
+    });
+}
+
+/// Where to try saving when the usual file can't be written: a folder in
+/// the home directory, or the temp directory without one.
+pub fn fallback_path(state_path: &Path) -> PathBuf {
+    let file = state_path
+        .file_name()
+        .map(|f| f.to_os_string())
+        .unwrap_or_else(|| "world_state.json".into());
+    let base = std::env::var_os("HOME")
+        .map(PathBuf::from)
+        .filter(|home| home.is_dir())
+        .unwrap_or_else(std::env::temp_dir);
+    base.join(".rubber-duck-mcp").join(file)
+}
+
+/// Where saves are going now: the fallback once saving has moved there,
+/// otherwise `state_path`.
+pub fn save_path(state_path: &Path) -> PathBuf {
+    current()
+        .fallback
+        .unwrap_or_else(|| state_path.to_path_buf())
+}
+
+/// The save to pick up from at startup. An earlier run that had to move to
+/// the fallback left its progress there, so that's taken when it's newer
+/// than the usual file, and saving carries on there.
+pub fn resume_path(state_path: &Path) -> PathBuf {
+    let fallback = fallback_path(state_path);
+    let modified = |p: &Path| std::fs::metadata(p).and_then(|m| m.modified()).ok();
+    let Some(moved) = modified(&fallback) else {
+        return state_path.to_path_buf();
+    };
+    if modified(state_path).is_some_and(|usual| usual >= moved) {
+        return state_path.to_path_buf();
+    }
+    tracing::warn!(
+        "Resuming from {:?}, saved after {:?} couldn't be written",
+        fallback,
+        state_path
+    );
+    record_fallback(&fallback);
+    with_health(|h| h.fallback_tried = true);
+    fallback
+}
+
+/// What the player should be told about saving in the next reply: a
+/// warning while it's failing, or word once it recovers.
+pub fn take_notice() -> Option<String> {
+    with_health(|h| {
+        if h.failing() {
+            h.warned = true;
+            return Some(format!(
+                "The world feels strangely impermanent — your progress is NOT being saved: {}",
+                h.last_error.as_deref().unwrap_or("unknown error")
+            ));
+        }
+        if h.warned {
+            h.warned = false;
+            return Some(match &h.fallback {
+                Some(path) => format!(
+                    "The world wavered, then steadied: the usual save couldn't be written, so your progress is being saved to {} instead.",
+                    path.display()
+                ),
+                None => "The world feels solid again. Your progress is being saved.".to_string(),
+            });
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persistence::World;
+
+    /// A read-only directory to save into. Permissions don't stop root, so
+    /// there a file standing where the directory should be does instead.
+    fn unwritable_dir(root: &Path) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = root.join("read-only");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o555)).unwrap();
+        let probe = dir.join("probe");
+        if std::fs::write(&probe, "").is_err() {
+            return dir;
+        }
+        let _ = std::fs::remove_file(probe);
+        let blocked = root.join("not-a-directory");
+        std::fs::write(&blocked, "").unwrap();
+        blocked
+    }
+
+    #[test]
+    fn progress_saved_to_the_fallback_is_there_after_a_restart() {
+        let id = std::process::id();
+        let root = std::env::temp_dir().join(format!("rubber-duck-save-health-{}", id));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        let usual = unwritable_dir(&root).join(format!("world-{}.json", id));
+        let fallback = fallback_path(&usual);
+        let _ = std::fs::remove_file(&fallback);
+
+        let mut world = World::new(usual.clone());
+        for _ in 0..10 {
+            world.tick();
+        }
+        let tick = world.state.time.tick;
+        // Saves elsewhere going through break the run of failures; keep at it
+        for _ in 0..100 {
+            if world.state_path != usual {
+                break;
+            }
+            assert!(world.save().is_err() || world.state_path == fallback);
+        }
+        assert_eq!(world.state_path, fallback);
+        assert_eq!(save_path(&usual), fallback);
+        drop(world);
+
+        let resumed = resume_path(&usual);
+        assert_eq!(resumed, fallback);
+        let world = World::new(resumed);
+        assert_eq!(world.state.time.tick, tick);
+
+        drop(world);
+        let _ = std::fs::remove_file(&fallback);
+        if let Some(dir) = fallback.parent() {
+            let _ = std::fs::remove_dir(dir);
+        }
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn a_usual_save_newer_than_the_fallback_is_kept() {
+        let id = std::process::id();
+        let dir = std::env::temp_dir().join(format!("rubber-duck-resume-{}", id));
+        std::fs::create_dir_all(&dir).unwrap();
+        let usual = dir.join(format!("world-{}.json", id));
+        let fallback = fallback_path(&usual);
+        std::fs::create_dir_all(fallback.parent().unwrap()).unwrap();
+        let stale = std::fs::File::create(&fallback).unwrap();
+        let hour_ago = SystemTime::now() - std::time::Duration::from_secs(3600);
+        stale.set_modified(hour_ago).unwrap();
+        std::fs::write(&usual, "{}").unwrap();
+
+        assert_eq!(resume_path(&usual), usual);
+
+        let _ = std::fs::remove_file(&fallback);
+        let _ = std::fs::remove_dir(fallback.parent().unwrap());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
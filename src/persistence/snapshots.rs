@@ -0,0 +1,173 @@
+//! Milestone checkpoints kept beside the rolling save: the dawn of each day,
+//! the doorstep of the cave, a blueprint finished. Each is a full copy of
+//! the save under `snapshots/`, and only the newest few are kept.
+
+use anyhow::{bail, Result};
+use std::path::{Path, PathBuf};
+use std::thread::JoinHandle;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Snapshots kept when `RUBBER_DUCK_SNAPSHOTS` doesn't say otherwise.
+pub const DEFAULT_SNAPSHOTS: usize = 12;
+/// Environment variable choosing how many snapshots to keep; 0 turns them off.
+pub const SNAPSHOTS_ENV: &str = "RUBBER_DUCK_SNAPSHOTS";
+
+/// How many snapshots to keep, from `RUBBER_DUCK_SNAPSHOTS` or the default.
+pub fn snapshots_to_keep() -> usize {
+    let Ok(v) = std::env::var(SNAPSHOTS_ENV) else {
+        return DEFAULT_SNAPSHOTS;
+    };
+    v.trim().parse().unwrap_or_else(|_| {
+        tracing::warn!("Ignoring {}={}; expected a whole number", SNAPSHOTS_ENV, v);
+        DEFAULT_SNAPSHOTS
+    })
+}
+
+/// A checkpoint waiting to be written: its name and the save as it stood.
+#[derive(Debug, Clone)]
+pub struct PendingSnapshot {
+    pub name: String,
+    pub json: String,
+}
+
+/// One snapshot on disk, for listing.
+#[derive(Debug, Clone)]
+pub struct SnapshotInfo {
+    pub name: String,
+    pub day: u32,
+    /// Seconds since the epoch when it was written.
+    pub saved_at: u64,
+}
+
+/// Where the snapshots for a save live: `snapshots/` beside it.
+pub fn snapshot_dir(state_path: &Path) -> PathBuf {
+    state_path
+        .parent()
+        .map(|p| p.join("snapshots"))
+        .unwrap_or_else(|| PathBuf::from("snapshots"))
+}
+
+/// Names are used as file names, so only plain ones are allowed.
+fn valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Write the snapshots on a background thread, each to a temporary file
+/// renamed into place so a half-written one is never seen, then prune.
+/// Join the handle before exiting, or the last ones may be lost.
+pub fn write_in_background(
+    state_path: &Path,
+    pending: Vec<PendingSnapshot>,
+) -> Option<JoinHandle<()>> {
+    let keep = snapshots_to_keep();
+    if pending.is_empty() || keep == 0 {
+        return None;
+    }
+    let dir = snapshot_dir(state_path);
+    Some(std::thread::spawn(move || {
+        for snapshot in pending {
+            if let Err(e) = write_one(&dir, &snapshot) {
+                tracing::warn!("Failed to write snapshot {}: {}", snapshot.name, e);
+            }
+        }
+        prune(&dir, keep);
+    }))
+}
+
+fn write_one(dir: &Path, snapshot: &PendingSnapshot) -> Result<()> {
+    if !valid_name(&snapshot.name) {
+        bail!("'{}' isn't a usable snapshot name", snapshot.name);
+    }
+    std::fs::create_dir_all(dir)?;
+    let target = dir.join(format!("{}.json", snapshot.name));
+    let temp = dir.join(format!(".{}.json.tmp", snapshot.name));
+    std::fs::write(&temp, &snapshot.json)?;
+    std::fs::rename(&temp, &target)?;
+    Ok(())
+}
+
+/// Drop the oldest snapshots past the `keep` newest.
+fn prune(dir: &Path, keep: usize) {
+    let snapshots = list_in(dir);
+    for old in snapshots.iter().skip(keep) {
+        let _ = std::fs::remove_file(dir.join(format!("{}.json", old.name)));
+    }
+}
+
+/// The snapshots beside a save, newest first.
+pub fn list(state_path: &Path) -> Vec<SnapshotInfo> {
+    list_in(&snapshot_dir(state_path))
+}
+
+fn list_in(dir: &Path) -> Vec<SnapshotInfo> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut found: Vec<SnapshotInfo> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let name = path
+                .file_name()?
+                .to_str()?
+                .strip_suffix(".json")?
+                .to_string();
+            if !valid_name(&name) {
+                return None;
+            }
+            let saved_at = entry
+                .metadata()
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let json = std::fs::read_to_string(&path).ok()?;
+            let value: serde_json::Value = serde_json::from_str(&json).ok()?;
+            let day = value
+                .pointer("/time/day")
+                .and_then(|d| d.as_u64())
+                .unwrap_or(0) as u32;
+            Some(SnapshotInfo {
+                name,
+                day,
+                saved_at,
+            })
+        })
+        .collect();
+    found.sort_by(|a, b| b.saved_at.cmp(&a.saved_at).then(b.day.cmp(&a.day)));
+    found
+}
+
+/// Copy a snapshot over the live save. The caller reloads the world from it.
+pub fn restore(state_path: &Path, name: &str) -> Result<()> {
+    if !valid_name(name) {
+        bail!("'{}' isn't a snapshot name", name);
+    }
+    let source = snapshot_dir(state_path).join(format!("{}.json", name));
+    if !source.exists() {
+        bail!("there's no snapshot called '{}'", name);
+    }
+    let temp = state_path.with_extension("json.restoring");
+    std::fs::copy(&source, &temp)?;
+    std::fs::rename(&temp, state_path)?;
+    Ok(())
+}
+
+/// How long ago `saved_at` was, roughly, for listing.
+pub fn age_phrase(saved_at: u64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(saved_at);
+    let secs = now.saturating_sub(saved_at);
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{} min ago", secs / 60),
+        3600..=86399 => format!("{} h ago", secs / 3600),
+        _ => format!("{} days ago", secs / 86400),
+    }
+}
@@ -1,927 +1,2758 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
+use super::letters::{self, DuckLetters, WeekLog, LETTER_DAYS, LETTER_MOOD_BONUS};
+use super::save_health;
+use super::snapshots::{self, PendingSnapshot};
+use crate::descriptions::LocationContext;
 use crate::entity::*;
 use crate::world::*;
 use rand::Rng;
 
 const TUTORIAL_BOOK_ID: &str = "book-tutorial";
+
+fn quantity_label(name: &str, qty: u32) -> String {
+    if qty > 1 {
+        format!("{} x{}", name, qty)
+    } else {
+        name.to_string()
+    }
+}
 const OLD_BOOK_ID: &str = "book-old";
-const DEATH_NOTE_ID: &str = "book-death-note";
+pub const DEATH_NOTE_ID: &str = "book-death-note";
 const FISHING_BOOK_ID: &str = "book-fishing";
-
+const MANTEL_ID: &str = "cabin_mantel";
+pub const TABLE_ID: &str = "cabin_table";
+pub const HEARTH_BASKET_ID: &str = "cabin_hearth_basket";
+pub const WINDOW_SILL_ID: &str = "cabin_window_sill";
+pub const WORKBENCH_ID: &str = "shed_workbench";
+pub const RAFT_ID: &str = "raft";
+pub const CAVE_ID: &str = "east_cave_entrance";
+pub const CAVE_BEAR_ID: &str = "cave_bear";
+pub const RUIN_ID: &str = "oasis_ruin";
+const RUIN_BOOK_ID: &str = "book-ruin-inscriptions";
+/// Carved on the ruin's walls, one panel per chamber: where the strange
+/// compass came from.
+const RUIN_INSCRIPTIONS: [&str; 3] = [
+    "Over the doorway: 'Here the keepers of the Mirror laid down what they would not carry. Whoever digs, dig gently.'",
+    "Along the hall, in a long carved band: 'We took the black stone from the heart of the Mirror, where the water was deepest and stillest. A sliver of it, hung on a thread, would always turn to face the place it was torn from. So we made the needles, and so we always found our way home.'",
+    "Around the plinth: 'The last needle went east with the last of us, to the house by the water. The stone stays here. Let it rest where the compass can still feel it.'",
+];
+/// Added to the Weathered Journal ahead of the cave bear.
+const BEAR_WARNING_PAGE: &str = "A page in a hurried, shaky hand: 'The cave east of here is not empty. Where the trees wear claw marks she is close. Go soft, carry no fire in, and if she wakes, a fish is worth more than any blade.'";
+/// Added to the Book of Fishing ahead of its closing page.
+const FISHING_SPOTS_PAGE: &str = "Not all water is the same. The reedy shallows give a bit of everything. The oasis pool is full of little desert pupfish that bite all day. Out in the middle of the lake, where only a raft will take you, the big ones lie deep; on hot days they go there to stay cool. On the snowy shore, or whenever the frost is hard, chip a hole in the ice and be patient. And fish one spot too hard and it goes quiet for a while.";
+/// Hunger or thirst below this drains energy; idle ticking won't cross it.
+const NEED_CRITICAL: f32 = 20.0;
+/// Cooking level at which the player starts keeping their own recipe book.
+const RECIPE_BOOK_SKILL: u8 = 15;
+/// Observation needed to read at a glance whether a tile is worth foraging.
+const FORAGE_HINT_OBSERVATION: u8 = 12;
+/// How far around the player a held map confirms charted ground.
+const MAP_CONFIRM_RADIUS: f32 = 2.0;
+/// Stumps kept at most; past this the oldest rot away first.
+const MAX_STUMPS: usize = 40;
+/// Days a stump stands before it rots away.
+const STUMP_DECAY_DAYS: u32 = 30;
+/// Queued messages kept at most; a long simulation drops the oldest.
+const MAX_PENDING_MESSAGES: usize = 30;
+/// How far, in tiles, a fox will go for food left out at night.
+const RAID_RANGE: i32 = 4;
+/// Chance each night tick that a fox in range raids food left in the open.
+const RAID_CHANCE: f64 = 0.03;
+/// Days fox tracks stay fresh enough to read.
+const RAID_TRACKS_DAYS: u32 = 2;
+/// Tiles around the cabin and the shed where no new tree takes root.
+const STRUCTURE_CLEARANCE: i32 = 2;
+/// Chance each tick that a bird perched on a roof drops a feather.
+const FEATHER_CHANCE: f64 = 0.01;
+/// Builds that can be set aside at once, besides the one being worked on.
+pub const MAX_PAUSED_PROJECTS: usize = 3;
+/// How long jarred fireflies last before they start to die: a night or two.
+pub const FIREFLY_JAR_MINUTES: u32 = 36 * 60;
+/// Health regained per ten minutes when warm, fed, watered and resting.
+const HEALTH_REGEN_RATE: f32 = 0.5;
+/// Sleep multiplies the regeneration rate rather than granting health.
+const SLEEP_REGEN_MULTIPLIER: f32 = 4.0;
+/// Most health that comfort and rest can restore in one day.
+const HEALTH_REGEN_DAILY_CAP: f32 = 40.0;
+/// Separate clear days a signal fire has to be seen on before anyone comes.
+pub const SIGNAL_DAYS_NEEDED: usize = 3;
+/// The last chapter of the journal, written when the rescuers arrive.
+const RESCUE_CHAPTER: &str = "The last chapter, in your own hand: 'Three clear days the smoke went up from the knoll, and on the third someone saw it. I can hear them coming through the trees. Whatever I choose now, this place kept me.'";
+
+/// What became of one animal the player killed.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ForageNode {
-    pub charges: u8,
-    pub cooldown: u8,
+pub struct KillRecord {
+    pub corpse_id: String,
+    pub species: Species,
+    pub tick: u64,
+    #[serde(default)]
+    pub butchered: bool,
+    #[serde(default)]
+    pub cooked: bool,
+    #[serde(default)]
+    pub hide_used: bool,
+    /// Left to rot without being butchered.
+    #[serde(default)]
+    pub wasted: bool,
+    /// The player has come back and seen the waste.
+    #[serde(default)]
+    pub mourned: bool,
+    #[serde(default)]
+    pub reconciled: bool,
 }
 
-impl ForageNode {
-    pub fn new(biome: Biome, rng: &mut impl Rng) -> Self {
-        let charges = match biome {
-            Biome::Desert => rng.gen_range(1..=2),
-            Biome::Oasis => rng.gen_range(3..=4),
-            Biome::WinterForest => rng.gen_range(2..=3),
-            Biome::Lake => rng.gen_range(3..=5),
-            Biome::BambooGrove => rng.gen_range(3..=5),
-            _ => rng.gen_range(4..=6),
-        };
-        Self {
-            charges,
-            cooldown: 0,
+/// One species in the Field Notes, and how closely it has been watched.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FieldNote {
+    pub species: Species,
+    pub detail: SightingDetail,
+}
+
+impl KillRecord {
+    pub fn fully_used(&self) -> bool {
+        self.butchered && self.cooked && self.hide_used
+    }
+}
+
+/// Where the 52 playing cards currently are.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CardCensus {
+    pub in_case: u32,
+    pub carried: u32,
+    pub in_cabin: u32,
+    pub on_ground: u32,
+    pub lost: u32,
+}
+
+impl CardCensus {
+    pub const DECK_SIZE: u32 = 52;
+
+    /// Every card still somewhere in the world.
+    pub fn total(&self) -> u32 {
+        self.in_case + self.loose()
+    }
+
+    /// Cards outside the case that the player could still gather up.
+    pub fn loose(&self) -> u32 {
+        self.carried + self.in_cabin + self.on_ground
+    }
+}
+
+/// Something that happened while the player slept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NightEvent {
+    /// The cabin fire burned out and the cold woke the player.
+    FireDied,
+    /// An animal came nosing around the camp, maybe making off with food.
+    Visitor { species: Species, stole: Option<Item> },
+    /// The weather turned to blizzard over someone sleeping outdoors.
+    Blizzard,
+}
+
+impl NightEvent {
+    /// Whether it ends the sleep early.
+    pub fn wakes(&self) -> bool {
+        !matches!(self, NightEvent::Visitor { .. })
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            NightEvent::FireDied => "You wake shivering in the dark. The fire has burned down to grey ash and the cold is creeping back into the cabin. A log and a match would bring it back ('use log on fire', then 'use matchbox on fire').".to_string(),
+            NightEvent::Visitor { species, stole: Some(item) } => format!(
+                "In the grey of morning you find fresh {} tracks circling the camp, and the {} you left on the ground is gone.",
+                species.name(),
+                item.name()
+            ),
+            NightEvent::Visitor { species, stole: None } => format!(
+                "Something snuffled around the camp in the night. Fresh {} tracks circle your sleeping spot, but nothing seems to be missing.",
+                species.name()
+            ),
+            NightEvent::Blizzard => "You wake with snow in your collar. The wind has turned and a blizzard is howling in; sleeping out here any longer would be dangerous. Find shelter.".to_string(),
         }
     }
 
-    pub fn tick(&mut self, biome: Biome, rng: &mut impl Rng) {
-        if self.charges > 0 {
-            return;
+    fn journal_line(&self) -> String {
+        match self {
+            NightEvent::FireDied => "A note: the fire went out in the night and I woke freezing. Bank it before bed.".to_string(),
+            NightEvent::Visitor { species, stole } => match stole {
+                Some(item) => format!(
+                    "A note: a {} visited in the night and took my {}. Don't leave food out.",
+                    species.name(),
+                    item.name()
+                ),
+                None => format!("A note: a {} came sniffing round in the night.", species.name()),
+            },
+            NightEvent::Blizzard => "A note: a blizzard caught me sleeping in the open.".to_string(),
         }
-        if self.cooldown > 0 {
-            self.cooldown -= 1;
-            if self.cooldown == 0 {
-                *self = Self::new(biome, rng);
-            }
+    }
+}
+
+/// Fox tracks by a cache of food one raided in the night.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaidTracks {
+    pub position: Position,
+    pub species: Species,
+    pub item: Item,
+    pub day: u32,
+    /// Taken off the shed's workbench rather than the open ground.
+    #[serde(default)]
+    pub from_shed: bool,
+}
+
+/// What the night looked like when the player lay down, so changes while
+/// asleep can be noticed.
+#[derive(Debug, Clone, Copy)]
+pub struct NightWatch {
+    fire_lit: bool,
+    outdoors: bool,
+    blizzard: bool,
+    visited: bool,
+}
+
+/// The optional long goal: signal for rescue from the lookout.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RescueGoal {
+    pub active: bool,
+    /// Days a signal fire burned on the lookout under a clear sky.
+    #[serde(default)]
+    pub signal_days: Vec<u32>,
+    #[serde(default)]
+    pub ending: Option<RescueEnding>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RescueEnding {
+    /// The rescuers have come; the player hasn't decided yet.
+    Awaiting,
+    /// The player sent them away and stayed on.
+    Stayed,
+}
+
+impl RescueGoal {
+    pub fn progress_line(&self) -> Option<String> {
+        if !self.active {
+            return None;
         }
+        Some(match self.ending {
+            Some(RescueEnding::Awaiting) => {
+                "Goal: rescue has come. Choose with 'rescue' - leave or stay.".to_string()
+            }
+            Some(RescueEnding::Stayed) => {
+                "Goal: you were found, and chose to stay.".to_string()
+            }
+            None => format!(
+                "Goal: signal for rescue - {}/{} clear days of smoke from the knoll.",
+                self.signal_days.len(),
+                SIGNAL_DAYS_NEEDED
+            ),
+        })
     }
 }
 
-/// The complete game state that gets saved/loaded
+/// Things lying under drifted sand on one desert tile.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GameState {
-    pub version: String,
-    pub time: WorldTime,
-    pub weather: RegionalWeather,
-    pub player: Player,
-    pub wildlife: Vec<Wildlife>,
+pub struct BuriedStash {
+    pub position: Position,
+    pub items: Vec<(Item, u32)>,
+}
+
+/// What sandstorms have done to the desert: loose things buried under the
+/// drift, dunes that have crept onto open ground, and whether the wind has
+/// bared the old cache yet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DesertSands {
     #[serde(default)]
-    pub objects: ObjectRegistry,
+    pub buried: Vec<BuriedStash>,
     #[serde(default)]
-    pub custom_names: HashMap<Item, String>,
+    pub dunes: HashSet<Position>,
     #[serde(default)]
-    pub forage_nodes: HashMap<Position, ForageNode>,
-    #[serde(default = "GameState::default_books")]
-    pub books: HashMap<String, BookEntry>,
-    #[serde(default = "GameState::default_next_book_id")]
-    pub next_book_id: u32,
+    pub cache_bared: bool,
+}
+
+impl DesertSands {
+    pub fn bury(&mut self, position: Position, items: Vec<(Item, u32)>) {
+        if items.is_empty() {
+            return;
+        }
+        let stash = match self.buried.iter_mut().find(|s| s.position == position) {
+            Some(stash) => stash,
+            None => {
+                self.buried.push(BuriedStash {
+                    position,
+                    items: Vec::new(),
+                });
+                self.buried.last_mut().unwrap()
+            }
+        };
+        for (item, qty) in items {
+            match stash.items.iter_mut().find(|(i, _)| *i == item) {
+                Some((_, existing)) => *existing += qty,
+                None => stash.items.push((item, qty)),
+            }
+        }
+    }
+
+    /// Everything buried at `position`, taken out of the sand.
+    pub fn unearth(&mut self, position: &Position) -> Vec<(Item, u32)> {
+        match self.buried.iter().position(|s| s.position == *position) {
+            Some(idx) => self.buried.remove(idx).items,
+            None => Vec::new(),
+        }
+    }
+
+    pub fn has_buried(&self, position: &Position) -> bool {
+        self.buried.iter().any(|s| s.position == *position)
+    }
+}
+
+/// Ticks a burning tile keeps going before it's burnt out.
+const BURN_TICKS: u32 = 6;
+/// Ticks a lit piece of kindling lasts in the hand.
+pub const LIT_KINDLING_TICKS: u32 = 4;
+
+/// One patch of ground on fire.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BurningTile {
+    pub position: Position,
+    /// Ticks it has burned so far.
     #[serde(default)]
-    pub card_case_cards_inside: u8,
+    pub ticks: u32,
+}
+
+/// Fire loose out in the open: the ground alight, what it has already
+/// burnt, and the kindling the player carries alight.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Wildfire {
     #[serde(default)]
-    pub card_case_open: bool,
+    pub burning: Vec<BurningTile>,
+    /// Burnt-out ground with nothing left to catch.
     #[serde(default)]
-    pub card_scatter_achievement: bool,
+    pub scorched: HashSet<Position>,
+    /// Trees charred by the fire that's burning now, for the journal.
     #[serde(default)]
-    pub tutorial_reward_claimed: bool,
+    pub charred: u32,
+    /// Where the fire that's burning now started.
     #[serde(default)]
-    pub tutorial_hint_shown: bool,
-    // Runtime state (not critical to save but nice to have)
+    pub origin: Option<Position>,
+    /// Ticks left on the lit kindling in the player's hand.
     #[serde(default)]
-    pub pending_messages: Vec<String>,
-    #[serde(default, rename = "cabin")]
-    #[serde(skip_serializing)]
-    legacy_cabin: Option<Cabin>,
-    #[serde(default, rename = "wood_shed")]
-    #[serde(skip_serializing)]
-    legacy_wood_shed: Option<WoodShed>,
-    #[serde(default, rename = "trees")]
-    #[serde(skip_serializing)]
-    legacy_trees: Option<Vec<Tree>>,
+    pub kindling_ticks: u32,
 }
 
-impl GameState {
-    pub fn default_books() -> HashMap<String, BookEntry> {
-        HashMap::new()
+impl Wildfire {
+    pub fn is_burning(&self, pos: &Position) -> bool {
+        self.burning.iter().any(|b| b.position == *pos)
     }
 
-    pub fn default_next_book_id() -> u32 {
-        1
+    /// Put out the fire on one tile. Burnt ground is left scorched.
+    pub fn douse(&mut self, pos: &Position) -> bool {
+        let before = self.burning.len();
+        self.burning.retain(|b| b.position != *pos);
+        if self.burning.len() == before {
+            return false;
+        }
+        self.scorched.insert(*pos);
+        true
     }
 
-    pub fn cabin_state(&self) -> Option<&Cabin> {
-        self.objects.find("cabin").and_then(|p| p.object.as_cabin())
+    /// The burning tile closest to `pos`, within `range`.
+    pub fn nearest(&self, pos: &Position, range: f32) -> Option<Position> {
+        self.burning
+            .iter()
+            .map(|b| b.position)
+            .filter(|p| p.distance_to(pos) <= range)
+            .min_by(|a, b| a.distance_to(pos).total_cmp(&b.distance_to(pos)))
     }
+}
 
-    pub fn cabin_state_mut(&mut self) -> Option<&mut Cabin> {
-        self.objects
-            .find_mut("cabin")
-            .and_then(|p| p.object.as_cabin_mut())
-    }
+/// How a grilled dish came off the fire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Doneness {
+    Undercooked,
+    Perfect,
+    Charred,
+}
 
-    pub fn wood_shed_state(&self) -> Option<&WoodShed> {
-        self.objects
-            .find("wood_shed")
-            .and_then(|p| p.object.as_wood_shed())
-    }
+/// Heat a dish can take past its window before it's charcoal, if nobody is
+/// there to pull it off.
+const BURN_AWAY_HEAT: f32 = 3.0;
 
-    pub fn wood_shed_state_mut(&mut self) -> Option<&mut WoodShed> {
-        self.objects
-            .find_mut("wood_shed")
-            .and_then(|p| p.object.as_wood_shed_mut())
-    }
+/// Fish or meat grilling over the cabin fire, taking a little more heat each
+/// tick until it's pulled off.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnTheFire {
+    pub raw: Item,
+    pub dish: Item,
+    pub portions: u32,
+    /// Heat taken so far; a tick over a steadily burning fire gives 1.
+    pub heat: f32,
+    /// Heat at which it's done.
+    pub ready_at: f32,
+    /// Heat past `ready_at` it stays just right.
+    pub window: f32,
+    /// Whether the player has been told it smells ready.
+    #[serde(default)]
+    pub cued: bool,
+    /// Whether the player has been told it's starting to scorch.
+    #[serde(default)]
+    pub scorch_cued: bool,
+}
 
-    pub fn table_surface(&self) -> Option<&ObjectSurface> {
-        self.objects
-            .find("cabin_table")
-            .and_then(|p| p.object.surface.as_ref())
+impl OnTheFire {
+    pub fn doneness(&self) -> Doneness {
+        if self.heat < self.ready_at {
+            Doneness::Undercooked
+        } else if self.heat <= self.ready_at + self.window {
+            Doneness::Perfect
+        } else {
+            Doneness::Charred
+        }
     }
 
-    pub fn table_surface_mut(&mut self) -> Option<&mut ObjectSurface> {
-        self.objects
-            .find_mut("cabin_table")
-            .and_then(|p| p.object.surface.as_mut())
+    /// Long enough past its window that, left alone, it burns away.
+    fn can_burn_away(&self) -> bool {
+        self.heat > self.ready_at + self.window + BURN_AWAY_HEAT
     }
 
-    fn ensure_core_cabin_items(cabin: &mut Cabin) {
-        if !cabin.items.contains(&Item::Kettle) {
-            cabin.items.push(Item::Kettle);
-        }
-        if !cabin.items.contains(&Item::TeaCup) {
-            cabin.items.push(Item::TeaCup);
+    /// What's on the grate, as it reads while it's there.
+    pub fn label(&self) -> &'static str {
+        match self.raw {
+            Item::RawMeat => "meat",
+            Item::BigFish => "fish fillets",
+            _ => "fish",
         }
-        if !cabin.items.contains(&Item::WildHerbs) {
-            cabin.items.push(Item::WildHerbs);
-        }
-        if !cabin.items.contains(&Item::CardCase)
-            && !cabin.table_items.contains(&Item::CardCase)
+    }
+}
+
+/// Grilled portions the player carries that came off the fire early or
+/// late, or just right. Portions not listed eat the ordinary way.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CookedPortions {
+    #[serde(default)]
+    pub portions: Vec<(Item, Doneness, u32)>,
+}
+
+impl CookedPortions {
+    pub fn add(&mut self, dish: Item, doneness: Doneness, qty: u32) {
+        match self
+            .portions
+            .iter_mut()
+            .find(|(i, d, _)| *i == dish && *d == doneness)
         {
-            cabin.table_items.push(Item::CardCase);
+            Some((_, _, n)) => *n += qty,
+            None if qty > 0 => self.portions.push((dish, doneness, qty)),
+            None => {}
         }
     }
 
-    fn ensure_table_object(&mut self, mut table_items: Vec<Item>) {
-        if let Some(table) = self.objects.find_mut("cabin_table") {
-            if let Some(surface) = table.object.surface.as_mut() {
-                surface.items.extend(table_items.drain(..));
-                surface.supports_mounts = true;
-                if surface.capacity.is_none() {
-                    surface.capacity = Some(8);
-                }
-            } else {
-                table.object.surface = Some(ObjectSurface {
-                    items: table_items,
-                    capacity: Some(8),
-                    supports_mounts: true,
-                });
-            }
-            return;
+    /// Take one listed portion of a dish, the oldest kind first.
+    pub fn take(&mut self, dish: &Item) -> Option<Doneness> {
+        let idx = self.portions.iter().position(|(i, _, _)| i == dish)?;
+        let doneness = self.portions[idx].1;
+        self.portions[idx].2 -= 1;
+        if self.portions[idx].2 == 0 {
+            self.portions.remove(idx);
         }
+        Some(doneness)
+    }
 
-        let mut table_obj = WorldObject::new(ObjectKind::Table);
-        if let Some(surface) = table_obj.surface.as_mut() {
-            surface.items.extend(table_items.drain(..));
-            surface.capacity = Some(8);
-            surface.supports_mounts = true;
+    /// Forget portions until at most `max` of a dish are listed, e.g. after
+    /// some were given away or left behind.
+    pub fn trim(&mut self, dish: &Item, max: u32) {
+        let listed: u32 = self
+            .portions
+            .iter()
+            .filter(|(i, _, _)| i == dish)
+            .map(|(_, _, n)| n)
+            .sum();
+        for _ in 0..listed.saturating_sub(max) {
+            self.take(dish);
         }
-        self.objects
-            .add("cabin_table", Position::new(0, 0), table_obj);
     }
+}
 
-    fn ensure_duck_present(&mut self) {
-        let duck = Item::RubberDuck;
-        let duck_on_table = self
-            .table_surface()
-            .map(|s| s.items.contains(&duck))
-            .unwrap_or(false);
-        let duck_in_cabin = self
-            .cabin_state()
-            .map(|c| c.items.contains(&duck) || c.table_items.contains(&duck))
-            .unwrap_or(false);
-        let duck_with_player = self.player.inventory.has(&duck, 1);
-
-        if duck_on_table || duck_in_cabin || duck_with_player {
-            return;
-        }
+/// Work calls in a row before connection starts to wear thin.
+const GRIND_GRACE: u32 = 15;
 
-        if let Some(surface) = self.table_surface_mut() {
-            surface.items.push(duck);
-            return;
-        }
+/// Whether a tool call expresses something or just gets work done. Looking
+/// around, checking status and the like are neither.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolCategory {
+    Expressive,
+    Work,
+    Other,
+}
 
-        if let Some(cabin) = self.cabin_state_mut() {
-            cabin.items.push(duck);
+impl ToolCategory {
+    pub fn of(tool: &str) -> Self {
+        match tool {
+            "talk" | "write" | "meditate" | "lakeside" | "tidy" | "sit" | "name" => {
+                ToolCategory::Expressive
+            }
+            "take" | "drop" | "store" | "use" | "create" | "fish" | "tend_fire" | "kick"
+            | "catch" | "throw" | "search" | "approach" | "mount" => ToolCategory::Work,
+            _ => ToolCategory::Other,
         }
     }
+}
 
-    fn ensure_player_visit(&mut self) {
-        self.player.mark_visited();
+/// Tool calls by category, and how long it's been since the last one that
+/// wasn't work.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ToolTally {
+    #[serde(default)]
+    pub expressive: u32,
+    #[serde(default)]
+    pub work: u32,
+    /// Work calls since the last expressive one.
+    #[serde(default)]
+    pub grind_streak: u32,
+}
+
+/// Names the Death Note takes before its ink is gone for good.
+const DEATH_NOTE_INK: u32 = 3;
+
+/// What's left of the Death Note's ink, and the names it's still acting on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeathNote {
+    #[serde(default = "DeathNote::full_ink")]
+    pub ink: u32,
+    /// Species written down, with ticks until the nearest one falls.
+    #[serde(default)]
+    pub pending: Vec<(Species, u32)>,
+    /// Pages holding names that mean nothing to it; blank again by dawn.
+    #[serde(default)]
+    pub fading: Vec<usize>,
+    /// Set the first time it refused a name.
+    #[serde(default)]
+    pub refusal_achievement: bool,
+}
+
+impl DeathNote {
+    fn full_ink() -> u32 {
+        DEATH_NOTE_INK
     }
+}
 
-    pub fn damage_tool(&mut self, item: &Item, amount: u32, context: &str) {
-        let Some(max) = Player::tool_max_durability(item) else {
-            return;
-        };
-        let entry = self.player.tool_durability.entry(*item).or_insert(max);
-        if *entry <= amount {
-            let _ = self.player.inventory.remove(item, 1);
-            self.player.tool_durability.remove(item);
-            self.pending_messages
-                .push(format!("Your {} breaks while {}.", item.name(), context));
-        } else {
-            *entry -= amount;
+impl Default for DeathNote {
+    fn default() -> Self {
+        Self {
+            ink: DEATH_NOTE_INK,
+            pending: Vec::new(),
+            fading: Vec::new(),
+            refusal_achievement: false,
         }
     }
+}
 
-    /// Apply a melee attack from the player to a nearby wildlife entity, if any matches the target hint.
-    /// Returns a descriptive message if an attack occurred.
-    pub fn attack_nearby_wildlife(
-        &mut self,
-        map: &WorldMap,
-        _weapon: &Item,
-        base_damage: f32,
-        target_hint: Option<&str>,
-    ) -> Option<String> {
-        let pos = self.player.position;
-        let hint = target_hint
-            .map(|s| s.to_lowercase())
-            .unwrap_or_else(|| String::new());
+/// Environment variable listing one-time seeds a new game should leave out,
+/// comma separated (e.g. "starter_pig,tutorial_reward").
+pub const SKIP_SEEDS_ENV: &str = "RUBBER_DUCK_SKIP_SEEDS";
+
+/// One-time setup a world gets when it is first made. Each runs at most once
+/// per world, so reloading never puts back what the player ate, moved or
+/// butchered, and a scenario can leave any of them out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Seed {
+    /// Kettle, tea cup and herbs in the cabin.
+    TeaThings,
+    /// The pig carcass just south of the cabin.
+    StarterPig,
+    /// The bundle that appears once the tutorial book is finished.
+    TutorialReward,
+}
 
-        let mut candidate_index: Option<usize> = None;
-        let mut candidate_distance = f32::MAX;
+impl Seed {
+    pub const ALL: [Seed; 3] = [Seed::TeaThings, Seed::StarterPig, Seed::TutorialReward];
 
-        for (idx, w) in self.wildlife.iter().enumerate() {
-            let dist = pos.distance_to(&w.position);
-            if dist > 1.6 {
-                continue;
-            }
-            if let Some((r, c)) = w.position.as_usize() {
-                if !map.is_walkable(r, c) {
-                    continue;
-                }
+    pub fn key(self) -> &'static str {
+        match self {
+            Seed::TeaThings => "tea_things",
+            Seed::StarterPig => "starter_pig",
+            Seed::TutorialReward => "tutorial_reward",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Seed> {
+        let s = s.trim().to_lowercase().replace([' ', '-'], "_");
+        Self::ALL.into_iter().find(|seed| seed.key() == s)
+    }
+}
+
+/// Seeds a new game should skip, from `RUBBER_DUCK_SKIP_SEEDS`.
+pub fn skipped_seeds_from_env() -> HashSet<Seed> {
+    let Ok(v) = std::env::var(SKIP_SEEDS_ENV) else {
+        return HashSet::new();
+    };
+    v.split(',')
+        .filter(|s| !s.trim().is_empty())
+        .filter_map(|s| {
+            let seed = Seed::parse(s);
+            if seed.is_none() {
+                tracing::warn!("Ignoring unknown seed '{}' in {}", s.trim(), SKIP_SEEDS_ENV);
             }
-            if !hint.is_empty() {
-                let name = w.species.name().to_lowercase();
-                if !name.contains(&hint) && !hint.contains(&name) && !hint.contains("animal") {
-                    continue;
-                }
-            }
-            if dist < candidate_distance {
-                candidate_distance = dist;
-                candidate_index = Some(idx);
-            }
-        }
+            seed
+        })
+        .collect()
+}
 
-        let idx = candidate_index?;
-        if idx >= self.wildlife.len() {
-            return None;
-        }
+/// Health regained by the body on its own today, so one long evening by the
+/// fire can't undo any amount of harm.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HealthRegen {
+    pub day: u32,
+    pub gained: f32,
+}
 
-        let mut rng = rand::thread_rng();
-        let w = &mut self.wildlife[idx];
-        let name = w.species.name();
-        let hit = match w.body.apply_random_damage(&mut rng, base_damage) {
-            Some(hit) => hit,
-            None => return None,
-        };
+/// One of the basics the cabin tutorial teaches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Practice {
+    LitFire,
+    CookedFish,
+    FelledTree,
+}
 
-        // Sync a coarse overall health ratio into the global health bar for now
-        let overall_ratio = w.body.overall_health_ratio();
-        if overall_ratio <= 0.0 {
-            // nothing special; corpse will be spawned below
-        }
+/// How often the player has done each of the tutorial's basics, so someone
+/// who learns by doing gets credit without reading the book.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PracticeTally {
+    #[serde(default)]
+    pub fires_lit: u32,
+    #[serde(default)]
+    pub fish_cooked: u32,
+    #[serde(default)]
+    pub trees_felled: u32,
+}
 
-        let message = w.body.describe_hit(&hit, name);
+impl PracticeTally {
+    pub fn covers_tutorial(&self) -> bool {
+        self.fires_lit > 0 && self.fish_cooked > 0 && self.trees_felled > 0
+    }
+}
 
-        let killed = w.body.is_vital_broken();
-        if killed {
-            let body_snapshot = w.body.clone();
-            let corpse = WorldObject::new(ObjectKind::Corpse(Corpse {
-                species: w.species,
-                freshness: 0,
-                body: Some(body_snapshot),
-            }));
-            let id = format!("corpse-{}-{}", name, self.objects.placed.len());
-            self.objects.add(id, w.position, corpse);
+/// Where a line goes in, which decides what can come out of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FishingSpot {
+    /// Lake water along the shore, or the raft moored close in.
+    Shallows,
+    /// Open lake with water on every side, out of reach except by raft.
+    DeepWater,
+    /// The desert pool: small fish, but plenty of them.
+    Oasis,
+    /// The lake's frozen margin on the snowy shore, or anywhere in a hard frost.
+    IceHole,
+}
 
-            self.wildlife.remove(idx);
+impl FishingSpot {
+    /// Classify the water the player would cast into from `pos`; `None`
+    /// if there is none within reach.
+    pub fn at(map: &WorldMap, pos: &Position, afloat: bool, temperature: f32) -> Option<Self> {
+        let around: Vec<&Tile> = (-1..=1)
+            .flat_map(|dr| (-1..=1).map(move |dc| Position::new(pos.row + dr, pos.col + dc)))
+            .filter_map(|p| map.tile_at(&p))
+            .collect();
+        let water = |t: &&Tile| matches!(t.biome, Biome::Lake | Biome::Oasis);
+        if !around.iter().any(water) {
+            return None;
+        }
+        if afloat {
+            let open = around.len() == 9 && around.iter().all(|t| t.tile_type == TileType::Lake);
+            return Some(if open {
+                FishingSpot::DeepWater
+            } else {
+                FishingSpot::Shallows
+            });
         }
+        if around.iter().any(|t| t.biome == Biome::Oasis) {
+            return Some(FishingSpot::Oasis);
+        }
+        let snowy_shore = map.biome_at(pos) == Some(Biome::WinterForest);
+        Some(if snowy_shore || temperature < 0.0 {
+            FishingSpot::IceHole
+        } else {
+            FishingSpot::Shallows
+        })
+    }
 
-        // Small chance to improve survival skill through direct hunting practice
-        if rng.gen_bool(0.3) {
-            self.player.skills.improve("survival", 1);
+    /// How the catch is introduced in the result text.
+    pub fn phrase(&self) -> &'static str {
+        match self {
+            FishingSpot::Shallows => "from the reedy shallows",
+            FishingSpot::DeepWater => "out of the deep water under the raft",
+            FishingSpot::Oasis => "from the clear oasis pool",
+            FishingSpot::IceHole => "through a hole chipped in the shore ice",
         }
+    }
+}
 
-        // Slight mood impact depending on outcome
-        if killed {
-            self.player.modify_mood(-2.0);
-        } else {
-            self.player.modify_mood(-1.0);
+/// Small restorative rituals (skipping stones, watching ripples...) and how
+/// often each has been done today. Repeats the same day give less back.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RitualLog {
+    #[serde(default)]
+    pub day: u32,
+    #[serde(default)]
+    pub counts: HashMap<String, u32>,
+    /// Feel for skipping stones; grows with practice and fades overnight.
+    #[serde(default)]
+    pub stone_knack: f32,
+    #[serde(default)]
+    pub perfect_skip: bool,
+}
+
+impl RitualLog {
+    /// Record one performance and return the reward multiplier for it.
+    pub fn perform(&mut self, day: u32, ritual: &str) -> f32 {
+        if day != self.day {
+            self.day = day;
+            self.counts.clear();
+            self.stone_knack *= 0.5;
         }
+        let count = self.counts.entry(ritual.to_string()).or_insert(0);
+        let multiplier = 0.6f32.powi(*count as i32);
+        *count += 1;
+        multiplier
+    }
+}
 
-        Some(message)
+/// JSON object keys have to be strings, so maps keyed by a position are
+/// saved as a list of pairs. Saves written before that hold an empty object.
+mod position_map {
+    use crate::world::Position;
+    use serde::de::IgnoredAny;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashMap;
+
+    pub fn serialize<S, V>(map: &HashMap<Position, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        V: Serialize,
+    {
+        serializer.collect_seq(map.iter())
     }
 
-    fn update_player_cognition(&mut self) {
-        let body = &self.player.body;
-        let head_ratio = body.head_health_ratio();
-        let health_ratio = (self.player.health / 100.0).clamp(0.0, 1.0);
-        let energy_ratio = (self.player.energy / 100.0).clamp(0.0, 1.0);
+    pub fn deserialize<'de, D, V>(deserializer: D) -> Result<HashMap<Position, V>, D::Error>
+    where
+        D: Deserializer<'de>,
+        V: Deserialize<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Saved<V> {
+            Pairs(Vec<(Position, V)>),
+            Legacy(IgnoredAny),
+        }
+        Ok(match Saved::deserialize(deserializer)? {
+            Saved::Pairs(pairs) => pairs.into_iter().collect(),
+            Saved::Legacy(_) => HashMap::new(),
+        })
+    }
+}
 
-        let mut cognition = 100.0;
+/// Ten-minute steps for a picked-over node to grow one charge back.
+const FORAGE_REGROW_STEPS: f32 = 36.0;
+/// How long a node stripped bare grows back thinner: two days.
+const FORAGE_SCAR_STEPS: f32 = 288.0;
+/// How long watering or ash keeps a node growing faster: a day.
+const FORAGE_TEND_STEPS: f32 = 144.0;
 
-        // Head injuries have the largest impact
-        let head_penalty = (1.0 - head_ratio) * 40.0;
-        // Low energy makes thinking harder, especially below ~70
-        let energy_penalty = ((0.7 - energy_ratio).max(0.0) / 0.7) * 30.0;
-        // Overall poor health also drags cognition down
-        let health_penalty = ((0.8 - health_ratio).max(0.0) / 0.8) * 20.0;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForageNode {
+    pub charges: u8,
+    pub cooldown: u8,
+    /// Charges the node holds when fully grown. Zero on saves from before
+    /// nodes regrew; filled in on the next tick.
+    #[serde(default)]
+    pub capacity: u8,
+    /// Progress toward the next charge coming back.
+    #[serde(default)]
+    pub growth: f32,
+    /// Ten-minute steps left before a node that was stripped bare can grow
+    /// back to its full size.
+    #[serde(default)]
+    pub scarred: f32,
+    /// Ten-minute steps left of the faster growth that watering or ash gives.
+    #[serde(default)]
+    pub tended: f32,
+}
 
-        cognition -= head_penalty + energy_penalty + health_penalty;
-        self.player.cognition = cognition.clamp(0.0, 100.0);
+impl ForageNode {
+    pub fn new(biome: Biome, world_seed: u64, pos: Position) -> Self {
+        let charges = Self::fresh_capacity(biome, world_seed, pos);
+        Self {
+            charges,
+            cooldown: 0,
+            capacity: charges,
+            growth: 0.0,
+            scarred: 0.0,
+            tended: 0.0,
+        }
     }
 
-    /// Butcher a corpse at the player's current position, if any, yielding resources and updating state.
-    pub fn butcher_corpse_at_player(&mut self, _weapon: &Item) -> Option<String> {
-        let pos = self.player.position;
+    /// What an untouched patch holds. The world's seed and the tile decide
+    /// it, so a node forgotten once it has grown back comes back the same.
+    fn fresh_capacity(biome: Biome, world_seed: u64, pos: Position) -> u8 {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(
+            world_seed ^ ((pos.row as i64 as u64) << 40) ^ ((pos.col as i64 as u64) << 20),
+        );
+        match biome {
+            Biome::Desert => rng.gen_range(1..=2),
+            Biome::Oasis => rng.gen_range(3..=4),
+            Biome::WinterForest => rng.gen_range(2..=3),
+            Biome::Lake => rng.gen_range(3..=5),
+            Biome::BambooGrove => rng.gen_range(3..=5),
+            _ => rng.gen_range(4..=6),
+        }
+    }
 
-        let mut found_index: Option<usize> = None;
+    /// The most the node will grow back to right now: about half while it
+    /// is still recovering from being stripped bare.
+    pub fn ceiling(&self) -> u8 {
+        if self.scarred > 0.0 {
+            self.capacity.div_ceil(2)
+        } else {
+            self.capacity
+        }
+    }
 
-        for (idx, po) in self.objects.placed.iter().enumerate() {
-            if po.position == pos {
-                if let ObjectKind::Corpse(c) = &po.object.kind {
-                    found_index = Some(idx);
-                    break;
-                }
-            }
+    /// Whether the last forager left at least half of it standing.
+    pub fn spared(&self) -> bool {
+        self.charges * 2 >= self.capacity
+    }
+
+    /// Take one charge. Stripping the last of it scars the node.
+    pub fn harvest(&mut self) {
+        self.charges = self.charges.saturating_sub(1);
+        self.growth = 0.0;
+        if self.charges == 0 {
+            self.cooldown = 12;
+            self.scarred = FORAGE_SCAR_STEPS;
         }
+    }
 
-        let idx = found_index?;
-        let (species, freshness) = match &self.objects.placed.get(idx)?.object.kind {
-            ObjectKind::Corpse(c) => (c.species, c.freshness),
-            _ => return None,
-        };
+    /// Water or ash worked in around the roots. False if it has all the
+    /// help it can use already or nothing to recover from.
+    pub fn tend(&mut self) -> bool {
+        if self.tended > 0.0 || !self.recovering() {
+            return false;
+        }
+        self.tended = FORAGE_TEND_STEPS;
+        true
+    }
 
-        let (base_meat, base_hide, base_fat) = match species {
-            Species::Deer | Species::Caribou => (6, 2, 2),
-            Species::Wolf | Species::Fox | Species::DesertFox | Species::SnowFox => (4, 1, 2),
-            Species::SnowHare | Species::Rabbit => (2, 1, 1),
-            _ => (3, 1, 1),
-        };
+    /// Still short of what it held before anyone foraged here.
+    pub fn recovering(&self) -> bool {
+        self.charges < self.capacity || self.scarred > 0.0
+    }
 
-        // Simple freshness stages: fresh, aging, and spoiled.
-        let (meat, hide, fat, freshness_note) = if freshness < 30 {
-            (base_meat, base_hide, base_fat, None)
-        } else if freshness < 90 {
-            let meat = ((base_meat as f32) * 0.6).round() as i32;
-            let fat = ((base_fat as f32) * 0.7).round() as i32;
-            (
-                meat.max(0),
-                base_hide,
-                fat.max(0),
-                Some(
-                    "The carcass is no longer freshly killed, but you trim away the worst parts and salvage what you can.",
-                ),
+    /// Fully grown back with nothing pending, and the size a fresh node on
+    /// this tile would be: no different from a patch nobody has touched, so
+    /// it needn't be remembered.
+    pub fn settled(&self, biome: Biome, world_seed: u64, pos: Position) -> bool {
+        self.capacity == Self::fresh_capacity(biome, world_seed, pos)
+            && !self.recovering()
+            && self.cooldown == 0
+            && self.tended == 0.0
+    }
+
+    /// Whether new growth is far enough along to be seen.
+    pub fn sprouting(&self) -> bool {
+        self.charges > 0 && self.charges < self.ceiling() && self.growth >= 0.5
+    }
+
+    /// What the player can see of the node recovering, without needing a
+    /// forager's eye.
+    fn regrowth_line(&self, brush: &str) -> Option<String> {
+        let mut line = if self.charges > 0 && self.scarred > 0.0 {
+            format!(
+                "Young growth is coming up where you stripped the {} bare, but it's coming back thin.",
+                brush
             )
-        } else {
-            let meat = 0;
-            let fat = (base_fat / 2).max(0);
-            (
-                meat,
-                base_hide,
-                fat,
-                Some(
-                    "Most of the meat has spoiled; you focus on hide and whatever fat still seems safe.",
-                ),
+        } else if self.sprouting() {
+            format!(
+                "New shoots are pushing up in the {} where you foraged last.",
+                brush
             )
+        } else {
+            String::new()
         };
-
-        if meat == 0 && hide == 0 && fat == 0 {
-            // Even a spoiled carcass at least teaches you what rot looks like.
-            if let Some(po) = self.objects.placed.get_mut(idx) {
-                po.object.kind =
-                    ObjectKind::GenericStructure("picked-over remains".to_string());
+        if self.tended > 0.0 {
+            if !line.is_empty() {
+                line.push(' ');
             }
-            return Some(
-                "This carcass has spoiled too far to yield anything useful. You leave only scattered bones and feathers behind."
-                    .to_string(),
-            );
+            line.push_str("The soil around the roots is dark where you tended it.");
         }
+        Some(line).filter(|l| !l.is_empty())
+    }
 
-        if meat > 0 {
-            self.player.inventory.add(Item::RawMeat, meat as u32);
+    /// `scale` is how much of a ten-minute step this tick covers.
+    pub fn tick(&mut self, biome: Biome, world_seed: u64, pos: Position, scale: f32) {
+        if self.capacity == 0 {
+            self.capacity = self
+                .charges
+                .max(Self::fresh_capacity(biome, world_seed, pos));
         }
-        if hide > 0 {
-            self.player.inventory.add(Item::RawHide, hide as u32);
+        // Tending doubles the pace of everything below.
+        let pace = if self.tended > 0.0 { 2.0 } else { 1.0 };
+        self.tended = (self.tended - scale).max(0.0);
+        self.scarred = (self.scarred - scale * pace).max(0.0);
+
+        if self.charges == 0 && self.cooldown > 0 {
+            self.cooldown = self.cooldown.saturating_sub(pace as u8);
+            if self.cooldown == 0 {
+                self.charges = self.ceiling();
+            }
+            return;
         }
-        if fat > 0 {
-            self.player.inventory.add(Item::AnimalFat, fat as u32);
+        if self.charges >= self.ceiling() {
+            self.growth = 0.0;
+            return;
         }
-
-        self.player.skills.improve("survival", 2);
-        self.player.skills.improve("tailoring", 1);
-        self.player.modify_energy(-5.0);
-
-        if let Some(po) = self.objects.placed.get_mut(idx) {
-            po.object.kind =
-                ObjectKind::GenericStructure("picked-over remains".to_string());
+        // A node left half standing recovers twice as fast.
+        let rate = if self.spared() { 2.0 } else { 1.0 };
+        self.growth += scale * pace * rate / FORAGE_REGROW_STEPS;
+        if self.growth >= 1.0 {
+            self.growth -= 1.0;
+            self.charges += 1;
         }
-
-        let base_text =
-            "You carefully butcher the carcass, setting aside meat, hide, and fat for later use.";
-        let message = match freshness_note {
-            Some(note) => format!("{} {}", note, base_text),
-            None => base_text.to_string(),
-        };
-
-        Some(message)
     }
+}
 
-    pub fn refresh_blueprint_knowledge(&mut self, push_messages: bool) {
-        let tutorial_done = self.book_completed(TUTORIAL_BOOK_ID);
-        let fishing_done = self.book_completed(FISHING_BOOK_ID);
-        let active_target = self.player.active_project.as_ref().map(|bp| bp.target_item);
-
-        let add_if = |state: &mut Self, item: Item, condition: bool, reason: &str| {
-            if !(condition || active_target == Some(item)) {
-                return;
-            }
-            if state.player.known_blueprints.insert(item) && push_messages {
-                state.pending_messages.push(format!(
-                    "You learned the {} blueprint. {}",
-                    item.name(),
-                    reason
-                ));
-            }
-        };
+/// A place the ancient map draws. See `GameState::map_landmarks`.
+struct Landmark {
+    note: &'static str,
+    name: &'static str,
+    meaning: &'static str,
+    pos: Position,
+    radius: i32,
+}
 
-        add_if(
-            self,
-            Item::StoneKnife,
-            self.player.skills.survival >= 8,
-            "Basic survival practice reveals how to knap and lash a knife.",
-        );
-        add_if(
-            self,
-            Item::Cordage,
-            self.player.skills.tailoring >= 8,
-            "You recognize how to twist plant fibers into rope.",
-        );
-        add_if(
-            self,
-            Item::Campfire,
-            self.player.skills.fire_making >= 8 || self.player.skills.survival >= 8,
-            "Fire-making fundamentals click into place.",
-        );
-        add_if(
-            self,
-            Item::StoneAxe,
-            self.player.skills.woodcutting >= 12 || tutorial_done,
-            "Woodcutting skill or completing the cabin tutorial reveals axe joinery.",
-        );
-        add_if(
-            self,
-            Item::FishingRod,
-            fishing_done,
-            "Finishing the Book of Fishing shows how to lash a simple rod.",
-        );
-        add_if(
-            self,
-            Item::Raft,
-            self.player.skills.survival >= 20,
-            "Survival practice teaches how to lash a sturdy raft from logs and cordage.",
-        );
-    }
+/// The complete game state that gets saved/loaded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameState {
+    pub version: String,
+    /// Seed the map was generated from; the map itself isn't saved, so this
+    /// is how a reload gets the same world back.
+    #[serde(default)]
+    pub world_seed: u64,
+    /// Half-width of the map, recorded so a reload lays positions out the same.
+    #[serde(default = "GameState::default_map_extent")]
+    pub map_extent: i32,
+    pub time: WorldTime,
+    pub weather: RegionalWeather,
+    pub player: Player,
+    pub wildlife: Vec<Wildlife>,
+    #[serde(default)]
+    pub objects: ObjectRegistry,
+    #[serde(default)]
+    pub custom_names: HashMap<Item, String>,
+    #[serde(default, with = "position_map")]
+    pub forage_nodes: HashMap<Position, ForageNode>,
+    #[serde(default = "GameState::default_books")]
+    pub books: HashMap<String, BookEntry>,
+    #[serde(default = "GameState::default_next_book_id")]
+    pub next_book_id: u32,
+    /// How many of the ancient map's landmarks the player has studied out.
+    #[serde(default)]
+    pub map_studies: u8,
+    #[serde(default)]
+    pub card_case_cards_inside: u8,
+    #[serde(default)]
+    pub card_case_open: bool,
+    #[serde(default)]
+    pub card_scatter_achievement: bool,
+    /// Cards known to have been lost for good (thrown into the lake).
+    #[serde(default)]
+    pub cards_lost: u32,
+    #[serde(default)]
+    pub cards_regenerated: bool,
+    /// Tiles whose hidden detail the player has turned up by searching.
+    #[serde(default)]
+    pub noticed_details: HashSet<Position>,
+    /// Tiles whose hidden detail has already been examined and used up.
+    #[serde(default)]
+    pub claimed_details: HashSet<Position>,
+    /// Logs and firewood in the pack that are still green.
+    #[serde(default)]
+    pub carried_green_wood: GreenWood,
+    /// Minutes the fireflies in the jar have left.
+    #[serde(default)]
+    pub firefly_jar_minutes: u32,
+    #[serde(default)]
+    pub health_regen: HealthRegen,
+    #[serde(default)]
+    pub rescue: RescueGoal,
+    #[serde(default)]
+    pub sands: DesertSands,
+    #[serde(default)]
+    pub wildfire: Wildfire,
+    /// What's grilling over the cabin fire, if anything.
+    #[serde(default)]
+    pub on_the_fire: Option<OnTheFire>,
+    #[serde(default)]
+    pub cooked_portions: CookedPortions,
+    #[serde(default)]
+    pub tool_tally: ToolTally,
+    #[serde(default)]
+    pub region_names: RegionNames,
+    /// Where each keepsake or tool was first picked up.
+    #[serde(default)]
+    pub item_origins: HashMap<Item, String>,
+    #[serde(default)]
+    pub death_note: DeathNote,
+    /// Set while the player sleeps, so ticks recover faster.
+    #[serde(skip)]
+    pub sleeping: bool,
+    /// Set while a strenuous action plays out; the body doesn't mend mid-effort.
+    #[serde(skip)]
+    pub exerting: bool,
+    /// Set while the clock runs without the player deciding anything (simulate).
+    #[serde(skip)]
+    pub idle_ticking: bool,
+    /// How often chance happenings (raids, night visitors, newcomers at the
+    /// edges) come, as a share of usual; a quiet stretch of simulate turns
+    /// it down.
+    #[serde(skip, default = "GameState::usual_event_rate")]
+    pub event_rate: f64,
+    /// Raised when idle ticking brings hunger or thirst to the brink; needs stop
+    /// draining until the player acts again.
+    #[serde(default)]
+    pub urgent_need: Option<String>,
+    #[serde(default)]
+    pub vital_bands: VitalBands,
+    #[serde(default)]
+    pub duck_throw_achievement: bool,
+    #[serde(default)]
+    pub rituals: RitualLog,
+    #[serde(default)]
+    pub last_dawn_summary_day: u32,
+    /// The region and weather the player was last warned about on stepping out.
+    #[serde(default)]
+    pub advised_weather: Option<(Region, Weather)>,
+    /// Milestone snapshots taken since the last save, waiting to be written.
+    #[serde(skip)]
+    pub pending_snapshots: Vec<PendingSnapshot>,
+    #[serde(default)]
+    pub tutorial_reward_claimed: bool,
+    /// One-time seeds already laid down, or skipped by the scenario.
+    #[serde(default = "GameState::legacy_seeds")]
+    pub seeded: HashSet<Seed>,
+    #[serde(default)]
+    pub tutorial_hint_shown: bool,
+    #[serde(default)]
+    pub practice: PracticeTally,
+    /// The tutorial bundle came from doing the basics, not reading about them.
+    #[serde(default)]
+    pub tutorial_by_practice: bool,
+    /// Builds set aside for later, each keeping the materials already in it.
+    #[serde(default)]
+    pub paused_projects: Vec<Blueprint>,
+    /// The number the last laid-out project was given.
+    #[serde(default)]
+    pub last_project_id: u32,
+    /// The number the last crafted seat set down outdoors was given.
+    #[serde(default)]
+    pub last_seat_id: u32,
+    /// The duck's weekly letters and the week it's keeping track of.
+    #[serde(default)]
+    pub letters: DuckLetters,
+    /// Dishes cooked at least once, in the order they were first made.
+    #[serde(default)]
+    pub cooked_dishes: Vec<Item>,
+    #[serde(default)]
+    pub recipe_book_id: Option<String>,
+    #[serde(default)]
+    pub kills: Vec<KillRecord>,
+    #[serde(default)]
+    pub first_kills: Vec<Species>,
+    /// Species sketched into the journal from close up by approaching them.
+    #[serde(default)]
+    pub sketched_species: Vec<Species>,
+    /// Recent catches per fishing spot, easing off with time. Heavily
+    /// fished water gets stingy.
+    #[serde(default)]
+    pub fishing_pressure: HashMap<FishingSpot, f32>,
+    /// Fox tracks left by raided food, until they go cold.
+    #[serde(default)]
+    pub raid_tracks: Vec<RaidTracks>,
+    /// Species seen so far, in the order they were first noted.
+    #[serde(default)]
+    pub field_notes: Vec<FieldNote>,
+    #[serde(default)]
+    pub field_notes_id: Option<String>,
+    /// Reflective entries written in response to what the player has done.
+    #[serde(default)]
+    pub journal: Vec<String>,
+    /// Mood applied each tick while `lingering_ticks` lasts (regret or peace).
+    #[serde(default)]
+    pub lingering_mood: f32,
+    #[serde(default)]
+    pub lingering_ticks: u32,
+    // Runtime state (not critical to save but nice to have)
+    #[serde(default)]
+    pub pending_messages: Vec<String>,
+    #[serde(default, rename = "cabin")]
+    #[serde(skip_serializing)]
+    legacy_cabin: Option<Cabin>,
+    #[serde(default, rename = "wood_shed")]
+    #[serde(skip_serializing)]
+    legacy_wood_shed: Option<WoodShed>,
+    #[serde(default, rename = "trees")]
+    #[serde(skip_serializing)]
+    legacy_trees: Option<Vec<Tree>>,
+}
 
-    fn ensure_book_registry(&mut self) {
-        let mut insert_if_missing = |id: &str, title: &str, pages: Vec<&str>, writable: bool| {
-            if !self.books.contains_key(id) {
-                self.books.insert(
-                    id.to_string(),
-                    BookEntry {
-                        id: id.to_string(),
-                        title: title.to_string(),
-                        pages: pages.into_iter().map(|p| p.to_string()).collect(),
-                        writable,
-                    },
-                );
-            }
-        };
+impl GameState {
+    pub fn default_books() -> HashMap<String, BookEntry> {
+        HashMap::new()
+    }
 
-        insert_if_missing(
-            TUTORIAL_BOOK_ID,
-            "Cabin Tutorial",
-            vec![
-                "Welcome to the cabin. As you cross the threshold, a voice you don't quite own whispers: 'Mortal, read this tutorial book from the first page to the very last. If you ignore it, this world will kill you slowly.' Start simple: use hands on bush to forage for sticks, fibers, berries and herbs. Small piles add up.",
-                "To light a fire, you usually need three things: chopped firewood, kindling or tinder, and a way to spark.",
-                "The wood shed holds logs and an axe. Inside the shed, use axe on block to split logs into firewood. Logs don't last forever.",
-                "You'll also need more logs in the long run. Outside, move next to a tree and use axe on tree. Heavy swings cost energy.",
-                "Once you have fuel, go to the cabin hearth and use kindling on fire to lay a base. Then use matchbox on fire when you're ready.",
-                "If the fire dies, you can add fuel later: use firewood on fire or toss in dry sticks, bark, or very old books you don't mind losing.",
-                "For hunger, you can fish, forage, or shake fruit. Near the lake, even bare hands can sometimes pull a fish from the shallows.",
-                "Try use hands on water or near the shore and pay attention to ripples and timing. A steady rhythm often helps.",
-                "If you catch a fish, go back to the cabin. Stand by the hearth with a lit fire and use fish on fire to grill a simple meal.",
-                "Some trees bear fruit. If you find an apple tree or other fruit tree, sometimes a good kick is enough to drop a snack.",
-                "In gentle time, fruit slowly returns. Don't strip every tree bare at once; patience feeds you twice.",
-                "Books, maps and strange objects in the cabin hint at deeper systems. Not all of them explain themselves immediately.",
-                "If you feel lost, look around, meditate by the lake, or talk to the rubber duck. Sometimes the quiet answers first.",
-                "On the path just south of the cabin, you may notice a small carcass and a simple knife nearby. When you feel ready, stand by the carcass, 'take knife', then 'use knife on carcass' (or 'use knife on pig') to practice butchering and carry the meat inside to cook over the hearth.",
-                "You can use 'examine self' any time to check your condition. 'examine pig' or 'examine deer' will show how injured nearby animals are (and if several share your tile, they'll all be listed), and standing on a carcass then typing 'examine corpse' will show which parts were hurt the most.",
-                "If a dog or cat learns to trust you enough to follow you, you can give it a proper name with commands like 'name dog 멍멍이' or 'name cat 나비' while it is nearby.",
-            ],
-            false,
-        );
-        insert_if_missing(
-            OLD_BOOK_ID,
-            "Weathered Journal",
-            vec![
-                "The cabin creaks but endures. The lake stays still even in wind.",
-                "Someone underlined a phrase: 'Keep writing; the ink remembers what you might forget.'",
-            ],
-            false,
-        );
-        insert_if_missing(
-            DEATH_NOTE_ID,
-            "Death Note",
-            vec!["The human whose name is written in this note shall die."],
-            true,
-        );
-        insert_if_missing(
-            FISHING_BOOK_ID,
-            "Book of Fishing",
-            vec![
-                "A simple rod needs a straight pole, a bendable tip, and cordage tied in clean knots. Bamboo or a stiff stick will do.",
-                "Fish cruise the shallows at dawn and dusk. In storms they sink deep and hide; in clear weather, keep quiet and watch for ripples.",
-                "Close the book and you can almost feel the rhythm of casting. You think you could craft a wooden fishing rod now.",
-            ],
-            false,
-        );
+    pub fn default_next_book_id() -> u32 {
+        1
+    }
 
-        let max_seen = self
-            .books
-            .keys()
-            .filter_map(|k| k.strip_prefix("book-"))
-            .filter_map(|n| n.parse::<u32>().ok())
-            .max()
-            .unwrap_or(0);
-        if self.next_book_id <= max_seen {
-            self.next_book_id = max_seen + 1;
-        }
+    pub fn cabin_state(&self) -> Option<&Cabin> {
+        self.objects.find("cabin").and_then(|p| p.object.as_cabin())
     }
 
-    fn ensure_cabin_books(&mut self) {
-        let Some(cabin) = self.cabin_state_mut() else {
-            return;
-        };
-        let ensure = |cabin: &mut Cabin, id: &str, item: Item| {
-            if !cabin.book_ids.iter().any(|b| b == id) {
-                cabin.book_ids.push(id.to_string());
-            }
-            if !cabin.items.contains(&item) {
-                cabin.items.push(item);
-            }
-        };
-        ensure(cabin, TUTORIAL_BOOK_ID, Item::TutorialBook);
-        ensure(cabin, OLD_BOOK_ID, Item::OldBook);
-        ensure(cabin, DEATH_NOTE_ID, Item::DeathNote);
-        ensure(cabin, FISHING_BOOK_ID, Item::BookOfFishing);
+    pub fn cabin_state_mut(&mut self) -> Option<&mut Cabin> {
+        self.objects
+            .find_mut("cabin")
+            .and_then(|p| p.object.as_cabin_mut())
     }
 
-    pub fn generate_book_id(&mut self) -> String {
-        let id = format!("book-{}", self.next_book_id);
-        self.next_book_id += 1;
-        id
+    pub fn wood_shed_state(&self) -> Option<&WoodShed> {
+        self.objects
+            .find("wood_shed")
+            .and_then(|p| p.object.as_wood_shed())
     }
 
-    pub fn book_entry(&self, id: &str) -> Option<&BookEntry> {
-        self.books.get(id)
+    pub fn wood_shed_state_mut(&mut self) -> Option<&mut WoodShed> {
+        self.objects
+            .find_mut("wood_shed")
+            .and_then(|p| p.object.as_wood_shed_mut())
     }
 
-    pub fn book_entry_mut(&mut self, id: &str) -> Option<&mut BookEntry> {
-        self.books.get_mut(id)
+    pub fn table_surface(&self) -> Option<&ObjectSurface> {
+        self.objects
+            .find(TABLE_ID)
+            .and_then(|p| p.object.surface.as_ref())
     }
 
-    pub fn register_book(&mut self, entry: BookEntry) -> String {
-        let id = entry.id.clone();
-        self.books.insert(id.clone(), entry);
-        id
+    pub fn table_surface_mut(&mut self) -> Option<&mut ObjectSurface> {
+        self.objects
+            .find_mut(TABLE_ID)
+            .and_then(|p| p.object.surface.as_mut())
     }
 
-    pub fn player_has_book(&self, id: &str) -> bool {
-        self.player.book_ids.iter().any(|b| b == id)
+    pub fn mantel_surface(&self) -> Option<&ObjectSurface> {
+        self.objects
+            .find(MANTEL_ID)
+            .and_then(|p| p.object.surface.as_ref())
     }
 
-    pub fn add_player_book(&mut self, id: &str) {
-        if !self.player.book_ids.iter().any(|b| b == id) {
-            self.player.book_ids.push(id.to_string());
-        }
+    pub fn mantel_surface_mut(&mut self) -> Option<&mut ObjectSurface> {
+        self.objects
+            .find_mut(MANTEL_ID)
+            .and_then(|p| p.object.surface.as_mut())
     }
 
-    pub fn remove_player_book(&mut self, id: &str) -> bool {
-        if let Some(pos) = self.player.book_ids.iter().position(|b| b == id) {
-            self.player.book_ids.remove(pos);
-            return true;
-        }
-        false
+    pub fn surface(&self, id: &str) -> Option<&ObjectSurface> {
+        self.objects
+            .find(id)
+            .and_then(|p| p.object.surface.as_ref())
     }
 
-    pub fn pop_any_player_book(&mut self) -> Option<String> {
-        self.player.book_ids.pop()
+    pub fn surface_mut(&mut self, id: &str) -> Option<&mut ObjectSurface> {
+        self.objects
+            .find_mut(id)
+            .and_then(|p| p.object.surface.as_mut())
     }
 
-    pub fn book_id_for_item<'a>(&self, item: &'a Item) -> Option<&'a str> {
-        match item {
-            Item::TutorialBook => Some(TUTORIAL_BOOK_ID),
-            Item::OldBook => Some(OLD_BOOK_ID),
-            Item::DeathNote => Some(DEATH_NOTE_ID),
-            Item::BookOfFishing => Some(FISHING_BOOK_ID),
+    /// Where a drop lands from where the player stands: the furniture at
+    /// their cabin spot, or the workbench in the shed. `None` means the floor.
+    pub fn drop_surface(&self) -> Option<&'static str> {
+        match (&self.player.room, self.player.cabin_spot) {
+            (Some(Room::CabinMain), Some(CabinSpot::Table)) => Some(TABLE_ID),
+            (Some(Room::CabinMain), Some(CabinSpot::Hearth)) => Some(HEARTH_BASKET_ID),
+            (Some(Room::CabinMain), Some(CabinSpot::Window)) => Some(WINDOW_SILL_ID),
+            (Some(Room::WoodShed), _) => Some(WORKBENCH_ID),
             _ => None,
         }
     }
 
-    pub fn take_cabin_book_for_item(&mut self, item: &Item) -> Option<String> {
-        let id_hint = self.book_id_for_item(item).map(|s| s.to_string());
-        let Some(cabin) = self.cabin_state_mut() else {
-            return None;
-        };
-        if let Some(id) = id_hint {
-            if let Some(pos) = cabin.book_ids.iter().position(|b| b == &id) {
-                return Some(cabin.book_ids.remove(pos));
-            }
-        }
-        if matches!(item, Item::Book) {
-            return cabin.book_ids.pop();
+    /// Every surface in a room that things can be taken from, in search order.
+    pub fn room_surfaces(room: &Room) -> &'static [&'static str] {
+        match room {
+            Room::CabinMain => &[TABLE_ID, MANTEL_ID, HEARTH_BASKET_ID, WINDOW_SILL_ID],
+            Room::WoodShed => &[WORKBENCH_ID],
+            Room::CabinTerrace => &[],
         }
-        None
     }
 
-    pub fn add_cabin_book(&mut self, id: String) {
-        if let Some(cabin) = self.cabin_state_mut() {
-            if !cabin.book_ids.iter().any(|b| b == &id) {
-                cabin.book_ids.push(id);
-            }
+    /// How a surface reads after "on"/"from": ("in the basket by the hearth",
+    /// "from the basket by the hearth").
+    pub fn surface_phrases(id: &str) -> (&'static str, &'static str) {
+        match id {
+            TABLE_ID => ("on the table", "from the table"),
+            MANTEL_ID => ("on the mantelpiece", "from the mantelpiece"),
+            HEARTH_BASKET_ID => ("in the basket by the hearth", "from the basket by the hearth"),
+            WINDOW_SILL_ID => ("on the window sill", "from the window sill"),
+            WORKBENCH_ID => ("on the workbench", "from the workbench"),
+            _ => ("on the surface", "from the surface"),
         }
     }
 
-    pub fn accessible_book(&self, query: &str) -> Option<&BookEntry> {
-        let q = query.to_lowercase();
-        let mut ids_to_check: Vec<String> = self.player.book_ids.clone();
-        if matches!(self.player.room, Some(Room::CabinMain)) {
-            if let Some(cabin) = self.cabin_state() {
-                ids_to_check.extend(cabin.book_ids.clone());
+    fn lay_out_tea_things(cabin: &mut Cabin) {
+        for item in [Item::TeaCup, Item::Kettle, Item::WildHerbs] {
+            if !cabin.items.contains(&item) {
+                cabin.items.add(item, 1);
             }
         }
-        for id in ids_to_check {
-            if let Some(book) = self.books.get(&id) {
-                if book.id.to_lowercase().contains(&q) || book.title.to_lowercase().contains(&q) {
-                    return Some(book);
-                }
-            }
-        }
-        None
     }
 
-    pub fn accessible_book_ids(&self) -> Vec<String> {
-        let mut ids = self.player.book_ids.clone();
-        if matches!(self.player.room, Some(Room::CabinMain)) {
-            if let Some(cabin) = self.cabin_state() {
-                ids.extend(cabin.book_ids.clone());
+    fn ensure_table_object(&mut self, table_items: LocationItems) {
+        if let Some(table) = self.objects.find_mut(TABLE_ID) {
+            if let Some(surface) = table.object.surface.as_mut() {
+                for (item, qty) in table_items.items {
+                    surface.items.add(item, qty);
+                }
+                surface.supports_mounts = true;
+                if surface.capacity.is_none() {
+                    surface.capacity = Some(8);
+                }
+            } else {
+                table.object.surface = Some(ObjectSurface {
+                    items: table_items,
+                    capacity: Some(8),
+                    supports_mounts: true,
+                });
             }
-        }
-        ids
-    }
-
-    pub fn maybe_trigger_tutorial_hint(&mut self) {
-        if self.tutorial_hint_shown {
             return;
         }
-        if !matches!(self.player.room, Some(Room::CabinMain)) {
-            return;
-        }
-        self.tutorial_hint_shown = true;
-        self.pending_messages.push(
-            "For a moment the air in the cabin thickens. A voice that is not quite yours echoes inside your skull:\n\"Mortal, read the cabin tutorial book from the first page to the very last. If you ignore it, this world will find slow, petty ways to kill you.\""
-                .to_string(),
-        );
-    }
 
-    pub fn grant_tutorial_reward_if_needed(&mut self, map: &mut WorldMap) {
-        if self.tutorial_reward_claimed {
-            return;
-        }
-        if !self.book_completed(TUTORIAL_BOOK_ID) {
-            return;
+        let mut table_obj = WorldObject::new(ObjectKind::Table);
+        if let Some(surface) = table_obj.surface.as_mut() {
+            surface.items = table_items;
+            surface.capacity = Some(8);
+            surface.supports_mounts = true;
         }
+        self.objects
+            .add(TABLE_ID, Position::new(0, 0), table_obj);
+    }
 
-        let mut dropped = false;
-
-        match self.player.room {
-            Some(Room::CabinMain) => {
-                if let Some(cabin) = self.cabin_state_mut() {
-                    cabin.add_item(Item::Knife);
-                    cabin.add_item(Item::Kindling);
-                    cabin.add_item(Item::Kindling);
-                    cabin.add_item(Item::Kindling);
-                    cabin.add_item(Item::Kindling);
-                    cabin.add_item(Item::Kindling);
-                    cabin.add_item(Item::Apple);
-                    cabin.add_item(Item::Apple);
-                    cabin.add_item(Item::Apple);
-                    cabin.add_item(Item::Apple);
-                    cabin.add_item(Item::Apple);
-                    cabin.add_item(Item::Apple);
-                    cabin.add_item(Item::Apple);
-                    cabin.add_item(Item::Apple);
-                    cabin.add_item(Item::Apple);
-                    cabin.add_item(Item::Apple);
-                    dropped = true;
-                }
-            }
-            _ => {
-                if let Some((r, c)) = self.player.position.as_usize() {
-                    if let Some(tile) = map.get_tile_mut(r, c) {
-                        tile.items.add(Item::Knife, 1);
-                        tile.items.add(Item::Kindling, 5);
-                        tile.items.add(Item::Apple, 10);
-                        dropped = true;
-                    }
-                }
+    fn ensure_seat_objects(&mut self) {
+        let seats = [
+            ("cabin_armchair", SeatKind::Armchair, Room::CabinMain),
+            ("terrace_bench", SeatKind::TerraceBench, Room::CabinTerrace),
+        ];
+        for (id, kind, room) in seats {
+            if self.objects.find(id).is_some() {
+                continue;
             }
-        }
-
-        if dropped {
-            self.tutorial_reward_claimed = true;
-            self.pending_messages.push(
-                "As you finish the cabin tutorial, a small bundle of supplies appears at your feet: 10 apples, 5 pieces of kindling, and a simple knife."
-                    .to_string(),
-            );
+            let seat = WorldObject::new(ObjectKind::Seat(Seat {
+                kind,
+                room: Some(room),
+            }));
+            self.objects.add(id, Position::new(0, 0), seat);
         }
     }
 
-    pub fn player_or_cabin_has_book(&self, id: &str) -> bool {
-        self.player.book_ids.iter().any(|b| b == id)
-            || (matches!(self.player.room, Some(Room::CabinMain))
-                && self
-                    .cabin_state()
-                    .map(|c| c.book_ids.iter().any(|b| b == id))
-                    .unwrap_or(false))
+    /// Seats the player can reach from where they stand: furniture in the
+    /// current room, or free-standing seats on the current tile outdoors.
+    pub fn seats_here(&self) -> Vec<&PlacedObject> {
+        let room = self.player.room.clone();
+        let pos = self.player.position;
+        self.objects
+            .placed
+            .iter()
+            .filter(|po| match po.object.as_seat() {
+                Some(seat) => match (&room, &seat.room) {
+                    (Some(r), Some(seat_room)) => r == seat_room,
+                    (None, None) => po.position == pos,
+                    _ => false,
+                },
+                None => false,
+            })
+            .collect()
     }
 
-    pub fn book_page(&self, id: &str) -> usize {
-        self.player.book_progress.get(id).copied().unwrap_or(0)
+    pub fn current_seat(&self) -> Option<&Seat> {
+        let id = self.player.seated.as_deref()?;
+        self.objects.find(id).and_then(|po| po.object.as_seat())
     }
 
-    pub fn set_book_page(&mut self, id: &str, page: usize) {
-        self.player.book_progress.insert(id.to_string(), page);
+    /// Seated in the armchair beside a lit hearth, or on a crafted seat
+    /// beside a built campfire outdoors.
+    pub fn seated_by_fire(&self, map: &WorldMap) -> bool {
+        let Some(seat) = self.current_seat() else {
+            return false;
+        };
+        match (seat.kind, &seat.room) {
+            (SeatKind::Armchair, Some(Room::CabinMain)) => self
+                .cabin_state()
+                .map(|c| !matches!(c.fireplace.state, FireState::Cold))
+                .unwrap_or(false),
+            (SeatKind::Stool | SeatKind::Bench, None) => map
+                .tile_at(&self.player.position)
+                .map(|t| t.items.items.iter().any(|(i, q)| *i == Item::Campfire && *q > 0))
+                .unwrap_or(false),
+            _ => false,
+        }
     }
 
-    fn book_completed(&self, id: &str) -> bool {
-        let read_page = self.book_page(id);
-        let total_pages = self.books.get(id).map(|b| b.pages.len()).unwrap_or(0);
-        total_pages > 0 && read_page >= total_pages
+    /// Standing at the hearth while the fire is lit.
+    pub fn at_hearth(&self) -> bool {
+        matches!(self.player.room, Some(Room::CabinMain))
+            && self.player.cabin_spot == Some(CabinSpot::Hearth)
+            && self
+                .cabin_state()
+                .map(|c| !matches!(c.fireplace.state, FireState::Cold))
+                .unwrap_or(false)
     }
 
-    pub fn knows_blueprint(&self, item: Item) -> bool {
-        self.player.known_blueprints.contains(&item)
+    /// Close to a lit fire, whether standing by it or seated beside it.
+    pub fn by_fire(&self, map: &WorldMap) -> bool {
+        self.at_hearth() || self.seated_by_fire(map)
     }
 
-    pub fn known_blueprint_names(&self) -> Vec<String> {
-        let mut names: Vec<String> = self
-            .player
-            .known_blueprints
-            .iter()
-            .map(|i| i.name().to_string())
-            .collect();
-        names.sort();
-        names
+    /// How much warmer or colder the player's spot is than the room as a
+    /// whole: close to a lit fire, or in the draught of an open door.
+    fn cabin_spot_warmth(&self) -> f32 {
+        match self.player.cabin_spot {
+            Some(CabinSpot::Hearth) if self.at_hearth() => 3.0,
+            Some(CabinSpot::Door) if self.cabin_state().map(|c| c.door_open).unwrap_or(false) => {
+                -2.0
+            }
+            _ => 0.0,
+        }
     }
 
-    pub fn blueprint_hint_text(&self, item: Item) -> Option<&'static str> {
-        self.blueprint_hint(item)
+    /// Get up from any seat. Returns a short line for the next action's text.
+    pub fn stand_up(&mut self) -> Option<String> {
+        let name = self.current_seat().map(|s| s.kind.name());
+        let was_seated = self.player.seated.take().is_some();
+        match (was_seated, name) {
+            (true, Some(name)) => Some(format!("You get up from the {}.", name)),
+            _ => None,
+        }
     }
 
-    fn blueprint_hint(&self, item: Item) -> Option<&'static str> {
-        match item {
-            Item::StoneAxe => {
-                Some("Raise woodcutting to 12 or finish the Cabin Tutorial to learn it.")
+    fn ensure_mantel_object(&mut self) {
+        if self.objects.find(MANTEL_ID).is_some() {
+            return;
+        }
+
+        // The curios that always sat "on the mantelpiece" move onto the real surface.
+        let mut curios = LocationItems::new();
+        if let Some(cabin) = self.cabin_state_mut() {
+            for curio in [Item::Matchbox, Item::StrangeCompass, Item::AncientMap] {
+                if cabin.take_item(&curio) {
+                    curios.add(curio, 1);
+                }
             }
-            Item::StoneKnife => Some("Build basic survival skill to unlock this."),
-            Item::Campfire => Some("Practice fire-making to level 8+ to learn this pattern."),
-            Item::Cordage => Some("Tailoring 8+ reveals how to twist cordage."),
-            Item::FishingRod => Some("Finish reading the Book of Fishing to unlock this."),
-            Item::Raft => Some("Grow your survival skill to 20+ to learn this build."),
-            _ => None,
         }
+
+        let mut mantel =
+            WorldObject::new(ObjectKind::GenericStructure("mantelpiece".to_string()));
+        mantel.size = ObjectSize::Small;
+        mantel.surface = Some(ObjectSurface {
+            items: curios,
+            capacity: Some(4),
+            supports_mounts: true,
+        });
+        self.objects.add(MANTEL_ID, Position::new(0, 0), mantel);
     }
 
-    pub fn locked_blueprint_hints(&self) -> Vec<String> {
-        let targets = [
-            Item::StoneKnife,
-            Item::Campfire,
-            Item::Cordage,
-            Item::StoneAxe,
-            Item::FishingRod,
-            Item::Raft,
+    /// The hearth basket, the window sill and the shed workbench: places to
+    /// set things down besides the table and the floor.
+    fn ensure_room_surfaces(&mut self) {
+        let surfaces = [
+            (HEARTH_BASKET_ID, "hearth basket", Position::new(0, 0), 8),
+            (WINDOW_SILL_ID, "window sill", Position::new(0, 0), 4),
+            (WORKBENCH_ID, "workbench", Position::new(-1, -1), 6),
         ];
-        let mut hints = Vec::new();
-        for item in targets {
-            if !self.knows_blueprint(item) {
-                if let Some(hint) = self.blueprint_hint(item) {
-                    hints.push(format!("{}: {}", item.name(), hint));
-                }
+        for (id, name, pos, capacity) in surfaces {
+            if self.objects.find(id).is_some() {
+                continue;
             }
+            let mut obj = WorldObject::new(ObjectKind::GenericStructure(name.to_string()));
+            obj.size = ObjectSize::Small;
+            obj.surface = Some(ObjectSurface {
+                items: LocationItems::new(),
+                capacity: Some(capacity),
+                supports_mounts: false,
+            });
+            self.objects.add(id, pos, obj);
         }
-        hints
     }
 
-    pub fn foraging_node_for(
-        &mut self,
-        pos: Position,
-        map: &WorldMap,
-        rng: &mut impl Rng,
-    ) -> &mut ForageNode {
-        let biome = pos
-            .as_usize()
-            .and_then(|(r, c)| map.get_tile(r, c).map(|t| t.biome))
-            .unwrap_or(Biome::MixedForest);
-        self.forage_nodes
-            .entry(pos)
-            .or_insert_with(|| ForageNode::new(biome, rng))
+    /// The duck perched on the mantelpiece keeps watch over the cabin.
+    pub fn duck_on_mantel(&self) -> bool {
+        self.mantel_surface()
+            .map(|s| s.items.contains(&Item::RubberDuck))
+            .unwrap_or(false)
     }
 
-    pub fn on_player_pickup(&mut self, item: &Item) {
-        if matches!(
-            item,
-            Item::Book | Item::TutorialBook | Item::OldBook | Item::DeathNote | Item::BookOfFishing
-        ) {
-            if let Some(book_id) = self
-                .take_cabin_book_for_item(item)
-                .or_else(|| self.book_id_for_item(item).map(|s| s.to_string()))
-            {
-                self.add_player_book(&book_id);
-            }
+    fn ensure_duck_present(&mut self, map: &WorldMap) {
+        let duck = Item::RubberDuck;
+        if self.item_census(&duck, map) > 0 {
+            return;
+        }
+
+        if let Some(surface) = self.table_surface_mut() {
+            surface.items.add(duck, 1);
+            return;
+        }
+
+        if let Some(cabin) = self.cabin_state_mut() {
+            cabin.items.add(duck, 1);
         }
     }
 
-    pub fn on_player_drop(&mut self, item: &Item) -> Option<String> {
-        if matches!(
-            item,
-            Item::Book | Item::TutorialBook | Item::OldBook | Item::DeathNote | Item::BookOfFishing
-        ) {
-            // Prefer removing a matching special book id; otherwise pop any
-            if let Some(id) = self
-                .book_id_for_item(item)
-                .and_then(|id| self.remove_player_book(id).then(|| id.to_string()))
-            {
-                return Some(id);
-            }
-            if let Some(id) = self.pop_any_player_book() {
-                return Some(id);
-            }
+    fn ensure_player_visit(&mut self) {
+        self.player.mark_visited();
+    }
+
+    pub fn damage_tool(&mut self, item: &Item, wear: ToolWear, context: &str) {
+        let Some(max) = Player::tool_max_durability(item) else {
+            return;
+        };
+        let amount = wear.amount();
+        let entry = self.player.tool_durability.entry(*item).or_insert(max);
+        if *entry <= amount {
+            let _ = self.player.inventory.remove(item, 1);
+            self.player.tool_durability.remove(item);
+            let label = self.display_name(item);
+            self.pending_messages
+                .push(format!("Your {} breaks while {}.", label, context));
+        } else {
+            *entry -= amount;
         }
-        None
     }
 
-    fn bootstrap_structures(&mut self) {
-        let mut cabin_state = self.legacy_cabin.take().unwrap_or_else(Cabin::new);
-        Self::ensure_core_cabin_items(&mut cabin_state);
-        let mut table_items = std::mem::take(&mut cabin_state.table_items);
+    /// Apply a melee attack from the player to a nearby wildlife entity, if any matches the target hint.
+    /// Returns a descriptive message if an attack occurred.
+    pub fn attack_nearby_wildlife(
+        &mut self,
+        map: &WorldMap,
+        _weapon: &Item,
+        base_damage: f32,
+        target_hint: Option<&str>,
+    ) -> Option<String> {
+        let pos = self.player.position;
+        let hint = target_hint
+            .map(|s| s.to_lowercase())
+            .unwrap_or_else(|| String::new());
+
+        let mut candidate_index: Option<usize> = None;
+        let mut candidate_distance = f32::MAX;
 
-        if self.objects.find("cabin").is_none() {
-            self.objects.add(
-                "cabin",
-                Position::new(0, 0),
-                WorldObject::new(ObjectKind::Cabin(cabin_state)),
-            );
-        } else if let Some(po) = self.objects.find_mut("cabin") {
-            if let Some(cabin) = po.object.as_cabin_mut() {
-                Self::ensure_core_cabin_items(cabin);
-                if table_items.is_empty() && !cabin.table_items.is_empty() {
-                    table_items.extend(cabin.table_items.iter().copied());
-                }
+        for (idx, w) in self.wildlife.iter().enumerate() {
+            let dist = pos.distance_to(&w.position);
+            if dist > 1.6 {
+                continue;
             }
-            // Move cabin to new origin
-            if po.position != Position::new(0, 0) {
-                po.position = Position::new(0, 0);
+            if !w.on_land(map) {
+                continue;
             }
-        }
-
-        let wood_shed_state = self.legacy_wood_shed.take().unwrap_or_else(WoodShed::new);
-        if self.objects.find("wood_shed").is_none() {
-            self.objects.add(
-                "wood_shed",
-                Position::new(-1, -1),
-                WorldObject::new(ObjectKind::WoodShed(wood_shed_state)),
-            );
-        } else if let Some(po) = self.objects.find_mut("wood_shed") {
-            if po.object.as_wood_shed().is_none() {
-                po.object.kind = ObjectKind::WoodShed(wood_shed_state);
+            if !hint.is_empty() {
+                let name = w.species.name().to_lowercase();
+                if !name.contains(&hint) && !hint.contains(&name) && !hint.contains("animal") {
+                    continue;
+                }
             }
-            if po.position != Position::new(-1, -1) {
-                po.position = Position::new(-1, -1);
+            if dist < candidate_distance {
+                candidate_distance = dist;
+                candidate_index = Some(idx);
             }
         }
 
-        // Ensure an east-side cave entrance exists in the winter forest
-        if self.objects.find("east_cave_entrance").is_none() {
-            let cave_pos = Position::new(0, 8);
-            let cave = WorldObject::new(ObjectKind::GenericStructure("cave entrance".to_string()));
-            self.objects
-                .add("east_cave_entrance", cave_pos, cave);
-        }
-
-        self.ensure_table_object(table_items);
-        self.ensure_duck_present();
-        self.ensure_pig_carcass_near_cabin();
+        let idx = candidate_index?;
+        self.strike_wildlife(idx, base_damage)
+    }
+
+    /// Land a blow on one animal, leaving a corpse if it was fatal.
+    pub fn strike_wildlife(&mut self, idx: usize, base_damage: f32) -> Option<String> {
+        if idx >= self.wildlife.len() {
+            return None;
+        }
+
+        let mut rng = rand::thread_rng();
+        let w = &mut self.wildlife[idx];
+        let name = w.species.name();
+        let hit = match w.body.apply_random_damage(&mut rng, base_damage) {
+            Some(hit) => hit,
+            None => return None,
+        };
+
+        // Sync a coarse overall health ratio into the global health bar for now
+        let overall_ratio = w.body.overall_health_ratio();
+        if overall_ratio <= 0.0 {
+            // nothing special; corpse will be spawned below
+        }
+
+        let message = w.body.describe_hit(&hit, name);
+        // Anything that survives being hit remembers it
+        w.alarm(60.0);
+
+        let killed = w.body.is_vital_broken();
+        let killed_species = killed.then(|| self.leave_corpse(idx));
+
+        // Small chance to improve survival skill through direct hunting practice
+        if rng.gen_bool(0.3) {
+            self.player.skills.improve(Skill::Survival, 1);
+        }
+
+        // Mood impact depending on outcome; a first kill weighs far heavier
+        match killed_species {
+            Some((species, corpse_id)) => self.record_kill(species, corpse_id),
+            None => self.player.modify_mood(-1.0),
+        }
+
+        Some(message)
+    }
+
+    /// Take an animal out of the living and leave its body where it stood.
+    fn leave_corpse(&mut self, idx: usize) -> (Species, String) {
+        let w = self.wildlife.remove(idx);
+        let corpse = WorldObject::new(ObjectKind::Corpse(Corpse {
+            species: w.species,
+            freshness: 0,
+            body: Some(w.body),
+        }));
+        let id = format!("corpse-{}-{}", w.species.name(), self.objects.placed.len());
+        self.objects.add(id.clone(), w.position, corpse);
+        (w.species, id)
+    }
+
+    fn record_kill(&mut self, species: Species, corpse_id: String) {
+        self.kills.push(KillRecord {
+            corpse_id,
+            species,
+            tick: self.time.tick,
+            butchered: false,
+            cooked: false,
+            hide_used: false,
+            wasted: false,
+            mourned: false,
+            reconciled: false,
+        });
+        if self.first_kills.contains(&species) {
+            self.player.modify_mood(-2.0);
+            return;
+        }
+        self.first_kills.push(species);
+        self.player.modify_mood(-8.0);
+        let entry = format!(
+            "The first {} I have killed. It was warm and then it wasn't. I want to remember that it cost something.",
+            species.name()
+        );
+        self.journal.push(entry.clone());
+        let lead = self
+            .player
+            .render("The stillness afterward is very loud. Later {name} write{s}:");
+        self.pending_messages
+            .push(format!("{} \"{}\"", lead, entry));
+    }
+
+    /// The most recent kill, if it happened within the last few hours.
+    pub fn recent_kill(&self) -> Option<&KillRecord> {
+        self.kills
+            .last()
+            .filter(|k| self.time.tick.saturating_sub(k.tick) <= 36)
+    }
+
+    /// Meat from a butchered kill made it to the fire.
+    pub fn note_kill_meat_cooked(&mut self) {
+        if let Some(k) = self.kills.iter_mut().find(|k| k.butchered && !k.cooked) {
+            k.cooked = true;
+        }
+        self.check_kill_reconciliation();
+    }
+
+    /// Hide from a butchered kill was worked into something.
+    pub fn note_kill_hide_used(&mut self) {
+        if let Some(k) = self.kills.iter_mut().find(|k| k.butchered && !k.hide_used) {
+            k.hide_used = true;
+        }
+        self.check_kill_reconciliation();
+    }
+
+    fn check_kill_reconciliation(&mut self) {
+        let Some(record) = self
+            .kills
+            .iter_mut()
+            .find(|k| k.fully_used() && !k.reconciled)
+        else {
+            return;
+        };
+        record.reconciled = true;
+        let entry = format!(
+            "Nothing wasted. The {} fed me, and its hide will keep me warm. I think that is the most I can give back.",
+            record.species.name()
+        );
+        self.journal.push(entry.clone());
+        let lead = self
+            .player
+            .render("A quiet settles in {them}. {Name} write{s}:");
+        self.pending_messages
+            .push(format!("{} \"{}\"", lead, entry));
+        self.player.modify_mood(3.0);
+        self.lingering_mood = 0.25;
+        self.lingering_ticks = 24;
+    }
+
+    /// Regret and reconciliation fade in slowly; a wasted kill stings again
+    /// when the player walks back past it.
+    fn update_hunting_conscience(&mut self) {
+        for po in &self.objects.placed {
+            let ObjectKind::Corpse(corpse) = &po.object.kind else {
+                continue;
+            };
+            if corpse.freshness < 90 {
+                continue;
+            }
+            if let Some(k) = self
+                .kills
+                .iter_mut()
+                .find(|k| k.corpse_id == po.id && !k.butchered)
+            {
+                k.wasted = true;
+            }
+        }
+
+        if self.player.room.is_none() {
+            let pos = self.player.position;
+            let here: Vec<String> = self
+                .objects
+                .objects_at(&pos)
+                .iter()
+                .map(|po| po.id.clone())
+                .collect();
+            if let Some(k) = self
+                .kills
+                .iter_mut()
+                .find(|k| k.wasted && !k.mourned && here.contains(&k.corpse_id))
+            {
+                k.mourned = true;
+                let name = k.species.name();
+                self.pending_messages.push(format!(
+                    "You come upon the {} you killed and left. It has rotted where it fell, taken by nothing but flies. The waste of it follows you for a while.",
+                    name
+                ));
+                self.journal.push(format!(
+                    "I found the {} I left to rot. I took its life and then didn't even take the meat.",
+                    name
+                ));
+                self.player.modify_mood(-4.0);
+                self.lingering_mood = -0.15;
+                self.lingering_ticks = 20;
+            }
+        }
+
+        if self.lingering_ticks > 0 {
+            self.lingering_ticks -= 1;
+            self.player.modify_mood(self.lingering_mood);
+        }
+    }
+
+    fn update_player_cognition(&mut self) {
+        let body = &self.player.body;
+        let head_ratio = body.head_health_ratio();
+        let health_ratio = (self.player.health / 100.0).clamp(0.0, 1.0);
+        let energy_ratio = (self.player.energy / 100.0).clamp(0.0, 1.0);
+
+        let mut cognition = 100.0;
+
+        // Head injuries have the largest impact
+        let head_penalty = (1.0 - head_ratio) * 40.0;
+        // Low energy makes thinking harder, especially below ~70
+        let energy_penalty = ((0.7 - energy_ratio).max(0.0) / 0.7) * 30.0;
+        // Overall poor health also drags cognition down
+        let health_penalty = ((0.8 - health_ratio).max(0.0) / 0.8) * 20.0;
+
+        cognition -= head_penalty + energy_penalty + health_penalty;
+        self.player.cognition = cognition.clamp(0.0, 100.0);
+    }
+
+    /// Butcher a corpse at the player's current position, if any, yielding resources and updating state.
+    pub fn butcher_corpse_at_player(&mut self, _weapon: &Item) -> Option<String> {
+        let pos = self.player.position;
+
+        let mut found_index: Option<usize> = None;
+
+        for (idx, po) in self.objects.placed.iter().enumerate() {
+            if po.position == pos {
+                if let ObjectKind::Corpse(c) = &po.object.kind {
+                    found_index = Some(idx);
+                    break;
+                }
+            }
+        }
+
+        let idx = found_index?;
+        let (species, freshness) = match &self.objects.placed.get(idx)?.object.kind {
+            ObjectKind::Corpse(c) => (c.species, c.freshness),
+            _ => return None,
+        };
+        let corpse_id = self.objects.placed[idx].id.clone();
+        if let Some(k) = self.kills.iter_mut().find(|k| k.corpse_id == corpse_id) {
+            k.butchered = true;
+        }
+
+        let (base_meat, base_hide, base_fat) = match species {
+            Species::Deer | Species::Caribou => (6, 2, 2),
+            Species::Wolf | Species::Fox | Species::DesertFox | Species::SnowFox => (4, 1, 2),
+            Species::SnowHare | Species::Rabbit => (2, 1, 1),
+            _ => (3, 1, 1),
+        };
+
+        // Simple freshness stages: fresh, aging, and spoiled.
+        let (meat, hide, fat, freshness_note) = if freshness < 30 {
+            (base_meat, base_hide, base_fat, None)
+        } else if freshness < 90 {
+            let meat = ((base_meat as f32) * 0.6).round() as i32;
+            let fat = ((base_fat as f32) * 0.7).round() as i32;
+            (
+                meat.max(0),
+                base_hide,
+                fat.max(0),
+                Some(
+                    "The carcass is no longer freshly killed, but you trim away the worst parts and salvage what you can.",
+                ),
+            )
+        } else {
+            let meat = 0;
+            let fat = (base_fat / 2).max(0);
+            (
+                meat,
+                base_hide,
+                fat,
+                Some(
+                    "Most of the meat has spoiled; you focus on hide and whatever fat still seems safe.",
+                ),
+            )
+        };
+
+        if meat == 0 && hide == 0 && fat == 0 {
+            // Even a spoiled carcass at least teaches you what rot looks like.
+            if let Some(po) = self.objects.placed.get_mut(idx) {
+                po.object.kind =
+                    ObjectKind::GenericStructure("picked-over remains".to_string());
+            }
+            return Some(
+                "This carcass has spoiled too far to yield anything useful. You leave only scattered bones and feathers behind."
+                    .to_string(),
+            );
+        }
+
+        if meat > 0 {
+            self.player.inventory.add(Item::RawMeat, meat as u32);
+        }
+        if hide > 0 {
+            self.player.inventory.add(Item::RawHide, hide as u32);
+        }
+        if fat > 0 {
+            self.player.inventory.add(Item::AnimalFat, fat as u32);
+        }
+
+        self.player.skills.improve(Skill::Survival, 2);
+        self.player.skills.improve(Skill::Tailoring, 1);
+        self.player.modify_energy(-5.0);
+
+        if let Some(po) = self.objects.placed.get_mut(idx) {
+            po.object.kind =
+                ObjectKind::GenericStructure("picked-over remains".to_string());
+        }
+
+        let base_text =
+            "You carefully butcher the carcass, setting aside meat, hide, and fat for later use.";
+        let message = match freshness_note {
+            Some(note) => format!("{} {}", note, base_text),
+            None => base_text.to_string(),
+        };
+
+        Some(message)
+    }
+
+    pub fn refresh_blueprint_knowledge(&mut self, push_messages: bool) {
+        let tutorial_done = self.book_completed(TUTORIAL_BOOK_ID);
+        let fishing_done = self.book_completed(FISHING_BOOK_ID);
+        let active_target = self.player.active_project.as_ref().map(|bp| bp.target_item);
+
+        let add_if = |state: &mut Self, item: Item, condition: bool, reason: &str| {
+            if !(condition || active_target == Some(item)) {
+                return;
+            }
+            if state.player.known_blueprints.insert(item) && push_messages {
+                state.pending_messages.push(format!(
+                    "You learned the {} blueprint. {}",
+                    item.name(),
+                    reason
+                ));
+            }
+        };
+
+        add_if(
+            self,
+            Item::StoneKnife,
+            self.player.skills.survival >= 8,
+            "Basic survival practice reveals how to knap and lash a knife.",
+        );
+        add_if(
+            self,
+            Item::Cordage,
+            self.player.skills.tailoring >= 8,
+            "You recognize how to twist plant fibers into rope.",
+        );
+        add_if(
+            self,
+            Item::Campfire,
+            self.player.skills.fire_making >= 8 || self.player.skills.survival >= 8,
+            "Fire-making fundamentals click into place.",
+        );
+        add_if(
+            self,
+            Item::StoneAxe,
+            self.player.skills.woodcutting >= 12 || tutorial_done,
+            "Woodcutting skill or completing the cabin tutorial reveals axe joinery.",
+        );
+        add_if(
+            self,
+            Item::FishingRod,
+            fishing_done,
+            "Finishing the Book of Fishing shows how to lash a simple rod.",
+        );
+        add_if(
+            self,
+            Item::Raft,
+            self.player.skills.survival >= 20,
+            "Survival practice teaches how to lash a sturdy raft from logs and cordage.",
+        );
+        add_if(
+            self,
+            Item::WoodenStool,
+            self.player.skills.woodcutting >= 14,
+            "Working wood by hand shows you how to peg a simple stool together.",
+        );
+        add_if(
+            self,
+            Item::WoodenBench,
+            self.player.skills.woodcutting >= 18,
+            "Steady woodcutting practice reveals how to brace a bench that won't wobble.",
+        );
+        let butchered_any = self.kills.iter().any(|k| k.butchered);
+        add_if(
+            self,
+            Item::HideWrap,
+            butchered_any || self.player.skills.tailoring >= 15,
+            "Holding a fresh hide, you see how to stitch it into a wrap rather than waste it.",
+        );
+        add_if(
+            self,
+            Item::Bandage,
+            self.player.skills.survival >= 10 || self.player.body.bleeding_parts() > 0,
+            "Basic first aid: herbs packed in fiber will close a wound.",
+        );
+        add_if(
+            self,
+            Item::Thermometer,
+            self.player.skills.observation >= 14,
+            "Watching resin soften in the sun gives you an idea for measuring the air.",
+        );
+        add_if(
+            self,
+            Item::SignalFire,
+            self.rescue.active,
+            "If anyone is out there, they'll need to see you. You sketch a tall signal pyre for the highest ground around.",
+        );
+        let ruin_found = self.ruin().map(|r| r.discovered).unwrap_or(false);
+        add_if(
+            self,
+            Item::Shovel,
+            ruin_found || self.player.skills.survival >= 25,
+            "Thinking of all that drifted sand, you work out how to lash a broad wooden shovel.",
+        );
+        add_if(
+            self,
+            Item::ClayJar,
+            self.player.inventory.has(&Item::Clay, 1),
+            "Working the wet clay between your fingers, you see how it could be pinched into a jar.",
+        );
+        add_if(
+            self,
+            Item::WeatherVane,
+            self.player.skills.survival >= 14,
+            "Reading the sky every day, you work out how to build something that reads the wind for you.",
+        );
+    }
+
+    fn ensure_book_registry(&mut self) {
+        let mut insert_if_missing = |id: &str, title: &str, pages: Vec<&str>, writable: bool| {
+            if !self.books.contains_key(id) {
+                self.books.insert(
+                    id.to_string(),
+                    BookEntry {
+                        id: id.to_string(),
+                        title: title.to_string(),
+                        pages: pages.into_iter().map(|p| p.to_string()).collect(),
+                        writable,
+                    },
+                );
+            }
+        };
+
+        insert_if_missing(
+            TUTORIAL_BOOK_ID,
+            "Cabin Tutorial",
+            vec![
+                "Welcome to the cabin. As you cross the threshold, a voice you don't quite own whispers: 'Mortal, read this tutorial book from the first page to the very last. If you ignore it, this world will kill you slowly.' Start simple: use hands on bush to forage for sticks, fibers, berries and herbs. Small piles add up.",
+                "To light a fire, you usually need three things: chopped firewood, kindling or tinder, and a way to spark.",
+                "The wood shed holds logs and an axe. Inside the shed, use log on chopping block to stand a log up, then use axe on block to split it; the pieces go on the firewood stack, and take firewood brings one along. Logs don't last forever.",
+                "You'll also need more logs in the long run. Outside, move next to a tree and use axe on tree. Heavy swings cost energy.",
+                "Once you have fuel, go to the cabin hearth and lay the fire in layers: tinder first (dry leaves, bark, a pinecone), then kindling, then firewood on top. Use each on fire, then use matchbox on fire. Logs alone will never take a match.",
+                "If the fire dies, you can add fuel later: use firewood on fire or toss in dry sticks, bark, or very old books you don't mind losing.",
+                "For hunger, you can fish, forage, or shake fruit. Near the lake, even bare hands can sometimes pull a fish from the shallows.",
+                "Try use hands on water or near the shore and pay attention to ripples and timing. A steady rhythm often helps.",
+                "If you catch a fish, go back to the cabin. Stand by the hearth with a lit fire and use fish on fire to grill a simple meal.",
+                "Some trees bear fruit. If you find an apple tree or other fruit tree, sometimes a good kick is enough to drop a snack.",
+                "In gentle time, fruit slowly returns. Don't strip every tree bare at once; patience feeds you twice.",
+                "Books, maps and strange objects in the cabin hint at deeper systems. Not all of them explain themselves immediately.",
+                "If you feel lost, look around, meditate by the lake, or talk to the rubber duck. Sometimes the quiet answers first.",
+                "On the path just south of the cabin, you may notice a small carcass and a simple knife nearby. When you feel ready, stand by the carcass, 'take knife', then 'use knife on carcass' (or 'use knife on pig') to practice butchering and carry the meat inside to cook over the hearth.",
+                "You can use 'examine self' any time to check your condition. 'examine pig' or 'examine deer' will show how injured nearby animals are (and if several share your tile, they'll all be listed), and standing on a carcass then typing 'examine corpse' will show which parts were hurt the most.",
+                "If a dog or cat learns to trust you enough to follow you, you can give it a proper name with commands like 'name dog 멍멍이' or 'name cat 나비' while it is nearby.",
+            ],
+            false,
+        );
+        insert_if_missing(
+            OLD_BOOK_ID,
+            "Weathered Journal",
+            vec![
+                "The cabin creaks but endures. The lake stays still even in wind.",
+                "Someone underlined a phrase: 'Keep writing; the ink remembers what you might forget.'",
+                BEAR_WARNING_PAGE,
+            ],
+            false,
+        );
+        insert_if_missing(
+            DEATH_NOTE_ID,
+            "Death Note",
+            vec!["The human whose name is written in this note shall die."],
+            true,
+        );
+        insert_if_missing(
+            FISHING_BOOK_ID,
+            "Book of Fishing",
+            vec![
+                "A simple rod needs a straight pole, a bendable tip, and cordage tied in clean knots. Bamboo or a stiff stick will do.",
+                "Fish cruise the shallows at dawn and dusk. In storms they sink deep and hide; in clear weather, keep quiet and watch for ripples.",
+                FISHING_SPOTS_PAGE,
+                "Close the book and you can almost feel the rhythm of casting. You think you could craft a wooden fishing rod now.",
+            ],
+            false,
+        );
+
+        if let Some(journal) = self.books.get_mut(OLD_BOOK_ID) {
+            if !journal.pages.iter().any(|p| p == BEAR_WARNING_PAGE) {
+                journal.pages.push(BEAR_WARNING_PAGE.to_string());
+            }
+        }
+        if let Some(fishing) = self.books.get_mut(FISHING_BOOK_ID) {
+            if !fishing.pages.iter().any(|p| p == FISHING_SPOTS_PAGE) {
+                let before_last = fishing.pages.len().saturating_sub(1);
+                fishing
+                    .pages
+                    .insert(before_last, FISHING_SPOTS_PAGE.to_string());
+            }
+        }
+        // The printed books teach commands; one that has stopped working
+        // is a bug in the book, not something for the player to puzzle over.
+        for book in self.books.values().filter(|b| !b.writable) {
+            for page in &book.pages {
+                for phrase in crate::actions::untrue_use_phrases(page) {
+                    tracing::warn!(
+                        "'{}' teaches '{}', which the game doesn't do",
+                        book.title,
+                        phrase
+                    );
+                }
+            }
+        }
+
+        let max_seen = self
+            .books
+            .keys()
+            .filter_map(|k| k.strip_prefix("book-"))
+            .filter_map(|n| n.parse::<u32>().ok())
+            .max()
+            .unwrap_or(0);
+        if self.next_book_id <= max_seen {
+            self.next_book_id = max_seen + 1;
+        }
+    }
+
+    fn ensure_cabin_books(&mut self, map: &WorldMap) {
+        let books = [
+            (TUTORIAL_BOOK_ID, Item::TutorialBook),
+            (OLD_BOOK_ID, Item::OldBook),
+            (DEATH_NOTE_ID, Item::DeathNote),
+            (FISHING_BOOK_ID, Item::BookOfFishing),
+        ];
+        // A named book the player has carried off is still the cabin's;
+        // only one gone from the world entirely is put back on the shelf.
+        let missing: Vec<Item> = books
+            .iter()
+            .map(|(_, item)| *item)
+            .filter(|item| self.item_census(item, map) == 0)
+            .collect();
+        let Some(cabin) = self.cabin_state_mut() else {
+            return;
+        };
+        for (id, item) in books {
+            if !cabin.book_ids.iter().any(|b| b == id) {
+                cabin.book_ids.push(id.to_string());
+            }
+            if missing.contains(&item) {
+                cabin.items.add(item, 1);
+            }
+        }
+    }
+
+    pub fn generate_book_id(&mut self) -> String {
+        let id = format!("book-{}", self.next_book_id);
+        self.next_book_id += 1;
+        id
+    }
+
+    pub fn book_entry(&self, id: &str) -> Option<&BookEntry> {
+        self.books.get(id)
+    }
+
+    pub fn book_entry_mut(&mut self, id: &str) -> Option<&mut BookEntry> {
+        self.books.get_mut(id)
+    }
+
+    pub fn register_book(&mut self, entry: BookEntry) -> String {
+        let id = entry.id.clone();
+        self.books.insert(id.clone(), entry);
+        id
+    }
+
+    /// Note a dish the player just made: trains cooking, and from
+    /// `RECIPE_BOOK_SKILL` onward keeps a "My Recipes" page for every first.
+    pub fn record_dish(&mut self, dish: Item, gain: u8) {
+        self.player.skills.improve(Skill::Cooking, gain);
+        if dish == Item::CookedFish {
+            self.note_practice(Practice::CookedFish);
+        }
+        self.letters.week.meals += 1;
+        let first_time = !self.cooked_dishes.contains(&dish);
+        if first_time {
+            self.cooked_dishes.push(dish);
+        }
+        if self.player.skills.get(Skill::Cooking) < RECIPE_BOOK_SKILL {
+            return;
+        }
+        if self.ensure_recipe_book() || !first_time {
+            return;
+        }
+        let page = Self::recipe_page(dish);
+        if let Some(book) = self
+            .recipe_book_id
+            .clone()
+            .and_then(|id| self.books.get_mut(&id))
+        {
+            book.pages.push(page);
+            self.pending_messages.push(format!(
+                "You jot the {} into My Recipes while it's fresh in your mind.",
+                dish.name()
+            ));
+        }
+    }
+
+    /// Start the recipe book the first time the player is skilled enough,
+    /// back-filling a page for every dish made so far. Returns true if created.
+    fn ensure_recipe_book(&mut self) -> bool {
+        if self.recipe_book_id.is_some() {
+            return false;
+        }
+        let id = self.generate_book_id();
+        let mut entry = BookEntry::new(id.clone(), "My Recipes", true);
+        entry.pages = self
+            .cooked_dishes
+            .iter()
+            .map(|d| Self::recipe_page(*d))
+            .collect();
+        self.register_book(entry);
+        self.recipe_book_id = Some(id.clone());
+        if self.player.inventory.add(Item::Book, 1) {
+            self.add_player_book(&id);
+        } else if let Some(cabin) = self.cabin_state_mut() {
+            cabin.add_item(Item::Book);
+            cabin.book_ids.push(id.clone());
+        }
+        self.pending_messages.push(format!(
+            "Cooking has started to feel like yours. You begin a little book of your own: My Recipes [{}].",
+            id
+        ));
+        true
+    }
+
+    fn recipe_page(dish: Item) -> String {
+        let note = match dish {
+            Item::CookedFish => "Gut and rinse the fish. Lay it over steady coals, not flame, and turn it once. It's done when it flakes at a touch.",
+            Item::CookedMeat => "Grill close to the embers until it sizzles and browns, then let it rest a moment. Patience makes it tender.",
+            Item::CookedBerries => "Two handfuls of berries on a flat stone by the fire. Pull them when the juices turn to syrup.",
+            Item::HerbalTea => "A pinch of wild herbs in the cup, water just off the boil. Wait. The waiting is part of it.",
+            _ => "Cooked over the cabin fire. Worth making again.",
+        };
+        format!("{}: {}", dish.name(), note)
+    }
+
+    /// Note everything a look just took in, nearest first so one look
+    /// never writes the same animal up twice.
+    pub fn record_sightings(&mut self, sightings: &[(Species, f32)]) {
+        let mut sightings = sightings.to_vec();
+        sightings.sort_by(|a, b| a.1.total_cmp(&b.1));
+        for (species, distance) in sightings {
+            self.record_sighting(species, distance);
+        }
+    }
+
+    /// Note a species the player has just seen in the Field Notes: a first
+    /// sighting opens a page, a closer look fills it out. Returns true if
+    /// the notes changed.
+    pub fn record_sighting(&mut self, species: Species, distance: f32) -> bool {
+        if !Species::FIELD_GUIDE.contains(&species) {
+            return false;
+        }
+        let detail = SightingDetail::at_distance(distance);
+        let message = match self.field_notes.iter_mut().find(|n| n.species == species) {
+            Some(note) if note.detail >= detail => return false,
+            Some(note) => {
+                note.detail = detail;
+                format!(
+                    "You add what you saw of the {} to your Field Notes.",
+                    species.name()
+                )
+            }
+            None => {
+                self.field_notes.push(FieldNote { species, detail });
+                format!(
+                    "You jot a first note on the {} into your Field Notes.",
+                    species.name()
+                )
+            }
+        };
+        self.ensure_field_notes();
+        self.pending_messages.push(message);
+        if let Some(book) = self
+            .field_notes_id
+            .clone()
+            .and_then(|id| self.books.get_mut(&id))
+        {
+            book.pages = Self::field_note_pages(&self.field_notes);
+        }
+        if self.field_notes.len() == Species::FIELD_GUIDE.len() && !self.player.naturalist {
+            self.player.naturalist = true;
+            self.journal.push(
+                "Every animal in the valley now has a page in the Field Notes. You notice you have started to see the woods the way they do."
+                    .to_string(),
+            );
+            self.pending_messages.push(
+                "That's the last of them: every species in the valley has a page. Your eye for movement has grown sharper for good."
+                    .to_string(),
+            );
+        }
+        true
+    }
+
+    /// Start the Field Notes with the player's first sighting.
+    fn ensure_field_notes(&mut self) {
+        if self.field_notes_id.is_some() {
+            return;
+        }
+        let id = self.generate_book_id();
+        self.register_book(BookEntry::new(id.clone(), "Field Notes", false));
+        self.field_notes_id = Some(id.clone());
+        if self.player.inventory.add(Item::Book, 1) {
+            self.add_player_book(&id);
+        } else if let Some(cabin) = self.cabin_state_mut() {
+            cabin.add_item(Item::Book);
+            cabin.book_ids.push(id.clone());
+        }
+        self.pending_messages.push(format!(
+            "You start a little book for the animals you come across: Field Notes [{}].",
+            id
+        ));
+    }
+
+    /// A tally page, then one page per species in field-guide order.
+    fn field_note_pages(notes: &[FieldNote]) -> Vec<String> {
+        let mut pages = vec![format!(
+            "Field Notes\n{} of {} species seen in the valley.",
+            notes.len(),
+            Species::FIELD_GUIDE.len()
+        )];
+        pages.extend(Species::FIELD_GUIDE.iter().filter_map(|species| {
+            notes
+                .iter()
+                .find(|n| n.species == *species)
+                .map(|n| species.field_note(n.detail))
+        }));
+        pages
+    }
+
+    pub fn player_has_book(&self, id: &str) -> bool {
+        self.player.book_ids.iter().any(|b| b == id)
+    }
+
+    pub fn add_player_book(&mut self, id: &str) {
+        if !self.player.book_ids.iter().any(|b| b == id) {
+            self.player.book_ids.push(id.to_string());
+        }
+    }
+
+    pub fn remove_player_book(&mut self, id: &str) -> bool {
+        if let Some(pos) = self.player.book_ids.iter().position(|b| b == id) {
+            self.player.book_ids.remove(pos);
+            return true;
+        }
+        false
+    }
+
+    pub fn pop_any_player_book(&mut self) -> Option<String> {
+        self.player.book_ids.pop()
+    }
+
+    pub fn book_id_for_item<'a>(&self, item: &'a Item) -> Option<&'a str> {
+        match item {
+            Item::TutorialBook => Some(TUTORIAL_BOOK_ID),
+            Item::OldBook => Some(OLD_BOOK_ID),
+            Item::DeathNote => Some(DEATH_NOTE_ID),
+            Item::BookOfFishing => Some(FISHING_BOOK_ID),
+            _ => None,
+        }
+    }
+
+    pub fn take_cabin_book_for_item(&mut self, item: &Item) -> Option<String> {
+        let id_hint = self.book_id_for_item(item).map(|s| s.to_string());
+        let Some(cabin) = self.cabin_state_mut() else {
+            return None;
+        };
+        if let Some(id) = id_hint {
+            if let Some(pos) = cabin.book_ids.iter().position(|b| b == &id) {
+                return Some(cabin.book_ids.remove(pos));
+            }
+        }
+        if matches!(item, Item::Book) {
+            return cabin.book_ids.pop();
+        }
+        None
+    }
+
+    pub fn add_cabin_book(&mut self, id: String) {
+        if let Some(cabin) = self.cabin_state_mut() {
+            if !cabin.book_ids.iter().any(|b| b == &id) {
+                cabin.book_ids.push(id);
+            }
+        }
+    }
+
+    pub fn accessible_book(&self, query: &str) -> Option<&BookEntry> {
+        let q = query.to_lowercase();
+        let mut ids_to_check: Vec<String> = self.player.book_ids.clone();
+        if matches!(self.player.room, Some(Room::CabinMain)) {
+            if let Some(cabin) = self.cabin_state() {
+                ids_to_check.extend(cabin.book_ids.clone());
+            }
+        }
+        for id in ids_to_check {
+            if let Some(book) = self.books.get(&id) {
+                if book.id.to_lowercase().contains(&q) || book.title.to_lowercase().contains(&q) {
+                    return Some(book);
+                }
+            }
+        }
+        None
+    }
+
+    pub fn accessible_book_ids(&self) -> Vec<String> {
+        let mut ids = self.player.book_ids.clone();
+        if matches!(self.player.room, Some(Room::CabinMain)) {
+            if let Some(cabin) = self.cabin_state() {
+                ids.extend(cabin.book_ids.clone());
+            }
+        }
+        ids
+    }
+
+    /// A book within reach by its id or by the title it was given, in
+    /// whichever language it was written.
+    pub fn find_book_id(&self, query: &str) -> Option<String> {
+        let wanted = query.trim().to_lowercase();
+        let ids = self.accessible_book_ids();
+        ids.iter()
+            .find(|id| id.to_lowercase() == wanted)
+            .or_else(|| {
+                ids.iter().find(|id| {
+                    self.books
+                        .get(id.as_str())
+                        .map(|b| b.title.trim().to_lowercase() == wanted)
+                        .unwrap_or(false)
+                })
+            })
+            .cloned()
+    }
+
+    pub fn maybe_trigger_tutorial_hint(&mut self) {
+        // Someone who already has the basics has nothing to be threatened over
+        if self.tutorial_hint_shown || self.tutorial_reward_claimed {
+            return;
+        }
+        if !matches!(self.player.room, Some(Room::CabinMain)) {
+            return;
+        }
+        self.tutorial_hint_shown = true;
+        self.pending_messages.push(
+            "For a moment the air in the cabin thickens. A voice that is not quite yours echoes inside your skull:\n\"Mortal, read the cabin tutorial book from the first page to the very last. If you ignore it, this world will find slow, petty ways to kill you.\""
+                .to_string(),
+        );
+    }
+
+    /// Hand over the tutorial bundle once: the full one for reading the book
+    /// through, a smaller one for having learned the basics by doing them.
+    pub fn grant_tutorial_reward_if_needed(&mut self, map: &mut WorldMap) {
+        if self.tutorial_reward_claimed || self.seeded.contains(&Seed::TutorialReward) {
+            return;
+        }
+        let read = self.book_completed(TUTORIAL_BOOK_ID);
+        if !read && !self.practice.covers_tutorial() {
+            return;
+        }
+        let bundle: &[(Item, u32)] = if read {
+            &[(Item::Knife, 1), (Item::Kindling, 5), (Item::Apple, 10)]
+        } else {
+            &[(Item::Kindling, 2), (Item::Apple, 5)]
+        };
+
+        let dropped = match self.player.room {
+            Some(Room::CabinMain) => match self.cabin_state_mut() {
+                Some(cabin) => {
+                    for (item, qty) in bundle {
+                        cabin.items.add(*item, *qty);
+                    }
+                    true
+                }
+                None => false,
+            },
+            _ => match map
+                .index_of(&self.player.position)
+                .and_then(|(r, c)| map.get_tile_mut(r, c))
+            {
+                Some(tile) => {
+                    for (item, qty) in bundle {
+                        tile.items.add(*item, *qty);
+                    }
+                    true
+                }
+                None => false,
+            },
+        };
+        if !dropped {
+            return;
+        }
+
+        self.tutorial_reward_claimed = true;
+        self.seeded.insert(Seed::TutorialReward);
+        if read {
+            self.pending_messages.push(
+                "As you finish the cabin tutorial, a small bundle of supplies appears at your feet: 10 apples, 5 pieces of kindling, and a simple knife."
+                    .to_string(),
+            );
+        } else {
+            self.tutorial_by_practice = true;
+            self.pending_messages.push(
+                "The air thickens, and the voice that is not quite yours returns, sounding put out:\n\"Fire, fish, a felled tree. You never opened the book, and you learned it anyway. Fine. The world will stop plotting against you.\"\nA smaller bundle than the book promised lands at your feet: 5 apples and 2 pieces of kindling."
+                    .to_string(),
+            );
+        }
+    }
+
+    /// Count one of the tutorial's basics done for real.
+    pub fn note_practice(&mut self, practice: Practice) {
+        let tally = &mut self.practice;
+        let count = match practice {
+            Practice::LitFire => &mut tally.fires_lit,
+            Practice::CookedFish => &mut tally.fish_cooked,
+            Practice::FelledTree => &mut tally.trees_felled,
+        };
+        *count += 1;
+    }
+
+    pub fn player_or_cabin_has_book(&self, id: &str) -> bool {
+        self.player.book_ids.iter().any(|b| b == id)
+            || (matches!(self.player.room, Some(Room::CabinMain))
+                && self
+                    .cabin_state()
+                    .map(|c| c.book_ids.iter().any(|b| b == id))
+                    .unwrap_or(false))
+    }
+
+    pub fn book_page(&self, id: &str) -> usize {
+        self.player.book_progress.get(id).copied().unwrap_or(0)
+    }
+
+    pub fn set_book_page(&mut self, id: &str, page: usize) {
+        self.player.book_progress.insert(id.to_string(), page);
+    }
+
+    fn book_completed(&self, id: &str) -> bool {
+        let read_page = self.book_page(id);
+        let total_pages = self.books.get(id).map(|b| b.pages.len()).unwrap_or(0);
+        total_pages > 0 && read_page >= total_pages
+    }
+
+    pub fn knows_blueprint(&self, item: Item) -> bool {
+        self.player.known_blueprints.contains(&item)
+    }
+
+    pub fn known_blueprint_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .player
+            .known_blueprints
+            .iter()
+            .map(|i| i.name().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    pub fn blueprint_hint_text(&self, item: Item) -> Option<&'static str> {
+        self.blueprint_hint(item)
+    }
+
+    fn blueprint_hint(&self, item: Item) -> Option<&'static str> {
+        match item {
+            Item::StoneAxe => {
+                Some("Raise woodcutting to 12 or finish the Cabin Tutorial to learn it.")
+            }
+            Item::StoneKnife => Some("Build basic survival skill to unlock this."),
+            Item::Campfire => Some("Practice fire-making to level 8+ to learn this pattern."),
+            Item::Cordage => Some("Tailoring 8+ reveals how to twist cordage."),
+            Item::FishingRod => Some("Finish reading the Book of Fishing to unlock this."),
+            Item::Raft => Some("Grow your survival skill to 20+ to learn this build."),
+            Item::WoodenStool => Some("Raise woodcutting to 14 to learn this pattern."),
+            Item::WoodenBench => Some("Raise woodcutting to 18 to learn this pattern."),
+            Item::HideWrap => Some("Butcher an animal or reach tailoring 15 to learn this."),
+            Item::Bandage => Some("Practice survival to level 10 to learn this."),
+            Item::Thermometer => Some("Raise observation to 14 to learn this pattern."),
+            Item::WeatherVane => Some("Grow your survival skill to 14+ to learn this build."),
+            Item::ClayJar => Some("Dig some clay from the lakeshore to learn this."),
+            Item::Shovel => Some("Find something worth digging out to learn this."),
+            Item::SignalFire => Some("Take on the rescue goal in settings to plan this."),
+            _ => None,
+        }
+    }
+
+    pub fn locked_blueprint_hints(&self) -> Vec<String> {
+        let targets = [
+            Item::StoneKnife,
+            Item::Campfire,
+            Item::Cordage,
+            Item::StoneAxe,
+            Item::FishingRod,
+            Item::Raft,
+            Item::WoodenStool,
+            Item::WoodenBench,
+            Item::HideWrap,
+            Item::Bandage,
+            Item::Thermometer,
+            Item::WeatherVane,
+            Item::ClayJar,
+            Item::Shovel,
+            Item::SignalFire,
+        ];
+        let mut hints = Vec::new();
+        for item in targets {
+            if !self.knows_blueprint(item) {
+                if let Some(hint) = self.blueprint_hint(item) {
+                    hints.push(format!("{}: {}", item.name(), hint));
+                }
+            }
+        }
+        hints
+    }
+
+    pub fn foraging_node_for(&mut self, pos: Position, map: &WorldMap) -> &mut ForageNode {
+        let biome = map
+            .index_of(&pos)
+            .and_then(|(r, c)| map.get_tile(r, c).map(|t| t.biome))
+            .unwrap_or(Biome::MixedForest);
+        let seed = self.world_seed;
+        self.forage_nodes
+            .entry(pos)
+            .or_insert_with(|| ForageNode::new(biome, seed, pos))
+    }
+
+    /// The forage node at `pos`, if anyone has foraged there. Unlike
+    /// `foraging_node_for` this never creates one.
+    pub fn forage_node_at(&self, pos: &Position) -> Option<&ForageNode> {
+        self.forage_nodes.get(pos)
+    }
+
+    /// A one-line read on what is worth gathering here, for an eye sharp
+    /// enough to tell: how picked-over the undergrowth is, and any bamboo
+    /// or laden apple tree on or beside this tile. How a patch the player
+    /// has foraged is growing back shows to anyone.
+    pub fn forage_hint(&self, map: &WorldMap) -> Option<String> {
+        if self.player.room.is_some() || self.player.inside.is_some() {
+            return None;
+        }
+        let pos = self.player.position;
+        let biome = map.biome_at(&pos)?;
+        let brush = match biome {
+            Biome::Desert => "scrub",
+            Biome::BambooGrove => "grove floor",
+            Biome::Path | Biome::Clearing => "verge",
+            _ => "undergrowth",
+        };
+        // Anyone can see the regrowth of a patch they've worked themselves.
+        let regrowth = self
+            .forage_node_at(&pos)
+            .and_then(|node| node.regrowth_line(brush));
+        if self.player.effective_skill(Skill::Observation) < FORAGE_HINT_OBSERVATION {
+            return regrowth;
+        }
+        let mut lines = Vec::new();
+
+        if biome != Biome::Lake {
+            lines.push(match self.forage_node_at(&pos) {
+                None => format!("The {} here looks untouched.", brush),
+                Some(node) if node.charges == 0 => format!(
+                    "The {} here looks stripped bare; give it a day or two.",
+                    brush
+                ),
+                Some(node) if node.charges <= 2 => format!(
+                    "The {} here has been picked over, though a little is left.",
+                    brush
+                ),
+                Some(_) => format!("There is still plenty to gather in the {} here.", brush),
+            });
+        }
+
+        let nearby: Vec<&Tree> = std::iter::once(pos)
+            .chain(
+                [
+                    Direction::North,
+                    Direction::East,
+                    Direction::South,
+                    Direction::West,
+                ]
+                .into_iter()
+                .map(|d| pos.move_in_direction(d)),
+            )
+            .filter_map(|p| self.objects.find_tree_at(&p))
+            .filter(|t| !t.felled)
+            .collect();
+        if nearby.iter().any(|t| matches!(t.kind, TreeType::Bamboo)) {
+            lines.push("Tall bamboo close by stands ready for cutting.".to_string());
+        }
+        if let Some(laden) = nearby
+            .iter()
+            .find(|t| t.is_ripe() && t.fruit_count * 2 >= t.fruit_max)
+        {
+            lines.push(match laden.kind {
+                TreeType::DatePalm => "A date palm nearby hangs heavy with ripe dates.".to_string(),
+                _ => "An apple tree nearby hangs heavy with ripe fruit.".to_string(),
+            });
+        }
+        lines.extend(regrowth);
+
+        Some(lines.join(" ")).filter(|l| !l.is_empty())
+    }
+
+    pub fn on_player_pickup(&mut self, item: &Item) {
+        self.note_item_origin(item);
+        if let Some(max) = Player::tool_max_durability(item) {
+            self.player.tool_durability.entry(*item).or_insert(max);
+        }
+        if matches!(
+            item,
+            Item::Book | Item::TutorialBook | Item::OldBook | Item::DeathNote | Item::BookOfFishing
+        ) {
+            if let Some(book_id) = self
+                .take_cabin_book_for_item(item)
+                .or_else(|| self.book_id_for_item(item).map(|s| s.to_string()))
+            {
+                self.add_player_book(&book_id);
+            }
+        }
+    }
+
+    /// Remember where a tool or keepsake first came into the player's hands.
+    fn note_item_origin(&mut self, item: &Item) {
+        if !matches!(item.category(), ItemCategory::Tool | ItemCategory::Other)
+            || self.item_origins.contains_key(item)
+        {
+            return;
+        }
+        let place = match (&self.player.room, self.player.inside.as_deref()) {
+            (Some(Room::CabinMain), _) => "in the cabin".to_string(),
+            (Some(Room::WoodShed), _) => "in the wood shed".to_string(),
+            (Some(Room::CabinTerrace), _) => "on the terrace".to_string(),
+            (None, Some(RUIN_ID)) => "in the buried ruin".to_string(),
+            (None, Some(CAVE_ID)) => "in the cave".to_string(),
+            _ => {
+                let pos = self.player.position;
+                let region = WorldMap::region_of(pos.row, pos.col);
+                format!(
+                    "at {},{} in {}",
+                    pos.row,
+                    pos.col,
+                    self.region_names.name(region)
+                )
+            }
+        };
+        self.item_origins.insert(
+            *item,
+            format!("You first picked it up {} on day {}.", place, self.time.day),
+        );
+    }
+
+    /// Where a tool or keepsake came from, and the first book the player has
+    /// read that mentions it.
+    pub fn item_provenance(&self, item: &Item) -> Option<String> {
+        if !matches!(item.category(), ItemCategory::Tool | ItemCategory::Other) {
+            return None;
+        }
+        let name = item.name().to_lowercase();
+        let mut ids: Vec<&String> = self.books.keys().collect();
+        ids.sort();
+        let mention = ids.into_iter().find_map(|id| {
+            let book = &self.books[id];
+            let read = self.book_page(id).min(book.pages.len());
+            book.pages[..read]
+                .iter()
+                .any(|page| page.to_lowercase().contains(&name))
+                .then(|| format!("{} mentions it.", book.title))
+        });
+        let notes: Vec<String> = self
+            .item_origins
+            .get(item)
+            .cloned()
+            .into_iter()
+            .chain(mention)
+            .collect();
+        (!notes.is_empty()).then(|| notes.join(" "))
+    }
+
+    pub fn on_player_drop(&mut self, item: &Item) -> Option<String> {
+        if matches!(
+            item,
+            Item::Book | Item::TutorialBook | Item::OldBook | Item::DeathNote | Item::BookOfFishing
+        ) {
+            // Prefer removing a matching special book id; otherwise pop any
+            if let Some(id) = self
+                .book_id_for_item(item)
+                .and_then(|id| self.remove_player_book(id).then(|| id.to_string()))
+            {
+                return Some(id);
+            }
+            if let Some(id) = self.pop_any_player_book() {
+                return Some(id);
+            }
+        }
+        None
+    }
+
+    fn bootstrap_structures(&mut self, map: &WorldMap) {
+        if let Some(po) = self.objects.find_mut("cabin") {
+            if let Some(cabin) = po.object.as_cabin_mut() {
+                cabin.fireplace.migrate_legacy();
+            }
+            // Move cabin to new origin
+            if po.position != Position::new(0, 0) {
+                po.position = Position::new(0, 0);
+            }
+        } else {
+            let mut cabin_state = self.legacy_cabin.take().unwrap_or_else(Cabin::new);
+            cabin_state.fireplace.migrate_legacy();
+            self.objects.add(
+                "cabin",
+                Position::new(0, 0),
+                WorldObject::new(ObjectKind::Cabin(cabin_state)),
+            );
+        }
+        if self.first_seeding(Seed::TeaThings) {
+            if let Some(cabin) = self.cabin_state_mut() {
+                Self::lay_out_tea_things(cabin);
+            }
+        }
+        // Whatever still sits on the cabin's own table moves to the table object.
+        let table_items = self
+            .cabin_state_mut()
+            .map(|c| std::mem::take(&mut c.table_items))
+            .unwrap_or_default();
+
+        let wood_shed_state = self.legacy_wood_shed.take().unwrap_or_else(WoodShed::new);
+        if self.objects.find("wood_shed").is_none() {
+            self.objects.add(
+                "wood_shed",
+                Position::new(-1, -1),
+                WorldObject::new(ObjectKind::WoodShed(wood_shed_state)),
+            );
+        } else if let Some(po) = self.objects.find_mut("wood_shed") {
+            if po.object.as_wood_shed().is_none() {
+                po.object.kind = ObjectKind::WoodShed(wood_shed_state);
+            }
+            if po.position != Position::new(-1, -1) {
+                po.position = Position::new(-1, -1);
+            }
+        }
+
+        // Ensure an east-side cave entrance exists in the winter forest
+        if let Some(cave) = self.objects.find_mut(CAVE_ID) {
+            cave.object.enterable.get_or_insert(Enterable::Inside);
+        } else {
+            let cave_pos = map.layout().cave_entrance();
+            let cave = WorldObject::new(ObjectKind::GenericStructure("cave entrance".to_string()))
+                .with_enterable(Enterable::Inside);
+            self.objects
+                .add(CAVE_ID, cave_pos, cave);
+        }
+        // A half-buried ruin in the desert west of the oasis
+        if self.objects.find(RUIN_ID).is_none() {
+            self.objects.add(
+                RUIN_ID,
+                map.layout().ruin_site(),
+                WorldObject::new(ObjectKind::Ruin(Ruin::default())),
+            );
+        }
+        // ...and that something sleeps at the back of the cave
+        if self.objects.find(CAVE_BEAR_ID).is_none() {
+            if let Some(cave_pos) = self.objects.find(CAVE_ID).map(|po| po.position) {
+                self.objects.add(
+                    CAVE_BEAR_ID,
+                    cave_pos,
+                    WorldObject::new(ObjectKind::CaveBear(CaveBear::new())),
+                );
+            }
+        }
+
+        self.ensure_table_object(table_items);
+        self.ensure_mantel_object();
+        self.ensure_room_surfaces();
+        self.ensure_seat_objects();
+        self.ensure_duck_present(map);
+        self.ensure_pig_carcass_near_cabin();
     }
 
     fn ensure_tree_objects_from_legacy(&mut self) {
@@ -936,740 +2767,5267 @@ impl GameState {
         }
     }
 
-    fn ensure_pig_carcass_near_cabin(&mut self) {
-        // Place a small starter carcass just south of the cabin, if none exists yet.
-        let pig_pos = Position::new(1, 0);
-        let exists = self.objects.placed.iter().any(|po| {
-            po.position == pig_pos
-                && matches!(po.object.kind, ObjectKind::Corpse(_) | ObjectKind::GenericStructure(_))
-        });
-        if !exists {
-            let corpse = WorldObject::new(ObjectKind::Corpse(Corpse {
-                species: Species::Pig,
-                freshness: 0,
-                body: None,
-            }));
-            self.objects.add("starter_pig", pig_pos, corpse);
+    fn ensure_pig_carcass_near_cabin(&mut self) {
+        // Place a small starter carcass just south of the cabin, once per world.
+        if !self.first_seeding(Seed::StarterPig) {
+            return;
+        }
+        let corpse = WorldObject::new(ObjectKind::Corpse(Corpse {
+            species: Species::Pig,
+            freshness: 0,
+            body: None,
+        }));
+        self.objects.add("starter_pig", Position::new(1, 0), corpse);
+    }
+
+    pub fn take_table_item(&mut self, item: &Item) -> bool {
+        if let Some(surface) = self.table_surface_mut() {
+            return surface.take_item(item);
+        }
+        if let Some(cabin) = self.cabin_state_mut() {
+            return cabin.take_table_item(item);
+        }
+        false
+    }
+
+    pub fn add_table_item(&mut self, item: Item) {
+        if let Some(surface) = self.table_surface_mut() {
+            surface.add_item(item);
+            return;
+        }
+        if let Some(cabin) = self.cabin_state_mut() {
+            cabin.add_table_item(item);
+        }
+    }
+
+    pub fn take_mantel_item(&mut self, item: &Item) -> bool {
+        self.mantel_surface_mut()
+            .map(|s| s.take_item(item))
+            .unwrap_or(false)
+    }
+
+    pub fn mantel_item_names(&self) -> Vec<String> {
+        self.mantel_surface()
+            .map(|s| s.items.labels(|i| self.display_name(i)))
+            .unwrap_or_default()
+    }
+
+    pub fn table_item_names(&self) -> Vec<String> {
+        if let Some(surface) = self.table_surface() {
+            return surface.items.labels(|i| self.display_name(i));
+        }
+        self.cabin_state()
+            .map(|c| c.table_item_names())
+            .unwrap_or_default()
+    }
+
+    /// The unclaimed hidden detail on the player's tile, noticed or not.
+    pub fn hidden_detail_here(&self, map: &WorldMap) -> Option<HiddenDetail> {
+        if self.player.room.is_some() || self.player.inside.is_some() {
+            return None;
+        }
+        let pos = self.player.position;
+        if self.claimed_details.contains(&pos) {
+            return None;
+        }
+        let biome = map.biome_at(&pos)?;
+        HiddenDetail::at(pos, biome)
+    }
+
+    /// The hidden detail here, if the player's eye is sharp enough to catch
+    /// it or they've already searched it out.
+    pub fn noticed_detail(&self, map: &WorldMap) -> Option<HiddenDetail> {
+        let detail = self.hidden_detail_here(map)?;
+        let sharp = self.player.effective_skill(Skill::Observation) >= detail.threshold();
+        (sharp || self.noticed_details.contains(&self.player.position)).then_some(detail)
+    }
+
+    /// Examine a noticed detail: take whatever it holds and note it down.
+    /// Each one can only be claimed once.
+    pub fn claim_hidden_detail(&mut self, target: &str, map: &mut WorldMap) -> Option<String> {
+        let detail = self.noticed_detail(map)?;
+        let target = target.to_lowercase();
+        if !detail.keywords().iter().any(|k| target.contains(k)) {
+            return None;
+        }
+        let pos = self.player.position;
+        self.claimed_details.insert(pos);
+        self.noticed_details.remove(&pos);
+        self.player.skills.improve(Skill::Observation, 2);
+        self.journal.push(detail.lore().to_string());
+
+        let mut message = detail.lore().to_string();
+        let mut found = Vec::new();
+        for (item, qty) in detail.find() {
+            found.push(quantity_label(&self.display_name(item), *qty));
+        }
+        if !found.is_empty() {
+            message.push_str(&format!(" You take: {}.", found.join(", ")));
+            if let Some(note) = self.land_at_feet(map, detail.find(), true) {
+                message.push(' ');
+                message.push_str(&note);
+            }
+        }
+        Some(message)
+    }
+
+    pub fn raft(&self) -> Option<&PlacedObject> {
+        self.objects.find(RAFT_ID)
+    }
+
+    pub fn aboard_raft(&self) -> bool {
+        self.player.inside.as_deref() == Some(RAFT_ID)
+    }
+
+    /// The deployed raft is on or next to the player's tile.
+    pub fn raft_within_reach(&self) -> bool {
+        self.raft()
+            .map(|po| self.player.position.distance_to(&po.position) <= 1.5)
+            .unwrap_or(false)
+    }
+
+    /// The object with storage that `target` names, out in the open within
+    /// arm's reach: on the player's tile, or the raft moored alongside.
+    pub fn storage_at_hand(&self, target: &str) -> Option<String> {
+        if self.player.room.is_some() {
+            return None;
+        }
+        let target = target.trim().to_lowercase();
+        if target.is_empty() {
+            return None;
+        }
+        self.objects
+            .placed
+            .iter()
+            .filter(|po| po.object.has_storage() && !po.object.is_hidden())
+            .filter(|po| {
+                po.position == self.player.position
+                    || (po.id == RAFT_ID && self.raft_within_reach())
+            })
+            .find(|po| {
+                let name = po.object.display_name().to_lowercase();
+                target.contains(&name) || name.contains(&target)
+            })
+            .map(|po| po.id.clone())
+    }
+
+    /// Closest walkable tile to `pos`, searching outward a ring at a time
+    /// from the nearest point on the map.
+    pub fn nearest_land(pos: Position, map: &WorldMap) -> Option<Position> {
+        let extent = map.extent();
+        let pos = Position::new(
+            pos.row.clamp(-extent, extent),
+            pos.col.clamp(-extent, extent),
+        );
+        for radius in 0..=extent * 2 {
+            let mut best: Option<(Position, f32)> = None;
+            for dr in -radius..=radius {
+                for dc in -radius..=radius {
+                    let p = Position::new(pos.row + dr, pos.col + dc);
+                    let Some((r, c)) = map.index_of(&p) else {
+                        continue;
+                    };
+                    if !map.is_walkable(r, c) {
+                        continue;
+                    }
+                    let d = pos.distance_to(&p);
+                    if best.map(|(_, bd)| d < bd).unwrap_or(true) {
+                        best = Some((p, d));
+                    }
+                }
+            }
+            if let Some((p, _)) = best {
+                return Some(p);
+            }
+        }
+        None
+    }
+
+    /// Puts the player back on solid ground if they've ended up somewhere
+    /// they can't be: off the map, or on foot on a tile nobody can stand
+    /// on. Run after every tool call, so a slip anywhere is caught at once.
+    pub fn keep_player_on_map(&mut self, map: &WorldMap) {
+        let pos = self.player.position;
+        let on_foot = self.player.room.is_none() && self.player.inside.is_none();
+        let sound = map
+            .index_of(&pos)
+            .map(|(r, c)| !on_foot || map.is_walkable(r, c))
+            .unwrap_or(false);
+        if sound {
+            return;
+        }
+        let Some(landing) = Self::nearest_land(pos, map) else {
+            return;
+        };
+        tracing::warn!(
+            "Player at {},{} was off solid ground; moved to {},{}",
+            pos.row,
+            pos.col,
+            landing.row,
+            landing.col
+        );
+        if !map.contains(&pos) {
+            self.player.room = None;
+            self.player.inside = None;
+        }
+        self.player.position = landing;
+        self.player.mark_visited();
+        self.pending_messages.push(
+            "The world tilts for a dizzy moment. When it steadies, you're standing on firm ground again."
+                .to_string(),
+        );
+    }
+
+    /// Under a roof or in rock: the cabin, the shed, the cave or the ruin.
+    /// Out on the raft is out in the weather.
+    pub fn sheltered(&self) -> bool {
+        self.player.room.is_some()
+            || self
+                .player
+                .inside
+                .as_deref()
+                .is_some_and(|id| id != RAFT_ID)
+    }
+
+    /// A word at the threshold when the player steps out into foul weather:
+    /// what it's doing out here and what they have against it. Given once a
+    /// spell; the next one comes after the weather has changed.
+    pub fn weather_advisory(&mut self) -> Option<String> {
+        let pos = self.player.position;
+        let region = WorldMap::region_of(pos.row, pos.col);
+        let weather = self.weather.for_region(region);
+        let conditions = match weather {
+            Weather::Blizzard => {
+                "A blizzard is howling across the ground out here; the cold will bite fast."
+            }
+            Weather::HeavySnow => {
+                "Heavy snow is coming down out here, and the cold creeps in quickly."
+            }
+            Weather::HeavyRain => {
+                "Rain is sheeting down out here; you'll be soaked and chilled before long."
+            }
+            Weather::Sandstorm => {
+                "A sandstorm is scouring the ground out here; you'll see little and breathe grit."
+            }
+            Weather::HeatWave => "The heat out here is fierce; you'll dry out fast in the sun.",
+            _ => return None,
+        };
+        if self.advised_weather == Some((region, weather)) {
+            return None;
+        }
+        self.advised_weather = Some((region, weather));
+
+        let inventory = &self.player.inventory;
+        let carried = |items: &[Item]| -> Vec<String> {
+            items
+                .iter()
+                .filter(|i| inventory.has(i, 1))
+                .map(|i| self.display_name(i))
+                .collect()
+        };
+        let mut lines = vec![conditions.to_string()];
+        let dark = self.time.time_of_day().is_night();
+        if dark {
+            lines.push("It's dark, too.".to_string());
+        }
+        if matches!(
+            weather,
+            Weather::Blizzard | Weather::HeavySnow | Weather::HeavyRain
+        ) {
+            let warm = carried(&[Item::WoolBlanket, Item::HideWrap]);
+            lines.push(if warm.is_empty() {
+                format!(
+                    "Your warmth is {:.0}, and you have nothing warm to pull around you.",
+                    self.player.warmth
+                )
+            } else {
+                format!(
+                    "Your warmth is {:.0}; you have the {} to pull around you.",
+                    self.player.warmth,
+                    warm.join(" and the ")
+                )
+            });
+        }
+        if dark || weather == Weather::Sandstorm {
+            let jar = inventory.has(&Item::FireflyJar, 1);
+            lines.push(if inventory.has(&Item::LitKindling, 1) {
+                "The burning kindling will light a few steps.".to_string()
+            } else if jar && self.player.hands.holds(&Item::FireflyJar) {
+                "The firefly jar in your hand will light the way.".to_string()
+            } else if jar {
+                "Your firefly jar is packed away; hold it to light the way.".to_string()
+            } else {
+                "You carry no light.".to_string()
+            });
+        }
+        if matches!(weather, Weather::HeatWave | Weather::Sandstorm) {
+            let water: Vec<String> = inventory
+                .slots
+                .iter()
+                .filter(|slot| slot.quantity > 0 && slot.item.is_drink())
+                .map(|slot| self.display_name(&slot.item))
+                .collect();
+            lines.push(if water.is_empty() {
+                "You have nothing to drink with you.".to_string()
+            } else {
+                format!("You have {} to drink.", water.join(" and "))
+            });
+        }
+        Some(format!("You pause on the threshold. {}", lines.join(" ")))
+    }
+
+    /// A raft left moored out on the water can be shoved a tile by a storm,
+    /// once per storm. Pulled up on the bank it stays put.
+    fn update_raft(&mut self, map: &WorldMap, rng: &mut impl Rng) {
+        if self.aboard_raft() {
+            return;
+        }
+        let Some(pos) = self.raft().map(|po| po.position) else {
+            return;
+        };
+        let on_water = map.is_open_water(&pos);
+        let stormy = matches!(
+            self.weather.get_for_position(pos.row, pos.col),
+            Weather::Blizzard | Weather::HeavyRain | Weather::HeavySnow | Weather::Sandstorm
+        );
+        let player_pos = self.player.position;
+        let Some(raft) = self.objects.find_mut(RAFT_ID) else {
+            return;
+        };
+        let drifted = raft.object.as_raft_mut().map(|r| r.drifted).unwrap_or(true);
+        if !stormy {
+            if let Some(r) = raft.object.as_raft_mut() {
+                r.drifted = false;
+            }
+            return;
+        }
+        if !on_water || drifted || !rng.gen_bool(0.05) {
+            return;
+        }
+        let dirs = [Direction::North, Direction::South, Direction::East, Direction::West];
+        let dir = dirs[rng.gen_range(0..dirs.len())];
+        // Only ever onto open water: never ashore, never off the edge of the map
+        let next = pos.move_in_direction(dir);
+        if !map.is_open_water(&next) {
+            return;
+        }
+        raft.position = next;
+        if let Some(r) = raft.object.as_raft_mut() {
+            r.drifted = true;
+        }
+        if player_pos.distance_to(&next) <= 4.0 {
+            self.pending_messages.push(format!(
+                "Out on the water, the storm shoves your moored raft a little way {}.",
+                dir.name()
+            ));
+        }
+    }
+
+    /// The raft breaks up under the player out on the lake. They swim for the
+    /// nearest shore, and whatever rode on the prow washes up beside them.
+    pub fn wreck_raft(&mut self, map: &mut WorldMap) -> String {
+        let pos = self.player.position;
+        self.objects.remove(RAFT_ID);
+        self.player.inside = None;
+        let landing = Self::nearest_land(pos, map).unwrap_or(pos);
+        self.player.position = landing;
+        self.player.mark_visited();
+        self.player.modify_warmth(-25.0);
+        self.player.modify_energy(-15.0);
+        self.player.modify_health(-5.0);
+        self.player.modify_mood(-6.0);
+
+        let mut message = "A wave slams into the raft and the lashings give way; the logs spin apart beneath you and you're in the freezing water. You swim, gasping, until your feet find the bottom, and drag yourself onto the shore."
+            .to_string();
+        // Swimming takes both hands; whatever was in them may not come too.
+        let mut rng = rand::thread_rng();
+        let held: Vec<Item> = self.player.hands.items().collect();
+        for item in held {
+            if rng.gen_bool(0.5) {
+                self.player.hands.stow(&item);
+                self.player.inventory.remove(&item, 1);
+                message.push_str(&format!(
+                    " The {} is torn from your grip as you swim and sinks out of sight.",
+                    self.display_name(&item)
+                ));
+            }
+        }
+        if let Some(prow) = self.player.mounts.raft_prow.take() {
+            if let Some(tile) = map.tile_at_mut(&landing) {
+                tile.items.add(prow, 1);
+            }
+            message.push_str(&format!(
+                " A moment later the {} washes up beside you.",
+                self.display_name(&prow)
+            ));
+        }
+        message
+    }
+
+    pub fn in_cave(&self) -> bool {
+        self.player.inside.as_deref() == Some(CAVE_ID)
+    }
+
+    /// The bear, while it still has the cave.
+    pub fn cave_bear(&self) -> Option<&CaveBear> {
+        self.objects
+            .find(CAVE_BEAR_ID)
+            .and_then(|po| po.object.as_cave_bear())
+            .filter(|b| b.state != BearState::DrivenOff)
+    }
+
+    pub fn cave_bear_mut(&mut self) -> Option<&mut CaveBear> {
+        self.objects
+            .find_mut(CAVE_BEAR_ID)
+            .and_then(|po| po.object.as_cave_bear_mut())
+            .filter(|b| b.state != BearState::DrivenOff)
+    }
+
+    /// Noise the player makes; only matters inside the cave.
+    /// A loud outdoor noise: every animal within `radius` tiles grows warier,
+    /// the nearer the more so.
+    pub fn startle_wildlife(&mut self, amount: f32, radius: f32) {
+        let pos = self.player.position;
+        for w in &mut self.wildlife {
+            let dist = pos.distance_to(&w.position);
+            if dist <= radius {
+                w.alarm(amount * (1.0 - dist / (radius + 1.0)));
+            }
+        }
+    }
+
+    pub fn make_noise(&mut self, amount: u8) {
+        if !self.in_cave() {
+            return;
+        }
+        let Some(change) = self.cave_bear_mut().and_then(|b| b.disturb(amount)) else {
+            return;
+        };
+        let message = match change {
+            BearState::Stirring => {
+                "The sound rolls back into the cave. Deep in the dark, something huge groans and shifts its weight."
+            }
+            BearState::Hostile => {
+                "A roar fills the cave, so loud you feel it in your teeth. Claws scrape stone as something enormous rises in the dark."
+            }
+            _ => return,
+        };
+        self.pending_messages.push(message.to_string());
+    }
+
+    /// Keep the bear's state machine moving: fire inside the cave wakes it,
+    /// an awake bear charges anyone still in there, and it settles back to
+    /// sleep once the cave is quiet.
+    fn update_cave_bear(&mut self, map: &WorldMap, rng: &mut impl Rng) {
+        let in_cave = self.in_cave();
+        let fire_inside = in_cave
+            && map
+                .tile_at(&self.player.position)
+                .map(|t| t.items.items.iter().any(|(i, q)| *i == Item::Campfire && *q > 0))
+                .unwrap_or(false);
+        if fire_inside {
+            let was_hostile = self
+                .cave_bear()
+                .map(|b| b.state == BearState::Hostile)
+                .unwrap_or(true);
+            if !was_hostile {
+                self.pending_messages.push(
+                    "Smoke and firelight fill the cave. From the back comes a furious bellow."
+                        .to_string(),
+                );
+            }
+            self.make_noise(CaveBear::WAKE_AT);
+        }
+
+        let Some(bear) = self.cave_bear_mut() else {
+            return;
+        };
+        if !in_cave {
+            let slipped_away = bear.crept_in && bear.state == BearState::Hibernating && !bear.avoided;
+            bear.crept_in = false;
+            bear.fighting = false;
+            let settled = bear.settle();
+            if slipped_away {
+                bear.avoided = true;
+                self.journal.push(
+                    "I crept into the east cave and listened to something enormous breathing in the dark. I left it sleeping. Some doors are better left closed."
+                        .to_string(),
+                );
+                self.pending_messages.push(
+                    "Back out under the open sky, you let out a breath you didn't know you were holding. (Achievement unlocked: Let Sleeping Bears Lie. Knowing when to leave is a skill too.)"
+                        .to_string(),
+                );
+            } else if settled == Some(BearState::Hibernating) && self
+                .objects
+                .find(CAVE_ID)
+                .map(|po| self.player.position.distance_to(&po.position) <= 3.0)
+                .unwrap_or(false) {
+                self.pending_messages
+                    .push("From the cave mouth, the heavy breathing slows and deepens again.".to_string());
+            }
+            return;
+        }
+
+        match bear.state {
+            BearState::Hibernating => bear.crept_in = true,
+            BearState::Hostile if bear.fighting => bear.fighting = false,
+            BearState::Hostile => {
+                let first = !bear.mauled;
+                bear.mauled = true;
+                let parts = [
+                    BodyPartKind::Torso,
+                    BodyPartKind::ArmLeft,
+                    BodyPartKind::ArmRight,
+                    BodyPartKind::LegLeft,
+                    BodyPartKind::LegRight,
+                ];
+                let part = parts[rng.gen_range(0..parts.len())];
+                let damage = rng.gen_range(18.0..30.0);
+                self.player.injure_part(part, damage, true);
+                self.player.inside = None;
+                self.player.modify_mood(-10.0);
+                self.pending_messages.push(format!(
+                    "The bear charges out of the dark. A swipe catches your {} and flings you back; you scramble, bleeding, out of the cave mouth into the open.",
+                    part.name()
+                ));
+                if first {
+                    self.journal.push(
+                        "The bear in the east cave woke and came for me. I got out, barely, with its marks on me. Next time: quiet, or a fish, or don't go in at all."
+                            .to_string(),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Carried logs or firewood that have already seasoned.
+    pub fn carried_seasoned(&self, item: &Item) -> u32 {
+        self.player
+            .inventory
+            .count(item)
+            .saturating_sub(self.carried_green_wood.count(item))
+    }
+
+    /// Forget green wood the player no longer carries (burned, whittled,
+    /// built with, or left somewhere).
+    pub fn sync_carried_wood(&mut self) {
+        for item in [Item::Log, Item::Firewood] {
+            let held = self.player.inventory.count(&item);
+            self.carried_green_wood.trim(&item, held);
+        }
+    }
+
+    /// Grill whatever is over the cabin fire a little more. A roaring fire
+    /// cooks fast and a smoldering one slowly, so the right moment to pull
+    /// it passes quickest over a roaring one. Someone in the room smells
+    /// when it's ready and when it starts to scorch; left alone too long,
+    /// it may burn away to nothing.
+    fn update_cooking(&mut self, rng: &mut impl Rng) {
+        let dishes: Vec<Item> = self.cooked_portions.portions.iter().map(|p| p.0).collect();
+        for dish in dishes {
+            let held = self.player.inventory.count(&dish);
+            self.cooked_portions.trim(&dish, held);
+        }
+
+        let heat = match self.cabin_state().map(|c| c.fireplace.state) {
+            Some(FireState::Smoldering) => 0.5,
+            Some(FireState::Burning) => 1.0,
+            Some(FireState::Roaring) => 1.5,
+            _ => return,
+        } * self.time.tick_scale();
+        let present = matches!(self.player.room, Some(Room::CabinMain));
+        let Some(food) = self.on_the_fire.as_mut() else {
+            return;
+        };
+        food.heat += heat;
+        let label = food.label();
+        if present {
+            match food.doneness() {
+                Doneness::Perfect if !food.cued => {
+                    food.cued = true;
+                    self.pending_messages
+                        .push(format!("The {} on the fire smells about ready.", label));
+                }
+                Doneness::Charred if !food.scorch_cued => {
+                    food.scorch_cued = true;
+                    self.pending_messages.push(format!(
+                        "A sharp smell of scorching drifts from the hearth: the {} is starting to char.",
+                        label
+                    ));
+                }
+                _ => {}
+            }
+        } else if food.can_burn_away() && rng.gen_bool(0.5) {
+            self.on_the_fire = None;
+            self.pending_messages.push(format!(
+                "The {} you left over the cabin fire will have burned away to charcoal by now.",
+                label
+            ));
+        }
+    }
+
+    /// What's grilling over the fire, for the view of the main room.
+    pub fn cooking_note(&self) -> Option<String> {
+        if !matches!(self.player.room, Some(Room::CabinMain)) {
+            return None;
+        }
+        let food = self.on_the_fire.as_ref()?;
+        let label = food.label();
+        Some(match food.doneness() {
+            Doneness::Undercooked => format!(
+                "The {} sizzles on the grate over the fire, not done yet.",
+                label
+            ),
+            Doneness::Perfect => format!(
+                "The {} on the grate smells ready to come off the fire.",
+                label
+            ),
+            Doneness::Charred => format!(
+                "The {} on the grate is blackening at the edges. Take it off the fire!",
+                label
+            ),
+        })
+    }
+
+    /// Stacked wood dries in the shed; carried wood dries slowly beside a
+    /// roaring hearth.
+    fn update_wood_seasoning(&mut self) {
+        self.sync_carried_wood();
+        if let Some(shed) = self.wood_shed_state_mut() {
+            let (logs, firewood) = (shed.logs, shed.firewood);
+            shed.green.trim(&Item::Log, logs);
+            shed.green.trim(&Item::Firewood, firewood);
+            shed.green.age(1);
+        }
+        let by_fire = matches!(self.player.room, Some(Room::CabinMain))
+            && self
+                .cabin_state()
+                .map(|c| c.fireplace.state == FireState::Roaring)
+                .unwrap_or(false);
+        if by_fire && self.time.tick.is_multiple_of(2) && self.carried_green_wood.age(1) > 0 {
+            self.pending_messages.push(
+                "The wood you're carrying has finally dried by the hearth; it feels lighter and rings when knocked."
+                    .to_string(),
+            );
+        }
+    }
+
+    /// Count every playing card across the case, the pack, the cabin and the
+    /// ground.
+    pub fn card_census(&self, map: &WorldMap) -> CardCensus {
+        let count_in = |items: &LocationItems| items.count(&Item::PlayingCard);
+        let mut census = CardCensus {
+            in_case: self.card_case_cards_inside as u32,
+            carried: self.player.inventory.count(&Item::PlayingCard),
+            lost: self.cards_lost,
+            ..CardCensus::default()
+        };
+
+        for placed in &self.objects.placed {
+            if let Some(cabin) = placed.object.as_cabin() {
+                census.in_cabin += count_in(&cabin.items) + count_in(&cabin.table_items);
+            }
+            if let Some(surface) = &placed.object.surface {
+                census.in_cabin += count_in(&surface.items);
+            }
+        }
+
+        for r in 0..map.height() {
+            for c in 0..map.width() {
+                if let Some(tile) = map.get_tile(r, c) {
+                    for (item, qty) in &tile.items.items {
+                        if *item == Item::PlayingCard {
+                            census.on_ground += *qty;
+                        }
+                    }
+                }
+            }
+        }
+
+        census
+    }
+
+    fn ensure_card_case_state(&mut self, map: &WorldMap) {
+        let census = self.card_census(map);
+        if census.total() == 0 && census.lost == 0 {
+            self.card_case_cards_inside = 52;
+            self.card_case_open = false;
+        }
+    }
+
+    /// Keep the deck at 52: absorb any surplus cards, and once per game let
+    /// the case turn up replacements for the ones lost for good.
+    pub fn audit_cards(&mut self, map: &mut WorldMap) {
+        let census = self.card_census(map);
+        let mut excess = census.total().saturating_sub(CardCensus::DECK_SIZE);
+        if excess > 0 {
+            let from_case = excess.min(self.card_case_cards_inside as u32);
+            self.card_case_cards_inside -= from_case as u8;
+            excess -= from_case;
+        }
+        while excess > 0 && self.player.inventory.remove(&Item::PlayingCard, 1) {
+            excess -= 1;
+        }
+        'ground: for r in 0..map.height() {
+            for c in 0..map.width() {
+                if excess == 0 {
+                    break 'ground;
+                }
+                if let Some(tile) = map.get_tile_mut(r, c) {
+                    while excess > 0 && tile.items.take(&Item::PlayingCard) {
+                        excess -= 1;
+                    }
+                }
+            }
+        }
+
+        if census.lost == 0
+            || self.cards_regenerated
+            || !self.player.inventory.has(&Item::CardCase, 1)
+        {
+            return;
+        }
+        let room = CardCensus::DECK_SIZE.saturating_sub(self.card_census(map).total());
+        let restored = census.lost.min(room);
+        self.cards_regenerated = true;
+        self.cards_lost = 0;
+        if restored == 0 {
+            return;
+        }
+        self.card_case_cards_inside =
+            (self.card_case_cards_inside as u32 + restored).min(52) as u8;
+        self.pending_messages.push(format!(
+            "Tucked behind the card case's worn lining you find {} spare card{}, as if someone once planned for losing a few.",
+            restored,
+            if restored == 1 { "" } else { "s" }
+        ));
+    }
+
+    /// How many of an item exist anywhere in the world: carried or perched,
+    /// in the cabin or on a surface, stowed in something, on the ground or
+    /// under the sand.
+    pub fn item_census(&self, item: &Item, map: &WorldMap) -> u32 {
+        let mounts = &self.player.mounts;
+        let mut count = self.player.inventory.count(item)
+            + [&mounts.shoulder, &mounts.raft_prow]
+                .iter()
+                .filter(|m| m.as_ref() == Some(item))
+                .count() as u32;
+
+        for placed in &self.objects.placed {
+            if let Some(cabin) = placed.object.as_cabin() {
+                count += cabin.items.count(item) + cabin.table_items.count(item);
+            }
+            if let Some(surface) = &placed.object.surface {
+                count += surface.items.count(item);
+            }
+            if let Some(storage) = &placed.object.storage {
+                count += storage.items.count(item);
+            }
+        }
+
+        for r in 0..map.height() {
+            for c in 0..map.width() {
+                if let Some(tile) = map.get_tile(r, c) {
+                    count += tile.items.count(item);
+                }
+            }
+        }
+
+        for stash in &self.sands.buried {
+            for (i, qty) in &stash.items {
+                if i == item {
+                    count += *qty;
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Put any one-of-a-kind thing that has come to exist twice back to one,
+    /// keeping the copy nearest the player's hand.
+    pub fn audit_unique_items(&mut self, map: &mut WorldMap) {
+        for item in Item::UNIQUE {
+            let excess = self.item_census(&item, map).saturating_sub(1);
+            if excess > 0 {
+                tracing::warn!("Found {} extra {:?}; removing them", excess, item);
+                self.remove_extras(&item, excess, map);
+            }
+        }
+    }
+
+    /// Take `excess` of an item out of the world, from the ground and the
+    /// sand first, then the cabin and what's stowed, and the player last.
+    fn remove_extras(&mut self, item: &Item, mut excess: u32, map: &mut WorldMap) {
+        'ground: for r in 0..map.height() {
+            for c in 0..map.width() {
+                if excess == 0 {
+                    break 'ground;
+                }
+                if let Some(tile) = map.get_tile_mut(r, c) {
+                    while excess > 0 && tile.items.take(item) {
+                        excess -= 1;
+                    }
+                }
+            }
+        }
+
+        for stash in &mut self.sands.buried {
+            for (_, qty) in stash.items.iter_mut().filter(|(i, _)| i == item) {
+                let taken = excess.min(*qty);
+                *qty -= taken;
+                excess -= taken;
+            }
+            stash.items.retain(|(_, qty)| *qty > 0);
+        }
+
+        let mut take_from = |items: &mut LocationItems| {
+            while excess > 0 && items.take(item) {
+                excess -= 1;
+            }
+        };
+        for placed in &mut self.objects.placed {
+            if let Some(surface) = placed.object.surface.as_mut() {
+                take_from(&mut surface.items);
+            }
+            if let Some(storage) = placed.object.storage.as_mut() {
+                take_from(&mut storage.items);
+            }
+            if let Some(cabin) = placed.object.as_cabin_mut() {
+                take_from(&mut cabin.items);
+                take_from(&mut cabin.table_items);
+            }
+        }
+
+        let mounts = &mut self.player.mounts;
+        for perch in [&mut mounts.raft_prow, &mut mounts.shoulder] {
+            if excess > 0 && perch.as_ref() == Some(item) {
+                *perch = None;
+                excess -= 1;
+            }
+        }
+        while excess > 0 && self.player.inventory.remove(item, 1) {
+            excess -= 1;
+        }
+    }
+
+    pub fn usual_event_rate() -> f64 {
+        1.0
+    }
+
+    /// Create a new game state with initial values
+    pub fn new(map: &WorldMap) -> Self {
+        let mut rng = rand::thread_rng();
+        let mut state = Self {
+            version: "1.0".to_string(),
+            world_seed: map.seed(),
+            map_extent: map.extent(),
+            time: WorldTime::new(),
+            weather: RegionalWeather::new(),
+            player: Player::new(),
+            wildlife: spawn_wildlife(),
+            objects: ObjectRegistry::new(),
+            custom_names: HashMap::new(),
+            forage_nodes: HashMap::new(),
+            books: GameState::default_books(),
+            next_book_id: GameState::default_next_book_id(),
+            pending_messages: Vec::new(),
+            legacy_cabin: None,
+            legacy_wood_shed: None,
+            legacy_trees: None,
+            map_studies: 0,
+            card_case_cards_inside: 52,
+            card_case_open: false,
+            card_scatter_achievement: false,
+            cards_lost: 0,
+            cards_regenerated: false,
+            carried_green_wood: GreenWood::default(),
+            firefly_jar_minutes: 0,
+            health_regen: HealthRegen::default(),
+            rescue: RescueGoal::default(),
+            sands: DesertSands::default(),
+            wildfire: Wildfire::default(),
+            on_the_fire: None,
+            cooked_portions: CookedPortions::default(),
+            tool_tally: ToolTally::default(),
+            region_names: RegionNames::default(),
+            item_origins: HashMap::new(),
+            death_note: DeathNote::default(),
+            sleeping: false,
+            exerting: false,
+            idle_ticking: false,
+            event_rate: Self::usual_event_rate(),
+            urgent_need: None,
+            vital_bands: VitalBands::default(),
+            noticed_details: HashSet::new(),
+            claimed_details: HashSet::new(),
+            duck_throw_achievement: false,
+            rituals: RitualLog::default(),
+            last_dawn_summary_day: 0,
+            advised_weather: None,
+            pending_snapshots: Vec::new(),
+            tutorial_reward_claimed: false,
+            seeded: skipped_seeds_from_env(),
+            tutorial_hint_shown: false,
+            practice: PracticeTally::default(),
+            tutorial_by_practice: false,
+            paused_projects: Vec::new(),
+            last_project_id: 0,
+            last_seat_id: 0,
+            letters: DuckLetters {
+                week: WeekLog::begin(1, &PracticeTally::default()),
+                ..DuckLetters::default()
+            },
+            cooked_dishes: Vec::new(),
+            recipe_book_id: None,
+            kills: Vec::new(),
+            first_kills: Vec::new(),
+            sketched_species: Vec::new(),
+            fishing_pressure: HashMap::new(),
+            raid_tracks: Vec::new(),
+            field_notes: Vec::new(),
+            field_notes_id: None,
+            journal: Vec::new(),
+            lingering_mood: 0.0,
+            lingering_ticks: 0,
+        };
+        state.ensure_book_registry();
+        state.bootstrap_structures(map);
+        state.ensure_cabin_books(map);
+        state.ensure_player_visit();
+        state.refresh_blueprint_knowledge(false);
+        state.seed_bamboo_grove(map);
+        state.seed_oasis_palms(map);
+        state.ensure_card_case_state(map);
+        state.seed_tree_population(map, &mut rng, 10);
+        state.ensure_tree_density(map, &mut rng);
+        state.update_player_cognition();
+        state.player.skills.start_day(state.time.day);
+        state
+    }
+
+    /// Save state to a JSON file
+    /// Write the state to `path`, returning how many bytes it came to.
+    pub fn save(&self, path: &Path) -> Result<u64> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, &json)?;
+        Ok(json.len() as u64)
+    }
+
+    /// Take a milestone snapshot of the save as it stands right now. It's
+    /// written beside the rolling save the next time the world is saved.
+    pub fn milestone(&mut self, name: String) {
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => self.pending_snapshots.push(PendingSnapshot { name, json }),
+            Err(e) => tracing::warn!("Failed to take snapshot {}: {}", name, e),
+        }
+    }
+
+    /// Load state from a JSON file
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        let state: GameState = serde_json::from_str(&json)?;
+        Ok(state)
+    }
+
+    /// Saves from before seeds were tracked had the cabin stocked and the pig
+    /// laid out on their first load already.
+    fn legacy_seeds() -> HashSet<Seed> {
+        HashSet::from([Seed::TeaThings, Seed::StarterPig])
+    }
+
+    /// Claim a one-time seed: true only the first time it's asked for, and
+    /// never for one the scenario skipped.
+    fn first_seeding(&mut self, seed: Seed) -> bool {
+        self.seeded.insert(seed)
+    }
+
+    fn default_map_extent() -> i32 {
+        DEFAULT_MAP_EXTENT
+    }
+
+    /// The seed and map extent a save was made with, without loading the
+    /// rest of it. Older saves were all made on the authored default map.
+    pub fn saved_map_settings(path: &Path) -> Option<(u64, i32)> {
+        let json = std::fs::read_to_string(path).ok()?;
+        let value: serde_json::Value = serde_json::from_str(&json).ok()?;
+        let seed = value.get("world_seed").and_then(|s| s.as_u64()).unwrap_or(0);
+        let extent = value
+            .get("map_extent")
+            .and_then(|e| e.as_i64())
+            .map(|e| e as i32)
+            .unwrap_or(DEFAULT_MAP_EXTENT);
+        Some((seed, extent))
+    }
+
+    /// Load state or create new if file doesn't exist
+    pub fn load_or_new(path: &Path, map: &WorldMap) -> Self {
+        if path.exists() {
+            match Self::load(path) {
+                Ok(mut state) => {
+                    tracing::info!("Loaded existing game state from {:?}", path);
+                    if state.wildlife.is_empty() {
+                        tracing::info!("Wildlife was empty, spawning new wildlife");
+                        state.wildlife = spawn_wildlife();
+                    }
+                    if state.custom_names.is_empty() {
+                        state.custom_names = HashMap::new();
+                    }
+                    if state.forage_nodes.is_empty() {
+                        state.forage_nodes = HashMap::new();
+                    }
+
+                    if state.books.is_empty() {
+                        state.books = GameState::default_books();
+                    }
+                    state.ensure_book_registry();
+
+                    state.ensure_tree_objects_from_legacy();
+                    state.bootstrap_structures(map);
+                    state.ensure_cabin_books(map);
+                    state.ensure_player_visit();
+                    state.refresh_blueprint_knowledge(false);
+                    state.seed_bamboo_grove(map);
+                    state.seed_oasis_palms(map);
+
+                    state.ensure_card_case_state(map);
+
+                    let mut rng = rand::thread_rng();
+                    state.seed_tree_population(map, &mut rng, 10);
+                    state.ensure_tree_density(map, &mut rng);
+                    state.update_player_cognition();
+                    state.compact(map);
+                    state
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load state: {}, creating new", e);
+                    Self::new(map)
+                }
+            }
+        } else {
+            tracing::info!("No save file found, creating new game state");
+            Self::new(map)
+        }
+    }
+
+    /// Trim what would otherwise pile up in the save forever: forage patches
+    /// fully grown back, stumps that have rotted away, fox tracks gone cold,
+    /// yesterday's skill gains, and queued messages past the cap. Run on
+    /// load and once a game day while ticking.
+    pub fn compact(&mut self, map: &WorldMap) {
+        let seed = self.world_seed;
+        self.forage_nodes.retain(|pos, node| {
+            let biome = map.biome_at(pos).unwrap_or(Biome::MixedForest);
+            !node.settled(biome, seed, *pos)
+        });
+
+        // Trees from saves made before the calendar catch up with it.
+        let season = self.time.season();
+        self.objects
+            .for_each_tree_mut(|tree, _| tree.set_season(season));
+
+        // Stumps from before felling was dated start their clock now.
+        let today = self.time.day;
+        self.player.skills.start_day(today);
+        self.raid_tracks
+            .retain(|t| today.saturating_sub(t.day) < RAID_TRACKS_DAYS);
+        self.objects.for_each_tree_mut(|tree, _| {
+            if tree.felled && tree.felled_day.is_none() {
+                tree.felled_day = Some(today);
+            }
+        });
+        let mut stumps: Vec<(u32, String)> = self
+            .objects
+            .placed
+            .iter()
+            .filter_map(|p| match &p.object.kind {
+                ObjectKind::Tree(tree) if tree.felled => {
+                    Some((tree.felled_day.unwrap_or(today), p.id.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        stumps.sort();
+        let excess = stumps.len().saturating_sub(MAX_STUMPS);
+        for (i, (day, id)) in stumps.iter().enumerate() {
+            if i < excess || today.saturating_sub(*day) >= STUMP_DECAY_DAYS {
+                self.objects.remove(id);
+            }
+        }
+
+        self.cap_pending_messages();
+    }
+
+    /// Drop the oldest queued messages past `MAX_PENDING_MESSAGES`.
+    fn cap_pending_messages(&mut self) {
+        let excess = self
+            .pending_messages
+            .len()
+            .saturating_sub(MAX_PENDING_MESSAGES);
+        self.pending_messages.drain(..excess);
+    }
+
+    /// Advance the simulation by one tick
+    pub fn tick_with_map(&mut self, map: &mut WorldMap) {
+        // Advance time
+        let day = self.time.day;
+        self.time.advance_tick();
+
+        // Update weather occasionally
+        if self.time.tick % 10 == 0 {
+            self.weather.update();
+            let pos = self.player.position;
+            let stormy = self.weather.get_for_position(pos.row, pos.col).is_storm();
+            self.letters.week.note_weather(stormy);
+            // Once the weather turns, the next bad spell earns a fresh warning
+            if let Some((region, weather)) = self.advised_weather {
+                if self.weather.for_region(region) != weather {
+                    self.advised_weather = None;
+                }
+            }
+        }
+
+        self.weather.tick_front();
+
+        let mut rng = rand::thread_rng();
+        // Update wildlife
+        let tod = self.time.time_of_day();
+        let structures = self.objects.structure_positions();
+        // Only someone out in the open is there to be shied away from
+        let outdoors = (self.player.room.is_none() && self.player.inside.is_none())
+            .then_some(self.player.position);
+        // Deer won't step up beside a campfire, and bolt from one they find
+        let fires = self.campfires_near_shy_wildlife(map);
+        let mut fire_shy = structures.clone();
+        for fire in &fires {
+            for dr in -1..=1 {
+                for dc in -1..=1 {
+                    fire_shy.insert(Position::new(fire.row + dr, fire.col + dc));
+                }
+            }
+        }
+        for w in &mut self.wildlife {
+            if !w.species.shies_from_fire() || fires.is_empty() {
+                w.update(tod, map, &self.weather, &structures, outdoors);
+            } else if let Some(fire) = fires.iter().find(|f| w.position.distance_to(f) < 1.5) {
+                w.flee_from(*fire, map, &structures, &mut rng);
+            } else {
+                w.update(tod, map, &self.weather, &fire_shy, outdoors);
+            }
+        }
+        self.update_companions(map, &structures, &mut rng);
+        self.update_roof_birds(map, &structures, &mut rng);
+        self.update_fox_raids(map, &mut rng);
+        self.maybe_spawn_edge_wildlife(map, &mut rng);
+
+        // Update fireplace and collect any warnings
+        // Wind drawing on the chimney makes the hearth burn hotter and faster
+        let scale = self.time.tick_scale();
+        let draw = self.weather.wind.fire_draw();
+        if let Some(cabin) = self.cabin_state_mut() {
+            if let Some(fire_msg) = cabin.fireplace.update(scale * draw) {
+                self.pending_messages.push(fire_msg);
+            }
+        }
+
+        self.update_cabin_upkeep(&mut rng);
+
+        self.update_trees(map, &mut rng);
+        self.update_forage_nodes(map);
+        self.ease_fishing_pressure(scale);
+        self.tick_corpses();
+        self.update_hunting_conscience();
+        self.update_wood_seasoning();
+        self.update_cooking(&mut rng);
+        self.update_raft(map, &mut rng);
+        self.update_cave_bear(map, &mut rng);
+        self.update_firefly_jar();
+        self.update_death_note();
+        self.update_ruin(&mut rng);
+        self.update_sandstorm(map, &mut rng);
+        self.update_lit_kindling(map, &mut rng);
+        self.update_wildfire(map, &mut rng);
+
+        // Hunger / thirst decay
+        if !self.hold_needs_while_idle(scale) {
+            self.decay_needs(scale);
+        }
+
+        // Update player warmth based on environment
+        self.update_player_comfort(map);
+        self.update_player_body(&mut rng);
+        self.update_health_regen();
+        self.dawn_summary(map);
+
+        // Check for newly unlocked blueprints as skills/books progress
+        self.refresh_blueprint_knowledge(true);
+        // Players who skipped the book still earn something for the basics
+        self.grant_tutorial_reward_if_needed(map);
+
+        // Keep cognition in sync with injuries, health, and rest
+        self.update_player_cognition();
+        self.check_vital_bands();
+
+        if self.time.day != day {
+            self.compact(map);
+        } else {
+            self.cap_pending_messages();
+        }
+    }
+
+    /// Campfires within two steps of any animal that shies from fire.
+    fn campfires_near_shy_wildlife(&self, map: &WorldMap) -> Vec<Position> {
+        let mut fires = Vec::new();
+        for w in self
+            .wildlife
+            .iter()
+            .filter(|w| w.alive && w.species.shies_from_fire())
+        {
+            for dr in -2..=2 {
+                for dc in -2..=2 {
+                    let pos = Position::new(w.position.row + dr, w.position.col + dc);
+                    let lit = map
+                        .tile_at(&pos)
+                        .map(|t| t.items.contains(&Item::Campfire))
+                        .unwrap_or(false);
+                    if lit && !fires.contains(&pos) {
+                        fires.push(pos);
+                    }
+                }
+            }
+        }
+        fires
+    }
+
+    /// The roof a small bird is sitting on, if it's resting beside one.
+    fn perched_roof(&self, w: &Wildlife) -> Option<&PlacedObject> {
+        if !w.alive || !w.species.perches_on_roofs() || w.behavior != Behavior::Resting {
+            return None;
+        }
+        self.objects
+            .rooftops()
+            .find(|roof| w.position.distance_to(&roof.position) < 1.5)
+    }
+
+    /// Small birds drift over to the cabin and shed by day and settle on
+    /// the roofs, now and then letting a feather fall beside them.
+    fn update_roof_birds(
+        &mut self,
+        map: &mut WorldMap,
+        structures: &HashSet<Position>,
+        rng: &mut impl Rng,
+    ) {
+        let roofs: Vec<Position> = self.objects.rooftops().map(|p| p.position).collect();
+        let day = !self.time.time_of_day().is_night();
+        let scale = self.time.tick_scale() as f64;
+        let mut feathers = Vec::new();
+        for i in 0..self.wildlife.len() {
+            if self.perched_roof(&self.wildlife[i]).is_some() {
+                if rng.gen_bool((FEATHER_CHANCE * scale).min(1.0)) {
+                    feathers.push(self.wildlife[i].position);
+                }
+                continue;
+            }
+            let w = &mut self.wildlife[i];
+            if !day || !w.alive || w.tamed || !w.species.perches_on_roofs() {
+                continue;
+            }
+            let Some(roof) = roofs
+                .iter()
+                .find(|r| w.position.distance_to(r) <= 3.0)
+                .copied()
+            else {
+                continue;
+            };
+            if w.position.distance_to(&roof) >= 1.5 {
+                if rng.gen_bool(0.2) {
+                    w.step_toward(roof, map, structures, rng);
+                }
+            } else if rng.gen_bool(0.3) {
+                w.behavior = Behavior::Resting;
+            }
+        }
+        for pos in feathers {
+            if let Some(tile) = map.tile_at_mut(&pos) {
+                tile.items.add(Item::Feather, 1);
+            }
+        }
+    }
+
+    /// At night a fox within reach of food left unattended may make off
+    /// with a piece of it, leaving its tracks behind. Food on the open
+    /// ground is easy pickings; food on the shed's workbench is much safer,
+    /// and safer still behind a shut door.
+    fn update_fox_raids(&mut self, map: &mut WorldMap, rng: &mut impl Rng) {
+        if !self.time.time_of_day().is_night() {
+            return;
+        }
+        let today = self.time.day;
+        let scale = self.time.tick_scale() as f64;
+        let player = self.player.position;
+        let in_shed = matches!(self.player.room, Some(Room::WoodShed));
+        let shed = self.objects.find(WORKBENCH_ID).map(|p| p.position);
+        let shed_exposure = match self.wood_shed_state() {
+            Some(shed) if shed.door_open => 0.5,
+            Some(_) => 0.1,
+            None => 1.0,
+        };
+        let foxes: Vec<(Position, Species)> = self
+            .wildlife
+            .iter()
+            .filter(|w| w.alive && !w.tamed && w.species.raids_caches())
+            .map(|w| (w.position, w.species))
+            .collect();
+        for (fox, species) in foxes {
+            let raided = |pos: &Position| {
+                self.raid_tracks
+                    .iter()
+                    .any(|t| t.position == *pos && t.day == today)
+            };
+            let unattended = |pos: &Position| player.distance_to(pos) >= 1.5;
+            // The nearest food on the open ground
+            let mut ground: Option<Position> = None;
+            for dr in -RAID_RANGE..=RAID_RANGE {
+                for dc in -RAID_RANGE..=RAID_RANGE {
+                    let pos = Position::new(fox.row + dr, fox.col + dc);
+                    let has_food = map
+                        .tile_at(&pos)
+                        .map(|t| t.items.list().iter().any(|i| i.is_food()))
+                        .unwrap_or(false);
+                    let nearer = ground
+                        .map(|g| fox.distance_to(&pos) < fox.distance_to(&g))
+                        .unwrap_or(true);
+                    if has_food && nearer && unattended(&pos) && !raided(&pos) {
+                        ground = Some(pos);
+                    }
+                }
+            }
+            let workbench = shed.filter(|pos| {
+                fox.distance_to(pos) <= RAID_RANGE as f32
+                    && !in_shed
+                    && unattended(pos)
+                    && !raided(pos)
+                    && self
+                        .surface(WORKBENCH_ID)
+                        .map(|s| s.items.list().iter().any(|i| i.is_food()))
+                        .unwrap_or(false)
+            });
+            let (target, exposure, from_shed) = match (ground, workbench) {
+                (Some(pos), _) => (pos, 1.0, false),
+                (None, Some(pos)) => (pos, shed_exposure, true),
+                (None, None) => continue,
+            };
+            if !rng.gen_bool((RAID_CHANCE * exposure * scale * self.event_rate).min(1.0)) {
+                continue;
+            }
+            let items = if from_shed {
+                self.surface_mut(WORKBENCH_ID).map(|s| &mut s.items)
+            } else {
+                map.tile_at_mut(&target).map(|t| &mut t.items)
+            };
+            let Some(items) = items else {
+                continue;
+            };
+            let Some(food) = items.list().into_iter().copied().find(|i| i.is_food()) else {
+                continue;
+            };
+            if items.take(&food) {
+                self.raid_tracks.push(RaidTracks {
+                    position: target,
+                    species,
+                    item: food,
+                    day: today,
+                    from_shed,
+                });
+            }
+        }
+    }
+
+    /// Fresh fox tracks by food that went missing, when the player is by
+    /// the spot, and any small bird sitting on the roof overhead or nearby.
+    pub fn camp_wildlife_note(&self) -> Option<String> {
+        let mut lines = Vec::new();
+        let outdoors = self.player.room.is_none() && self.player.inside.is_none();
+        let in_shed = matches!(self.player.room, Some(Room::WoodShed));
+        let pos = self.player.position;
+        for tracks in &self.raid_tracks {
+            let by_it = if tracks.from_shed {
+                in_shed || (outdoors && pos.distance_to(&tracks.position) < 1.5)
+            } else {
+                outdoors && pos == tracks.position
+            };
+            if !by_it {
+                continue;
+            }
+            lines.push(if tracks.from_shed {
+                format!(
+                    "Fresh {} tracks lead in and out of the shed, and the {} you left on the workbench is gone.",
+                    tracks.species.name(),
+                    tracks.item.name()
+                )
+            } else {
+                format!(
+                    "Fresh {} tracks circle the spot where you left the {}. It's gone.",
+                    tracks.species.name(),
+                    tracks.item.name()
+                )
+            });
+        }
+        let overhead = match self.player.room {
+            Some(Room::CabinMain) => self.objects.find("cabin").map(|p| p.position),
+            Some(Room::WoodShed) => self.objects.find("wood_shed").map(|p| p.position),
+            _ => None,
+        };
+        for w in &self.wildlife {
+            let Some(roof) = self.perched_roof(w) else {
+                continue;
+            };
+            if overhead == Some(roof.position) {
+                lines.push(
+                    "Small feet patter across the roof above, and a bird calls from up there."
+                        .to_string(),
+                );
+                break;
+            }
+            if outdoors && pos.distance_to(&roof.position) <= 2.0 {
+                lines.push(format!(
+                    "A {} sits on the roof of the {}, fluffed up and watching you.",
+                    w.species.name(),
+                    roof.object.display_name()
+                ));
+                break;
+            }
+        }
+        (!lines.is_empty()).then(|| lines.join(" "))
+    }
+
+    /// Fish drift back into water that has been left alone: about a catch's
+    /// worth of pressure lifts every few hours.
+    fn ease_fishing_pressure(&mut self, scale: f32) {
+        for pressure in self.fishing_pressure.values_mut() {
+            *pressure = (*pressure - 0.05 * scale).max(0.0);
+        }
+        self.fishing_pressure.retain(|_, p| *p > 0.0);
+    }
+
+    /// Idle ticking never carries hunger or thirst past the brink on its own:
+    /// the first time it would, raise the urgent-need flag and hold the needs
+    /// where they are until the player makes a choice.
+    fn hold_needs_while_idle(&mut self, scale: f32) -> bool {
+        if !self.idle_ticking {
+            return false;
+        }
+        if self.urgent_need.is_some() {
+            return true;
+        }
+        let drop = 0.5 * scale;
+        let (feeling, remedy) = if self.player.hydration - drop < NEED_CRITICAL {
+            ("parched", "drink")
+        } else if self.player.fullness - drop < NEED_CRITICAL {
+            ("hungry", "eat")
+        } else {
+            return false;
+        };
+        self.urgent_need = Some(format!(
+            "You are badly {}, and the hours stop slipping by unnoticed. Find something to {} before anything else.",
+            feeling, remedy
+        ));
+        true
+    }
+
+    fn decay_needs(&mut self, scale: f32) {
+        self.player.modify_fullness(-0.5 * scale);
+        self.player.modify_hydration(-0.5 * scale);
+        if self.player.fullness < NEED_CRITICAL {
+            self.player.modify_energy(-1.0);
+            self.player.modify_mood(-1.0);
+        }
+        if self.player.hydration < NEED_CRITICAL {
+            self.player.modify_energy(-1.0);
+            if self.player.hydration < 10.0 {
+                self.player.modify_health(-0.5);
+            }
+        }
+    }
+
+    /// Tell the player, once, whenever a vital sinks into a worse band or
+    /// climbs back out of one.
+    pub fn check_vital_bands(&mut self) {
+        let lines = self.vital_bands.update(&self.player);
+        self.pending_messages.extend(lines);
+    }
+
+    pub fn ruin(&self) -> Option<&Ruin> {
+        self.objects.find(RUIN_ID).and_then(|p| p.object.as_ruin())
+    }
+
+    pub fn ruin_mut(&mut self) -> Option<&mut Ruin> {
+        self.objects
+            .find_mut(RUIN_ID)
+            .and_then(|p| p.object.as_ruin_mut())
+    }
+
+    pub fn in_ruin(&self) -> bool {
+        self.player.inside.as_deref() == Some(RUIN_ID)
+    }
+
+    /// Mark the ruin found. Returns false if it already was.
+    pub fn discover_ruin(&mut self) -> bool {
+        let Some(ruin) = self.ruin_mut() else {
+            return false;
+        };
+        if ruin.discovered {
+            return false;
+        }
+        ruin.discovered = true;
+        true
+    }
+
+    /// The places the ancient map draws, in the order study picks them out:
+    /// what the map shows, its name for the place, what that turns out to
+    /// be, where it lies and how wide a band around it the map charts.
+    fn map_landmarks(&self, map: &WorldMap) -> Vec<Landmark> {
+        let centre_of = |biome: Biome| {
+            let tiles: Vec<Position> = map
+                .positions()
+                .filter(|p| map.biome_at(p) == Some(biome))
+                .collect();
+            let n = tiles.len().max(1) as i32;
+            (!tiles.is_empty()).then(|| {
+                Position::new(
+                    tiles.iter().map(|p| p.row).sum::<i32>() / n,
+                    tiles.iter().map(|p| p.col).sum::<i32>() / n,
+                )
+            })
+        };
+        let ruin_found = self.ruin().map(|r| r.discovered).unwrap_or(false);
+        [
+            centre_of(Biome::Lake).map(|pos| Landmark {
+                note: "The lake is labeled 'The Mirror', and something is marked at its center.",
+                name: "The Mirror",
+                meaning: "the lake",
+                pos,
+                radius: 5,
+            }),
+            self.objects.find(RUIN_ID).map(|po| Landmark {
+                note: if ruin_found {
+                    "Past the oasis, the little inked square marks the ruin in the dunes, a careful X drawn beside it."
+                } else {
+                    "In the western margin, past the oasis, a small square is inked among the dunes with a needle drawn inside it and a careful X beside it. Something was built out there once."
+                },
+                name: "the inked square",
+                meaning: "the ruin in the dunes",
+                pos: po.position,
+                radius: 3,
+            }),
+            centre_of(Biome::Oasis).map(|pos| Landmark {
+                note: "Out in the desert, a ring of tiny palms is drawn around a pool and labeled 'The Well of Stars'.",
+                name: "The Well of Stars",
+                meaning: "the oasis",
+                pos,
+                radius: 3,
+            }),
+            self.objects.find(CAVE_ID).map(|po| Landmark {
+                note: "Among the eastern pines, a dark mouth is drawn with a paw print beside it, labeled 'The Sleeper'.",
+                name: "The Sleeper",
+                meaning: "the cave in the pines",
+                pos: po.position,
+                radius: 3,
+            }),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Study the ancient map. Each sitting picks out one more landmark and
+    /// charts the land around it; held up near a landmark already studied,
+    /// the map confirms the ground close by as if it had been walked.
+    pub fn study_ancient_map(&mut self, map: &WorldMap) -> String {
+        let landmarks = self.map_landmarks(map);
+        let studied = (self.map_studies as usize).min(landmarks.len());
+        let mut lines = Vec::new();
+        if studied == 0 {
+            lines.push(
+                "You unfold the brittle map. It shows this very area - but different.".to_string(),
+            );
+        }
+
+        let here = self.player.position;
+        let outdoors = self.player.room.is_none() && self.player.inside.is_none();
+        if outdoors && self.player.hands.holds(&Item::AncientMap) {
+            if let Some(near) = landmarks[..studied]
+                .iter()
+                .find(|lm| here.distance_to(&lm.pos) <= lm.radius as f32)
+            {
+                let confirmed: Vec<Position> = self
+                    .player
+                    .charted
+                    .iter()
+                    .filter(|p| p.distance_to(&here) <= MAP_CONFIRM_RADIUS)
+                    .copied()
+                    .collect();
+                if !confirmed.is_empty() {
+                    for pos in confirmed {
+                        self.player.charted.remove(&pos);
+                        self.player.visited.insert(pos);
+                    }
+                    lines.push(format!(
+                        "Holding the map up, you match the lines drawn around {} to the ground in front of you. The land close by is as familiar now as if you'd walked it.",
+                        near.name
+                    ));
+                }
+            }
+        }
+
+        match landmarks.get(studied) {
+            Some(lm) => {
+                self.map_studies = studied as u8 + 1;
+                if Some(lm.pos) == self.objects.find(RUIN_ID).map(|p| p.position) {
+                    self.discover_ruin();
+                }
+                let band: Vec<Position> = map
+                    .positions()
+                    .filter(|p| p.distance_to(&lm.pos) <= lm.radius as f32)
+                    .filter(|p| !self.player.visited.contains(p))
+                    .collect();
+                self.player.charted.extend(band);
+                lines.push(lm.note.to_string());
+            }
+            None if lines.is_empty() => lines.push(
+                "You pore over the map again, but it has nothing new to tell you. Held up near one of its landmarks, it might still help you find your feet."
+                    .to_string(),
+            ),
+            None => {}
+        }
+
+        let key: Vec<String> = landmarks[..(self.map_studies as usize).min(landmarks.len())]
+            .iter()
+            .map(|lm| format!("{} is {}", lm.name, lm.meaning))
+            .collect();
+        if !key.is_empty() {
+            lines.push(format!("Matched so far: {}.", key.join("; ")));
+        }
+        lines.join(" ")
+    }
+
+    /// A sandstorm over the buried ruin may scour the dune off its walls.
+    fn update_ruin(&mut self, rng: &mut impl Rng) {
+        let Some(pos) = self
+            .objects
+            .find(RUIN_ID)
+            .filter(|p| p.object.is_hidden())
+            .map(|p| p.position)
+        else {
+            return;
+        };
+        if self.weather.get_for_position(pos.row, pos.col) != Weather::Sandstorm
+            || !rng.gen_bool(0.05)
+        {
+            return;
+        }
+        self.discover_ruin();
+        self.journal.push(
+            "A sandstorm tore a dune away west of the oasis and bared the walls of an old stone ruin."
+                .to_string(),
+        );
+        if self.player.position.distance_to(&pos) <= 10.0 {
+            self.pending_messages.push(
+                "Through a gap in the storm you glimpse it: west of the oasis the wind has torn a dune away, and the tops of old stone walls stand bare in the sand."
+                    .to_string(),
+            );
+        }
+    }
+
+    /// Count a tool call that went through. Expressive ones deepen the
+    /// player's connection to the place; past a long run of nothing but
+    /// work, each further chore wears it down a little.
+    pub fn note_tool(&mut self, tool: &str) {
+        match ToolCategory::of(tool) {
+            ToolCategory::Expressive => {
+                self.tool_tally.expressive += 1;
+                self.tool_tally.grind_streak = 0;
+                let gain = match tool {
+                    "talk" | "write" => 1.5,
+                    "meditate" | "lakeside" => 1.0,
+                    _ => 0.5,
+                };
+                self.player.modify_connection(gain);
+            }
+            ToolCategory::Work => {
+                self.tool_tally.work += 1;
+                self.tool_tally.grind_streak += 1;
+                if self.tool_tally.grind_streak > GRIND_GRACE {
+                    self.player.modify_connection(-0.5);
+                }
+            }
+            ToolCategory::Other => {}
+        }
+    }
+
+    /// A sandstorm working over the desert: loose things go under the drift,
+    /// a dune or two wanders, anyone caught out in it ends up full of grit,
+    /// and once in a long while the wind bares the old cache.
+    /// Dry undergrowth a stray flame could take hold in. Sand, snow, water
+    /// and bare paths won't burn, nor ground that already has.
+    fn ground_burns(&self, map: &WorldMap, pos: &Position) -> bool {
+        let Some(tile) = map.tile_at(pos) else {
+            return false;
+        };
+        matches!(tile.tile_type, TileType::Forest(_) | TileType::Clearing)
+            && !matches!(
+                tile.biome,
+                Biome::Desert | Biome::Oasis | Biome::WinterForest | Biome::Lake
+            )
+            && !self.sands.dunes.contains(pos)
+            && !self.wildfire.scorched.contains(pos)
+            && !self.wildfire.is_burning(pos)
+    }
+
+    /// Fire or fresh burn scars around the player, for the outdoor view.
+    pub fn wildfire_note(&self) -> Option<String> {
+        if self.player.room.is_some() || self.player.inside.is_some() {
+            return None;
+        }
+        let pos = self.player.position;
+        if self.wildfire.is_burning(&pos) {
+            return Some(
+                "**Fire!** The undergrowth around you is burning. Step off this ground, or beat it out ('tend_fire extinguish')."
+                    .to_string(),
+            );
+        }
+        if let Some(near) = self.wildfire.nearest(&pos, 1.5) {
+            return Some(format!(
+                "Flames crackle through the undergrowth just to the {}. You could beat them out from here ('tend_fire extinguish').",
+                pos.bearing_to(&near)
+            ));
+        }
+        if let Some(near) = self.wildfire.nearest(&pos, 6.0) {
+            return Some(format!(
+                "Smoke rolls up from a fire in the woods to the {}.",
+                pos.bearing_to(&near)
+            ));
+        }
+        self.wildfire
+            .scorched
+            .contains(&pos)
+            .then(|| "The ground here is burnt black, ash soft underfoot.".to_string())
+    }
+
+    /// A flame let loose at `pos`: rolls whether the ground catches, given
+    /// the weather there and the wind. Returns whether it did.
+    pub fn try_start_wildfire(
+        &mut self,
+        map: &WorldMap,
+        pos: Position,
+        rng: &mut impl Rng,
+    ) -> bool {
+        if !self.ground_burns(map, &pos) {
+            return false;
+        }
+        let weather = self.weather.get_for_position(pos.row, pos.col);
+        let chance = weather.ignition_chance() * (0.5 + self.weather.wind.fire_spread() * 2.0);
+        if chance <= 0.0 || !rng.gen_bool(chance.min(1.0)) {
+            return false;
+        }
+        if self.wildfire.burning.is_empty() {
+            self.wildfire.origin = Some(pos);
+            self.wildfire.charred = 0;
+            let region = self
+                .region_names
+                .name(WorldMap::region_of(pos.row, pos.col))
+                .to_string();
+            self.journal.push(format!(
+                "A careless flame caught the dry undergrowth in {} and fire got loose.",
+                region
+            ));
+        }
+        self.wildfire.burning.push(BurningTile {
+            position: pos,
+            ticks: 0,
+        });
+        true
+    }
+
+    /// Lit kindling burns down in the hand; what reaches the fingers gets
+    /// dropped, and outdoors it may take the ground with it.
+    fn update_lit_kindling(&mut self, map: &WorldMap, rng: &mut impl Rng) {
+        if !self.player.inventory.has(&Item::LitKindling, 1) {
+            self.wildfire.kindling_ticks = 0;
+            return;
+        }
+        if self.wildfire.kindling_ticks == 0 {
+            self.wildfire.kindling_ticks = LIT_KINDLING_TICKS;
+        }
+        self.wildfire.kindling_ticks -= 1;
+        if self.wildfire.kindling_ticks > 0 {
+            return;
+        }
+        self.player.inventory.remove(&Item::LitKindling, 1);
+        let outdoors = self.player.room.is_none() && self.player.inside.is_none();
+        let pos = self.player.position;
+        let message = if outdoors && self.try_start_wildfire(map, pos, rng) {
+            "The kindling burns down to your fingers and you drop it. The dry litter at your feet catches, and flames start to spread."
+        } else {
+            "The kindling burns down to your fingers and you drop it. It gutters out."
+        };
+        self.pending_messages.push(message.to_string());
+    }
+
+    /// Burning ground chars the trees on it, spreads a tile at a time while
+    /// it's dry, and goes out in the rain or once there's nothing left.
+    fn update_wildfire(&mut self, map: &WorldMap, rng: &mut impl Rng) {
+        if self.wildfire.burning.is_empty() && self.wildfire.origin.is_none() {
+            return;
+        }
+        let player_pos = self.player.position;
+        let outdoors = self.player.room.is_none() && self.player.inside.is_none();
+        let spread = self.weather.wind.fire_spread();
+        let downwind = self.weather.wind.downwind();
+        let mut still_burning = Vec::new();
+        let mut caught = Vec::new();
+        let mut rained_out = false;
+        let mut went_out = Vec::new();
+
+        for mut patch in std::mem::take(&mut self.wildfire.burning) {
+            let pos = patch.position;
+            if self
+                .weather
+                .get_for_position(pos.row, pos.col)
+                .ignition_chance()
+                == 0.0
+            {
+                self.wildfire.scorched.insert(pos);
+                rained_out = true;
+                went_out.push(pos);
+                continue;
+            }
+            for po in self.objects.objects_at_mut(&pos) {
+                if let Some(tree) = po.object.as_tree_mut() {
+                    if tree.burn() {
+                        self.wildfire.charred += 1;
+                    }
+                }
+            }
+            patch.ticks += 1;
+            if patch.ticks >= BURN_TICKS {
+                self.wildfire.scorched.insert(pos);
+                went_out.push(pos);
+                continue;
+            }
+            for dir in [
+                Direction::North,
+                Direction::South,
+                Direction::East,
+                Direction::West,
+            ] {
+                let chance = if dir == downwind {
+                    spread * 1.5
+                } else {
+                    spread * 0.5
+                };
+                if rng.gen_bool(chance.min(1.0)) {
+                    caught.push(pos.move_in_direction(dir));
+                }
+            }
+            still_burning.push(patch);
+        }
+        self.wildfire.burning = still_burning;
+        for pos in caught {
+            if self.ground_burns(map, &pos) {
+                self.wildfire.burning.push(BurningTile {
+                    position: pos,
+                    ticks: 0,
+                });
+                if outdoors && pos.distance_to(&player_pos) <= 3.0 && pos != player_pos {
+                    self.pending_messages.push(format!(
+                        "Fire races through the undergrowth to the {}.",
+                        player_pos.bearing_to(&pos)
+                    ));
+                }
+            }
+        }
+
+        if outdoors && self.wildfire.is_burning(&player_pos) {
+            self.player.modify_health(-3.0);
+            self.player.modify_mood(-2.0);
+            self.pending_messages.push(
+                "Flames lick at your legs and the smoke stings your eyes. Get off this ground or beat the fire out."
+                    .to_string(),
+            );
+        }
+
+        if self.wildfire.burning.is_empty() {
+            let region = self
+                .wildfire
+                .origin
+                .take()
+                .map(|p| {
+                    self.region_names
+                        .name(WorldMap::region_of(p.row, p.col))
+                        .to_string()
+                })
+                .unwrap_or_else(|| "the woods".to_string());
+            let charred = std::mem::take(&mut self.wildfire.charred);
+            let how = if rained_out {
+                "The weather put out"
+            } else if went_out.is_empty() {
+                "You put out"
+            } else {
+                "Nothing was left to feed"
+            };
+            let trees = match charred {
+                0 => "No trees were lost.".to_string(),
+                1 => "One tree stands charred.".to_string(),
+                n => format!("{} trees stand charred.", n),
+            };
+            self.journal
+                .push(format!("{} the fire in {}. {}", how, region, trees));
+            if outdoors && went_out.iter().any(|p| p.distance_to(&player_pos) <= 5.0) {
+                self.pending_messages
+                    .push("The last of the fire dies away into smoking black ground.".to_string());
+            }
+        }
+    }
+
+    fn update_sandstorm(&mut self, map: &mut WorldMap, rng: &mut impl Rng) {
+        let storm: Vec<Position> = map
+            .positions()
+            .filter(|p| map.biome_at(p) == Some(Biome::Desert))
+            .filter(|p| self.weather.get_for_position(p.row, p.col) == Weather::Sandstorm)
+            .collect();
+        if storm.is_empty() {
+            return;
+        }
+        let scale = self.time.tick_scale() as f64;
+        let player_pos = self.player.position;
+        let outdoors = self.player.room.is_none() && self.player.inside.is_none();
+
+        // Stones are everywhere; it's what was dropped that goes missing
+        for pos in &storm {
+            if *pos == player_pos || !rng.gen_bool((0.05 * scale).min(1.0)) {
+                continue;
+            }
+            let Some(tile) = map.tile_at_mut(pos) else {
+                continue;
+            };
+            let loose: Vec<usize> = (0..tile.items.items.len())
+                .filter(|&i| tile.items.items[i].0 != Item::Stone)
+                .collect();
+            if loose.is_empty() {
+                continue;
+            }
+            let stack = tile.items.items.remove(loose[rng.gen_range(0..loose.len())]);
+            self.sands.bury(*pos, vec![stack]);
+        }
+
+        // A dune piles up over open sand, or moves on and bares what it hid
+        if rng.gen_bool((0.2 * scale).min(1.0)) {
+            for _ in 0..rng.gen_range(1..=2) {
+                let pos = storm[rng.gen_range(0..storm.len())];
+                if pos == player_pos || !self.objects.objects_at(&pos).is_empty() {
+                    continue;
+                }
+                let Some(tile) = map.tile_at_mut(&pos) else {
+                    continue;
+                };
+                if self.sands.dunes.remove(&pos) {
+                    for (item, qty) in self.sands.unearth(&pos) {
+                        tile.items.add(item, qty);
+                    }
+                } else {
+                    self.sands.dunes.insert(pos);
+                    let (stones, loose): (Vec<_>, Vec<_>) = tile
+                        .items
+                        .items
+                        .drain(..)
+                        .partition(|(item, _)| *item == Item::Stone);
+                    tile.items.items = stones;
+                    self.sands.bury(pos, loose);
+                }
+            }
+        }
+
+        if outdoors && storm.contains(&player_pos) {
+            let before = self.player.grit;
+            self.player.grit = (before + 12.0 * scale as f32).min(100.0);
+            if before < 1.0 {
+                self.pending_messages.push(
+                    "Sand scours your face and packs into your eyes and hair. Everything swims; you'll want water to wash it out."
+                        .to_string(),
+                );
+            }
+        }
+
+        if !self.sands.cache_bared && rng.gen_bool((0.01 * scale).min(1.0)) {
+            let pos = storm[rng.gen_range(0..storm.len())];
+            self.sands.cache_bared = true;
+            self.sands.bury(
+                pos,
+                vec![
+                    (Item::ClayJar, 1),
+                    (Item::Cordage, 2),
+                    (Item::Flint, 2),
+                    (Item::Date, 3),
+                ],
+            );
+            let from_home = self
+                .objects
+                .find("cabin")
+                .map(|c| c.position.rough_bearing_to(&pos))
+                .unwrap_or("west");
+            self.journal.push(format!(
+                "A sandstorm scoured the desert to the {} of the cabin and bared the corner of an old buried cache.",
+                from_home
+            ));
+            tracing::info!("Sandstorm bared the desert cache at {}", pos);
+            if player_pos.distance_to(&pos) <= 8.0 {
+                self.pending_messages.push(format!(
+                    "Through a gap in the storm you see a dune torn open {}, and the corner of a wooden box jutting from the sand. A search there would dig it free.",
+                    crate::descriptions::distance_phrase(&player_pos, &pos)
+                ));
+            }
+        }
+    }
+
+    /// Whether a dune has drifted over `pos` in a past storm.
+    pub fn dune_at(&self, pos: &Position) -> bool {
+        self.sands.dunes.contains(pos)
+    }
+
+    /// Dig out whatever the sand has buried at the player's feet, laying it
+    /// on the ground. Returns the names of what came up.
+    pub fn unearth_here(&mut self, map: &mut WorldMap) -> Vec<String> {
+        let pos = self.player.position;
+        let items = self.sands.unearth(&pos);
+        let names = items
+            .iter()
+            .map(|(item, qty)| quantity_label(&self.display_name(item), *qty))
+            .collect();
+        if let Some(tile) = map.tile_at_mut(&pos) {
+            for (item, qty) in items {
+                tile.items.add(item, qty);
+            }
+        }
+        names
+    }
+
+    /// Rinse storm grit out at the water's edge. Returns false if there was
+    /// none to wash off.
+    pub fn wash_off_grit(&mut self) -> bool {
+        if self.player.grit < 1.0 {
+            return false;
+        }
+        self.player.grit = 0.0;
+        true
+    }
+
+    /// Copy the inscription panel for `chamber` into the ruin's book and the
+    /// journal, the first time it is read. Returns the text if it was new.
+    pub fn reveal_inscription(&mut self, chamber: u8) -> Option<&'static str> {
+        let text = *RUIN_INSCRIPTIONS.get(chamber as usize)?;
+        let entry = self
+            .books
+            .entry(RUIN_BOOK_ID.to_string())
+            .or_insert_with(|| BookEntry {
+                id: RUIN_BOOK_ID.to_string(),
+                title: "Ruin Inscriptions".to_string(),
+                pages: Vec::new(),
+                writable: false,
+            });
+        if entry.pages.iter().any(|p| p == text) {
+            return None;
+        }
+        entry.pages.push(text.to_string());
+        self.journal.push(text.to_string());
+        Some(text)
+    }
+
+    /// Every inscription panel read so far, in order.
+    pub fn ruin_inscriptions(&self) -> Vec<String> {
+        self.books
+            .get(RUIN_BOOK_ID)
+            .map(|b| b.pages.clone())
+            .unwrap_or_default()
+    }
+
+    /// Names in the Death Note come due, and whatever it didn't take fades
+    /// by morning.
+    fn update_death_note(&mut self) {
+        let mut due = Vec::new();
+        self.death_note.pending.retain_mut(|(species, ticks)| {
+            *ticks = ticks.saturating_sub(1);
+            if *ticks == 0 {
+                due.push(*species);
+            }
+            *ticks > 0
+        });
+        let here = self.player.position;
+        for species in due {
+            let nearest = self
+                .wildlife
+                .iter()
+                .enumerate()
+                .filter(|(_, w)| w.alive && !w.tamed && w.species == species)
+                .min_by(|(_, a), (_, b)| {
+                    here.distance_to(&a.position)
+                        .partial_cmp(&here.distance_to(&b.position))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|(idx, w)| (idx, here.distance_to(&w.position)));
+            let Some((idx, dist)) = nearest else {
+                continue;
+            };
+            self.leave_corpse(idx);
+            self.player.modify_mood(-2.0);
+            if dist <= 6.0 {
+                self.pending_messages.push(format!(
+                    "Not far off, a {} stops, sways, and keels over. The timing is uncanny.",
+                    species.name()
+                ));
+            }
+            self.journal.push(format!(
+                "A {} dropped where it stood, not long after I wrote the word. I keep telling myself it's a coincidence.",
+                species.name()
+            ));
+        }
+
+        if self.time.time_of_day() == TimeOfDay::Dawn && !self.death_note.fading.is_empty() {
+            let pages = std::mem::take(&mut self.death_note.fading);
+            if let Some(book) = self.book_entry_mut(DEATH_NOTE_ID) {
+                for page in pages {
+                    book.set_page(page, "");
+                }
+            }
+        }
+    }
+
+    /// Jarred fireflies fade; left shut in too long, they die.
+    fn update_firefly_jar(&mut self) {
+        if self.firefly_jar_minutes == 0 {
+            return;
+        }
+        let step = self.time.minutes_per_tick;
+        self.firefly_jar_minutes = self.firefly_jar_minutes.saturating_sub(step);
+        if self.firefly_jar_minutes > 0 {
+            return;
+        }
+        if self.player.inventory.remove(&Item::FireflyJar, 1) {
+            self.player.inventory.add(Item::ClayJar, 1);
+            self.player.modify_mood(-3.0);
+            self.pending_messages.push(
+                "The lights in your jar have gone out. The fireflies lie still at the bottom, and you wish you had let them go while they still glowed."
+                    .to_string(),
+            );
+        }
+    }
+
+    fn tick_corpses(&mut self) {
+        for po in &mut self.objects.placed {
+            if let ObjectKind::Corpse(corpse) = &mut po.object.kind {
+                corpse.freshness = corpse.freshness.saturating_add(1);
+            }
+        }
+    }
+
+    fn update_companions(
+        &mut self,
+        map: &WorldMap,
+        structures: &HashSet<Position>,
+        rng: &mut impl Rng,
+    ) {
+        let player_pos = self.player.position;
+
+        for w in &mut self.wildlife {
+            if !w.tamed {
+                continue;
+            }
+            if !matches!(w.species, Species::Dog | Species::Cat) {
+                continue;
+            }
+
+            let dist = w.position.distance_to(&player_pos);
+            if dist <= 1.5 {
+                continue;
+            }
+
+            // Close the longer gap first, so the path stays straight.
+            let dr = player_pos.row - w.position.row;
+            let dc = player_pos.col - w.position.col;
+            let dir = if dr.abs() >= dc.abs() {
+                if dr < 0 {
+                    Direction::North
+                } else {
+                    Direction::South
+                }
+            } else if dc < 0 {
+                Direction::West
+            } else {
+                Direction::East
+            };
+            w.step(dir, map, structures, rng);
+        }
+    }
+
+    fn maybe_spawn_edge_wildlife(&mut self, map: &WorldMap, rng: &mut impl Rng) {
+        if self.wildlife.len() > 80 {
+            return;
+        }
+        if !rng.gen_bool((0.04 * self.event_rate).min(1.0)) {
+            return;
+        }
+
+        // Newcomers drift in from the near edge of one of the regions
+        let region = Region::ALL[rng.gen_range(0..Region::ALL.len())];
+        let (row_range, col_range) = match region {
+            Region::NorthernWoods => (-12..-4, -4..5),
+            Region::SouthernWoods => (4..12, -4..5),
+            Region::EasternPines => (-4..5, 7..13),
+            Region::GlassDesert => (-4..5, -14..-7),
+        };
+
+        let row = rng.gen_range(row_range);
+        let col = rng.gen_range(col_range);
+        let pos = Position::new(row, col);
+        if let Some((r, c)) = map.index_of(&pos) {
+            if !map.is_walkable(r, c) {
+                return;
+            }
+        } else {
+            return;
+        }
+
+        let biome = map
+            .index_of(&pos)
+            .and_then(|(r, c)| map.get_tile(r, c).map(|t| t.biome))
+            .unwrap_or(Biome::MixedForest);
+
+        let species = match biome {
+            Biome::SpringForest | Biome::MixedForest => {
+                let choices = [
+                    Species::Deer,
+                    Species::Rabbit,
+                    Species::Squirrel,
+                    Species::Boar,
+                    Species::Goat,
+                    Species::Sheep,
+                    Species::Horse,
+                    Species::Bear,
+                    Species::Lynx,
+                    Species::Dog,
+                    Species::Cat,
+                ];
+                choices[rng.gen_range(0..choices.len())]
+            }
+            Biome::WinterForest => {
+                let choices = [
+                    Species::SnowFox,
+                    Species::Wolf,
+                    Species::Caribou,
+                    Species::SnowHare,
+                    Species::Moose,
+                    Species::Elk,
+                    Species::Bear,
+                ];
+                choices[rng.gen_range(0..choices.len())]
+            }
+            Biome::Desert | Biome::Oasis => {
+                let choices = [
+                    Species::DesertLizard,
+                    Species::Scorpion,
+                    Species::DesertFox,
+                    Species::Hawk,
+                    Species::Rattlesnake,
+                    Species::Camel,
+                    Species::Hyena,
+                ];
+                choices[rng.gen_range(0..choices.len())]
+            }
+            Biome::Lake | Biome::Path | Biome::Clearing | Biome::BambooGrove => {
+                let choices = [
+                    Species::Duck,
+                    Species::Heron,
+                    Species::Frog,
+                    Species::Pig,
+                    Species::Goat,
+                    Species::Dog,
+                    Species::Cat,
+                ];
+                choices[rng.gen_range(0..choices.len())]
+            }
+        };
+
+        self.wildlife.push(Wildlife::new(species, pos));
+    }
+
+    fn update_forage_nodes(&mut self, map: &WorldMap) {
+        let scale = self.time.tick_scale();
+        let seed = self.world_seed;
+        let positions: Vec<Position> = self.forage_nodes.keys().copied().collect();
+        for pos in positions {
+            if let Some(node) = self.forage_nodes.get_mut(&pos) {
+                let biome = map
+                    .index_of(&pos)
+                    .and_then(|(r, c)| map.get_tile(r, c).map(|t| t.biome))
+                    .unwrap_or(Biome::MixedForest);
+                node.tick(biome, seed, pos, scale);
+            }
+        }
+    }
+
+    /// Weather where the cabin stands; drafts and heat waves follow it.
+    fn cabin_weather(&self) -> Weather {
+        let pos = self
+            .objects
+            .find("cabin")
+            .map(|p| p.position)
+            .unwrap_or(Position::new(0, 0));
+        self.weather.get_for_position(pos.row, pos.col)
+    }
+
+    /// Indoor temperature of the main room, accounting for the fire, open
+    /// windows or door, and any snow that has blown in.
+    pub fn cabin_temperature(&self) -> f32 {
+        let Some(cabin) = self.cabin_state() else {
+            return 16.0;
+        };
+        let mut fire_heat = cabin.fireplace.heat_output();
+        let weather = self.cabin_weather();
+        let drafty = cabin.is_drafty();
+        let mut temp = 16.0;
+        match weather {
+            Weather::HeatWave => {
+                temp += 8.0;
+                if cabin.windows_open {
+                    temp -= 10.0;
+                }
+            }
+            Weather::Blizzard if drafty => {
+                temp -= 12.0;
+                fire_heat *= 0.5;
+            }
+            Weather::HeavySnow if drafty => temp -= 6.0,
+            _ => {}
+        }
+        if fire_heat > 0.0 {
+            temp += 2.0 + fire_heat;
+        }
+        temp - cabin.snow_drifts() as f32
+    }
+
+    /// Snow blows in through open windows or the door during hard weather,
+    /// and a lit hearth slowly melts it away again. Dust settles as well.
+    fn update_cabin_upkeep(&mut self, rng: &mut impl Rng) {
+        let weather = self.cabin_weather();
+        let in_cabin = matches!(self.player.room, Some(Room::CabinMain));
+        let Some(cabin) = self.cabin_state_mut() else {
+            return;
+        };
+        let mut message = None;
+        if matches!(weather, Weather::Blizzard | Weather::HeavySnow)
+            && cabin.is_drafty()
+            && cabin.snow_drifts() < 3
+            && rng.gen_bool(0.3)
+        {
+            cabin.drifts += 1;
+            if in_cabin {
+                message = Some(
+                    "A gust drives snow through the open cabin and it heaps on the floorboards."
+                        .to_string(),
+                );
+            }
+        } else if cabin.snow_drifts() > 0
+            && cabin.fireplace.heat_output() > 0.0
+            && rng.gen_bool(0.2)
+        {
+            cabin.drifts -= 1;
+            if in_cabin {
+                message = Some(
+                    "A drift of snow near the hearth slumps into a puddle and soaks away."
+                        .to_string(),
+                );
+            }
+        }
+        cabin.decay_tidiness();
+        let orderly = cabin.tidiness >= 75.0;
+        if let Some(msg) = message {
+            self.pending_messages.push(msg);
+        }
+        // An orderly home is quietly restful to be in.
+        if in_cabin && orderly {
+            self.player.modify_mood(0.2);
+        }
+    }
+
+    /// Air temperature on the player's tile, ignoring any shelter.
+    pub fn outdoor_temperature(&self, map: &WorldMap) -> f32 {
+        let world_row = self.player.position.row;
+        let world_col = self.player.position.col;
+        let (row, col) = map
+            .index_of(&self.player.position)
+            .unwrap_or((map.height() / 2, map.width() / 2));
+        let biome = map
+            .get_tile(row, col)
+            .map(|t| t.biome)
+            .unwrap_or(Biome::MixedForest);
+        let tod = self.time.time_of_day();
+        let weather_temp = self
+            .weather
+            .get_for_position(world_row, world_col)
+            .temperature_modifier();
+        biome.base_temperature() + tod.temperature_modifier() + weather_temp
+    }
+
+    /// The temperature the player is actually exposed to, shelter included.
+    pub fn current_temperature(&self, map: &WorldMap) -> f32 {
+        let world_row = self.player.position.row;
+        let world_col = self.player.position.col;
+        match self.player.room {
+            Some(Room::CabinMain) => self.cabin_temperature() + self.cabin_spot_warmth(),
+            Some(Room::WoodShed) => {
+                let open = self.wood_shed_state().map(|s| s.door_open).unwrap_or(true);
+                let severe = matches!(
+                    self.weather.get_for_position(world_row, world_col),
+                    Weather::Blizzard | Weather::HeavySnow
+                );
+                if open && severe {
+                    10.0
+                } else {
+                    16.0
+                }
+            }
+            Some(_) => 16.0, // Indoor base temp
+            // Thick stone and shade keep the ruin near cellar-cool
+            None if self.in_ruin() => 22.0 + (self.outdoor_temperature(map) - 22.0) * 0.25,
+            None => self.outdoor_temperature(map),
+        }
+    }
+
+    /// How the current temperature reads to the player: a feeling, plus the
+    /// number once they carry a thermometer.
+    pub fn temperature_readout(&self, map: &WorldMap) -> String {
+        let temp = self.current_temperature(map);
+        let feel = match temp {
+            t if t < -5.0 => "bitter cold",
+            t if t < 5.0 => "cold",
+            t if t < 12.0 => "chilly",
+            t if t < 18.0 => "cool",
+            t if t < 25.0 => "mild",
+            t if t < 32.0 => "warm",
+            _ => "sweltering",
+        };
+        if self.player.inventory.has(&Item::Thermometer, 1) {
+            format!("{:.0}°C ({})", temp, feel)
+        } else {
+            feel.to_string()
+        }
+    }
+
+    pub fn has_weather_vane(&self) -> bool {
+        self.cabin_state().map(|c| c.weather_vane).unwrap_or(false)
+    }
+
+    /// What the sky suggests about the coming hours. A mounted weather vane
+    /// sharpens this from a rough feel to a region-by-region reading.
+    pub fn forecast(&self) -> String {
+        let pos = self.player.position;
+        let local = self.weather.get_for_position(pos.row, pos.col);
+        let outlook = |w: Weather| match w {
+            Weather::Clear | Weather::Cloudy | Weather::HeatWave => "fair",
+            Weather::Overcast | Weather::LightRain | Weather::LightSnow | Weather::Fog => {
+                "unsettled"
+            }
+            Weather::HeavyRain | Weather::HeavySnow | Weather::Blizzard | Weather::Sandstorm => {
+                "stormy"
+            }
+        };
+
+        if !self.has_weather_vane() {
+            return format!(
+                "You study the sky and the feel of the air. It seems likely to stay {} for a while, though the weather here can turn. {}",
+                outlook(local),
+                self.weather.wind.feel()
+            );
+        }
+
+        let front = self.weather_front();
+        let wind = self.weather.wind;
+        let wind_line = if wind.strength == WindStrength::Calm {
+            "Wind: calm; the vane hangs still.".to_string()
+        } else {
+            format!(
+                "Wind: {} out of the {}, blowing {}.",
+                wind.strength.name(),
+                wind.from.name(),
+                wind.downwind().name()
+            )
+        };
+        let regions = Region::ALL
+            .iter()
+            .map(|r| {
+                format!(
+                    "{} ({}): {}",
+                    capitalize(r.direction().name()),
+                    self.region_names.name(*r),
+                    self.weather.for_region(*r).name()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" | ");
+        format!(
+            "The weather vane on the cabin roof gives you a clearer read.\n\
+            {}\n\
+            {}\n\
+            Here it is {} and likely to stay that way for the next hour or so. {}",
+            regions,
+            wind_line,
+            local.name(),
+            front
+        )
+    }
+
+    /// Which region's weather is the one to watch, as read from the vane.
+    fn weather_front(&self) -> String {
+        let (region, weather) = Region::ALL
+            .iter()
+            .map(|r| (*r, self.weather.for_region(*r)))
+            .min_by(|a, b| {
+                a.1.visibility_modifier()
+                    .partial_cmp(&b.1.visibility_modifier())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap_or((Region::SouthernWoods, self.weather.south));
+        format!(
+            "The vane swings toward the {}; the {} over {} is the front to watch.",
+            region.direction().name(),
+            weather.name(),
+            self.region_names.name(region)
+        )
+    }
+
+    /// Things landing at the player's feet. Harvested results (from chopping
+    /// and the like) go into the pack whenever they fit; loose items only do
+    /// when the auto-pickup setting wants them. Whatever isn't taken stays on
+    /// the tile. Only the items passed in are considered, so nothing else
+    /// lying here (someone's cache, a set trap) is ever swept up.
+    pub fn land_at_feet(
+        &mut self,
+        map: &mut WorldMap,
+        items: &[(Item, u32)],
+        harvested: bool,
+    ) -> Option<String> {
+        let mode = self.player.auto_pickup;
+        let mut taken: Vec<String> = Vec::new();
+        let mut left: Vec<String> = Vec::new();
+        let mut overflow = false;
+        let pos = self.player.position;
+        for &(item, qty) in items {
+            let wanted = harvested || mode.wants(&item);
+            let mut carried = 0;
+            if wanted {
+                while carried < qty && self.player.inventory.add(item, 1) {
+                    carried += 1;
+                }
+                overflow |= carried < qty;
+            }
+            if carried > 0 {
+                taken.push(quantity_label(&self.display_name(&item), carried));
+            }
+            let rest = qty - carried;
+            if rest > 0 {
+                let placed = map
+                    .tile_at_mut(&pos)
+                    .map(|tile| tile.items.add(item, rest))
+                    .is_some();
+                if placed {
+                    left.push(quantity_label(&self.display_name(&item), rest));
+                }
+            }
+        }
+
+        let mut parts = Vec::new();
+        if !taken.is_empty() && !harvested {
+            parts.push(format!("You scoop up {}.", taken.join(", ")));
+        }
+        if !left.is_empty() {
+            if overflow {
+                parts.push(format!(
+                    "Your pack is too full for the rest, so {} stays on the ground.",
+                    left.join(", ")
+                ));
+            } else {
+                parts.push(format!("On the ground at your feet: {}.", left.join(", ")));
+            }
+        }
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(" "))
+        }
+    }
+
+    /// Once a day at dawn, a short note on the day's weather.
+    /// The region the player is out in, once the ground underfoot has taken
+    /// on its character; the shared lake and clearing belong to none.
+    pub fn region_here(&self, map: &WorldMap) -> Option<Region> {
+        if self.player.room.is_some() || self.player.inside.is_some() {
+            return None;
+        }
+        let pos = self.player.position;
+        let region = WorldMap::region_of(pos.row, pos.col);
+        let (r, c) = map.index_of(&pos)?;
+        let biome = map.get_biome_at(r, c)?;
+        let belongs = biome == region.biome()
+            || (region == Region::GlassDesert && biome == Biome::Oasis);
+        belongs.then_some(region)
+    }
+
+    /// Give a region a name of the player's own; blank restores the old one.
+    pub fn name_region(&mut self, region: Region, name: &str) -> String {
+        let old = self.region_names.name(region).to_string();
+        self.region_names.set(region, name);
+        let new = self.region_names.name(region);
+        if old == new {
+            format!("{} keeps its name.", capitalize(new))
+        } else {
+            format!("You decide to call {} '{}' from now on.", old, new)
+        }
+    }
+
+    fn dawn_summary(&mut self, map: &WorldMap) {
+        if self.time.time_of_day() != TimeOfDay::Dawn
+            || self.last_dawn_summary_day == self.time.day
+        {
+            return;
+        }
+        self.last_dawn_summary_day = self.time.day;
+        self.maybe_write_duck_letter();
+        self.milestone(format!("day-{}", self.time.day));
+        let pos = self.player.position;
+        let greeting = match &self.player.name {
+            Some(name) => format!("Good morning, {}. ", name),
+            None => String::new(),
+        };
+        let place = match self.region_here(map) {
+            Some(region) => format!(" in {}", self.region_names.name(region)),
+            None => String::new(),
+        };
+        let mut summary = format!(
+            "{}Dawn of day {}{}. The air is {} and the sky {}.",
+            greeting,
+            self.time.day,
+            place,
+            self.temperature_readout(map),
+            self.weather.get_for_position(pos.row, pos.col).name()
+        );
+        if self.has_weather_vane() {
+            summary.push(' ');
+            summary.push_str(&self.weather_front());
+        }
+        self.pending_messages.push(summary);
+    }
+
+    /// At the dawn a week is out, the duck leaves a letter about it on the
+    /// cabin table and starts keeping track of the next.
+    fn maybe_write_duck_letter(&mut self) {
+        let day = self.time.day;
+        let week = &self.letters.week;
+        if week.start_day == 0 {
+            self.letters.week = WeekLog::begin(day, &self.practice);
+            return;
+        }
+        if day < week.start_day + LETTER_DAYS {
+            return;
+        }
+        let number = self.letters.written.len() as u32 + 1;
+        let mut entry = BookEntry::new(
+            self.generate_book_id(),
+            format!("Letter from the Duck, Week {}", number),
+            false,
+        );
+        entry.pages = letters::write_letter(
+            number,
+            &self.letters.week,
+            &self.practice,
+            day - 1,
+            self.player.name.as_deref(),
+        );
+        let id = self.register_book(entry);
+        self.letters.written.push(id.clone());
+        let in_storm = self.letters.week.in_storm;
+        self.letters.week = WeekLog::begin(day, &self.practice);
+        self.letters.week.in_storm = in_storm;
+
+        let on_table = self
+            .table_surface_mut()
+            .map(|s| s.add_item(Item::Book))
+            .unwrap_or(false);
+        if let Some(cabin) = self.cabin_state_mut() {
+            if !on_table {
+                cabin.add_item(Item::Book);
+            }
+            cabin.book_ids.push(id.clone());
+        }
+        self.pending_messages.push(format!(
+            "Something new is waiting in the cabin: a letter [{}], addressed in a hand that looks suspiciously like a duck's.",
+            id
+        ));
+    }
+
+    /// The first read of each of the duck's letters lifts the mood.
+    pub fn read_duck_letter(&mut self, id: &str) -> Option<String> {
+        if !self.letters.written.iter().any(|w| w == id)
+            || !self.letters.read.insert(id.to_string())
+        {
+            return None;
+        }
+        self.player.modify_mood(LETTER_MOOD_BONUS);
+        Some("Being written to, even by a duck, warms you more than you'd admit.".to_string())
+    }
+
+    /// Bleeding, frostnip from the cold, and slow natural healing.
+    fn update_player_body(&mut self, rng: &mut impl Rng) {
+        let bleeding = self.player.body.bleeding_parts();
+        if bleeding > 0 {
+            self.player.modify_health(-0.3 * bleeding as f32);
+        }
+
+        if self.player.room.is_none() && self.player.warmth < 20.0 && rng.gen_bool(0.05) {
+            let arm = if rng.gen_bool(0.5) {
+                BodyPartKind::ArmLeft
+            } else {
+                BodyPartKind::ArmRight
+            };
+            let already = self
+                .player
+                .body
+                .part(arm)
+                .map(|p| p.has(PartCondition::Frostnipped))
+                .unwrap_or(true);
+            if !already {
+                self.player.injure_part(arm, 4.0, false);
+                if let Some(part) = self.player.body.part_mut(arm) {
+                    part.add_condition(PartCondition::Frostnipped);
+                }
+                self.pending_messages.push(format!(
+                    "The fingers of your {} have gone white and numb with cold. Frostnip - get warm soon.",
+                    arm.name()
+                ));
+            }
+        }
+        if self.player.warmth >= 45.0 {
+            for part in &mut self.player.body.parts {
+                if part.has(PartCondition::Frostnipped) && rng.gen_bool(0.1) {
+                    part.clear_condition(PartCondition::Frostnipped);
+                }
+            }
+        }
+
+        let well_fed = self.player.fullness > 30.0 && self.player.hydration > 30.0;
+        self.player.body.heal_tick(rng, well_fed);
+    }
+
+    pub fn begin_night_watch(&self) -> NightWatch {
+        let pos = self.player.position;
+        let fire_lit = matches!(self.player.room, Some(Room::CabinMain))
+            && self
+                .cabin_state()
+                .map(|c| c.fireplace.state != FireState::Cold)
+                .unwrap_or(false);
+        NightWatch {
+            fire_lit,
+            outdoors: self.player.room.is_none() && self.player.inside.is_none(),
+            blizzard: self.weather.get_for_position(pos.row, pos.col) == Weather::Blizzard,
+            visited: false,
+        }
+    }
+
+    /// Look for anything that happened during one sleeping tick. Events are
+    /// logged to the journal as they happen.
+    pub fn night_event(
+        &mut self,
+        watch: &mut NightWatch,
+        map: &mut WorldMap,
+        rng: &mut impl Rng,
+    ) -> Option<NightEvent> {
+        let pos = self.player.position;
+        let event = if watch.fire_lit
+            && self
+                .cabin_state()
+                .map(|c| c.fireplace.state == FireState::Cold)
+                .unwrap_or(false)
+        {
+            watch.fire_lit = false;
+            Some(NightEvent::FireDied)
+        } else if watch.outdoors
+            && !watch.blizzard
+            && self.weather.get_for_position(pos.row, pos.col) == Weather::Blizzard
+        {
+            watch.blizzard = true;
+            Some(NightEvent::Blizzard)
+        } else if !watch.visited
+            && self.time.time_of_day().is_night()
+            && rng.gen_bool((0.03 * self.event_rate).min(1.0))
+        {
+            watch.visited = true;
+            let biome = map
+                .biome_at(&pos)
+                .unwrap_or(Biome::MixedForest);
+            let species = match biome {
+                Biome::WinterForest => Species::SnowFox,
+                Biome::Desert | Biome::Oasis => Species::DesertFox,
+                _ => Species::Fox,
+            };
+            let stole = map.index_of(&pos).and_then(|(r, c)| {
+                let tile = map.get_tile_mut(r, c)?;
+                let food = tile
+                    .items
+                    .items
+                    .iter()
+                    .find(|(item, qty)| *qty > 0 && item.is_food())
+                    .map(|(item, _)| *item)?;
+                tile.items.take(&food).then_some(food)
+            });
+            Some(NightEvent::Visitor { species, stole })
+        } else {
+            None
+        };
+        if let Some(e) = &event {
+            self.journal.push(e.journal_line());
+            if let NightEvent::Blizzard = e {
+                self.player.modify_warmth(-10.0);
+            }
+        }
+        event
+    }
+
+    /// Count today's signal fire toward rescue. Returns how many days have
+    /// counted so far, or `None` if a fire already burned today.
+    pub fn record_signal_fire(&mut self) -> Option<usize> {
+        let day = self.time.day;
+        if self.rescue.signal_days.contains(&day) {
+            return None;
+        }
+        self.rescue.signal_days.push(day);
+        let count = self.rescue.signal_days.len();
+        if count >= SIGNAL_DAYS_NEEDED && self.rescue.ending.is_none() {
+            self.rescue.ending = Some(RescueEnding::Awaiting);
+            self.journal.push(RESCUE_CHAPTER.to_string());
+        }
+        Some(count)
+    }
+
+    /// Send the rescuers away and stay; the goal turns into lasting calm.
+    pub fn stay_after_rescue(&mut self) {
+        self.rescue.ending = Some(RescueEnding::Stayed);
+        self.player.modify_mood(15.0);
+    }
+
+    /// Closing tally for a run that ends with the player leaving.
+    pub fn run_summary(&self) -> String {
+        let skills = &self.player.skills;
+        let mut best: Vec<(&str, u8)> = Skill::ALL
+            .iter()
+            .map(|skill| (skill.name(), skills.get(*skill)))
+            .collect();
+        best.sort_by_key(|(_, level)| std::cmp::Reverse(*level));
+        let top: Vec<String> = best
+            .iter()
+            .take(3)
+            .map(|(name, lvl)| format!("{} {}", name, lvl))
+            .collect();
+
+        let mut achievements = Vec::new();
+        if self.tutorial_by_practice {
+            achievements.push("learned the basics without the book");
+        } else if self.tutorial_reward_claimed {
+            achievements.push("finished the cabin tutorial");
+        }
+        if self.card_scatter_achievement {
+            achievements.push("scattered the cards");
+        }
+        if self.duck_throw_achievement {
+            achievements.push("threw the duck");
+        }
+        if self.death_note.refusal_achievement {
+            achievements.push("was refused by the Death Note");
+        }
+        if self.player.naturalist {
+            achievements.push("completed the field notes");
+        }
+        if self.ruin().map(|r| r.fully_cleared()).unwrap_or(false) {
+            achievements.push("dug out the desert ruin");
+        }
+        achievements.push("signalled for rescue");
+
+        format!(
+            "**Your stay, in sum:**\n- Days: {}\n- Best skills: {}\n- Dishes cooked: {}\n- Animals killed: {}\n- Journal entries: {}\n- Achievements: {}",
+            self.time.day,
+            top.join(", "),
+            self.cooked_dishes.len(),
+            self.kills.len(),
+            self.journal.len(),
+            achievements.join(", ")
+        )
+    }
+
+    /// Health the body would mend per tick right now, before the daily cap.
+    /// Needs a full belly, water and warmth; bleeding stops it outright and
+    /// other treated or untreated conditions slow it.
+    pub fn health_regen_rate(&self) -> f32 {
+        let p = &self.player;
+        if self.exerting
+            || p.health >= 100.0
+            || p.fullness < 40.0
+            || p.hydration < 40.0
+            || !(35.0..=70.0).contains(&p.warmth)
+            || p.body.bleeding_parts() > 0
+        {
+            return 0.0;
+        }
+        let conditions = p
+            .body
+            .parts
+            .iter()
+            .filter(|part| !part.conditions.is_empty() || part.ratio() < 0.5)
+            .count();
+        let mut rate = HEALTH_REGEN_RATE * self.time.tick_scale() / (1 + conditions) as f32;
+        if self.sleeping {
+            rate *= SLEEP_REGEN_MULTIPLIER;
+        }
+        rate
+    }
+
+    /// Whether health is climbing back on its own at the moment.
+    pub fn is_recovering(&self) -> bool {
+        self.health_regen_rate() > 0.0
+            && (self.health_regen.day != self.time.day
+                || self.health_regen.gained < HEALTH_REGEN_DAILY_CAP)
+    }
+
+    fn update_health_regen(&mut self) {
+        if self.health_regen.day != self.time.day {
+            self.health_regen = HealthRegen {
+                day: self.time.day,
+                gained: 0.0,
+            };
+        }
+        let room = HEALTH_REGEN_DAILY_CAP - self.health_regen.gained;
+        let amount = self.health_regen_rate().min(room);
+        if amount <= 0.0 {
+            return;
+        }
+        let before = self.player.health;
+        self.player.modify_health(amount);
+        self.health_regen.gained += self.player.health - before;
+    }
+
+    fn update_player_comfort(&mut self, map: &WorldMap) {
+        let base_temp = self.current_temperature(map);
+
+        // Adjust player warmth toward environmental temperature
+        let comfort_target = (base_temp + 20.0).clamp(0.0, 100.0);
+        let current = self.player.warmth;
+        let delta = (comfort_target - current) * 0.1; // Gradual change
+        self.player.modify_warmth(delta);
+
+        // Having chosen to stay, the place itself keeps the player's spirits up
+        if self.rescue.ending == Some(RescueEnding::Stayed) {
+            self.player.modify_mood(0.2 * self.time.tick_scale());
+        }
+
+        // Mood effects from comfort
+        if self.player.warmth > 40.0 && self.player.warmth < 60.0 {
+            self.player.modify_mood(0.5); // Comfortable = happier
+        } else if self.player.warmth < 30.0 || self.player.warmth > 70.0 {
+            self.player.modify_mood(-0.5); // Uncomfortable = less happy
+        }
+    }
+
+    fn living_tree_count(&self) -> usize {
+        self.objects.living_tree_count()
+    }
+
+    /// Whether a new tree must not take root at `pos`: on or beside the
+    /// player, on anything placed or dropped there (the stones every tile
+    /// starts with don't count), or in the clearing around the cabin and the
+    /// shed.
+    fn tree_spot_forbidden(&self, map: &WorldMap, pos: &Position) -> bool {
+        let near = |other: &Position, radius: i32| {
+            (pos.row - other.row).abs() <= radius && (pos.col - other.col).abs() <= radius
+        };
+        if near(&self.player.position, 1) {
+            return true;
+        }
+        if !self.objects.objects_at(pos).is_empty() {
+            return true;
+        }
+        if map.tile_at(pos).is_some_and(|t| {
+            t.items
+                .items
+                .iter()
+                .any(|(item, qty)| *qty > 0 && *item != Item::Stone)
+        }) {
+            return true;
+        }
+        ["cabin", "wood_shed"].iter().any(|id| {
+            self.objects
+                .find(id)
+                .is_some_and(|p| near(&p.position, STRUCTURE_CLEARANCE))
+        })
+    }
+
+    fn find_free_tree_spot(
+        &self,
+        map: &WorldMap,
+        rng: &mut impl Rng,
+        attempts: usize,
+    ) -> Option<Position> {
+        for _ in 0..attempts {
+            let row = rng.gen_range(-map.extent()..=map.extent());
+            let col = rng.gen_range(-map.extent()..=map.extent());
+            let pos = Position::new(row, col);
+            if self.tree_spot_forbidden(map, &pos) || self.player.recent_steps.contains(&pos) {
+                continue;
+            }
+            let Some((gr, gc)) = map.index_of(&pos) else {
+                continue;
+            };
+            let Some(tile) = map.get_tile(gr, gc) else {
+                continue;
+            };
+            if matches!(tile.tile_type, TileType::Forest(biome) if !matches!(biome, Biome::Desert))
+                && tile.walkable
+            {
+                return Some(pos);
+            }
+        }
+        None
+    }
+
+    fn random_tree_kind(&self, rng: &mut impl Rng) -> TreeType {
+        match rng.gen_range(0..3) {
+            0 => TreeType::Pine,
+            1 => TreeType::Birch,
+            _ => TreeType::Apple,
+        }
+    }
+
+    fn spawn_tree(&mut self, map: &WorldMap, rng: &mut impl Rng) -> bool {
+        let Some(pos) = self.find_free_tree_spot(map, rng, 50) else {
+            return false;
+        };
+        let kind = map
+            .tile_at(&pos)
+            .map(|t| {
+                if matches!(t.biome, Biome::BambooGrove) {
+                    TreeType::Bamboo
+                } else {
+                    self.random_tree_kind(rng)
+                }
+            })
+            .unwrap_or_else(|| self.random_tree_kind(rng));
+        let mut tree = Tree::with_random_fruit(pos, kind, self.time.season(), rng);
+        tree.apply_kind_defaults();
+        let id = format!("tree-{}-{}-{}", pos.row, pos.col, self.objects.placed.len());
+        self.objects
+            .add(id, pos, WorldObject::new(ObjectKind::Tree(tree)));
+        true
+    }
+
+    fn seed_tree_population(&mut self, map: &WorldMap, rng: &mut impl Rng, target: usize) {
+        while self.living_tree_count() < target {
+            if !self.spawn_tree(map, rng) {
+                break;
+            }
+        }
+    }
+
+    fn ensure_tree_density(&mut self, map: &WorldMap, rng: &mut impl Rng) {
+        let extent = map.extent();
+        let mut world_row = -extent;
+        while world_row <= extent {
+            let mut world_col = -extent;
+            while world_col <= extent {
+                let mut eligible_positions: Vec<Position> = Vec::new();
+
+                let block_row_max = (world_row + 2).min(extent);
+                let block_col_max = (world_col + 2).min(extent);
+
+                let mut r = world_row;
+                while r <= block_row_max {
+                    let mut c = world_col;
+                    while c <= block_col_max {
+                        let pos = Position::new(r, c);
+                        if let Some((gr, gc)) = map.index_of(&pos) {
+                            if let Some(tile) = map.get_tile(gr, gc) {
+                                if matches!(
+                                    tile.tile_type,
+                                    TileType::Forest(biome) if !matches!(biome, Biome::Desert)
+                                ) && tile.walkable
+                                    && tile.elevation < BARE_ELEVATION
+                                {
+                                    eligible_positions.push(pos);
+                                }
+                            }
+                        }
+                        c += 1;
+                    }
+                    r += 1;
+                }
+
+                if !eligible_positions.is_empty() {
+                    let mut has_tree = false;
+                    for pos in &eligible_positions {
+                        if self
+                            .objects
+                            .objects_at(pos)
+                            .iter()
+                            .any(|p| matches!(p.object.kind, ObjectKind::Tree(ref tree) if !tree.felled))
+                        {
+                            has_tree = true;
+                            break;
+                        }
+                    }
+
+                    // Keep off forbidden tiles, and off the player's recent
+                    // path while anywhere else in the block will do.
+                    let allowed: Vec<Position> = eligible_positions
+                        .iter()
+                        .copied()
+                        .filter(|pos| !self.tree_spot_forbidden(map, pos))
+                        .collect();
+                    let unwalked: Vec<Position> = allowed
+                        .iter()
+                        .copied()
+                        .filter(|pos| !self.player.recent_steps.contains(pos))
+                        .collect();
+                    let choices = if unwalked.is_empty() {
+                        &allowed
+                    } else {
+                        &unwalked
+                    };
+
+                    if !has_tree && !choices.is_empty() {
+                        let pos = choices[rng.gen_range(0..choices.len())];
+
+                        let kind = map
+                            .tile_at(&pos)
+                            .map(|t| {
+                                if matches!(t.biome, Biome::BambooGrove) {
+                                    TreeType::Bamboo
+                                } else {
+                                    self.random_tree_kind(rng)
+                                }
+                            })
+                            .unwrap_or_else(|| self.random_tree_kind(rng));
+
+                        let mut tree = Tree::with_random_fruit(pos, kind, self.time.season(), rng);
+                        tree.apply_kind_defaults();
+                        let id =
+                            format!("tree-{}-{}-{}", pos.row, pos.col, self.objects.placed.len());
+                        self.objects
+                            .add(id, pos, WorldObject::new(ObjectKind::Tree(tree)));
+                    }
+                }
+
+                world_col += 3;
+            }
+            world_row += 3;
+        }
+    }
+
+    fn seed_bamboo_grove(&mut self, map: &WorldMap) {
+        for pos in map.layout().grove_stands() {
+            if self
+                .objects
+                .objects_at(&pos)
+                .iter()
+                .any(|p| matches!(p.object.kind, ObjectKind::Tree(_)))
+            {
+                continue;
+            }
+            let mut tree = Tree::new(pos, TreeType::Bamboo);
+            tree.apply_kind_defaults();
+            let id = format!("bamboo-{}-{}", pos.row, pos.col);
+            self.objects
+                .add(id, pos, WorldObject::new(ObjectKind::Tree(tree)));
+        }
+    }
+
+    /// Date palms on the oasis shore, planted once like the bamboo.
+    fn seed_oasis_palms(&mut self, map: &WorldMap) {
+        let season = self.time.season();
+        for pos in map.layout().oasis_palms() {
+            let open_ground = map
+                .tile_at(&pos)
+                .map(|t| t.walkable && matches!(t.tile_type, TileType::Forest(_)))
+                .unwrap_or(false);
+            if !open_ground
+                || self
+                    .objects
+                    .objects_at(&pos)
+                    .iter()
+                    .any(|p| matches!(p.object.kind, ObjectKind::Tree(_)))
+            {
+                continue;
+            }
+            let mut tree = Tree::new(pos, TreeType::DatePalm);
+            tree.set_season(season);
+            let id = format!("palm-{}-{}", pos.row, pos.col);
+            self.objects
+                .add(id, pos, WorldObject::new(ObjectKind::Tree(tree)));
         }
     }
 
-    pub fn take_table_item(&mut self, item: &Item) -> bool {
-        if let Some(surface) = self.table_surface_mut() {
-            return surface.take_item(item);
-        }
-        if let Some(cabin) = self.cabin_state_mut() {
-            return cabin.take_table_item(item);
+    fn update_trees(&mut self, map: &WorldMap, rng: &mut impl Rng) {
+        let season = self.time.season();
+        self.objects
+            .for_each_tree_mut(|tree, _| tree.tick_growth(season, rng));
+        if self.living_tree_count() <= 5 {
+            let _ = self.spawn_tree(map, rng);
         }
-        false
     }
 
-    pub fn add_table_item(&mut self, item: Item) {
-        if let Some(surface) = self.table_surface_mut() {
-            surface.add_item(item);
+    pub fn set_custom_name(&mut self, item: Item, name: &str) {
+        let trimmed = name.trim();
+        if trimmed.is_empty() {
+            self.custom_names.remove(&item);
             return;
         }
-        if let Some(cabin) = self.cabin_state_mut() {
-            cabin.add_table_item(item);
-        }
-    }
-
-    pub fn table_item_names(&self) -> Vec<String> {
-        if let Some(surface) = self.table_surface() {
-            return surface.items.iter().map(|i| i.name().to_string()).collect();
-        }
-        self.cabin_state()
-            .map(|c| c.table_item_names())
-            .unwrap_or_default()
+        let capped = trimmed.chars().take(32).collect::<String>();
+        self.custom_names.insert(item, capped);
     }
 
-    fn has_any_playing_cards(&self, map: &WorldMap) -> bool {
-        if self.player.inventory.has(&Item::PlayingCard, 1) {
-            return true;
+    /// Why `name` can't be given to `item` or book `book`: it's already
+    /// another item's custom name, a book's title, or an item's own name.
+    /// Reusing one would leave later lookups guessing which was meant.
+    pub fn name_conflict(
+        &self,
+        name: &str,
+        item: Option<Item>,
+        book: Option<&str>,
+    ) -> Option<String> {
+        let trimmed = name.trim();
+        if trimmed.is_empty() {
+            return None;
         }
-
-        if self
-            .cabin_state()
-            .map(|c| {
-                c.items.contains(&Item::PlayingCard)
-                    || c.table_items.contains(&Item::PlayingCard)
-            })
-            .unwrap_or(false)
+        if let Some((other, taken)) = self
+            .custom_names
+            .iter()
+            .find(|(other, n)| Some(**other) != item && n.eq_ignore_ascii_case(trimmed))
         {
-            return true;
+            return Some(format!(
+                "You already call your {} '{}'. Pick a name nothing else goes by.",
+                other.name(),
+                taken
+            ));
         }
-
-        if self
-            .table_surface()
-            .map(|s| s.items.contains(&Item::PlayingCard))
-            .unwrap_or(false)
+        if let Some(other) = self
+            .books
+            .values()
+            .find(|b| Some(b.id.as_str()) != book && b.title.trim().eq_ignore_ascii_case(trimmed))
         {
-            return true;
+            return Some(format!(
+                "There is already a book called '{}' [{}]. Pick a name nothing else goes by.",
+                other.title, other.id
+            ));
         }
-
-        for r in 0..MAP_HEIGHT {
-            for c in 0..MAP_WIDTH {
-                if let Some(tile) = map.get_tile(r, c) {
-                    if tile
-                        .items
-                        .items
-                        .iter()
-                        .any(|(item, qty)| *item == Item::PlayingCard && *qty > 0)
-                    {
-                        return true;
-                    }
-                }
-            }
+        match Item::from_str(trimmed).filter(|i| i.matches_exact(trimmed)) {
+            Some(other) if Some(other) != item => Some(format!(
+                "'{}' already means the {}. Pick a name nothing else goes by.",
+                trimmed,
+                other.name()
+            )),
+            _ => None,
         }
-
-        false
     }
 
-    fn ensure_card_case_state(&mut self, map: &WorldMap) {
-        if self.card_case_cards_inside == 0 && !self.has_any_playing_cards(map) {
-            self.card_case_cards_inside = 52;
-            self.card_case_open = false;
+    /// Retitle a book the player can reach. Only books they can write in
+    /// will take a new title.
+    pub fn rename_book(&mut self, id: &str, name: &str) -> Result<String, String> {
+        let trimmed = name.trim();
+        if trimmed.is_empty() {
+            return Err("A book needs a title. Give it a new one instead.".to_string());
+        }
+        if let Some(conflict) = self.name_conflict(trimmed, None, Some(id)) {
+            return Err(conflict);
+        }
+        let book = self
+            .books
+            .get_mut(id)
+            .ok_or_else(|| "You don't have a book like that within reach.".to_string())?;
+        if !book.writable {
+            return Err(format!(
+                "'{}' isn't yours to retitle; its name is already set down for good.",
+                book.title
+            ));
         }
+        let capped = trimmed.chars().take(32).collect::<String>();
+        let old = std::mem::replace(&mut book.title, capped.clone());
+        Ok(format!(
+            "You write a new title on the cover of '{}' [{}]: '{}'.",
+            old, id, capped
+        ))
     }
 
-    /// Create a new game state with initial values
-    pub fn new(map: &WorldMap) -> Self {
-        let mut rng = rand::thread_rng();
-        let mut state = Self {
-            version: "1.0".to_string(),
-            time: WorldTime::new(),
-            weather: RegionalWeather::new(),
-            player: Player::new(),
-            wildlife: spawn_wildlife(),
-            objects: ObjectRegistry::new(),
-            custom_names: HashMap::new(),
-            forage_nodes: HashMap::new(),
-            books: GameState::default_books(),
-            next_book_id: GameState::default_next_book_id(),
-            pending_messages: Vec::new(),
-            legacy_cabin: None,
-            legacy_wood_shed: None,
-            legacy_trees: None,
-            card_case_cards_inside: 52,
-            card_case_open: false,
-            card_scatter_achievement: false,
-            tutorial_reward_claimed: false,
-            tutorial_hint_shown: false,
-        };
-        state.ensure_book_registry();
-        state.bootstrap_structures();
-        state.ensure_cabin_books();
-        state.ensure_player_visit();
-        state.refresh_blueprint_knowledge(false);
-        state.seed_bamboo_grove();
-        state.ensure_card_case_state(map);
-        state.seed_tree_population(map, &mut rng, 10);
-        state.ensure_tree_density(map, &mut rng);
-        state.update_player_cognition();
-        state
+    pub fn custom_name(&self, item: &Item) -> Option<&str> {
+        self.custom_names.get(item).map(|s| s.as_str())
     }
 
-    /// Save state to a JSON file
-    pub fn save(&self, path: &Path) -> Result<()> {
-        let json = serde_json::to_string_pretty(self)?;
-        std::fs::write(path, json)?;
-        Ok(())
+    pub fn display_name(&self, item: &Item) -> String {
+        self.custom_name(item)
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| item.name().to_string())
     }
 
-    /// Load state from a JSON file
-    pub fn load(path: &Path) -> Result<Self> {
-        let json = std::fs::read_to_string(path)?;
-        let state: GameState = serde_json::from_str(&json)?;
-        Ok(state)
+    /// What the player has in hand, as a phrase: "the axe", or "the fishing
+    /// rod and the firefly jar". `None` when both hands are empty.
+    pub fn in_hand(&self) -> Option<String> {
+        let names: Vec<String> = self
+            .player
+            .hands
+            .items()
+            .filter(|item| self.player.inventory.has(item, 1))
+            .map(|item| format!("the {}", self.display_name(&item)))
+            .collect();
+        (!names.is_empty()).then(|| names.join(" and "))
     }
 
-    /// Load state or create new if file doesn't exist
-    pub fn load_or_new(path: &Path, map: &WorldMap) -> Self {
-        if path.exists() {
-            match Self::load(path) {
-                Ok(mut state) => {
-                    tracing::info!("Loaded existing game state from {:?}", path);
-                    if state.wildlife.is_empty() {
-                        tracing::info!("Wildlife was empty, spawning new wildlife");
-                        state.wildlife = spawn_wildlife();
-                    }
-                    if state.custom_names.is_empty() {
-                        state.custom_names = HashMap::new();
-                    }
-                    if state.forage_nodes.is_empty() {
-                        state.forage_nodes = HashMap::new();
-                    }
+    /// Inventory-style label: the custom name with the original in parentheses.
+    pub fn labeled_name(&self, item: &Item) -> String {
+        match self.custom_name(item) {
+            Some(custom) => format!("{} ({})", custom, item.name()),
+            None => item.name().to_string(),
+        }
+    }
 
-                    if state.books.is_empty() {
-                        state.books = GameState::default_books();
-                    }
-                    state.ensure_book_registry();
+    /// Resolve a player-typed item name, honoring custom names before canonical ones.
+    /// What the description of the player's surroundings reads from.
+    pub fn location_context<'a>(&'a self, map: &'a WorldMap) -> LocationContext<'a> {
+        LocationContext {
+            player: &self.player,
+            map,
+            time: &self.time,
+            weather: &self.weather,
+            wildlife: &self.wildlife,
+            objects: &self.objects,
+            custom_names: &self.custom_names,
+            region_names: &self.region_names,
+            letters_written: self.letters.written.len() as u32,
+        }
+    }
 
-                    state.ensure_tree_objects_from_legacy();
-                    state.bootstrap_structures();
-                    state.ensure_cabin_books();
-                    state.ensure_player_visit();
-                    state.refresh_blueprint_knowledge(false);
-                    state.seed_bamboo_grove();
+    pub fn resolve_item(&self, query: &str) -> Option<Item> {
+        let norm = query.trim().to_lowercase();
+        if norm.is_empty() {
+            return None;
+        }
+        let by_custom = self
+            .custom_names
+            .iter()
+            .find(|(_, name)| name.to_lowercase() == norm)
+            .map(|(item, _)| *item);
+        by_custom.or_else(|| Item::from_str(query))
+    }
 
-                    state.ensure_card_case_state(map);
+    pub fn name_companion(&mut self, target: &str, new_name: &str) -> Result<String, String> {
+        let norm = target.to_lowercase();
+        let pos = self.player.position;
+        let mut best_idx: Option<usize> = None;
+        let mut best_dist = f32::MAX;
 
-                    let mut rng = rand::thread_rng();
-                    state.seed_tree_population(map, &mut rng, 10);
-                    state.ensure_tree_density(map, &mut rng);
-                    state.update_player_cognition();
-                    state
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to load state: {}, creating new", e);
-                    Self::new(map)
-                }
+        for (idx, w) in self.wildlife.iter().enumerate() {
+            if !w.tamed {
+                continue;
+            }
+            let species_name = w.species.name().to_lowercase();
+            if !species_name.contains(&norm) && !norm.contains(&species_name) {
+                continue;
+            }
+            let dist = pos.distance_to(&w.position);
+            if dist <= 6.0 && dist < best_dist {
+                best_dist = dist;
+                best_idx = Some(idx);
             }
-        } else {
-            tracing::info!("No save file found, creating new game state");
-            Self::new(map)
         }
-    }
 
-    /// Advance the simulation by one tick
-    pub fn tick_with_map(&mut self, map: &WorldMap) {
-        // Advance time
-        self.time.advance_tick();
+        let idx = best_idx.ok_or_else(|| {
+            "You don't have a tamed companion like that nearby.".to_string()
+        })?;
 
-        // Update weather occasionally
-        if self.time.tick % 10 == 0 {
-            self.weather.update();
+        let trimmed = new_name.trim();
+        if trimmed.is_empty() {
+            let w = &mut self.wildlife[idx];
+            return Ok(match w.name.take() {
+                Some(old) => format!(
+                    "You stop calling your {} '{}'. It doesn't seem to mind.",
+                    w.species.name(),
+                    old
+                ),
+                None => format!("Your {} has no name to clear.", w.species.name()),
+            });
         }
+        let capped = trimmed.chars().take(32).collect::<String>();
 
-        let mut rng = rand::thread_rng();
-        // Update wildlife
-        let tod = self.time.time_of_day();
-        for w in &mut self.wildlife {
-            w.update(tod, map, &self.weather);
+        if let Some(w) = self.wildlife.get_mut(idx) {
+            w.name = Some(capped.clone());
+            let species_name = w.species.name();
+            return Ok(format!("You name your {} '{}'.", species_name, capped));
         }
-        self.update_companions(map);
-        self.maybe_spawn_edge_wildlife(map, &mut rng);
 
-        // Update fireplace and collect any warnings
-        if let Some(cabin) = self.cabin_state_mut() {
-            if let Some(fire_msg) = cabin.fireplace.update() {
-                self.pending_messages.push(fire_msg);
+        Err("Something went wrong while naming that companion.".to_string())
+    }
+
+    pub fn player_can_access_item(&self, item: &Item) -> bool {
+        if self.player.inventory.has(item, 1) || self.player.mounts.has(item) {
+            return true;
+        }
+        if matches!(self.player.room, Some(Room::CabinMain)) {
+            let in_cabin = self
+                .cabin_state()
+                .map(|c| c.items.contains(item) || c.table_items.contains(item))
+                .unwrap_or(false);
+            let on_surface = [self.table_surface(), self.mantel_surface()]
+                .into_iter()
+                .flatten()
+                .any(|s| s.items.contains(item));
+            if in_cabin || on_surface {
+                return true;
             }
         }
+        false
+    }
+}
 
-        self.update_trees(map, &mut rng);
-        self.update_forage_nodes(map, &mut rng);
-        self.tick_corpses();
+impl Default for GameState {
+    fn default() -> Self {
+        Self::new(&WorldMap::new())
+    }
+}
+
+/// Full world context including map (which isn't saved)
+pub struct World {
+    pub map: WorldMap,
+    pub state: GameState,
+    pub state_path: std::path::PathBuf,
+    /// The thread still writing the last batch of snapshots, if any.
+    snapshot_writer: Option<std::thread::JoinHandle<()>>,
+}
+
+impl World {
+    pub fn new(state_path: std::path::PathBuf) -> Self {
+        // A save keeps its own world; only new games take the environment's settings
+        let (seed, extent) = GameState::saved_map_settings(&state_path)
+            .unwrap_or_else(|| (world_seed_from_env(), map_extent_from_env()));
+        let mut map = WorldMap::generate(seed, extent);
+        let mut state = GameState::load_or_new(&state_path, &map);
+        state.audit_cards(&mut map);
+        state.audit_unique_items(&mut map);
+        if let Some(minutes) = std::env::var("RUBBER_DUCK_MINUTES_PER_TICK")
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+        {
+            if !state.time.set_minutes_per_tick(minutes) {
+                tracing::warn!(
+                    "Ignoring RUBBER_DUCK_MINUTES_PER_TICK={}; use 5, 10, or 20",
+                    minutes
+                );
+            }
+        }
+        Self {
+            map,
+            state,
+            state_path,
+            snapshot_writer: None,
+        }
+    }
 
-        // Hunger / thirst decay
-        self.player.modify_fullness(-0.5);
-        self.player.modify_hydration(-0.5);
-        if self.player.fullness < 20.0 {
-            self.player.modify_energy(-1.0);
-            self.player.modify_mood(-1.0);
-            if self.player.fullness < 10.0 {
-                self.pending_messages
-                    .push("Your stomach growls painfully. You need to eat soon.".to_string());
+    /// Write the save. After a run of failures the save moves, once, to a
+    /// fallback under the home or temp directory.
+    pub fn save(&mut self) -> Result<()> {
+        self.flush_snapshots();
+        let e = match self.state.save(&self.state_path) {
+            Ok(bytes) => {
+                save_health::record_success(bytes);
+                return Ok(());
             }
+            Err(e) => e,
+        };
+        if !save_health::record_failure(&e.to_string()) {
+            return Err(e);
         }
-        if self.player.hydration < 20.0 {
-            self.player.modify_energy(-1.0);
-            if self.player.hydration < 10.0 {
-                self.player.modify_health(-0.5);
-                self.pending_messages
-                    .push("Your mouth is dry and head swims. Drink water soon.".to_string());
-            }
+        let fallback = save_health::fallback_path(&self.state_path);
+        if let Some(parent) = fallback.parent() {
+            let _ = std::fs::create_dir_all(parent);
         }
+        let Ok(bytes) = self.state.save(&fallback) else {
+            return Err(e);
+        };
+        tracing::warn!(
+            "Saving to {:?} from now on; {:?} couldn't be written: {}",
+            fallback,
+            self.state_path,
+            e
+        );
+        save_health::record_fallback(&fallback);
+        save_health::record_success(bytes);
+        self.state_path = fallback;
+        Ok(())
+    }
 
-        // Update player warmth based on environment
-        self.update_player_comfort(map);
-
-        // Check for newly unlocked blueprints as skills/books progress
-        self.refresh_blueprint_knowledge(true);
-
-        // Keep cognition in sync with injuries, health, and rest
-        self.update_player_cognition();
+    /// Hand the snapshots taken since the last save to a writer thread,
+    /// once the previous batch has finished.
+    pub fn flush_snapshots(&mut self) {
+        let pending = std::mem::take(&mut self.state.pending_snapshots);
+        if pending.is_empty() {
+            return;
+        }
+        self.join_snapshot_writer();
+        self.snapshot_writer = snapshots::write_in_background(&self.state_path, pending);
     }
 
-    fn tick_corpses(&mut self) {
-        for po in &mut self.objects.placed {
-            if let ObjectKind::Corpse(corpse) = &mut po.object.kind {
-                corpse.freshness = corpse.freshness.saturating_add(1);
-            }
+    fn join_snapshot_writer(&mut self) {
+        if let Some(writer) = self.snapshot_writer.take() {
+            let _ = writer.join();
         }
     }
 
-    fn update_companions(&mut self, map: &WorldMap) {
-        let player_pos = self.player.position;
+    pub fn tick(&mut self) {
+        let started = std::time::Instant::now();
+        self.state.tick_with_map(&mut self.map);
+        crate::metrics::record_tick(started.elapsed());
+    }
+}
 
-        for w in &mut self.wildlife {
-            if !w.tamed {
-                continue;
-            }
-            if !matches!(w.species, Species::Dog | Species::Cat) {
-                continue;
-            }
+impl Drop for World {
+    fn drop(&mut self) {
+        self.join_snapshot_writer();
+    }
+}
 
-            let dist = w.position.distance_to(&player_pos);
-            if dist <= 1.5 {
-                continue;
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::{interaction, movement, InteractionResult};
+    use crate::descriptions::DescriptionGenerator;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn the_run_summary_leads_with_the_best_skill() {
+        let mut state = GameState::default();
+        while state.player.skills.get(Skill::Observation) < 40 {
+            state.player.skills.improve(Skill::Observation, 255);
+        }
+        let summary = state.run_summary();
+        let level = state.player.skills.get(Skill::Observation);
+        let best = format!("{} {}", Skill::Observation.name(), level);
+        let listed: Vec<&str> = Skill::ALL.iter().map(|s| s.name()).collect();
+        let first = listed
+            .iter()
+            .filter_map(|name| summary.find(&format!("{} ", name)))
+            .min();
+        assert_eq!(first, summary.find(&best), "{}", summary);
+    }
 
-            let dr = (player_pos.row - w.position.row).signum();
-            let dc = (player_pos.col - w.position.col).signum();
-            let new_pos = Position::new(w.position.row + dr, w.position.col + dc);
-            if let Some((r, c)) = new_pos.as_usize() {
-                if map.is_walkable(r, c) {
-                    w.position = new_pos;
+    /// Shuffle the player around the cabin and the woods nearby, doing
+    /// whatever can be done with the cards, ticking and reloading between.
+    #[test]
+    fn the_world_never_holds_more_than_a_deck() {
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let mut rng = StdRng::seed_from_u64(52);
+        for step in 0..2000 {
+            match rng.gen_range(0..10) {
+                0 => {
+                    interaction::try_take("card", &mut state, &mut map);
+                }
+                1 => {
+                    interaction::try_take_all("all cards", &mut state, &mut map);
+                }
+                2 => {
+                    interaction::try_drop("card", &mut state, &mut map);
+                }
+                3 => {
+                    interaction::try_use("card", Some("card case"), &mut state, &mut map);
+                }
+                4 => {
+                    interaction::try_use("card case", None, &mut state, &mut map);
+                }
+                5 => {
+                    if rng.gen_bool(0.5) {
+                        interaction::try_open("card case", &mut state);
+                    } else {
+                        interaction::try_close("card case", &mut state);
+                    }
+                }
+                6 => {
+                    if rng.gen_bool(0.5) {
+                        interaction::try_drop("card case", &mut state, &mut map);
+                    } else {
+                        interaction::try_take("card case", &mut state, &mut map);
+                    }
+                }
+                7 => {
+                    if rng.gen_bool(0.3) {
+                        state.player.room = Some(Room::CabinMain);
+                        state.player.position = Position::new(0, 0);
+                    } else {
+                        state.player.room = None;
+                        state.player.position =
+                            Position::new(rng.gen_range(-2..=2), rng.gen_range(-2..=2));
+                    }
+                }
+                8 => state.tick_with_map(&mut map),
+                _ => {
+                    let json = serde_json::to_string(&state).unwrap();
+                    state = serde_json::from_str(&json).unwrap();
+                    state.audit_cards(&mut map);
                 }
             }
+            let census = state.card_census(&map);
+            assert!(
+                census.total() + census.lost <= CardCensus::DECK_SIZE,
+                "step {}: {:?}",
+                step,
+                census
+            );
         }
     }
 
-    fn maybe_spawn_edge_wildlife(&mut self, map: &WorldMap, rng: &mut impl Rng) {
-        if self.wildlife.len() > 80 {
-            return;
-        }
-        if !rng.gen_bool(0.04) {
-            return;
+    #[test]
+    fn no_way_of_moving_the_duck_about_makes_two() {
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let mut rng = StdRng::seed_from_u64(2221);
+        let path =
+            std::env::temp_dir().join(format!("rubber-duck-ducks-{}.json", std::process::id()));
+        for step in 0..1500 {
+            match rng.gen_range(0..8) {
+                0 => {
+                    interaction::try_take("rubber duck", &mut state, &mut map);
+                }
+                1 => {
+                    interaction::try_take_all("all", &mut state, &mut map);
+                }
+                2 => {
+                    interaction::try_drop("rubber duck", &mut state, &mut map);
+                }
+                3 => {
+                    interaction::try_store("rubber duck", &mut state, &mut map);
+                }
+                4 => {
+                    let spot = ["mantel", "table", "shoulder"][rng.gen_range(0..3)];
+                    interaction::try_mount("rubber duck", spot, &mut state);
+                }
+                5 => {
+                    if rng.gen_bool(0.5) {
+                        state.player.room = Some(Room::CabinMain);
+                        state.player.position = Position::new(0, 0);
+                    } else {
+                        state.player.room = None;
+                        state.player.position =
+                            Position::new(rng.gen_range(1..=3), rng.gen_range(-2..=2));
+                    }
+                }
+                6 => state.tick_with_map(&mut map),
+                // Reloads, now and then a full one onto a fresh map with bare ground
+                _ if rng.gen_bool(0.8) => {
+                    let json = serde_json::to_string(&state).unwrap();
+                    state = serde_json::from_str(&json).unwrap();
+                    state.audit_unique_items(&mut map);
+                }
+                _ => {
+                    state.save(&path).unwrap();
+                    if rng.gen_bool(0.3) {
+                        map = WorldMap::new();
+                    }
+                    state = GameState::load_or_new(&path, &map);
+                    state.audit_unique_items(&mut map);
+                }
+            }
+            let ducks = state.item_census(&Item::RubberDuck, &map);
+            assert!(ducks <= 1, "{} ducks at step {}", ducks, step);
         }
+        let _ = std::fs::remove_file(&path);
+    }
 
-        let edge = rng.gen_range(0..4);
-        let (row_range, col_range) = match edge {
-            0 => (-12..-4, -4..5),  // north band
-            1 => (4..12, -4..5),    // south band
-            2 => (-4..5, 7..13),    // east band
-            _ => (-4..5, -14..-7),  // west band
-        };
-
-        let row = rng.gen_range(row_range);
-        let col = rng.gen_range(col_range);
-        let pos = Position::new(row, col);
-        if let Some((r, c)) = pos.as_usize() {
-            if !map.is_walkable(r, c) {
-                return;
-            }
-        } else {
-            return;
+    #[test]
+    fn yesterday_s_skill_gains_are_gone_by_morning() {
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let day = state.time.day;
+        state.player.skills.start_day(day);
+        state.player.skills.improve(Skill::Woodcutting, 12);
+        assert_eq!(state.player.skills.gained_on(Skill::Woodcutting, day).0, 12);
+
+        while state.time.day == day {
+            state.tick_with_map(&mut map);
         }
+        let skills = &state.player.skills;
+        assert_eq!(skills.today.day, state.time.day);
+        assert_eq!(skills.gained_on(Skill::Woodcutting, state.time.day), (0, 0));
+        assert!(skills.today.xp.is_empty());
+    }
 
-        let biome = pos
-            .as_usize()
-            .and_then(|(r, c)| map.get_tile(r, c).map(|t| t.biome))
-            .unwrap_or(Biome::MixedForest);
+    /// On the map, on their feet only where there's ground, afloat only on water.
+    fn assert_placed(state: &GameState, map: &WorldMap, step: usize) {
+        let pos = state.player.position;
+        assert!(map.contains(&pos), "off the map at step {}", step);
+        if state.aboard_raft() {
+            assert!(map.is_open_water(&pos), "raft aground at step {}", step);
+        } else if state.player.room.is_none() {
+            assert!(map.can_stand_at(&pos), "in the water at step {}", step);
+        }
+        if let Some(raft) = state.raft() {
+            assert!(map.is_open_water(&raft.position), "step {}", step);
+        }
+    }
 
-        let species = match biome {
-            Biome::SpringForest | Biome::MixedForest => {
-                let choices = [
-                    Species::Deer,
-                    Species::Rabbit,
-                    Species::Squirrel,
-                    Species::Boar,
-                    Species::Goat,
-                    Species::Sheep,
-                    Species::Horse,
-                    Species::Bear,
-                    Species::Lynx,
-                    Species::Dog,
-                    Species::Cat,
-                ];
-                choices[rng.gen_range(0..choices.len())]
-            }
-            Biome::WinterForest => {
-                let choices = [
-                    Species::SnowFox,
-                    Species::Wolf,
-                    Species::Caribou,
-                    Species::SnowHare,
-                    Species::Moose,
-                    Species::Elk,
-                    Species::Bear,
-                ];
-                choices[rng.gen_range(0..choices.len())]
-            }
-            Biome::Desert | Biome::Oasis => {
-                let choices = [
-                    Species::DesertLizard,
-                    Species::Scorpion,
-                    Species::DesertFox,
-                    Species::Hawk,
-                    Species::Rattlesnake,
-                    Species::Camel,
-                    Species::Hyena,
-                ];
-                choices[rng.gen_range(0..choices.len())]
+    #[test]
+    fn walking_paddling_and_drifting_never_leave_solid_ground() {
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let mut rng = StdRng::seed_from_u64(2211);
+        let extent = map.extent();
+        let lake: Vec<Position> = (-extent..=extent)
+            .flat_map(|row| (-extent..=extent).map(move |col| Position::new(row, col)))
+            .filter(|p| map.is_open_water(p))
+            .collect();
+        assert!(!lake.is_empty());
+        let dirs = [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ];
+        for step in 0..5000 {
+            // Storms and gales blow up and die down
+            if rng.gen_bool(0.05) {
+                let weather = if rng.gen_bool(0.5) {
+                    Weather::HeavyRain
+                } else {
+                    Weather::Clear
+                };
+                let w = &mut state.weather;
+                (w.north, w.south, w.east, w.west) = (weather, weather, weather, weather);
+                w.wind.from = dirs[rng.gen_range(0..dirs.len())];
+                w.wind.strength = if rng.gen_bool(0.5) {
+                    WindStrength::Gale
+                } else {
+                    WindStrength::Calm
+                };
             }
-            Biome::Lake | Biome::Path | Biome::Clearing | Biome::BambooGrove => {
-                let choices = [
-                    Species::Duck,
-                    Species::Heron,
-                    Species::Frog,
-                    Species::Pig,
-                    Species::Goat,
-                    Species::Dog,
-                    Species::Cat,
-                ];
-                choices[rng.gen_range(0..choices.len())]
+            let dir = dirs[rng.gen_range(0..dirs.len())];
+            match rng.gen_range(0..10) {
+                0 if state.player.room.is_none() => {
+                    if state.raft().is_none() {
+                        let mooring = lake[rng.gen_range(0..lake.len())];
+                        let raft = WorldObject::new(ObjectKind::Raft(Raft::default()));
+                        state.objects.add(RAFT_ID, mooring, raft);
+                    }
+                    state.player.position = state.raft().unwrap().position;
+                    state.player.inside = Some(RAFT_ID.to_string());
+                }
+                1 => {
+                    movement::try_exit(&mut state.player, &map, &state.objects);
+                }
+                2 => {
+                    if let Some(home) = movement::home_step(&state.player, &map, &state.objects) {
+                        movement::try_move(&mut state.player, home, &map, &state.objects, true);
+                    }
+                }
+                3 => state.update_raft(&map, &mut rng),
+                // Anywhere at all that can be stood on, out to the very edges
+                4 => {
+                    let row = rng.gen_range(-extent..=extent);
+                    let pos = Position::new(row, rng.gen_range(-extent..=extent));
+                    if map.can_stand_at(&pos) {
+                        state.player.exit_room();
+                        state.player.inside = None;
+                        state.player.position = pos;
+                    }
+                }
+                _ if state.aboard_raft() => {
+                    movement::try_paddle(&mut state, dir, &mut map);
+                }
+                _ => {
+                    movement::try_move(&mut state.player, dir, &map, &state.objects, true);
+                }
             }
-        };
-
-        self.wildlife.push(Wildlife::new(species, pos));
+            assert_placed(&state, &map, step);
+        }
     }
 
-    fn update_forage_nodes(&mut self, map: &WorldMap, rng: &mut impl Rng) {
-        let positions: Vec<Position> = self.forage_nodes.keys().copied().collect();
-        for pos in positions {
-            if let Some(node) = self.forage_nodes.get_mut(&pos) {
-                let biome = pos
-                    .as_usize()
-                    .and_then(|(r, c)| map.get_tile(r, c).map(|t| t.biome))
-                    .unwrap_or(Biome::MixedForest);
-                node.tick(biome, rng);
+    #[test]
+    fn compacting_an_old_save_shrinks_it_without_changing_the_game() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        // Foraged everywhere over the years, most of it long grown back,
+        // every tile walked, and a backlog of messages nobody read
+        let ground: Vec<Position> = map.positions().filter(|p| map.can_stand_at(p)).collect();
+        for (i, pos) in ground.iter().enumerate() {
+            let node = state.foraging_node_for(*pos, &map);
+            if i % 50 == 0 {
+                node.harvest();
             }
+            state.player.position = *pos;
+            state.player.mark_visited();
+        }
+        for i in 0..500 {
+            let unread = format!("Message {} that nobody read.", i);
+            state.pending_messages.push(unread);
+        }
+        let foraged = state.forage_nodes.clone();
+        let visited = state.player.visited.clone();
+        let aged = serde_json::to_string(&state).unwrap().len();
+
+        state.compact(&map);
+        let json = serde_json::to_string(&state).unwrap();
+        let compacted = json.len();
+        assert!(compacted * 2 < aged, "{} bytes down to {}", aged, compacted);
+
+        let mut reloaded: GameState = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded.player.visited, visited);
+        assert_eq!(reloaded.pending_messages.len(), MAX_PENDING_MESSAGES);
+        for (pos, was) in &foraged {
+            let node = reloaded.foraging_node_for(*pos, &map);
+            assert_eq!(
+                (node.charges, node.cooldown, node.capacity, node.scarred),
+                (was.charges, was.cooldown, was.capacity, was.scarred),
+                "{:?}",
+                pos
+            );
         }
     }
 
-    fn update_player_comfort(&mut self, map: &WorldMap) {
-        let fire_heat = if matches!(self.player.room, Some(Room::CabinMain)) {
-            self.cabin_state()
-                .map(|c| c.fireplace.heat_output())
-                .unwrap_or(0.0)
-        } else {
-            0.0
-        };
-
-        // Get position for temperature calculation
-        let world_row = self.player.position.row;
-        let world_col = self.player.position.col;
-        let (row, col) = self
-            .player
-            .position
-            .as_usize()
-            .unwrap_or((MAP_ORIGIN_ROW as usize, MAP_ORIGIN_COL as usize));
-        let biome = map
-            .get_tile(row, col)
-            .map(|t| t.biome)
-            .unwrap_or(Biome::MixedForest);
-        let tod = self.time.time_of_day();
-
-        let base_temp = match self.player.room {
-            Some(_) if fire_heat > 0.0 => 18.0 + fire_heat,
-            Some(_) => 16.0, // Indoor base temp
-            None => {
-                let weather_temp = self
-                    .weather
-                    .get_for_position(world_row, world_col)
-                    .temperature_modifier();
-                biome.base_temperature() + tod.temperature_modifier() + weather_temp
+    #[test]
+    fn a_banked_fire_lasts_the_night_on_less_wood() {
+        let mut map = WorldMap::new();
+        let mut nights = Vec::new();
+        for banked in [false, true] {
+            let mut state = GameState::new(&map);
+            let hearth = &mut state.cabin_state_mut().unwrap().fireplace;
+            hearth.state = FireState::Roaring;
+            hearth.fuel = 100.0;
+            hearth.bulk_lit = true;
+            if banked {
+                hearth.bank().unwrap();
             }
-        };
-
-        // Adjust player warmth toward environmental temperature
-        let comfort_target = (base_temp + 20.0).clamp(0.0, 100.0);
-        let current = self.player.warmth;
-        let delta = (comfort_target - current) * 0.1; // Gradual change
-        self.player.modify_warmth(delta);
+            // Seven hours: an open fire burns out even on a still night, and
+            // a banked one holds even in a gale
+            for _ in 0..42 {
+                state.tick_with_map(&mut map);
+            }
+            let hearth = &state.cabin_state().unwrap().fireplace;
+            nights.push((hearth.state, hearth.fuel));
+        }
+        let (open, banked) = (nights[0], nights[1]);
+        assert_eq!(open.0, FireState::Cold);
+        assert_ne!(banked.0, FireState::Cold);
+        assert!(100.0 - banked.1 < 100.0 - open.1, "{:?}", nights);
+    }
 
-        // Mood effects from comfort
-        if self.player.warmth > 40.0 && self.player.warmth < 60.0 {
-            self.player.modify_mood(0.5); // Comfortable = happier
-        } else if self.player.warmth < 30.0 || self.player.warmth > 70.0 {
-            self.player.modify_mood(-0.5); // Uncomfortable = less happy
+    #[test]
+    fn each_edge_of_the_map_puts_a_stray_player_back_where_they_left() {
+        let map = WorldMap::new();
+        let reach = map.extent() * 2;
+        for dir in [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ] {
+            let mut state = GameState::new(&map);
+            // The last ground there is heading that way
+            let (dr, dc) = dir.delta();
+            let edge = map
+                .positions()
+                .filter(|p| map.can_stand_at(p))
+                .max_by_key(|p| p.row * dr + p.col * dc)
+                .unwrap();
+            state.player.position = edge;
+            movement::try_move(&mut state.player, dir, &map, &state.objects, true);
+            assert_eq!(state.player.position, edge, "walked {:?} off the map", dir);
+
+            // However it got out there, a player past the edge comes back
+            let stray = Position::new(edge.row + dr * reach, edge.col + dc * reach);
+            state.player.position = stray;
+            state.keep_player_on_map(&map);
+            assert_eq!(state.player.position, edge, "{:?}", dir);
         }
     }
 
-    fn living_tree_count(&self) -> usize {
-        self.objects.living_tree_count()
+    #[test]
+    fn a_blizzard_leaks_in_and_open_windows_cool_a_heat_wave() {
+        fn indoors(state: &mut GameState, weather: Weather, windows_open: bool) -> f32 {
+            let w = &mut state.weather;
+            (w.north, w.south, w.east, w.west) = (weather, weather, weather, weather);
+            state.cabin_state_mut().unwrap().windows_open = windows_open;
+            state.cabin_temperature()
+        }
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        assert_eq!(state.cabin_state().unwrap().snow_drifts(), 0);
+
+        let shut = indoors(&mut state, Weather::HeatWave, false);
+        let open = indoors(&mut state, Weather::HeatWave, true);
+        assert_eq!(shut - open, 10.0);
+
+        // Shut tight, a blizzard outside makes no difference
+        assert_eq!(indoors(&mut state, Weather::Blizzard, false), 16.0);
+        let hearth = &mut state.cabin_state_mut().unwrap().fireplace;
+        hearth.state = FireState::Burning;
+        hearth.fuel = 50.0;
+        let fire = hearth.heat_output();
+        assert!(fire > 0.0);
+        let shut = indoors(&mut state, Weather::Blizzard, false);
+        let open = indoors(&mut state, Weather::Blizzard, true);
+        // The draught takes twelve degrees and half of what the fire gives
+        assert_eq!(shut - open, 12.0 + fire * 0.5);
     }
 
-    fn find_free_tree_spot(
-        &self,
-        map: &WorldMap,
-        rng: &mut impl Rng,
-        attempts: usize,
-    ) -> Option<Position> {
-        for _ in 0..attempts {
-            let row = rng.gen_range(-MAP_EXTENT..=MAP_EXTENT);
-            let col = rng.gen_range(-MAP_EXTENT..=MAP_EXTENT);
-            let pos = Position::new(row, col);
-            if self
-                .objects
-                .objects_at(&pos)
-                .iter()
-                .any(|p| matches!(p.object.kind, ObjectKind::Tree(_)) || p.object.anchored)
-            {
-                continue;
-            }
-            let Some((gr, gc)) = pos.as_usize() else {
-                continue;
-            };
-            let Some(tile) = map.get_tile(gr, gc) else {
-                continue;
-            };
-            if matches!(tile.tile_type, TileType::Forest(biome) if !matches!(biome, Biome::Desert))
-                && tile.walkable
-            {
-                return Some(pos);
+    #[test]
+    fn a_cluttered_cabin_runs_down_faster_and_tidying_restores_it() {
+        let mut map = WorldMap::new();
+        let mut fallen = Vec::new();
+        for clutter in [0, 13] {
+            let mut state = GameState::new(&map);
+            let cabin = state.cabin_state_mut().unwrap();
+            cabin.items.add(Item::Firewood, clutter);
+            let before = cabin.tidiness;
+            for _ in 0..100 {
+                state.tick_with_map(&mut map);
             }
+            fallen.push(before - state.cabin_state().unwrap().tidiness);
         }
-        None
+        assert!(fallen[0] > 0.0, "{:?}", fallen);
+        assert!(fallen[1] > fallen[0], "{:?}", fallen);
+
+        let mut state = GameState::new(&map);
+        state.player.room = Some(Room::CabinMain);
+        let cabin = state.cabin_state_mut().unwrap();
+        cabin.tidiness = 20.0;
+        cabin.drifts = 2;
+        let mood = state.player.mood;
+        let tidied = interaction::try_tidy(&mut state);
+        assert!(matches!(tidied, InteractionResult::ActionSuccess { .. }));
+        let cabin = state.cabin_state().unwrap();
+        assert_eq!(cabin.tidiness, 100.0);
+        assert_eq!(cabin.snow_drifts(), 0);
+        assert!(state.player.mood > mood);
+        // Nothing left to put right
+        let again = interaction::try_tidy(&mut state);
+        assert!(!matches!(again, InteractionResult::ActionSuccess { .. }));
     }
 
-    fn random_tree_kind(&self, rng: &mut impl Rng) -> TreeType {
-        match rng.gen_range(0..3) {
-            0 => TreeType::Pine,
-            1 => TreeType::Birch,
-            _ => TreeType::Apple,
+    #[test]
+    fn my_recipes_is_started_once_with_a_page_for_each_new_dish() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let skills = &mut state.player.skills;
+        while skills.get(Skill::Cooking) < RECIPE_BOOK_SKILL - 1 {
+            skills.improve(Skill::Cooking, 1);
         }
+        state.record_dish(Item::CookedFish, 0);
+        assert!(state.recipe_book_id.is_none());
+
+        // Cooking the berries is enough practice to start the book
+        state.record_dish(Item::CookedBerries, 255);
+        let id = state.recipe_book_id.clone().unwrap();
+        assert!(state.player.book_ids.contains(&id));
+        // Started with the fish from before, as well as the berries
+        assert_eq!(state.books[&id].pages.len(), 2);
+
+        state.record_dish(Item::CookedBerries, 0);
+        state.record_dish(Item::CookedFish, 0);
+        assert_eq!(state.books[&id].pages.len(), 2);
+        state.record_dish(Item::RoastedNuts, 0);
+        assert_eq!(state.books[&id].pages.len(), 3);
+
+        assert_eq!(state.recipe_book_id.as_deref(), Some(id.as_str()));
+        let recipe_books = state
+            .books
+            .values()
+            .filter(|b| b.title == "My Recipes")
+            .count();
+        assert_eq!(recipe_books, 1);
     }
 
-    fn spawn_tree(&mut self, map: &WorldMap, rng: &mut impl Rng) -> bool {
-        let Some(pos) = self.find_free_tree_spot(map, rng, 50) else {
-            return false;
+    #[test]
+    fn a_wasted_kill_stings_and_a_fully_used_one_is_made_peace_with() {
+        let map = WorldMap::new();
+        let kill = |state: &mut GameState, pos: Position| {
+            state.wildlife.push(Wildlife::new(Species::Deer, pos));
+            let (species, corpse) = state.leave_corpse(state.wildlife.len() - 1);
+            state.record_kill(species, corpse);
         };
-        let kind = pos
-            .as_usize()
-            .and_then(|(r, c)| map.get_tile(r, c))
-            .map(|t| {
-                if matches!(t.biome, Biome::BambooGrove) {
-                    TreeType::Bamboo
-                } else {
-                    self.random_tree_kind(rng)
-                }
-            })
-            .unwrap_or_else(|| self.random_tree_kind(rng));
-        let mut tree = Tree::with_random_fruit(pos, kind, rng);
-        tree.apply_kind_defaults();
-        let id = format!("tree-{}-{}-{}", pos.row, pos.col, self.objects.placed.len());
-        self.objects
-            .add(id, pos, WorldObject::new(ObjectKind::Tree(tree)));
-        true
+
+        // Left where it fell until it rots, then found again
+        let mut state = GameState::new(&map);
+        state.player.room = None;
+        state.player.position = Position::new(3, 3);
+        kill(&mut state, Position::new(4, 4));
+        for _ in 0..90 {
+            state.tick_corpses();
+            state.update_hunting_conscience();
+        }
+        assert!(state.kills[0].wasted);
+        assert!(!state.kills[0].mourned);
+        let mood = state.player.mood;
+        state.player.position = Position::new(4, 4);
+        state.update_hunting_conscience();
+        assert!(state.kills[0].mourned);
+        assert!(state.player.mood < mood);
+        assert!(state.lingering_mood < 0.0);
+
+        // Butchered, cooked and its hide put to use
+        let mut state = GameState::new(&map);
+        state.player.room = None;
+        state.player.position = Position::new(3, 3);
+        kill(&mut state, Position::new(3, 3));
+        state.butcher_corpse_at_player(&Item::Knife).unwrap();
+        state.note_kill_meat_cooked();
+        assert!(!state.kills[0].reconciled);
+        let mood = state.player.mood;
+        state.note_kill_hide_used();
+        assert!(state.kills[0].reconciled);
+        assert!(!state.kills[0].wasted);
+        assert!(state.journal.last().unwrap().starts_with("Nothing wasted."));
+        assert!(state.lingering_mood > 0.0);
+        for _ in 0..24 {
+            state.update_hunting_conscience();
+        }
+        assert!(state.player.mood > mood);
+        assert_eq!(state.lingering_ticks, 0);
     }
 
-    fn seed_tree_population(&mut self, map: &WorldMap, rng: &mut impl Rng, target: usize) {
-        while self.living_tree_count() < target {
-            if !self.spawn_tree(map, rng) {
-                break;
-            }
+    #[test]
+    fn a_ritual_gives_less_each_time_today_and_all_of_it_again_tomorrow() {
+        let mut rituals = RitualLog::default();
+        let today: Vec<f32> = (0..3).map(|_| rituals.perform(4, "skip_stone")).collect();
+        assert_eq!(today[0], 1.0);
+        assert!(today[1] < today[0] && today[2] < today[1], "{:?}", today);
+        // Each ritual wears thin on its own
+        assert_eq!(rituals.perform(4, "watch_ripples"), 1.0);
+        assert_eq!(rituals.counts["skip_stone"], 3);
+
+        rituals.stone_knack = 4.0;
+        assert_eq!(rituals.perform(5, "skip_stone"), 1.0);
+        assert_eq!(rituals.perform(5, "watch_ripples"), 1.0);
+        assert_eq!(rituals.day, 5);
+        assert_eq!(rituals.counts["skip_stone"], 1);
+        // The knack fades overnight, but not all the way
+        assert_eq!(rituals.stone_knack, 2.0);
+    }
+
+    #[test]
+    fn the_thermometer_reads_the_game_s_temperature_and_the_vane_sharpens_the_forecast() {
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.player.room = None;
+        state.player.position = Position::new(1, 0);
+        assert!(!state.temperature_readout(&map).contains("°C"));
+        state.player.inventory.add(Item::Thermometer, 1);
+        let reading = format!("{:.0}°C (", state.current_temperature(&map));
+        assert!(state.temperature_readout(&map).starts_with(&reading));
+
+        let (clear, storm) = (Weather::Clear, Weather::Blizzard);
+        let w = &mut state.weather;
+        (w.north, w.south, w.east, w.west) = (clear, clear, storm, clear);
+        let rough = state.forecast();
+        assert!(!rough.contains(storm.name()), "{}", rough);
+        state.player.inventory.add(Item::WeatherVane, 1);
+        let mounted = interaction::try_use("weather vane", None, &mut state, &mut map);
+        assert!(matches!(mounted, InteractionResult::ActionSuccess { .. }));
+        assert!(state.has_weather_vane());
+        // Region by region, so a storm in the east shows even from here
+        let sharp = state.forecast();
+        assert!(sharp.contains(storm.name()), "{}", sharp);
+        assert!(sharp.contains("Wind:"), "{}", sharp);
+    }
+
+    #[test]
+    fn auto_pickup_takes_what_the_setting_wants_and_leaves_what_won_t_fit() {
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.player.room = None;
+        state.player.position = Position::new(3, 3);
+        state.player.inventory = Inventory::new();
+        let pos = state.player.position;
+        let on_ground = |map: &WorldMap, item: Item| map.tile_at(&pos).unwrap().items.count(&item);
+        // Someone else's things already here are never swept up
+        map.tile_at_mut(&pos).unwrap().items.add(Item::WildBerry, 3);
+        let fallen = [(Item::WildBerry, 2), (Item::Log, 1)];
+
+        let mut kept = Vec::new();
+        for mode in [AutoPickup::Off, AutoPickup::Light, AutoPickup::All] {
+            state.player.auto_pickup = mode;
+            let berries = on_ground(&map, Item::WildBerry);
+            let logs = on_ground(&map, Item::Log);
+            let carried = state.player.inventory.clone();
+            state.land_at_feet(&mut map, &fallen, false);
+            let picked = |item: Item| state.player.inventory.count(&item) - carried.count(&item);
+            let (berry, log) = (picked(Item::WildBerry), picked(Item::Log));
+            assert_eq!(on_ground(&map, Item::WildBerry) + berry, berries + 2);
+            assert_eq!(on_ground(&map, Item::Log) + log, logs + 1);
+            kept.push((berry, log));
         }
+        assert_eq!(kept, [(0, 0), (2, 0), (2, 1)]);
+
+        // A full pack leaves the rest on the ground, and says so
+        while state.player.inventory.add(Item::Log, 1) {}
+        let logs = on_ground(&map, Item::Log);
+        let note = state.land_at_feet(&mut map, &[(Item::Log, 2)], false);
+        assert_eq!(on_ground(&map, Item::Log), logs + 2);
+        assert!(note.unwrap().contains("too full"));
     }
 
-    fn ensure_tree_density(&mut self, map: &WorldMap, rng: &mut impl Rng) {
-        let mut world_row = -MAP_EXTENT;
-        while world_row <= MAP_EXTENT {
-            let mut world_col = -MAP_EXTENT;
-            while world_col <= MAP_EXTENT {
-                let mut eligible_positions: Vec<Position> = Vec::new();
+    #[test]
+    fn green_wood_seasons_in_the_shed_over_three_days() {
+        // Stock from saves made before seasoning counts as already dry
+        let old = serde_json::json!({
+            "logs": 4,
+            "firewood": 2,
+            "axe_on_floor": false,
+            "chopping_block": ChoppingBlock::new(),
+        });
+        let shed: WoodShed = serde_json::from_value(old).unwrap();
+        assert_eq!(shed.seasoned(&Item::Log), 4);
+        assert_eq!(shed.seasoned(&Item::Firewood), 2);
+
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let shed = state.wood_shed_state_mut().unwrap();
+        shed.firewood = 5;
+        shed.green.add(Item::Firewood, 3, 0);
+        for _ in 1..GreenWood::SEASONING_TICKS {
+            state.tick_with_map(&mut map);
+        }
+        let shed = state.wood_shed_state_mut().unwrap();
+        assert_eq!(shed.seasoned(&Item::Firewood), 2);
+        // Seasoned wood comes off the stack before the green
+        assert_eq!(shed.take_wood(&Item::Firewood), Some(None));
+        assert_eq!(shed.green.count(&Item::Firewood), 3);
+        state.tick_with_map(&mut map);
+        let shed = state.wood_shed_state_mut().unwrap();
+        assert_eq!(shed.seasoned(&Item::Firewood), 4);
+        assert!(shed.green.batches.is_empty());
+    }
 
-                let block_row_max = (world_row + 2).min(MAP_EXTENT);
-                let block_col_max = (world_col + 2).min(MAP_EXTENT);
+    #[test]
+    fn a_sharp_eye_sees_what_a_novice_walks_past_and_finds_it_only_once() {
+        let mut map = WorldMap::new();
+        let spot = map
+            .positions()
+            .filter(|p| map.can_stand_at(p))
+            .find(|p| {
+                let detail = map.biome_at(p).and_then(|b| HiddenDetail::at(*p, b));
+                detail.map(|d| d.threshold() >= 22).unwrap_or(false)
+            })
+            .unwrap();
+        let here = |state: &GameState, map: &WorldMap| {
+            DescriptionGenerator::describe_location(
+                &state.location_context(map),
+                state.noticed_detail(map),
+                None,
+                &mut Vec::new(),
+            )
+        };
 
-                let mut r = world_row;
-                while r <= block_row_max {
-                    let mut c = world_col;
-                    while c <= block_col_max {
-                        let pos = Position::new(r, c);
-                        if let Some((gr, gc)) = pos.as_usize() {
-                            if let Some(tile) = map.get_tile(gr, gc) {
-                                if matches!(
-                                    tile.tile_type,
-                                    TileType::Forest(biome) if !matches!(biome, Biome::Desert)
-                                ) && tile.walkable
-                                {
-                                    eligible_positions.push(pos);
-                                }
-                            }
-                        }
-                        c += 1;
-                    }
-                    r += 1;
-                }
+        let mut novice = GameState::new(&map);
+        novice.player.room = None;
+        novice.player.position = spot;
+        let mut expert = novice.clone();
+        while expert.player.effective_skill(Skill::Observation) < 40 {
+            expert.player.skills.improve(Skill::Observation, 255);
+        }
+        let detail = expert.hidden_detail_here(&map).unwrap();
+        assert!(novice.noticed_detail(&map).is_none());
+        assert!(!here(&novice, &map).contains(detail.notice()));
+        assert_eq!(expert.noticed_detail(&map), Some(detail));
+        assert!(here(&expert, &map).contains(detail.notice()));
+
+        let keyword = detail.keywords()[0];
+        assert!(novice.claim_hidden_detail(keyword, &mut map).is_none());
+        assert!(expert.claim_hidden_detail(keyword, &mut map).is_some());
+        assert_eq!(expert.journal.last().unwrap(), detail.lore());
+        // Found once, then it's just part of the place
+        assert!(expert.claim_hidden_detail(keyword, &mut map).is_none());
+        assert!(!here(&expert, &map).contains(detail.notice()));
+    }
 
-                if !eligible_positions.is_empty() {
-                    let mut has_tree = false;
-                    for pos in &eligible_positions {
-                        if self
-                            .objects
-                            .objects_at(pos)
-                            .iter()
-                            .any(|p| matches!(p.object.kind, ObjectKind::Tree(ref tree) if !tree.felled))
-                        {
-                            has_tree = true;
-                            break;
-                        }
-                    }
+    #[test]
+    fn the_cave_bear_can_be_avoided_woken_fed_or_fought() {
+        let mut map = WorldMap::new();
+        let in_the_cave = |map: &WorldMap| {
+            let mut state = GameState::new(map);
+            state.player.exit_room();
+            state.player.position = state.objects.find(CAVE_ID).unwrap().position;
+            state.player.inside = Some(CAVE_ID.to_string());
+            state.player.inventory = Inventory::new();
+            state
+        };
+        let bear = |state: &GameState| {
+            let po = state.objects.find(CAVE_BEAR_ID).unwrap();
+            po.object.as_cave_bear().unwrap().clone()
+        };
+        let mut rng = StdRng::seed_from_u64(7);
+
+        // Crept in, listened, and left it sleeping
+        let mut state = in_the_cave(&map);
+        state.update_cave_bear(&map, &mut rng);
+        state.player.inside = None;
+        state.update_cave_bear(&map, &mut rng);
+        assert!(bear(&state).avoided);
+        assert_eq!(bear(&state).state, BearState::Hibernating);
+
+        // Too much noise, and it comes for you
+        let mut state = in_the_cave(&map);
+        let health = state.player.body.overall_health_ratio();
+        state.make_noise(CaveBear::WAKE_AT);
+        assert_eq!(bear(&state).state, BearState::Hostile);
+        state.update_cave_bear(&map, &mut rng);
+        assert!(bear(&state).mauled);
+        assert!(!state.in_cave());
+        assert!(state.player.body.overall_health_ratio() < health);
+
+        // Stirring, and calmed with a fish for good
+        let mut state = in_the_cave(&map);
+        state.make_noise(CaveBear::STIR_AT);
+        assert_eq!(bear(&state).state, BearState::Stirring);
+        state.player.inventory.add(Item::Fish, 1);
+        interaction::try_use("fish", Some("bear"), &mut state, &mut map);
+        assert_eq!(bear(&state).state, BearState::Pacified);
+        state.make_noise(CaveBear::WAKE_AT);
+        assert_eq!(bear(&state).state, BearState::Pacified);
+        let name = format!("rubber-duck-bear-{}.json", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        state.save(&path).unwrap();
+        let state = GameState::load_or_new(&path, &map);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(bear(&state).state, BearState::Pacified);
+
+        // Fought until it leaves the cave
+        let mut state = in_the_cave(&map);
+        let po = state.objects.find_mut(CAVE_BEAR_ID).unwrap();
+        if let ObjectKind::CaveBear(b) = &mut po.object.kind {
+            b.health = 1.0;
+        }
+        state.player.inventory.add(Item::Axe, 1);
+        interaction::try_use("axe", Some("bear"), &mut state, &mut map);
+        assert_eq!(bear(&state).state, BearState::DrivenOff);
+        assert!(state.cave_bear().is_none());
+        assert!(state.journal.last().unwrap().contains("it fled"));
+    }
 
-                    if !has_tree {
-                        let idx = rng.gen_range(0..eligible_positions.len());
-                        let pos = eligible_positions[idx];
+    #[test]
+    fn a_strong_wind_draws_the_hearth_down_faster() {
+        let mut map = WorldMap::new();
+        let mut burned = |strength: WindStrength| {
+            let mut state = GameState::new(&map);
+            state.time.tick = 1;
+            state.weather.wind.strength = strength;
+            let fire = &mut state.cabin_state_mut().unwrap().fireplace;
+            (fire.state, fire.bulk_lit, fire.fuel) = (FireState::Roaring, true, 100.0);
+            state.tick_with_map(&mut map);
+            100.0 - state.cabin_state().unwrap().fireplace.fuel
+        };
+        let still = burned(WindStrength::Calm);
+        assert!(still > 0.0);
+        assert!((burned(WindStrength::Breeze) - still).abs() < 0.01);
+        assert!((burned(WindStrength::Strong) - still * 1.25).abs() < 0.01);
+        assert!((burned(WindStrength::Gale) - still * 1.5).abs() < 0.01);
+    }
 
-                        let kind = pos
-                            .as_usize()
-                            .and_then(|(gr, gc)| map.get_tile(gr, gc))
-                            .map(|t| {
-                                if matches!(t.biome, Biome::BambooGrove) {
-                                    TreeType::Bamboo
-                                } else {
-                                    self.random_tree_kind(rng)
-                                }
-                            })
-                            .unwrap_or_else(|| self.random_tree_kind(rng));
+    #[test]
+    fn the_night_can_kill_the_fire_bring_a_visitor_or_turn_to_blizzard() {
+        let mut map = WorldMap::new();
+        let mut rng = StdRng::seed_from_u64(11);
+        let night = |map: &WorldMap| {
+            let mut state = GameState::new(map);
+            let (w, clear) = (&mut state.weather, Weather::Clear);
+            (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+            state.time.hour = 23;
+            state.event_rate = 0.0;
+            state
+        };
 
-                        let mut tree = Tree::with_random_fruit(pos, kind, rng);
-                        tree.apply_kind_defaults();
-                        let id =
-                            format!("tree-{}-{}-{}", pos.row, pos.col, self.objects.placed.len());
-                        self.objects
-                            .add(id, pos, WorldObject::new(ObjectKind::Tree(tree)));
-                    }
-                }
+        // A fire lit at bedtime that burns out wakes you once
+        let mut state = night(&map);
+        state.player.room = Some(Room::CabinMain);
+        let fire = &mut state.cabin_state_mut().unwrap().fireplace;
+        (fire.state, fire.bulk_lit, fire.fuel) = (FireState::Burning, true, 10.0);
+        let mut watch = state.begin_night_watch();
+        assert_eq!(state.night_event(&mut watch, &mut map, &mut rng), None);
+        state.cabin_state_mut().unwrap().fireplace.extinguish();
+        let event = state.night_event(&mut watch, &mut map, &mut rng);
+        assert_eq!(event, Some(NightEvent::FireDied));
+        assert!(event.unwrap().wakes());
+        assert!(state.journal.last().unwrap().contains("the fire went out"));
+        assert_eq!(state.night_event(&mut watch, &mut map, &mut rng), None);
+
+        // A fox makes off with the fish left out on the ground
+        let mut state = night(&map);
+        state.player.exit_room();
+        let pos = state.player.position;
+        let (r, c) = map.index_of(&pos).unwrap();
+        map.get_tile_mut(r, c).unwrap().items.add(Item::Fish, 1);
+        state.event_rate = 100.0;
+        let mut watch = state.begin_night_watch();
+        let Some(NightEvent::Visitor { stole, .. }) =
+            state.night_event(&mut watch, &mut map, &mut rng)
+        else {
+            panic!("something should have come by in the night");
+        };
+        assert_eq!(stole, Some(Item::Fish));
+        let ground = &map.get_tile(r, c).unwrap().items;
+        assert!(!ground.items.iter().any(|(i, q)| *i == Item::Fish && *q > 0));
+        assert!(state.journal.last().unwrap().contains("took my"));
+        // Only the once a night
+        assert_eq!(state.night_event(&mut watch, &mut map, &mut rng), None);
+
+        // The weather turning on someone sleeping out in the open
+        let mut state = night(&map);
+        state.player.exit_room();
+        let mut watch = state.begin_night_watch();
+        let warmth = state.player.warmth;
+        let (w, storm) = (&mut state.weather, Weather::Blizzard);
+        (w.north, w.south, w.east, w.west) = (storm, storm, storm, storm);
+        let event = state.night_event(&mut watch, &mut map, &mut rng);
+        assert_eq!(event, Some(NightEvent::Blizzard));
+        assert!(event.unwrap().wakes());
+        assert!(state.player.warmth < warmth);
+    }
 
-                world_col += 3;
+    #[test]
+    fn a_sandstorm_buries_what_was_dropped_and_digging_brings_it_back() {
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let mut rng = StdRng::seed_from_u64(7);
+        let (w, storm) = (&mut state.weather, Weather::Sandstorm);
+        (w.north, w.south, w.east, w.west) = (storm, storm, storm, storm);
+        let pos = map
+            .positions()
+            .find(|p| map.biome_at(p) == Some(Biome::Desert))
+            .unwrap();
+        let lying = |map: &WorldMap| map.tile_at(&pos).unwrap().items.count(&Item::Cordage);
+
+        // Twice over: the storm takes it under, then search or a shovel turns it up
+        for dig in [false, true] {
+            map.tile_at_mut(&pos).unwrap().items.add(Item::Cordage, 1);
+            state.player.room = None;
+            state.player.position = Position::new(pos.row, pos.col + 3);
+            for _ in 0..2000 {
+                if state.sands.has_buried(&pos) && lying(&map) == 0 {
+                    break;
+                }
+                state.update_sandstorm(&mut map, &mut rng);
             }
-            world_row += 3;
+            assert!(state.sands.has_buried(&pos));
+            assert_eq!(lying(&map), 0);
+
+            // Out of sight, and still under the sand after a reload
+            state.player.position = pos;
+            let take = interaction::try_take("cordage", &mut state, &mut map);
+            assert!(!matches!(take, InteractionResult::ActionSuccess { .. }));
+            let name = format!("rubber-duck-sands-{}.json", std::process::id());
+            let path = std::env::temp_dir().join(name);
+            state.save(&path).unwrap();
+            state = GameState::load_or_new(&path, &map);
+            let _ = std::fs::remove_file(&path);
+            assert!(state.sands.has_buried(&pos));
+
+            let found = if dig {
+                state.player.inventory.add(Item::Shovel, 1);
+                interaction::try_use("shovel", None, &mut state, &mut map)
+            } else {
+                interaction::try_search(&mut state, &mut map)
+            };
+            assert!(matches!(found, InteractionResult::ActionSuccess { .. }));
+            assert!(!state.sands.has_buried(&pos));
+            assert!(lying(&map) >= 1);
+            let tile = map.tile_at_mut(&pos).unwrap();
+            tile.items.take_all(&Item::Cordage);
         }
     }
 
-    fn seed_bamboo_grove(&mut self) {
-        let grove_positions = [
-            Position::new(0, -2),
-            Position::new(0, -3),
-            Position::new(1, -2),
+    #[test]
+    fn the_death_note_takes_an_animal_s_kind_and_refuses_its_owner() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.add_player_book(DEATH_NOTE_ID);
+        let here = state.player.position;
+        let near = Position::new(here.row, here.col + 1);
+        let far = Position::new(here.row + 10, here.col);
+        state.wildlife = vec![
+            Wildlife::new(Species::Rabbit, far),
+            Wildlife::new(Species::Rabbit, near),
         ];
-        for pos in grove_positions {
-            if self
-                .objects
-                .objects_at(&pos)
-                .iter()
-                .any(|p| matches!(p.object.kind, ObjectKind::Tree(_)))
-            {
-                continue;
-            }
-            let mut tree = Tree::new(pos, TreeType::Bamboo);
-            tree.apply_kind_defaults();
-            let id = format!("bamboo-{}-{}", pos.row, pos.col);
-            self.objects
-                .add(id, pos, WorldObject::new(ObjectKind::Tree(tree)));
-        }
-    }
+        let write = |state: &mut GameState, text: &str| {
+            let result = interaction::write_on_book(text, DEATH_NOTE_ID, state);
+            let InteractionResult::ActionSuccess { message, .. } = result else {
+                panic!("the note refused {}", text);
+            };
+            message
+        };
 
-    fn update_trees(&mut self, map: &WorldMap, rng: &mut impl Rng) {
-        self.objects
-            .for_each_tree_mut(|tree, _| tree.tick_growth(rng));
-        if self.living_tree_count() <= 5 {
-            let _ = self.spawn_tree(map, rng);
+        // The nearest rabbit keels over within a few ticks, and only that one
+        write(&mut state, "page1:rabbits");
+        assert_eq!(state.death_note.ink, DEATH_NOTE_INK - 1);
+        for _ in 0..3 {
+            state.update_death_note();
         }
+        assert_eq!(state.wildlife.len(), 1);
+        assert_eq!(state.wildlife[0].position, far);
+        let placed = &state.objects.placed;
+        let corpse = placed.iter().find(|p| p.id.starts_with("corpse-rabbit"));
+        assert_eq!(corpse.map(|p| p.position), Some(near));
+        let entry = state.journal.last().unwrap();
+        assert!(entry.contains("dropped where it stood"));
+
+        // Its owner's name won't take, costs no ink, and the achievement comes once
+        state.player.name = Some("Robin".to_string());
+        let refused = write(&mut state, "page2:Robin");
+        assert!(refused.contains("Achievement unlocked"), "{}", refused);
+        assert!(state.death_note.refusal_achievement);
+        let again = write(&mut state, "page2:robin");
+        assert!(again.contains("won't sink in") && !again.contains("Achievement"));
+        assert_eq!(state.death_note.ink, DEATH_NOTE_INK - 1);
+        assert!(state.death_note.pending.is_empty());
     }
 
-    pub fn set_custom_name(&mut self, item: Item, name: &str) {
-        let trimmed = name.trim();
-        if trimmed.is_empty() {
-            self.custom_names.remove(&item);
-            return;
+    #[test]
+    fn one_time_seeds_stay_used_up_across_reloads() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        assert!(state.objects.find("starter_pig").is_some());
+        let kettle_in_cabin = |state: &GameState| {
+            let cabin = state.cabin_state().unwrap();
+            cabin.items.count(&Item::Kettle)
+        };
+        assert_eq!(kettle_in_cabin(&state), 1);
+
+        // Butcher the pig away and carry the kettle off, then reload twice
+        state.objects.remove("starter_pig");
+        state.cabin_state_mut().unwrap().items.take(&Item::Kettle);
+        state.player.inventory.add(Item::Kettle, 1);
+        let name = format!("rubber-duck-seeds-{}.json", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        for _ in 0..2 {
+            state.save(&path).unwrap();
+            state = GameState::load_or_new(&path, &map);
         }
-        let capped = trimmed.chars().take(32).collect::<String>();
-        self.custom_names.insert(item, capped);
+        let _ = std::fs::remove_file(&path);
+        assert!(state.objects.find("starter_pig").is_none());
+        assert_eq!(kettle_in_cabin(&state), 0);
+        assert_eq!(state.player.inventory.count(&Item::Kettle), 1);
+
+        // A scenario that skips a seed still gets the others on first setup
+        state.seeded = HashSet::from([Seed::StarterPig]);
+        state.bootstrap_structures(&map);
+        assert!(state.objects.find("starter_pig").is_none());
+        assert_eq!(kettle_in_cabin(&state), 1);
+        assert!(state.seeded.contains(&Seed::TeaThings));
     }
 
-    pub fn custom_name(&self, item: &Item) -> Option<&str> {
-        self.custom_names.get(item).map(|s| s.as_str())
+    #[test]
+    fn the_tutorial_bundle_comes_once_by_reading_or_by_doing() {
+        let mut map = WorldMap::new();
+        let apples = |state: &GameState, map: &WorldMap| {
+            let tile = map.tile_at(&state.player.position).unwrap();
+            tile.items.count(&Item::Apple) + state.cabin_state().unwrap().items.count(&Item::Apple)
+        };
+        let finish_book = |state: &mut GameState| {
+            let pages = state.books[TUTORIAL_BOOK_ID].pages.len();
+            state.set_book_page(TUTORIAL_BOOK_ID, pages);
+        };
+
+        // Read to the last page in the cabin: the full bundle, and only once
+        let mut state = GameState::new(&map);
+        state.player.room = Some(Room::CabinMain);
+        let before = apples(&state, &map);
+        finish_book(&mut state);
+        state.grant_tutorial_reward_if_needed(&mut map);
+        assert_eq!(apples(&state, &map), before + 10);
+        assert!(state.cabin_state().unwrap().items.contains(&Item::Knife));
+        state.note_practice(Practice::LitFire);
+        state.note_practice(Practice::CookedFish);
+        state.note_practice(Practice::FelledTree);
+        state.grant_tutorial_reward_if_needed(&mut map);
+        assert_eq!(apples(&state, &map), before + 10);
+        assert!(!state.tutorial_by_practice);
+
+        // Never opened, but all three done by hand: the smaller bundle
+        let mut state = GameState::new(&map);
+        state.player.exit_room();
+        let before = apples(&state, &map);
+        state.note_practice(Practice::LitFire);
+        state.note_practice(Practice::CookedFish);
+        state.grant_tutorial_reward_if_needed(&mut map);
+        assert_eq!(apples(&state, &map), before);
+        state.note_practice(Practice::FelledTree);
+        state.grant_tutorial_reward_if_needed(&mut map);
+        assert_eq!(apples(&state, &map), before + 5);
+        assert!(state.tutorial_by_practice);
+        let grudging = state.pending_messages.last().unwrap();
+        assert!(grudging.contains("You never opened the book"));
+
+        // The threat no longer comes, and reading it after changes nothing
+        state.player.room = Some(Room::CabinMain);
+        state.pending_messages.clear();
+        state.maybe_trigger_tutorial_hint();
+        assert!(state.pending_messages.is_empty());
+        finish_book(&mut state);
+        state.player.exit_room();
+        state.grant_tutorial_reward_if_needed(&mut map);
+        assert_eq!(apples(&state, &map), before + 5);
     }
 
-    pub fn display_name(&self, item: &Item) -> String {
-        self.custom_name(item)
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| item.name().to_string())
+    #[test]
+    fn wildfire_spreads_chars_trees_holds_over_a_reload_and_can_be_put_out() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let mut rng = StdRng::seed_from_u64(11);
+        let (w, clear) = (&mut state.weather, Weather::Clear);
+        (w.north, w.south, w.east, w.west) = (clear, clear, clear, clear);
+        state.weather.wind.strength = WindStrength::Gale;
+        state.player.room = Some(Room::CabinMain);
+        let neighbours = |p: &Position| {
+            [Direction::North, Direction::South]
+                .into_iter()
+                .chain([Direction::East, Direction::West])
+                .map(|d| p.move_in_direction(d))
+                .collect::<Vec<_>>()
+        };
+        let tree = |state: &GameState, p: &Position| {
+            let objects = state.objects.objects_at(p);
+            objects.iter().find_map(|o| o.object.as_tree()).cloned()
+        };
+        let pos = map
+            .positions()
+            .filter(|p| state.ground_burns(&map, p))
+            .filter(|p| neighbours(p).iter().all(|n| state.ground_burns(&map, n)))
+            .find(|p| tree(&state, p).is_some())
+            .unwrap();
+
+        // Dry ground catches sooner or later; rain never lets it
+        while !state.try_start_wildfire(&map, pos, &mut rng) {}
+        assert!(state.wildfire.is_burning(&pos));
+        assert_eq!(state.wildfire.origin, Some(pos));
+        assert!(!state.try_start_wildfire(&map, pos, &mut rng));
+
+        // It spreads to the next tile and chars the tree it started under
+        for _ in 0..BURN_TICKS - 1 {
+            state.update_wildfire(&map, &mut rng);
+        }
+        assert!(state.wildfire.burning.iter().any(|b| b.position != pos));
+        assert!(tree(&state, &pos).unwrap().is_charred());
+        assert!(state.wildfire.charred >= 1);
+
+        // Still burning after a save and reload
+        let burning = state.wildfire.burning.len();
+        let name = format!("rubber-duck-fire-{}.json", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        state.save(&path).unwrap();
+        state = GameState::load_or_new(&path, &map);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(state.wildfire.burning.len(), burning);
+        assert_eq!(state.wildfire.origin, Some(pos));
+
+        // Water puts out the burning tile underfoot and leaves it scorched
+        let near = state.wildfire.burning[0].position;
+        state.player.room = None;
+        state.player.position = near;
+        state.player.inventory.add(Item::CleanWater, 1);
+        let r = interaction::try_tend_fire("extinguish", &mut state);
+        assert!(matches!(r, InteractionResult::ActionSuccess { .. }));
+        assert!(!state.wildfire.is_burning(&near));
+        assert!(state.wildfire.scorched.contains(&near));
+        assert!(!state.try_start_wildfire(&map, near, &mut rng));
+
+        // Rain puts out the rest, and the journal says so
+        state.player.room = Some(Room::CabinMain);
+        let (w, rain) = (&mut state.weather, Weather::HeavyRain);
+        (w.north, w.south, w.east, w.west) = (rain, rain, rain, rain);
+        let before = state.wildfire.burning.clone();
+        state.update_wildfire(&map, &mut rng);
+        assert!(state.wildfire.burning.is_empty());
+        let scorched = &state.wildfire.scorched;
+        assert!(before.iter().all(|b| scorched.contains(&b.position)));
+        assert_eq!(state.wildfire.origin, None);
+        let last = state.journal.last().unwrap();
+        assert!(last.starts_with("The weather put out the fire"));
     }
 
-    pub fn name_companion(&mut self, target: &str, new_name: &str) -> Result<String, String> {
-        let norm = target.to_lowercase();
-        let pos = self.player.position;
-        let mut best_idx: Option<usize> = None;
-        let mut best_dist = f32::MAX;
+    #[test]
+    fn a_distant_sighting_opens_a_field_note_and_a_close_look_fills_it_once() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let rabbit = Species::Rabbit;
+        let detail = |state: &GameState| {
+            let note = state.field_notes.iter().find(|n| n.species == rabbit);
+            note.map(|n| n.detail)
+        };
+        let pages = |state: &GameState| {
+            let id = state.field_notes_id.clone().unwrap();
+            state.books.get(&id).unwrap().pages.clone()
+        };
 
-        for (idx, w) in self.wildlife.iter().enumerate() {
-            if !w.tamed {
-                continue;
-            }
-            let species_name = w.species.name().to_lowercase();
-            if !species_name.contains(&norm) && !norm.contains(&species_name) {
-                continue;
-            }
-            let dist = pos.distance_to(&w.position);
-            if dist <= 6.0 && dist < best_dist {
-                best_dist = dist;
-                best_idx = Some(idx);
-            }
+        // Seen far off: a stub page, in a book the player now carries
+        state.record_sightings(&[(rabbit, 6.0)]);
+        assert_eq!(detail(&state), Some(SightingDetail::Glimpsed));
+        let id = state.field_notes_id.clone().unwrap();
+        assert!(state.player_has_book(&id));
+        assert!(pages(&state)[1].contains("Glimpsed from a distance"));
+        assert!(!state.record_sighting(rabbit, 8.0));
+
+        // Up close, the page fills out exactly once
+        state.pending_messages.clear();
+        assert!(state.record_sighting(rabbit, 1.0));
+        assert!(!state.record_sighting(rabbit, 0.5));
+        assert!(!state.record_sighting(rabbit, 2.0));
+        assert_eq!(detail(&state), Some(SightingDetail::Studied));
+        let added = state
+            .pending_messages
+            .iter()
+            .filter(|m| m.contains("You add what you saw of the"))
+            .count();
+        assert_eq!(added, 1);
+        assert!(pages(&state)[1].contains(rabbit.close_detail()));
+        assert_eq!(pages(&state).len(), 2);
+
+        // The notes and their book come back after a reload
+        let name = format!("rubber-duck-notes-{}.json", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        state.save(&path).unwrap();
+        state = GameState::load_or_new(&path, &map);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(detail(&state), Some(SightingDetail::Studied));
+        assert_eq!(state.field_notes_id, Some(id));
+        assert!(!state.record_sighting(rabbit, 1.0));
+
+        // Every species noted makes a naturalist, once
+        for species in Species::FIELD_GUIDE {
+            state.record_sighting(species, 6.0);
+        }
+        assert!(state.player.naturalist);
+        let sharper = |state: &GameState| {
+            let line = "Every animal in the valley";
+            state.journal.iter().filter(|j| j.contains(line)).count()
+        };
+        assert_eq!(sharper(&state), 1);
+        for species in Species::FIELD_GUIDE {
+            state.record_sighting(species, 1.0);
         }
+        assert_eq!(sharper(&state), 1);
+    }
 
-        let idx = best_idx.ok_or_else(|| {
-            "You don't have a tamed companion like that nearby.".to_string()
-        })?;
+    #[test]
+    fn fishing_spots_are_told_apart_by_the_water_around_the_cast() {
+        let map = WorldMap::new();
+        let near = |p: &Position, biome: Biome| {
+            (-1..=1).any(|dr| {
+                (-1..=1).any(|dc| {
+                    let q = Position::new(p.row + dr, p.col + dc);
+                    map.biome_at(&q) == Some(biome)
+                })
+            })
+        };
+        let shore = |biome: Biome, water: Biome| {
+            map.positions()
+                .filter(|p| map.can_stand_at(p) && map.biome_at(p) == Some(biome))
+                .filter(|p| near(p, water))
+                .find(|p| water == Biome::Oasis || !near(p, Biome::Oasis))
+                .unwrap()
+        };
+        let spot = |p: Position, afloat: bool, temperature: f32| {
+            FishingSpot::at(&map, &p, afloat, temperature)
+        };
 
-        let trimmed = new_name.trim();
-        if trimmed.is_empty() {
-            return Err("Please provide a non-empty name.".to_string());
-        }
-        let capped = trimmed.chars().take(32).collect::<String>();
+        // From the bank
+        let shallows = shore(Biome::SpringForest, Biome::Lake);
+        assert_eq!(spot(shallows, false, 12.0), Some(FishingSpot::Shallows));
+        assert_eq!(spot(shallows, false, -4.0), Some(FishingSpot::IceHole));
+        let snowy = shore(Biome::WinterForest, Biome::Lake);
+        assert_eq!(spot(snowy, false, 12.0), Some(FishingSpot::IceHole));
+        let pool = shore(Biome::Desert, Biome::Oasis);
+        assert_eq!(spot(pool, false, 30.0), Some(FishingSpot::Oasis));
+        let dry = map
+            .positions()
+            .find(|p| !near(p, Biome::Lake) && !near(p, Biome::Oasis))
+            .unwrap();
+        assert_eq!(spot(dry, false, 12.0), None);
+
+        // From the raft: open water all round is deep, close in is still shallow
+        let lake = |p: &Position| map.tile_at(p).map(|t| t.tile_type) == Some(TileType::Lake);
+        let open = map
+            .positions()
+            .find(|p| {
+                (-1..=1).all(|dr| (-1..=1).all(|dc| lake(&Position::new(p.row + dr, p.col + dc))))
+            })
+            .unwrap();
+        assert_eq!(spot(open, true, 12.0), Some(FishingSpot::DeepWater));
+        let moored = map
+            .positions()
+            .filter(|p| lake(p) && !near(p, Biome::Oasis))
+            .find(|p| near(p, Biome::SpringForest))
+            .unwrap();
+        assert_eq!(spot(moored, true, 12.0), Some(FishingSpot::Shallows));
+        assert_eq!(spot(moored, true, -4.0), Some(FishingSpot::Shallows));
+
+        // The catch names the spot it came from
+        let mut state = GameState::new(&map);
+        state.player.room = None;
+        state.player.position = pool;
+        let phrase = capitalize(FishingSpot::Oasis.phrase());
+        let landed = (0..200).any(|_| {
+            state.player.energy = 100.0;
+            state.player.inventory = Inventory::new();
+            let r = interaction::try_fish(&mut state, &map, Some("hands"));
+            matches!(r, InteractionResult::ActionSuccess { message, .. } if message.starts_with(&phrase))
+        });
+        assert!(landed);
+    }
 
-        if let Some(w) = self.wildlife.get_mut(idx) {
-            w.name = Some(capped.clone());
-            let species_name = w.species.name();
-            return Ok(format!("You name your {} '{}'.", species_name, capped));
-        }
+    #[test]
+    fn a_stripped_patch_grows_back_thin_and_slow_while_a_spared_one_recovers_fast() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.player.room = None;
+        let pos = state.player.position;
+        let biome = Biome::MixedForest;
+        let fresh = ForageNode::new(biome, state.world_seed, pos);
+        let full = fresh.capacity;
+        assert!(full >= 4);
+        let picked = |times: u8| {
+            let mut node = fresh.clone();
+            (0..times).for_each(|_| node.harvest());
+            node
+        };
+        // Ten-minute steps until the node is back to full, and the most it
+        // held before its scar healed
+        let recover = |mut node: ForageNode| {
+            let (mut steps, mut while_scarred) = (0, 0);
+            while node.charges < full && steps < 2000 {
+                node.tick(biome, state.world_seed, pos, 1.0);
+                steps += 1;
+                if node.scarred > 0.0 {
+                    while_scarred = while_scarred.max(node.charges);
+                }
+            }
+            (steps, while_scarred)
+        };
 
-        Err("Something went wrong while naming that companion.".to_string())
+        let spared = picked(full / 2);
+        assert!(spared.spared() && spared.scarred == 0.0);
+        let stripped = picked(full);
+        assert_eq!(stripped.charges, 0);
+        assert!(!stripped.spared() && stripped.scarred > 0.0);
+        let mut tended = stripped.clone();
+        assert!(tended.tend());
+        assert!(!tended.tend());
+        assert!(!fresh.clone().tend());
+
+        let (spared_steps, _) = recover(spared);
+        let (stripped_steps, thin) = recover(stripped.clone());
+        let (tended_steps, _) = recover(tended.clone());
+        assert!(spared_steps < 100, "{}", spared_steps);
+        assert!(stripped_steps > FORAGE_SCAR_STEPS as u32);
+        assert_eq!(thin, full.div_ceil(2));
+        assert!(tended_steps < stripped_steps);
+        assert!(tended_steps > spared_steps);
+
+        // What shows on the ground, and that the scar and tending are kept
+        let mut node = stripped;
+        (0..20).for_each(|_| node.tick(biome, state.world_seed, pos, 1.0));
+        state.forage_nodes.insert(pos, node);
+        let hint = state.forage_hint(&map).unwrap();
+        assert!(hint.contains("coming back thin"), "{}", hint);
+        state.forage_nodes.insert(pos, tended);
+        let name = format!("rubber-duck-forage-{}.json", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        state.save(&path).unwrap();
+        state = GameState::load_or_new(&path, &map);
+        let _ = std::fs::remove_file(&path);
+        let node = state.forage_node_at(&pos).unwrap();
+        assert!(node.scarred > 0.0 && node.tended > 0.0);
+        let hint = state.forage_hint(&map).unwrap();
+        assert!(hint.contains("dark where you tended it"), "{}", hint);
+
+        let mut sprouting = picked(2);
+        (0..12).for_each(|_| sprouting.tick(biome, state.world_seed, pos, 1.0));
+        state.forage_nodes.insert(pos, sprouting);
+        let hint = state.forage_hint(&map).unwrap();
+        assert!(hint.contains("New shoots are pushing up"), "{}", hint);
     }
 
-    pub fn player_can_access_item(&self, item: &Item) -> bool {
-        if self.player.inventory.has(item, 1) {
-            return true;
+    #[test]
+    fn a_used_tool_comes_into_hand_and_a_held_one_can_be_lost_swimming() {
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.player.room = None;
+        state.player.inventory = Inventory::new();
+        for item in [Item::Knife, Item::Axe, Item::Shovel, Item::Stick] {
+            state.player.inventory.add(item, 1);
         }
-        if matches!(self.player.room, Some(Room::CabinMain)) {
-            let in_cabin = self
-                .cabin_state()
-                .map(|c| c.items.contains(item) || c.table_items.contains(item))
-                .unwrap_or(false);
-            let on_table = self
-                .table_surface()
-                .map(|s| s.items.contains(item))
-                .unwrap_or(false);
-            if in_cabin || on_table {
-                return true;
+        assert!(state.player.hands.is_empty());
+
+        // Using a tool takes it up; a third pushes the oldest back in the pack
+        interaction::try_use("knife", Some("stick"), &mut state, &mut map);
+        assert_eq!(state.player.hands.main, Some(Item::Knife));
+        let me = interaction::examine("self", &state, &map);
+        assert!(me.contains("You have the knife in hand."), "{}", me);
+        interaction::try_use("axe", Some("tree"), &mut state, &mut map);
+        let hands = &state.player.hands;
+        assert_eq!(hands.main, Some(Item::Axe));
+        assert_eq!(hands.off, Some(Item::Knife));
+        interaction::try_use("shovel", None, &mut state, &mut map);
+        let hands = &state.player.hands;
+        assert_eq!(hands.main, Some(Item::Shovel));
+        assert_eq!(hands.off, Some(Item::Axe));
+        assert!(state.player.inventory.has(&Item::Knife, 1));
+
+        // What's in hand is kept over a reload
+        let name = format!("rubber-duck-hands-{}.json", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        state.save(&path).unwrap();
+        state = GameState::load_or_new(&path, &map);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(state.in_hand().unwrap(), "the wooden shovel and the axe");
+
+        // A swim for shore can take what's in hand, never what's stowed
+        let (mut lost, mut kept) = (0, 0);
+        for _ in 0..40 {
+            state.player.inventory = Inventory::new();
+            state.player.inventory.add(Item::Knife, 1);
+            state.player.inventory.add(Item::Axe, 1);
+            state.player.hands = Hands::default();
+            state.player.hands.take_up(Item::Axe);
+            let message = state.wreck_raft(&mut map);
+            let pack = &state.player.inventory;
+            assert!(pack.has(&Item::Knife, 1));
+            if pack.has(&Item::Axe, 1) {
+                assert!(state.player.hands.holds(&Item::Axe));
+                kept += 1;
+            } else {
+                assert!(state.player.hands.is_empty());
+                assert!(message.contains("The axe is torn from your grip"));
+                lost += 1;
             }
         }
-        false
+        assert!(lost > 0 && kept > 0, "lost {}, kept {}", lost, kept);
     }
-}
 
-impl Default for GameState {
-    fn default() -> Self {
-        Self::new(&WorldMap::new())
+    #[test]
+    fn the_ancient_map_charts_its_landmarks_and_confirms_ground_held_up_nearby() {
+        let map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        state.player.inventory.add(Item::AncientMap, 1);
+        let landmarks = state.map_landmarks(&map);
+        let mirror = landmarks[0].pos;
+
+        // Each sitting charts one more landmark, none of it counted as walked
+        let text = state.study_ancient_map(&map);
+        assert!(text.contains("'The Mirror'"));
+        assert!(text.contains("Matched so far: The Mirror is the lake."));
+        let charted = &state.player.charted;
+        assert!(charted.iter().all(|p| p.distance_to(&mirror) <= 5.0));
+        assert!(charted.len() > 20);
+        assert!(charted.is_disjoint(&state.player.visited));
+        for _ in 1..landmarks.len() {
+            state.study_ancient_map(&map);
+        }
+        assert_eq!(state.map_studies as usize, landmarks.len());
+        assert!(state.ruin().unwrap().discovered);
+        let charted = state.player.charted.len();
+        let text = state.study_ancient_map(&map);
+        assert!(text.contains("nothing new to tell you"));
+        assert_eq!(state.player.charted.len(), charted);
+
+        // On charted ground by the lake, but the map still in the pack
+        let spot = *state
+            .player
+            .charted
+            .iter()
+            .filter(|p| map.can_stand_at(p))
+            .find(|p| p.distance_to(&mirror) <= 4.0)
+            .unwrap();
+        state.player.room = None;
+        state.player.position = spot;
+        state.player.hands = Hands::default();
+        state.study_ancient_map(&map);
+        assert!(state.player.charted.contains(&spot));
+
+        // Held up, it turns the ground close by into ground as good as walked
+        state.player.hands.take_up(Item::AncientMap);
+        let text = state.study_ancient_map(&map);
+        assert!(text.contains("as familiar now as if you'd walked it"));
+        assert!(state.player.visited.contains(&spot));
+        let left = &state.player.charted;
+        assert!(left.iter().all(|p| p.distance_to(&spot) > 2.0));
+        assert!(left.len() < charted);
+
+        // Charted and visited ground are kept apart over a reload
+        let name = format!("rubber-duck-charted-{}.json", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        let before = (state.player.charted.clone(), state.player.visited.clone());
+        state.save(&path).unwrap();
+        state = GameState::load_or_new(&path, &map);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(state.player.charted, before.0);
+        assert_eq!(state.player.visited, before.1);
     }
-}
-
-/// Full world context including map (which isn't saved)
-pub struct World {
-    pub map: WorldMap,
-    pub state: GameState,
-    pub state_path: std::path::PathBuf,
-}
 
-impl World {
-    pub fn new(state_path: std::path::PathBuf) -> Self {
+    #[test]
+    fn grilled_fish_comes_off_underdone_just_right_or_charred_by_when_its_pulled() {
         let map = WorldMap::new();
-        let state = GameState::load_or_new(&state_path, &map);
-        Self {
-            map,
-            state,
-            state_path,
+        let mut rng = StdRng::seed_from_u64(22);
+        let grill = |fire: FireState, cooking: u8, ticks: u32, rng: &mut StdRng| {
+            let mut state = GameState::new(&map);
+            state.player.room = Some(Room::CabinMain);
+            state.cabin_state_mut().unwrap().fireplace.state = fire;
+            while state.player.skills.get(Skill::Cooking) < cooking {
+                state.player.skills.improve(Skill::Cooking, 255);
+            }
+            state.player.inventory.add(Item::Fish, 1);
+            let mut map = map.clone();
+            interaction::try_use("fish", Some("fire"), &mut state, &mut map);
+            assert!(state.on_the_fire.is_some());
+            for _ in 0..ticks {
+                state.update_cooking(rng);
+            }
+            state
+        };
+        let pull = |state: &mut GameState| match interaction::try_take_off_fire("fish", state) {
+            Some(InteractionResult::ActionSuccess { message, .. }) => message,
+            _ => panic!("nothing came off the fire"),
+        };
+        let eat = |state: &mut GameState| {
+            state.player.fullness = 20.0;
+            let mut messages = Vec::new();
+            if let InteractionResult::ActionSuccess { message, .. } =
+                interaction::try_consume("cooked fish", None, false, state)
+            {
+                messages.push(message);
+            }
+            (state.player.fullness - 20.0, messages.concat())
+        };
+
+        // Pulled after one tick over a steady fire, it's still raw inside
+        let mut early = grill(FireState::Burning, 0, 1, &mut rng);
+        assert!(early.pending_messages.is_empty());
+        assert!(pull(&mut early).contains("too soon"));
+        let (under, text) = eat(&mut early);
+        assert!(text.contains("underdone") || text.contains("stomach turns"));
+
+        // At two the smell says it's ready, once, and it eats best
+        let mut right = grill(FireState::Burning, 0, 2, &mut rng);
+        let cues = |state: &GameState| {
+            let messages = state.pending_messages.iter();
+            messages.filter(|m| m.contains("about ready")).count()
+        };
+        assert_eq!(cues(&right), 1);
+        right.update_cooking(&mut rng);
+        assert_eq!(cues(&right), 1);
+        assert!(pull(&mut right).contains("just the right moment"));
+        let (perfect, text) = eat(&mut right);
+        assert!(text.contains("Cooked just right"), "{}", text);
+
+        // Left two more, it scorches and is pulled blackened
+        let mut late = grill(FireState::Burning, 0, 4, &mut rng);
+        let scorch = |m: &String| m.contains("starting to char");
+        assert!(late.pending_messages.iter().any(scorch));
+        assert!(pull(&mut late).contains("blackened"));
+        let (charred, text) = eat(&mut late);
+        assert!(text.contains("char is bitter"), "{}", text);
+        assert!(under < charred && charred < perfect);
+
+        // A roaring fire passes the moment sooner; a practiced cook has longer
+        let roaring = grill(FireState::Roaring, 0, 3, &mut rng);
+        let burning = grill(FireState::Burning, 0, 3, &mut rng);
+        let fire = |state: &GameState| state.on_the_fire.as_ref().unwrap().doneness();
+        assert_eq!(fire(&roaring), Doneness::Charred);
+        assert_eq!(fire(&burning), Doneness::Perfect);
+        let practiced = grill(FireState::Roaring, 80, 3, &mut rng);
+        assert_eq!(fire(&practiced), Doneness::Perfect);
+
+        // What's grilling and what's been told about it survive a reload
+        let name = format!("rubber-duck-grill-{}.json", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        right = grill(FireState::Burning, 0, 2, &mut rng);
+        right.save(&path).unwrap();
+        let mut right = GameState::load_or_new(&path, &map);
+        let _ = std::fs::remove_file(&path);
+        let food = right.on_the_fire.as_ref().unwrap();
+        assert_eq!((food.heat, food.cued), (2.0, true));
+        assert!(right.cooking_note().unwrap().contains("smells ready"));
+
+        // Walked away from, it's left to char and then burns away to nothing
+        right.player.room = None;
+        let mut ticks = 0;
+        while right.on_the_fire.is_some() && ticks < 40 {
+            right.update_cooking(&mut rng);
+            ticks += 1;
+        }
+        assert!(right.on_the_fire.is_none());
+        assert!(ticks > 4);
+        let burned = right.pending_messages.last().unwrap();
+        assert!(burned.contains("burned away to charcoal"), "{}", burned);
+    }
+
+    #[test]
+    fn a_fox_raids_fish_left_in_the_open_far_oftener_than_fish_in_the_shed() {
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let mut rng = StdRng::seed_from_u64(25);
+        state.time.hour = 23;
+        state.player.room = Some(Room::CabinMain);
+        let bench = state.objects.find(WORKBENCH_ID).unwrap().position;
+        let fox = Position::new(bench.row + 2, bench.col);
+        state.wildlife = vec![Wildlife::new(Species::Fox, fox)];
+        // Nothing else to eat lying about within the fox's reach
+        for pos in map.positions().filter(|p| fox.distance_to(p) <= 8.0) {
+            if let Some(tile) = map.tile_at_mut(&pos) {
+                for item in tile.items.list().into_iter().copied().collect::<Vec<_>>() {
+                    if item.is_food() {
+                        while tile.items.take(&item) {}
+                    }
+                }
+            }
+        }
+        let raids = |state: &mut GameState, map: &mut WorldMap, rng: &mut StdRng| {
+            let mut raids = 0;
+            for _ in 0..3000 {
+                state.update_fox_raids(map, rng);
+                if !state.raid_tracks.is_empty() {
+                    raids += 1;
+                    state.raid_tracks.clear();
+                }
+            }
+            raids
+        };
+
+        // A fish left on the ground a step from the fox
+        let cache = map
+            .positions()
+            .find(|p| map.can_stand_at(p) && fox.distance_to(p) <= 2.0 && *p != bench)
+            .unwrap();
+        map.tile_at_mut(&cache).unwrap().items.add(Item::Fish, 3000);
+        let open = raids(&mut state, &mut map, &mut rng);
+        let left = map.tile_at(&cache).unwrap().items.count(&Item::Fish);
+        assert_eq!(3000 - left, open);
+        while map.tile_at_mut(&cache).unwrap().items.take(&Item::Fish) {}
+
+        // The same fish on the workbench behind a shut shed door
+        state.wood_shed_state_mut().unwrap().door_open = false;
+        let surface = state.surface_mut(WORKBENCH_ID).unwrap();
+        surface.items.add(Item::Fish, 3000);
+        let shut = raids(&mut state, &mut map, &mut rng);
+        state.wood_shed_state_mut().unwrap().door_open = true;
+        let ajar = raids(&mut state, &mut map, &mut rng);
+        assert!(open > 40, "{} raids in the open", open);
+        assert!(shut * 4 < open, "{} shut against {} open", shut, open);
+        assert!(shut < ajar && ajar < open, "{} < {} < {}", shut, ajar, open);
+
+        // Nobody raids by day, nor with the player standing by the bench
+        state.time.hour = 12;
+        assert_eq!(raids(&mut state, &mut map, &mut rng), 0);
+        state.time.hour = 23;
+        (state.player.room, state.player.position) = (None, bench);
+        assert_eq!(raids(&mut state, &mut map, &mut rng), 0);
+
+        // The tracks are read where the fish went, then go cold
+        state.player.position = Position::new(fox.row + 10, fox.col);
+        map.tile_at_mut(&cache).unwrap().items.add(Item::Fish, 1);
+        while state.raid_tracks.is_empty() {
+            state.update_fox_raids(&mut map, &mut rng);
         }
+        state.player.position = cache;
+        let note = state.camp_wildlife_note().unwrap_or_default();
+        assert!(note.contains("tracks circle the spot"), "{}", note);
+        assert!(note.contains("fish"), "{}", note);
+        state.time.day += RAID_TRACKS_DAYS;
+        state.compact(&map);
+        assert!(state.raid_tracks.is_empty());
     }
 
-    pub fn save(&self) -> Result<()> {
-        self.state.save(&self.state_path)
+    #[test]
+    fn an_apple_tree_sets_ripens_stands_bare_and_blossoms_across_a_year() {
+        use crate::actions::crafting::{self, CraftResult};
+        struct Seen {
+            season: Season,
+            stage: FruitStage,
+            most: u8,
+            look: String,
+            shaken: u32,
+            kicks: String,
+        }
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let mut rng = StdRng::seed_from_u64(28);
+        state.player.room = None;
+        state.player.inside = None;
+        let spot = map
+            .positions()
+            .find(|p| map.can_stand_at(p) && state.objects.objects_at(p).is_empty())
+            .unwrap();
+        state.player.position = spot;
+        let mut apple = Tree::new(spot, TreeType::Apple);
+        apple.set_season(state.time.season());
+        let apple = WorldObject::new(ObjectKind::Tree(apple));
+        state.objects.add("tracked-apple", spot, apple);
+        let tree = |state: &GameState| state.objects.find_tree_at(&spot).unwrap().clone();
+
+        // A day of growth at a time, with a look and a round of kicks at
+        // each season's end
+        let mut year = Vec::new();
+        for day in 1..=4 * WorldTime::DAYS_PER_SEASON {
+            state.time.day = day;
+            let mut most = 0;
+            for _ in 0..30 {
+                state.update_trees(&map, &mut rng);
+                most = most.max(tree(&state).fruit_count);
+            }
+            if state.time.day_of_season() < WorldTime::DAYS_PER_SEASON {
+                continue;
+            }
+            let look = interaction::examine("tree", &state, &map);
+            let (mut shaken, mut kicks) = (0, String::new());
+            for _ in 0..40 {
+                let full = state.objects.find_tree_mut_at(&spot).unwrap();
+                full.fruit_count = full.fruit_cap();
+                let before = full.fruit_count;
+                if let CraftResult::Success(text) = crafting::kick_tree(&mut state, &mut map) {
+                    kicks.push_str(&text);
+                }
+                shaken += u32::from(before - tree(&state).fruit_count);
+            }
+            let season = state.time.season();
+            let stage = tree(&state).fruit_stage;
+            year.push(Seen {
+                season,
+                stage,
+                most,
+                look,
+                shaken,
+                kicks,
+            });
+        }
+        let [summer, autumn, winter, spring] = &year[..] else {
+            panic!("{} seasons went by", year.len());
+        };
+
+        // Summer sets hard green fruit, half a crop that barely shakes loose
+        assert_eq!(summer.season, Season::Summer);
+        assert_eq!((summer.stage, summer.most), (FruitStage::Setting, 3));
+        assert!(summer.look.contains("green apples"), "{}", summer.look);
+        assert!(summer.shaken > 0 && summer.shaken <= 40);
+        assert!(summer.kicks.contains("not ready yet"));
+
+        // Autumn ripens the full crop, and a kick brings plenty down
+        assert_eq!(autumn.season, Season::Autumn);
+        assert_eq!((autumn.stage, autumn.most), (FruitStage::Ripe, 6));
+        assert!(autumn.look.contains("ripened red"), "{}", autumn.look);
+        assert!(autumn.shaken > summer.shaken * 2);
+
+        // Winter strips it, and spring brings blossom but nothing to eat
+        assert_eq!(winter.season, Season::Winter);
+        assert_eq!((winter.stage, winter.most), (FruitStage::Bare, 0));
+        assert!(winter.look.contains("stand bare"), "{}", winter.look);
+        assert_eq!(winter.shaken, 0);
+        assert_eq!(spring.season, Season::Spring);
+        assert_eq!((spring.stage, spring.most), (FruitStage::Blossom, 0));
+        assert!(spring.look.contains("blossom crowds"), "{}", spring.look);
+        assert_eq!(spring.shaken, 0);
+        assert!(spring.kicks.contains("Blossom drifts down"));
+
+        // A tree from an older save is brought in line with the calendar
+        state.time.day = 1;
+        let old = state.objects.find_tree_mut_at(&spot).unwrap();
+        (old.fruit_stage, old.fruit_count) = (FruitStage::Bare, 5);
+        state.compact(&map);
+        let caught_up = tree(&state);
+        assert_eq!(caught_up.fruit_stage, FruitStage::Setting);
+        assert_eq!(caught_up.fruit_count, 3);
     }
 
-    pub fn tick(&mut self) {
-        self.state.tick_with_map(&self.map);
+    #[test]
+    fn reseeded_trees_keep_off_the_player_their_things_and_the_cabin_clearing() {
+        let mut map = WorldMap::new();
+        let mut state = GameState::new(&map);
+        let is_tree = |p: &PlacedObject| matches!(p.object.kind, ObjectKind::Tree(_));
+        state.objects.placed.retain(|p| !is_tree(p));
+        state.player.room = None;
+        state.player.inside = None;
+
+        // A camp in an open clearing away from the cabin, with a walked path
+        let cabin = state.objects.find("cabin").unwrap().position;
+        let forest = |p: &Position| {
+            let tile = map.tile_at(p).unwrap();
+            matches!(tile.tile_type, TileType::Forest(b) if b != Biome::Desert) && tile.walkable
+        };
+        let roomy = |p: &Position| {
+            let corners = [(-3, -3), (-3, 3), (3, -3), (3, 3)];
+            corners
+                .iter()
+                .all(|(r, c)| map.contains(&Position::new(p.row + r, p.col + c)))
+        };
+        let camp = map
+            .positions()
+            .find(|p| cabin.distance_to(p) > 8.0 && roomy(p) && forest(p))
+            .unwrap();
+        for col in camp.col - 3..=camp.col + 3 {
+            state.player.position = Position::new(camp.row + 3, col);
+            state.player.mark_visited();
+        }
+        state.player.position = camp;
+        let stash = Position::new(camp.row - 2, camp.col + 2);
+        map.tile_at_mut(&stash).unwrap().items.add(Item::Stick, 3);
+        let built = Position::new(camp.row - 2, camp.col - 2);
+        let wall = WorldObject::new(ObjectKind::Wall);
+        state.objects.add("wall-1", built, wall);
+
+        let placed = state.objects.placed.iter().map(|p| p.position);
+        let mut forbidden: HashSet<Position> = placed.collect();
+        forbidden.insert(stash);
+        for (centre, reach) in [(camp, 1), (cabin, STRUCTURE_CLEARANCE)] {
+            for r in -reach..=reach {
+                for c in -reach..=reach {
+                    forbidden.insert(Position::new(centre.row + r, centre.col + c));
+                }
+            }
+        }
+        let shed = state.objects.find("wood_shed").unwrap().position;
+        for r in -STRUCTURE_CLEARANCE..=STRUCTURE_CLEARANCE {
+            for c in -STRUCTURE_CLEARANCE..=STRUCTURE_CLEARANCE {
+                forbidden.insert(Position::new(shed.row + r, shed.col + c));
+            }
+        }
+
+        // Aggressive reseeding, over and over, never lands on any of it
+        let mut planted = 0;
+        for seed in 0..8 {
+            let mut state = state.clone();
+            let mut rng = StdRng::seed_from_u64(seed);
+            state.ensure_tree_density(&map, &mut rng);
+            state.seed_tree_population(&map, &mut rng, 400);
+            let trees: Vec<Position> = state
+                .objects
+                .placed
+                .iter()
+                .filter(|p| is_tree(p))
+                .map(|p| p.position)
+                .collect();
+            planted += trees.len();
+            for pos in &trees {
+                assert!(!forbidden.contains(pos), "a tree took root at {:?}", pos);
+                assert!(!state.player.recent_steps.contains(pos), "{:?}", pos);
+            }
+        }
+        assert!(planted > 8 * 100, "only {} trees planted", planted);
     }
 }
@@ -0,0 +1,252 @@
+use super::GameState;
+use crate::world::{Position, WorldMap};
+use serde::Serialize;
+use std::fmt;
+use std::path::Path;
+
+/// Everything the game knows about one tile, gathered from the map, the
+/// state and the object registry in one place. Backs the `debug_tile` tool
+/// and the web view's `/tile` endpoint.
+#[derive(Debug, Clone, Serialize)]
+pub struct TileReport {
+    pub row: i32,
+    pub col: i32,
+    pub region: String,
+    pub biome: String,
+    pub tile_type: String,
+    pub walkable: bool,
+    pub elevation: f32,
+    pub outcrop: bool,
+    /// Loose items on the ground.
+    pub ground_items: Vec<(String, u32)>,
+    /// Charges and cooldown of the forage node, once anyone has foraged here.
+    pub forage: Option<ForageReport>,
+    pub dune: bool,
+    pub burning: bool,
+    pub scorched: bool,
+    pub buried: Vec<(String, u32)>,
+    pub objects: Vec<ObjectReport>,
+    pub wildlife: Vec<String>,
+    /// Fox tracks by food taken from here, until they go cold.
+    pub tracks: Vec<TrackReport>,
+    pub player_here: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ForageReport {
+    pub charges: u8,
+    pub cooldown: u8,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrackReport {
+    pub species: String,
+    pub took: String,
+    pub day: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ObjectReport {
+    pub id: String,
+    pub name: String,
+    pub hidden: bool,
+    /// What the object holds in its own storage, for those that have one.
+    pub stored: Option<Vec<(String, u32)>>,
+}
+
+impl TileReport {
+    /// `None` if the position is off the map.
+    pub fn gather(map: &WorldMap, state: &GameState, pos: Position) -> Option<Self> {
+        let tile = map.tile_at(&pos)?;
+        let region = state
+            .region_names
+            .name(WorldMap::region_of(pos.row, pos.col))
+            .to_string();
+        let objects = state
+            .objects
+            .placed
+            .iter()
+            .filter(|po| po.position == pos)
+            .map(|po| ObjectReport {
+                id: po.id.clone(),
+                name: po.object.display_name(),
+                hidden: po.object.is_hidden(),
+                stored: po.object.storage.as_ref().map(|s| {
+                    s.items
+                        .items
+                        .iter()
+                        .map(|(item, qty)| (item.name().to_string(), *qty))
+                        .collect()
+                }),
+            })
+            .collect();
+        let wildlife = state
+            .wildlife
+            .iter()
+            .filter(|w| w.alive && w.position == pos)
+            .map(|w| match &w.name {
+                Some(name) => format!("{} ({})", w.species.name(), name),
+                None => w.species.name().to_string(),
+            })
+            .collect();
+        let buried = state
+            .sands
+            .buried
+            .iter()
+            .filter(|s| s.position == pos)
+            .flat_map(|s| s.items.iter())
+            .map(|(item, qty)| (item.name().to_string(), *qty))
+            .collect();
+        let tracks = state
+            .raid_tracks
+            .iter()
+            .filter(|t| t.position == pos)
+            .map(|t| TrackReport {
+                species: t.species.name().to_string(),
+                took: t.item.name().to_string(),
+                day: t.day,
+            })
+            .collect();
+
+        Some(Self {
+            row: pos.row,
+            col: pos.col,
+            region,
+            biome: format!("{:?}", tile.biome),
+            tile_type: format!("{:?}", tile.tile_type),
+            walkable: tile.walkable,
+            elevation: tile.elevation,
+            outcrop: tile.outcrop,
+            ground_items: tile
+                .items
+                .items
+                .iter()
+                .filter(|(_, qty)| *qty > 0)
+                .map(|(item, qty)| (item.name().to_string(), *qty))
+                .collect(),
+            forage: state.forage_nodes.get(&pos).map(|n| ForageReport {
+                charges: n.charges,
+                cooldown: n.cooldown,
+            }),
+            dune: state.sands.dunes.contains(&pos),
+            burning: state.wildfire.is_burning(&pos),
+            scorched: state.wildfire.scorched.contains(&pos),
+            buried,
+            objects,
+            wildlife,
+            tracks,
+            player_here: state.player.position == pos
+                && state.player.room.is_none()
+                && state.player.inside.is_none(),
+        })
+    }
+}
+
+fn item_list(items: &[(String, u32)]) -> String {
+    if items.is_empty() {
+        return "none".to_string();
+    }
+    items
+        .iter()
+        .map(|(name, qty)| format!("{} x{}", name, qty))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl fmt::Display for TileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Tile {},{} ({})", self.row, self.col, self.region)?;
+        writeln!(
+            f,
+            "- biome {}, {}, {}, elevation {:.1}{}",
+            self.biome,
+            self.tile_type,
+            if self.walkable { "walkable" } else { "not walkable" },
+            self.elevation,
+            if self.outcrop { ", outcrop" } else { "" }
+        )?;
+        writeln!(f, "- ground: {}", item_list(&self.ground_items))?;
+        match &self.forage {
+            Some(node) => writeln!(
+                f,
+                "- forage: {} charges, cooldown {}",
+                node.charges, node.cooldown
+            )?,
+            None => writeln!(f, "- forage: untouched")?,
+        }
+        if self.dune {
+            writeln!(f, "- under a dune")?;
+        }
+        if self.burning {
+            writeln!(f, "- on fire")?;
+        } else if self.scorched {
+            writeln!(f, "- scorched by fire")?;
+        }
+        writeln!(f, "- buried: {}", item_list(&self.buried))?;
+        if self.objects.is_empty() {
+            writeln!(f, "- objects: none")?;
+        } else {
+            let objects: Vec<String> = self
+                .objects
+                .iter()
+                .map(|o| {
+                    format!(
+                        "{} [{}]{}{}",
+                        o.name,
+                        o.id,
+                        if o.hidden { " (hidden)" } else { "" },
+                        match &o.stored {
+                            Some(items) => format!(" holding {}", item_list(items)),
+                            None => String::new(),
+                        }
+                    )
+                })
+                .collect();
+            writeln!(f, "- objects: {}", objects.join(", "))?;
+        }
+        if self.wildlife.is_empty() {
+            write!(f, "- wildlife: none")?;
+        } else {
+            write!(f, "- wildlife: {}", self.wildlife.join(", "))?;
+        }
+        for t in &self.tracks {
+            write!(
+                f,
+                "\n- {} tracks from day {}, where the {} was taken",
+                t.species, t.day, t.took
+            )?;
+        }
+        if self.player_here {
+            write!(f, "\n- the player is standing here")?;
+        }
+        Ok(())
+    }
+}
+
+/// `/tile?row=R&col=C` in world coordinates, for the web view: the status
+/// and the same report `debug_tile` gives, as JSON.
+pub fn tile_json(url: &str, state_path: &Path, map: &WorldMap) -> (u16, String) {
+    let query = url.split_once('?').map(|(_, q)| q).unwrap_or("");
+    let param = |key: &str| {
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(k, _)| *k == key)
+            .and_then(|(_, v)| v.parse::<i32>().ok())
+    };
+    let error = |msg: &str| serde_json::json!({ "error": msg }).to_string();
+    let (Some(row), Some(col)) = (param("row"), param("col")) else {
+        let hint = "give whole-number row and col, e.g. /tile?row=1&col=0";
+        return (400, error(hint));
+    };
+    let Ok(state) = GameState::load(state_path) else {
+        return (503, error("no saved state to read yet"));
+    };
+    match TileReport::gather(map, &state, Position::new(row, col)) {
+        Some(report) => (
+            200,
+            serde_json::to_string(&report).unwrap_or_else(|_| "{}".to_string()),
+        ),
+        None => (404, error("that tile is off the map")),
+    }
+}
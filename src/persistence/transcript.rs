@@ -0,0 +1,272 @@
+//! The session transcript: every tool call and the reply the client saw,
+//! one JSON line each, under `transcripts/` beside the save. Each session
+//! starts its own file stamped with when it began; a file that fills up
+//! rolls over to a fresh one, and only the newest few are kept.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Calls a transcript file holds before the next one is started.
+pub const MAX_ENTRIES: usize = 2000;
+/// Transcript files kept; older ones are removed as new ones begin.
+pub const TRANSCRIPTS_KEPT: usize = 10;
+
+/// One tool call as the client saw it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptEntry {
+    /// Seconds since the epoch when the reply went out.
+    pub time: u64,
+    /// The in-game clock at the reply.
+    #[serde(default)]
+    pub game_time: String,
+    pub tool: String,
+    #[serde(default)]
+    pub arguments: Option<Value>,
+    pub text: String,
+    #[serde(default)]
+    pub is_error: bool,
+}
+
+/// The file this session is writing to, opened with the first call.
+#[derive(Debug)]
+pub struct Transcript {
+    dir: PathBuf,
+    started_at: u64,
+    path: Option<PathBuf>,
+    entries: usize,
+    part: u32,
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Where the transcripts for a save live: `transcripts/` beside it.
+pub fn transcript_dir(state_path: &Path) -> PathBuf {
+    state_path
+        .parent()
+        .map(|p| p.join("transcripts"))
+        .unwrap_or_else(|| PathBuf::from("transcripts"))
+}
+
+impl Transcript {
+    pub fn new(state_path: &Path) -> Self {
+        Self {
+            dir: transcript_dir(state_path),
+            started_at: now_secs(),
+            path: None,
+            entries: 0,
+            part: 0,
+        }
+    }
+
+    /// The file written so far this session, if anything has been.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Append one call, starting a new file first if this session has none
+    /// yet or the current one is full.
+    pub fn record(&mut self, entry: &TranscriptEntry) -> Result<()> {
+        if self.path.is_none() || self.entries >= MAX_ENTRIES {
+            self.roll_over()?;
+        }
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let mut f = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(f, "{}", serde_json::to_string(entry)?)?;
+        self.entries += 1;
+        Ok(())
+    }
+
+    fn roll_over(&mut self) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        self.part += 1;
+        let name = if self.part == 1 {
+            format!("session-{}.jsonl", self.started_at)
+        } else {
+            format!("session-{}-{}.jsonl", self.started_at, self.part)
+        };
+        self.path = Some(self.dir.join(name));
+        self.entries = 0;
+        prune(&self.dir, TRANSCRIPTS_KEPT.saturating_sub(1));
+        Ok(())
+    }
+}
+
+/// The transcript files in `dir`, newest first.
+fn list_in(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut found: Vec<(u64, u32, PathBuf)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let stem = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("session-")?
+                .strip_suffix(".jsonl")?
+                .to_string();
+            let (started, part) = match stem.split_once('-') {
+                Some((s, p)) => (s.parse().ok()?, p.parse().ok()?),
+                None => (stem.parse().ok()?, 1),
+            };
+            Some((started, part, path))
+        })
+        .collect();
+    found.sort_by_key(|(started, part, _)| std::cmp::Reverse((*started, *part)));
+    found.into_iter().map(|(_, _, path)| path).collect()
+}
+
+/// Drop the oldest transcripts past the `keep` newest.
+fn prune(dir: &Path, keep: usize) {
+    for old in list_in(dir).iter().skip(keep) {
+        let _ = std::fs::remove_file(old);
+    }
+}
+
+/// The newest transcript beside a save, for readers outside the session.
+pub fn latest(state_path: &Path) -> Option<PathBuf> {
+    list_in(&transcript_dir(state_path)).into_iter().next()
+}
+
+/// Every call in a transcript file, in the order they were made. Lines that
+/// don't parse, such as one cut short by a full disk, are passed over.
+pub fn read_entries(path: &Path) -> Vec<TranscriptEntry> {
+    std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+fn call_line(entry: &TranscriptEntry) -> String {
+    match entry.arguments.as_ref().filter(|a| !a.is_null()) {
+        Some(args) => format!("{} {}", entry.tool, args),
+        None => entry.tool.clone(),
+    }
+}
+
+/// The transcript as markdown, one section per call.
+pub fn render_text(entries: &[TranscriptEntry]) -> String {
+    if entries.is_empty() {
+        return "No calls recorded yet this session.".to_string();
+    }
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let error = if entry.is_error { " (error)" } else { "" };
+            format!(
+                "### {}. `{}`{} · {}\n\n{}",
+                i + 1,
+                call_line(entry),
+                error,
+                entry.game_time,
+                entry.text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The transcript as a plain page for `GET /transcript`.
+pub fn render_html(entries: &[TranscriptEntry]) -> String {
+    let body = if entries.is_empty() {
+        "<p>No calls recorded yet.</p>".to_string()
+    } else {
+        entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let class = if entry.is_error { "call error" } else { "call" };
+                format!(
+                    "<div class=\"{}\"><div class=\"head\">{}. <code>{}</code><span class=\"when\">{}</span></div><pre>{}</pre></div>",
+                    class,
+                    i + 1,
+                    escape_html(&call_line(entry)),
+                    escape_html(&entry.game_time),
+                    escape_html(&entry.text)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8" />
+<title>Rubber Duck Transcript</title>
+<style>
+body {{ margin:0 auto; max-width:820px; padding:16px; font-family: 'IBM Plex Mono', 'Fira Code', monospace; background:#0b0d11; color:#dce3ec; }}
+.call {{ margin:0 0 12px 0; padding:8px; background:#141b26; border-radius:6px; border:1px solid #1f2935; }}
+.call.error {{ border-color:#6b2a2a; }}
+.head {{ color:#9cc3ff; margin-bottom:6px; }}
+.when {{ float:right; font-size:11px; color:#6b7687; }}
+pre {{ margin:0; white-space:pre-wrap; font-size:13px; }}
+</style>
+</head>
+<body>
+<h2>Transcript</h2>
+{}
+</body>
+</html>
+"#,
+        body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transcripts_list_newest_first() {
+        let dir =
+            std::env::temp_dir().join(format!("rubber-duck-transcripts-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "session-100.jsonl",
+            "session-300-2.jsonl",
+            "session-300.jsonl",
+            "session-200-3.jsonl",
+            "notes.txt",
+        ] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        let names: Vec<String> = list_in(&dir)
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "session-300-2.jsonl",
+                "session-300.jsonl",
+                "session-200-3.jsonl",
+                "session-100.jsonl"
+            ]
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Biome, Position};
+use crate::entity::Item;
+
+/// Tiles just west of the east cave mouth, where the bear marks its range.
+const CAVE_APPROACH: [(i32, i32); 3] = [(0, 7), (-1, 7), (1, 7)];
+
+/// Something easy to walk past on a tile. It is only mentioned once the
+/// player's observation is sharp enough, or after they search for it, and
+/// can be examined once for a small find or a bit of lore.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HiddenDetail {
+    HollowLog,
+    UnusualStone,
+    ClawMarks,
+    RareHerbs,
+    OldFirePit,
+}
+
+impl HiddenDetail {
+    /// The detail seeded at `pos`, if any. Deterministic, so a tile always
+    /// hides the same thing; roughly one tile in nine has something.
+    pub fn at(pos: Position, biome: Biome) -> Option<Self> {
+        // The trees on the approach to the east cave always carry a warning
+        if CAVE_APPROACH.contains(&(pos.row, pos.col)) {
+            return Some(HiddenDetail::ClawMarks);
+        }
+        let mut h = (pos.row as i64 as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ (pos.col as i64 as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+        h ^= h >> 31;
+        h = h.wrapping_mul(0x94D0_49BB_1331_11EB);
+        h ^= h >> 29;
+        if !h.is_multiple_of(9) {
+            return None;
+        }
+        let candidates: &[HiddenDetail] = match biome {
+            Biome::MixedForest => &[
+                HiddenDetail::HollowLog,
+                HiddenDetail::ClawMarks,
+                HiddenDetail::UnusualStone,
+            ],
+            Biome::SpringForest => &[HiddenDetail::HollowLog, HiddenDetail::RareHerbs],
+            Biome::WinterForest => &[HiddenDetail::ClawMarks, HiddenDetail::UnusualStone],
+            Biome::BambooGrove | Biome::Oasis => &[HiddenDetail::RareHerbs],
+            Biome::Desert => &[HiddenDetail::UnusualStone, HiddenDetail::OldFirePit],
+            Biome::Path | Biome::Clearing => &[HiddenDetail::OldFirePit, HiddenDetail::UnusualStone],
+            Biome::Lake => &[],
+        };
+        if candidates.is_empty() {
+            return None;
+        }
+        Some(candidates[((h >> 8) % candidates.len() as u64) as usize])
+    }
+
+    /// Observation needed to notice it without searching.
+    pub fn threshold(&self) -> u8 {
+        match self {
+            HiddenDetail::ClawMarks => 15,
+            HiddenDetail::OldFirePit => 18,
+            HiddenDetail::UnusualStone => 22,
+            HiddenDetail::HollowLog => 28,
+            HiddenDetail::RareHerbs => 32,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HiddenDetail::HollowLog => "hollow log",
+            HiddenDetail::UnusualStone => "unusual stone",
+            HiddenDetail::ClawMarks => "claw marks",
+            HiddenDetail::RareHerbs => "rare herbs",
+            HiddenDetail::OldFirePit => "old fire pit",
+        }
+    }
+
+    /// Words that point `examine` at this detail.
+    pub fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            HiddenDetail::HollowLog => &["hollow", "log", "stash"],
+            HiddenDetail::UnusualStone => &["stone", "rock", "fossil"],
+            HiddenDetail::ClawMarks => &["claw", "marks", "trunk", "scratch"],
+            HiddenDetail::RareHerbs => &["herb", "patch", "plant"],
+            HiddenDetail::OldFirePit => &["fire pit", "pit", "ring", "ash"],
+        }
+    }
+
+    /// How it shows up in the location description once noticed.
+    pub fn notice(&self) -> &'static str {
+        match self {
+            HiddenDetail::HollowLog => {
+                "Half buried in leaf litter lies a hollow log, and something seems tucked inside it."
+            }
+            HiddenDetail::UnusualStone => {
+                "One stone at your feet doesn't match the others: its surface is oddly patterned."
+            }
+            HiddenDetail::ClawMarks => {
+                "Deep parallel gouges score the bark of a nearby trunk, well above head height."
+            }
+            HiddenDetail::RareHerbs => {
+                "A low patch of silvery-leaved herbs grows in the shade, easy to mistake for weeds."
+            }
+            HiddenDetail::OldFirePit => {
+                "A ring of blackened stones hides under the grass: someone's fire pit, long cold."
+            }
+        }
+    }
+
+    /// What examining it reveals, also written to the journal.
+    pub fn lore(&self) -> &'static str {
+        match self {
+            HiddenDetail::HollowLog => {
+                "Inside the hollow log someone left a small bundle wrapped in oilcloth. Whoever stashed it never came back."
+            }
+            HiddenDetail::UnusualStone => {
+                "Turned over, the stone shows the pressed print of a tiny fern, older than the forest around it. Its broken edge is sharp enough to be useful."
+            }
+            HiddenDetail::ClawMarks => {
+                "The gouges are old, weathered grey, but the bark has not closed over them. Something large marks this stretch of forest as its own, and it isn't far from here."
+            }
+            HiddenDetail::RareHerbs => {
+                "The silvery leaves smell sharp and clean when crushed. You pick a few sprigs, leaving the roots to grow back."
+            }
+            HiddenDetail::OldFirePit => {
+                "Under the ash you find a few lumps of good charcoal and a rusted tin lid. Someone once sat here long enough to build a proper ring."
+            }
+        }
+    }
+
+    /// What the player takes away from it.
+    pub fn find(&self) -> &'static [(Item, u32)] {
+        match self {
+            HiddenDetail::HollowLog => &[(Item::Cordage, 1), (Item::Mushroom, 1)],
+            HiddenDetail::UnusualStone => &[(Item::SharpStone, 1)],
+            HiddenDetail::ClawMarks => &[],
+            HiddenDetail::RareHerbs => &[(Item::WildHerbs, 3)],
+            HiddenDetail::OldFirePit => &[(Item::Charcoal, 2)],
+        }
+    }
+}
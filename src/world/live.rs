@@ -0,0 +1,23 @@
+//! The map as the game last left it. Dropped things and other changes to
+//! the ground live only in the game's own map, never in the save, so a copy
+//! regenerated from the seed can't show them. The server shares its map
+//! after every call; the web thread reads it for `/state` and `/tile`.
+
+use super::map::WorldMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+fn registry() -> &'static Mutex<Option<Arc<WorldMap>>> {
+    static REGISTRY: OnceLock<Mutex<Option<Arc<WorldMap>>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(None))
+}
+
+/// Share the map as it stands now.
+pub fn share_map(map: &WorldMap) {
+    let shared = Arc::new(map.clone());
+    *registry().lock().unwrap_or_else(|e| e.into_inner()) = Some(shared);
+}
+
+/// The last map shared, if the game has answered a call yet.
+pub fn latest_map() -> Option<Arc<WorldMap>> {
+    registry().lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
@@ -1,12 +1,54 @@
 use crate::entity::{Item, LocationItems};
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// World coords span -extent..=extent on both axes, cabin at the origin.
+pub const DEFAULT_MAP_EXTENT: i32 = 50;
+/// Smallest extent that still holds the authored landmarks with room to walk.
+pub const MIN_MAP_EXTENT: i32 = 20;
+pub const MAX_MAP_EXTENT: i32 = 200;
+/// Environment variable choosing the map extent for new games.
+pub const MAP_EXTENT_ENV: &str = "RUBBER_DUCK_MAP_EXTENT";
+/// Top of the rocky knoll in the northern woods, the highest ground around.
+pub const LOOKOUT: (i32, i32) = (-10, 2);
+/// Knoll ground this high is bare rock; nothing takes root on it.
+pub const BARE_ELEVATION: f32 = 3.0;
+/// Environment variable choosing the world seed for new games.
+pub const WORLD_SEED_ENV: &str = "RUBBER_DUCK_WORLD_SEED";
+
+/// The seed for a new game from `RUBBER_DUCK_WORLD_SEED`. Unset, unparsable
+/// or 0 all mean the hand-authored layout.
+pub fn world_seed_from_env() -> u64 {
+    match std::env::var(WORLD_SEED_ENV) {
+        Ok(v) => v.trim().parse().unwrap_or_else(|_| {
+            tracing::warn!("Ignoring {}={}; expected a whole number", WORLD_SEED_ENV, v);
+            0
+        }),
+        Err(_) => 0,
+    }
+}
 
-pub const MAP_EXTENT: i32 = 50; // world coords span -50..50
-pub const MAP_WIDTH: usize = (MAP_EXTENT as usize * 2) + 1;
-pub const MAP_HEIGHT: usize = (MAP_EXTENT as usize * 2) + 1;
-pub const MAP_ORIGIN_ROW: i32 = MAP_EXTENT;
-pub const MAP_ORIGIN_COL: i32 = MAP_EXTENT;
+/// The map extent for a new game from `RUBBER_DUCK_MAP_EXTENT`, or the default.
+pub fn map_extent_from_env() -> i32 {
+    let Ok(v) = std::env::var(MAP_EXTENT_ENV) else {
+        return DEFAULT_MAP_EXTENT;
+    };
+    match v.trim().parse::<i32>() {
+        Ok(extent) if (MIN_MAP_EXTENT..=MAX_MAP_EXTENT).contains(&extent) => extent,
+        _ => {
+            tracing::warn!(
+                "Ignoring {}={}; use a whole number from {} to {}",
+                MAP_EXTENT_ENV,
+                v,
+                MIN_MAP_EXTENT,
+                MAX_MAP_EXTENT
+            );
+            DEFAULT_MAP_EXTENT
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Direction {
@@ -40,6 +82,17 @@ impl Direction {
         }
     }
 
+    pub fn name(&self) -> &'static str {
+        match self {
+            Direction::North => "north",
+            Direction::South => "south",
+            Direction::East => "east",
+            Direction::West => "west",
+            Direction::Up => "up",
+            Direction::Down => "down",
+        }
+    }
+
     pub fn from_str(s: &str) -> Option<Direction> {
         match s.to_lowercase().as_str() {
             "n" | "north" => Some(Direction::North),
@@ -96,6 +149,99 @@ impl Biome {
     }
 }
 
+/// The four seasonal quarters around the cabin. Every tile belongs to exactly
+/// one; the lake and clearing at the centre fall to whichever they lean into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Region {
+    NorthernWoods,
+    EasternPines,
+    SouthernWoods,
+    GlassDesert,
+}
+
+impl Region {
+    /// Clockwise from north, the order the weather vane reads them in.
+    pub const ALL: [Region; 4] = [
+        Region::NorthernWoods,
+        Region::EasternPines,
+        Region::SouthernWoods,
+        Region::GlassDesert,
+    ];
+
+    /// Which way the region lies from the cabin.
+    pub fn direction(&self) -> Direction {
+        match self {
+            Region::NorthernWoods => Direction::North,
+            Region::EasternPines => Direction::East,
+            Region::SouthernWoods => Direction::South,
+            Region::GlassDesert => Direction::West,
+        }
+    }
+
+    /// The season-setting biome whose weather rolls over the region.
+    pub fn biome(&self) -> Biome {
+        match self {
+            Region::NorthernWoods => Biome::SpringForest,
+            Region::EasternPines => Biome::WinterForest,
+            Region::SouthernWoods => Biome::MixedForest,
+            Region::GlassDesert => Biome::Desert,
+        }
+    }
+
+    pub fn default_name(&self) -> &'static str {
+        match self {
+            Region::NorthernWoods => "the Northern Woods",
+            Region::EasternPines => "the Eastern Pines",
+            Region::SouthernWoods => "the Southern Woods",
+            Region::GlassDesert => "the Glass Desert",
+        }
+    }
+
+    /// A region by compass word or by its default name.
+    pub fn parse(s: &str) -> Option<Region> {
+        let s = s.trim().to_lowercase();
+        let s = s.strip_prefix("the ").unwrap_or(&s);
+        Self::ALL.into_iter().find(|r| {
+            r.direction().name() == s
+                || r.default_name().trim_start_matches("the ").to_lowercase() == s
+        })
+    }
+}
+
+/// What the player has chosen to call the regions, over their defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegionNames(HashMap<Region, String>);
+
+impl RegionNames {
+    pub fn name(&self, region: Region) -> &str {
+        self.0
+            .get(&region)
+            .map(|s| s.as_str())
+            .unwrap_or(region.default_name())
+    }
+
+    /// A region by the player's name for it, its compass word or its default name.
+    pub fn find(&self, s: &str) -> Option<Region> {
+        let wanted = s.trim().to_lowercase();
+        let wanted = wanted.strip_prefix("the ").unwrap_or(&wanted);
+        self.0
+            .iter()
+            .find(|(_, name)| name.to_lowercase() == wanted)
+            .map(|(r, _)| *r)
+            .or_else(|| Region::parse(s))
+    }
+
+    /// Rename a region; an empty name restores the default.
+    pub fn set(&mut self, region: Region, name: &str) {
+        let name = name.trim();
+        if name.is_empty() {
+            self.0.remove(&region);
+        } else {
+            self.0.insert(region, name.to_string());
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TileType {
     Forest(Biome),
@@ -111,16 +257,18 @@ pub struct Tile {
     pub elevation: f32,
     pub walkable: bool,
     pub items: LocationItems,
+    /// Boulders and a spill of loose stone.
+    #[serde(default)]
+    pub outcrop: bool,
 }
 
 impl Tile {
-    pub fn new(tile_type: TileType, biome: Biome) -> Self {
+    pub fn new(tile_type: TileType, biome: Biome, rng: &mut impl Rng) -> Self {
         let walkable = !matches!(tile_type, TileType::Lake);
         let mut items = LocationItems::new();
 
         // Spawn basic resources (Stones) everywhere except deep lake
         if !matches!(tile_type, TileType::Lake) {
-            let mut rng = rand::thread_rng();
             let stone_count = rng.gen_range(3..=10);
             items.add(Item::Stone, stone_count);
         }
@@ -131,41 +279,276 @@ impl Tile {
             elevation: 0.0,
             walkable,
             items,
+            outcrop: false,
         }
     }
 }
 
+/// Where the movable landmarks sit. Seed 0 is the hand-authored layout;
+/// any other seed shifts them around the fixed cabin, lake and shed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Layout {
+    pub seed: u64,
+    pub extent: i32,
+    /// Per-row shift of the desert's eastern edge.
+    desert_jitter: Vec<i32>,
+    /// Per-row shift of the winter forest's western edge.
+    winter_jitter: Vec<i32>,
+    /// Per-column shift of the northern forest band's edge.
+    north_jitter: Vec<i32>,
+    /// North-west corner of the 2x3 bamboo grove.
+    grove: (i32, i32),
+    /// North-west corner of the 5x2 oasis pool.
+    oasis: (i32, i32),
+    cave: (i32, i32),
+    outcrops: Vec<(i32, i32)>,
+}
+
+impl Layout {
+    /// The grove can grow at any corner of the lake.
+    const GROVE_SITES: [(i32, i32); 4] = [(0, -3), (0, 1), (-7, -3), (-7, 1)];
+
+    pub fn authored(extent: i32) -> Self {
+        let span = (extent * 2 + 1) as usize;
+        Self {
+            seed: 0,
+            extent,
+            desert_jitter: vec![0; span],
+            winter_jitter: vec![0; span],
+            north_jitter: vec![0; span],
+            grove: Self::GROVE_SITES[0],
+            oasis: (-5, -4),
+            cave: (0, 8),
+            outcrops: Vec::new(),
+        }
+    }
+
+    pub fn from_seed(seed: u64, extent: i32) -> Self {
+        if seed == 0 {
+            return Self::authored(extent);
+        }
+        let span = (extent * 2 + 1) as usize;
+        let reach = extent / 4;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let desert_jitter = Self::wander(&mut rng, span);
+        let winter_jitter = Self::wander(&mut rng, span);
+        let north_jitter = Self::wander(&mut rng, span);
+        let grove = Self::GROVE_SITES[rng.gen_range(0..Self::GROVE_SITES.len())];
+        let oasis = (rng.gen_range(-8..=0), rng.gen_range(-10..=-7));
+        let cave = (rng.gen_range(-4..=4), 8);
+        let outcrops = (0..rng.gen_range(3..=5))
+            .map(|_| loop {
+                let spot: (i32, i32) =
+                    (rng.gen_range(-reach..=reach), rng.gen_range(-reach..=reach));
+                // Keep the cabin clearing and the path open
+                if spot.0.abs() + spot.1.abs() > 3 && spot.1 != 0 {
+                    break spot;
+                }
+            })
+            .collect();
+        Self {
+            seed,
+            extent,
+            desert_jitter,
+            winter_jitter,
+            north_jitter,
+            grove,
+            oasis,
+            cave,
+            outcrops,
+        }
+    }
+
+    /// A slow random walk between -1 and 1, so edges bend rather than fray.
+    fn wander(rng: &mut StdRng, len: usize) -> Vec<i32> {
+        let mut shift = 0;
+        (0..len)
+            .map(|_| {
+                if rng.gen_bool(0.3) {
+                    shift = (shift + if rng.gen_bool(0.5) { 1 } else { -1 }).clamp(-1, 1);
+                }
+                shift
+            })
+            .collect()
+    }
+
+    fn in_grove(&self, row: i32, col: i32) -> bool {
+        let (r, c) = self.grove;
+        (r..=r + 1).contains(&row) && (c..=c + 2).contains(&col)
+    }
+
+    fn in_oasis(&self, row: i32, col: i32) -> bool {
+        let (r, c) = self.oasis;
+        (r..=r + 4).contains(&row) && (c..=c + 1).contains(&col)
+    }
+
+    /// The fixed lake, less any of it the oasis has taken over.
+    fn in_lake(&self, row: i32, col: i32) -> bool {
+        (-5..=-1).contains(&row) && (-4..=4).contains(&col) && !self.in_oasis(row, col)
+    }
+
+    fn jitter(&self, shifts: &[i32], world: i32) -> i32 {
+        shifts
+            .get((world + self.extent) as usize)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Tiles where bamboo is planted at the start.
+    pub fn grove_stands(&self) -> [Position; 3] {
+        let (r, c) = self.grove;
+        [
+            Position::new(r, c + 1),
+            Position::new(r, c),
+            Position::new(r + 1, c + 1),
+        ]
+    }
+
+    /// Tiles on the dry western shore of the oasis where date palms grow.
+    pub fn oasis_palms(&self) -> [Position; 3] {
+        let (r, c) = self.oasis;
+        [
+            Position::new(r, c - 1),
+            Position::new(r + 2, c - 1),
+            Position::new(r + 4, c - 1),
+        ]
+    }
+
+    pub fn cave_entrance(&self) -> Position {
+        Position::new(self.cave.0, self.cave.1)
+    }
+
+    /// Where the old ruin lies buried, out in the dunes past the oasis.
+    pub fn ruin_site(&self) -> Position {
+        Position::new(self.oasis.0 + 2, self.oasis.1 - 3)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorldMap {
     tiles: Vec<Vec<Tile>>,
+    layout: Layout,
 }
 
 impl WorldMap {
+    /// The hand-authored map at the default size.
     pub fn new() -> Self {
-        let mut tiles = Vec::with_capacity(MAP_HEIGHT);
+        Self::generate(0, DEFAULT_MAP_EXTENT)
+    }
 
-        for row in 0..MAP_HEIGHT {
-            let mut row_tiles = Vec::with_capacity(MAP_WIDTH);
-            for col in 0..MAP_WIDTH {
-                let tile = Self::generate_tile(row, col);
+    pub fn generate(seed: u64, extent: i32) -> Self {
+        let extent = extent.clamp(MIN_MAP_EXTENT, MAX_MAP_EXTENT);
+        let layout = Layout::from_seed(seed, extent);
+        // Stone counts come from a stream of their own so reloads match
+        let mut rng = StdRng::seed_from_u64(!seed);
+        let span = (extent * 2 + 1) as usize;
+        let mut tiles = Vec::with_capacity(span);
+
+        for row in 0..span {
+            let mut row_tiles = Vec::with_capacity(span);
+            for col in 0..span {
+                let tile = Self::generate_tile(&layout, row, col, &mut rng);
                 row_tiles.push(tile);
             }
             tiles.push(row_tiles);
         }
 
-        WorldMap { tiles }
+        WorldMap { tiles, layout }
+    }
+
+    pub fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.layout.seed
+    }
+
+    pub fn extent(&self) -> i32 {
+        self.layout.extent
+    }
+
+    pub fn width(&self) -> usize {
+        (self.extent() * 2 + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.extent() * 2 + 1) as usize
+    }
+
+    /// Whether a world position lies on this map.
+    pub fn contains(&self, pos: &Position) -> bool {
+        pos.row.abs() <= self.extent() && pos.col.abs() <= self.extent()
+    }
+
+    /// Grid indices for a world position, if it's on the map.
+    pub fn index_of(&self, pos: &Position) -> Option<(usize, usize)> {
+        if self.contains(pos) {
+            Some((
+                (pos.row + self.extent()) as usize,
+                (pos.col + self.extent()) as usize,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Whether a world position is on this map and solid enough to stand on.
+    pub fn can_stand_at(&self, pos: &Position) -> bool {
+        self.index_of(pos)
+            .map(|(r, c)| self.is_walkable(r, c))
+            .unwrap_or(false)
     }
 
-    fn generate_tile(row: usize, col: usize) -> Tile {
+    /// Whether a world position is on this map and open water a raft can float on.
+    pub fn is_open_water(&self, pos: &Position) -> bool {
+        self.tile_at(pos)
+            .map(|t| matches!(t.tile_type, TileType::Lake))
+            .unwrap_or(false)
+    }
+
+    /// The world position of grid indices.
+    pub fn position_of(&self, row: usize, col: usize) -> Position {
+        Position::new(row as i32 - self.extent(), col as i32 - self.extent())
+    }
+
+    pub fn tile_at(&self, pos: &Position) -> Option<&Tile> {
+        self.index_of(pos).and_then(|(r, c)| self.get_tile(r, c))
+    }
+
+    pub fn tile_at_mut(&mut self, pos: &Position) -> Option<&mut Tile> {
+        self.index_of(pos)
+            .and_then(|(r, c)| self.get_tile_mut(r, c))
+    }
+
+    pub fn biome_at(&self, pos: &Position) -> Option<Biome> {
+        self.tile_at(pos).map(|t| t.biome)
+    }
+
+    /// Every position on the map, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let extent = self.extent();
+        (-extent..=extent)
+            .flat_map(move |row| (-extent..=extent).map(move |col| Position::new(row, col)))
+    }
+
+    fn generate_tile(layout: &Layout, row: usize, col: usize, rng: &mut StdRng) -> Tile {
         // Convert grid index to world coordinates (origin at cabin)
-        let world_row = row as i32 - MAP_ORIGIN_ROW;
-        let world_col = col as i32 - MAP_ORIGIN_COL;
+        let world_row = row as i32 - layout.extent;
+        let world_col = col as i32 - layout.extent;
 
         // Determine biome based on position
-        let biome = Self::determine_biome(world_row, world_col);
-        let tile_type = Self::determine_tile_type(world_row, world_col, biome);
+        let biome = Self::determine_biome(layout, world_row, world_col);
+        let tile_type = Self::determine_tile_type(layout, world_row, world_col, biome);
 
-        let mut tile = Tile::new(tile_type, biome);
+        let mut tile = Tile::new(tile_type, biome, rng);
+        if tile.walkable && layout.outcrops.contains(&(world_row, world_col)) {
+            tile.outcrop = true;
+            tile.items.add(Item::Stone, rng.gen_range(12..=20));
+        }
+        let (peak_row, peak_col) = LOOKOUT;
+        let from_peak = ((world_row - peak_row).abs() + (world_col - peak_col).abs()) as f32;
+        tile.elevation = (4.0 - from_peak).max(0.0);
 
         // Place a starter knife on the path just south of the cabin so new players
         // naturally notice it near the tutorial carcass.
@@ -173,22 +556,19 @@ impl WorldMap {
             tile.items.add(Item::Knife, 1);
         }
 
-        if world_row.abs() == MAP_EXTENT || world_col.abs() == MAP_EXTENT {
+        if world_row.abs() == layout.extent || world_col.abs() == layout.extent {
             tile.walkable = false; // Impassable border
         }
         tile
     }
 
-    fn determine_biome(world_row: i32, world_col: i32) -> Biome {
+    fn determine_biome(layout: &Layout, world_row: i32, world_col: i32) -> Biome {
+        // Oasis pool: west end of the lake unless the seed moved it out into the dunes
+        if layout.in_oasis(world_row, world_col) {
+            return Biome::Oasis;
+        }
         // Lake core: rows -5..-1, cols -4..4 (north of cabin)
-        let is_lake_area = world_row >= -5 && world_row <= -1 && world_col >= -4 && world_col <= 4;
-        if is_lake_area {
-            if world_col <= -3 {
-                return Biome::Oasis;
-            }
-            if world_col >= 3 {
-                return Biome::Lake;
-            }
+        if layout.in_lake(world_row, world_col) {
             return Biome::Lake;
         }
 
@@ -197,23 +577,23 @@ impl WorldMap {
             return Biome::Clearing;
         }
 
-        // Bamboo grove near lake south-west edge
-        if world_row >= 0 && world_row <= 1 && world_col >= -3 && world_col <= -1 {
+        // Bamboo grove at one corner of the lake (south-west when authored)
+        if layout.in_grove(world_row, world_col) {
             return Biome::BambooGrove;
         }
 
         // West side - Desert band
-        if world_col <= -5 {
+        if world_col <= -5 + layout.jitter(&layout.desert_jitter, world_row) {
             return Biome::Desert;
         }
 
         // East side - Winter
-        if world_col >= 5 {
+        if world_col >= 5 + layout.jitter(&layout.winter_jitter, world_row) {
             return Biome::WinterForest;
         }
 
         // North band (rows <= -4) - Spring/Autumn forest
-        if world_row <= -4 {
+        if world_row <= -4 + layout.jitter(&layout.north_jitter, world_col) {
             return Biome::SpringForest;
         }
 
@@ -226,9 +606,14 @@ impl WorldMap {
         Biome::MixedForest
     }
 
-    fn determine_tile_type(world_row: i32, world_col: i32, biome: Biome) -> TileType {
-        // Lake tiles
-        if world_row >= -5 && world_row <= -1 && world_col >= -4 && world_col <= 4 {
+    fn determine_tile_type(
+        layout: &Layout,
+        world_row: i32,
+        world_col: i32,
+        biome: Biome,
+    ) -> TileType {
+        // Lake and oasis water
+        if layout.in_lake(world_row, world_col) || layout.in_oasis(world_row, world_col) {
             return TileType::Lake;
         }
 
@@ -255,36 +640,54 @@ impl WorldMap {
     }
 
     pub fn is_valid_position(&self, row: i32, col: i32) -> bool {
-        let gr = row + MAP_ORIGIN_ROW;
-        let gc = col + MAP_ORIGIN_COL;
-        gr >= 0 && gc >= 0 && (gr as usize) < MAP_HEIGHT && (gc as usize) < MAP_WIDTH
+        self.contains(&Position::new(row, col))
     }
 
     pub fn is_walkable(&self, row: usize, col: usize) -> bool {
         self.get_tile(row, col).map(|t| t.walkable).unwrap_or(false)
     }
 
+    pub fn elevation_at(&self, pos: &Position) -> f32 {
+        self.tile_at(pos).map(|t| t.elevation).unwrap_or(0.0)
+    }
+
+    /// The walkable tile standing highest, first found on ties.
+    pub fn highest_point(&self) -> Position {
+        let mut best = (Position::new(LOOKOUT.0, LOOKOUT.1), f32::MIN);
+        for (r, row) in self.tiles.iter().enumerate() {
+            for (c, tile) in row.iter().enumerate() {
+                if tile.walkable && tile.elevation > best.1 {
+                    let pos = self.position_of(r, c);
+                    best = (pos, tile.elevation);
+                }
+            }
+        }
+        best.0
+    }
+
     pub fn get_biome_at(&self, row: usize, col: usize) -> Option<Biome> {
         self.get_tile(row, col).map(|t| t.biome)
     }
 
     /// Calculate which seasonal biome direction dominates at this position
     pub fn get_dominant_direction(&self, row: usize, col: usize) -> Direction {
-        let row_diff = row as i32 - MAP_ORIGIN_ROW;
-        let col_diff = col as i32 - MAP_ORIGIN_COL;
+        let (row, col) = (row as i32 - self.extent(), col as i32 - self.extent());
+        Self::region_of(row, col).direction()
+    }
 
-        if row_diff.abs() > col_diff.abs() {
-            if row_diff < 0 {
-                Direction::North
+    /// The region holding a world position, by whichever axis it lies
+    /// farther along from the cabin. Diagonals go to east or west.
+    pub fn region_of(row: i32, col: i32) -> Region {
+        if row.abs() > col.abs() {
+            if row < 0 {
+                Region::NorthernWoods
             } else {
-                Direction::South
+                Region::SouthernWoods
             }
+        } else if col < 0 {
+            Region::GlassDesert
         } else {
-            if col_diff < 0 {
-                Direction::West
-            } else {
-                Direction::East
-            }
+            Region::EasternPines
         }
     }
 }
@@ -320,25 +723,162 @@ impl Position {
         (dr * dr + dc * dc).sqrt()
     }
 
-    pub fn is_valid(&self) -> bool {
-        let gr = self.row + MAP_ORIGIN_ROW;
-        let gc = self.col + MAP_ORIGIN_COL;
-        gr >= 0 && gc >= 0 && (gr as usize) < MAP_HEIGHT && (gc as usize) < MAP_WIDTH
+    /// Which of the eight compass points `other` lies toward.
+    pub fn bearing_to(&self, other: &Position) -> &'static str {
+        const POINTS: [&str; 8] = [
+            "east",
+            "north-east",
+            "north",
+            "north-west",
+            "west",
+            "south-west",
+            "south",
+            "south-east",
+        ];
+        let dr = (other.row - self.row) as f32;
+        let dc = (other.col - self.col) as f32;
+        if dr == 0.0 && dc == 0.0 {
+            return "here";
+        }
+        // Rows grow southward, so north is negative.
+        let sector = ((-dr).atan2(dc) / std::f32::consts::FRAC_PI_4).round() as i32;
+        POINTS[sector.rem_euclid(8) as usize]
     }
 
-    pub fn as_usize(&self) -> Option<(usize, usize)> {
-        if self.is_valid() {
-            let gr = (self.row + MAP_ORIGIN_ROW) as usize;
-            let gc = (self.col + MAP_ORIGIN_COL) as usize;
-            Some((gr, gc))
+    /// The four-point direction `other` mostly lies toward.
+    pub fn rough_bearing_to(&self, other: &Position) -> &'static str {
+        let dr = other.row - self.row;
+        let dc = other.col - self.col;
+        if dr.abs() >= dc.abs() {
+            if dr < 0 {
+                "north"
+            } else {
+                "south"
+            }
+        } else if dc > 0 {
+            "east"
         } else {
-            None
+            "west"
         }
     }
 }
 
+/// Where home lies from `from`: tile-exact when `exact` (a compass in hand),
+/// a rough guess otherwise, and whether the ground underfoot is familiar.
+pub fn home_bearing(from: &Position, home: &Position, exact: bool, familiar: bool) -> String {
+    let distance = from.distance_to(home).round() as i32;
+    let lead = if exact {
+        format!(
+            "The compass puts the cabin {} tile{} to the {}.",
+            distance,
+            if distance == 1 { "" } else { "s" },
+            from.bearing_to(home)
+        )
+    } else {
+        let how_far = match distance {
+            0..=3 => "close by",
+            4..=10 => "a short walk",
+            11..=25 => "a fair way",
+            _ => "a long way",
+        };
+        format!(
+            "The cabin should be {} off, somewhere to the {}.",
+            how_far,
+            from.rough_bearing_to(home)
+        )
+    };
+    let ground = if familiar {
+        "You've walked this ground before."
+    } else {
+        "This ground is new to you."
+    };
+    format!("{} {}", lead, ground)
+}
+
 impl std::fmt::Display for Position {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "({}, {})", self.row, self.col)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every tile's type, biome and elevation, row by row.
+    fn grid(map: &WorldMap) -> Vec<(TileType, Biome, f32)> {
+        map.tiles
+            .iter()
+            .flatten()
+            .map(|t| (t.tile_type, t.biome, t.elevation))
+            .collect()
+    }
+
+    #[test]
+    fn a_seed_always_grows_the_same_world() {
+        let a = WorldMap::generate(7, DEFAULT_MAP_EXTENT);
+        let b = WorldMap::generate(7, DEFAULT_MAP_EXTENT);
+        assert_eq!(grid(&a), grid(&b));
+    }
+
+    #[test]
+    fn another_seed_grows_another_world() {
+        let a = WorldMap::generate(7, DEFAULT_MAP_EXTENT);
+        let b = WorldMap::generate(8, DEFAULT_MAP_EXTENT);
+        assert_ne!(grid(&a), grid(&b));
+    }
+
+    #[test]
+    fn the_compass_gives_home_to_the_tile_and_a_guess_only_roughly() {
+        let home = Position::new(0, 0);
+        let off = Position::new(5, -3);
+        assert_eq!(
+            home_bearing(&off, &home, true, false),
+            "The compass puts the cabin 6 tiles to the north-east. This ground is new to you."
+        );
+        assert_eq!(
+            home_bearing(&off, &home, false, true),
+            "The cabin should be a short walk off, somewhere to the north. You've walked this ground before."
+        );
+        let next_door = Position::new(0, 1);
+        let beside = home_bearing(&next_door, &home, true, true);
+        assert!(beside.starts_with("The compass puts the cabin 1 tile to the west."));
+        let far = Position::new(0, 30);
+        assert!(home_bearing(&far, &home, false, false).contains("a long way off"));
+    }
+
+    #[test]
+    fn every_tile_sits_in_one_region_and_gets_that_region_s_weather() {
+        use crate::world::{RegionalWeather, Weather};
+
+        let map = WorldMap::new();
+        let mut weather = RegionalWeather::new();
+        (weather.north, weather.east) = (Weather::Fog, Weather::Blizzard);
+        (weather.south, weather.west) = (Weather::LightRain, Weather::Sandstorm);
+        let mut seen = Vec::new();
+        for pos in map.positions() {
+            let region = WorldMap::region_of(pos.row, pos.col);
+            let matching = Region::ALL.iter().filter(|r| **r == region).count();
+            assert_eq!(matching, 1);
+            if !seen.contains(&region) {
+                seen.push(region);
+            }
+
+            // The quadrant rule the weather used before regions had names
+            let (row, col) = (pos.row, pos.col);
+            let quadrant = if row.abs() > col.abs() {
+                if row < 0 {
+                    weather.north
+                } else {
+                    weather.south
+                }
+            } else if col < 0 {
+                weather.west
+            } else {
+                weather.east
+            };
+            assert_eq!(weather.get_for_position(row, col), quadrant, "{}", pos);
+        }
+        assert_eq!(seen.len(), Region::ALL.len());
+    }
+}
@@ -1,11 +1,16 @@
+pub mod details;
+pub mod live;
 pub mod map;
 pub mod object;
+pub mod scenario;
 pub mod simulation;
 pub mod time;
 pub mod weather;
 
+pub use details::*;
 pub use map::*;
 pub use object::*;
+pub use scenario::*;
 pub use simulation::*;
 pub use time::*;
 pub use weather::*;
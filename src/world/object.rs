@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
-use crate::entity::{Body, Cabin, Item, Species, Tree, TreeType, WoodShed};
+use crate::entity::{Body, Cabin, Item, LocationItems, Room, Species, Tree, TreeType, WoodShed};
 use crate::world::Position;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -23,7 +24,7 @@ impl ObjectSize {
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ObjectSurface {
-    pub items: Vec<Item>,
+    pub items: LocationItems,
     pub capacity: Option<usize>,
     #[serde(default)]
     pub supports_mounts: bool,
@@ -32,21 +33,60 @@ pub struct ObjectSurface {
 impl ObjectSurface {
     pub fn add_item(&mut self, item: Item) -> bool {
         if let Some(cap) = self.capacity {
-            if self.items.len() >= cap {
+            if self.items.total() as usize >= cap {
                 return false;
             }
         }
-        self.items.push(item);
+        self.items.add(item, 1);
         true
     }
 
     pub fn take_item(&mut self, item: &Item) -> bool {
-        if let Some(idx) = self.items.iter().position(|i| i == item) {
-            self.items.remove(idx);
-            true
-        } else {
-            false
+        self.items.take(item)
+    }
+}
+
+/// Things kept in a placed object itself, like cargo lashed to the raft.
+/// They belong to the object and go wherever it goes, whether or not the
+/// player is anywhere near.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectStorage {
+    pub items: LocationItems,
+    /// Most items it holds, counted one by one.
+    pub capacity: u32,
+}
+
+impl ObjectStorage {
+    pub fn new(capacity: u32) -> Self {
+        Self {
+            items: LocationItems::new(),
+            capacity,
+        }
+    }
+
+    pub fn count(&self) -> u32 {
+        self.items.items.iter().map(|(_, qty)| qty).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    pub fn room(&self) -> u32 {
+        self.capacity.saturating_sub(self.count())
+    }
+
+    /// Stow as many of `qty` as fit, returning how many went in.
+    pub fn put(&mut self, item: Item, qty: u32) -> u32 {
+        let fits = qty.min(self.room());
+        if fits > 0 {
+            self.items.add(item, fits);
         }
+        fits
+    }
+
+    pub fn take(&mut self, item: &Item) -> bool {
+        self.items.take(item)
     }
 }
 
@@ -58,6 +98,254 @@ pub struct Corpse {
     pub body: Option<Body>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SeatKind {
+    Armchair,
+    TerraceBench,
+    Stool,
+    Bench,
+}
+
+impl SeatKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SeatKind::Armchair => "armchair",
+            SeatKind::TerraceBench => "terrace bench",
+            SeatKind::Stool => "wooden stool",
+            SeatKind::Bench => "wooden bench",
+        }
+    }
+
+    /// Crafted furniture that can be carried and set down as a seat.
+    pub fn from_item(item: &Item) -> Option<Self> {
+        match item {
+            Item::WoodenStool => Some(SeatKind::Stool),
+            Item::WoodenBench => Some(SeatKind::Bench),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Seat {
+    pub kind: SeatKind,
+    /// Interior room the seat stands in; `None` for seats out in the open.
+    #[serde(default)]
+    pub room: Option<Room>,
+}
+
+/// How a placed object can be entered with `enter` and left with `exit`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Enterable {
+    /// Leads into one of the fixed interior rooms.
+    Room(Room),
+    /// A structure you step into; the player stays on its tile, marked as
+    /// inside it.
+    Inside,
+    /// A vehicle: the player rides along wherever it goes.
+    Aboard,
+}
+
+/// The crafted raft once it has been put in the water.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Raft {
+    /// Already pushed a tile by the current storm.
+    #[serde(default)]
+    pub drifted: bool,
+}
+
+impl Raft {
+    /// Logs and gear that can ride lashed to the deck besides the paddler.
+    pub const CARGO_CAPACITY: u32 = 10;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BearState {
+    Hibernating,
+    /// Half awake: grumbling, shifting, one more disturbance from rising.
+    Stirring,
+    Hostile,
+    /// Fed and content; it won't rise against the player again.
+    Pacified,
+    /// Beaten back and gone from the cave for good.
+    DrivenOff,
+}
+
+/// The bear sleeping out the winter at the back of the east cave. Noise and
+/// fire inside the cave build its agitation; quiet lets it settle again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaveBear {
+    pub state: BearState,
+    #[serde(default)]
+    pub agitation: u8,
+    pub health: f32,
+    /// The player has been inside while it slept and not yet left.
+    #[serde(default)]
+    pub crept_in: bool,
+    /// Squared up to it this turn, so it fights back instead of charging.
+    #[serde(default)]
+    pub fighting: bool,
+    #[serde(default)]
+    pub avoided: bool,
+    #[serde(default)]
+    pub mauled: bool,
+}
+
+impl CaveBear {
+    pub const STIR_AT: u8 = 3;
+    pub const WAKE_AT: u8 = 6;
+    pub const MAX_HEALTH: f32 = 80.0;
+
+    pub fn new() -> Self {
+        Self {
+            state: BearState::Hibernating,
+            agitation: 0,
+            health: Self::MAX_HEALTH,
+            crept_in: false,
+            fighting: false,
+            avoided: false,
+            mauled: false,
+        }
+    }
+
+    /// Still in the cave and still able to wake.
+    pub fn can_wake(&self) -> bool {
+        matches!(
+            self.state,
+            BearState::Hibernating | BearState::Stirring | BearState::Hostile
+        )
+    }
+
+    /// Add `amount` of disturbance, returning the new state if it changed.
+    pub fn disturb(&mut self, amount: u8) -> Option<BearState> {
+        if !self.can_wake() {
+            return None;
+        }
+        self.agitation = self.agitation.saturating_add(amount);
+        let next = if self.agitation >= Self::WAKE_AT {
+            BearState::Hostile
+        } else if self.agitation >= Self::STIR_AT {
+            BearState::Stirring
+        } else {
+            self.state
+        };
+        if next == self.state || self.state == BearState::Hostile {
+            return None;
+        }
+        self.state = next;
+        Some(next)
+    }
+
+    /// One undisturbed tick; it drifts back to sleep once agitation runs out.
+    pub fn settle(&mut self) -> Option<BearState> {
+        if !self.can_wake() {
+            return None;
+        }
+        self.agitation = self.agitation.saturating_sub(1);
+        if self.agitation == 0 && self.state != BearState::Hibernating {
+            self.state = BearState::Hibernating;
+            return Some(BearState::Hibernating);
+        }
+        None
+    }
+
+    /// What the player senses of it from inside the cave.
+    pub fn sense(&self) -> &'static str {
+        match self.state {
+            BearState::Hibernating => {
+                "From deeper in comes slow, heavy breathing, far too large for anything small. Something is sleeping back there."
+            }
+            BearState::Stirring => {
+                "Somewhere in the dark a great weight shifts and grumbles. The breathing has lost its slow rhythm."
+            }
+            BearState::Hostile => {
+                "A bear stands in the dark at the back of the cave, head low, huffing hard. It is awake, and it is looking at you."
+            }
+            BearState::Pacified => {
+                "The bear lies curled at the back of the cave among fish bones, one eye half open. It seems to tolerate you."
+            }
+            BearState::DrivenOff => {
+                "The back of the cave is empty now: a flattened bed of leaves and a heavy musk where the bear used to sleep."
+            }
+        }
+    }
+}
+
+/// The half-buried ruin west of the oasis. Its chambers are choked with
+/// sand and open up one at a time as the player digs them clear, over as
+/// many visits as it takes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Ruin {
+    /// Uncovered by a sandstorm or traced from the ancient map. Until then
+    /// it is just another dune.
+    pub discovered: bool,
+    /// Chambers dug clear beyond the entrance hall.
+    pub chambers_cleared: u8,
+    /// Shovel loads moved out of the chamber being dug now.
+    pub sand_moved: u8,
+    pub relic_taken: bool,
+}
+
+impl Ruin {
+    /// Sand-filled chambers beyond the entrance hall.
+    pub const CHAMBERS: u8 = 2;
+    pub const LOADS_PER_CHAMBER: u8 = 3;
+
+    pub fn fully_cleared(&self) -> bool {
+        self.chambers_cleared >= Self::CHAMBERS
+    }
+
+    /// Move one load of sand. Returns the chamber this opened up, if it was
+    /// the last load for it.
+    pub fn dig(&mut self) -> Option<u8> {
+        if self.fully_cleared() {
+            return None;
+        }
+        self.sand_moved += 1;
+        if self.sand_moved < Self::LOADS_PER_CHAMBER {
+            return None;
+        }
+        self.sand_moved = 0;
+        self.chambers_cleared += 1;
+        Some(self.chambers_cleared)
+    }
+
+    pub fn chamber_name(index: u8) -> &'static str {
+        match index {
+            0 => "entrance hall",
+            1 => "hall of inscriptions",
+            _ => "inner chamber",
+        }
+    }
+
+    /// How the interior reads, deepest open chamber last.
+    pub fn interior(&self) -> String {
+        let mut text = String::from(
+            "You stand in the entrance hall of the ruin, out of the sun. Thick sandstone walls hold the air cool and still, and a stone bench along one wall is worn smooth, a good place to wait out the heat.",
+        );
+        text.push(' ');
+        text.push_str(match self.chambers_cleared {
+            0 => "Carved marks run along the lintel of a doorway ahead, but the passage beyond is packed with drifted sand to the ceiling.",
+            1 => "The doorway ahead now opens into a long hall whose walls are covered in carving. At its far end a second doorway is still choked with sand.",
+            _ => "Beyond the hall of inscriptions, the inner chamber stands open: a small round room with a stone plinth at its centre.",
+        });
+        if !self.fully_cleared() && self.sand_moved > 0 {
+            text.push_str(&format!(
+                " You've cut {} of the way through the sand in the {}.",
+                match self.sand_moved {
+                    1 => "a third",
+                    _ => "two thirds",
+                },
+                Self::chamber_name(self.chambers_cleared + 1)
+            ));
+        }
+        if self.fully_cleared() && !self.relic_taken {
+            text.push_str(" Something dark rests on the plinth.");
+        }
+        text
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ObjectKind {
     Cabin(Cabin),
@@ -67,6 +355,10 @@ pub enum ObjectKind {
     Wall,
     Boulder,
     Corpse(Corpse),
+    Seat(Seat),
+    Raft(Raft),
+    CaveBear(CaveBear),
+    Ruin(Ruin),
     GenericStructure(String),
 }
 
@@ -75,12 +367,20 @@ impl ObjectKind {
         match self {
             ObjectKind::Cabin(_) => "cabin".to_string(),
             ObjectKind::WoodShed(_) => "wood shed".to_string(),
-            ObjectKind::Tree(tree) => match tree.kind {
-                TreeType::Pine => "pine tree".to_string(),
-                TreeType::Birch => "birch tree".to_string(),
-                TreeType::Apple => "apple tree".to_string(),
-                TreeType::Bamboo => "bamboo grove".to_string(),
-            },
+            ObjectKind::Tree(tree) => {
+                let name = match tree.kind {
+                    TreeType::Pine => "pine tree",
+                    TreeType::Birch => "birch tree",
+                    TreeType::Apple => "apple tree",
+                    TreeType::Bamboo => "bamboo grove",
+                    TreeType::DatePalm => "date palm",
+                };
+                if tree.is_charred() {
+                    format!("charred {}", name)
+                } else {
+                    name.to_string()
+                }
+            }
             ObjectKind::Table => "table".to_string(),
             ObjectKind::Wall => "wall".to_string(),
             ObjectKind::Boulder => "boulder".to_string(),
@@ -94,6 +394,10 @@ impl ObjectKind {
                     format!("{} carcass", name)
                 }
             }
+            ObjectKind::Seat(seat) => seat.kind.name().to_string(),
+            ObjectKind::Raft(_) => "raft".to_string(),
+            ObjectKind::CaveBear(_) => "cave bear".to_string(),
+            ObjectKind::Ruin(_) => "sand-buried ruin".to_string(),
             ObjectKind::GenericStructure(name) => name.clone(),
         }
     }
@@ -107,6 +411,10 @@ impl ObjectKind {
             ObjectKind::Wall => ObjectSize::Large,
             ObjectKind::Boulder => ObjectSize::Large,
             ObjectKind::Corpse(_) => ObjectSize::Small,
+            ObjectKind::Seat(_) => ObjectSize::Medium,
+            ObjectKind::Raft(_) => ObjectSize::Large,
+            ObjectKind::CaveBear(_) => ObjectSize::Large,
+            ObjectKind::Ruin(_) => ObjectSize::Large,
             ObjectKind::GenericStructure(_) => ObjectSize::Large,
         }
     }
@@ -119,6 +427,33 @@ impl ObjectKind {
         }
     }
 
+    pub fn default_enterable(&self) -> Option<Enterable> {
+        match self {
+            ObjectKind::Cabin(_) => Some(Enterable::Room(Room::CabinMain)),
+            ObjectKind::WoodShed(_) => Some(Enterable::Room(Room::WoodShed)),
+            ObjectKind::Raft(_) => Some(Enterable::Aboard),
+            ObjectKind::Ruin(_) => Some(Enterable::Inside),
+            _ => None,
+        }
+    }
+
+    /// Extra words that should find this object when entering it.
+    pub fn enter_aliases(&self) -> &'static [&'static str] {
+        match self {
+            ObjectKind::Cabin(_) => &["door", "house"],
+            ObjectKind::Ruin(_) => &["ruin", "ruins", "temple"],
+            _ => &[],
+        }
+    }
+
+    /// How many items the object can hold in its own storage, if any.
+    pub fn default_storage(&self) -> Option<ObjectStorage> {
+        match self {
+            ObjectKind::Raft(_) => Some(ObjectStorage::new(Raft::CARGO_CAPACITY)),
+            _ => None,
+        }
+    }
+
     pub fn supports_surface(&self) -> bool {
         matches!(
             self,
@@ -133,6 +468,10 @@ pub struct WorldObject {
     pub anchored: bool,
     #[serde(default)]
     pub surface: Option<ObjectSurface>,
+    #[serde(default)]
+    pub enterable: Option<Enterable>,
+    #[serde(default)]
+    pub storage: Option<ObjectStorage>,
     pub kind: ObjectKind,
 }
 
@@ -144,14 +483,55 @@ impl WorldObject {
         } else {
             None
         };
+        let enterable = kind.default_enterable();
+        let storage = kind.default_storage();
         Self {
             size,
             anchored: true,
             surface,
+            enterable,
+            storage,
             kind,
         }
     }
 
+    pub fn with_enterable(mut self, enterable: Enterable) -> Self {
+        self.enterable = Some(enterable);
+        self
+    }
+
+    /// How this object can be entered; saves predating the field fall back
+    /// to what its kind implies.
+    pub fn entry(&self) -> Option<Enterable> {
+        self.enterable
+            .clone()
+            .or_else(|| self.kind.default_enterable())
+    }
+
+    /// Whether things can be stowed in this object.
+    pub fn has_storage(&self) -> bool {
+        self.storage.is_some() || self.kind.default_storage().is_some()
+    }
+
+    /// The object's own storage; saves predating the field get an empty one
+    /// sized for its kind.
+    pub fn storage_mut(&mut self) -> Option<&mut ObjectStorage> {
+        if self.storage.is_none() {
+            self.storage = self.kind.default_storage();
+        }
+        self.storage.as_mut()
+    }
+
+    /// Whether `target` (already lowercased) names this object for `enter`.
+    pub fn matches_entry_target(&self, target: &str) -> bool {
+        let name = self.display_name().to_lowercase();
+        target.contains(&name)
+            || name
+                .split_whitespace()
+                .any(|word| word.len() > 2 && target.contains(word))
+            || self.kind.enter_aliases().iter().any(|a| target.contains(a))
+    }
+
     pub fn display_name(&self) -> String {
         self.kind.name()
     }
@@ -191,6 +571,63 @@ impl WorldObject {
         }
     }
 
+    pub fn as_seat(&self) -> Option<&Seat> {
+        match &self.kind {
+            ObjectKind::Seat(seat) => Some(seat),
+            _ => None,
+        }
+    }
+
+    /// Furniture that lives inside a room and shouldn't show up outdoors:
+    /// room seats, the table, and the mantel, sills and benches set up as
+    /// surfaces. The cave bear counts too: it is only ever sensed from
+    /// inside the cave.
+    pub fn is_indoor_furniture(&self) -> bool {
+        self.as_seat().map(|s| s.room.is_some()).unwrap_or(false)
+            || matches!(self.kind, ObjectKind::CaveBear(_) | ObjectKind::Table)
+            || matches!(self.kind, ObjectKind::GenericStructure(_)) && self.surface.is_some()
+    }
+
+    pub fn as_raft_mut(&mut self) -> Option<&mut Raft> {
+        match &mut self.kind {
+            ObjectKind::Raft(raft) => Some(raft),
+            _ => None,
+        }
+    }
+
+    pub fn as_ruin(&self) -> Option<&Ruin> {
+        match &self.kind {
+            ObjectKind::Ruin(ruin) => Some(ruin),
+            _ => None,
+        }
+    }
+
+    pub fn as_ruin_mut(&mut self) -> Option<&mut Ruin> {
+        match &mut self.kind {
+            ObjectKind::Ruin(ruin) => Some(ruin),
+            _ => None,
+        }
+    }
+
+    /// Still buried: not seen, entered, or looked at until discovered.
+    pub fn is_hidden(&self) -> bool {
+        self.as_ruin().map(|r| !r.discovered).unwrap_or(false)
+    }
+
+    pub fn as_cave_bear(&self) -> Option<&CaveBear> {
+        match &self.kind {
+            ObjectKind::CaveBear(bear) => Some(bear),
+            _ => None,
+        }
+    }
+
+    pub fn as_cave_bear_mut(&mut self) -> Option<&mut CaveBear> {
+        match &mut self.kind {
+            ObjectKind::CaveBear(bear) => Some(bear),
+            _ => None,
+        }
+    }
+
     pub fn as_tree_mut(&mut self) -> Option<&mut Tree> {
         match &mut self.kind {
             ObjectKind::Tree(tree) => Some(tree),
@@ -251,7 +688,7 @@ impl ObjectRegistry {
     pub fn objects_at(&self, position: &Position) -> Vec<&PlacedObject> {
         self.placed
             .iter()
-            .filter(|p| &p.position == position)
+            .filter(|p| &p.position == position && !p.object.is_hidden())
             .collect()
     }
 
@@ -262,9 +699,40 @@ impl ObjectRegistry {
             .collect()
     }
 
+    /// Tiles taken up by buildings, walls and boulders, which animals
+    /// walk around rather than through.
+    pub fn structure_positions(&self) -> HashSet<Position> {
+        self.placed
+            .iter()
+            .filter(|p| {
+                p.object.anchored
+                    && matches!(
+                        p.object.kind,
+                        ObjectKind::Cabin(_)
+                            | ObjectKind::WoodShed(_)
+                            | ObjectKind::Wall
+                            | ObjectKind::Boulder
+                            | ObjectKind::GenericStructure(_)
+                    )
+            })
+            .map(|p| p.position)
+            .collect()
+    }
+
+    /// The buildings small birds perch on: the cabin and the wood shed.
+    pub fn rooftops(&self) -> impl Iterator<Item = &PlacedObject> {
+        self.placed.iter().filter(|p| {
+            matches!(
+                p.object.kind,
+                ObjectKind::Cabin(_) | ObjectKind::WoodShed(_)
+            )
+        })
+    }
+
     pub fn visible_from(&self, origin: &Position) -> Vec<&PlacedObject> {
         self.placed
             .iter()
+            .filter(|p| !p.object.is_hidden())
             .filter(|p| {
                 let distance = origin.distance_to(&p.position);
                 distance <= p.object.visibility_range() as f32 + 0.01
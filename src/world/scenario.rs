@@ -0,0 +1,143 @@
+//! Named stretches `simulate` can run in place of a bare tick count. Each one
+//! only sets up the ordinary tick loop: how long it runs, whether the night
+//! watch is kept, how often chance events come, and whether a front is held
+//! over the player's region.
+
+use super::{Biome, Region, Weather, WorldTime};
+
+/// The longest any scenario runs, in game minutes: a week.
+pub const MAX_SCENARIO_MINUTES: u32 = 7 * 24 * 60;
+/// How long a forced storm sits over the player's region.
+pub const STORM_MINUTES: u32 = 6 * 60;
+/// Share of the usual chance events a quiet week sees.
+pub const QUIET_EVENT_RATE: f64 = 0.25;
+/// The hour dawn breaks.
+const DAWN_HOUR: u32 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scenario {
+    /// On to the next dawn, keeping the night watch.
+    Overnight,
+    /// A storm front pushed over the player's region until it blows out.
+    Storm,
+    /// Seven days with chance events turned down.
+    QuietWeek,
+}
+
+impl Scenario {
+    pub const ALL: [Scenario; 3] = [Scenario::Overnight, Scenario::Storm, Scenario::QuietWeek];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Scenario::Overnight => "overnight",
+            Scenario::Storm => "storm",
+            Scenario::QuietWeek => "quiet week",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        let key = name.trim().to_lowercase();
+        Self::ALL.into_iter().find(|s| s.name() == key)
+    }
+
+    /// Whether it bends the weather, which only the developer tools may do.
+    pub fn forces_weather(&self) -> bool {
+        matches!(self, Scenario::Storm)
+    }
+
+    /// Game minutes it covers from `time`, never more than a week.
+    pub fn minutes(&self, time: &WorldTime) -> u32 {
+        let minutes = match self {
+            Scenario::Overnight => minutes_to_dawn(time),
+            Scenario::Storm => STORM_MINUTES,
+            Scenario::QuietWeek => 7 * 24 * 60,
+        };
+        minutes.min(MAX_SCENARIO_MINUTES)
+    }
+
+    pub fn ticks(&self, time: &WorldTime) -> u32 {
+        time.ticks_for_minutes(self.minutes(time))
+    }
+
+    /// Whether the night watch is kept, as over someone asleep.
+    pub fn keeps_night_watch(&self) -> bool {
+        matches!(self, Scenario::Overnight)
+    }
+
+    /// Share of the usual chance events it sees.
+    pub fn event_rate(&self) -> f64 {
+        match self {
+            Scenario::QuietWeek => QUIET_EVENT_RATE,
+            Scenario::Overnight | Scenario::Storm => 1.0,
+        }
+    }
+
+    /// The weather it holds over `region` while it runs, if any.
+    pub fn front_for(&self, region: Region) -> Option<Weather> {
+        match self {
+            Scenario::Storm => Some(storm_for(region.biome())),
+            Scenario::Overnight | Scenario::QuietWeek => None,
+        }
+    }
+}
+
+/// The kind of storm a region gets.
+fn storm_for(biome: Biome) -> Weather {
+    match biome {
+        Biome::Desert => Weather::Sandstorm,
+        Biome::WinterForest => Weather::Blizzard,
+        _ => Weather::HeavyRain,
+    }
+}
+
+/// Minutes from `time` until dawn; at or past today's, that's tomorrow's.
+pub fn minutes_to_dawn(time: &WorldTime) -> u32 {
+    let now = time.hour as u32 * 60 + time.minute as u32;
+    let dawn = DAWN_HOUR * 60;
+    if now < dawn {
+        dawn - now
+    } else {
+        24 * 60 - now + dawn
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u8, minute: u8, minutes_per_tick: u32) -> WorldTime {
+        let mut time = WorldTime::new();
+        (time.hour, time.minute) = (hour, minute);
+        time.minutes_per_tick = minutes_per_tick;
+        time
+    }
+
+    #[test]
+    fn overnight_runs_to_the_next_dawn_in_whole_ticks() {
+        // Before dawn it's the same night; from dawn on, the next one
+        assert_eq!(minutes_to_dawn(&at(22, 0, 10)), 7 * 60);
+        assert_eq!(minutes_to_dawn(&at(3, 30, 10)), 90);
+        assert_eq!(minutes_to_dawn(&at(5, 0, 10)), 24 * 60);
+        assert_eq!(minutes_to_dawn(&at(4, 59, 10)), 1);
+
+        let overnight = Scenario::Overnight;
+        assert_eq!(overnight.ticks(&at(22, 0, 10)), 42);
+        assert_eq!(overnight.ticks(&at(22, 0, 20)), 21);
+        assert_eq!(overnight.ticks(&at(22, 0, 5)), 84);
+        // A part tick still runs, so the clock reaches dawn
+        assert_eq!(overnight.ticks(&at(3, 35, 20)), 5);
+        assert_eq!(overnight.ticks(&at(4, 59, 10)), 1);
+
+        // The others run their set length, and nothing past a week
+        assert_eq!(Scenario::Storm.ticks(&at(12, 0, 10)), 36);
+        assert_eq!(Scenario::QuietWeek.ticks(&at(12, 0, 10)), 7 * 144);
+        for scenario in Scenario::ALL {
+            assert!(scenario.minutes(&at(5, 0, 10)) <= MAX_SCENARIO_MINUTES);
+            assert_eq!(
+                Scenario::parse(&scenario.name().to_uppercase()),
+                Some(scenario)
+            );
+        }
+        assert_eq!(Scenario::parse("quiet  week"), None);
+    }
+}
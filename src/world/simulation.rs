@@ -9,10 +9,9 @@ pub fn calculate_temperature(
     indoor: bool,
     fire_heat: f32,
 ) -> f32 {
-    let (row, col) = pos.as_usize().unwrap_or((
-        super::map::MAP_ORIGIN_ROW as usize,
-        super::map::MAP_ORIGIN_COL as usize,
-    ));
+    let (row, col) = map
+        .index_of(pos)
+        .unwrap_or((map.height() / 2, map.width() / 2));
     let biome = map.get_biome_at(row, col).unwrap_or(Biome::MixedForest);
     let base_temp = biome.base_temperature();
 
@@ -115,6 +114,33 @@ pub fn describe_sky(
         },
     }
 
+    // Wind
+    let wind = weather.wind;
+    match wind.strength {
+        weather::WindStrength::Calm => {}
+        weather::WindStrength::Breeze => {
+            description.push_str(&format!("A light breeze drifts in from the {}. ", wind.from.name()));
+        }
+        weather::WindStrength::Strong if biome == Biome::Lake => {
+            description.push_str(&format!(
+                "A strong wind out of the {} raises whitecaps across the water. ",
+                wind.from.name()
+            ));
+        }
+        weather::WindStrength::Strong => {
+            description.push_str(&format!(
+                "A strong wind out of the {} bends the treetops. ",
+                wind.from.name()
+            ));
+        }
+        weather::WindStrength::Gale => {
+            description.push_str(&format!(
+                "A gale tears out of the {}, driving everything before it. ",
+                wind.from.name()
+            ));
+        }
+    }
+
     // Special features based on biome
     match biome {
         Biome::WinterForest
@@ -94,24 +94,97 @@ impl TimeOfDay {
     }
 }
 
+/// The quarter of the year, turning over every `WorldTime::DAYS_PER_SEASON`
+/// days. It sets what the fruit trees are doing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub const ALL: [Season; 4] = [
+        Season::Spring,
+        Season::Summer,
+        Season::Autumn,
+        Season::Winter,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Season::Spring => "spring",
+            Season::Summer => "summer",
+            Season::Autumn => "autumn",
+            Season::Winter => "winter",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorldTime {
     pub day: u32,
     pub hour: u8,   // 0-23
     pub minute: u8, // 0-59
     pub tick: u64,  // Total simulation ticks
+    /// Game minutes each tick covers: 5, 10 or 20.
+    #[serde(default = "WorldTime::default_minutes_per_tick")]
+    pub minutes_per_tick: u32,
 }
 
 impl WorldTime {
+    /// Minutes in one unit of an action's `time_cost`, which is written as if
+    /// every tick were ten minutes.
+    pub const COST_MINUTES: u32 = 10;
+    pub const TICK_OPTIONS: [u32; 3] = [5, 10, 20];
+    /// Days in each season; four make a year.
+    pub const DAYS_PER_SEASON: u32 = 7;
+
+    fn default_minutes_per_tick() -> u32 {
+        Self::COST_MINUTES
+    }
+
     pub fn new() -> Self {
         Self {
             day: 1,
             hour: 8, // Start at 8 AM
             minute: 0,
             tick: 0,
+            minutes_per_tick: Self::default_minutes_per_tick(),
+        }
+    }
+
+    pub fn set_minutes_per_tick(&mut self, minutes: u32) -> bool {
+        if Self::TICK_OPTIONS.contains(&minutes) {
+            self.minutes_per_tick = minutes;
+            true
+        } else {
+            false
         }
     }
 
+    /// Ticks needed to cover `minutes` of game time (at least one).
+    pub fn ticks_for_minutes(&self, minutes: u32) -> u32 {
+        minutes.div_ceil(self.minutes_per_tick.max(1)).max(1)
+    }
+
+    /// Ticks to run for an action's `time_cost`.
+    pub fn ticks_for_cost(&self, time_cost: u32) -> u32 {
+        self.ticks_for_minutes(time_cost * Self::COST_MINUTES)
+    }
+
+    /// Game minutes that `ticks` ticks add up to.
+    pub fn minutes_for_ticks(&self, ticks: u32) -> u32 {
+        ticks * self.minutes_per_tick
+    }
+
+    /// How much of a ten-minute step one tick is; per-tick drains multiply by
+    /// this so a finer clock doesn't make the world hungrier.
+    pub fn tick_scale(&self) -> f32 {
+        self.minutes_per_tick as f32 / Self::COST_MINUTES as f32
+    }
+
     pub fn advance(&mut self, minutes: u32) {
         let total_minutes = self.minute as u32 + minutes;
         let additional_hours = total_minutes / 60;
@@ -126,8 +199,23 @@ impl WorldTime {
     }
 
     pub fn advance_tick(&mut self) {
-        // Each tick is roughly 10 minutes
-        self.advance(10);
+        self.advance(self.minutes_per_tick);
+    }
+
+    /// The season on `day`. The first day falls at the start of summer, so
+    /// a new arrival finds the apples already setting.
+    pub fn season_on(day: u32) -> Season {
+        let quarter = day.saturating_sub(1) / Self::DAYS_PER_SEASON + 1;
+        Season::ALL[quarter as usize % Season::ALL.len()]
+    }
+
+    pub fn season(&self) -> Season {
+        Self::season_on(self.day)
+    }
+
+    /// Which day of the current season it is, counting from 1.
+    pub fn day_of_season(&self) -> u32 {
+        self.day.saturating_sub(1) % Self::DAYS_PER_SEASON + 1
     }
 
     pub fn time_of_day(&self) -> TimeOfDay {
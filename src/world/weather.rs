@@ -1,4 +1,4 @@
-use super::map::Biome;
+use super::map::{Biome, Direction, Region, WorldMap};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
@@ -34,6 +34,39 @@ impl Weather {
         }
     }
 
+    /// A single glyph for the web view's weather strip.
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            Weather::Clear => "☀",
+            Weather::Cloudy => "⛅",
+            Weather::Overcast => "☁",
+            Weather::LightRain => "🌦",
+            Weather::HeavyRain => "🌧",
+            Weather::Fog => "🌫",
+            Weather::Sandstorm => "🌪",
+            Weather::HeatWave => "🌡",
+            Weather::LightSnow => "🌨",
+            Weather::HeavySnow => "❄",
+            Weather::Blizzard => "🌬",
+        }
+    }
+
+    /// How readily a stray flame catches on open ground; nothing catches in
+    /// rain or snow.
+    pub fn ignition_chance(&self) -> f64 {
+        match self {
+            Weather::LightRain
+            | Weather::HeavyRain
+            | Weather::LightSnow
+            | Weather::HeavySnow
+            | Weather::Blizzard => 0.0,
+            Weather::Fog => 0.05,
+            Weather::Cloudy | Weather::Overcast => 0.15,
+            Weather::Clear | Weather::Sandstorm => 0.3,
+            Weather::HeatWave => 0.6,
+        }
+    }
+
     pub fn visibility_modifier(&self) -> f32 {
         match self {
             Weather::Clear => 1.0,
@@ -50,6 +83,14 @@ impl Weather {
         }
     }
 
+    /// Weather that's more than unpleasant: the kind a person weathers.
+    pub fn is_storm(&self) -> bool {
+        matches!(
+            self,
+            Weather::Blizzard | Weather::HeavyRain | Weather::HeavySnow | Weather::Sandstorm
+        )
+    }
+
     pub fn temperature_modifier(&self) -> f32 {
         match self {
             Weather::Clear => 0.0,
@@ -126,12 +167,149 @@ impl Weather {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum WindStrength {
+    Calm,
+    Breeze,
+    Strong,
+    Gale,
+}
+
+impl WindStrength {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WindStrength::Calm => "calm",
+            WindStrength::Breeze => "a light breeze",
+            WindStrength::Strong => "a strong wind",
+            WindStrength::Gale => "a gale",
+        }
+    }
+
+    /// The strength a region's weather pushes the wind toward.
+    fn driven_by(weather: Weather) -> WindStrength {
+        match weather {
+            Weather::Blizzard | Weather::Sandstorm => WindStrength::Gale,
+            Weather::HeavyRain | Weather::HeavySnow => WindStrength::Strong,
+            Weather::Cloudy | Weather::Overcast | Weather::LightRain | Weather::LightSnow => {
+                WindStrength::Breeze
+            }
+            Weather::Clear | Weather::Fog | Weather::HeatWave => WindStrength::Calm,
+        }
+    }
+
+    fn step_toward(self, target: WindStrength) -> WindStrength {
+        let order = [
+            WindStrength::Calm,
+            WindStrength::Breeze,
+            WindStrength::Strong,
+            WindStrength::Gale,
+        ];
+        let idx = order.iter().position(|s| *s == self).unwrap_or(0);
+        if target > self {
+            order[idx + 1]
+        } else if target < self {
+            order[idx - 1]
+        } else {
+            self
+        }
+    }
+}
+
+/// One wind over the whole valley, blowing out of the stormiest front.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Wind {
+    /// The direction it blows from.
+    pub from: Direction,
+    pub strength: WindStrength,
+}
+
+impl Default for Wind {
+    fn default() -> Self {
+        Self {
+            from: Direction::West,
+            strength: WindStrength::Breeze,
+        }
+    }
+}
+
+impl Wind {
+    /// Where it pushes things.
+    pub fn downwind(&self) -> Direction {
+        self.from.opposite()
+    }
+
+    /// Whether travelling `dir` puts the wind on your side.
+    pub fn is_crosswind(&self, dir: Direction) -> bool {
+        self.strength != WindStrength::Calm && dir != self.from && dir != self.downwind()
+    }
+
+    /// How much faster a fire burns with the wind drawing on it.
+    pub fn fire_draw(&self) -> f32 {
+        match self.strength {
+            WindStrength::Calm | WindStrength::Breeze => 1.0,
+            WindStrength::Strong => 1.25,
+            WindStrength::Gale => 1.5,
+        }
+    }
+
+    /// Chance each tick that a wildfire jumps to the next tile.
+    pub fn fire_spread(&self) -> f64 {
+        match self.strength {
+            WindStrength::Calm => 0.1,
+            WindStrength::Breeze => 0.2,
+            WindStrength::Strong => 0.35,
+            WindStrength::Gale => 0.5,
+        }
+    }
+
+    /// How many tiles upwind a distant sound can still reach you from.
+    pub fn carry_range(&self) -> i32 {
+        match self.strength {
+            WindStrength::Calm => 0,
+            WindStrength::Breeze => 4,
+            WindStrength::Strong => 7,
+            WindStrength::Gale => 10,
+        }
+    }
+
+    /// A rough feel for the wind, without instruments.
+    pub fn feel(&self) -> String {
+        match self.strength {
+            WindStrength::Calm => "The air is still.".to_string(),
+            WindStrength::Breeze => {
+                format!("A light breeze comes from somewhere {}ward.", self.from.name())
+            }
+            WindStrength::Strong => format!(
+                "A strong wind pushes at you out of the {}.",
+                self.from.name()
+            ),
+            WindStrength::Gale => format!(
+                "A gale roars out of the {}, hard enough to lean on.",
+                self.from.name()
+            ),
+        }
+    }
+}
+
+/// A front held over one region whatever the dice say. When it runs out the
+/// region clears to something calmer.
+#[derive(Debug, Clone, Copy)]
+pub struct ForcedFront {
+    pub region: Region,
+    pub weather: Weather,
+    pub ticks_left: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegionalWeather {
     pub north: Weather, // Spring/Autumn
     pub south: Weather, // Mixed
     pub east: Weather,  // Winter
     pub west: Weather,  // Desert
+    #[serde(default)]
+    pub wind: Wind,
+    #[serde(skip)]
+    pub forced: Option<ForcedFront>,
 }
 
 impl RegionalWeather {
@@ -141,6 +319,8 @@ impl RegionalWeather {
             south: Weather::random_for_biome(Biome::MixedForest),
             east: Weather::random_for_biome(Biome::WinterForest),
             west: Weather::random_for_biome(Biome::Desert),
+            wind: Wind::default(),
+            forced: None,
         }
     }
 
@@ -148,34 +328,91 @@ impl RegionalWeather {
         let mut rng = rand::thread_rng();
 
         // 20% chance to change weather each update
-        if rng.gen_bool(0.2) {
-            self.north = Weather::random_for_biome(Biome::SpringForest);
+        for region in Region::ALL {
+            if let Some(front) = self.forced.filter(|f| f.region == region) {
+                *self.for_region_mut(region) = front.weather;
+                continue;
+            }
+            if rng.gen_bool(0.2) {
+                *self.for_region_mut(region) = Weather::random_for_biome(region.biome());
+            }
         }
-        if rng.gen_bool(0.2) {
-            self.south = Weather::random_for_biome(Biome::MixedForest);
+        self.update_wind(&mut rng);
+    }
+
+    /// Hold `weather` over `region` for the next `ticks` ticks.
+    pub fn force_front(&mut self, region: Region, weather: Weather, ticks: u32) {
+        *self.for_region_mut(region) = weather;
+        self.forced = Some(ForcedFront {
+            region,
+            weather,
+            ticks_left: ticks,
+        });
+    }
+
+    /// Count a forced front down by a tick. Returns whether it lifted on
+    /// this one.
+    pub fn tick_front(&mut self) -> bool {
+        let Some(front) = &mut self.forced else {
+            return false;
+        };
+        front.ticks_left = front.ticks_left.saturating_sub(1);
+        if front.ticks_left > 0 {
+            return false;
         }
-        if rng.gen_bool(0.2) {
-            self.east = Weather::random_for_biome(Biome::WinterForest);
+        let region = front.region;
+        self.forced = None;
+        let calmer: Vec<Weather> = Weather::possible_for_biome(region.biome())
+            .into_iter()
+            .filter(|w| !w.is_storm())
+            .collect();
+        let mut rng = rand::thread_rng();
+        *self.for_region_mut(region) = calmer[rng.gen_range(0..calmer.len())];
+        true
+    }
+
+    /// Ease the wind a step toward whatever the fronts are driving, and
+    /// swing it round to blow out of the roughest one.
+    fn update_wind(&mut self, rng: &mut impl Rng) {
+        let (front, weather) = Region::ALL
+            .iter()
+            .map(|r| (r.direction(), self.for_region(*r)))
+            .max_by_key(|(_, w)| WindStrength::driven_by(*w))
+            .unwrap_or((Direction::West, self.west));
+        let target = WindStrength::driven_by(weather);
+        if rng.gen_bool(0.8) {
+            self.wind.strength = self.wind.strength.step_toward(target);
         }
-        if rng.gen_bool(0.2) {
-            self.west = Weather::random_for_biome(Biome::Desert);
+        if target >= WindStrength::Strong && rng.gen_bool(0.5) {
+            self.wind.from = front;
+        } else if rng.gen_bool(0.15) {
+            let veer = match self.wind.from {
+                Direction::North | Direction::South => [Direction::East, Direction::West],
+                _ => [Direction::North, Direction::South],
+            };
+            self.wind.from = veer[rng.gen_range(0..veer.len())];
         }
     }
 
     pub fn get_for_position(&self, world_row: i32, world_col: i32) -> Weather {
-        // Determine quadrant relative to world origin (0,0 at the cabin)
-        if world_row.abs() > world_col.abs() {
-            if world_row < 0 {
-                self.north
-            } else {
-                self.south
-            }
-        } else {
-            if world_col < 0 {
-                self.west
-            } else {
-                self.east
-            }
+        self.for_region(WorldMap::region_of(world_row, world_col))
+    }
+
+    pub fn for_region(&self, region: Region) -> Weather {
+        match region {
+            Region::NorthernWoods => self.north,
+            Region::EasternPines => self.east,
+            Region::SouthernWoods => self.south,
+            Region::GlassDesert => self.west,
+        }
+    }
+
+    fn for_region_mut(&mut self, region: Region) -> &mut Weather {
+        match region {
+            Region::NorthernWoods => &mut self.north,
+            Region::EasternPines => &mut self.east,
+            Region::SouthernWoods => &mut self.south,
+            Region::GlassDesert => &mut self.west,
         }
     }
 }
@@ -185,3 +422,33 @@ impl Default for RegionalWeather {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_forced_front_holds_through_updates_then_lifts_to_calmer_weather() {
+        let mut weather = RegionalWeather::new();
+        let region = Region::EasternPines;
+        weather.force_front(region, Weather::Blizzard, 3);
+        assert_eq!(weather.for_region(region), Weather::Blizzard);
+
+        // The dice don't move it while it holds
+        for _ in 0..50 {
+            weather.update();
+            assert_eq!(weather.for_region(region), Weather::Blizzard);
+        }
+        assert!(!weather.tick_front());
+        assert!(!weather.tick_front());
+        assert_eq!(weather.forced.map(|f| f.ticks_left), Some(1));
+
+        // On its last tick it clears to something the region gets that isn't a storm
+        assert!(weather.tick_front());
+        assert!(weather.forced.is_none());
+        let after = weather.for_region(region);
+        assert!(!after.is_storm(), "{:?}", after);
+        assert!(Weather::possible_for_biome(region.biome()).contains(&after));
+        assert!(!weather.tick_front());
+    }
+}